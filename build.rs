@@ -0,0 +1,10 @@
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    #[cfg(feature = "grpc")]
+    {
+        println!("cargo:rerun-if-changed=proto/cyclogenese.proto");
+        let descriptors = protox::compile(["proto/cyclogenese.proto"], ["proto"])?;
+        tonic_build::configure().compile_fds(descriptors)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,165 @@
+//! Différentiation automatique en mode direct
+//!
+//! Un nombre dual a + a'·ε, avec ε² = 0, porte une valeur et sa dérivée par
+//! rapport à un paramètre d'entrée. Une simulation sur `Dual`, dont l'anomalie
+//! étudiée a une dérivée initiale de 1, donne en une seule passe la dérivée exacte
+//! de chaque résultat par rapport à ce paramètre, sans erreur de troncature.
+//!
+//! La position de la dépression reste en `f64` : sur le plan β ou la sphère, la
+//! dérivée ne tient pas compte du déplacement de la trajectoire en latitude.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{BaroclinicCyclogenesis, Float, MeteoError};
+
+/// Nombre dual : valeur et dérivée par rapport au paramètre étudié
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Dual {
+    value: f64,
+    derivative: f64,
+}
+
+impl Dual {
+    /// Nombre de valeur `value` et de dérivée `derivative`
+    pub fn new(value: f64, derivative: f64) -> Self {
+        Self { value, derivative }
+    }
+
+    /// Paramètre par rapport auquel dériver, de dérivée 1
+    pub fn variable(value: f64) -> Self {
+        Self::new(value, 1.0)
+    }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    pub fn derivative(&self) -> f64 {
+        self.derivative
+    }
+}
+
+impl fmt::Display for Dual {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} + {}ε", self.value, self.derivative)
+    }
+}
+
+impl Add for Dual {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.value + other.value, self.derivative + other.derivative)
+    }
+}
+
+impl AddAssign for Dual {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Dual {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.value - other.value, self.derivative - other.derivative)
+    }
+}
+
+impl Mul for Dual {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::new(self.value * other.value, self.derivative * other.value + self.value * other.derivative)
+    }
+}
+
+impl Div for Dual {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        Self::new(
+            self.value / other.value,
+            (self.derivative * other.value - self.value * other.derivative) / (other.value * other.value),
+        )
+    }
+}
+
+impl Neg for Dual {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.value, -self.derivative)
+    }
+}
+
+impl Float for Dual {
+    fn from_f64(value: f64) -> Self {
+        Self::new(value, 0.0)
+    }
+
+    fn to_f64(self) -> f64 {
+        self.value
+    }
+
+    fn abs(self) -> Self {
+        if self.value < 0.0 { -self } else { self }
+    }
+
+    fn sqrt(self) -> Self {
+        let root = self.value.sqrt();
+        Self::new(root, self.derivative / (2.0 * root))
+    }
+}
+
+/// Paramètre d'entrée différentiable
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Parameter {
+    SurfaceTemp,
+    AltitudeTemp,
+}
+
+/// Maximum du tourbillon d'une simulation et sa dérivée
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PeakSensitivity {
+    pub hour: u32,        // Heure du maximum
+    pub vorticity: f64,   // Tourbillon relatif au maximum (s⁻¹)
+    pub derivative: f64,  // Dérivée du tourbillon au maximum par rapport au paramètre (s⁻¹ par unité)
+}
+
+/// Tourbillon de plus grande valeur absolue du cas à deux anomalies et sa dérivée
+/// exacte par rapport à un paramètre, `None` sans pas de temps
+pub fn peak_vorticity_sensitivity(
+    surface_temp: f64,
+    altitude_temp: f64,
+    latitude: f64,
+    time_steps: u32,
+    parameter: Parameter,
+) -> Result<Option<PeakSensitivity>, MeteoError> {
+    let (surface, altitude) = match parameter {
+        Parameter::SurfaceTemp => (Dual::variable(surface_temp), Dual::from_f64(altitude_temp)),
+        Parameter::AltitudeTemp => (Dual::from_f64(surface_temp), Dual::variable(altitude_temp)),
+    };
+    let mut cyclogenesis = BaroclinicCyclogenesis::new(surface, altitude, latitude)?;
+    peak_sensitivity(&mut cyclogenesis, time_steps)
+}
+
+/// Tourbillon de plus grande valeur absolue et sa dérivée exacte, pour une
+/// simulation dont les anomalies portent les dérivées initiales
+pub fn peak_sensitivity(
+    cyclogenesis: &mut BaroclinicCyclogenesis<Dual>,
+    time_steps: u32,
+) -> Result<Option<PeakSensitivity>, MeteoError> {
+    let mut peak: Option<PeakSensitivity> = None;
+    for hour in 0..time_steps {
+        let vorticity = cyclogenesis.step(hour)?.relative_vorticity;
+        if peak.is_none_or(|peak| vorticity.value.abs() > peak.vorticity.abs()) {
+            peak = Some(PeakSensitivity { hour, vorticity: vorticity.value, derivative: vorticity.derivative });
+        }
+    }
+    Ok(peak)
+}
@@ -0,0 +1,353 @@
+//! Traitement par lots d'un répertoire de scénarios
+//!
+//! Les fichiers du répertoire retenus par les filtres (motifs `*` et `?` sur le
+//! nom) sont simulés en parallèle. Chaque scénario réussi donne un fichier
+//! `<nom>.json` de ses résultats dans le répertoire de sortie, lisible par
+//! [`crate::diff::load`] ; l'index `index.json` résume tous les scénarios, y
+//! compris ceux en échec avec leur erreur.
+//!
+//! L'index sert aussi de journal : il est écrit avant la première simulation,
+//! tous les scénarios en attente, puis réécrit (par renommage, donc jamais à
+//! moitié) après chacune. Une reprise ([`BatchReport::resume`]) garde les
+//! scénarios réussis dont le fichier de résultats existe encore et relance les
+//! autres : ceux en échec, ceux restés en attente après une interruption et
+//! ceux ajoutés au répertoire depuis.
+//!
+//! Chaque simulation est comptée dans les [`Metrics`] du lot, sous le nom de son
+//! scénario (champ `name`, ou nom du fichier sans extension).
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+
+use crate::metrics::Metrics;
+use crate::{MeteoError, NumberFormat, OutputLanguage, Scenario};
+
+/// Nom de l'index écrit dans le répertoire de sortie
+pub const INDEX_FILE: &str = "index.json";
+
+/// Filtres sur les noms des fichiers de scénario
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchFilter {
+    include: Vec<String>,
+    exclude: Vec<String>,
+}
+
+impl Default for BatchFilter {
+    /// Tous les fichiers TOML
+    fn default() -> Self {
+        Self { include: vec!["*.toml".to_string()], exclude: Vec::new() }
+    }
+}
+
+impl BatchFilter {
+    /// Fichiers retenus par l'un des motifs `include` (tous les TOML sans motif)
+    /// et par aucun des motifs `exclude`
+    pub fn new(include: &[String], exclude: &[String]) -> Self {
+        let include = if include.is_empty() { Self::default().include } else { include.to_vec() };
+        Self { include, exclude: exclude.to_vec() }
+    }
+
+    /// Le fichier de ce nom est-il retenu ?
+    pub fn matches(&self, name: &str) -> bool {
+        self.include.iter().any(|pattern| glob_match(pattern, name))
+            && !self.exclude.iter().any(|pattern| glob_match(pattern, name))
+    }
+}
+
+/// Correspondance d'un nom avec un motif où `*` remplace toute suite de
+/// caractères et `?` un caractère
+pub fn glob_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                // L'étoile précédente absorbe un caractère de plus
+                Some((star, matched)) => {
+                    p = star + 1;
+                    n = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Fichiers de scénario d'un répertoire retenus par les filtres, par ordre de nom
+pub fn discover<P: AsRef<Path>>(directory: P, filter: &BatchFilter) -> Result<Vec<PathBuf>, MeteoError> {
+    let directory = directory.as_ref();
+    let error = |e: std::io::Error| MeteoError::InvalidScenario(format!("{}: {}", directory.display(), e));
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(directory).map_err(error)? {
+        let path = entry.map_err(error)?.path();
+        let selected = path.is_file() && path.file_name().and_then(|name| name.to_str()).is_some_and(|name| filter.matches(name));
+        if selected {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+/// État d'un scénario dans le journal du lot
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JobStatus {
+    Pending,
+    Succeeded,
+    Failed,
+}
+
+/// Bilan d'un scénario du lot
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatchEntry {
+    pub scenario: String,                     // Nom du fichier de scénario
+    pub status: JobStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,               // Nom du fichier de résultats
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_steps: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub peak_hour: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub peak_vorticity: Option<f64>,          // Tourbillon relatif au pic (s⁻¹), avec son signe
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lowest_pressure: Option<f64>,         // Pression centrale minimale (hPa)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_kind: Option<String>,           // Variante de [`MeteoError`], voir [`MeteoError::kind`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl BatchEntry {
+    fn pending(scenario: String) -> Self {
+        Self {
+            scenario,
+            status: JobStatus::Pending,
+            output: None,
+            time_steps: None,
+            peak_hour: None,
+            peak_vorticity: None,
+            lowest_pressure: None,
+            error_kind: None,
+            error: None,
+        }
+    }
+
+    fn failed(scenario: String, error: &MeteoError, language: OutputLanguage) -> Self {
+        Self {
+            status: JobStatus::Failed,
+            error_kind: Some(error.kind().to_string()),
+            error: Some(error.message(language)),
+            ..Self::pending(scenario)
+        }
+    }
+
+    pub fn succeeded(&self) -> bool {
+        self.status == JobStatus::Succeeded
+    }
+}
+
+/// Bilan d'un lot, dans l'ordre des noms de fichiers
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BatchReport {
+    pub entries: Vec<BatchEntry>,
+}
+
+impl BatchReport {
+    /// Simule les scénarios d'un répertoire sur `jobs` fils et écrit leurs
+    /// résultats et l'index dans `output` ; un scénario en échec n'interrompt
+    /// pas les autres
+    pub fn run<P: AsRef<Path>, Q: AsRef<Path>>(
+        directory: P,
+        output: Q,
+        filter: &BatchFilter,
+        jobs: usize,
+        language: OutputLanguage,
+        metrics: &Metrics,
+    ) -> Result<Self, MeteoError> {
+        let paths = discover(directory, filter)?;
+        let entries = paths.iter().map(|path| BatchEntry::pending(file_name(path))).collect();
+        let todo = paths.into_iter().enumerate().collect();
+        Self { entries }.execute(todo, output.as_ref(), jobs, language, metrics)
+    }
+
+    /// Reprend un lot d'après l'index de `output` : seuls les scénarios qui n'y
+    /// sont pas réussis sont simulés ; sans index, équivaut à [`BatchReport::run`]
+    pub fn resume<P: AsRef<Path>, Q: AsRef<Path>>(
+        directory: P,
+        output: Q,
+        filter: &BatchFilter,
+        jobs: usize,
+        language: OutputLanguage,
+        metrics: &Metrics,
+    ) -> Result<Self, MeteoError> {
+        let output = output.as_ref();
+        let previous = if output.join(INDEX_FILE).exists() { Self::load_index(output)?.entries } else { Vec::new() };
+        let mut entries = Vec::new();
+        let mut todo = Vec::new();
+        for path in discover(directory, filter)? {
+            let name = file_name(&path);
+            let done = previous.iter().find(|entry| entry.scenario == name).filter(|entry| {
+                entry.succeeded() && entry.output.as_ref().is_some_and(|file| output.join(file).exists())
+            });
+            match done {
+                Some(entry) => entries.push(entry.clone()),
+                None => {
+                    todo.push((entries.len(), path));
+                    entries.push(BatchEntry::pending(name));
+                }
+            }
+        }
+        Self { entries }.execute(todo, output, jobs, language, metrics)
+    }
+
+    /// Simule les scénarios `todo` (position dans l'index, chemin) en tenant le
+    /// journal à jour après chacun
+    fn execute(
+        self,
+        todo: Vec<(usize, PathBuf)>,
+        output: &Path,
+        jobs: usize,
+        language: OutputLanguage,
+        metrics: &Metrics,
+    ) -> Result<Self, MeteoError> {
+        fs::create_dir_all(output).map_err(|e| MeteoError::InvalidRun(format!("{}: {}", output.display(), e)))?;
+        self.write_index(output)?;
+
+        let next = AtomicUsize::new(0);
+        let journal = Mutex::new((self, None::<MeteoError>));
+        thread::scope(|scope| {
+            for _ in 0..jobs.clamp(1, todo.len().max(1)) {
+                scope.spawn(|| {
+                    while let Some((index, path)) = todo.get(next.fetch_add(1, Ordering::Relaxed)) {
+                        let entry = run_one(path, output, language, metrics);
+                        let mut journal = journal.lock().expect("fil de lot interrompu");
+                        journal.0.entries[*index] = entry;
+                        if let Err(error) = journal.0.write_index(output) {
+                            journal.1.get_or_insert(error);
+                        }
+                    }
+                });
+            }
+        });
+        match journal.into_inner().expect("fil de lot interrompu") {
+            (_, Some(error)) => Err(error),
+            (report, None) => Ok(report),
+        }
+    }
+
+    /// Écrit l'index dans le répertoire de sortie, en remplaçant l'ancien d'un bloc
+    pub fn write_index<P: AsRef<Path>>(&self, output: P) -> Result<(), MeteoError> {
+        let path = output.as_ref().join(INDEX_FILE);
+        let partial = path.with_extension("json.partial");
+        let text = serde_json::to_string_pretty(self).expect("index sérialisable");
+        fs::write(&partial, text)
+            .and_then(|_| fs::rename(&partial, &path))
+            .map_err(|e| MeteoError::InvalidRun(format!("{}: {}", path.display(), e)))
+    }
+
+    /// Lit l'index d'un répertoire de sortie
+    pub fn load_index<P: AsRef<Path>>(output: P) -> Result<Self, MeteoError> {
+        let path = output.as_ref().join(INDEX_FILE);
+        let text = fs::read_to_string(&path).map_err(|e| MeteoError::InvalidRun(format!("{}: {}", path.display(), e)))?;
+        serde_json::from_str(&text).map_err(|e| MeteoError::InvalidRun(format!("{}: {}", path.display(), e)))
+    }
+
+    /// Scénarios en échec
+    pub fn failures(&self) -> impl Iterator<Item = &BatchEntry> {
+        self.entries.iter().filter(|entry| entry.status == JobStatus::Failed)
+    }
+
+    /// Scénarios en attente, restés dans le journal d'un lot interrompu
+    pub fn pending(&self) -> impl Iterator<Item = &BatchEntry> {
+        self.entries.iter().filter(|entry| entry.status == JobStatus::Pending)
+    }
+
+    /// Tableau du pic de tourbillon et de la pression minimale de chaque scénario
+    pub fn to_table(&self, language: OutputLanguage, format: &NumberFormat) -> String {
+        let (name, vorticity, pressure) = match language {
+            OutputLanguage::French => ("Scénario", "ζ maximal (10⁻⁵ s⁻¹)", "Pression min. (hPa)"),
+            OutputLanguage::English => ("Scenario", "Peak ζ (10⁻⁵ s⁻¹)", "Min. pressure (hPa)"),
+        };
+        let width = self.entries.iter().map(|entry| entry.scenario.chars().count()).max().unwrap_or(0).max(name.chars().count());
+        let mut table = format!("{:<width$} | {:<20} | {}\n", name, vorticity, pressure, width = width);
+        table += &format!("{}|----------------------|----------------------\n", "-".repeat(width + 1));
+        for entry in &self.entries {
+            table += &format!("{:<width$} | ", entry.scenario, width = width);
+            match (entry.peak_vorticity, entry.lowest_pressure, &entry.error) {
+                (Some(peak), Some(lowest), _) => {
+                    table += &format!("{} | {}\n", format.format_width(peak * 1e5, 20), format.format_width(lowest, 20));
+                }
+                (_, _, Some(error)) => table += &format!("{}\n", error),
+                _ if entry.status == JobStatus::Pending => match language {
+                    OutputLanguage::French => table += "en attente\n",
+                    OutputLanguage::English => table += "pending\n",
+                },
+                _ => table += "\n",
+            }
+        }
+
+        let failed = self.failures().count();
+        let succeeded = self.entries.iter().filter(|entry| entry.succeeded()).count();
+        table += &match language {
+            OutputLanguage::French => format!("\n{} scénario(s) simulé(s), {} en échec\n", succeeded, failed),
+            OutputLanguage::English => format!("\n{} scenario(s) simulated, {} failed\n", succeeded, failed),
+        };
+        table
+    }
+}
+
+/// Simule un scénario et écrit ses résultats
+fn run_one(path: &Path, output: &Path, language: OutputLanguage, metrics: &Metrics) -> BatchEntry {
+    let name = file_name(path);
+    let stem = path.file_stem().map(|stem| stem.to_string_lossy().into_owned()).unwrap_or_default();
+    let outcome = Scenario::load(path).and_then(|scenario| {
+        let start = Instant::now();
+        let mut cyclogenesis = scenario.to_cyclogenesis()?;
+        let results = cyclogenesis.simulate_interaction(scenario.time_steps)?;
+        let file = format!("{}.json", stem);
+        let target = output.join(&file);
+        let text = serde_json::to_string_pretty(&results).expect("résultats sérialisables");
+        fs::write(&target, text).map_err(|e| MeteoError::InvalidRun(format!("{}: {}", target.display(), e)))?;
+        metrics.record_scenario(Some(scenario.name.as_deref().unwrap_or(&stem)), scenario.time_steps, start.elapsed());
+
+        let peak = results.iter().max_by(|a, b| a.relative_vorticity.abs().total_cmp(&b.relative_vorticity.abs()));
+        let lowest = results.iter().map(|result| cyclogenesis.central_pressure(result)).reduce(f64::min);
+        Ok(BatchEntry {
+            status: JobStatus::Succeeded,
+            output: Some(file),
+            time_steps: Some(scenario.time_steps),
+            peak_hour: peak.map(|peak| peak.hour),
+            peak_vorticity: peak.map(|peak| peak.relative_vorticity),
+            lowest_pressure: lowest,
+            ..BatchEntry::pending(name.clone())
+        })
+    });
+    outcome.unwrap_or_else(|error| {
+        metrics.record_error();
+        BatchEntry::failed(name, &error, language)
+    })
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default()
+}
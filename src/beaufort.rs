@@ -0,0 +1,98 @@
+//! Échelle de Beaufort des vents de surface
+//!
+//! Le vent de surface est approché par le maximum du vent tangentiel de la
+//! circulation de surface (voir `wind`), sans réduction par le frottement. Les
+//! seuils sont ceux de l'OMM, en vent moyen à 10 m.
+
+use std::fmt;
+
+use crate::OutputLanguage;
+
+/// Bornes supérieures des forces 0 à 11 (m/s) ; au-delà, force 12
+const UPPER_BOUNDS: [f64; 12] = [0.5, 1.5, 3.3, 5.5, 7.9, 10.7, 13.8, 17.1, 20.7, 24.4, 28.4, 32.6];
+
+/// Force du vent sur l'échelle de Beaufort, de 0 à 12
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Beaufort(u8);
+
+impl Beaufort {
+    /// Plus forte valeur de l'échelle
+    pub const MAX: Beaufort = Beaufort(12);
+
+    /// Force correspondant à une vitesse de vent (m/s)
+    pub fn from_speed(speed: f64) -> Self {
+        let speed = speed.abs();
+        let force = UPPER_BOUNDS.iter().position(|bound| speed < *bound).unwrap_or(UPPER_BOUNDS.len());
+        Beaufort(force as u8)
+    }
+
+    /// Force de 0 à 12, `None` au-delà
+    pub fn new(force: u8) -> Option<Self> {
+        (force <= Self::MAX.0).then_some(Beaufort(force))
+    }
+
+    /// Numéro de la force
+    pub fn force(self) -> u8 {
+        self.0
+    }
+
+    /// Plus faible vitesse de la force (m/s)
+    pub fn lower_bound(self) -> f64 {
+        match self.0 {
+            0 => 0.0,
+            force => UPPER_BOUNDS[force as usize - 1],
+        }
+    }
+
+    /// Nom de la force (« Tempête », « Storm »...)
+    pub fn name(self, language: OutputLanguage) -> &'static str {
+        const FRENCH: [&str; 13] = [
+            "Calme",
+            "Très légère brise",
+            "Légère brise",
+            "Petite brise",
+            "Jolie brise",
+            "Bonne brise",
+            "Vent frais",
+            "Grand frais",
+            "Coup de vent",
+            "Fort coup de vent",
+            "Tempête",
+            "Violente tempête",
+            "Ouragan",
+        ];
+        const ENGLISH: [&str; 13] = [
+            "Calm",
+            "Light air",
+            "Light breeze",
+            "Gentle breeze",
+            "Moderate breeze",
+            "Fresh breeze",
+            "Strong breeze",
+            "Near gale",
+            "Gale",
+            "Strong gale",
+            "Storm",
+            "Violent storm",
+            "Hurricane",
+        ];
+        match language {
+            OutputLanguage::French => FRENCH[self.0 as usize],
+            OutputLanguage::English => ENGLISH[self.0 as usize],
+        }
+    }
+
+    /// Libellé lisible (« Tempête, force 10 », « Storm force 10 »)
+    pub fn describe(self, language: OutputLanguage) -> String {
+        match language {
+            OutputLanguage::French => format!("{}, force {}", self.name(language), self.0),
+            OutputLanguage::English => format!("{} force {}", self.name(language), self.0),
+        }
+    }
+}
+
+impl fmt::Display for Beaufort {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
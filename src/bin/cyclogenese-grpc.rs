@@ -0,0 +1,34 @@
+use std::error::Error;
+use std::net::{SocketAddr, TcpListener};
+use std::sync::Arc;
+
+use cyclogenese_rust::metrics::Metrics;
+use cyclogenese_rust::{grpc, server};
+
+const DEFAULT_ADDR: &str = "127.0.0.1:50051";
+
+/// `cyclogenese-grpc [ADRESSE] [ADRESSE_METRIQUES]` : la seconde adresse expose
+/// `/metrics` pour Prometheus
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut args = std::env::args().skip(1);
+    let addr: SocketAddr = args
+        .next()
+        .as_deref()
+        .unwrap_or(DEFAULT_ADDR)
+        .parse()?;
+
+    let metrics = Arc::new(Metrics::default());
+    if let Some(metrics_addr) = args.next() {
+        let listener = TcpListener::bind(&metrics_addr)?;
+        let metrics = Arc::clone(&metrics);
+        std::thread::spawn(move || server::serve_metrics(listener, metrics));
+        println!("Métriques Prometheus sur http://{}/metrics", metrics_addr);
+    }
+
+    println!("Service de simulation gRPC à l'écoute sur {}", addr);
+
+    let runtime = tokio::runtime::Runtime::new()?;
+    runtime.block_on(grpc::serve(addr, metrics))?;
+
+    Ok(())
+}
@@ -0,0 +1,130 @@
+//! Couche limite de hauteur évolutive
+//!
+//! Sans couche limite, le flux de chaleur de surface et le pompage d'Ekman
+//! supposent implicitement une couche de mélange de 1000 m. Une couche limite
+//! évolutive suit à chaque pas sa hauteur d'équilibre, en s'y ajustant en
+//! six heures au pas et selon le schéma de l'intégration :
+//!
+//! - le brassage mécanique l'épaissit avec le vent, h = 0,25·u*/|f| où la
+//!   vitesse de frottement u* = √C_D·U suit la loi de traînée de la surface ;
+//! - un flux de chaleur positif l'épaissit par convection, de 300 m par kelvin.
+//!
+//! Le même flux réchauffe d'autant plus une couche mince : le forçage thermique
+//! de l'anomalie de surface est multiplié par 1000/h. Le pompage d'Ekman croît
+//! au contraire avec la hauteur de la couche, son taux étant multiplié par
+//! h/1000 ; l'usure du tourbillon cumule alors les taux de chaque pas.
+
+use std::ops::RangeInclusive;
+
+use crate::surface::OCEAN_DRAG;
+use crate::{BaroclinicCyclogenesis, Float, IntegrationScheme, MeteoError};
+
+/// Hauteur de couche limite implicite du modèle sans couche limite (m)
+pub const REFERENCE_DEPTH: f64 = 1000.0;
+
+/// Hauteurs de couche limite acceptées (m)
+const DEPTH_RANGE: RangeInclusive<f64> = 100.0..=3000.0;
+
+/// Coefficient de la hauteur de brassage mécanique 0,25·u*/|f|
+const MECHANICAL_COEFFICIENT: f64 = 0.25;
+
+/// Épaississement convectif par kelvin de flux de chaleur (m/K)
+const CONVECTIVE_DEPTH: f64 = 300.0;
+
+/// Temps d'ajustement vers la hauteur d'équilibre (s)
+pub(crate) const ADJUSTMENT_TIME: f64 = 6.0 * 3600.0;
+
+/// Durée d'un pas de temps (s)
+const STEP_DURATION: f64 = 3600.0;
+
+/// État de la couche limite
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundaryLayer {
+    depth: f64,  // Hauteur de la couche limite (m)
+    decay: f64,  // Usure cumulée du tourbillon par le frottement, sans dimension
+}
+
+impl BoundaryLayer {
+    /// Couche limite de hauteur initiale `depth` (m, 100 à 3000)
+    pub fn new(depth: f64) -> Result<Self, MeteoError> {
+        if !DEPTH_RANGE.contains(&depth) {
+            return Err(MeteoError::InvalidScenario(format!(
+                "hauteur de couche limite {} m hors de [{}, {}]",
+                depth,
+                DEPTH_RANGE.start(),
+                DEPTH_RANGE.end()
+            )));
+        }
+        Ok(Self { depth, decay: 0.0 })
+    }
+
+    /// Hauteur de la couche limite (m)
+    pub fn depth(&self) -> f64 {
+        self.depth
+    }
+
+    /// Facteur du forçage thermique de surface, 1000/h
+    pub fn flux_scale(&self) -> f64 {
+        REFERENCE_DEPTH / self.depth
+    }
+
+    /// Facteur du taux de pompage d'Ekman, h/1000
+    pub fn pumping_scale(&self) -> f64 {
+        self.depth / REFERENCE_DEPTH
+    }
+
+    /// Usure du tourbillon par le frottement cumulée jusqu'au pas courant
+    pub fn friction_factor(&self) -> f64 {
+        (-self.decay).exp()
+    }
+
+    /// Hauteur d'équilibre (m) pour un vent de surface `wind` (m/s), un
+    /// coefficient de traînée `drag`, un paramètre de Coriolis `coriolis` (s⁻¹)
+    /// et un forçage thermique de surface `flux` (K)
+    pub fn equilibrium_depth(wind: f64, drag: f64, coriolis: f64, flux: f64) -> f64 {
+        let friction_velocity = drag.sqrt() * wind.abs();
+        let mechanical = if coriolis == 0.0 {
+            *DEPTH_RANGE.end()
+        } else {
+            MECHANICAL_COEFFICIENT * friction_velocity / coriolis.abs()
+        };
+        (mechanical + CONVECTIVE_DEPTH * flux.max(0.0)).clamp(*DEPTH_RANGE.start(), *DEPTH_RANGE.end())
+    }
+
+    /// Avance d'un pas : ajustement vers la hauteur `equilibrium` (m), en
+    /// sous-pas `dt` (s) du schéma `scheme`, et cumul de l'usure au taux
+    /// `spin_down_rate` (s⁻¹) du pas
+    fn advance(&mut self, equilibrium: f64, spin_down_rate: f64, scheme: IntegrationScheme, dt: f64) {
+        self.depth = scheme.relax(self.depth, equilibrium, ADJUSTMENT_TIME, dt);
+        self.decay += spin_down_rate * STEP_DURATION;
+    }
+}
+
+impl<T: Float> BaroclinicCyclogenesis<T> {
+    /// Fait évoluer une couche limite de hauteur initiale `depth` (m)
+    pub fn with_boundary_layer(mut self, depth: f64) -> Result<Self, MeteoError> {
+        self.boundary_layer = Some(BoundaryLayer::new(depth)?);
+        Ok(self)
+    }
+
+    /// État de la couche limite, `None` pour une hauteur implicite constante
+    pub fn boundary_layer(&self) -> Option<BoundaryLayer> {
+        self.boundary_layer
+    }
+
+    /// Facteur du taux de pompage d'Ekman, 1 sans couche limite
+    pub(crate) fn pumping_scale(&self) -> f64 {
+        self.boundary_layer.map_or(1.0, |layer| layer.pumping_scale())
+    }
+
+    /// Avance la couche limite d'un pas, pour le vent (m/s), le forçage
+    /// thermique (K) et le taux d'usure (s⁻¹) de l'anomalie de surface
+    pub(crate) fn advance_boundary_layer(&mut self, wind: f64, flux: f64, spin_down_rate: f64) {
+        let drag = self.drag_law().map_or(OCEAN_DRAG, |law| law.drag_coefficient(wind));
+        let coriolis = self.coriolis_parameter(self.latitude());
+        let (scheme, dt) = (self.scheme(), self.time_step());
+        if let Some(layer) = &mut self.boundary_layer {
+            layer.advance(BoundaryLayer::equilibrium_depth(wind, drag, coriolis, flux), spin_down_rate, scheme, dt);
+        }
+    }
+}
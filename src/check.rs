@@ -0,0 +1,291 @@
+//! Vérification d'un scénario sans le simuler
+//!
+//! La construction de la simulation contrôle les plages de chaque paramètre ; la
+//! vérification y ajoute la cohérence physique de la configuration (zone
+//! barocline, inclinaison, échelle des anomalies), le critère d'instabilité de
+//! Charney–Stern et la limite de stabilité du pas de temps. La
+//! simulation à blanc ([`DryRun`]) complète la vérification par les paramètres
+//! dérivés et une estimation de la durée du calcul.
+
+use std::time::{Duration, Instant};
+
+use crate::integration::StabilityLimit;
+use crate::io::sounding::standard_height;
+use crate::plausibility::PlausibilityWarning;
+use crate::regime::DynamicalRegime;
+use crate::stability::CharneySternCheck;
+use crate::validation::Clamp;
+use crate::{MeteoError, NumberFormat, OutputLanguage, Scenario, StepPolicy};
+
+/// Constat de la vérification d'un scénario
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Finding {
+    /// Régime dynamique de la configuration initiale
+    Regime(DynamicalRegime),
+    /// Critère de Charney–Stern, satisfait ou non
+    CharneyStern(CharneySternCheck),
+    /// Pas de temps au-delà de la limite de stabilité d'un terme intégré
+    UnstableTimeStep(StabilityLimit, StepPolicy),
+    /// Simulation sans pas de temps
+    NoTimeSteps,
+    /// Équateur : pas d'équilibre du vent thermique
+    Equator,
+    /// Gradient méridien nul : aucune interaction barocline
+    NoBaroclinicZone,
+    /// Anomalie d'altitude décalée vers l'est : le couplage affaiblit la perturbation
+    EastwardTilt,
+    /// Anomalies plus courtes que la coupure d'Eady : aucune croissance
+    BelowEadyCutoff,
+    /// Valeur hors plage ramenée à sa borne par la politique souple
+    Clamped(Clamp),
+    /// Configuration valide mais physiquement douteuse
+    Implausible(PlausibilityWarning),
+}
+
+impl Finding {
+    /// Le constat signale un problème plutôt qu'une information
+    pub fn is_warning(&self) -> bool {
+        match self {
+            Finding::Regime(_) => false,
+            Finding::CharneyStern(check) => !check.is_satisfied(),
+            _ => true,
+        }
+    }
+
+    /// Compte rendu d'une ligne du constat
+    pub fn describe(&self, language: OutputLanguage, format: &NumberFormat) -> String {
+        match (language, self) {
+            (_, Finding::Regime(regime)) => regime.describe(language, format),
+            (_, Finding::CharneyStern(check)) => check.describe(language, format),
+            (_, Finding::UnstableTimeStep(limit, policy)) => limit.describe(*policy, language, format),
+            (_, Finding::Clamped(clamp)) => clamp.describe(language, format),
+            (_, Finding::Implausible(warning)) => warning.describe(language, format),
+            (OutputLanguage::French, Finding::NoTimeSteps) => "Attention : durée nulle, aucun pas de temps simulé".to_string(),
+            (OutputLanguage::French, Finding::Equator) => {
+                "Attention : à l'équateur, le vent thermique n'existe pas et les anomalies ne se développent pas".to_string()
+            }
+            (OutputLanguage::French, Finding::NoBaroclinicZone) => {
+                "Attention : gradient méridien nul, les anomalies n'interagissent pas".to_string()
+            }
+            (OutputLanguage::French, Finding::EastwardTilt) => {
+                "Attention : anomalie d'altitude décalée vers l'est, le couplage affaiblit la perturbation".to_string()
+            }
+            (OutputLanguage::French, Finding::BelowEadyCutoff) => {
+                "Attention : anomalies plus courtes que la coupure d'Eady (0,42 L_R), aucune croissance".to_string()
+            }
+            (OutputLanguage::English, Finding::NoTimeSteps) => "Warning: zero duration, no time step simulated".to_string(),
+            (OutputLanguage::English, Finding::Equator) => {
+                "Warning: at the equator the thermal wind does not exist and the anomalies do not develop".to_string()
+            }
+            (OutputLanguage::English, Finding::NoBaroclinicZone) => {
+                "Warning: zero meridional gradient, the anomalies do not interact".to_string()
+            }
+            (OutputLanguage::English, Finding::EastwardTilt) => {
+                "Warning: upper anomaly shifted eastward, the coupling weakens the perturbation".to_string()
+            }
+            (OutputLanguage::English, Finding::BelowEadyCutoff) => {
+                "Warning: anomalies shorter than the Eady cutoff (0.42 L_R), no growth".to_string()
+            }
+        }
+    }
+}
+
+/// Résultat de la vérification d'un scénario
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScenarioCheck {
+    findings: Vec<Finding>,
+}
+
+impl ScenarioCheck {
+    /// Vérifie un scénario ; une valeur hors plage est renvoyée en erreur
+    pub fn run(scenario: &Scenario) -> Result<Self, MeteoError> {
+        let cyclogenesis = scenario.to_cyclogenesis()?;
+        let mut findings = Vec::new();
+
+        findings.extend(scenario.clamps().into_iter().map(Finding::Clamped));
+
+        if scenario.time_steps == 0 {
+            findings.push(Finding::NoTimeSteps);
+        }
+        if let Some(regime) = cyclogenesis.dynamical_regime() {
+            findings.push(Finding::Regime(regime));
+        }
+        match cyclogenesis.charney_stern() {
+            Some(check) => findings.push(Finding::CharneyStern(check)),
+            None => findings.push(Finding::Equator),
+        }
+        // L'équateur a son propre constat
+        let equator = findings.contains(&Finding::Equator);
+        findings.extend(
+            cyclogenesis
+                .plausibility_warnings()
+                .into_iter()
+                .filter(|warning| !(equator && matches!(warning, PlausibilityWarning::LowLatitude(_))))
+                .map(Finding::Implausible),
+        );
+        if cyclogenesis.meridional_gradient() == 0.0 {
+            findings.push(Finding::NoBaroclinicZone);
+        }
+        if cyclogenesis.coupling_efficiency(cyclogenesis.first_hour()) < 0.0 {
+            findings.push(Finding::EastwardTilt);
+        }
+        let selectivity =
+            cyclogenesis.surface_anomaly().scale_selectivity() + cyclogenesis.altitude_anomaly().scale_selectivity();
+        if selectivity == 0.0 {
+            findings.push(Finding::BelowEadyCutoff);
+        }
+        if let Some(limit) = cyclogenesis.stability_limit(scenario.time_steps).filter(|limit| !limit.is_stable()) {
+            findings.push(Finding::UnstableTimeStep(limit, cyclogenesis.step_policy()));
+        }
+
+        Ok(Self { findings })
+    }
+
+    /// Constats, informations comprises
+    pub fn findings(&self) -> &[Finding] {
+        &self.findings
+    }
+
+    /// Aucun constat ne signale de problème
+    pub fn is_clean(&self) -> bool {
+        !self.findings.iter().any(Finding::is_warning)
+    }
+
+    /// Compte rendu de la vérification, une ligne par constat suivie du verdict
+    pub fn describe(&self, language: OutputLanguage, format: &NumberFormat) -> String {
+        let warnings = self.findings.iter().filter(|finding| finding.is_warning()).count();
+        let mut report: String =
+            self.findings.iter().map(|finding| finding.describe(language, format) + "\n").collect();
+        report += &match (language, warnings) {
+            (OutputLanguage::French, 0) => "Scénario valide".to_string(),
+            (OutputLanguage::French, count) => format!("Scénario valide, {} avertissement(s)", count),
+            (OutputLanguage::English, 0) => "Valid scenario".to_string(),
+            (OutputLanguage::English, count) => format!("Valid scenario, {} warning(s)", count),
+        };
+        report + "\n"
+    }
+}
+
+/// Écart toléré entre l'altitude d'une anomalie et celle de son niveau de
+/// pression dans l'atmosphère standard (m)
+pub const HYDROSTATIC_TOLERANCE: f64 = 1000.0;
+
+/// Niveau d'une anomalie comparé à l'atmosphère standard
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnomalyLevel {
+    pub altitude: f64,           // Altitude de l'anomalie (m)
+    pub pressure: f64,           // Pression de l'anomalie (hPa)
+    pub standard_altitude: f64,  // Altitude standard de cette pression (m)
+}
+
+impl AnomalyLevel {
+    /// L'altitude et la pression sont compatibles à [`HYDROSTATIC_TOLERANCE`] près
+    pub fn is_hydrostatic(&self) -> bool {
+        (self.altitude - self.standard_altitude).abs() <= HYDROSTATIC_TOLERANCE
+    }
+}
+
+/// Simulation à blanc : vérification, paramètres dérivés et durée attendue,
+/// sans intégrer le scénario
+#[derive(Debug, Clone, PartialEq)]
+pub struct DryRun {
+    pub check: ScenarioCheck,
+    pub coriolis: f64,                    // Paramètre de Coriolis (s⁻¹)
+    pub thermal_wind_shear: Option<f64>,  // Cisaillement du vent thermique (m/s), `None` à l'équateur
+    pub levels: Vec<AnomalyLevel>,
+    pub time_step: f64,                   // Pas de temps effectif (s)
+    pub integration_steps: u64,           // Pas d'intégration sur toute la durée
+    pub estimated_runtime: Duration,
+}
+
+impl DryRun {
+    /// Vérifie un scénario et estime sa durée de calcul en chronométrant une
+    /// heure sur une simulation jetable ; une valeur hors plage est renvoyée en erreur
+    pub fn run(scenario: &Scenario) -> Result<Self, MeteoError> {
+        let check = ScenarioCheck::run(scenario)?;
+        let mut cyclogenesis = scenario.to_cyclogenesis()?;
+        cyclogenesis.apply_step_policy(scenario.time_steps);
+
+        let levels = cyclogenesis
+            .anomalies
+            .iter()
+            .map(|anomaly| AnomalyLevel {
+                altitude: anomaly.position.altitude,
+                pressure: anomaly.position.pressure,
+                standard_altitude: standard_height(anomaly.position.pressure),
+            })
+            .collect();
+        let integration_steps = u64::from(cyclogenesis.substeps()) * u64::from(scenario.time_steps);
+
+        let estimated_runtime = if scenario.time_steps == 0 {
+            Duration::ZERO
+        } else {
+            let start = Instant::now();
+            scenario.to_cyclogenesis()?.step(0)?;
+            start.elapsed() * scenario.time_steps
+        };
+
+        Ok(Self {
+            check,
+            coriolis: cyclogenesis.coriolis_parameter(cyclogenesis.initial_latitude),
+            thermal_wind_shear: cyclogenesis.thermal_wind_shear(),
+            levels,
+            time_step: cyclogenesis.time_step(),
+            integration_steps,
+            estimated_runtime,
+        })
+    }
+
+    /// Compte rendu : paramètres dérivés, durée attendue puis vérification
+    pub fn describe(&self, language: OutputLanguage, format: &NumberFormat) -> String {
+        let shear = self.thermal_wind_shear.map_or("∞".to_string(), |shear| format.format(shear));
+        let runtime = format.format(self.estimated_runtime.as_secs_f64() * 1000.0);
+        let mut report = match language {
+            OutputLanguage::French => format!(
+                "Paramètre de Coriolis f = {} 10⁻⁴ s⁻¹\nCisaillement du vent thermique = {} m/s\n",
+                format.format(self.coriolis * 1e4),
+                shear
+            ),
+            OutputLanguage::English => format!(
+                "Coriolis parameter f = {} 10⁻⁴ s⁻¹\nThermal wind shear = {} m/s\n",
+                format.format(self.coriolis * 1e4),
+                shear
+            ),
+        };
+        for level in &self.levels {
+            let (altitude, pressure, standard) =
+                (format.format(level.altitude), format.format(level.pressure), format.format(level.standard_altitude));
+            report += &match (language, level.is_hydrostatic()) {
+                (OutputLanguage::French, true) => {
+                    format!("Anomalie à {} m et {} hPa (altitude standard {} m)\n", altitude, pressure, standard)
+                }
+                (OutputLanguage::French, false) => format!(
+                    "Attention : anomalie à {} m et {} hPa, loin de l'altitude standard de {} m\n",
+                    altitude, pressure, standard
+                ),
+                (OutputLanguage::English, true) => {
+                    format!("Anomaly at {} m and {} hPa (standard altitude {} m)\n", altitude, pressure, standard)
+                }
+                (OutputLanguage::English, false) => format!(
+                    "Warning: anomaly at {} m and {} hPa, far from the standard altitude of {} m\n",
+                    altitude, pressure, standard
+                ),
+            };
+        }
+        report += &match language {
+            OutputLanguage::French => format!(
+                "Pas de temps {} s, {} pas d'intégration, durée estimée {} ms\n",
+                format.format(self.time_step),
+                self.integration_steps,
+                runtime
+            ),
+            OutputLanguage::English => format!(
+                "Time step {} s, {} integration steps, estimated runtime {} ms\n",
+                format.format(self.time_step),
+                self.integration_steps,
+                runtime
+            ),
+        };
+        report + &self.check.describe(language, format)
+    }
+}
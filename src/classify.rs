@@ -0,0 +1,257 @@
+//! Classement de la sévérité du système
+//!
+//! Un `Classifier` range chaque pas de temps dans une classe à partir du vent
+//! de surface et de la pression centrale équivalente. Trois classements sont
+//! fournis : l'échelle de Beaufort, des seuils de vent inspirés de ceux qui
+//! conduisent le Met Office à nommer une tempête, et des catégories de pression
+//! centrale. Les classes sont ordonnées ; certaines sont dites sévères.
+
+use std::str::FromStr;
+
+use crate::beaufort::Beaufort;
+use crate::{BaroclinicCyclogenesis, DevelopmentResult, Float, MeteoError, NumberFormat, OutputLanguage};
+
+/// État du système à une heure, tel que le voient les classements
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StormState {
+    pub hour: u32,
+    pub relative_vorticity: f64,  // Tourbillon relatif (s⁻¹)
+    pub surface_wind: f64,        // Vent maximal de la circulation de surface (m/s)
+    pub central_pressure: f64,    // Pression centrale équivalente (hPa)
+    pub pressure_deficit: f64,    // Écart de pression à l'environnement (hPa)
+}
+
+/// Classe attribuée à un pas de temps
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Class {
+    pub rank: u8,       // Rang dans le classement, croissant avec la sévérité
+    pub label: String,  // Libellé lisible
+    pub severe: bool,   // La classe décrit un système sévère
+}
+
+/// Classement d'un état du système
+pub trait Classifier {
+    /// Nom du classement
+    fn name(&self, language: OutputLanguage) -> &'static str;
+
+    /// Classe d'un état, `None` sous le premier seuil
+    fn classify(&self, state: &StormState, language: OutputLanguage) -> Option<Class>;
+
+    /// Premier état rangé dans une classe sévère
+    fn first_severe<'a>(&self, states: &'a [StormState]) -> Option<&'a StormState> {
+        states
+            .iter()
+            .find(|state| self.classify(state, OutputLanguage::English).is_some_and(|class| class.severe))
+    }
+}
+
+/// Échelle de Beaufort du vent de surface, sévère dès la force 10
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BeaufortClassifier;
+
+impl Classifier for BeaufortClassifier {
+    fn name(&self, language: OutputLanguage) -> &'static str {
+        match language {
+            OutputLanguage::French => "Échelle de Beaufort",
+            OutputLanguage::English => "Beaufort scale",
+        }
+    }
+
+    fn classify(&self, state: &StormState, language: OutputLanguage) -> Option<Class> {
+        let force = Beaufort::from_speed(state.surface_wind);
+        Some(Class { rank: force.force(), label: force.describe(language), severe: force.force() >= 10 })
+    }
+}
+
+/// Seuils de vent de surface d'une tempête et d'une tempête sévère
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindstormClassifier {
+    windstorm: f64,  // Vent minimal d'une tempête (m/s)
+    severe: f64,     // Vent minimal d'une tempête sévère (m/s)
+}
+
+impl Default for WindstormClassifier {
+    /// Fort coup de vent (force 9) et violente tempête (force 11)
+    fn default() -> Self {
+        Self { windstorm: 20.8, severe: 28.5 }
+    }
+}
+
+impl WindstormClassifier {
+    /// Seuils en m/s, celui de la tempête sévère au-dessus de celui de la tempête
+    pub fn new(windstorm: f64, severe: f64) -> Result<Self, MeteoError> {
+        if !(windstorm > 0.0 && severe > windstorm) {
+            return Err(MeteoError::InvalidScenario(format!(
+                "seuils de tempête invalides: {} m/s et {} m/s",
+                windstorm, severe
+            )));
+        }
+        Ok(Self { windstorm, severe })
+    }
+}
+
+impl Classifier for WindstormClassifier {
+    fn name(&self, language: OutputLanguage) -> &'static str {
+        match language {
+            OutputLanguage::French => "Seuils de tempête",
+            OutputLanguage::English => "Windstorm thresholds",
+        }
+    }
+
+    fn classify(&self, state: &StormState, language: OutputLanguage) -> Option<Class> {
+        let (rank, french, english) = if state.surface_wind >= self.severe {
+            (2, "Tempête sévère", "Severe windstorm")
+        } else if state.surface_wind >= self.windstorm {
+            (1, "Tempête", "Windstorm")
+        } else {
+            return None;
+        };
+        let label = match language {
+            OutputLanguage::French => french,
+            OutputLanguage::English => english,
+        };
+        Some(Class { rank, label: label.to_string(), severe: rank == 2 })
+    }
+}
+
+/// Catégories de pression centrale, sévères sous 960 hPa
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PressureClassifier;
+
+/// Bornes supérieures des catégories de pression centrale (hPa), par sévérité croissante
+const PRESSURE_CATEGORIES: [(f64, &str, &str); 4] = [
+    (1000.0, "Dépression modérée", "Moderate low"),
+    (980.0, "Dépression creuse", "Deep low"),
+    (960.0, "Dépression très creuse", "Very deep low"),
+    (940.0, "Dépression exceptionnelle", "Exceptional low"),
+];
+
+impl Classifier for PressureClassifier {
+    fn name(&self, language: OutputLanguage) -> &'static str {
+        match language {
+            OutputLanguage::French => "Pression centrale",
+            OutputLanguage::English => "Central pressure",
+        }
+    }
+
+    fn classify(&self, state: &StormState, language: OutputLanguage) -> Option<Class> {
+        let rank = PRESSURE_CATEGORIES.iter().take_while(|(bound, _, _)| state.central_pressure < *bound).count();
+        let (_, french, english) = PRESSURE_CATEGORIES.get(rank.checked_sub(1)?)?;
+        let label = match language {
+            OutputLanguage::French => french,
+            OutputLanguage::English => english,
+        };
+        Some(Class { rank: rank as u8, label: label.to_string(), severe: rank >= 3 })
+    }
+}
+
+/// Classements fournis, choisis par leur nom
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BuiltinClassifier {
+    Beaufort,
+    #[default]
+    Windstorm,
+    Pressure,
+}
+
+impl BuiltinClassifier {
+    /// Classement aux seuils par défaut
+    pub fn classifier(self) -> Box<dyn Classifier> {
+        match self {
+            BuiltinClassifier::Beaufort => Box::new(BeaufortClassifier),
+            BuiltinClassifier::Windstorm => Box::new(WindstormClassifier::default()),
+            BuiltinClassifier::Pressure => Box::new(PressureClassifier),
+        }
+    }
+}
+
+impl FromStr for BuiltinClassifier {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "beaufort" => Ok(BuiltinClassifier::Beaufort),
+            "windstorm" => Ok(BuiltinClassifier::Windstorm),
+            "pressure" => Ok(BuiltinClassifier::Pressure),
+            _ => Err(format!("Classement inconnu: {} (beaufort, windstorm, pressure)", value)),
+        }
+    }
+}
+
+impl<T: Float> BaroclinicCyclogenesis<T> {
+    /// État du système à l'heure d'un résultat
+    pub fn storm_state(&self, result: &DevelopmentResult<T>) -> StormState {
+        StormState {
+            hour: result.hour,
+            relative_vorticity: result.relative_vorticity.to_f64(),
+            surface_wind: self.surface_wind(result),
+            central_pressure: self.central_pressure(result),
+            pressure_deficit: self.pressure_deficit(result),
+        }
+    }
+
+    /// État du système à chaque heure d'une simulation
+    pub fn storm_states(&self, results: &[DevelopmentResult<T>]) -> Vec<StormState> {
+        results.iter().map(|result| self.storm_state(result)).collect()
+    }
+}
+
+/// Phrase annonçant la première heure où le système atteint une classe sévère
+pub fn severity_statement(classifier: &dyn Classifier, states: &[StormState], language: OutputLanguage) -> String {
+    let first = classifier
+        .first_severe(states)
+        .and_then(|state| Some((state.hour, classifier.classify(state, language)?)));
+    match (language, first) {
+        (OutputLanguage::French, Some((hour, class))) => {
+            format!("Le système devient « {} » à l'heure {}", class.label, hour)
+        }
+        (OutputLanguage::English, Some((hour, class))) => {
+            format!("The system becomes \"{}\" at hour {}", class.label, hour)
+        }
+        (OutputLanguage::French, None) => {
+            format!("Le système n'atteint aucune classe sévère ({})", classifier.name(language).to_lowercase())
+        }
+        (OutputLanguage::English, None) => {
+            format!("The system reaches no severe class ({})", classifier.name(language).to_lowercase())
+        }
+    }
+}
+
+/// Tableau du vent, de la pression et de la classe à chaque heure, suivi de la
+/// phrase de sévérité
+pub fn to_table(
+    classifier: &dyn Classifier,
+    states: &[StormState],
+    language: OutputLanguage,
+    format: &NumberFormat,
+) -> String {
+    let unit = format.wind_unit().symbol();
+    let header = match language {
+        OutputLanguage::French => format!(
+            "Heure | {:<20} | Pression (hPa)       | {}",
+            format!("Vent ({})", unit),
+            classifier.name(language)
+        ),
+        OutputLanguage::English => format!(
+            "Hour  | {:<20} | Pressure (hPa)       | {}",
+            format!("Wind ({})", unit),
+            classifier.name(language)
+        ),
+    };
+
+    let mut table = format!(
+        "{}\n------|----------------------|----------------------|----------------------\n",
+        header
+    );
+    for state in states {
+        let class = classifier.classify(state, language).map(|class| class.label).unwrap_or_else(|| "—".to_string());
+        table += &format!(
+            "{:4} | {} | {} | {}\n",
+            state.hour,
+            format.format_wind_width(state.surface_wind, 20),
+            format.format_width(state.central_pressure, 20),
+            class
+        );
+    }
+    table + &format!("\n{}\n", severity_statement(classifier, states, language))
+}
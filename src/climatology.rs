@@ -0,0 +1,199 @@
+//! État de fond tiré d'une climatologie zonale
+//!
+//! Une climatologie donne la température moyenne sur un cercle de latitude à
+//! chaque niveau de pression. À la latitude de la simulation, elle fixe l'état
+//! de fond au lieu d'une température de référence unique :
+//!
+//! - la température de référence est celle du niveau le plus bas ;
+//! - le gradient méridien est la moyenne, du niveau le plus bas à 500 hPa, de
+//!   la décroissance de la température vers le pôle (K/1000 km) ;
+//! - la tropopause est le premier niveau au-dessus de 500 hPa où le gradient
+//!   vertical de température passe sous 2 K/km (définition de l'OMM), les
+//!   altitudes étant intégrées par la relation hypsométrique ; à défaut, la
+//!   tropopause de référence est conservée.
+//!
+//! Entre les points de la table, la température est interpolée linéairement en
+//! latitude et en logarithme de la pression ; au-delà, la valeur du bord est
+//! reprise. Une table se lit en CSV ([`crate::io::climatology`]) ou en NetCDF-3
+//! avec la fonctionnalité `netcdf`.
+
+use std::cmp::Ordering;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::diagnostics::DRY_AIR_GAS_CONSTANT;
+use crate::io::sounding::standard_height;
+use crate::season::BackgroundState;
+use crate::{BaroclinicCyclogenesis, Float, MeteoError, TROPOSPHERE_DEPTH};
+
+/// Longueur d'un degré de latitude (km)
+const DEGREE_LENGTH: f64 = 111.195;
+
+/// Gradient vertical sous lequel commence la tropopause (K/m)
+const TROPOPAUSE_LAPSE_RATE: f64 = 2.0e-3;
+
+/// Table zonale de température
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Climatology {
+    pub latitudes: Vec<f64>,         // Latitudes croissantes (°)
+    pub pressures: Vec<f64>,         // Niveaux de pression décroissants (hPa)
+    pub temperature: Vec<Vec<f64>>,  // Température (K), une ligne par latitude et une colonne par niveau
+}
+
+impl Climatology {
+    /// Lit une table CSV, ou NetCDF-3 (extension `.nc`) avec la fonctionnalité `netcdf`
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, MeteoError> {
+        let path = path.as_ref();
+        log_event!(info, path = %path.display(), "lecture de la climatologie");
+        if path.extension().is_some_and(|extension| extension == "nc") {
+            #[cfg(feature = "netcdf")]
+            return crate::io::era5::read_climatology(path);
+            #[cfg(not(feature = "netcdf"))]
+            return Err(MeteoError::InvalidClimatology(format!(
+                "{}: la lecture NetCDF nécessite la fonctionnalité `netcdf`",
+                path.display()
+            )));
+        }
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| MeteoError::InvalidClimatology(format!("{}: {}", path.display(), e)))?;
+        crate::io::climatology::parse_csv(&text)
+    }
+
+    /// Table rangée par latitude croissante et niveau décroissant, quel que soit
+    /// l'ordre des coordonnées ; `temperature[j][k]` est la température à la
+    /// latitude `latitudes[j]` et au niveau `pressures[k]`
+    pub fn from_table(latitudes: Vec<f64>, pressures: Vec<f64>, temperature: Vec<Vec<f64>>) -> Result<Self, MeteoError> {
+        let climatology = Self { latitudes, pressures, temperature };
+        climatology.validate_shape()?;
+        let mut rows: Vec<_> = (0..climatology.latitudes.len()).collect();
+        rows.sort_by(|a, b| climatology.latitudes[*a].total_cmp(&climatology.latitudes[*b]));
+        let mut columns: Vec<_> = (0..climatology.pressures.len()).collect();
+        columns.sort_by(|a, b| climatology.pressures[*b].total_cmp(&climatology.pressures[*a]));
+
+        let sorted = Self {
+            latitudes: rows.iter().map(|j| climatology.latitudes[*j]).collect(),
+            pressures: columns.iter().map(|k| climatology.pressures[*k]).collect(),
+            temperature: rows.iter().map(|j| columns.iter().map(|k| climatology.temperature[*j][*k]).collect()).collect(),
+        };
+        sorted.validate()?;
+        Ok(sorted)
+    }
+
+    /// Vérifie la forme de la table et ses valeurs
+    pub fn validate(&self) -> Result<(), MeteoError> {
+        self.validate_shape()?;
+        let invalid = |message: String| Err(MeteoError::InvalidClimatology(message));
+        let increasing = self.latitudes.windows(2).all(|pair| pair[0].partial_cmp(&pair[1]) == Some(Ordering::Less));
+        if !increasing || !self.latitudes.iter().all(|lat| (-90.0..=90.0).contains(lat)) {
+            return invalid(format!("latitudes non croissantes ou hors de [-90, 90]: {:?}", self.latitudes));
+        }
+        let decreasing = self.pressures.windows(2).all(|pair| pair[0].partial_cmp(&pair[1]) == Some(Ordering::Greater));
+        if !decreasing || !self.pressures.iter().all(|p| *p > 0.0 && p.is_finite()) {
+            return invalid(format!("niveaux non décroissants ou négatifs: {:?}", self.pressures));
+        }
+        if let Some(value) = self.temperature.iter().flatten().find(|t| !(**t > 0.0 && t.is_finite())) {
+            return invalid(format!("température invalide: {} K", value));
+        }
+        Ok(())
+    }
+
+    /// Vérifie les dimensions de la table
+    fn validate_shape(&self) -> Result<(), MeteoError> {
+        if self.latitudes.is_empty() || self.pressures.len() < 2 {
+            return Err(MeteoError::InvalidClimatology(format!(
+                "{} latitude(s) et {} niveau(x), au moins une latitude et deux niveaux attendus",
+                self.latitudes.len(),
+                self.pressures.len()
+            )));
+        }
+        if self.temperature.len() != self.latitudes.len() || self.temperature.iter().any(|row| row.len() != self.pressures.len()) {
+            return Err(MeteoError::InvalidClimatology(format!(
+                "la table de température doit être de taille {}×{}",
+                self.latitudes.len(),
+                self.pressures.len()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Température (K) à une latitude (°) et une pression (hPa)
+    pub fn temperature_at(&self, latitude: f64, pressure: f64) -> f64 {
+        let (lower, upper, weight) = bracket(&self.latitudes, latitude);
+        let profile = |row: &[f64]| {
+            let levels: Vec<_> = self.pressures.iter().map(|p| -p.ln()).collect();
+            let (below, above, weight) = bracket(&levels, -pressure.ln());
+            row[below] + weight * (row[above] - row[below])
+        };
+        let (south, north) = (profile(&self.temperature[lower]), profile(&self.temperature[upper]));
+        south + weight * (north - south)
+    }
+
+    /// État de fond à une latitude (°)
+    pub fn background(&self, latitude: f64) -> Result<BackgroundState, MeteoError> {
+        self.validate()?;
+        let profile: Vec<_> = self.pressures.iter().map(|p| self.temperature_at(latitude, *p)).collect();
+
+        // Décroissance de la température vers le pôle, moyennée sous 500 hPa
+        let poleward = if latitude >= 0.0 { 1.0 } else { -1.0 };
+        let gradients: Vec<_> = self
+            .pressures
+            .iter()
+            .filter(|&&p| p >= 500.0)
+            .map(|&p| {
+                let contrast = self.temperature_at(latitude - poleward, p) - self.temperature_at(latitude + poleward, p);
+                contrast / (2.0 * DEGREE_LENGTH) * 1000.0
+            })
+            .collect();
+        let meridional_gradient = if gradients.is_empty() {
+            0.0
+        } else {
+            (gradients.iter().sum::<f64>() / gradients.len() as f64).max(0.0)
+        };
+
+        Ok(BackgroundState {
+            base_temp: profile[0],
+            meridional_gradient,
+            tropopause_height: tropopause_height(&self.pressures, &profile).unwrap_or(TROPOSPHERE_DEPTH),
+        })
+    }
+}
+
+/// Altitude de la tropopause d'un profil, `None` si le gradient vertical ne
+/// passe jamais sous le seuil au-dessus de 500 hPa
+fn tropopause_height(pressures: &[f64], temperatures: &[f64]) -> Option<f64> {
+    let gravity = 9.81;
+    let mut height = standard_height(pressures[0]);
+    for (pressure, temperature) in pressures.windows(2).zip(temperatures.windows(2)) {
+        let thickness = DRY_AIR_GAS_CONSTANT * (temperature[0] + temperature[1]) / (2.0 * gravity) * (pressure[0] / pressure[1]).ln();
+        let lapse_rate = (temperature[0] - temperature[1]) / thickness;
+        if pressure[0] <= 500.0 && lapse_rate < TROPOPAUSE_LAPSE_RATE {
+            return Some(height);
+        }
+        height += thickness;
+    }
+    None
+}
+
+/// Indices encadrant `value` dans des coordonnées croissantes et poids du
+/// second ; la valeur du bord est reprise au-delà
+fn bracket(coordinates: &[f64], value: f64) -> (usize, usize, f64) {
+    let last = coordinates.len() - 1;
+    match coordinates.iter().position(|coordinate| *coordinate > value) {
+        Some(0) => (0, 0, 0.0),
+        None => (last, last, 0.0),
+        Some(upper) => {
+            let lower = upper - 1;
+            (lower, upper, (value - coordinates[lower]) / (coordinates[upper] - coordinates[lower]))
+        }
+    }
+}
+
+impl<T: Float> BaroclinicCyclogenesis<T> {
+    /// Place les anomalies dans l'état de fond de la climatologie à leur latitude
+    pub fn with_climatology(self, climatology: &Climatology) -> Result<Self, MeteoError> {
+        let background = climatology.background(self.initial_latitude)?;
+        self.with_background(background)
+    }
+}
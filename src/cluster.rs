@@ -0,0 +1,260 @@
+//! Regroupement des membres d'un ensemble en scénarios représentatifs
+//!
+//! Chaque membre est décrit par sa trajectoire heure par heure : pression
+//! centrale, tourbillon relatif et, quand la simulation suit le déplacement
+//! du système, latitude et longitude du centre. Chaque grandeur est réduite
+//! par son écart type dans l'ensemble, puis les membres sont regroupés par
+//! k-moyennes (algorithme de Lloyd). Les centres initiaux sont choisis sans
+//! tirage, de proche en proche : le membre le plus proche de la moyenne, puis
+//! chaque fois le membre le plus éloigné des centres déjà retenus, ce qui rend
+//! le regroupement reproductible.
+//!
+//! Un groupe est résumé par sa trajectoire moyenne (centroïde) et par son
+//! membre représentatif, le plus proche du centroïde.
+
+use crate::ensemble::Ensemble;
+use crate::{Float, MeteoError, NumberFormat, OutputLanguage};
+
+/// Nombre maximal d'itérations de l'algorithme de Lloyd
+const MAX_ITERATIONS: usize = 100;
+
+/// Trajectoire moyenne d'un groupe à une heure
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CentroidStep {
+    pub hour: u32,
+    pub central_pressure: f64,    // Pression centrale (hPa)
+    pub relative_vorticity: f64,  // Tourbillon relatif (s⁻¹)
+    pub latitude: Option<f64>,    // Centre du système (°), si la simulation le suit
+    pub longitude: Option<f64>,
+}
+
+/// Groupe de membres
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cluster {
+    pub members: Vec<usize>,         // Indices des membres dans l'ensemble
+    pub representative: usize,       // Membre le plus proche du centroïde
+    pub centroid: Vec<CentroidStep>,
+}
+
+impl Cluster {
+    /// Heure et valeur de la pression centrale la plus basse du centroïde
+    pub fn lowest_pressure(&self) -> Option<(u32, f64)> {
+        self.centroid
+            .iter()
+            .map(|step| (step.hour, step.central_pressure))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+    }
+}
+
+/// Membres d'un ensemble regroupés en scénarios représentatifs
+#[derive(Debug, Clone)]
+pub struct Clustering {
+    clusters: Vec<Cluster>,
+}
+
+impl Clustering {
+    /// Regroupe les membres en `count` groupes au plus, autant que de membres,
+    /// classés par effectif décroissant ; des membres identiques peuvent
+    /// laisser des groupes vides, écartés
+    pub fn compute<T: Float>(ensemble: &Ensemble<T>, count: usize) -> Result<Self, MeteoError> {
+        let members = ensemble.members();
+        if count == 0 || count > members.len() {
+            return Err(MeteoError::InvalidScenario(format!(
+                "nombre de groupes invalide: {} pour {} membres",
+                count,
+                members.len()
+            )));
+        }
+        let hours = ensemble.hours();
+        if members.iter().any(|member| member.states.len() != hours.len()) {
+            return Err(MeteoError::InvalidScenario("membres de durées différentes".to_string()));
+        }
+        let tracked = members.iter().all(|member| member.results.iter().all(|result| result.latitude().is_some()));
+
+        // Trajectoire de chaque membre : pression, tourbillon et position à chaque heure
+        let trajectories: Vec<Vec<f64>> = members
+            .iter()
+            .map(|member| {
+                member
+                    .states
+                    .iter()
+                    .zip(&member.results)
+                    .flat_map(|(state, result)| {
+                        let mut features = vec![state.central_pressure, state.relative_vorticity];
+                        if tracked {
+                            features.push(result.latitude().unwrap_or_default());
+                            features.push(result.longitude().unwrap_or_default());
+                        }
+                        features
+                    })
+                    .collect()
+            })
+            .collect();
+        let features = standardize(&trajectories);
+        let assignment = k_means(&features, count);
+
+        let width = if tracked { 4 } else { 2 };
+        let mut clusters: Vec<_> = (0..count)
+            .map(|cluster| (0..members.len()).filter(|member| assignment[*member] == cluster).collect::<Vec<_>>())
+            .filter(|indices| !indices.is_empty())
+            .map(|indices| {
+                let mean = centroid(&trajectories, &indices);
+                let scaled = centroid(&features, &indices);
+                let representative = *indices
+                    .iter()
+                    .min_by(|a, b| distance(&features[**a], &scaled).total_cmp(&distance(&features[**b], &scaled)))
+                    .unwrap_or(&0);
+                let centroid = hours
+                    .iter()
+                    .zip(mean.chunks(width))
+                    .map(|(hour, values)| CentroidStep {
+                        hour: *hour,
+                        central_pressure: values[0],
+                        relative_vorticity: values[1],
+                        latitude: tracked.then(|| values[2]),
+                        longitude: tracked.then(|| values[3]),
+                    })
+                    .collect();
+                Cluster { members: indices, representative, centroid }
+            })
+            .collect();
+        clusters.sort_by(|a, b| b.members.len().cmp(&a.members.len()).then(a.members.cmp(&b.members)));
+        Ok(Self { clusters })
+    }
+
+    pub fn clusters(&self) -> &[Cluster] {
+        &self.clusters
+    }
+
+    /// Effectif et trajectoire de chaque groupe, puis tableau de la pression
+    /// centrale moyenne de chaque groupe
+    pub fn to_table(&self, language: OutputLanguage, format: &NumberFormat) -> String {
+        let mut table = String::new();
+        for (index, cluster) in self.clusters.iter().enumerate() {
+            table += &describe_cluster(index + 1, cluster, language, format);
+            table += "\n";
+        }
+
+        let mut header = match language {
+            OutputLanguage::French => "\nHeure".to_string(),
+            OutputLanguage::English => "\nHour ".to_string(),
+        };
+        let mut rule = "------".to_string();
+        for index in 1..=self.clusters.len() {
+            let label = match language {
+                OutputLanguage::French => format!("Groupe {} (hPa)", index),
+                OutputLanguage::English => format!("Cluster {} (hPa)", index),
+            };
+            header += &format!(" | {:<20}", label);
+            rule += "|----------------------";
+        }
+        table += &format!("{}\n{}\n", header.trim_end(), rule);
+        let steps = self.clusters.first().map_or(0, |cluster| cluster.centroid.len());
+        for step in 0..steps {
+            table += &format!("{:4}", self.clusters[0].centroid[step].hour);
+            for cluster in &self.clusters {
+                table += &format!(" | {}", format.format_width(cluster.centroid[step].central_pressure, 20));
+            }
+            table += "\n";
+        }
+        table
+    }
+}
+
+/// Phrase résumant un groupe : effectif, représentant, creusement et position finale
+fn describe_cluster(number: usize, cluster: &Cluster, language: OutputLanguage, format: &NumberFormat) -> String {
+    let count = cluster.members.len();
+    let (hour, pressure) = cluster.lowest_pressure().unwrap_or_default();
+    let pressure = format.format(pressure);
+    let mut text = match language {
+        OutputLanguage::French => format!(
+            "Groupe {} : {} membre(s), représentant n° {}, pression minimale {} hPa à l'heure {}",
+            number, count, cluster.representative, pressure, hour
+        ),
+        OutputLanguage::English => format!(
+            "Cluster {}: {} member(s), representative #{}, lowest pressure {} hPa at hour {}",
+            number, count, cluster.representative, pressure, hour
+        ),
+    };
+    if let Some(CentroidStep { latitude: Some(latitude), longitude: Some(longitude), .. }) = cluster.centroid.last() {
+        let (latitude, longitude) = (format.format(*latitude), format.format(*longitude));
+        text += &match language {
+            OutputLanguage::French => format!(", centre final {}° N {}° E", latitude, longitude),
+            OutputLanguage::English => format!(", final centre {}° N {}° E", latitude, longitude),
+        };
+    }
+    text
+}
+
+/// Réduit chaque composante par son écart type dans l'ensemble, centrée sur sa moyenne
+fn standardize(trajectories: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    let count = trajectories.len() as f64;
+    let width = trajectories.first().map_or(0, Vec::len);
+    let scales: Vec<_> = (0..width)
+        .map(|feature| {
+            let mean = trajectories.iter().map(|trajectory| trajectory[feature]).sum::<f64>() / count;
+            let variance =
+                trajectories.iter().map(|trajectory| (trajectory[feature] - mean).powi(2)).sum::<f64>() / count;
+            (mean, if variance > 0.0 { variance.sqrt() } else { 1.0 })
+        })
+        .collect();
+    trajectories
+        .iter()
+        .map(|trajectory| trajectory.iter().zip(&scales).map(|(value, (mean, scale))| (value - mean) / scale).collect())
+        .collect()
+}
+
+/// Groupe de chaque point par k-moyennes, depuis des centres choisis de proche en proche
+fn k_means(points: &[Vec<f64>], count: usize) -> Vec<usize> {
+    let all: Vec<_> = (0..points.len()).collect();
+    let mean = centroid(points, &all);
+    let nearest = |center: &[f64]| {
+        (0..points.len()).min_by(|a, b| distance(&points[*a], center).total_cmp(&distance(&points[*b], center)))
+    };
+    let mut centers = vec![points[nearest(&mean).unwrap_or(0)].clone()];
+    while centers.len() < count {
+        let farthest = (0..points.len())
+            .max_by(|a, b| {
+                let gap = |point: usize| centers.iter().map(|center| distance(&points[point], center)).fold(f64::INFINITY, f64::min);
+                gap(*a).total_cmp(&gap(*b)).then(b.cmp(a))
+            })
+            .unwrap_or(0);
+        centers.push(points[farthest].clone());
+    }
+
+    let mut assignment = vec![usize::MAX; points.len()];
+    for _ in 0..MAX_ITERATIONS {
+        let next: Vec<_> = points
+            .iter()
+            .map(|point| {
+                (0..count)
+                    .min_by(|a, b| distance(point, &centers[*a]).total_cmp(&distance(point, &centers[*b])))
+                    .unwrap_or(0)
+            })
+            .collect();
+        if next == assignment {
+            break;
+        }
+        assignment = next;
+        for (cluster, center) in centers.iter_mut().enumerate() {
+            let indices: Vec<_> = (0..points.len()).filter(|point| assignment[*point] == cluster).collect();
+            // Un groupe vidé garde son centre
+            if !indices.is_empty() {
+                *center = centroid(points, &indices);
+            }
+        }
+    }
+    assignment
+}
+
+/// Moyenne des points d'indices donnés
+fn centroid(points: &[Vec<f64>], indices: &[usize]) -> Vec<f64> {
+    let width = points.first().map_or(0, Vec::len);
+    let count = indices.len().max(1) as f64;
+    (0..width).map(|feature| indices.iter().map(|index| points[*index][feature]).sum::<f64>() / count).collect()
+}
+
+/// Carré de la distance euclidienne
+fn distance(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| (a - b).powi(2)).sum()
+}
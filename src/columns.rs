@@ -0,0 +1,242 @@
+//! Stockage en colonnes des résultats d'une simulation
+//!
+//! Chaque grandeur d'un [`DevelopmentResult`] est rangée dans son propre
+//! vecteur contigu (structure de tableaux) : les parcours d'une seule grandeur
+//! restent en cache et les colonnes se transmettent sans copie à Arrow, Polars
+//! ou NumPy. Une ligne se lit sans copie par une vue ([`ResultRow`]), un
+//! résultat se reconstruit à la demande, et les conversions depuis et vers
+//! `Vec<DevelopmentResult>` gardent l'interface par lignes.
+
+use chrono::{DateTime, Utc};
+
+use crate::{BaroclinicCyclogenesis, DevelopmentResult, Float, MeteoError};
+
+/// Résultats d'une simulation, une colonne par grandeur
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResultColumns<T: Float = f64> {
+    hours: Vec<u32>,
+    vertical_velocity: Vec<T>,           // m/s
+    relative_vorticity: Vec<T>,          // s⁻¹
+    low_level_divergence: Vec<T>,        // s⁻¹
+    upper_level_divergence: Vec<T>,      // s⁻¹
+    valid_times: Vec<Option<DateTime<Utc>>>,
+    latitudes: Vec<Option<f64>>,         // °
+    longitudes: Vec<Option<f64>>,        // °
+}
+
+impl<T: Float> Default for ResultColumns<T> {
+    fn default() -> Self {
+        Self::with_capacity(0)
+    }
+}
+
+impl<T: Float> ResultColumns<T> {
+    /// Colonnes vides, prévues pour `capacity` heures
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            hours: Vec::with_capacity(capacity),
+            vertical_velocity: Vec::with_capacity(capacity),
+            relative_vorticity: Vec::with_capacity(capacity),
+            low_level_divergence: Vec::with_capacity(capacity),
+            upper_level_divergence: Vec::with_capacity(capacity),
+            valid_times: Vec::with_capacity(capacity),
+            latitudes: Vec::with_capacity(capacity),
+            longitudes: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Ajoute un résultat à la fin des colonnes
+    pub fn push(&mut self, result: &DevelopmentResult<T>) {
+        self.hours.push(result.hour);
+        self.vertical_velocity.push(result.vertical_velocity);
+        self.relative_vorticity.push(result.relative_vorticity);
+        self.low_level_divergence.push(result.low_level_divergence);
+        self.upper_level_divergence.push(result.upper_level_divergence);
+        self.valid_times.push(result.valid_time);
+        self.latitudes.push(result.latitude);
+        self.longitudes.push(result.longitude);
+    }
+
+    pub fn len(&self) -> usize {
+        self.hours.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.hours.is_empty()
+    }
+
+    /// Vue sur la ligne d'une position, `None` au-delà de la fin
+    pub fn row(&self, index: usize) -> Option<ResultRow<'_, T>> {
+        (index < self.len()).then_some(ResultRow { columns: self, index })
+    }
+
+    /// Vues sur les lignes, dans l'ordre des heures
+    pub fn rows(&self) -> impl Iterator<Item = ResultRow<'_, T>> + '_ {
+        (0..self.len()).map(|index| ResultRow { columns: self, index })
+    }
+
+    /// Résultat reconstruit à une position, `None` au-delà de la fin
+    pub fn get(&self, index: usize) -> Option<DevelopmentResult<T>> {
+        self.row(index).map(|row| row.to_result())
+    }
+
+    /// Résultats reconstruits, dans l'ordre des heures
+    pub fn iter(&self) -> impl Iterator<Item = DevelopmentResult<T>> + '_ {
+        self.rows().map(|row| row.to_result())
+    }
+
+    /// Heures depuis le départ de la simulation
+    pub fn hours(&self) -> &[u32] {
+        &self.hours
+    }
+
+    /// Vitesse verticale (m/s)
+    pub fn vertical_velocity(&self) -> &[T] {
+        &self.vertical_velocity
+    }
+
+    /// Tourbillon relatif (s⁻¹)
+    pub fn relative_vorticity(&self) -> &[T] {
+        &self.relative_vorticity
+    }
+
+    /// Divergence dans les basses couches (s⁻¹)
+    pub fn low_level_divergence(&self) -> &[T] {
+        &self.low_level_divergence
+    }
+
+    /// Divergence en haute troposphère (s⁻¹)
+    pub fn upper_level_divergence(&self) -> &[T] {
+        &self.upper_level_divergence
+    }
+
+    /// Dates de validité, connues si la simulation a une date de départ
+    pub fn valid_times(&self) -> &[Option<DateTime<Utc>>] {
+        &self.valid_times
+    }
+
+    /// Latitudes du système (°)
+    pub fn latitudes(&self) -> &[Option<f64>] {
+        &self.latitudes
+    }
+
+    /// Longitudes du système (°)
+    pub fn longitudes(&self) -> &[Option<f64>] {
+        &self.longitudes
+    }
+}
+
+/// Ligne des colonnes de résultats, lue sans copie
+#[derive(Debug, Clone, Copy)]
+pub struct ResultRow<'a, T: Float = f64> {
+    columns: &'a ResultColumns<T>,
+    index: usize,
+}
+
+impl<T: Float> ResultRow<'_, T> {
+    /// Position de la ligne dans les colonnes
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Heure depuis le départ de la simulation
+    pub fn hour(&self) -> u32 {
+        self.columns.hours[self.index]
+    }
+
+    /// Vitesse verticale (m/s), positive pour une ascendance
+    pub fn vertical_velocity(&self) -> T {
+        self.columns.vertical_velocity[self.index]
+    }
+
+    /// Tourbillon relatif (s⁻¹)
+    pub fn relative_vorticity(&self) -> T {
+        self.columns.relative_vorticity[self.index]
+    }
+
+    /// Divergence dans les basses couches (s⁻¹), négative en cas de convergence
+    pub fn low_level_divergence(&self) -> T {
+        self.columns.low_level_divergence[self.index]
+    }
+
+    /// Divergence en haute troposphère (s⁻¹)
+    pub fn upper_level_divergence(&self) -> T {
+        self.columns.upper_level_divergence[self.index]
+    }
+
+    /// Date de validité, si la simulation a une date de départ
+    pub fn valid_time(&self) -> Option<DateTime<Utc>> {
+        self.columns.valid_times[self.index]
+    }
+
+    /// Latitude du système (°), connue lorsque la géométrie le fait se déplacer
+    pub fn latitude(&self) -> Option<f64> {
+        self.columns.latitudes[self.index]
+    }
+
+    /// Longitude du système (°), connue en géométrie sphérique
+    pub fn longitude(&self) -> Option<f64> {
+        self.columns.longitudes[self.index]
+    }
+
+    /// Résultat reconstruit à partir de la ligne
+    pub fn to_result(&self) -> DevelopmentResult<T> {
+        DevelopmentResult {
+            hour: self.hour(),
+            vertical_velocity: self.vertical_velocity(),
+            relative_vorticity: self.relative_vorticity(),
+            low_level_divergence: self.low_level_divergence(),
+            upper_level_divergence: self.upper_level_divergence(),
+            valid_time: self.valid_time(),
+            latitude: self.latitude(),
+            longitude: self.longitude(),
+        }
+    }
+}
+
+impl<T: Float> PartialEq<DevelopmentResult<T>> for ResultRow<'_, T> {
+    fn eq(&self, result: &DevelopmentResult<T>) -> bool {
+        self.to_result() == *result
+    }
+}
+
+impl<T: Float> FromIterator<DevelopmentResult<T>> for ResultColumns<T> {
+    fn from_iter<I: IntoIterator<Item = DevelopmentResult<T>>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut columns = Self::with_capacity(iter.size_hint().0);
+        for result in iter {
+            columns.push(&result);
+        }
+        columns
+    }
+}
+
+impl<T: Float> From<&[DevelopmentResult<T>]> for ResultColumns<T> {
+    fn from(results: &[DevelopmentResult<T>]) -> Self {
+        let mut columns = Self::with_capacity(results.len());
+        for result in results {
+            columns.push(result);
+        }
+        columns
+    }
+}
+
+impl<T: Float> From<Vec<DevelopmentResult<T>>> for ResultColumns<T> {
+    fn from(results: Vec<DevelopmentResult<T>>) -> Self {
+        Self::from(results.as_slice())
+    }
+}
+
+impl<T: Float> From<ResultColumns<T>> for Vec<DevelopmentResult<T>> {
+    fn from(columns: ResultColumns<T>) -> Self {
+        columns.iter().collect()
+    }
+}
+
+impl<T: Float> BaroclinicCyclogenesis<T> {
+    /// Simule l'interaction comme [`BaroclinicCyclogenesis::simulate_interaction`],
+    /// en rangeant les résultats en colonnes
+    pub fn simulate_columns(&mut self, time_steps: u32) -> Result<ResultColumns<T>, MeteoError> {
+        self.hours(time_steps)?.map(|hour| self.step(hour)).collect()
+    }
+}
@@ -0,0 +1,167 @@
+//! Démon de simulation à l'écoute d'une socket Unix
+//!
+//! Le processus reste chargé et sert autant de clients que voulu, chacun dans
+//! son propre fil, ce qui évite le coût d'un lancement par simulation. Une
+//! connexion échange des trames : leur longueur en octets (`u32`
+//! petit-boutiste) suivie d'autant d'octets de JSON. Une requête est un scénario
+//! JSON ; la réponse est `{"results":[...]}` ou `{"error":"..."}`. Un client
+//! enchaîne autant de requêtes qu'il veut sur la même connexion et la ferme
+//! quand il a fini. Des résultats trop longs pour une trame donnent une erreur
+//! et la connexion reste ouverte. Les compteurs du démon peuvent être exposés à Prometheus
+//! par [`crate::server::serve_metrics`].
+
+use std::io::{ErrorKind, Read, Write};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::Path;
+use std::sync::Arc;
+use std::thread;
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+
+use crate::metrics::Metrics;
+use crate::{DevelopmentResult, MeteoError, OutputLanguage, Scenario};
+
+/// Taille maximale d'une trame (octets) ; une trame plus longue ferme la connexion
+pub const MAX_FRAME_SIZE: usize = 16 << 20;
+
+/// Réponse à une requête
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Response {
+    Results(Vec<DevelopmentResult>),
+    Error(String),
+}
+
+/// Lit une trame ; `None` si la connexion est fermée entre deux trames
+pub fn read_frame<R: Read>(source: &mut R) -> Result<Option<Vec<u8>>, MeteoError> {
+    let mut length = [0; 4];
+    let mut filled = 0;
+    while filled < length.len() {
+        match source.read(&mut length[filled..]) {
+            Ok(0) if filled == 0 => return Ok(None),
+            Ok(0) => return Err(protocol("longueur de trame tronquée")),
+            Ok(read) => filled += read,
+            Err(error) if error.kind() == ErrorKind::Interrupted => {}
+            Err(error) => return Err(socket_error(error)),
+        }
+    }
+
+    let length = u32::from_le_bytes(length) as usize;
+    if length > MAX_FRAME_SIZE {
+        return Err(protocol(&format!("trame de {} octets (au plus {})", length, MAX_FRAME_SIZE)));
+    }
+    let mut payload = vec![0; length];
+    source.read_exact(&mut payload).map_err(|_| protocol("trame tronquée"))?;
+    Ok(Some(payload))
+}
+
+/// Écrit une trame
+pub fn write_frame<W: Write>(sink: &mut W, payload: &[u8]) -> Result<(), MeteoError> {
+    if payload.len() > MAX_FRAME_SIZE {
+        return Err(protocol(&format!("trame de {} octets (au plus {})", payload.len(), MAX_FRAME_SIZE)));
+    }
+    sink.write_all(&(payload.len() as u32).to_le_bytes())
+        .and_then(|_| sink.write_all(payload))
+        .and_then(|_| sink.flush())
+        .map_err(socket_error)
+}
+
+/// Réponse du démon à une requête
+pub fn respond(request: &[u8], language: OutputLanguage) -> Response {
+    answer(request, language).1
+}
+
+/// Réponse à une requête, avec le nom du scénario reçu
+fn answer(request: &[u8], language: OutputLanguage) -> (Option<String>, Response) {
+    let scenario = match serde_json::from_slice::<Scenario>(request) {
+        Ok(scenario) => scenario,
+        Err(e) => return (None, Response::Error(MeteoError::InvalidScenario(e.to_string()).message(language))),
+    };
+    match scenario.run() {
+        Ok(results) => (scenario.name, Response::Results(results)),
+        Err(error) => (scenario.name, Response::Error(error.message(language))),
+    }
+}
+
+/// Trame d'une réponse ; des résultats trop longs pour une trame sont remplacés
+/// par une erreur, le client restant connecté
+pub fn encode(response: &Response, language: OutputLanguage) -> Vec<u8> {
+    let payload = serde_json::to_vec(response).expect("réponse sérialisable");
+    if payload.len() <= MAX_FRAME_SIZE {
+        return payload;
+    }
+    let error = protocol(&format!("réponse de {} octets (au plus {})", payload.len(), MAX_FRAME_SIZE));
+    serde_json::to_vec(&Response::Error(error.message(language))).expect("réponse sérialisable")
+}
+
+/// Ouvre la socket ; un fichier laissé par un démon arrêté est remplacé, une
+/// socket encore servie est refusée
+pub fn bind<P: AsRef<Path>>(path: P) -> Result<UnixListener, MeteoError> {
+    let path = path.as_ref();
+    if path.exists() {
+        if UnixStream::connect(path).is_ok() {
+            return Err(MeteoError::InvalidRun(format!("{}: un démon écoute déjà", path.display())));
+        }
+        std::fs::remove_file(path).map_err(|e| MeteoError::InvalidRun(format!("{}: {}", path.display(), e)))?;
+    }
+    UnixListener::bind(path).map_err(|e| MeteoError::InvalidRun(format!("{}: {}", path.display(), e)))
+}
+
+/// Sert les clients de la socket, sans fin
+pub fn serve(listener: UnixListener, language: OutputLanguage, metrics: Arc<Metrics>) -> ! {
+    loop {
+        // Une connexion refusée par le système ne concerne que son client
+        if let Ok((stream, _)) = listener.accept() {
+            let metrics = Arc::clone(&metrics);
+            thread::spawn(move || handle(stream, language, &metrics));
+        }
+    }
+}
+
+/// Répond aux requêtes d'un client jusqu'à la fermeture de sa connexion
+fn handle(mut stream: UnixStream, language: OutputLanguage, metrics: &Metrics) {
+    log_event!(debug, "client connecté");
+    loop {
+        let request = match read_frame(&mut stream) {
+            Ok(Some(request)) => request,
+            Ok(None) => break,
+            Err(error) => {
+                // La trame suivante est introuvable : le client est prévenu et déconnecté
+                let _ = write_frame(&mut stream, &encode(&Response::Error(error.message(language)), language));
+                break;
+            }
+        };
+        let start = Instant::now();
+        let (name, response) = answer(&request, language);
+        let payload = encode(&response, language);
+        match &response {
+            Response::Results(results) if payload.len() <= MAX_FRAME_SIZE => {
+                metrics.record_scenario(name.as_deref(), results.len() as u32, start.elapsed())
+            }
+            _ => metrics.record_error(),
+        }
+        if write_frame(&mut stream, &payload).is_err() {
+            break;
+        }
+    }
+    log_event!(debug, "client déconnecté");
+}
+
+/// Côté client : envoie un scénario et attend ses résultats
+pub fn request<S: Read + Write>(stream: &mut S, scenario: &Scenario) -> Result<Vec<DevelopmentResult>, MeteoError> {
+    write_frame(stream, &serde_json::to_vec(scenario).expect("scénario sérialisable"))?;
+    let payload = read_frame(stream)?.ok_or_else(|| protocol("connexion fermée par le démon"))?;
+    match serde_json::from_slice(&payload).map_err(|e| protocol(&e.to_string()))? {
+        Response::Results(results) => Ok(results),
+        Response::Error(message) => Err(MeteoError::InvalidRun(message)),
+    }
+}
+
+fn protocol(message: &str) -> MeteoError {
+    MeteoError::InvalidRun(format!("protocole du démon: {}", message))
+}
+
+fn socket_error(error: std::io::Error) -> MeteoError {
+    MeteoError::InvalidRun(format!("socket: {}", error))
+}
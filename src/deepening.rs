@@ -0,0 +1,118 @@
+//! Creusement de la pression centrale en hPa par 24 h
+//!
+//! La pression centrale équivalente (voir [`crate::pressure`]) est comparée à
+//! celle des 24 heures précédentes. Tant que la simulation n'a pas encore duré
+//! 24 heures, la fenêtre se réduit au temps écoulé et la baisse est ramenée à
+//! 24 h. Le creusement est positif quand la pression baisse ; rapporté à
+//! sin φ / sin 60°, il s'exprime en bergerons (Sanders et Gyakum, 1980) et le
+//! creusement est explosif à partir d'un bergeron.
+
+use crate::{BaroclinicCyclogenesis, DevelopmentResult, Float, NumberFormat, OutputLanguage};
+
+/// Fenêtre glissante du creusement (h)
+pub const DEEPENING_WINDOW: u32 = 24;
+
+/// Creusement sur la fenêtre glissante se terminant à une heure de la simulation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DeepeningRate {
+    pub hour: u32,
+    pub central_pressure: f64,  // Pression centrale (hPa)
+    pub window: u32,            // Durée de la fenêtre (h), inférieure à 24 h en début de simulation
+    pub rate: f64,              // Baisse de pression ramenée à 24 h (hPa/24 h)
+    pub bergeron: f64,          // Creusement rapporté à sin φ / sin 60°, nul à l'équateur
+}
+
+impl DeepeningRate {
+    /// Creusement explosif, d'au moins un bergeron
+    pub fn is_explosive(&self) -> bool {
+        self.bergeron >= 1.0
+    }
+}
+
+impl<T: Float> BaroclinicCyclogenesis<T> {
+    /// Creusement sur 24 heures glissantes, à partir de la deuxième heure
+    pub fn deepening_rates(&self, results: &[DevelopmentResult<T>]) -> Vec<DeepeningRate> {
+        let pressures: Vec<_> = results.iter().map(|result| self.central_pressure(result)).collect();
+        results
+            .iter()
+            .enumerate()
+            .skip(1)
+            .filter_map(|(index, result)| {
+                // Premier pas de la fenêtre : au plus 24 h avant
+                let start = results[..index]
+                    .iter()
+                    .position(|start| result.hour - start.hour <= DEEPENING_WINDOW)?;
+                let window = result.hour - results[start].hour;
+                if window == 0 {
+                    return None;
+                }
+                let rate = (pressures[start] - pressures[index]) * f64::from(DEEPENING_WINDOW) / f64::from(window);
+
+                let latitude = result.latitude.unwrap_or_else(|| self.latitude());
+                let sine = latitude.to_radians().sin().abs();
+                let bergeron = if sine == 0.0 { 0.0 } else { rate / f64::from(DEEPENING_WINDOW) * 60f64.to_radians().sin() / sine };
+                Some(DeepeningRate { hour: result.hour, central_pressure: pressures[index], window, rate, bergeron })
+            })
+            .collect()
+    }
+}
+
+/// Phrase signalant le creusement le plus fort
+pub fn describe_peak(rates: &[DeepeningRate], language: OutputLanguage, format: &NumberFormat) -> String {
+    let Some(peak) = rates.iter().max_by(|a, b| a.rate.total_cmp(&b.rate)) else {
+        return match language {
+            OutputLanguage::French => "Creusement sur 24 h indisponible".to_string(),
+            OutputLanguage::English => "24 h deepening unavailable".to_string(),
+        };
+    };
+    let (rate, bergeron) = (format.format(peak.rate), format.format(peak.bergeron));
+    match (language, peak.is_explosive()) {
+        (OutputLanguage::French, false) => {
+            format!("Creusement maximal : {} hPa/24 h ({} bergeron) à l'heure {}", rate, bergeron, peak.hour)
+        }
+        (OutputLanguage::French, true) => {
+            format!("Creusement maximal : {} hPa/24 h ({} bergeron, explosif) à l'heure {}", rate, bergeron, peak.hour)
+        }
+        (OutputLanguage::English, false) => {
+            format!("Peak deepening: {} hPa/24 h ({} Bergeron) at hour {}", rate, bergeron, peak.hour)
+        }
+        (OutputLanguage::English, true) => {
+            format!("Peak deepening: {} hPa/24 h ({} Bergeron, explosive) at hour {}", rate, bergeron, peak.hour)
+        }
+    }
+}
+
+/// Tableau de la pression centrale et du creusement sur 24 h, suivi du creusement maximal
+pub fn to_table(rates: &[DeepeningRate], language: OutputLanguage, format: &NumberFormat) -> String {
+    let (header, partial) = match language {
+        OutputLanguage::French => (
+            "Heure | Pression (hPa)       | Creusement (hPa/24h) | Creusement Bergeron  | Fenêtre",
+            "ramené à 24 h",
+        ),
+        OutputLanguage::English => (
+            "Hour  | Pressure (hPa)       | Deepening (hPa/24h)  | Deepening (Bergeron) | Window",
+            "scaled to 24 h",
+        ),
+    };
+
+    let mut table = format!(
+        "{}\n------|----------------------|----------------------|----------------------|----------------------\n",
+        header
+    );
+    for rate in rates {
+        let window = if rate.window < DEEPENING_WINDOW {
+            format!("{} h ({})", rate.window, partial)
+        } else {
+            format!("{} h", rate.window)
+        };
+        table += &format!(
+            "{:4} | {} | {} | {} | {}\n",
+            rate.hour,
+            format.format_width(rate.central_pressure, 20),
+            format.format_width(rate.rate, 20),
+            format.format_width(rate.bergeron, 20),
+            window
+        );
+    }
+    table + &format!("\n{}\n", describe_peak(rates, language, format))
+}
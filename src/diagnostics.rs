@@ -0,0 +1,275 @@
+//! Diagnostics physiques calculés à chaque pas de temps
+//!
+//! Le cisaillement vertical est celui du vent thermique des anomalies, amplifié
+//! par leur intensité au fil du développement. La stabilité statique suit le
+//! gradient thermique standard, modifié par le contraste vertical des anomalies.
+//! Le vent agéostrophique est celui qu'impose la tendance non équilibrée du vent
+//! tourbillonnaire ζ·R : |V_ag| = R·|∂ζ/∂t| / |f|.
+//!
+//! Au niveau complet ([`DiagnosticsLevel::Full`]), chaque pas relève aussi la
+//! chaîne de calcul de chaque anomalie : terme de Coriolis, forçage, vent
+//! thermique, facteurs de pression et d'altitude, intensité et facteur
+//! d'interaction, de quoi vérifier le calcul pas à pas.
+
+use std::str::FromStr;
+
+use crate::energetics::EnergyBudget;
+use crate::phase::PHASE_LOCK_RATE;
+use crate::qvector::QVector;
+use crate::vorticity::VorticityBudget;
+use crate::{BaroclinicCyclogenesis, DevelopmentResult, Float, MeteoError, NumberFormat, OutputLanguage, PhysicalConstants};
+
+/// Nombre de Richardson critique sous lequel l'écoulement devient turbulent
+pub const CRITICAL_RICHARDSON: f64 = 0.25;
+
+/// Gradient thermique vertical standard et adiabatique sèche (K/m)
+pub(crate) const STANDARD_LAPSE_RATE: f64 = 0.0065;
+pub(crate) const DRY_LAPSE_RATE: f64 = 9.81 / 1004.0;
+
+/// Constante des gaz parfaits de l'air sec (J kg⁻¹ K⁻¹)
+pub(crate) const DRY_AIR_GAS_CONSTANT: f64 = 287.05;
+
+/// Stabilité statique N² de l'état de base (s⁻²), N² = g/T₀ · (Γd − Γ)
+pub(crate) fn background_stability(constants: &PhysicalConstants) -> f64 {
+    constants.gravity / constants.base_temp * (DRY_LAPSE_RATE - STANDARD_LAPSE_RATE)
+}
+
+/// Détail des diagnostics relevés à chaque pas
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DiagnosticsLevel {
+    /// Diagnostics de la couche et bilans
+    #[default]
+    Standard,
+    /// Diagnostics standard et chaîne de calcul de chaque anomalie
+    Full,
+}
+
+impl FromStr for DiagnosticsLevel {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "standard" => Ok(DiagnosticsLevel::Standard),
+            "full" => Ok(DiagnosticsLevel::Full),
+            _ => Err(format!("Niveau de diagnostics inconnu: {} (standard, full)", value)),
+        }
+    }
+}
+
+/// Grandeurs intermédiaires du calcul d'une anomalie à un pas de temps
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnomalyDiagnostics {
+    pub pressure: f64,            // Niveau de l'anomalie (hPa)
+    pub coriolis: f64,            // Terme de Coriolis Ω·sin φ du modèle (s⁻¹)
+    pub forcing: f64,             // Chauffage diurne, flux de surface, train d'ondes ou filament (K)
+    pub thermal_wind: f64,        // Vent thermique de l'anomalie, signé selon sa nature
+    pub pressure_factor: f64,     // √(1000/p) de la vitesse verticale
+    pub altitude_factor: f64,     // exp(−z/8000) de la vitesse verticale, amorti au-dessus de la tropopause
+    pub intensity: f64,           // Intensité de l'anomalie
+    pub vertical_velocity: f64,   // Contribution à la vitesse verticale avant interaction (m/s)
+    pub relative_vorticity: f64,  // Contribution au tourbillon relatif avant frottement et interaction (s⁻¹)
+    pub friction_factor: f64,     // Usure du tourbillon par le frottement de surface, 1 sans surface
+    pub interaction_factor: f64,  // Renforcement par le couplage avec les autres anomalies
+}
+
+/// Diagnostics d'un pas de temps
+#[derive(Debug, Clone, PartialEq)]
+pub struct StepDiagnostics {
+    pub hour: u32,
+    pub shear: Option<f64>,       // Cisaillement du vent entre les niveaux (m/s), indéfini à l'équateur
+    pub richardson: Option<f64>,  // Nombre de Richardson global de la couche
+    pub energy: EnergyBudget,     // Cycle énergétique de Lorenz
+    pub vorticity: VorticityBudget,  // Contributions à la tendance du tourbillon
+    pub q_vector: QVector,        // Forçage quasi géostrophique de l'ascendance
+    pub phase: f64,               // Déphasage entre les anomalies (rad)
+    pub phase_tendency: Option<f64>,  // Évolution du déphasage (rad/s)
+    pub ageostrophic_wind: Option<f64>,  // Vent agéostrophique (m/s), indéfini à l'équateur
+    pub boundary_layer_depth: Option<f64>,  // Hauteur de la couche limite en fin de pas (m), `None` sans couche limite évolutive
+    pub anomalies: Vec<AnomalyDiagnostics>,  // Chaîne de calcul, vide sauf au niveau complet
+}
+
+impl StepDiagnostics {
+    /// Nombre de Richardson sous la valeur critique de 0,25
+    pub fn is_shear_unstable(&self) -> bool {
+        self.richardson.is_some_and(|richardson| richardson < CRITICAL_RICHARDSON)
+    }
+
+    /// Déphasage quasi stationnaire, à moins de 1°/h
+    pub fn is_phase_locked(&self) -> bool {
+        self.phase_tendency.is_some_and(|tendency| tendency.abs() < PHASE_LOCK_RATE)
+    }
+}
+
+impl<T: Float> BaroclinicCyclogenesis<T> {
+    /// Choisit le détail des diagnostics, standard par défaut
+    pub fn with_diagnostics_level(mut self, level: DiagnosticsLevel) -> Self {
+        self.diagnostics_level = level;
+        self
+    }
+
+    /// Détail des diagnostics retenu
+    pub fn diagnostics_level(&self) -> DiagnosticsLevel {
+        self.diagnostics_level
+    }
+
+    /// Chaîne de calcul de chaque anomalie au dernier pas, vide sauf au niveau complet
+    pub fn anomaly_diagnostics(&self) -> &[AnomalyDiagnostics] {
+        &self.anomaly_diagnostics
+    }
+}
+
+impl BaroclinicCyclogenesis {
+    /// Diagnostics de l'état courant, à appeler avec le résultat du dernier pas et
+    /// le déphasage courant des anomalies (rad)
+    pub fn diagnose(&self, result: &DevelopmentResult, phase: f64) -> StepDiagnostics {
+        let surface = self.surface_anomaly();
+        let altitude = self.altitude_anomaly();
+        let intensity = (surface.intensity + altitude.intensity) / 2.0;
+        let shear = self.thermal_wind_shear().map(|shear| shear * intensity);
+
+        // N² = g/T₀ · (Γd − Γ), le contraste des anomalies modifiant Γ dans la couche
+        let depth = (altitude.position.altitude - surface.position.altitude).abs();
+        let richardson = shear.filter(|_| depth > 0.0).map(|shear| {
+            let lapse_rate = STANDARD_LAPSE_RATE + (surface.temperature_delta - altitude.temperature_delta) / depth;
+            let brunt_vaisala = surface.constants.gravity / surface.constants.base_temp * (DRY_LAPSE_RATE - lapse_rate);
+            brunt_vaisala * depth * depth / (shear * shear)
+        });
+
+        let vorticity = self.vorticity_budget(result);
+        let coriolis = self.coriolis_parameter(self.latitude());
+        let ageostrophic_wind = (coriolis != 0.0)
+            .then(|| self.mean_radius() * vorticity.tendency().abs() / coriolis.abs());
+
+        StepDiagnostics {
+            hour: result.hour,
+            shear,
+            richardson,
+            energy: self.energy_budget(result),
+            vorticity,
+            q_vector: self.q_vector(result),
+            phase,
+            phase_tendency: shear.map(|shear| self.phase_tendency(phase, shear)),
+            ageostrophic_wind,
+            boundary_layer_depth: self.boundary_layer.map(|layer| layer.depth()),
+            anomalies: self.anomaly_diagnostics.clone(),
+        }
+    }
+
+    /// Simule l'interaction et relève les diagnostics de chaque pas
+    ///
+    /// La politique de pas est appliquée avant l'intégration.
+    pub fn simulate_with_diagnostics(
+        &mut self,
+        time_steps: u32,
+    ) -> Result<(Vec<DevelopmentResult>, Vec<StepDiagnostics>), MeteoError> {
+        self.apply_step_policy(time_steps);
+        let mut results = Vec::with_capacity(time_steps as usize);
+        let mut diagnostics = Vec::with_capacity(time_steps as usize);
+        let mut phase = self.initial_phase();
+        for hour in self.hours(time_steps)? {
+            let result = self.step(hour)?;
+            let step = self.diagnose(&result, phase);
+            if let Some(shear) = step.shear {
+                phase = self.advance_phase(phase, shear);
+            }
+            diagnostics.push(step);
+            results.push(result);
+        }
+        Ok((results, diagnostics))
+    }
+}
+
+/// Tableau des diagnostics, signalant les pas où Ri < 0,25 et le verrouillage de phase
+pub fn to_table(diagnostics: &[StepDiagnostics], language: OutputLanguage, format: &NumberFormat) -> String {
+    let unit = format.wind_unit().symbol();
+    let (header, shear_flag, lock_flag) = match language {
+        OutputLanguage::French => (
+            format!(
+                "Heure | {:<20} | Richardson           | Déphasage (°)        | {:<20} |",
+                format!("Cisaillement ({})", unit),
+                format!("Agéostrophique ({})", unit)
+            ),
+            "Ri < 0,25",
+            "phase verrouillée",
+        ),
+        OutputLanguage::English => (
+            format!(
+                "Hour  | {:<20} | Richardson           | Phase (°)            | {:<20} |",
+                format!("Shear ({})", unit),
+                format!("Ageostrophic ({})", unit)
+            ),
+            "Ri < 0.25",
+            "phase locked",
+        ),
+    };
+    let cell = |value: Option<f64>| match value {
+        Some(value) => format.format_width(value, 20),
+        None => format!("{:>20}", "—"),
+    };
+    let wind = |value: Option<f64>| cell(value.map(|value| format.wind_unit().express(value)));
+
+    let mut table = format!(
+        "{}\n------|----------------------|----------------------|----------------------|----------------------|\n",
+        header
+    );
+    for step in diagnostics {
+        let flags: Vec<_> = [(step.is_shear_unstable(), shear_flag), (step.is_phase_locked(), lock_flag)]
+            .into_iter()
+            .filter_map(|(raised, flag)| raised.then_some(flag))
+            .collect();
+        let line = format!(
+            "{:4} | {} | {} | {} | {} | {}",
+            step.hour,
+            wind(step.shear),
+            cell(step.richardson),
+            format.format_width(step.phase.to_degrees(), 20),
+            wind(step.ageostrophic_wind),
+            flags.join(", ")
+        );
+        table += line.trim_end();
+        table.push('\n');
+    }
+
+    if let Some(locked) = diagnostics.iter().find(|step| step.is_phase_locked()) {
+        let phase = format.format(locked.phase.to_degrees());
+        table += &match language {
+            OutputLanguage::French => format!("\nVerrouillage de phase à l'heure {} (déphasage {}°)\n", locked.hour, phase),
+            OutputLanguage::English => format!("\nPhase locking at hour {} (phase {}°)\n", locked.hour, phase),
+        };
+    }
+    table
+}
+
+/// Tableau de la chaîne de calcul, une ligne par anomalie et par pas
+pub fn to_full_table(diagnostics: &[StepDiagnostics], language: OutputLanguage, format: &NumberFormat) -> String {
+    let header = match language {
+        OutputLanguage::French => {
+            "Heure | Pression (hPa)       | Ω·sin φ (10⁻⁵ s⁻¹)   | Forçage (K)          | Vent thermique       | \
+             Facteur pression     | Facteur altitude     | Intensité            | Facteur interaction"
+        }
+        OutputLanguage::English => {
+            "Hour  | Pressure (hPa)       | Ω·sin φ (10⁻⁵ s⁻¹)   | Forcing (K)          | Thermal wind         | \
+             Pressure factor      | Altitude factor      | Intensity            | Interaction factor"
+        }
+    };
+    let mut table = format!("{}\n------{}\n", header, "|----------------------".repeat(8));
+    for step in diagnostics {
+        for anomaly in &step.anomalies {
+            let cells: Vec<_> = [
+                anomaly.pressure,
+                anomaly.coriolis * 1e5,
+                anomaly.forcing,
+                anomaly.thermal_wind,
+                anomaly.pressure_factor,
+                anomaly.altitude_factor,
+                anomaly.intensity,
+                anomaly.interaction_factor,
+            ]
+            .iter()
+            .map(|value| format.format_width(*value, 20))
+            .collect();
+            table += &format!("{:4} | {}\n", step.hour, cells.join(" | "));
+        }
+    }
+    table
+}
@@ -0,0 +1,156 @@
+//! Comparaison de deux simulations enregistrées en JSON ou au format binaire
+//!
+//! Les résultats sont alignés par heure. L'écart de chaque pas est celui de la
+//! seconde simulation par rapport à la première ; l'heure de divergence est la
+//! première où l'écart du tourbillon dépasse la tolérance relative demandée.
+
+use std::fs;
+use std::path::Path;
+
+use crate::io::binary;
+use crate::{DevelopmentResult, MeteoError, NumberFormat, OutputLanguage};
+
+/// Tolérance relative par défaut sur le tourbillon relatif
+pub const DEFAULT_TOLERANCE: f64 = 1.0e-6;
+
+/// Écart entre deux simulations à une heure commune
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StepDifference {
+    pub hour: u32,
+    pub vertical_velocity: f64,   // Écart de vitesse verticale (m/s)
+    pub relative_vorticity: f64,  // Écart de tourbillon relatif (s⁻¹)
+    pub reference_vorticity: f64, // Tourbillon relatif de la première simulation (s⁻¹)
+}
+
+/// Comparaison heure par heure de deux simulations
+#[derive(Debug, Clone)]
+pub struct RunDiff {
+    steps: Vec<StepDifference>,
+    only_first: Vec<u32>,   // Heures absentes de la seconde simulation
+    only_second: Vec<u32>,  // Heures absentes de la première simulation
+}
+
+/// Lit les résultats d'une simulation enregistrés en JSON ou au format binaire
+/// de [`crate::io::binary`], reconnu à sa signature
+pub fn load<P: AsRef<Path>>(path: P) -> Result<Vec<DevelopmentResult>, MeteoError> {
+    let path = path.as_ref();
+    let bytes = fs::read(path).map_err(|e| MeteoError::InvalidRun(format!("{}: {}", path.display(), e)))?;
+    if bytes.starts_with(&binary::MAGIC) {
+        return binary::read(bytes.as_slice()).map(|(_, results)| results).map_err(|error| match error {
+            MeteoError::InvalidBinary(message) => MeteoError::InvalidBinary(format!("{}: {}", path.display(), message)),
+            error => error,
+        });
+    }
+    serde_json::from_slice(&bytes).map_err(|e| MeteoError::InvalidRun(format!("{}: {}", path.display(), e)))
+}
+
+impl RunDiff {
+    /// Aligne deux simulations par heure et calcule leurs écarts
+    pub fn compute(first: &[DevelopmentResult], second: &[DevelopmentResult]) -> Self {
+        let steps = first
+            .iter()
+            .filter_map(|a| {
+                let b = second.iter().find(|b| b.hour == a.hour)?;
+                Some(StepDifference {
+                    hour: a.hour,
+                    vertical_velocity: b.vertical_velocity - a.vertical_velocity,
+                    relative_vorticity: b.relative_vorticity - a.relative_vorticity,
+                    reference_vorticity: a.relative_vorticity,
+                })
+            })
+            .collect();
+        let missing = |results: &[DevelopmentResult], others: &[DevelopmentResult]| {
+            results
+                .iter()
+                .filter(|a| !others.iter().any(|b| b.hour == a.hour))
+                .map(|a| a.hour)
+                .collect()
+        };
+
+        Self {
+            steps,
+            only_first: missing(first, second),
+            only_second: missing(second, first),
+        }
+    }
+
+    /// Écarts aux heures communes, dans l'ordre de la première simulation
+    pub fn steps(&self) -> &[StepDifference] {
+        &self.steps
+    }
+
+    /// Heures présentes dans une seule des deux simulations (première, seconde)
+    pub fn unmatched_hours(&self) -> (&[u32], &[u32]) {
+        (&self.only_first, &self.only_second)
+    }
+
+    /// Pas du plus grand écart de tourbillon relatif
+    pub fn max_vorticity_difference(&self) -> Option<&StepDifference> {
+        self.steps.iter().max_by(|a, b| a.relative_vorticity.abs().total_cmp(&b.relative_vorticity.abs()))
+    }
+
+    /// Pas du plus grand écart de vitesse verticale
+    pub fn max_vertical_velocity_difference(&self) -> Option<&StepDifference> {
+        self.steps.iter().max_by(|a, b| a.vertical_velocity.abs().total_cmp(&b.vertical_velocity.abs()))
+    }
+
+    /// Première heure où l'écart du tourbillon dépasse `tolerance` fois le
+    /// tourbillon de la première simulation
+    pub fn divergence_hour(&self, tolerance: f64) -> Option<u32> {
+        self.steps
+            .iter()
+            .find(|step| step.relative_vorticity.abs() > tolerance * step.reference_vorticity.abs())
+            .map(|step| step.hour)
+    }
+
+    /// Tableau des écarts par heure suivi du résumé
+    pub fn to_table(&self, tolerance: f64, language: OutputLanguage, format: &NumberFormat) -> String {
+        let header = match language {
+            OutputLanguage::French => "Heure | Δ Vitesse verticale (cm/s) | Δ Tourbillon relatif (10⁻⁵ s⁻¹)",
+            OutputLanguage::English => "Hour  | Δ Vertical velocity (cm/s) | Δ Relative vorticity (10⁻⁵ s⁻¹)",
+        };
+
+        let mut table = format!("{}\n{}\n", header, language.table_rule());
+        for step in &self.steps {
+            table += &format!(
+                "{:4} | {} | {}\n",
+                step.hour,
+                format.format_width(step.vertical_velocity * 100.0, 20),
+                format.format_width(step.relative_vorticity * 1e5, 20)
+            );
+        }
+
+        table += "\n";
+        if let Some(step) = self.max_vorticity_difference() {
+            let value = format.format(step.relative_vorticity.abs() * 1e5);
+            table += &match language {
+                OutputLanguage::French => format!("Écart maximal du tourbillon : {} 10⁻⁵ s⁻¹ à l'heure {}\n", value, step.hour),
+                OutputLanguage::English => format!("Maximum vorticity difference: {} 10⁻⁵ s⁻¹ at hour {}\n", value, step.hour),
+            };
+        }
+        if let Some(step) = self.max_vertical_velocity_difference() {
+            let value = format.format(step.vertical_velocity.abs() * 100.0);
+            table += &match language {
+                OutputLanguage::French => format!("Écart maximal de la vitesse verticale : {} cm/s à l'heure {}\n", value, step.hour),
+                OutputLanguage::English => format!("Maximum vertical velocity difference: {} cm/s at hour {}\n", value, step.hour),
+            };
+        }
+        table += &match (language, self.divergence_hour(tolerance)) {
+            (OutputLanguage::French, Some(hour)) => format!("Divergence à l'heure {} (tolérance relative {})\n", hour, tolerance),
+            (OutputLanguage::French, None) => format!("Aucune divergence (tolérance relative {})\n", tolerance),
+            (OutputLanguage::English, Some(hour)) => format!("Divergence at hour {} (relative tolerance {})\n", hour, tolerance),
+            (OutputLanguage::English, None) => format!("No divergence (relative tolerance {})\n", tolerance),
+        };
+        for (hours, run) in [(&self.only_first, 2), (&self.only_second, 1)] {
+            if hours.is_empty() {
+                continue;
+            }
+            let list: Vec<_> = hours.iter().map(u32::to_string).collect();
+            table += &match language {
+                OutputLanguage::French => format!("Heures absentes de la simulation {} : {}\n", run, list.join(", ")),
+                OutputLanguage::English => format!("Hours missing from run {}: {}\n", run, list.join(", ")),
+            };
+        }
+        table
+    }
+}
@@ -0,0 +1,112 @@
+//! Relation de dispersion : taux de croissance en fonction de la longueur d'onde
+//!
+//! Le scénario est simulé pour chaque longueur d'onde λ = 2π × rayon des
+//! anomalies. Le taux de croissance retenu est le taux d'amplification moyen du
+//! tourbillon relatif sur la durée de la simulation.
+
+use std::f64::consts::PI;
+
+use crate::{MeteoError, NumberFormat, OutputLanguage, Scenario};
+
+/// Secondes par jour, pour exprimer les taux de croissance
+const SECONDS_PER_DAY: f64 = 86400.0;
+
+/// Point de la courbe de dispersion
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DispersionPoint {
+    pub wavelength: f64,   // Longueur d'onde (m)
+    pub growth_rate: f64,  // Taux de croissance (s⁻¹)
+}
+
+/// Courbe de dispersion d'un scénario
+#[derive(Debug, Clone)]
+pub struct DispersionCurve {
+    points: Vec<DispersionPoint>,
+}
+
+impl DispersionCurve {
+    /// Simule le scénario pour chaque longueur d'onde (m)
+    pub fn compute<I>(scenario: &Scenario, wavelengths: I) -> Result<Self, MeteoError>
+    where
+        I: IntoIterator<Item = f64>,
+    {
+        let points = wavelengths
+            .into_iter()
+            .map(|wavelength| {
+                let scenario = Scenario {
+                    horizontal_scale: Some(wavelength / (2.0 * PI)),
+                    ..scenario.clone()
+                };
+                Ok(DispersionPoint {
+                    wavelength,
+                    growth_rate: growth_rate(&scenario)?,
+                })
+            })
+            .collect::<Result<_, MeteoError>>()?;
+
+        Ok(Self { points })
+    }
+
+    /// Points de la courbe, dans l'ordre des longueurs d'onde demandées
+    pub fn points(&self) -> &[DispersionPoint] {
+        &self.points
+    }
+
+    /// Longueur d'onde la plus instable
+    pub fn most_unstable(&self) -> Option<&DispersionPoint> {
+        self.points
+            .iter()
+            .filter(|point| point.growth_rate.is_finite())
+            .max_by(|a, b| a.growth_rate.total_cmp(&b.growth_rate))
+    }
+
+    /// Tableau des longueurs d'onde (km) et taux de croissance (jour⁻¹)
+    pub fn to_table(&self, language: OutputLanguage, format: &NumberFormat) -> String {
+        let (header, summary) = match language {
+            OutputLanguage::French => (
+                "Longueur d'onde (km) | Taux de croissance (jour⁻¹)",
+                "Longueur d'onde la plus instable :",
+            ),
+            OutputLanguage::English => (
+                "Wavelength (km)      | Growth rate (day⁻¹)",
+                "Most unstable wavelength:",
+            ),
+        };
+
+        let mut table = format!("{}\n---------------------|----------------------\n", header);
+        for point in &self.points {
+            table += &format!(
+                "{} | {}\n",
+                format.format_width(point.wavelength / 1000.0, 20),
+                format.format_width(point.growth_rate * SECONDS_PER_DAY, 20)
+            );
+        }
+        if let Some(point) = self.most_unstable() {
+            table += &format!(
+                "\n{} {} km ({} {})\n",
+                summary,
+                format.format(point.wavelength / 1000.0),
+                format.format(point.growth_rate * SECONDS_PER_DAY),
+                match language {
+                    OutputLanguage::French => "jour⁻¹",
+                    OutputLanguage::English => "day⁻¹",
+                }
+            );
+        }
+        table
+    }
+}
+
+/// Taux d'amplification moyen du tourbillon relatif (s⁻¹), nul sans développement
+fn growth_rate(scenario: &Scenario) -> Result<f64, MeteoError> {
+    let results = scenario.run()?;
+    let (Some(first), Some(last)) = (results.first(), results.last()) else {
+        return Ok(0.0);
+    };
+
+    let elapsed = f64::from(last.hour - first.hour) * 3600.0;
+    if elapsed == 0.0 || first.relative_vorticity == 0.0 || last.relative_vorticity == 0.0 {
+        return Ok(0.0);
+    }
+    Ok((last.relative_vorticity / first.relative_vorticity).abs().ln() / elapsed)
+}
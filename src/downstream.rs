@@ -0,0 +1,219 @@
+//! Développement en aval : chaîne de systèmes baroclines le long d'un cercle de latitude
+//!
+//! Un système barocline qui s'affaiblit cède son énergie vers l'aval, où elle
+//! amorce le système suivant : le paquet d'ondes se propage plus vite que
+//! chacun des systèmes qui le composent. La chaîne simule le scénario de base à
+//! sa longitude, porté par un talweg d'altitude en approche (celui du scénario,
+//! sinon 4 K, 1500 km de demi-longueur d'onde et 15 m/s), puis, dès que
+//! l'énergie cinétique des perturbations du dernier système commence à
+//! décroître, amorce le suivant `spacing` degrés plus à l'est.
+//!
+//! Le système amorcé reçoit une fraction `transfer` de l'énergie de son
+//! prédécesseur : l'énergie cinétique variant comme le carré du tourbillon,
+//! son anomalie d'altitude est celle du prédécesseur multipliée par √transfer.
+//! La chaîne s'arrête au nombre de systèmes demandé, quand un système ne
+//! décroît pas avant la fin de la simulation, ou à la dernière heure du
+//! scénario, qui fixe la durée de toute la chaîne. Un système s'éteint quand
+//! son talweg l'a dépassé d'une demi-longueur d'onde.
+//!
+//! Le diagramme de Hovmöller du paquet superpose, à chaque heure, le profil de
+//! tourbillon de chaque système actif autour de son centre ; la pente de la
+//! ligne joignant les débuts des systèmes donne la vitesse de groupe.
+
+use crate::energetics::EnergyBudget;
+use crate::hovmoller::{Hovmoller, HovmollerAxis};
+use crate::stability::EARTH_RADIUS;
+use crate::{BaroclinicCyclogenesis, MeteoError, NumberFormat, OutputLanguage, ResultSeries, Scenario, UpperTrough};
+
+/// Scénario de base et règles d'amorçage d'une chaîne
+#[derive(Debug, Clone)]
+pub struct ChainConfig {
+    pub scenario: Scenario,   // Premier système ; `time_steps` fixe la durée de la chaîne
+    pub members: usize,       // Nombre maximal de systèmes
+    pub spacing: f64,         // Écart en longitude entre deux systèmes successifs (°)
+    pub transfer: f64,        // Fraction de l'énergie transmise au système suivant
+    pub trough: UpperTrough,  // Talweg portant l'anomalie d'altitude de chaque système
+}
+
+impl ChainConfig {
+    /// Crée une chaîne d'au plus `members` systèmes espacés de `spacing` degrés
+    pub fn new(scenario: Scenario, members: usize, spacing: f64, transfer: f64) -> Result<Self, MeteoError> {
+        let trough = match scenario.upper_trough {
+            Some(trough) => trough,
+            None => UpperTrough::new(4.0, 1.5e6, 15.0)?,
+        };
+        let config = Self { scenario, members, spacing, transfer, trough };
+        config.validate()?;
+        Ok(config)
+    }
+
+    fn validate(&self) -> Result<(), MeteoError> {
+        if self.members == 0 {
+            return Err(MeteoError::InvalidScenario("chaîne sans système".to_string()));
+        }
+        if !(self.spacing > 0.0 && self.spacing < 360.0) {
+            return Err(MeteoError::InvalidScenario(format!("écart {}° entre systèmes hors de ]0, 360[", self.spacing)));
+        }
+        if !(self.transfer > 0.0 && self.transfer <= 1.0) {
+            return Err(MeteoError::InvalidScenario(format!("fraction transmise {} hors de ]0, 1]", self.transfer)));
+        }
+        self.trough.validate()
+    }
+}
+
+/// Système de la chaîne
+pub struct ChainMember {
+    pub onset: u32,                            // Heure d'amorçage, sur l'axe de la chaîne
+    pub longitude: f64,                        // Longitude de départ, dépliée vers l'est (°)
+    pub altitude_temp: f64,                    // Anomalie thermique d'altitude reçue (K)
+    pub cyclogenesis: BaroclinicCyclogenesis,  // État en fin de simulation
+    pub series: ResultSeries,                  // Heures comptées depuis l'amorçage
+    pub energy: Vec<EnergyBudget>,
+}
+
+impl ChainMember {
+    fn simulate(config: &ChainConfig, onset: u32, longitude: f64, altitude_temp: f64) -> Result<Self, MeteoError> {
+        let wrapped = (longitude + 180.0).rem_euclid(360.0) - 180.0;
+        let (trough, remaining) = (config.trough, config.scenario.time_steps - onset);
+        let lifetime = (0..remaining).take_while(|hour| trough.distance(*hour) >= -trough.half_wavelength()).count();
+        let scenario = Scenario {
+            altitude_temp,
+            time_steps: lifetime as u32,
+            longitude: config.scenario.longitude.map(|_| wrapped),
+            upper_trough: Some(trough),
+            ..config.scenario.clone()
+        };
+        let mut cyclogenesis = scenario.to_cyclogenesis()?;
+        cyclogenesis.longitude = wrapped;
+        let results = cyclogenesis.simulate_interaction(scenario.time_steps)?;
+        let energy = results.iter().map(|result| cyclogenesis.energy_budget(result)).collect();
+        Ok(Self { onset, longitude, altitude_temp, cyclogenesis, series: ResultSeries::new(results), energy })
+    }
+
+    /// Heure de la chaîne où l'énergie cinétique des perturbations commence à
+    /// décroître, `None` si elle croît jusqu'au bout
+    pub fn decay_onset(&self) -> Option<u32> {
+        self.energy
+            .windows(2)
+            .position(|pair| pair[1].eddy_kinetic < pair[0].eddy_kinetic)
+            .map(|index| self.onset + index as u32 + 1)
+    }
+}
+
+/// Chaîne de systèmes, d'ouest en est
+pub struct DownstreamChain {
+    members: Vec<ChainMember>,
+    hours: u32,
+}
+
+impl DownstreamChain {
+    /// Simule la chaîne de `config`, amorçant chaque système au déclin du précédent
+    pub fn simulate(config: &ChainConfig) -> Result<Self, MeteoError> {
+        config.validate()?;
+        let hours = config.scenario.time_steps;
+        let longitude = config.scenario.to_cyclogenesis()?.longitude;
+        let mut members = vec![ChainMember::simulate(config, 0, longitude, config.scenario.altitude_temp)?];
+        while members.len() < config.members {
+            let last = &members[members.len() - 1];
+            let onset = match last.decay_onset() {
+                Some(onset) if onset < hours => onset,
+                _ => break,
+            };
+            let (longitude, altitude_temp) = (last.longitude + config.spacing, last.altitude_temp * config.transfer.sqrt());
+            members.push(ChainMember::simulate(config, onset, longitude, altitude_temp)?);
+        }
+        Ok(Self { members, hours })
+    }
+
+    /// Systèmes, dans l'ordre de leur amorçage
+    pub fn members(&self) -> &[ChainMember] {
+        &self.members
+    }
+
+    /// Vitesse de groupe du paquet d'ondes entre le premier et le dernier
+    /// système (m/s), `None` pour un seul système
+    pub fn group_velocity(&self) -> Option<f64> {
+        let (first, last) = (self.members.first()?, self.members.last()?);
+        if last.onset == first.onset {
+            return None;
+        }
+        let latitude = first.cyclogenesis.initial_latitude;
+        let distance = EARTH_RADIUS * latitude.to_radians().cos() * (last.longitude - first.longitude).to_radians();
+        Some(distance / (f64::from(last.onset - first.onset) * 3600.0))
+    }
+
+    /// Diagramme de Hovmöller du paquet d'ondes
+    ///
+    /// L'axe couvre les longitudes de départ des systèmes avec la marge de
+    /// `axis` ; le centre de chaque heure est celui du système le plus intense,
+    /// inchangé quand aucun système n'est actif.
+    pub fn hovmoller(&self, axis: &HovmollerAxis) -> Hovmoller {
+        let west = self.members[0].longitude - axis.half_width();
+        let east = self.members[self.members.len() - 1].longitude + axis.half_width();
+        let count = ((east - west) / axis.spacing()).round() as usize + 1;
+        let longitudes: Vec<_> = (0..count).map(|index| west + index as f64 * axis.spacing()).collect();
+
+        let mut center_longitudes = Vec::with_capacity(self.hours as usize);
+        let mut vorticity = vec![0.0; self.hours as usize * longitudes.len()];
+        for hour in 0..self.hours {
+            let row = &mut vorticity[hour as usize * longitudes.len()..(hour as usize + 1) * longitudes.len()];
+            let mut strongest = (0.0, center_longitudes.last().copied().unwrap_or(self.members[0].longitude));
+            for member in self.members.iter().filter(|member| member.onset <= hour) {
+                let Some(result) = member.series.row((hour - member.onset) as usize) else {
+                    continue;
+                };
+                let longitude = result.longitude().unwrap_or(member.longitude);
+                let center = member.longitude + (longitude - member.longitude + 180.0).rem_euclid(360.0) - 180.0;
+                let latitude = result.latitude().unwrap_or(member.cyclogenesis.initial_latitude);
+                let scale = EARTH_RADIUS * latitude.to_radians().cos() / member.cyclogenesis.mean_radius();
+                let shape = member.cyclogenesis.surface_anomaly().structure.shape();
+                for (value, longitude) in row.iter_mut().zip(&longitudes) {
+                    *value += result.relative_vorticity() * shape.profile(scale * (longitude - center).to_radians());
+                }
+                if result.relative_vorticity().abs() > strongest.0 {
+                    strongest = (result.relative_vorticity().abs(), center);
+                }
+            }
+            center_longitudes.push(strongest.1);
+        }
+        Hovmoller::from_rows((0..self.hours).collect(), longitudes, center_longitudes, vorticity)
+    }
+
+    /// Tableau des systèmes de la chaîne : amorçage, anomalie reçue et pic
+    pub fn to_table(&self, language: OutputLanguage, format: &NumberFormat) -> String {
+        let header = match language {
+            OutputLanguage::French => {
+                "Système | Longitude (°) | Amorçage (h) | T' altitude (K)      | ζ max (10⁻⁵ s⁻¹)     | Heure du pic"
+            }
+            OutputLanguage::English => {
+                "System  | Longitude (°) | Onset (h)    | Upper T' (K)         | Max ζ (10⁻⁵ s⁻¹)     | Peak hour"
+            }
+        };
+        let mut table = format!(
+            "{}\n--------|---------------|--------------|----------------------|----------------------|-------------\n",
+            header
+        );
+        for (index, member) in self.members.iter().enumerate() {
+            let (peak, hour) = member.series.peak().map_or_else(
+                || (format!("{:>20}", "-"), "-".to_string()),
+                |peak| (format.format_width(peak.relative_vorticity() * 1e5, 20), (member.onset + peak.hour()).to_string()),
+            );
+            table += &format!(
+                "{:7} | {} | {:12} | {} | {} | {:>12}\n",
+                index + 1,
+                format.format_width(member.longitude, 13),
+                member.onset,
+                format.format_width(member.altitude_temp, 20),
+                peak,
+                hour
+            );
+        }
+        if let Some(speed) = self.group_velocity() {
+            table += &match language {
+                OutputLanguage::French => format!("\nVitesse de groupe du paquet : {} m/s\n", format.format(speed)),
+                OutputLanguage::English => format!("\nWave packet group velocity: {} m/s\n", format.format(speed)),
+            };
+        }
+        table
+    }
+}
@@ -0,0 +1,70 @@
+//! Cycle énergétique de Lorenz des perturbations
+//!
+//! L'énergie potentielle disponible des perturbations est celle de l'anomalie
+//! thermique nette de la colonne, rapportée à la stabilité statique de l'état de
+//! base. L'énergie cinétique est celle du vent tourbillonnaire V = ζ·R. La
+//! conversion barocline (g/T₀)·w·T' transforme la première en la seconde lorsque
+//! l'air chaud s'élève et l'air froid s'affaisse.
+
+use crate::diagnostics::background_stability;
+use crate::{BaroclinicCyclogenesis, DevelopmentResult, NumberFormat, OutputLanguage};
+
+/// Bilan énergétique d'un pas de temps, par unité de masse
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EnergyBudget {
+    pub hour: u32,
+    pub eddy_potential: f64,  // Énergie potentielle disponible des perturbations (J/kg)
+    pub eddy_kinetic: f64,    // Énergie cinétique des perturbations (J/kg)
+    pub conversion: f64,      // Conversion barocline APE → EKE (W/kg)
+}
+
+impl BaroclinicCyclogenesis {
+    /// Bilan énergétique associé au résultat du pas `result.hour`
+    pub fn energy_budget(&self, result: &DevelopmentResult) -> EnergyBudget {
+        let constants = &self.surface_anomaly().constants;
+        let heating = self.solar_heating(result.hour);
+        let wave = self.rossby_wave.map_or(0.0, |wave| wave.temperature_perturbation(result.hour));
+        let temperature: f64 = self
+            .anomalies
+            .iter()
+            .map(|anomaly| anomaly.temperature_delta + if anomaly.is_upper_level() { wave } else { heating })
+            .sum();
+        let radius = self
+            .anomalies
+            .iter()
+            .map(|anomaly| anomaly.structure.radius())
+            .sum::<f64>()
+            / self.anomalies.len() as f64;
+
+        let buoyancy = constants.gravity / constants.base_temp;
+        let stability = background_stability(constants);
+        let wind = result.relative_vorticity * radius;
+
+        EnergyBudget {
+            hour: result.hour,
+            eddy_potential: 0.5 * buoyancy * buoyancy * temperature * temperature / stability,
+            eddy_kinetic: 0.5 * wind * wind,
+            conversion: buoyancy * result.vertical_velocity * temperature,
+        }
+    }
+}
+
+/// Tableau du bilan énergétique
+pub fn to_table(budgets: &[EnergyBudget], language: OutputLanguage, format: &NumberFormat) -> String {
+    let header = match language {
+        OutputLanguage::French => "Heure | APE (J/kg)           | EKE (J/kg)           | Conversion (W/kg)",
+        OutputLanguage::English => "Hour  | APE (J/kg)           | EKE (J/kg)           | Conversion (W/kg)",
+    };
+
+    let mut table = format!("{}\n------|----------------------|----------------------|----------------------\n", header);
+    for budget in budgets {
+        table += &format!(
+            "{:4} | {} | {} | {}\n",
+            budget.hour,
+            format.format_width(budget.eddy_potential, 20),
+            format.format_width(budget.eddy_kinetic, 20),
+            format.format_width(budget.conversion, 20)
+        );
+    }
+    table
+}
@@ -0,0 +1,564 @@
+//! Ensemble de simulations aux anomalies perturbées
+//!
+//! Chaque membre reprend le scénario de contrôle en perturbant ses anomalies
+//! thermiques de surface et d'altitude par des tirages gaussiens indépendants,
+//! d'écarts types donnés. Le générateur pseudo-aléatoire (SplitMix64) est
+//! initialisé par une graine : un même ensemble se reproduit à l'identique.
+//!
+//! Les grandeurs d'un membre (tourbillon, vent, pression centrale, creusement
+//! sur 24 h) sont interpolées linéairement entre ses heures, pour être comparées
+//! à des seuils ou à des observations. La probabilité d'un événement à seuil
+//! est, à chaque heure, la fraction des membres qui le prévoient.
+//!
+//! Un ensemble décalé (« lagged ») réunit des simulations lancées à des heures
+//! différentes le long d'une même simulation de contrôle : chacune repart des
+//! anomalies du scénario, placées à la position atteinte par le contrôle à son
+//! heure de départ, et ne couvre que la suite de la période. Les membres
+//! peuvent être pondérés ; moyennes, dispersions, probabilités et scores
+//! tiennent compte des poids des membres présents à chaque heure.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Serialize, Serializer};
+
+use crate::classify::StormState;
+use crate::deepening::DeepeningRate;
+use crate::{BaroclinicCyclogenesis, DevelopmentResult, Float, MeteoError, NumberFormat, OutputLanguage, Scenario};
+
+/// Taille et dispersion d'un ensemble
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EnsembleConfig {
+    members: usize,
+    surface_spread: f64,   // Écart type de l'anomalie de surface (K)
+    altitude_spread: f64,  // Écart type de l'anomalie d'altitude (K)
+    seed: u64,
+}
+
+impl Default for EnsembleConfig {
+    /// 20 membres, anomalies perturbées de 1 K
+    fn default() -> Self {
+        Self { members: 20, surface_spread: 1.0, altitude_spread: 1.0, seed: 0 }
+    }
+}
+
+impl EnsembleConfig {
+    /// Ensemble de `members` membres, écarts types des anomalies de surface et d'altitude (K)
+    pub fn new(members: usize, surface_spread: f64, altitude_spread: f64) -> Result<Self, MeteoError> {
+        let valid_spread = |spread: f64| spread >= 0.0 && spread.is_finite();
+        if members < 2 || !valid_spread(surface_spread) || !valid_spread(altitude_spread) {
+            return Err(MeteoError::InvalidScenario(format!(
+                "ensemble invalide: {} membres, dispersion {} K et {} K",
+                members, surface_spread, altitude_spread
+            )));
+        }
+        Ok(Self { members, surface_spread, altitude_spread, seed: 0 })
+    }
+
+    /// Graine du générateur pseudo-aléatoire
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn members(&self) -> usize {
+        self.members
+    }
+}
+
+/// Simulation d'un membre et grandeurs dérivées
+#[derive(Debug, Clone)]
+pub struct EnsembleMember<T: Float = f64> {
+    pub scenario: Scenario,
+    pub results: Vec<DevelopmentResult<T>>,
+    pub states: Vec<StormState>,
+    pub deepening: Vec<DeepeningRate>,
+}
+
+impl<T: Float> EnsembleMember<T> {
+    /// Simule un scénario dans la précision `T`
+    pub fn simulate_as(scenario: Scenario) -> Result<Self, MeteoError> {
+        let mut cyclogenesis = scenario.to_cyclogenesis_as::<T>()?;
+        let results = cyclogenesis.simulate_interaction(scenario.time_steps)?;
+        let states = cyclogenesis.storm_states(&results);
+        let deepening = cyclogenesis.deepening_rates(&results);
+        Ok(Self { scenario, results, states, deepening })
+    }
+
+    /// Grandeur à une heure, interpolée entre les heures simulées ; `None` hors de
+    /// la période simulée
+    pub fn value(&self, variable: EnsembleVariable, hour: f64) -> Option<f64> {
+        let series: Vec<(f64, f64)> = match variable {
+            EnsembleVariable::Vorticity => {
+                self.states.iter().map(|state| (f64::from(state.hour), state.relative_vorticity)).collect()
+            }
+            EnsembleVariable::Wind => self.states.iter().map(|state| (f64::from(state.hour), state.surface_wind)).collect(),
+            EnsembleVariable::Pressure => {
+                self.states.iter().map(|state| (f64::from(state.hour), state.central_pressure)).collect()
+            }
+            EnsembleVariable::Deepening => self.deepening.iter().map(|rate| (f64::from(rate.hour), rate.rate)).collect(),
+        };
+        interpolate(&series, hour)
+    }
+}
+
+impl EnsembleMember {
+    /// Simule un scénario
+    pub fn simulate(scenario: Scenario) -> Result<Self, MeteoError> {
+        Self::simulate_as(scenario)
+    }
+
+    /// Simule le scénario depuis l'heure `start_hour` de la simulation de contrôle
+    /// `trunk`, en repartant des anomalies initiales à la position atteinte ; les
+    /// heures restent celles du contrôle
+    pub fn lagged(scenario: &Scenario, trunk: &[DevelopmentResult], start_hour: u32) -> Result<Self, MeteoError> {
+        let Some(index) = trunk.iter().position(|result| result.hour == start_hour) else {
+            return Err(MeteoError::InvalidScenario(format!("heure de départ {} absente du contrôle", start_hour)));
+        };
+        let elapsed = start_hour - trunk[0].hour;
+        let mut scenario = Scenario {
+            time_steps: scenario.time_steps.saturating_sub(elapsed),
+            start_time: scenario.start_time.map(|start| start + chrono::TimeDelta::hours(i64::from(elapsed))),
+            ..scenario.clone()
+        };
+        let mut cyclogenesis = if index == 0 {
+            scenario.to_cyclogenesis()?
+        } else {
+            let mut cyclogenesis = BaroclinicCyclogenesis::from_state(&scenario, &trunk[..index])?;
+            cyclogenesis.first_hour = 0;
+            cyclogenesis
+        };
+        if let Some(latitude) = trunk[index].latitude {
+            scenario.latitude = latitude;
+        }
+        if trunk[index].longitude.is_some() {
+            scenario.longitude = trunk[index].longitude;
+        }
+
+        let mut results = cyclogenesis.simulate_interaction(scenario.time_steps)?;
+        let mut states = cyclogenesis.storm_states(&results);
+        let mut deepening = cyclogenesis.deepening_rates(&results);
+        for result in &mut results {
+            result.hour += elapsed;
+        }
+        for state in &mut states {
+            state.hour += elapsed;
+        }
+        for rate in &mut deepening {
+            rate.hour += elapsed;
+        }
+        Ok(Self { scenario, results, states, deepening })
+    }
+}
+
+/// Départ d'une simulation d'un ensemble décalé
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LaggedRun {
+    pub start_hour: u32,  // Heure de départ le long de la simulation de contrôle
+    pub weight: f64,      // Poids du membre, relatif aux autres
+}
+
+/// Ensemble de simulations
+#[derive(Debug, Clone)]
+pub struct Ensemble<T: Float = f64> {
+    members: Vec<EnsembleMember<T>>,
+    weights: Vec<f64>,  // Poids relatifs des membres, unitaires par défaut
+}
+
+impl<T: Float> Ensemble<T> {
+    /// Simule les membres perturbés d'un scénario de contrôle, dans la précision `T`
+    pub fn generate_as(scenario: &Scenario, config: &EnsembleConfig) -> Result<Self, MeteoError> {
+        let mut random = SplitMix64(config.seed);
+        let members = (0..config.members)
+            .map(|_| {
+                let mut member = scenario.clone();
+                member.surface_temp += config.surface_spread * random.gaussian();
+                member.altitude_temp += config.altitude_spread * random.gaussian();
+                EnsembleMember::simulate_as(member)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { weights: vec![1.0; members.len()], members })
+    }
+
+    /// Ensemble formé de membres déjà simulés, au moins deux
+    pub fn from_members(members: Vec<EnsembleMember<T>>) -> Result<Self, MeteoError> {
+        if members.len() < 2 {
+            return Err(MeteoError::InvalidScenario(format!("ensemble de {} membre(s)", members.len())));
+        }
+        Ok(Self { weights: vec![1.0; members.len()], members })
+    }
+
+    /// Pondère les membres, un poids positif ou nul par membre, de somme non nulle
+    pub fn with_weights(mut self, weights: &[f64]) -> Result<Self, MeteoError> {
+        let valid = weights.len() == self.members.len()
+            && weights.iter().all(|weight| *weight >= 0.0 && weight.is_finite())
+            && weights.iter().sum::<f64>() > 0.0;
+        if !valid {
+            return Err(MeteoError::InvalidScenario(format!(
+                "poids invalides: {:?} pour {} membres",
+                weights,
+                self.members.len()
+            )));
+        }
+        self.weights = weights.to_vec();
+        Ok(self)
+    }
+
+    pub fn members(&self) -> &[EnsembleMember<T>] {
+        &self.members
+    }
+
+    /// Poids relatifs des membres
+    pub fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+
+    /// Heures simulées par au moins un membre, croissantes
+    pub fn hours(&self) -> Vec<u32> {
+        let mut hours: Vec<_> = self.members.iter().flat_map(|member| member.states.iter().map(|state| state.hour)).collect();
+        hours.sort_unstable();
+        hours.dedup();
+        hours
+    }
+
+    /// Valeurs des membres à une heure, des membres qui la simulent
+    pub fn values(&self, variable: EnsembleVariable, hour: f64) -> Vec<f64> {
+        self.members.iter().filter_map(|member| member.value(variable, hour)).collect()
+    }
+
+    /// Valeurs et poids des membres à une heure, des membres qui la simulent
+    pub fn weighted_values(&self, variable: EnsembleVariable, hour: f64) -> Vec<(f64, f64)> {
+        self.members
+            .iter()
+            .zip(&self.weights)
+            .filter_map(|(member, weight)| Some((member.value(variable, hour)?, *weight)))
+            .collect()
+    }
+
+    /// Tableau de la moyenne et de la dispersion du tourbillon et de la pression centrale
+    pub fn to_table(&self, language: OutputLanguage, format: &NumberFormat) -> String {
+        let header = match language {
+            OutputLanguage::French => {
+                "Heure | ζ moyen (10⁻⁵ s⁻¹)   | Dispersion ζ         | Pression moyenne     | Dispersion (hPa)"
+            }
+            OutputLanguage::English => {
+                "Hour  | Mean ζ (10⁻⁵ s⁻¹)    | ζ spread             | Mean pressure (hPa)  | Spread (hPa)"
+            }
+        };
+
+        let mut table = format!(
+            "{}\n------|----------------------|----------------------|----------------------|----------------------\n",
+            header
+        );
+        for hour in self.hours() {
+            let (vorticity_mean, vorticity_spread) = mean_and_spread(&self.weighted_values(EnsembleVariable::Vorticity, f64::from(hour)));
+            let (pressure_mean, pressure_spread) = mean_and_spread(&self.weighted_values(EnsembleVariable::Pressure, f64::from(hour)));
+            table += &format!(
+                "{:4} | {} | {} | {} | {}\n",
+                hour,
+                format.format_width(vorticity_mean * 1e5, 20),
+                format.format_width(vorticity_spread * 1e5, 20),
+                format.format_width(pressure_mean, 20),
+                format.format_width(pressure_spread, 20)
+            );
+        }
+        let members = self.members.len();
+        table + &match language {
+            OutputLanguage::French => format!("\nEnsemble de {} membres\n", members),
+            OutputLanguage::English => format!("\nEnsemble of {} members\n", members),
+        }
+    }
+}
+
+impl Ensemble {
+    /// Simule les membres perturbés d'un scénario de contrôle
+    pub fn generate(scenario: &Scenario, config: &EnsembleConfig) -> Result<Self, MeteoError> {
+        Self::generate_as(scenario, config)
+    }
+
+    /// Ensemble décalé : simulations lancées aux heures de départ de `runs` le
+    /// long de la simulation de contrôle du scénario, pondérées par leurs poids
+    pub fn lagged(scenario: &Scenario, runs: &[LaggedRun]) -> Result<Self, MeteoError> {
+        let mut hours: Vec<_> = runs.iter().map(|run| run.start_hour).collect();
+        hours.sort_unstable();
+        hours.dedup();
+        if hours.len() != runs.len() {
+            return Err(MeteoError::InvalidScenario("heures de départ répétées dans l'ensemble décalé".to_string()));
+        }
+        let trunk = scenario.run()?;
+        let members = runs
+            .iter()
+            .map(|run| EnsembleMember::lagged(scenario, &trunk, run.start_hour))
+            .collect::<Result<Vec<_>, _>>()?;
+        let weights: Vec<_> = runs.iter().map(|run| run.weight).collect();
+        Self::from_members(members)?.with_weights(&weights)
+    }
+}
+
+impl<T: Float> Ensemble<T> {
+    /// Probabilité de chaque événement à chaque heure, indéfinie quand aucun
+    /// membre ne fournit la grandeur (creusement de la première heure)
+    pub fn exceedance(&self, thresholds: &[Threshold]) -> Vec<ExceedanceStep> {
+        self.hours()
+            .into_iter()
+            .map(|hour| ExceedanceStep {
+                hour,
+                probabilities: thresholds
+                    .iter()
+                    .map(|threshold| EventProbability {
+                        event: *threshold,
+                        probability: threshold.weighted_probability(&self.weighted_values(threshold.variable, f64::from(hour))),
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+}
+
+/// Probabilité d'un événement à une heure
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct EventProbability {
+    pub event: Threshold,
+    pub probability: Option<f64>,  // Fraction des membres, entre 0 et 1
+}
+
+/// Probabilités des événements à une heure de la simulation
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ExceedanceStep {
+    pub hour: u32,
+    pub probabilities: Vec<EventProbability>,  // Dans l'ordre des seuils
+}
+
+/// Présentation des probabilités de dépassement
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExceedanceOutput {
+    #[default]
+    Table,
+    Json,
+}
+
+impl FromStr for ExceedanceOutput {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "table" => Ok(ExceedanceOutput::Table),
+            "json" => Ok(ExceedanceOutput::Json),
+            _ => Err(format!("Présentation inconnue: {} (table, json)", value)),
+        }
+    }
+}
+
+/// Phrase signalant la probabilité la plus forte de chaque événement, à la
+/// première heure où elle est atteinte
+pub fn describe_peaks(steps: &[ExceedanceStep], language: OutputLanguage, format: &NumberFormat) -> String {
+    let events = steps.first().map_or(0, |step| step.probabilities.len());
+    (0..events)
+        .map(|index| {
+            let peak = steps
+                .iter()
+                .filter_map(|step| Some((step.hour, step.probabilities[index].probability?)))
+                .rev()
+                .max_by(|a, b| a.1.total_cmp(&b.1));
+            let event = steps[0].probabilities[index].event;
+            match (language, peak) {
+                (OutputLanguage::French, Some((hour, probability))) => {
+                    format!("Probabilité maximale de {} : {} à l'heure {}", event, format.format(probability), hour)
+                }
+                (OutputLanguage::English, Some((hour, probability))) => {
+                    format!("Peak probability of {}: {} at hour {}", event, format.format(probability), hour)
+                }
+                (OutputLanguage::French, None) => format!("Probabilité de {} indisponible", event),
+                (OutputLanguage::English, None) => format!("Probability of {} unavailable", event),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Tableau de la probabilité de chaque événement, une colonne par seuil
+pub fn exceedance_table(steps: &[ExceedanceStep], language: OutputLanguage, format: &NumberFormat) -> String {
+    let events: Vec<_> = steps.first().map_or_else(Vec::new, |step| step.probabilities.iter().map(|p| p.event).collect());
+    let mut header = match language {
+        OutputLanguage::French => "Heure".to_string(),
+        OutputLanguage::English => "Hour ".to_string(),
+    };
+    let mut rule = "------".to_string();
+    for event in &events {
+        header += &format!(" | {:<20}", event.to_string());
+        rule += "|----------------------";
+    }
+
+    let mut table = format!("{}\n{}\n", header.trim_end(), rule);
+    for step in steps {
+        table += &format!("{:4}", step.hour);
+        for probability in &step.probabilities {
+            table += &match probability.probability {
+                Some(probability) => format!(" | {}", format.format_width(probability, 20)),
+                None => format!(" | {:>20}", "-"),
+            };
+        }
+        table += "\n";
+    }
+    table + &format!("\n{}\n", describe_peaks(steps, language, format))
+}
+
+/// Bloc JSON des probabilités de dépassement
+pub fn exceedance_json(steps: &[ExceedanceStep]) -> String {
+    serde_json::to_string_pretty(steps).expect("probabilités sérialisables")
+}
+
+/// Grandeur d'un membre comparée à un seuil
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EnsembleVariable {
+    Vorticity,  // Tourbillon relatif (s⁻¹)
+    Wind,       // Vent maximal de surface (m/s)
+    Pressure,   // Pression centrale (hPa)
+    Deepening,  // Creusement sur 24 h (hPa/24 h)
+}
+
+impl EnsembleVariable {
+    /// Nom dans les seuils (`deepening>20`)
+    pub fn name(self) -> &'static str {
+        match self {
+            EnsembleVariable::Vorticity => "vorticity",
+            EnsembleVariable::Wind => "wind",
+            EnsembleVariable::Pressure => "pressure",
+            EnsembleVariable::Deepening => "deepening",
+        }
+    }
+
+    /// Unité des seuils ; le tourbillon est compté en 10⁻⁵ s⁻¹
+    pub fn unit(self) -> &'static str {
+        match self {
+            EnsembleVariable::Vorticity => "10⁻⁵ s⁻¹",
+            EnsembleVariable::Wind => "m/s",
+            EnsembleVariable::Pressure => "hPa",
+            EnsembleVariable::Deepening => "hPa/24 h",
+        }
+    }
+
+    /// Facteur des unités SI vers celle des seuils
+    fn scale(self) -> f64 {
+        match self {
+            EnsembleVariable::Vorticity => 1e5,
+            _ => 1.0,
+        }
+    }
+}
+
+/// Événement « grandeur au-dessus (ou au-dessous) d'un seuil »
+///
+/// Le tourbillon est comparé en valeur absolue. S'écrit `deepening>20`,
+/// `pressure<980`, `wind>25` ou `vorticity>10`, dans les unités de
+/// [`EnsembleVariable::unit`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Threshold {
+    pub variable: EnsembleVariable,
+    pub above: bool,
+    pub value: f64,  // Seuil, dans l'unité de la grandeur
+}
+
+impl Threshold {
+    /// L'événement se produit-il pour une valeur en unités SI ?
+    pub fn is_met(&self, value: f64) -> bool {
+        let value = match self.variable {
+            EnsembleVariable::Vorticity => value.abs() * self.variable.scale(),
+            _ => value * self.variable.scale(),
+        };
+        if self.above { value > self.value } else { value < self.value }
+    }
+
+    /// Fraction des valeurs (unités SI) pour lesquelles l'événement se produit
+    pub fn probability(&self, values: &[f64]) -> Option<f64> {
+        (!values.is_empty()).then(|| values.iter().filter(|value| self.is_met(**value)).count() as f64 / values.len() as f64)
+    }
+
+    /// Part du poids des valeurs (unités SI, poids) pour lesquelles l'événement se produit
+    pub fn weighted_probability(&self, values: &[(f64, f64)]) -> Option<f64> {
+        let total = values.iter().map(|(_, weight)| weight).sum::<f64>();
+        let met = values.iter().filter(|(value, _)| self.is_met(*value)).fold(0.0, |met, (_, weight)| met + weight);
+        (total > 0.0).then(|| met / total)
+    }
+}
+
+impl fmt::Display for Threshold {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}{}", self.variable.name(), if self.above { '>' } else { '<' }, self.value)
+    }
+}
+
+impl Serialize for Threshold {
+    /// Sérialisé sous sa forme textuelle, `deepening>20`
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl FromStr for Threshold {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let error = || format!("Seuil inconnu: {} (deepening>20, pressure<980, wind>25, vorticity>10)", value);
+        let (index, above) = value
+            .find(['>', '<'])
+            .map(|index| (index, value[index..].starts_with('>')))
+            .ok_or_else(error)?;
+        let variable = match value[..index].trim().to_lowercase().as_str() {
+            "vorticity" => EnsembleVariable::Vorticity,
+            "wind" => EnsembleVariable::Wind,
+            "pressure" => EnsembleVariable::Pressure,
+            "deepening" => EnsembleVariable::Deepening,
+            _ => return Err(error()),
+        };
+        let threshold = value[index + 1..].trim().parse::<f64>().ok().filter(|value| value.is_finite()).ok_or_else(error)?;
+        Ok(Self { variable, above, value: threshold })
+    }
+}
+
+/// Moyenne et écart type pondérés d'un échantillon (valeur, poids), nuls sans poids
+fn mean_and_spread(values: &[(f64, f64)]) -> (f64, f64) {
+    let total = values.iter().map(|(_, weight)| weight).sum::<f64>();
+    if total <= 0.0 {
+        return (0.0, 0.0);
+    }
+    let mean = values.iter().map(|(value, weight)| weight * value).sum::<f64>() / total;
+    let variance = values.iter().map(|(value, weight)| weight * (value - mean).powi(2)).sum::<f64>() / total;
+    (mean, variance.sqrt())
+}
+
+/// Valeur d'une série (heure, valeur) interpolée à une heure, `None` hors de la série
+fn interpolate(series: &[(f64, f64)], hour: f64) -> Option<f64> {
+    let after = series.iter().position(|(time, _)| *time >= hour)?;
+    let (time_b, value_b) = series[after];
+    let (time_a, value_a) = series[after.saturating_sub(1)];
+    if hour < time_a {
+        return None;
+    }
+    let weight = if time_b > time_a { (hour - time_a) / (time_b - time_a) } else { 1.0 };
+    Some(value_a + weight * (value_b - value_a))
+}
+
+/// Générateur pseudo-aléatoire SplitMix64
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Tirage uniforme dans ]0, 1]
+    fn uniform(&mut self) -> f64 {
+        ((self.next_u64() >> 11) + 1) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Tirage gaussien centré réduit (Box–Muller)
+    fn gaussian(&mut self) -> f64 {
+        let (u, v) = (self.uniform(), self.uniform());
+        (-2.0 * u.ln()).sqrt() * (2.0 * std::f64::consts::PI * v).cos()
+    }
+}
@@ -0,0 +1,114 @@
+//! Codes de sortie et compte rendu d'erreur lisible par une machine
+//!
+//! Chaque erreur appartient à une classe associée à un code de sortie propre,
+//! de quoi distinguer dans un script un scénario invalide d'une divergence
+//! numérique ou d'un échec d'entrée-sortie sans analyser le message. Le compte
+//! rendu JSON reprend la variante, la classe, le code et le message.
+
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use crate::{MeteoError, OutputLanguage};
+
+/// Classe d'une erreur
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorClass {
+    /// Arguments de la ligne de commande incorrects
+    Usage,
+    /// Paramètre ou scénario hors de son domaine de validité
+    InvalidScenario,
+    /// Données d'entrée illisibles (radiosondage, METAR, GRIB2, climatologie, fichier binaire...)
+    InvalidInput,
+    /// Divergence de l'intégration
+    NumericalInstability,
+    /// Échec de lecture, d'écriture ou d'exécution
+    RunFailure,
+}
+
+impl ErrorClass {
+    /// Code de sortie du processus ; 2 est celui des erreurs d'arguments de `clap`
+    pub fn exit_code(self) -> u8 {
+        match self {
+            ErrorClass::Usage => 2,
+            ErrorClass::InvalidScenario => 3,
+            ErrorClass::InvalidInput => 4,
+            ErrorClass::NumericalInstability => 5,
+            ErrorClass::RunFailure => 6,
+        }
+    }
+}
+
+impl MeteoError {
+    /// Classe de l'erreur
+    pub fn class(&self) -> ErrorClass {
+        match self {
+            MeteoError::InvalidSounding(_)
+            | MeteoError::InvalidMetar(_)
+            | MeteoError::InvalidGrib(_)
+            | MeteoError::InvalidNetcdf(_)
+            | MeteoError::InvalidObservations(_)
+            | MeteoError::InvalidBinary(_)
+            | MeteoError::InvalidClimatology(_) => ErrorClass::InvalidInput,
+            MeteoError::NumericalInstability(_) => ErrorClass::NumericalInstability,
+            MeteoError::InvalidRun(_) => ErrorClass::RunFailure,
+            _ => ErrorClass::InvalidScenario,
+        }
+    }
+}
+
+/// Format des erreurs affichées sur la sortie d'erreur
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ErrorFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+impl FromStr for ErrorFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "text" => Ok(ErrorFormat::Text),
+            "json" => Ok(ErrorFormat::Json),
+            _ => Err(format!("Format d'erreur inconnu: {} (text, json)", value)),
+        }
+    }
+}
+
+/// Compte rendu d'une erreur
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ErrorReport {
+    pub kind: String,  // Variante de `MeteoError`, stable d'une langue à l'autre
+    pub class: ErrorClass,
+    pub exit_code: u8,
+    pub message: String,
+}
+
+impl ErrorReport {
+    /// Compte rendu d'une erreur de la simulation
+    pub fn new(error: &MeteoError, language: OutputLanguage) -> Self {
+        let class = error.class();
+        Self { kind: error.kind().to_string(), class, exit_code: class.exit_code(), message: error.message(language) }
+    }
+
+    /// Compte rendu d'un argument incorrect
+    pub fn usage(message: &str) -> Self {
+        Self {
+            kind: "InvalidArgument".to_string(),
+            class: ErrorClass::Usage,
+            exit_code: ErrorClass::Usage.exit_code(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Compte rendu dans le format demandé, sur une ligne
+    pub fn render(&self, format: ErrorFormat) -> String {
+        match format {
+            ErrorFormat::Text => self.message.clone(),
+            ErrorFormat::Json => serde_json::to_string(self).expect("compte rendu sérialisable"),
+        }
+    }
+}
@@ -0,0 +1,278 @@
+//! Explication terme à terme d'un pas de temps
+//!
+//! Chaque grandeur du calcul est donnée avec sa formule, les valeurs qui y
+//! entrent et son unité, de la force de Coriolis de chaque anomalie jusqu'au
+//! tourbillon relatif du système : le modèle se lit comme un support de cours.
+//! Les valeurs sont celles du pas effectivement simulé, relevées au niveau de
+//! diagnostics complet.
+
+use crate::diagnostics::{AnomalyDiagnostics, DiagnosticsLevel};
+use crate::format::{ExponentStyle, Precision};
+use crate::{BaroclinicCyclogenesis, DevelopmentResult, MeteoError, NumberFormat, OutputLanguage};
+
+/// Données d'une anomalie entrant dans le calcul
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnomalyExplanation {
+    pub temperature_delta: f64,  // ΔT (K)
+    pub altitude: f64,           // z (m)
+    pub radius: f64,             // R (m)
+    pub selectivity: f64,        // Sélectivité d'échelle s du taux de croissance
+    pub vorticity_factor: f64,   // Amplification A du tourbillon par le profil horizontal
+    pub cyclonic: bool,
+    pub chain: AnomalyDiagnostics,
+}
+
+impl AnomalyExplanation {
+    /// Facteur a du tourbillon, doublé au-dessus de 500 hPa
+    fn height_factor(&self) -> f64 {
+        if self.chain.pressure < 500.0 { 2.0 } else { 1.0 }
+    }
+}
+
+/// Libellés des termes
+#[derive(Clone, Copy)]
+enum Term {
+    Heading,
+    Anomaly,
+    Coriolis,
+    Intensity,
+    ThermalWind,
+    PressureFactor,
+    AltitudeFactor,
+    VerticalVelocity,
+    RelativeVorticity,
+    InteractionFactor,
+    SurfaceFriction,
+    BoundaryLayer,
+    System,
+    Divergence,
+    Nudging,
+}
+
+impl Term {
+    fn label(self, language: OutputLanguage) -> &'static str {
+        match (language, self) {
+            (OutputLanguage::French, Term::Heading) => "Explication du pas de l'heure",
+            (OutputLanguage::French, Term::Anomaly) => "Anomalie à",
+            (OutputLanguage::French, Term::Coriolis) => "Terme de Coriolis",
+            (OutputLanguage::French, Term::Intensity) => "Intensité",
+            (OutputLanguage::French, Term::ThermalWind) => "Vent thermique",
+            (OutputLanguage::French, Term::PressureFactor) => "Facteur de pression",
+            (OutputLanguage::French, Term::AltitudeFactor) => "Facteur d'altitude",
+            (OutputLanguage::French, Term::VerticalVelocity) => "Vitesse verticale",
+            (OutputLanguage::French, Term::RelativeVorticity) => "Tourbillon relatif",
+            (OutputLanguage::French, Term::InteractionFactor) => "Facteur d'interaction",
+            (OutputLanguage::French, Term::SurfaceFriction) => "Frottement de surface",
+            (OutputLanguage::French, Term::BoundaryLayer) => "Couche limite",
+            (OutputLanguage::French, Term::System) => "Système",
+            (OutputLanguage::French, Term::Divergence) => "Divergence des basses couches",
+            (OutputLanguage::French, Term::Nudging) => "rappel",
+            (OutputLanguage::English, Term::Heading) => "Explanation of the step at hour",
+            (OutputLanguage::English, Term::Anomaly) => "Anomaly at",
+            (OutputLanguage::English, Term::Coriolis) => "Coriolis term",
+            (OutputLanguage::English, Term::Intensity) => "Intensity",
+            (OutputLanguage::English, Term::ThermalWind) => "Thermal wind",
+            (OutputLanguage::English, Term::PressureFactor) => "Pressure factor",
+            (OutputLanguage::English, Term::AltitudeFactor) => "Altitude factor",
+            (OutputLanguage::English, Term::VerticalVelocity) => "Vertical velocity",
+            (OutputLanguage::English, Term::RelativeVorticity) => "Relative vorticity",
+            (OutputLanguage::English, Term::InteractionFactor) => "Interaction factor",
+            (OutputLanguage::English, Term::SurfaceFriction) => "Surface friction",
+            (OutputLanguage::English, Term::BoundaryLayer) => "Boundary layer",
+            (OutputLanguage::English, Term::System) => "System",
+            (OutputLanguage::English, Term::Divergence) => "Low-level divergence",
+            (OutputLanguage::English, Term::Nudging) => "nudging",
+        }
+    }
+}
+
+/// Explication d'un pas de temps
+#[derive(Debug, Clone, PartialEq)]
+pub struct Explanation {
+    pub hour: u32,
+    pub earth_omega: f64,  // Ω (rad/s)
+    pub gravity: f64,      // g (m/s²)
+    pub base_temp: f64,    // T₀ (K)
+    pub tropopause_height: f64,  // H (m)
+    pub boundary_layer_depth: Option<f64>,  // h (m) au début du pas, `None` sans couche limite évolutive
+    pub anomalies: Vec<AnomalyExplanation>,
+    pub result: DevelopmentResult,
+}
+
+impl BaroclinicCyclogenesis {
+    /// Simule jusqu'à l'heure `hour` incluse et explique ce dernier pas
+    pub fn explain(&mut self, hour: u32) -> Result<Explanation, MeteoError> {
+        if hour < self.first_hour {
+            return Err(MeteoError::InvalidScenario(format!(
+                "heure {} antérieure au départ de la simulation (heure {})",
+                hour, self.first_hour
+            )));
+        }
+        let level = self.diagnostics_level;
+        let mut boundary_layer_depth = None;
+        self.diagnostics_level = DiagnosticsLevel::Full;
+        let outcome = (self.first_hour..hour).try_for_each(|hour| self.step(hour).map(drop)).and_then(|()| {
+            boundary_layer_depth = self.boundary_layer.map(|layer| layer.depth());
+            self.step(hour)
+        });
+        self.diagnostics_level = level;
+        let result = outcome?;
+
+        let anomalies = self
+            .anomalies
+            .iter()
+            .zip(&self.anomaly_diagnostics)
+            .map(|(anomaly, chain)| AnomalyExplanation {
+                temperature_delta: anomaly.temperature_delta,
+                altitude: anomaly.position.altitude,
+                radius: anomaly.structure.radius(),
+                selectivity: anomaly.scale_selectivity(),
+                vorticity_factor: anomaly.structure.vorticity_factor(),
+                cyclonic: anomaly.is_cyclonic,
+                chain: *chain,
+            })
+            .collect();
+        let constants = &self.surface_anomaly().constants;
+        Ok(Explanation {
+            hour,
+            earth_omega: constants.earth_omega,
+            gravity: constants.gravity,
+            base_temp: constants.base_temp,
+            tropopause_height: self.tropopause_height,
+            boundary_layer_depth,
+            anomalies,
+            result,
+        })
+    }
+}
+
+impl Explanation {
+    /// Compte rendu : une ligne par terme, formule puis valeurs substituées
+    ///
+    /// Les valeurs sont données à trois chiffres significatifs, en notation
+    /// scientifique lorsqu'elles sont très petites ou très grandes.
+    pub fn describe(&self, language: OutputLanguage, format: &NumberFormat) -> String {
+        let format = format.with_exponent(ExponentStyle::Auto).with_precision(Precision::Significant(3));
+        let f = |value: f64| format.format(value);
+        let mut text = format!("{} {}\n", Term::Heading.label(language), self.hour);
+
+        let (mut vertical_velocity, mut relative_vorticity) = (0.0, 0.0);
+        for anomaly in &self.anomalies {
+            let chain = &anomaly.chain;
+            let latitude = (chain.coriolis / self.earth_omega).clamp(-1.0, 1.0).asin().to_degrees();
+            let wind_sign = if anomaly.cyclonic { "" } else { "−" };
+            let velocity_sign = if chain.pressure > 500.0 { "" } else { "−" };
+            let vorticity_sign = wind_sign;
+            vertical_velocity += chain.vertical_velocity * chain.interaction_factor;
+            relative_vorticity += chain.relative_vorticity * chain.friction_factor * chain.interaction_factor;
+
+            let lines = [
+                format!(
+                    "\n{} {} hPa (ΔT = {} K, z = {} m, R = {} m)",
+                    Term::Anomaly.label(language),
+                    f(chain.pressure), f(anomaly.temperature_delta), f(anomaly.altitude), f(anomaly.radius)
+                ),
+                format!(
+                    "  {} Ω·sin φ = {} · sin({}°) = {} s⁻¹",
+                    Term::Coriolis.label(language),
+                    f(self.earth_omega), f(latitude), f(chain.coriolis)
+                ),
+                format!(
+                    "  {} I = 1 + h/12 · s = 1 + {}/12 · {} = {}",
+                    Term::Intensity.label(language),
+                    self.hour, f(anomaly.selectivity), f(chain.intensity)
+                ),
+                format!(
+                    "  {} V_T = {}(ΔT + F)/T₀ · g · 1000 · Ω·sin φ = {}({} + {})/{} · {} · 1000 · {} = {}",
+                    Term::ThermalWind.label(language),
+                    wind_sign, wind_sign,
+                    f(anomaly.temperature_delta), f(chain.forcing), f(self.base_temp), f(self.gravity), f(chain.coriolis),
+                    f(chain.thermal_wind)
+                ),
+                format!(
+                    "  {} √(1000/p) = √(1000/{}) = {}",
+                    Term::PressureFactor.label(language),
+                    f(chain.pressure), f(chain.pressure_factor)
+                ),
+                if anomaly.altitude > self.tropopause_height {
+                    format!(
+                        "  {} exp(−H/8000) · exp(−(z − H)/1000) = exp(−{}/8000) · exp(−({} − {})/1000) = {}",
+                        Term::AltitudeFactor.label(language),
+                        f(self.tropopause_height), f(anomaly.altitude), f(self.tropopause_height), f(chain.altitude_factor)
+                    )
+                } else {
+                    format!(
+                        "  {} exp(−z/8000) = exp(−{}/8000) = {}",
+                        Term::AltitudeFactor.label(language),
+                        f(anomaly.altitude), f(chain.altitude_factor)
+                    )
+                },
+                format!(
+                    "  {} w = {}{} · V_T · √(1000/p) · exp(−z/8000) · I = {}{} · {} · {} · {} · {} = {} m/s",
+                    Term::VerticalVelocity.label(language),
+                    velocity_sign, f(0.1), velocity_sign, f(0.1),
+                    f(chain.thermal_wind), f(chain.pressure_factor), f(chain.altitude_factor), f(chain.intensity),
+                    f(chain.vertical_velocity)
+                ),
+                format!(
+                    "  {} ζ = {}V_T/R · I · a · A = {}{}/{} · {} · {} · {} = {} s⁻¹",
+                    Term::RelativeVorticity.label(language),
+                    vorticity_sign, vorticity_sign,
+                    f(chain.thermal_wind), f(anomaly.radius), f(chain.intensity), f(anomaly.height_factor()),
+                    f(anomaly.vorticity_factor), f(chain.relative_vorticity)
+                ),
+                format!(
+                    "  {} = max(0, 1 + Σ poids · couplage) = {}",
+                    Term::InteractionFactor.label(language),
+                    f(chain.interaction_factor)
+                ),
+            ];
+            for line in lines {
+                text += &line;
+                text.push('\n');
+            }
+            if chain.friction_factor < 1.0 {
+                text += &format!(
+                    "  {} = exp(−√(K·|f|/2)/H · t) = {}\n",
+                    Term::SurfaceFriction.label(language),
+                    f(chain.friction_factor)
+                );
+            }
+        }
+
+        if let Some(depth) = self.boundary_layer_depth {
+            text += &format!(
+                "\n{} h = {} m, 1000/h = {}, h/1000 = {}\n",
+                Term::BoundaryLayer.label(language),
+                f(depth), f(1000.0 / depth), f(depth / 1000.0)
+            );
+        }
+
+        // Le reste du tourbillon vient de l'effet β et du rappel vers les observations
+        let remainder = self.result.relative_vorticity() - relative_vorticity;
+        let lines = [
+            format!("\n{}", Term::System.label(language)),
+            format!(
+                "  {} w = Σ wᵢ · facteurᵢ = {} m/s",
+                Term::VerticalVelocity.label(language),
+                f(vertical_velocity)
+            ),
+            format!(
+                "  {} = −π · w/H = −π · {}/{} = {} s⁻¹",
+                Term::Divergence.label(language),
+                f(self.result.vertical_velocity()), f(self.tropopause_height), f(self.result.low_level_divergence())
+            ),
+            format!(
+                "  {} ζ = Σ ζᵢ · facteurᵢ + ζ_β + {} = {} + {} = {} s⁻¹",
+                Term::RelativeVorticity.label(language),
+                Term::Nudging.label(language),
+                f(relative_vorticity), f(remainder), f(self.result.relative_vorticity())
+            ),
+        ];
+        for line in lines {
+            text += &line;
+            text.push('\n');
+        }
+        text
+    }
+}
@@ -0,0 +1,70 @@
+//! Type scalaire des grandeurs simulées
+//!
+//! Le cœur de la simulation (anomalies thermiques, pas de temps, résultats), la
+//! construction d'un scénario et les ensembles perturbés sont génériques sur le
+//! trait `Float` : les grands ensembles tournent en `f32` pour diviser la mémoire
+//! par deux, tandis que `f64` reste le type par défaut. Les
+//! paramètres de configuration (positions, rayons, géométrie) restent en `f64`
+//! et sont convertis à leur entrée dans les calculs.
+
+use std::fmt::Debug;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub};
+
+/// Scalaire flottant des grandeurs simulées
+pub trait Float:
+    Copy
+    + Debug
+    + PartialEq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+{
+    /// Convertit une constante ou un paramètre de configuration
+    fn from_f64(value: f64) -> Self;
+
+    /// Valeur en double précision, pour l'affichage et les contrôles
+    fn to_f64(self) -> f64;
+
+    fn abs(self) -> Self;
+
+    fn sqrt(self) -> Self;
+}
+
+impl Float for f64 {
+    fn from_f64(value: f64) -> Self {
+        value
+    }
+
+    fn to_f64(self) -> f64 {
+        self
+    }
+
+    fn abs(self) -> Self {
+        f64::abs(self)
+    }
+
+    fn sqrt(self) -> Self {
+        f64::sqrt(self)
+    }
+}
+
+impl Float for f32 {
+    fn from_f64(value: f64) -> Self {
+        value as f32
+    }
+
+    fn to_f64(self) -> f64 {
+        f64::from(self)
+    }
+
+    fn abs(self) -> Self {
+        f32::abs(self)
+    }
+
+    fn sqrt(self) -> Self {
+        f32::sqrt(self)
+    }
+}
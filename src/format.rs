@@ -0,0 +1,161 @@
+//! Mise en forme des nombres dans les tableaux et les exports texte
+
+use std::str::FromStr;
+
+use crate::units::WindUnit;
+
+/// Langues dont le séparateur décimal est la virgule
+const COMMA_LANGUAGES: [&str; 20] = [
+    "fr", "de", "es", "it", "pt", "nl", "ru", "pl", "cs", "sk", "sv", "da", "nb", "nn", "fi", "tr",
+    "el", "ro", "hu", "uk",
+];
+
+/// Seuils de la notation automatique : scientifique hors de [10⁻³, 10⁶[
+const AUTO_LOWER: f64 = 1.0e-3;
+const AUTO_UPPER: f64 = 1.0e6;
+
+/// Notation des puissances de dix
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExponentStyle {
+    /// Notation décimale (`0.0012`)
+    #[default]
+    Fixed,
+    /// Notation scientifique (`1.2e-3`)
+    Scientific,
+    /// Notation scientifique pour les valeurs très petites ou très grandes
+    Auto,
+}
+
+impl FromStr for ExponentStyle {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "fixed" => Ok(ExponentStyle::Fixed),
+            "scientific" => Ok(ExponentStyle::Scientific),
+            "auto" => Ok(ExponentStyle::Auto),
+            _ => Err(format!("Notation inconnue: {} (fixed, scientific, auto)", value)),
+        }
+    }
+}
+
+/// Nombre de chiffres affichés
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Precision {
+    /// Nombre fixe de décimales
+    Decimals(usize),
+    /// Nombre de chiffres significatifs
+    Significant(usize),
+}
+
+/// Règles de mise en forme des nombres
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumberFormat {
+    decimal_separator: char,
+    precision: Precision,
+    exponent: ExponentStyle,
+    wind_unit: WindUnit,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self {
+            decimal_separator: '.',
+            precision: Precision::Decimals(2),
+            exponent: ExponentStyle::Fixed,
+            wind_unit: WindUnit::MetersPerSecond,
+        }
+    }
+}
+
+impl NumberFormat {
+    /// Adopte le séparateur décimal d'une locale (`fr-FR`, `en_US`...)
+    pub fn with_locale(mut self, locale: &str) -> Result<Self, String> {
+        let language = locale.split(['-', '_']).next().unwrap_or_default().to_lowercase();
+        if !(2..=3).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Err(format!("Locale invalide: {}", locale));
+        }
+
+        self.decimal_separator = if COMMA_LANGUAGES.contains(&language.as_str()) { ',' } else { '.' };
+        Ok(self)
+    }
+
+    /// Fixe le nombre de chiffres affichés
+    pub fn with_precision(mut self, precision: Precision) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Fixe la notation des puissances de dix
+    pub fn with_exponent(mut self, exponent: ExponentStyle) -> Self {
+        self.exponent = exponent;
+        self
+    }
+
+    /// Fixe l'unité des vitesses de vent
+    pub fn with_wind_unit(mut self, wind_unit: WindUnit) -> Self {
+        self.wind_unit = wind_unit;
+        self
+    }
+
+    /// Unité des vitesses de vent en vigueur
+    pub fn wind_unit(&self) -> WindUnit {
+        self.wind_unit
+    }
+
+    /// Séparateur décimal en vigueur
+    pub fn decimal_separator(&self) -> char {
+        self.decimal_separator
+    }
+
+    /// Met en forme une valeur
+    pub fn format(&self, value: f64) -> String {
+        let magnitude = value.abs();
+        let scientific = match self.exponent {
+            ExponentStyle::Fixed => false,
+            ExponentStyle::Scientific => true,
+            ExponentStyle::Auto => magnitude != 0.0 && !(AUTO_LOWER..AUTO_UPPER).contains(&magnitude),
+        };
+
+        let text = if scientific {
+            let decimals = match self.precision {
+                Precision::Decimals(decimals) => decimals,
+                Precision::Significant(digits) => digits.saturating_sub(1),
+            };
+            format!("{:.*e}", decimals, value)
+        } else {
+            let decimals = match self.precision {
+                Precision::Decimals(decimals) => decimals,
+                Precision::Significant(digits) if magnitude == 0.0 || !magnitude.is_finite() => {
+                    digits.saturating_sub(1)
+                }
+                Precision::Significant(digits) => {
+                    let leading = magnitude.log10().floor() as i64;
+                    (digits as i64 - 1 - leading).max(0) as usize
+                }
+            };
+            format!("{:.*}", decimals, value)
+        };
+
+        if self.decimal_separator == '.' {
+            text
+        } else {
+            text.replace('.', &self.decimal_separator.to_string())
+        }
+    }
+
+    /// Met en forme une valeur alignée à droite sur une largeur donnée
+    pub fn format_width(&self, value: f64, width: usize) -> String {
+        format!("{:>width$}", self.format(value), width = width)
+    }
+
+    /// Met en forme une vitesse de vent donnée en m/s, dans l'unité en vigueur
+    pub fn format_wind(&self, value: f64) -> String {
+        self.format(self.wind_unit.express(value))
+    }
+
+    /// Met en forme une vitesse de vent alignée à droite sur une largeur donnée
+    pub fn format_wind_width(&self, value: f64, width: usize) -> String {
+        self.format_width(self.wind_unit.express(value), width)
+    }
+}
@@ -0,0 +1,63 @@
+//! Repère terrestre ou repère lié à la dépression
+//!
+//! Les modèles conceptuels (norvégien, Shapiro–Keyser) sont dessinés dans le
+//! repère qui suit la dépression : on retranche au vent la vitesse de
+//! déplacement du système, due au vent directeur et à la dérive β, et les
+//! positions sont comptées depuis le centre. La vitesse de déplacement est
+//! estimée sur la trajectoire des résultats, par différences centrées ; elle est
+//! nulle sans position (plan f).
+
+use std::str::FromStr;
+
+use crate::stability::EARTH_RADIUS;
+use crate::DevelopmentResult;
+
+/// Repère d'expression des champs diagnostiques
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Frame {
+    /// Repère terrestre : vent total, longitudes géographiques
+    #[default]
+    Earth,
+    /// Repère lié à la dépression : vent relatif, positions comptées depuis le centre
+    StormRelative,
+}
+
+impl FromStr for Frame {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "earth" => Ok(Frame::Earth),
+            "storm" | "storm-relative" => Ok(Frame::StormRelative),
+            _ => Err(format!("Repère inconnu: {} (earth, storm)", value)),
+        }
+    }
+}
+
+/// Vitesse de déplacement du système à chaque heure (vers l'est, vers le nord), en m/s
+pub fn storm_motion(results: &[DevelopmentResult]) -> Vec<(f64, f64)> {
+    let position = |result: &DevelopmentResult| Some((result.latitude?, result.longitude, result.hour));
+    (0..results.len())
+        .map(|index| {
+            let before = &results[index.saturating_sub(1)];
+            let after = &results[(index + 1).min(results.len() - 1)];
+            let (Some((lat_a, lon_a, hour_a)), Some((lat_b, lon_b, hour_b))) = (position(before), position(after)) else {
+                return (0.0, 0.0);
+            };
+            let duration = (f64::from(hour_b) - f64::from(hour_a)) * 3600.0;
+            if duration == 0.0 {
+                return (0.0, 0.0);
+            }
+            // Sans longitude (plan β), le système ne se déplace qu'en latitude
+            let longitude_step = match (lon_a, lon_b) {
+                (Some(lon_a), Some(lon_b)) => (lon_b - lon_a + 180.0).rem_euclid(360.0) - 180.0,
+                _ => 0.0,
+            };
+            let mean_latitude = ((lat_a + lat_b) / 2.0).to_radians();
+            (
+                EARTH_RADIUS * mean_latitude.cos() * longitude_step.to_radians() / duration,
+                EARTH_RADIUS * (lat_b - lat_a).to_radians() / duration,
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,137 @@
+//! Interaction de deux dépressions voisines (effet Fujiwhara)
+//!
+//! En deçà d'une séparation donnée, deux tempêtes d'une même simulation
+//! s'entraînent l'une l'autre. Chacune est advectée par le vent tangentiel de
+//! sa voisine à la distance qui les sépare : la paire tourne autour de son
+//! centre, dans le sens de rotation des tempêtes, tandis qu'une vitesse
+//! radiale, fraction `attraction` de ce vent, les rapproche. Les distances sont
+//! comptées sur le plan tangent à leur latitude moyenne.
+//!
+//! La tempête de plus faible circulation ζ·R² est cisaillée par la plus forte :
+//! elle perd chaque heure la part 1 − exp(−V/d · 3600 s) de son tourbillon,
+//! V étant le vent de la plus forte à la distance d, et la plus forte reçoit la
+//! circulation cédée. Sur le plan f, les tempêtes restent en place et seule
+//! leur intensité est affectée.
+
+use serde::{Deserialize, Serialize};
+
+use crate::stability::EARTH_RADIUS;
+use crate::structure::AnomalyShape;
+use crate::wind::tangential_wind;
+use crate::MeteoError;
+
+/// Vitesse d'attraction par défaut, rapportée au vent tangentiel de la voisine
+pub const ATTRACTION_RATIO: f64 = 0.2;
+
+/// Interaction entre les tempêtes d'une simulation à plusieurs systèmes
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum InteractionModel {
+    /// Tempêtes indépendantes
+    #[default]
+    Independent,
+    /// Rotation mutuelle et attraction en deçà de `separation` (m)
+    Fujiwhara {
+        separation: f64,
+        #[serde(default = "default_attraction")]
+        attraction: f64,
+    },
+}
+
+fn default_attraction() -> f64 {
+    ATTRACTION_RATIO
+}
+
+impl InteractionModel {
+    /// Interaction Fujiwhara en deçà de `separation` (m), d'attraction par défaut
+    pub fn fujiwhara(separation: f64) -> Self {
+        InteractionModel::Fujiwhara { separation, attraction: ATTRACTION_RATIO }
+    }
+
+    /// Séparation en deçà de laquelle deux tempêtes interagissent (m), `None`
+    /// pour des tempêtes indépendantes
+    pub fn separation(&self) -> Option<f64> {
+        match *self {
+            InteractionModel::Independent => None,
+            InteractionModel::Fujiwhara { separation, .. } => Some(separation),
+        }
+    }
+
+    /// Vérifie la séparation (positive, sous 5000 km) et l'attraction (entre 0 et 1)
+    pub fn validate(&self) -> Result<(), MeteoError> {
+        if let InteractionModel::Fujiwhara { separation, attraction } = *self {
+            if !(separation > 0.0 && separation <= 5.0e6 && (0.0..=1.0).contains(&attraction)) {
+                return Err(MeteoError::InvalidScenario(format!("interaction invalide: {:?}", self)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Effets d'une heure d'interaction sur chacune des deux tempêtes, `None`
+    /// si elles sont indépendantes, confondues ou trop éloignées
+    pub(crate) fn pair_effects(&self, a: &Vortex, b: &Vortex) -> Option<(VortexEffect, VortexEffect)> {
+        let InteractionModel::Fujiwhara { separation, attraction } = *self else {
+            return None;
+        };
+        let latitude = (0.5 * (a.latitude + b.latitude)).to_radians();
+        let longitude = (a.longitude - b.longitude + 180.0).rem_euclid(360.0) - 180.0;
+        // Vecteur de b vers a (m)
+        let x = EARTH_RADIUS * latitude.cos() * longitude.to_radians();
+        let y = EARTH_RADIUS * (a.latitude - b.latitude).to_radians();
+        let distance = x.hypot(y);
+        if !(distance > 0.0 && distance <= separation) {
+            return None;
+        }
+
+        let (wind_on_a, wind_on_b) = (b.wind_at(distance), a.wind_at(distance));
+        let motion = |wind: f64, x: f64, y: f64| {
+            let (east, north) = (-wind * y / distance, wind * x / distance);
+            let pull = attraction * wind.abs() / distance;
+            ((east - pull * x) * 3600.0, (north - pull * y) * 3600.0)
+        };
+        let (a_east, a_north) = motion(wind_on_a, x, y);
+        let (b_east, b_north) = motion(wind_on_b, -x, -y);
+
+        // Circulation cédée par la plus faible à la plus forte
+        let (weak, strong, wind) = if a.circulation().abs() < b.circulation().abs() {
+            (a, b, wind_on_a)
+        } else {
+            (b, a, wind_on_b)
+        };
+        let lost = weak.vorticity * (1.0 - (-wind.abs() / distance * 3600.0).exp());
+        let gained = lost * weak.radius * weak.radius / (strong.radius * strong.radius);
+        let (a_vorticity, b_vorticity) = if std::ptr::eq(weak, a) { (-lost, gained) } else { (gained, -lost) };
+
+        Some((
+            VortexEffect { eastward: a_east, northward: a_north, vorticity: a_vorticity },
+            VortexEffect { eastward: b_east, northward: b_north, vorticity: b_vorticity },
+        ))
+    }
+}
+
+/// État d'une tempête au regard de l'interaction
+pub(crate) struct Vortex {
+    pub latitude: f64,   // °
+    pub longitude: f64,  // °
+    pub vorticity: f64,  // Tourbillon relatif (s⁻¹)
+    pub radius: f64,     // Rayon des anomalies (m)
+    pub shape: AnomalyShape,
+}
+
+impl Vortex {
+    fn circulation(&self) -> f64 {
+        self.vorticity * self.radius * self.radius
+    }
+
+    fn wind_at(&self, distance: f64) -> f64 {
+        tangential_wind(self.shape, self.radius, self.vorticity, distance)
+    }
+}
+
+/// Effet d'une heure d'interaction sur une tempête
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct VortexEffect {
+    pub eastward: f64,   // Déplacement vers l'est (m)
+    pub northward: f64,  // Déplacement vers le nord (m)
+    pub vorticity: f64,  // Tourbillon reçu, négatif s'il est cédé (s⁻¹)
+}
@@ -0,0 +1,178 @@
+//! Géométrie du domaine : plan f, plan β ou sphère
+//!
+//! Sur le plan f, le paramètre de Coriolis reste celui de la latitude initiale.
+//! Sur le plan β, le système dérive sous l'effet β, vers le pôle pour une
+//! dépression et vers l'équateur pour un anticyclone, à la vitesse typique
+//! 0,5·β·R². Le paramètre de Coriolis suit la latitude du système et la
+//! conservation du tourbillon absolu ajoute au tourbillon relatif le terme
+//! f₀ − f, qui freine les dépressions en route vers le pôle.
+//!
+//! En géométrie sphérique, le système est en outre entraîné vers l'est par le
+//! vent moyen de la couche et dérive vers l'ouest sous l'effet β ; sa trajectoire
+//! suit des arcs de grand cercle et le terme métrique u·tan φ / a s'ajoute au
+//! tourbillon relatif.
+//!
+//! La trajectoire avance en sous-pas au pas et selon le schéma de l'intégration.
+
+use serde::{Deserialize, Serialize};
+
+use crate::stability::EARTH_RADIUS;
+use crate::{BaroclinicCyclogenesis, DevelopmentResult, Float, IntegrationScheme};
+
+/// Vitesse de dérive β rapportée à β·R²
+const BETA_DRIFT_COEFFICIENT: f64 = 0.5;
+
+/// Vent directeur rapporté au cisaillement : vent moyen d'une couche sans vent en surface
+const STEERING_FRACTION: f64 = 0.5;
+
+/// Approximation de la géométrie terrestre
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Geometry {
+    #[default]
+    FPlane,
+    BetaPlane,
+    Spherical,
+}
+
+impl Geometry {
+    /// Le système se déplace au cours de la simulation
+    pub fn is_moving(&self) -> bool {
+        *self != Geometry::FPlane
+    }
+}
+
+impl<T: Float> BaroclinicCyclogenesis<T> {
+    /// Paramètre de Coriolis f = 2Ω sin φ à une latitude donnée (s⁻¹)
+    pub(crate) fn coriolis_parameter(&self, latitude: f64) -> f64 {
+        2.0 * self.surface_anomaly().constants.earth_omega * latitude.to_radians().sin()
+    }
+
+    /// Vent directeur vers l'est (m/s), nul à l'équateur
+    fn steering_wind(&self) -> T {
+        T::from_f64(STEERING_FRACTION) * self.thermal_wind_shear().unwrap_or(T::from_f64(0.0))
+    }
+
+    /// Gradient méridien β = 2Ω cos φ / a du paramètre de Coriolis (m⁻¹ s⁻¹), nul sur le plan f
+    pub(crate) fn beta_parameter(&self) -> f64 {
+        if !self.geometry.is_moving() {
+            return 0.0;
+        }
+        self.beta_at(self.latitude())
+    }
+
+    /// Gradient méridien β = 2Ω cos φ / a à une latitude donnée (m⁻¹ s⁻¹)
+    fn beta_at(&self, latitude: f64) -> f64 {
+        2.0 * self.surface_anomaly().constants.earth_omega * latitude.to_radians().cos() / EARTH_RADIUS
+    }
+
+    /// Termes β et métrique du tourbillon relatif (s⁻¹)
+    pub(crate) fn beta_vorticity(&self) -> T {
+        let beta = T::from_f64(self.coriolis_parameter(self.initial_latitude) - self.coriolis_parameter(self.latitude()));
+        match self.geometry {
+            Geometry::FPlane => T::from_f64(0.0),
+            Geometry::BetaPlane => beta,
+            Geometry::Spherical => {
+                beta + self.steering_wind() * T::from_f64(self.latitude().to_radians().tan()) / T::from_f64(EARTH_RADIUS)
+            }
+        }
+    }
+
+    /// Déplace le système pendant une heure, au pas et selon le schéma choisis
+    pub(crate) fn advance_track(&mut self, result: &DevelopmentResult<T>) {
+        if !self.geometry.is_moving() {
+            return;
+        }
+
+        let vorticity = result.relative_vorticity.to_f64();
+        let dt = self.time_step();
+        for _ in 0..self.substeps() {
+            let departure = (self.latitude(), self.longitude);
+            let velocity = self.track_velocity(departure.0, vorticity);
+            // Méthode de Heun : moyenne des vitesses au départ et à l'arrivée prévue
+            let velocity = match self.scheme {
+                IntegrationScheme::Explicit => velocity,
+                IntegrationScheme::SemiImplicit => {
+                    let (latitude, _) = self.track_position(departure, velocity, dt);
+                    let arrival = self.track_velocity(latitude, vorticity);
+                    ((velocity.0 + arrival.0) / 2.0, (velocity.1 + arrival.1) / 2.0)
+                }
+            };
+            let (latitude, longitude) = self.track_position(departure, velocity, dt);
+            self.move_to(latitude, longitude);
+        }
+    }
+
+    /// Vitesse de déplacement vers l'est et vers le nord (m/s) d'un système de
+    /// tourbillon relatif `vorticity` (s⁻¹) à la latitude `latitude` (°)
+    pub(crate) fn track_velocity(&self, latitude: f64, vorticity: f64) -> (f64, f64) {
+        let coriolis = self.coriolis_parameter(latitude);
+        let radius = self.mean_radius();
+
+        // Une dépression tourne dans le sens de f et dérive vers le pôle
+        let poleward = if vorticity * coriolis >= 0.0 { 1.0 } else { -1.0 };
+        let drift = BETA_DRIFT_COEFFICIENT * self.beta_at(latitude) * radius * radius;
+        let northward = poleward * coriolis.signum() * drift;
+
+        match self.geometry {
+            Geometry::FPlane => (0.0, 0.0),
+            Geometry::BetaPlane => (0.0, northward),
+            Geometry::Spherical => (self.steering_wind().to_f64() - drift, northward),
+        }
+    }
+
+    /// Position atteinte depuis `departure` (°) à la vitesse `velocity` (m/s)
+    /// pendant `duration` (s)
+    fn track_position(&self, departure: (f64, f64), velocity: (f64, f64), duration: f64) -> (f64, f64) {
+        let ((latitude, longitude), (eastward, northward)) = (departure, velocity);
+        match self.geometry {
+            Geometry::Spherical => great_circle(latitude, longitude, eastward, northward, duration),
+            _ => (latitude + (northward * duration / EARTH_RADIUS).to_degrees(), longitude),
+        }
+    }
+
+    /// Déplace le système de `eastward` mètres vers l'est et `northward` mètres
+    /// vers le nord, en géométrie mobile seulement
+    pub(crate) fn displace(&mut self, eastward: f64, northward: f64) {
+        if !self.geometry.is_moving() {
+            return;
+        }
+        let latitude = self.latitude();
+        let longitude = self.longitude + (eastward / (EARTH_RADIUS * latitude.to_radians().cos())).to_degrees();
+        self.move_to(latitude + (northward / EARTH_RADIUS).to_degrees(), (longitude + 180.0).rem_euclid(360.0) - 180.0);
+    }
+
+    fn move_to(&mut self, latitude: f64, longitude: f64) {
+        let latitude = latitude.clamp(-90.0, 90.0);
+        for anomaly in &mut self.anomalies {
+            anomaly.position.latitude = latitude;
+        }
+        self.longitude = longitude;
+        if self.diurnal_longitude.is_some() {
+            self.diurnal_longitude = Some(longitude.rem_euclid(360.0));
+        }
+    }
+
+    /// Latitude courante du système (°)
+    pub fn latitude(&self) -> f64 {
+        self.surface_anomaly().position.latitude
+    }
+
+    /// Longitude courante du système (°), comptée depuis la longitude de départ
+    /// si celle-ci est inconnue
+    pub fn longitude(&self) -> f64 {
+        self.longitude
+    }
+}
+
+/// Déplacement le long d'un arc de grand cercle à vitesse constante (°)
+fn great_circle(latitude: f64, longitude: f64, eastward: f64, northward: f64, duration: f64) -> (f64, f64) {
+    let distance = eastward.hypot(northward) * duration / EARTH_RADIUS;
+    let bearing = eastward.atan2(northward);
+    let phi = latitude.to_radians();
+
+    let arrival = (phi.sin() * distance.cos() + phi.cos() * distance.sin() * bearing.cos()).asin();
+    let turn = (bearing.sin() * distance.sin() * phi.cos()).atan2(distance.cos() - phi.sin() * arrival.sin());
+    let longitude = (longitude + turn.to_degrees() + 180.0).rem_euclid(360.0) - 180.0;
+    (arrival.to_degrees(), longitude)
+}
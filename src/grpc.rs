@@ -0,0 +1,159 @@
+//! Service gRPC de simulation, diffusant les résultats heure par heure
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Instant;
+
+use chrono::DateTime;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tonic::{Request, Response, Status};
+
+use crate::metrics::Metrics;
+use crate::{server, DevelopmentResult, MeteoError, Scenario};
+
+/// Messages et service générés depuis `proto/cyclogenese.proto`
+pub mod proto {
+    tonic::include_proto!("cyclogenese");
+}
+
+use proto::simulation_server::{Simulation, SimulationServer};
+
+/// Nombre de résultats mis en attente avant de ralentir la simulation
+const STREAM_BUFFER: usize = 16;
+
+impl From<DevelopmentResult> for proto::DevelopmentResult {
+    fn from(result: DevelopmentResult) -> Self {
+        Self {
+            hour: result.hour,
+            vertical_velocity: result.vertical_velocity,
+            relative_vorticity: result.relative_vorticity,
+            valid_time: result.valid_time.map(|time| time.timestamp()),
+            low_level_divergence: result.low_level_divergence,
+            upper_level_divergence: result.upper_level_divergence,
+            latitude: result.latitude,
+            longitude: result.longitude,
+        }
+    }
+}
+
+/// Résultat reçu d'un client du service, la date de validité n'ayant que la seconde comme précision
+impl From<proto::DevelopmentResult> for DevelopmentResult {
+    fn from(result: proto::DevelopmentResult) -> Self {
+        Self {
+            hour: result.hour,
+            vertical_velocity: result.vertical_velocity,
+            relative_vorticity: result.relative_vorticity,
+            low_level_divergence: result.low_level_divergence,
+            upper_level_divergence: result.upper_level_divergence,
+            valid_time: result.valid_time.and_then(|seconds| DateTime::from_timestamp(seconds, 0)),
+            latitude: result.latitude,
+            longitude: result.longitude,
+        }
+    }
+}
+
+/// Scénario de la bibliothèque décrit par une requête : fichier TOML complet de
+/// `definition`, ou champs de base complétés par les valeurs par défaut
+impl TryFrom<proto::Scenario> for Scenario {
+    type Error = MeteoError;
+
+    fn try_from(scenario: proto::Scenario) -> Result<Self, MeteoError> {
+        let mut converted = match scenario.definition {
+            Some(definition) => Scenario::from_toml(&definition)?,
+            None => {
+                let start_time = scenario
+                    .start_time
+                    .map(|seconds| {
+                        DateTime::from_timestamp(seconds, 0)
+                            .ok_or_else(|| MeteoError::InvalidScenario(format!("Date de départ invalide: {}", seconds)))
+                    })
+                    .transpose()?;
+                Scenario {
+                    surface_temp: scenario.surface_temp,
+                    altitude_temp: scenario.altitude_temp,
+                    latitude: scenario.latitude,
+                    time_steps: scenario.time_steps,
+                    start_time,
+                    ..Scenario::default()
+                }
+            }
+        };
+        if scenario.name.is_some() {
+            converted.name = scenario.name;
+        }
+        Ok(converted)
+    }
+}
+
+/// Implémentation du service `Simulation`, comptant ses simulations dans ses métriques
+#[derive(Debug, Default)]
+pub struct SimulationService {
+    metrics: Arc<Metrics>,
+}
+
+impl SimulationService {
+    pub fn new(metrics: Arc<Metrics>) -> Self {
+        Self { metrics }
+    }
+
+    pub fn metrics(&self) -> &Metrics {
+        &self.metrics
+    }
+}
+
+#[tonic::async_trait]
+impl Simulation for SimulationService {
+    type SimulateStream = ReceiverStream<Result<proto::DevelopmentResult, Status>>;
+
+    async fn simulate(
+        &self,
+        request: Request<proto::Scenario>,
+    ) -> Result<Response<Self::SimulateStream>, Status> {
+        let invalid = |message: String| {
+            self.metrics.record_error();
+            Status::invalid_argument(message)
+        };
+        let scenario = Scenario::try_from(request.into_inner()).map_err(|e| invalid(e.to_string()))?;
+        log_event!(info, latitude = scenario.latitude, time_steps = scenario.time_steps, "requête de simulation");
+        let mut cyclogenesis = scenario.to_cyclogenesis().map_err(|e| invalid(e.to_string()))?;
+        // Même limite que le serveur WebSocket, et pas de dépassement de la dernière heure
+        let hours = server::check_time_steps(scenario.time_steps)
+            .and_then(|_| cyclogenesis.hours(scenario.time_steps))
+            .map_err(|e| invalid(e.to_string()))?;
+
+        let (tx, rx) = mpsc::channel(STREAM_BUFFER);
+        let metrics = Arc::clone(&self.metrics);
+
+        // Le calcul tourne hors de l'exécuteur pour ne pas bloquer les autres requêtes
+        tokio::task::spawn_blocking(move || {
+            let start = Instant::now();
+            for hour in hours {
+                let message = cyclogenesis
+                    .step(hour)
+                    .map(Into::into)
+                    .map_err(|error| Status::internal(error.to_string()));
+                let failed = message.is_err();
+                if failed {
+                    metrics.record_error();
+                }
+                if tx.blocking_send(message).is_err() || failed {
+                    // Client déconnecté ou intégration divergente : inutile de poursuivre
+                    return;
+                }
+            }
+            metrics.record_scenario(scenario.name.as_deref(), scenario.time_steps, start.elapsed());
+        });
+
+        Ok(Response::new(ReceiverStream::new(rx)))
+    }
+}
+
+/// Démarre le serveur gRPC sur l'adresse donnée, ses simulations étant comptées
+/// dans `metrics`
+pub async fn serve(addr: SocketAddr, metrics: Arc<Metrics>) -> Result<(), tonic::transport::Error> {
+    tonic::transport::Server::builder()
+        .add_service(SimulationServer::new(SimulationService::new(metrics)))
+        .serve(addr)
+        .await
+}
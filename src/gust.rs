@@ -0,0 +1,148 @@
+//! Rafales de surface
+//!
+//! La rafale de pointe ajoute au vent moyen U un multiple de l'écart type de
+//! ses fluctuations, σ_u = I·U, l'intensité de turbulence à 10 m valant
+//! I = 1/ln(10/z₀) en atmosphère neutre : G = 1 + k·I·φ(Ri), avec un facteur
+//! de pointe k = 3 pour une rafale de 3 s. La fonction de stabilité φ amortit
+//! la turbulence d'une couche stable, 1/√(1 + 5·Ri), et la renforce dans une
+//! couche instable, (1 − 16·Ri)^¼ ; elle vaut 1 sans nombre de Richardson
+//! (équateur).
+//!
+//! L'activité convective, portée par le chauffage diurne de la surface ΔT,
+//! ajoute la moitié de la vitesse convective √(g·z_i·ΔT/T₀) de la couche
+//! limite de hauteur z_i, que les courants descendants ramènent au sol.
+
+use crate::diagnostics::StepDiagnostics;
+use crate::{BaroclinicCyclogenesis, DevelopmentResult, MeteoError, NumberFormat, OutputLanguage};
+
+/// Hauteur du vent de surface (m)
+const ANEMOMETER_HEIGHT: f64 = 10.0;
+
+/// Part de la vitesse convective ramenée au sol par les courants descendants
+const CONVECTIVE_FRACTION: f64 = 0.5;
+
+/// Paramètres du modèle de rafales
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GustModel {
+    peak_factor: f64,           // Facteur de pointe k
+    roughness_length: f64,      // Longueur de rugosité z₀ (m)
+    boundary_layer_depth: f64,  // Hauteur de la couche limite z_i (m)
+}
+
+impl Default for GustModel {
+    /// Rafale de 3 s en terrain découvert (z₀ = 3 cm), couche limite de 1 km
+    fn default() -> Self {
+        Self { peak_factor: 3.0, roughness_length: 0.03, boundary_layer_depth: 1000.0 }
+    }
+}
+
+impl GustModel {
+    /// Facteur de pointe, longueur de rugosité (m) et hauteur de couche limite (m)
+    pub fn new(peak_factor: f64, roughness_length: f64, boundary_layer_depth: f64) -> Result<Self, MeteoError> {
+        let positive = [peak_factor, roughness_length, boundary_layer_depth]
+            .iter()
+            .all(|value| *value > 0.0 && value.is_finite());
+        if !positive || roughness_length >= ANEMOMETER_HEIGHT {
+            return Err(MeteoError::InvalidScenario(format!(
+                "modèle de rafales invalide: facteur {}, rugosité {} m, couche limite {} m",
+                peak_factor, roughness_length, boundary_layer_depth
+            )));
+        }
+        Ok(Self { peak_factor, roughness_length, boundary_layer_depth })
+    }
+
+    /// Intensité de turbulence à 10 m en atmosphère neutre
+    pub fn turbulence_intensity(&self) -> f64 {
+        1.0 / (ANEMOMETER_HEIGHT / self.roughness_length).ln()
+    }
+
+    /// Facteur de rafale pour un nombre de Richardson donné
+    pub fn gust_factor(&self, richardson: Option<f64>) -> f64 {
+        let stability = match richardson {
+            Some(richardson) if richardson >= 0.0 => 1.0 / (1.0 + 5.0 * richardson).sqrt(),
+            Some(richardson) => (1.0 - 16.0 * richardson).powf(0.25),
+            None => 1.0,
+        };
+        1.0 + self.peak_factor * self.turbulence_intensity() * stability
+    }
+}
+
+/// Vent moyen et rafale de pointe à une heure de la simulation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Gust {
+    pub hour: u32,
+    pub mean_wind: f64,    // Vent maximal de la circulation de surface (m/s)
+    pub gust_factor: f64,  // Rapport de la rafale turbulente au vent moyen
+    pub convective: f64,   // Apport des courants descendants convectifs (m/s)
+    pub peak_gust: f64,    // Rafale de pointe (m/s)
+}
+
+impl BaroclinicCyclogenesis {
+    /// Estime la rafale de pointe à chaque heure d'une simulation et de ses diagnostics
+    pub fn gusts(&self, results: &[DevelopmentResult], diagnostics: &[StepDiagnostics], model: &GustModel) -> Vec<Gust> {
+        let constants = &self.surface_anomaly().constants;
+        results
+            .iter()
+            .zip(diagnostics)
+            .map(|(result, step)| {
+                let mean_wind = self.surface_wind(result);
+                let gust_factor = model.gust_factor(step.richardson);
+                let heating = self.solar_heating(result.hour).max(0.0);
+                let convective = CONVECTIVE_FRACTION
+                    * (constants.gravity * model.boundary_layer_depth * heating / constants.base_temp).sqrt();
+                Gust { hour: result.hour, mean_wind, gust_factor, convective, peak_gust: mean_wind * gust_factor + convective }
+            })
+            .collect()
+    }
+}
+
+/// Phrase signalant la rafale la plus forte
+pub fn describe_peak(gusts: &[Gust], language: OutputLanguage, format: &NumberFormat) -> String {
+    let Some(peak) = gusts.iter().max_by(|a, b| a.peak_gust.total_cmp(&b.peak_gust)) else {
+        return match language {
+            OutputLanguage::French => "Aucune rafale estimée".to_string(),
+            OutputLanguage::English => "No gust estimated".to_string(),
+        };
+    };
+    let gust = format!("{} {}", format.format_wind(peak.peak_gust), format.wind_unit().symbol());
+    match language {
+        OutputLanguage::French => format!("Rafale maximale : {} à l'heure {}", gust, peak.hour),
+        OutputLanguage::English => format!("Peak gust: {} at hour {}", gust, peak.hour),
+    }
+}
+
+/// Tableau du vent moyen, du facteur de rafale et de la rafale de pointe, suivi de
+/// la rafale maximale
+pub fn to_table(gusts: &[Gust], language: OutputLanguage, format: &NumberFormat) -> String {
+    let unit = format.wind_unit().symbol();
+    let header = match language {
+        OutputLanguage::French => format!(
+            "Heure | {:<20} | Facteur de rafale    | {:<20} | Rafale ({})",
+            format!("Vent moyen ({})", unit),
+            format!("Convection ({})", unit),
+            unit
+        ),
+        OutputLanguage::English => format!(
+            "Hour  | {:<20} | Gust factor          | {:<20} | Gust ({})",
+            format!("Mean wind ({})", unit),
+            format!("Convection ({})", unit),
+            unit
+        ),
+    };
+
+    let mut table = format!(
+        "{}\n------|----------------------|----------------------|----------------------|----------------------\n",
+        header
+    );
+    for gust in gusts {
+        table += &format!(
+            "{:4} | {} | {} | {} | {}\n",
+            gust.hour,
+            format.format_wind_width(gust.mean_wind, 20),
+            format.format_width(gust.gust_factor, 20),
+            format.format_wind_width(gust.convective, 20),
+            format.format_wind_width(gust.peak_gust, 20)
+        );
+    }
+    table + &format!("\n{}\n", describe_peak(gusts, language, format))
+}
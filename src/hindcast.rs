@@ -0,0 +1,104 @@
+//! Intégration rétrograde : anomalies précurseurs d'une dépression mature
+//!
+//! Le système est linéarisé autour de la trajectoire du scénario : d'une heure à
+//! la précédente, une perturbation est multipliée par l'inverse du facteur de
+//! croissance ζ(h)/ζ(h−1) de la simulation directe. Partant du tourbillon observé
+//! à la dernière heure, l'intégration remonte jusqu'à l'heure 0 et donne
+//! l'amplitude des anomalies qui mènent à cette dépression.
+//!
+//! Les termes additifs (forçage diurne, train d'ondes, dérive β) ne sont pas
+//! proportionnels aux anomalies : le précurseur n'est alors qu'une estimation.
+
+use crate::{MeteoError, NumberFormat, OutputLanguage, Scenario};
+
+/// État estimé à une heure de l'intégration rétrograde
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HindcastStep {
+    pub hour: u32,
+    pub vertical_velocity: f64,   // Vitesse verticale (m/s)
+    pub relative_vorticity: f64,  // Tourbillon relatif (s⁻¹)
+}
+
+/// Intégration rétrograde d'un scénario depuis son état mature
+#[derive(Debug, Clone)]
+pub struct Hindcast {
+    steps: Vec<HindcastStep>,
+    precursor: Scenario,
+}
+
+impl Hindcast {
+    /// Remonte le scénario depuis un tourbillon relatif `mature_vorticity` (s⁻¹)
+    /// atteint à sa dernière heure
+    pub fn compute(scenario: &Scenario, mature_vorticity: f64) -> Result<Self, MeteoError> {
+        let reference = scenario.run()?;
+        let Some(last) = reference.last() else {
+            return Err(MeteoError::InvalidScenario("simulation sans pas de temps".to_string()));
+        };
+        if !mature_vorticity.is_finite() || mature_vorticity * last.relative_vorticity <= 0.0 {
+            return Err(MeteoError::InvalidScenario(format!(
+                "le tourbillon mature {} s⁻¹ doit être de même signe que celui du scénario ({} s⁻¹)",
+                mature_vorticity, last.relative_vorticity
+            )));
+        }
+
+        let mut vorticity = mature_vorticity;
+        let mut steps = Vec::with_capacity(reference.len());
+        for (index, result) in reference.iter().enumerate().rev() {
+            if index + 1 < reference.len() {
+                let growth = reference[index + 1].relative_vorticity / result.relative_vorticity;
+                if !growth.is_finite() || growth == 0.0 {
+                    return Err(MeteoError::InvalidScenario(format!(
+                        "croissance nulle entre les heures {} et {}",
+                        result.hour,
+                        result.hour + 1
+                    )));
+                }
+                vorticity /= growth;
+            }
+            steps.push(HindcastStep {
+                hour: result.hour,
+                vertical_velocity: result.vertical_velocity * vorticity / result.relative_vorticity,
+                relative_vorticity: vorticity,
+            });
+        }
+
+        let amplitude = vorticity / reference[0].relative_vorticity;
+        let precursor = Scenario {
+            surface_temp: scenario.surface_temp * amplitude,
+            altitude_temp: scenario.altitude_temp * amplitude,
+            ..scenario.clone()
+        };
+        log_event!(info, amplitude, "intégration rétrograde");
+        Ok(Self { steps, precursor })
+    }
+
+    /// États estimés, de la dernière heure à l'heure 0
+    pub fn steps(&self) -> &[HindcastStep] {
+        &self.steps
+    }
+
+    /// Scénario des anomalies précurseurs, à relancer en intégration directe
+    pub fn precursor(&self) -> &Scenario {
+        &self.precursor
+    }
+
+    /// Tableau de l'intégration rétrograde et anomalies précurseurs
+    pub fn to_table(&self, language: OutputLanguage, format: &NumberFormat) -> String {
+        let (surface, altitude) = (format.format(self.precursor.surface_temp), format.format(self.precursor.altitude_temp));
+        let summary = match language {
+            OutputLanguage::French => format!("Anomalies précurseurs : {} K en surface, {} K en altitude", surface, altitude),
+            OutputLanguage::English => format!("Precursor anomalies: {} K at the surface, {} K aloft", surface, altitude),
+        };
+
+        let mut table = format!("{}\n{}\n", language.table_header(), language.table_rule());
+        for step in &self.steps {
+            table += &format!(
+                "{:4} | {} | {}\n",
+                step.hour,
+                format.format_width(step.vertical_velocity * 100.0, 20),
+                format.format_width(step.relative_vorticity * 1e5, 20)
+            );
+        }
+        table + &format!("\n{}\n", summary)
+    }
+}
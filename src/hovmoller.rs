@@ -0,0 +1,175 @@
+//! Diagramme de Hovmöller du tourbillon le long de la trajectoire
+//!
+//! À chaque heure, le tourbillon relatif est échantillonné le long du cercle de
+//! latitude du centre, sur un axe de longitudes fixe couvrant toute la
+//! trajectoire. Le profil horizontal est celui des anomalies : la trace du
+//! maximum dans le plan temps–longitude donne la vitesse de propagation du
+//! système.
+//!
+//! Les longitudes de l'axe sont dépliées pour rester croissantes à la traversée
+//! de l'antiméridien. Dans le repère lié à la dépression, l'axe est compté en
+//! écart de longitude au centre : le maximum reste sur l'axe 0.
+
+use std::fmt::Write;
+
+use crate::frame::Frame;
+use crate::stability::EARTH_RADIUS;
+use crate::{BaroclinicCyclogenesis, DevelopmentResult, MeteoError};
+
+/// Étendue et pas de l'axe des longitudes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HovmollerAxis {
+    half_width: f64,  // Marge de part et d'autre de la trajectoire (°)
+    spacing: f64,     // Pas en longitude (°)
+}
+
+impl Default for HovmollerAxis {
+    fn default() -> Self {
+        Self { half_width: 30.0, spacing: 1.0 }
+    }
+}
+
+impl HovmollerAxis {
+    /// Crée un axe de marge `half_width` et de pas `spacing` (°)
+    pub fn new(half_width: f64, spacing: f64) -> Result<Self, MeteoError> {
+        if !(half_width > 0.0 && half_width <= 180.0) {
+            return Err(MeteoError::InvalidGrid(format!("marge {}° hors de ]0, 180]", half_width)));
+        }
+        if !(spacing > 0.0 && spacing <= half_width) {
+            return Err(MeteoError::InvalidGrid(format!("pas {}° hors de ]0, {}]", spacing, half_width)));
+        }
+        Ok(Self { half_width, spacing })
+    }
+
+    /// Marge de part et d'autre de la trajectoire (°)
+    pub fn half_width(&self) -> f64 {
+        self.half_width
+    }
+
+    /// Pas en longitude (°)
+    pub fn spacing(&self) -> f64 {
+        self.spacing
+    }
+}
+
+/// Tourbillon relatif dans le plan temps–longitude
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hovmoller {
+    hours: Vec<u32>,
+    frame: Frame,                 // Repère de l'axe des longitudes
+    longitudes: Vec<f64>,         // Axe des longitudes, croissantes et dépliées, ou écarts au centre (°)
+    center_longitudes: Vec<f64>,  // Longitude du centre à chaque heure, dépliée (°)
+    vorticity: Vec<f64>,          // Tourbillon relatif (s⁻¹), heure par heure
+}
+
+impl Hovmoller {
+    /// Diagramme en longitudes géographiques, à partir de ses lignes déjà échantillonnées
+    pub(crate) fn from_rows(hours: Vec<u32>, longitudes: Vec<f64>, center_longitudes: Vec<f64>, vorticity: Vec<f64>) -> Self {
+        Self { hours, frame: Frame::Earth, longitudes, center_longitudes, vorticity }
+    }
+
+    /// Heures du diagramme
+    pub fn hours(&self) -> &[u32] {
+        &self.hours
+    }
+
+    /// Repère de l'axe : longitudes géographiques ou écarts au centre
+    pub fn frame(&self) -> Frame {
+        self.frame
+    }
+
+    /// Axe des longitudes, ou des écarts de longitude au centre (°)
+    pub fn longitudes(&self) -> &[f64] {
+        &self.longitudes
+    }
+
+    /// Longitude du centre à chaque heure (°)
+    pub fn center_longitudes(&self) -> &[f64] {
+        &self.center_longitudes
+    }
+
+    /// Tourbillon relatif (s⁻¹), rangé par heure puis par longitude
+    pub fn vorticity(&self) -> &[f64] {
+        &self.vorticity
+    }
+
+    /// Tourbillon relatif (s⁻¹) de la ligne d'indice `row`
+    pub fn row(&self, row: usize) -> Option<&[f64]> {
+        let columns = self.longitudes.len();
+        self.vorticity.get(row * columns..(row + 1) * columns)
+    }
+
+    /// Matrice CSV : les longitudes en première ligne, puis une ligne par heure
+    /// commençant par l'heure ; tourbillon en s⁻¹
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("hour");
+        for longitude in &self.longitudes {
+            let _ = write!(csv, ",{}", longitude);
+        }
+        csv += "\n";
+        for (index, hour) in self.hours.iter().enumerate() {
+            csv += &hour.to_string();
+            for value in self.row(index).unwrap_or_default() {
+                let _ = write!(csv, ",{:e}", value);
+            }
+            csv += "\n";
+        }
+        csv
+    }
+}
+
+impl BaroclinicCyclogenesis {
+    /// Diagramme de Hovmöller des résultats d'une simulation
+    ///
+    /// Sans position dans les résultats (plan f), le centre est la position
+    /// courante du système.
+    pub fn hovmoller(&self, results: &[DevelopmentResult], axis: &HovmollerAxis) -> Hovmoller {
+        self.hovmoller_in(results, axis, Frame::Earth)
+    }
+
+    /// Diagramme de Hovmöller dans le repère demandé
+    ///
+    /// Dans le repère lié à la dépression, l'axe couvre `axis` de part et
+    /// d'autre du centre, quelle que soit la trajectoire.
+    pub fn hovmoller_in(&self, results: &[DevelopmentResult], axis: &HovmollerAxis, frame: Frame) -> Hovmoller {
+        let mut center_longitudes: Vec<f64> = Vec::with_capacity(results.len());
+        for result in results {
+            let longitude = result.longitude.unwrap_or_else(|| self.longitude());
+            let unwrapped = match center_longitudes.last() {
+                Some(previous) => previous + (longitude - previous + 180.0).rem_euclid(360.0) - 180.0,
+                None => longitude,
+            };
+            center_longitudes.push(unwrapped);
+        }
+
+        let (west, east) = match frame {
+            Frame::Earth => (
+                center_longitudes.iter().copied().fold(f64::INFINITY, f64::min) - axis.half_width,
+                center_longitudes.iter().copied().fold(f64::NEG_INFINITY, f64::max) + axis.half_width,
+            ),
+            Frame::StormRelative => (-axis.half_width, axis.half_width),
+        };
+        let count = if results.is_empty() { 0 } else { ((east - west) / axis.spacing).round() as usize + 1 };
+        let longitudes: Vec<_> = (0..count).map(|index| west + index as f64 * axis.spacing).collect();
+
+        let (shape, radius) = (self.surface_anomaly().structure.shape(), self.mean_radius());
+        let mut vorticity = Vec::with_capacity(results.len() * longitudes.len());
+        for (result, center) in results.iter().zip(&center_longitudes) {
+            let latitude = result.latitude.unwrap_or_else(|| self.latitude());
+            let scale = EARTH_RADIUS * latitude.to_radians().cos() / radius;
+            let origin = if frame == Frame::Earth { *center } else { 0.0 };
+            for longitude in &longitudes {
+                let x = scale * (longitude - origin).to_radians();
+                vorticity.push(result.relative_vorticity * shape.profile(x));
+            }
+        }
+
+        Hovmoller {
+            hours: results.iter().map(|result| result.hour).collect(),
+            frame,
+            longitudes,
+            center_longitudes,
+            vorticity,
+        }
+    }
+}
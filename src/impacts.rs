@@ -0,0 +1,137 @@
+//! Première estimation de la surcote
+//!
+//! La surcote cumule deux contributions, sans marée ni propagation d'onde :
+//!
+//! - le baromètre inverse, η_p = −Δp/(ρ_e·g), soit environ 1 cm par hPa de
+//!   creusement de la pression centrale (voir [`crate::pressure`]) ;
+//! - la surélévation due au vent, à l'équilibre sur un plateau de largeur L et
+//!   de profondeur h : η_v = τ·L/(ρ_e·g·h), où τ = ρ_a·C_d·U·|U|·cos θ est la
+//!   tension du vent projetée sur la normale à la côte et C_d le coefficient de
+//!   traînée de Wu (1982), (0,8 + 0,065·U)·10⁻³.
+//!
+//! Le vent est le vent maximal de surface, supposé souffler de l'ouest sur le
+//! flanc équatorial d'une dépression (de l'est pour un anticyclone), dans les
+//! deux hémisphères. Un vent de terre abaisse le niveau de la mer.
+
+use crate::{BaroclinicCyclogenesis, DevelopmentResult, MeteoError, NumberFormat, OutputLanguage};
+
+/// Masse volumique de l'eau de mer (kg/m³)
+const SEAWATER_DENSITY: f64 = 1025.0;
+
+/// Masse volumique de l'air au niveau de la mer (kg/m³)
+const AIR_DENSITY: f64 = 1.225;
+
+/// Accélération de la pesanteur (m/s²)
+const GRAVITY: f64 = 9.81;
+
+/// Côte et plateau continental
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Coastline {
+    facing: f64,       // Direction vers le large (°, depuis le nord, sens horaire)
+    depth: f64,        // Profondeur moyenne du plateau (m)
+    shelf_width: f64,  // Largeur du plateau (m)
+}
+
+impl Default for Coastline {
+    /// Côte exposée à l'ouest, plateau de 100 km et 30 m de profondeur
+    fn default() -> Self {
+        Self { facing: 270.0, depth: 30.0, shelf_width: 1.0e5 }
+    }
+}
+
+impl Coastline {
+    /// Côte tournée vers `facing` (°), plateau de profondeur `depth` (m) et de largeur `shelf_width` (m)
+    pub fn new(facing: f64, depth: f64, shelf_width: f64) -> Result<Self, MeteoError> {
+        let valid = facing.is_finite() && [depth, shelf_width].iter().all(|value| *value > 0.0 && value.is_finite());
+        if !valid {
+            return Err(MeteoError::InvalidScenario(format!(
+                "côte invalide: orientation {}°, profondeur {} m, plateau de {} m",
+                facing, depth, shelf_width
+            )));
+        }
+        Ok(Self { facing: facing.rem_euclid(360.0), depth, shelf_width })
+    }
+
+    /// Direction vers le large (°)
+    pub fn facing(&self) -> f64 {
+        self.facing
+    }
+
+    /// Surélévation due au vent (m) venant de la direction `direction` (°)
+    pub fn wind_setup(&self, wind: f64, direction: f64) -> f64 {
+        let drag = (0.8 + 0.065 * wind) * 1e-3;
+        let onshore = (direction - self.facing).to_radians().cos();
+        AIR_DENSITY * drag * wind * wind * onshore * self.shelf_width / (SEAWATER_DENSITY * GRAVITY * self.depth)
+    }
+}
+
+/// Élévation du niveau de la mer due au baromètre inverse (m) pour un écart de pression (hPa)
+pub fn inverse_barometer(pressure_deficit: f64) -> f64 {
+    -pressure_deficit * 100.0 / (SEAWATER_DENSITY * GRAVITY)
+}
+
+/// Surcote estimée à une heure de la simulation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SurgeEstimate {
+    pub hour: u32,
+    pub inverse_barometer: f64,  // Contribution de la pression (m)
+    pub wind_setup: f64,         // Contribution du vent (m), négative par vent de terre
+    pub total: f64,              // Surcote (m)
+}
+
+impl BaroclinicCyclogenesis {
+    /// Estime la surcote sur une côte à chaque heure d'une simulation
+    pub fn storm_surge(&self, results: &[DevelopmentResult], coastline: &Coastline) -> Vec<SurgeEstimate> {
+        let direction = if self.surface_anomaly().is_cyclonic { 270.0 } else { 90.0 };
+        results
+            .iter()
+            .map(|result| {
+                let inverse_barometer = inverse_barometer(self.pressure_deficit(result));
+                let wind_setup = coastline.wind_setup(self.surface_wind(result), direction);
+                SurgeEstimate { hour: result.hour, inverse_barometer, wind_setup, total: inverse_barometer + wind_setup }
+            })
+            .collect()
+    }
+}
+
+/// Phrase signalant la surcote la plus forte
+pub fn describe_peak(surges: &[SurgeEstimate], language: OutputLanguage, format: &NumberFormat) -> String {
+    let Some(peak) = surges.iter().max_by(|a, b| a.total.total_cmp(&b.total)) else {
+        return match language {
+            OutputLanguage::French => "Aucune surcote estimée".to_string(),
+            OutputLanguage::English => "No surge estimated".to_string(),
+        };
+    };
+    let (total, pressure, wind) =
+        (format.format(peak.total), format.format(peak.inverse_barometer), format.format(peak.wind_setup));
+    match language {
+        OutputLanguage::French => format!(
+            "Surcote maximale : {} m (baromètre inverse {} m, vent {} m) à l'heure {}",
+            total, pressure, wind, peak.hour
+        ),
+        OutputLanguage::English => format!(
+            "Peak surge: {} m (inverse barometer {} m, wind setup {} m) at hour {}",
+            total, pressure, wind, peak.hour
+        ),
+    }
+}
+
+/// Tableau des contributions à la surcote, suivi de la surcote maximale
+pub fn to_table(surges: &[SurgeEstimate], language: OutputLanguage, format: &NumberFormat) -> String {
+    let header = match language {
+        OutputLanguage::French => "Heure | Baromètre (m)        | Vent (m)             | Surcote (m)",
+        OutputLanguage::English => "Hour  | Barometer (m)        | Wind setup (m)       | Surge (m)",
+    };
+
+    let mut table = format!("{}\n------|----------------------|----------------------|----------------------\n", header);
+    for surge in surges {
+        table += &format!(
+            "{:4} | {} | {} | {}\n",
+            surge.hour,
+            format.format_width(surge.inverse_barometer, 20),
+            format.format_width(surge.wind_setup, 20),
+            format.format_width(surge.total, 20)
+        );
+    }
+    table + &format!("\n{}\n", describe_peak(surges, language, format))
+}
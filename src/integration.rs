@@ -0,0 +1,285 @@
+//! Schéma d'intégration temporelle et pas de temps
+//!
+//! Chaque heure, les termes pronostiques de la simulation sont intégrés en
+//! sous-pas : la relaxation de la hauteur de couche limite vers son équilibre,
+//! celle de la correction du rappel vers les observations, la dérive de la
+//! trajectoire, ainsi que le déphasage relevé par
+//! [`BaroclinicCyclogenesis::simulate_with_diagnostics`].
+//!
+//! Le schéma explicite d'Euler devient instable lorsque le pas de temps dépasse
+//! l'échelle de relaxation d'un terme, ce qui arrive pour un rappel serré, aux
+//! hautes latitudes et sous un fort forçage. Le schéma semi-implicite traite la
+//! partie linéarisée de ces relaxations par la méthode des trapèzes, ce qui garde
+//! l'intégration stable quel que soit le pas ; il moyenne la vitesse de dérive
+//! au départ et à l'arrivée de chaque pas de la trajectoire (méthode de Heun).
+//!
+//! Avant l'intégration, la limite de stabilité est estimée pour chaque terme
+//! intégré et la plus contraignante est retenue. En explicite, l'amortissement
+//! σ·Δt d'une relaxation doit rester inférieur à 2 : σ = 1/τ pour le rappel et
+//! pour l'ajustement de la couche limite, σ est le taux de verrouillage du
+//! déphasage à l'intensité maximale atteinte sur la durée simulée. Pour le
+//! déphasage, le nombre de Courant k·ΔU·Δt, rotation par pas sous l'effet du
+//! cisaillement, est rapporté à titre indicatif : ce terme constant n'affecte
+//! pas la stabilité. La trajectoire, quel que soit le schéma, ne doit pas
+//! avancer de plus d'un rayon d'anomalie par pas : son nombre de Courant |c|·Δt/R
+//! reste inférieur à 1. Un pas trop long est signalé ou réduit selon la
+//! politique choisie.
+
+use std::ops::RangeInclusive;
+
+use serde::{Deserialize, Serialize};
+
+use crate::boundary_layer::ADJUSTMENT_TIME;
+use crate::{BaroclinicCyclogenesis, Float, MeteoError, NumberFormat, OutputLanguage};
+
+/// Pas de temps par défaut (s), soit soixante sous-pas par heure
+pub const DEFAULT_TIME_STEP: f64 = 60.0;
+
+/// Pas de temps acceptés (s)
+const TIME_STEP_RANGE: RangeInclusive<f64> = 1.0..=3600.0;
+
+/// Marge appliquée à la limite de stabilité lors de la réduction du pas
+const SAFETY_FACTOR: f64 = 0.9;
+
+/// Schéma d'intégration temporelle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IntegrationScheme {
+    #[default]
+    Explicit,
+    SemiImplicit,
+}
+
+impl IntegrationScheme {
+    /// Incrément sur un pas `dt` d'une variable de tendance `tendency`, dont la
+    /// dérivée par rapport à la variable vaut `jacobian`
+    ///
+    /// Seule la partie amortie (jacobien négatif) est traitée implicitement.
+    pub(crate) fn increment(self, tendency: f64, jacobian: f64, dt: f64) -> f64 {
+        match self {
+            IntegrationScheme::Explicit => dt * tendency,
+            IntegrationScheme::SemiImplicit => dt * tendency / (1.0 - 0.5 * dt * jacobian.min(0.0)),
+        }
+    }
+
+    /// Relaxe pendant une heure, au pas `dt` (s), une variable `value` vers la
+    /// cible `target` avec le temps de relaxation `timescale` (s)
+    pub(crate) fn relax(self, value: f64, target: f64, timescale: f64, dt: f64) -> f64 {
+        let steps = (3600.0 / dt).round() as u32;
+        (0..steps).fold(value, |value, _| value + self.increment((target - value) / timescale, -1.0 / timescale, dt))
+    }
+}
+
+/// Conduite à tenir face à un pas au-delà de la limite de stabilité
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StepPolicy {
+    /// Conserver le pas et signaler le dépassement
+    #[default]
+    Warn,
+    /// Réduire le pas sous la limite de stabilité
+    Reduce,
+}
+
+/// Terme intégré pas à pas
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SteppedTerm {
+    /// Déphasage diagnostique des anomalies
+    Phase,
+    /// Ajustement de la hauteur de couche limite
+    BoundaryLayer,
+    /// Correction du rappel vers les observations
+    Nudging,
+    /// Dérive de la trajectoire
+    Track,
+}
+
+impl SteppedTerm {
+    /// Complément de nom du terme dans les messages
+    fn label(self, language: OutputLanguage) -> &'static str {
+        match (language, self) {
+            (OutputLanguage::French, SteppedTerm::Phase) => "du déphasage",
+            (OutputLanguage::French, SteppedTerm::BoundaryLayer) => "de la couche limite",
+            (OutputLanguage::French, SteppedTerm::Nudging) => "du rappel",
+            (OutputLanguage::French, SteppedTerm::Track) => "de la trajectoire",
+            (OutputLanguage::English, SteppedTerm::Phase) => "of the phase shift",
+            (OutputLanguage::English, SteppedTerm::BoundaryLayer) => "of the boundary layer",
+            (OutputLanguage::English, SteppedTerm::Nudging) => "of the nudging",
+            (OutputLanguage::English, SteppedTerm::Track) => "of the track",
+        }
+    }
+}
+
+/// Limite de stabilité d'un terme intégré pour un pas de temps
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StabilityLimit {
+    pub term: SteppedTerm,
+    pub time_step: f64,      // Pas de temps retenu (s)
+    pub max_time_step: f64,  // Plus long pas stable (s)
+    pub courant: f64,        // Nombre de Courant : rotation du déphasage (rad) ou avancée de la trajectoire par rayon
+    pub damping: f64,        // Amortissement σ·Δt de la relaxation
+}
+
+impl StabilityLimit {
+    /// Le pas de temps respecte la limite de stabilité
+    pub fn is_stable(&self) -> bool {
+        self.time_step <= self.max_time_step
+    }
+
+    /// Pas de temps stable, arrondi à un diviseur de l'heure (s)
+    pub fn reduced_time_step(&self) -> f64 {
+        3600.0 / (3600.0 / (SAFETY_FACTOR * self.max_time_step)).ceil().min(3600.0)
+    }
+
+    /// Avertissement indiquant comment rétablir la stabilité, ou la réduction
+    /// appliquée selon la politique de pas
+    pub fn describe(&self, policy: StepPolicy, language: OutputLanguage, format: &NumberFormat) -> String {
+        let (time_step, limit) = (format.format(self.time_step), format.format(self.max_time_step));
+        let (courant, damping) = (format.format(self.courant), format.format(self.damping));
+        let (term, reduced) = (self.term.label(language), format.format(self.reduced_time_step()));
+        // Le schéma semi-implicite ne lève pas la limite de la trajectoire
+        let track = self.term == SteppedTerm::Track;
+        match (language, policy) {
+            (OutputLanguage::French, StepPolicy::Warn) => format!(
+                "Attention : pas de temps de {} s au-delà de la limite de stabilité de {} s {} \
+                 (amortissement {}, nombre de Courant {}) ; réduire `time_step`{}",
+                time_step,
+                limit,
+                term,
+                damping,
+                courant,
+                if track { "" } else { " ou choisir `scheme = \"semi_implicit\"`" }
+            ),
+            (OutputLanguage::French, StepPolicy::Reduce) => {
+                format!("Pas de temps réduit de {} s à {} s, sous la limite de stabilité de {} s {}", time_step, reduced, limit, term)
+            }
+            (OutputLanguage::English, StepPolicy::Warn) => format!(
+                "Warning: time step of {} s beyond the stability limit of {} s {} \
+                 (damping {}, Courant number {}); reduce `time_step`{}",
+                time_step,
+                limit,
+                term,
+                damping,
+                courant,
+                if track { "" } else { " or choose `scheme = \"semi_implicit\"`" }
+            ),
+            (OutputLanguage::English, StepPolicy::Reduce) => {
+                format!("Time step reduced from {} s to {} s, below the stability limit of {} s {}", time_step, reduced, limit, term)
+            }
+        }
+    }
+}
+
+impl<T: Float> BaroclinicCyclogenesis<T> {
+    /// Choisit le schéma d'intégration, explicite par défaut
+    pub fn with_scheme(mut self, scheme: IntegrationScheme) -> Self {
+        self.scheme = scheme;
+        self
+    }
+
+    /// Fixe le pas de temps de l'intégration (s), arrondi à un diviseur de l'heure
+    pub fn with_time_step(mut self, time_step: f64) -> Result<Self, MeteoError> {
+        if !TIME_STEP_RANGE.contains(&time_step) {
+            log_event!(warn, time_step, "pas de temps rejeté");
+            return Err(MeteoError::InvalidTimeStep(time_step));
+        }
+        self.time_step = time_step;
+        Ok(self)
+    }
+
+    /// Choisit la conduite à tenir face à un pas instable
+    pub fn with_step_policy(mut self, policy: StepPolicy) -> Self {
+        self.step_policy = policy;
+        self
+    }
+
+    /// Schéma d'intégration retenu
+    pub fn scheme(&self) -> IntegrationScheme {
+        self.scheme
+    }
+
+    /// Pas de temps effectif (s)
+    pub fn time_step(&self) -> f64 {
+        3600.0 / f64::from(self.substeps())
+    }
+
+    /// Politique de pas retenue
+    pub fn step_policy(&self) -> StepPolicy {
+        self.step_policy
+    }
+
+    /// Limite de stabilité du pas pour chaque terme intégré d'une simulation de
+    /// `time_steps` heures
+    ///
+    /// Le déphasage n'évolue pas à l'équateur ; la couche limite, le rappel et la
+    /// trajectoire ne comptent que s'ils sont actifs.
+    pub fn stability_limits(&self, time_steps: u32) -> Vec<StabilityLimit> {
+        let time_step = self.time_step();
+        let relaxation = |term, rate: f64| StabilityLimit {
+            term,
+            time_step,
+            max_time_step: match self.scheme {
+                IntegrationScheme::Explicit => 2.0 / rate,
+                IntegrationScheme::SemiImplicit => f64::INFINITY,
+            },
+            courant: 0.0,
+            damping: rate * time_step,
+        };
+        let mut limits = Vec::new();
+
+        if let Some(shear) = self.thermal_wind_shear() {
+            let last_hour = f64::from(self.first_hour.saturating_add(time_steps).saturating_sub(1));
+            let selectivity = (self.surface_anomaly().scale_selectivity() + self.altitude_anomaly().scale_selectivity()) / 2.0;
+            let intensity = 1.0 + last_hour / 12.0 * selectivity;
+            let (coupling, advection) = self.phase_terms(shear.to_f64() * intensity);
+            limits.push(StabilityLimit { courant: advection.abs() * time_step, ..relaxation(SteppedTerm::Phase, coupling.abs()) });
+        }
+        if self.boundary_layer.is_some() {
+            limits.push(relaxation(SteppedTerm::BoundaryLayer, 1.0 / ADJUSTMENT_TIME));
+        }
+        if let Some(nudging) = &self.nudging {
+            limits.push(relaxation(SteppedTerm::Nudging, 1.0 / nudging.timescale()));
+        }
+        if self.geometry.is_moving() {
+            // Dépression au départ, qui dérive vers le pôle
+            let latitude = self.latitude();
+            let (eastward, northward) = self.track_velocity(latitude, self.coriolis_parameter(latitude));
+            let crossing = self.mean_radius() / eastward.hypot(northward);
+            limits.push(StabilityLimit {
+                term: SteppedTerm::Track,
+                time_step,
+                max_time_step: crossing,
+                courant: time_step / crossing,
+                damping: 0.0,
+            });
+        }
+        limits
+    }
+
+    /// Limite de stabilité du terme le plus contraignant pour une simulation de
+    /// `time_steps` heures, `None` si aucun terme n'est intégré
+    pub fn stability_limit(&self, time_steps: u32) -> Option<StabilityLimit> {
+        self.stability_limits(time_steps).into_iter().min_by(|a, b| a.max_time_step.total_cmp(&b.max_time_step))
+    }
+
+    /// Applique la politique de pas avant une simulation de `time_steps` heures
+    /// et renvoie la limite dépassée, le cas échéant
+    pub fn apply_step_policy(&mut self, time_steps: u32) -> Option<StabilityLimit> {
+        let limit = self.stability_limit(time_steps).filter(|limit| !limit.is_stable())?;
+        match self.step_policy {
+            StepPolicy::Warn => {
+                log_event!(warn, time_step = limit.time_step, max_time_step = limit.max_time_step, "pas de temps instable");
+            }
+            StepPolicy::Reduce => {
+                self.time_step = limit.reduced_time_step();
+                log_event!(info, time_step = self.time_step, "pas de temps réduit");
+            }
+        }
+        Some(limit)
+    }
+
+    /// Nombre de pas d'intégration par heure
+    pub(crate) fn substeps(&self) -> u32 {
+        (3600.0 / self.time_step).round() as u32
+    }
+}
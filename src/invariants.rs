@@ -0,0 +1,53 @@
+//! Propriétés physiques que toute simulation doit respecter
+//!
+//! Ces prédicats servent aux tests par propriétés et peuvent vérifier les
+//! résultats d'une simulation dans un programme tiers. Ils s'appliquent aux
+//! simulations sans forçage diurne.
+
+use crate::DevelopmentResult;
+
+/// Signe attendu du tourbillon relatif : celui de l'anomalie thermique nette de la
+/// colonne, inversé dans l'hémisphère sud avec la force de Coriolis
+pub fn expected_vorticity_sign(surface_temp: f64, altitude_temp: f64, latitude: f64) -> f64 {
+    let net_anomaly = surface_temp + altitude_temp;
+    let sign = |value: f64| if value == 0.0 { 0.0 } else { value.signum() };
+    sign(net_anomaly) * sign(latitude)
+}
+
+/// Le tourbillon n'a jamais le signe opposé à celui de l'anomalie nette, et reste
+/// nul lorsque celle-ci est nulle
+pub fn vorticity_sign_matches_anomaly(
+    results: &[DevelopmentResult],
+    surface_temp: f64,
+    altitude_temp: f64,
+    latitude: f64,
+) -> bool {
+    let expected = expected_vorticity_sign(surface_temp, altitude_temp, latitude);
+    results.iter().all(|result| {
+        if expected == 0.0 {
+            result.relative_vorticity == 0.0
+        } else {
+            result.relative_vorticity * expected >= 0.0
+        }
+    })
+}
+
+/// Aucun développement : vitesse verticale et tourbillon nuls à chaque pas
+pub fn is_quiescent(results: &[DevelopmentResult]) -> bool {
+    results
+        .iter()
+        .all(|result| result.vertical_velocity == 0.0 && result.relative_vorticity == 0.0)
+}
+
+/// Les résultats de deux simulations symétriques par rapport à l'équateur sont
+/// opposés, à la tolérance relative près
+pub fn is_antisymmetric(north: &[DevelopmentResult], south: &[DevelopmentResult], tolerance: f64) -> bool {
+    let opposite = |a: f64, b: f64| (a + b).abs() <= tolerance * a.abs().max(b.abs());
+
+    north.len() == south.len()
+        && north.iter().zip(south).all(|(n, s)| {
+            n.hour == s.hour
+                && opposite(n.vertical_velocity, s.vertical_velocity)
+                && opposite(n.relative_vorticity, s.relative_vorticity)
+        })
+}
@@ -0,0 +1,122 @@
+//! Export des résultats au format Apache Arrow IPC
+//!
+//! [`record_batch`] convertit des [`ResultColumns`] en un `RecordBatch` de
+//! `arrow-array`, à passer tel quel à Polars, DataFusion ou pyarrow.
+//! [`ArrowWriter`] écrit un fichier `.arrow` (format « file » d'Arrow), un lot
+//! par appel à [`ArrowWriter::write`] ; [`to_stream`] rend un seul lot au
+//! format « stream », à lire par `arrow_ipc::reader::StreamReader` ou
+//! `pyarrow.ipc.open_stream`.
+//!
+//! Schéma, en unités SI :
+//!
+//! | Colonne                  | Type Arrow                    | Nulle |
+//! |--------------------------|-------------------------------|-------|
+//! | `hour`                   | `UInt32`                      | non   |
+//! | `vertical_velocity`      | `Float64` (m/s)               | non   |
+//! | `relative_vorticity`     | `Float64` (s⁻¹)               | non   |
+//! | `low_level_divergence`   | `Float64` (s⁻¹)               | non   |
+//! | `upper_level_divergence` | `Float64` (s⁻¹)               | non   |
+//! | `valid_time`             | `Timestamp(ms, "UTC")`        | oui   |
+//! | `latitude`               | `Float64` (°)                 | oui   |
+//! | `longitude`              | `Float64` (°)                 | oui   |
+
+use std::io::Write;
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, Float64Array, RecordBatch, TimestampMillisecondArray, UInt32Array};
+use arrow_ipc::writer::{FileWriter, StreamWriter};
+use arrow_schema::{ArrowError, DataType, Field, Schema, SchemaRef, TimeUnit};
+
+use crate::{Float, MeteoError, ResultColumns};
+
+/// Schéma des lots d'enregistrements
+pub fn schema() -> SchemaRef {
+    let quantity = |name| Field::new(name, DataType::Float64, false);
+    Arc::new(Schema::new(vec![
+        Field::new("hour", DataType::UInt32, false),
+        quantity("vertical_velocity"),
+        quantity("relative_vorticity"),
+        quantity("low_level_divergence"),
+        quantity("upper_level_divergence"),
+        Field::new("valid_time", DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into())), true),
+        Field::new("latitude", DataType::Float64, true),
+        Field::new("longitude", DataType::Float64, true),
+    ]))
+}
+
+/// Lot d'enregistrements des colonnes d'une simulation
+pub fn record_batch<T: Float>(columns: &ResultColumns<T>) -> Result<RecordBatch, MeteoError> {
+    let quantity = |values: &[T]| Arc::new(values.iter().map(|value| value.to_f64()).collect::<Float64Array>()) as ArrayRef;
+    let arrays = vec![
+        Arc::new(UInt32Array::from(columns.hours().to_vec())) as ArrayRef,
+        quantity(columns.vertical_velocity()),
+        quantity(columns.relative_vorticity()),
+        quantity(columns.low_level_divergence()),
+        quantity(columns.upper_level_divergence()),
+        Arc::new(
+            columns
+                .valid_times()
+                .iter()
+                .map(|time| time.map(|time| time.timestamp_millis()))
+                .collect::<TimestampMillisecondArray>()
+                .with_timezone("UTC"),
+        ),
+        Arc::new(Float64Array::from(columns.latitudes().to_vec())),
+        Arc::new(Float64Array::from(columns.longitudes().to_vec())),
+    ];
+    RecordBatch::try_new(schema(), arrays).map_err(arrow_error)
+}
+
+/// Écrivain d'un fichier Arrow IPC, un lot d'enregistrements par simulation
+pub struct ArrowWriter<W: Write> {
+    writer: FileWriter<W>,
+    batches: usize,
+}
+
+impl<W: Write> ArrowWriter<W> {
+    /// Écrit la signature et le schéma
+    pub fn new(sink: W) -> Result<Self, MeteoError> {
+        Ok(Self { writer: FileWriter::try_new(sink, &schema()).map_err(arrow_error)?, batches: 0 })
+    }
+
+    /// Ajoute les colonnes d'une simulation en un lot
+    pub fn write<T: Float>(&mut self, columns: &ResultColumns<T>) -> Result<(), MeteoError> {
+        self.writer.write(&record_batch(columns)?).map_err(arrow_error)?;
+        self.batches += 1;
+        Ok(())
+    }
+
+    /// Nombre de lots écrits
+    pub fn len(&self) -> usize {
+        self.batches
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.batches == 0
+    }
+
+    /// Écrit le pied de page et rend la destination
+    pub fn finish(mut self) -> Result<W, MeteoError> {
+        self.writer.finish().map_err(arrow_error)?;
+        self.writer.into_inner().map_err(arrow_error)
+    }
+}
+
+/// Écrit les colonnes d'une simulation dans un fichier Arrow IPC d'un seul lot
+pub fn write<W: Write, T: Float>(sink: W, columns: &ResultColumns<T>) -> Result<W, MeteoError> {
+    let mut writer = ArrowWriter::new(sink)?;
+    writer.write(columns)?;
+    writer.finish()
+}
+
+/// Flux Arrow IPC d'un seul lot : schéma, lot, marqueur de fin
+pub fn to_stream<T: Float>(columns: &ResultColumns<T>) -> Result<Vec<u8>, MeteoError> {
+    let mut writer = StreamWriter::try_new(Vec::new(), &schema()).map_err(arrow_error)?;
+    writer.write(&record_batch(columns)?).map_err(arrow_error)?;
+    writer.finish().map_err(arrow_error)?;
+    writer.into_inner().map_err(arrow_error)
+}
+
+fn arrow_error(error: ArrowError) -> MeteoError {
+    MeteoError::InvalidBinary(format!("écriture Arrow: {}", error))
+}
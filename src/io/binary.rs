@@ -0,0 +1,226 @@
+//! Format binaire versionné des fichiers de résultats
+//!
+//! Un fichier commence par un en-tête de 24 octets, en petit-boutiste :
+//!
+//! | Octets | Contenu                                                     |
+//! |--------|-------------------------------------------------------------|
+//! | 0–7    | signature `CYCLOGEN`                                        |
+//! | 8      | version majeure du schéma                                   |
+//! | 9      | version mineure du schéma                                   |
+//! | 10–11  | taille de l'en-tête (octets, `u16`)                         |
+//! | 12–13  | taille d'un enregistrement (octets, `u16`)                  |
+//! | 14–15  | réservé, nul                                                |
+//! | 16–23  | empreinte du scénario simulé (FNV-1a 64 bits de son JSON)  |
+//!
+//! Suivent les résultats, un enregistrement par heure, dont les 64 premiers
+//! octets suivent la disposition de [`crate::io::records`].
+//!
+//! Compatibilité ascendante : une version mineure ne peut qu'ajouter des champs
+//! à la fin de l'en-tête ou des enregistrements, dont les tailles sont écrites
+//! dans le fichier. Un lecteur saute les octets qu'il ne connaît pas et lit
+//! donc les fichiers de toute version mineure de sa version majeure ; une
+//! version majeure différente est refusée.
+
+use std::io::{ErrorKind, Read, Write};
+
+use crate::io::records::{self, RECORD_SIZE};
+use crate::{DevelopmentResult, MeteoError, Scenario};
+
+/// Signature en tête de fichier
+pub const MAGIC: [u8; 8] = *b"CYCLOGEN";
+
+/// Version majeure du schéma écrit ; les lecteurs refusent les autres
+pub const MAJOR_VERSION: u8 = 1;
+
+/// Version mineure du schéma écrit
+pub const MINOR_VERSION: u8 = 0;
+
+/// Taille de l'en-tête de la version 1.0 (octets)
+pub const HEADER_SIZE: usize = 24;
+
+/// En-tête d'un fichier de résultats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Header {
+    pub major: u8,
+    pub minor: u8,
+    pub header_size: u16,    // Taille totale de l'en-tête (octets)
+    pub record_size: u16,    // Taille d'un enregistrement (octets)
+    pub scenario_hash: u64,  // Empreinte du scénario, voir [`scenario_hash`]
+}
+
+impl Header {
+    /// En-tête de la version courante pour un scénario
+    pub fn new(scenario: &Scenario) -> Self {
+        Self {
+            major: MAJOR_VERSION,
+            minor: MINOR_VERSION,
+            header_size: HEADER_SIZE as u16,
+            record_size: RECORD_SIZE as u16,
+            scenario_hash: scenario_hash(scenario),
+        }
+    }
+
+    /// Le fichier a-t-il été produit par ce scénario ?
+    pub fn matches(&self, scenario: &Scenario) -> bool {
+        self.scenario_hash == scenario_hash(scenario)
+    }
+
+    pub(crate) fn encode(&self) -> [u8; HEADER_SIZE] {
+        let mut header = [0; HEADER_SIZE];
+        header[0..8].copy_from_slice(&MAGIC);
+        header[8] = self.major;
+        header[9] = self.minor;
+        header[10..12].copy_from_slice(&self.header_size.to_le_bytes());
+        header[12..14].copy_from_slice(&self.record_size.to_le_bytes());
+        header[16..24].copy_from_slice(&self.scenario_hash.to_le_bytes());
+        header
+    }
+
+    /// Lit et vérifie les 24 premiers octets d'un en-tête
+    pub(crate) fn decode(bytes: &[u8; HEADER_SIZE]) -> Result<Self, MeteoError> {
+        if bytes[0..8] != MAGIC {
+            return Err(invalid("signature CYCLOGEN absente"));
+        }
+        let size = |start: usize| u16::from_le_bytes([bytes[start], bytes[start + 1]]);
+        let header = Header {
+            major: bytes[8],
+            minor: bytes[9],
+            header_size: size(10),
+            record_size: size(12),
+            scenario_hash: u64::from_le_bytes(bytes[16..24].try_into().expect("8 octets")),
+        };
+        if header.major != MAJOR_VERSION {
+            return Err(invalid(&format!(
+                "version {}.{} non prise en charge (version {} attendue)",
+                header.major, header.minor, MAJOR_VERSION
+            )));
+        }
+        if usize::from(header.header_size) < HEADER_SIZE || usize::from(header.record_size) < RECORD_SIZE {
+            return Err(invalid(&format!(
+                "en-tête de {} octets ou enregistrements de {} octets trop courts",
+                header.header_size, header.record_size
+            )));
+        }
+        Ok(header)
+    }
+}
+
+/// Empreinte d'un scénario : FNV-1a 64 bits de sa sérialisation JSON
+pub fn scenario_hash(scenario: &Scenario) -> u64 {
+    let text = serde_json::to_string(scenario).expect("scénario sérialisable");
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3))
+}
+
+/// Écrivain d'un fichier de résultats
+pub struct BinaryWriter<W: Write> {
+    sink: W,
+    count: u64,
+}
+
+impl<W: Write> BinaryWriter<W> {
+    /// Écrit l'en-tête des résultats d'un scénario
+    pub fn new(mut sink: W, scenario: &Scenario) -> Result<Self, MeteoError> {
+        sink.write_all(&Header::new(scenario).encode()).map_err(write_error)?;
+        Ok(Self { sink, count: 0 })
+    }
+
+    /// Ajoute un résultat
+    pub fn write(&mut self, result: &DevelopmentResult) -> Result<(), MeteoError> {
+        self.sink.write_all(&records::encode(result)).map_err(write_error)?;
+        self.count += 1;
+        Ok(())
+    }
+
+    /// Nombre de résultats écrits
+    pub fn len(&self) -> u64 {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Vide le tampon et rend la destination
+    pub fn finish(mut self) -> Result<W, MeteoError> {
+        self.sink.flush().map_err(write_error)?;
+        Ok(self.sink)
+    }
+}
+
+/// Lecteur d'un fichier de résultats, itérant sur ses enregistrements
+pub struct BinaryReader<R: Read> {
+    source: R,
+    header: Header,
+    record: Vec<u8>,
+}
+
+impl<R: Read> BinaryReader<R> {
+    /// Lit et vérifie l'en-tête, en sautant les champs d'une version mineure ultérieure
+    pub fn new(mut source: R) -> Result<Self, MeteoError> {
+        let mut bytes = [0; HEADER_SIZE];
+        source.read_exact(&mut bytes).map_err(|_| invalid("en-tête incomplet"))?;
+        let header = Header::decode(&bytes)?;
+
+        let extra = u64::from(header.header_size) - HEADER_SIZE as u64;
+        let skipped = std::io::copy(&mut (&mut source).take(extra), &mut std::io::sink()).map_err(read_error)?;
+        if skipped < extra {
+            return Err(invalid("en-tête incomplet"));
+        }
+        Ok(Self { source, header, record: vec![0; usize::from(header.record_size)] })
+    }
+
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+}
+
+impl<R: Read> Iterator for BinaryReader<R> {
+    type Item = Result<DevelopmentResult, MeteoError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut filled = 0;
+        while filled < self.record.len() {
+            match self.source.read(&mut self.record[filled..]) {
+                Ok(0) if filled == 0 => return None,
+                Ok(0) => return Some(Err(invalid("enregistrement tronqué en fin de fichier"))),
+                Ok(read) => filled += read,
+                Err(error) if error.kind() == ErrorKind::Interrupted => {}
+                Err(error) => return Some(Err(read_error(error))),
+            }
+        }
+        // Enregistrements préalloués par une projection interrompue (`io::records`)
+        if self.record.iter().all(|&byte| byte == 0) {
+            return None;
+        }
+        // Les champs d'une version mineure ultérieure suivent les 64 premiers octets
+        Some(records::decode(self.record[..RECORD_SIZE].try_into().expect("enregistrement complet")))
+    }
+}
+
+/// Écrit les résultats d'un scénario
+pub fn write<W: Write>(sink: W, scenario: &Scenario, results: &[DevelopmentResult]) -> Result<W, MeteoError> {
+    let mut writer = BinaryWriter::new(sink, scenario)?;
+    for result in results {
+        writer.write(result)?;
+    }
+    writer.finish()
+}
+
+/// Lit l'en-tête et les résultats d'un fichier
+pub fn read<R: Read>(source: R) -> Result<(Header, Vec<DevelopmentResult>), MeteoError> {
+    let reader = BinaryReader::new(source)?;
+    let header = *reader.header();
+    Ok((header, reader.collect::<Result<_, _>>()?))
+}
+
+fn invalid(message: &str) -> MeteoError {
+    MeteoError::InvalidBinary(message.to_string())
+}
+
+fn read_error(error: std::io::Error) -> MeteoError {
+    invalid(&format!("lecture: {}", error))
+}
+
+fn write_error(error: std::io::Error) -> MeteoError {
+    invalid(&format!("écriture: {}", error))
+}
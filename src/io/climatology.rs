@@ -0,0 +1,54 @@
+//! Lecture d'une climatologie zonale au format CSV
+//!
+//! La première ligne nomme la colonne des latitudes, puis donne les niveaux de
+//! pression (hPa) ; chaque ligne suivante commence par une latitude (°) et
+//! donne la température (K) à chaque niveau :
+//!
+//! ```text
+//! latitude,1000,850,500,250,200
+//! 30,298.0,290.5,266.5,229.0,221.5
+//! 45,288.0,279.0,255.0,221.0,219.0
+//! ```
+//!
+//! Les lignes vides et celles qui commencent par `#` sont ignorées. Latitudes
+//! et niveaux peuvent venir dans un ordre quelconque.
+
+use crate::{Climatology, MeteoError};
+
+/// Lit une table zonale de température
+pub fn parse_csv(text: &str) -> Result<Climatology, MeteoError> {
+    let mut lines = text
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'));
+
+    let (_, header) = lines.next().ok_or_else(|| invalid("fichier vide"))?;
+    let mut columns = header.split(',').map(str::trim);
+    if !columns.next().is_some_and(|name| ["latitude", "lat"].contains(&name.to_lowercase().as_str())) {
+        return Err(invalid("première colonne latitude absente"));
+    }
+    let pressures = columns.map(|cell| number(cell, 1)).collect::<Result<Vec<_>, _>>()?;
+
+    let (mut latitudes, mut temperature) = (Vec::new(), Vec::new());
+    for (line_number, line) in lines {
+        let cells = line.split(',').map(str::trim).map(|cell| number(cell, line_number)).collect::<Result<Vec<_>, _>>()?;
+        if cells.len() != pressures.len() + 1 {
+            return Err(invalid(&format!("ligne {}: {} valeurs pour {} niveaux", line_number, cells.len() - 1, pressures.len())));
+        }
+        latitudes.push(cells[0]);
+        temperature.push(cells[1..].to_vec());
+    }
+    Climatology::from_table(latitudes, pressures, temperature)
+}
+
+fn number(cell: &str, line_number: usize) -> Result<f64, MeteoError> {
+    cell.parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite())
+        .ok_or_else(|| invalid(&format!("ligne {}: valeur invalide {}", line_number, cell)))
+}
+
+fn invalid(message: &str) -> MeteoError {
+    MeteoError::InvalidClimatology(message.to_string())
+}
@@ -0,0 +1,336 @@
+//! Lecture de sous-ensembles ERA5 (NetCDF-3) pour l'étude de tempêtes passées
+//!
+//! Les fichiers NetCDF-4 (HDF5), format par défaut du Climate Data Store, sont
+//! refusés : les convertir avec `nccopy -k classic`. Le fichier doit contenir la
+//! température `t` sur niveaux de pression. Le long
+//! d'une trajectoire, les anomalies sont mesurées par rapport à la moyenne du
+//! domaine au même instant et au même niveau, ce qui isole la perturbation de
+//! son environnement. La moyenne sur les instants et les longitudes donne une
+//! climatologie zonale.
+
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use netcdf3::{DataSet, DataVector, FileReader, Variable};
+
+use crate::io::sounding::standard_height;
+use crate::{BaroclinicCyclogenesis, Climatology, MeteoError, PhysicalConstants, Position, ThermalAnomaly};
+
+const TEMPERATURE_VAR: &str = "t";
+
+/// Signature d'un fichier HDF5, conteneur des fichiers NetCDF-4
+const HDF5_SIGNATURE: [u8; 8] = *b"\x89HDF\r\n\x1a\n";
+
+/// Noms des coordonnées selon les versions du service Copernicus
+const TIME_NAMES: [&str; 2] = ["time", "valid_time"];
+const LEVEL_NAMES: [&str; 2] = ["level", "pressure_level"];
+const LATITUDE_NAMES: [&str; 2] = ["latitude", "lat"];
+const LONGITUDE_NAMES: [&str; 2] = ["longitude", "lon"];
+
+/// Lit la climatologie zonale d'un sous-ensemble ERA5 (moyennes mensuelles par exemple)
+pub fn read_climatology<P: AsRef<Path>>(path: P) -> Result<Climatology, MeteoError> {
+    Era5Dataset::open(path)?.zonal_mean()
+}
+
+/// Point de la trajectoire observée d'une tempête
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrackPoint {
+    pub time_index: usize,  // Indice dans la coordonnée temporelle du fichier
+    pub latitude: f64,      // Latitude du centre (°)
+    pub longitude: f64,     // Longitude du centre (°)
+}
+
+/// Scénario reconstruit en un point de la trajectoire
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackScenario {
+    pub time: f64,            // Valeur de la coordonnée temporelle
+    pub latitude: f64,
+    pub longitude: f64,
+    pub surface_level: f64,   // Niveau de l'anomalie de surface (hPa)
+    pub altitude_level: f64,  // Niveau de l'anomalie d'altitude (hPa)
+    pub surface_temp: f64,    // Anomalie thermique de surface (K)
+    pub altitude_temp: f64,   // Anomalie thermique d'altitude (K)
+}
+
+impl TrackScenario {
+    /// Construit la simulation correspondant à ce point de la trajectoire
+    pub fn to_cyclogenesis(&self) -> Result<BaroclinicCyclogenesis, MeteoError> {
+        let constants = PhysicalConstants::default();
+        let position = |level: f64| Position::new(self.latitude, standard_height(level), level);
+
+        let surface_anomaly = ThermalAnomaly::new(self.surface_temp, position(self.surface_level)?, constants)?;
+        let altitude_anomaly = ThermalAnomaly::new(self.altitude_temp, position(self.altitude_level)?, constants)?;
+
+        Ok(BaroclinicCyclogenesis::from_anomalies(surface_anomaly, altitude_anomaly))
+    }
+}
+
+/// Champ de température ERA5 chargé en mémoire
+#[derive(Debug, Clone)]
+pub struct Era5Dataset {
+    times: Vec<f64>,
+    time_units: Option<String>,
+    levels: Vec<f64>,
+    latitudes: Vec<f64>,
+    longitudes: Vec<f64>,
+    temperature: Vec<f64>,  // Valeurs décompressées (K), NaN si manquantes
+    strides: [usize; 4],    // Pas mémoire des axes temps, niveau, latitude, longitude
+}
+
+impl Era5Dataset {
+    /// Ouvre un fichier NetCDF-3 issu du Climate Data Store
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, MeteoError> {
+        log_event!(info, path = %path.as_ref().display(), "ouverture du fichier ERA5");
+        if is_hdf5(path.as_ref()) {
+            return Err(invalid(&format!(
+                "{}: fichier NetCDF-4, seuls les fichiers NetCDF-3 sont lus (convertir avec `nccopy -k classic`)",
+                path.as_ref().display()
+            )));
+        }
+        let mut reader = FileReader::open(path).map_err(|e| {
+            invalid(&format!("{} (seuls les fichiers NetCDF-3 sont lus, convertir avec `nccopy -k classic`)", e))
+        })?;
+
+        let data_set = reader.data_set();
+        let time_name = find_name(data_set, &TIME_NAMES)?;
+        let level_name = find_name(data_set, &LEVEL_NAMES)?;
+        let latitude_name = find_name(data_set, &LATITUDE_NAMES)?;
+        let longitude_name = find_name(data_set, &LONGITUDE_NAMES)?;
+
+        let variable = data_set
+            .get_var(TEMPERATURE_VAR)
+            .ok_or_else(|| invalid("variable de température `t` absente"))?;
+
+        // Les axes peuvent être rangés dans n'importe quel ordre
+        let dims = variable.dim_names();
+        if dims.len() != 4 {
+            return Err(invalid("`t` doit avoir exactement quatre dimensions"));
+        }
+        let sizes: Vec<usize> = variable.get_dims().iter().map(|dim| dim.size()).collect();
+        let mut strides = [0; 4];
+        for (axis, name) in [time_name, level_name, latitude_name, longitude_name].iter().enumerate() {
+            let position = dims
+                .iter()
+                .position(|dim| dim == name)
+                .ok_or_else(|| invalid(&format!("`t` ne dépend pas de la dimension `{}`", name)))?;
+            strides[axis] = sizes[position + 1..].iter().product();
+        }
+
+        let time_units = data_set
+            .get_var(time_name)
+            .and_then(|var| var.get_attr_as_string("units"));
+
+        log_event!(debug, time = time_name, level = level_name, ?strides, "coordonnées ERA5 identifiées");
+        Ok(Self {
+            times: read_values(&mut reader, time_name)?,
+            time_units,
+            levels: read_values(&mut reader, level_name)?,
+            latitudes: read_values(&mut reader, latitude_name)?,
+            longitudes: read_values(&mut reader, longitude_name)?,
+            temperature: read_values(&mut reader, TEMPERATURE_VAR)?,
+            strides,
+        })
+    }
+
+    /// Valeurs de la coordonnée temporelle
+    pub fn times(&self) -> &[f64] {
+        &self.times
+    }
+
+    /// Unités de la coordonnée temporelle (par exemple `hours since 1900-01-01`)
+    pub fn time_units(&self) -> Option<&str> {
+        self.time_units.as_deref()
+    }
+
+    /// Niveaux de pression disponibles (hPa)
+    pub fn levels(&self) -> &[f64] {
+        &self.levels
+    }
+
+    /// Température au point de grille le plus proche (K)
+    pub fn temperature(
+        &self,
+        time_index: usize,
+        level: f64,
+        latitude: f64,
+        longitude: f64,
+    ) -> Result<f64, MeteoError> {
+        let level_index = self.level_index(level)?;
+        let index = self.offset(time_index, level_index)?
+            + nearest(&self.latitudes, latitude).ok_or_else(|| invalid("aucune latitude dans le fichier"))? * self.strides[2]
+            + nearest_longitude(&self.longitudes, longitude).ok_or_else(|| invalid("aucune longitude dans le fichier"))?
+                * self.strides[3];
+
+        Ok(self.temperature[index])
+    }
+
+    /// Reconstruit un scénario en chaque point de la trajectoire
+    pub fn track_scenarios(
+        &self,
+        track: &[TrackPoint],
+        surface_level: f64,
+        altitude_level: f64,
+    ) -> Result<Vec<TrackScenario>, MeteoError> {
+        track
+            .iter()
+            .map(|point| {
+                let anomaly = |level: f64| -> Result<f64, MeteoError> {
+                    let value = self.temperature(point.time_index, level, point.latitude, point.longitude)?;
+                    if value.is_nan() {
+                        return Err(invalid(&format!(
+                            "température manquante à {} hPa au point {}°, {}°",
+                            level, point.latitude, point.longitude
+                        )));
+                    }
+                    Ok(value - self.domain_mean(point.time_index, self.level_index(level)?)?)
+                };
+
+                Ok(TrackScenario {
+                    time: self.times[point.time_index],
+                    latitude: point.latitude,
+                    longitude: point.longitude,
+                    surface_level,
+                    altitude_level,
+                    surface_temp: anomaly(surface_level)?,
+                    altitude_temp: anomaly(altitude_level)?,
+                })
+            })
+            .collect()
+    }
+
+    /// Climatologie zonale : moyenne de la température sur les instants et les
+    /// longitudes du fichier, à chaque latitude et chaque niveau
+    pub fn zonal_mean(&self) -> Result<Climatology, MeteoError> {
+        let temperature = (0..self.latitudes.len())
+            .map(|j| {
+                (0..self.levels.len())
+                    .map(|k| {
+                        let (sum, count) = (0..self.times.len())
+                            .flat_map(|t| (0..self.longitudes.len()).map(move |i| (t, i)))
+                            .map(|(t, i)| {
+                                self.temperature[t * self.strides[0] + k * self.strides[1] + j * self.strides[2] + i * self.strides[3]]
+                            })
+                            .filter(|value| !value.is_nan())
+                            .fold((0.0, 0), |(sum, count), value| (sum + value, count + 1));
+                        if count == 0 {
+                            return Err(invalid(&format!(
+                                "aucune valeur valide à {}° et {} hPa",
+                                self.latitudes[j], self.levels[k]
+                            )));
+                        }
+                        Ok(sum / count as f64)
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Climatology::from_table(self.latitudes.clone(), self.levels.clone(), temperature)
+    }
+
+    /// Moyenne spatiale de la température à un instant et un niveau donnés
+    fn domain_mean(&self, time_index: usize, level_index: usize) -> Result<f64, MeteoError> {
+        let offset = self.offset(time_index, level_index)?;
+        let (sum, count) = (0..self.latitudes.len())
+            .flat_map(|j| (0..self.longitudes.len()).map(move |i| (j, i)))
+            .map(|(j, i)| self.temperature[offset + j * self.strides[2] + i * self.strides[3]])
+            .filter(|value| !value.is_nan())
+            .fold((0.0, 0), |(sum, count), value| (sum + value, count + 1));
+
+        if count == 0 {
+            return Err(invalid("aucune valeur valide sur le domaine"));
+        }
+        Ok(sum / count as f64)
+    }
+
+    fn level_index(&self, level: f64) -> Result<usize, MeteoError> {
+        self.levels
+            .iter()
+            .position(|candidate| (candidate - level).abs() < 1e-6)
+            .ok_or_else(|| invalid(&format!("niveau {} hPa absent du fichier", level)))
+    }
+
+    fn offset(&self, time_index: usize, level_index: usize) -> Result<usize, MeteoError> {
+        if time_index >= self.times.len() {
+            return Err(invalid(&format!("indice temporel {} hors du fichier", time_index)));
+        }
+        Ok(time_index * self.strides[0] + level_index * self.strides[1])
+    }
+}
+
+fn invalid(message: &str) -> MeteoError {
+    MeteoError::InvalidNetcdf(message.to_string())
+}
+
+fn find_name<'a>(data_set: &DataSet, candidates: &[&'a str]) -> Result<&'a str, MeteoError> {
+    candidates
+        .iter()
+        .copied()
+        .find(|name| data_set.has_var(name))
+        .ok_or_else(|| invalid(&format!("coordonnée {} absente", candidates.join("/"))))
+}
+
+/// Lit une variable en appliquant `scale_factor`, `add_offset` et les valeurs manquantes
+fn read_values(reader: &mut FileReader, name: &str) -> Result<Vec<f64>, MeteoError> {
+    let variable = reader
+        .data_set()
+        .get_var(name)
+        .ok_or_else(|| invalid(&format!("variable `{}` absente", name)))?;
+    let scale = numeric_attr(variable, "scale_factor").unwrap_or(1.0);
+    let offset = numeric_attr(variable, "add_offset").unwrap_or(0.0);
+    let missing = [numeric_attr(variable, "_FillValue"), numeric_attr(variable, "missing_value")];
+
+    let raw: Vec<f64> = match reader.read_var(name).map_err(|e| invalid(&e.to_string()))? {
+        DataVector::I8(values) => values.into_iter().map(f64::from).collect(),
+        DataVector::U8(values) => values.into_iter().map(f64::from).collect(),
+        DataVector::I16(values) => values.into_iter().map(f64::from).collect(),
+        DataVector::I32(values) => values.into_iter().map(f64::from).collect(),
+        DataVector::F32(values) => values.into_iter().map(f64::from).collect(),
+        DataVector::F64(values) => values,
+    };
+
+    Ok(raw
+        .into_iter()
+        .map(|value| {
+            if missing.contains(&Some(value)) {
+                f64::NAN
+            } else {
+                value * scale + offset
+            }
+        })
+        .collect())
+}
+
+fn numeric_attr(variable: &Variable, name: &str) -> Option<f64> {
+    variable
+        .get_attr_f64(name)
+        .and_then(|values| values.first().copied())
+        .or_else(|| variable.get_attr_f32(name).and_then(|v| v.first().map(|&x| f64::from(x))))
+        .or_else(|| variable.get_attr_i32(name).and_then(|v| v.first().map(|&x| f64::from(x))))
+        .or_else(|| variable.get_attr_i16(name).and_then(|v| v.first().map(|&x| f64::from(x))))
+        .or_else(|| variable.get_attr_i8(name).and_then(|v| v.first().map(|&x| f64::from(x))))
+        .or_else(|| variable.get_attr_u8(name).and_then(|v| v.first().map(|&x| f64::from(x))))
+}
+
+/// Vrai si le fichier commence par la signature HDF5
+fn is_hdf5(path: &Path) -> bool {
+    let mut signature = [0; 8];
+    File::open(path).and_then(|mut file| file.read_exact(&mut signature)).is_ok() && signature == HDF5_SIGNATURE
+}
+
+/// Indice de la coordonnée la plus proche, `None` pour une dimension vide
+fn nearest(coordinates: &[f64], value: f64) -> Option<usize> {
+    coordinates
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| (*a - value).abs().total_cmp(&(*b - value).abs()))
+        .map(|(index, _)| index)
+}
+
+fn nearest_longitude(longitudes: &[f64], value: f64) -> Option<usize> {
+    let distance = |lon: f64| ((lon - value + 540.0).rem_euclid(360.0) - 180.0).abs();
+    longitudes
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| distance(**a).total_cmp(&distance(**b)))
+        .map(|(index, _)| index)
+}
@@ -0,0 +1,141 @@
+//! Lecture de champs GRIB2 (GFS) comme conditions initiales
+//!
+//! Les températures et hauteurs géopotentielles de surface et de 500 hPa sont
+//! extraites au point de grille le plus proche de la position demandée, puis
+//! assemblées en un profil vertical exploité comme un radiosondage.
+
+use std::io::{Read, Seek};
+
+use grib::{Grib2Read, Grib2SubmessageDecoder, GribError, LatLons, SubMessage};
+
+use crate::io::sounding::{standard_pressure, Sounding, SoundingLevel};
+use crate::MeteoError;
+
+/// Paramètres GRIB2 (discipline, catégorie, numéro)
+const TEMPERATURE: (u8, u8, u8) = (0, 0, 0);
+const PRESSURE: (u8, u8, u8) = (0, 3, 0);
+const GEOPOTENTIAL_HEIGHT: (u8, u8, u8) = (0, 3, 5);
+
+/// Types de surfaces fixes (table 4.5)
+const GROUND_SURFACE: u8 = 1;
+const ISOBARIC_SURFACE: u8 = 100;
+
+/// Niveau isobare de l'anomalie d'altitude (Pa)
+const ALTITUDE_LEVEL: f64 = 50000.0;
+
+/// Champs extraits au point demandé
+#[derive(Debug, Default)]
+struct PointFields {
+    surface_temperature: Option<f64>,
+    surface_height: Option<f64>,
+    surface_pressure: Option<f64>,
+    upper_temperature: Option<f64>,
+    upper_height: Option<f64>,
+}
+
+/// Extrait le profil surface/500 hPa au point de grille le plus proche
+#[cfg_attr(feature = "tracing", tracing::instrument(skip(reader)))]
+pub fn read_profile<R: Read + Seek>(
+    reader: R,
+    latitude: f64,
+    longitude: f64,
+) -> Result<Sounding, MeteoError> {
+    let grib2 = grib::from_reader(reader).map_err(grib_error)?;
+    let mut fields = PointFields::default();
+
+    for (_index, submessage) in grib2.iter() {
+        let prod_def = submessage.prod_def();
+        let (Some(category), Some(number), Some((surface, _))) = (
+            prod_def.parameter_category(),
+            prod_def.parameter_number(),
+            prod_def.fixed_surfaces(),
+        ) else {
+            continue;
+        };
+        let parameter = (submessage.indicator().discipline, category, number);
+
+        let slot = match (parameter, surface.surface_type) {
+            (TEMPERATURE, GROUND_SURFACE) => &mut fields.surface_temperature,
+            (GEOPOTENTIAL_HEIGHT, GROUND_SURFACE) => &mut fields.surface_height,
+            (PRESSURE, GROUND_SURFACE) => &mut fields.surface_pressure,
+            (TEMPERATURE, ISOBARIC_SURFACE) if surface.value() == ALTITUDE_LEVEL => {
+                &mut fields.upper_temperature
+            }
+            (GEOPOTENTIAL_HEIGHT, ISOBARIC_SURFACE) if surface.value() == ALTITUDE_LEVEL => {
+                &mut fields.upper_height
+            }
+            _ => continue,
+        };
+
+        // Seule la première échéance rencontrée est retenue
+        if slot.is_none() {
+            log_event!(debug, ?parameter, surface_type = surface.surface_type, "champ GRIB2 retenu");
+            *slot = Some(nearest_value(submessage, latitude, longitude)?);
+        }
+    }
+
+    let surface_height = fields.surface_height.ok_or_else(|| missing("HGT surface"))?;
+    let surface = SoundingLevel {
+        pressure: fields
+            .surface_pressure
+            .map(|pa| pa / 100.0)
+            .unwrap_or_else(|| standard_pressure(surface_height)),
+        height: surface_height,
+        temperature: fields.surface_temperature.ok_or_else(|| missing("TMP surface"))?,
+    };
+    let upper = SoundingLevel {
+        pressure: ALTITUDE_LEVEL / 100.0,
+        height: fields.upper_height.ok_or_else(|| missing("HGT 500 hPa"))?,
+        temperature: fields.upper_temperature.ok_or_else(|| missing("TMP 500 hPa"))?,
+    };
+
+    Sounding::from_levels(None, Some(latitude), vec![surface, upper])
+        .map_err(|_| MeteoError::InvalidGrib("profil vide".to_string()))
+}
+
+/// Valeur du champ au point de grille le plus proche
+fn nearest_value<R: Grib2Read>(
+    submessage: SubMessage<R>,
+    latitude: f64,
+    longitude: f64,
+) -> Result<f64, MeteoError> {
+    let index = {
+        let scale = latitude.to_radians().cos();
+        let distance = |(lat, lon): (f64, f64)| {
+            let dlon = (lon - longitude + 540.0).rem_euclid(360.0) - 180.0;
+            (lat - latitude).powi(2) + (dlon * scale).powi(2)
+        };
+
+        submessage
+            .latlons()
+            .map_err(grib_error)?
+            .enumerate()
+            .min_by(|(_, a), (_, b)| distance(*a).total_cmp(&distance(*b)))
+            .map(|(index, _)| index)
+            .ok_or_else(|| MeteoError::InvalidGrib("grille vide".to_string()))?
+    };
+
+    let decoder = Grib2SubmessageDecoder::from(submessage).map_err(grib_error)?;
+    let value = decoder
+        .dispatch()
+        .map_err(grib_error)?
+        .nth(index)
+        .ok_or_else(|| MeteoError::InvalidGrib("point de grille hors des données".to_string()))?;
+
+    if value.is_nan() {
+        return Err(MeteoError::InvalidGrib(format!(
+            "valeur manquante au point {}°, {}°",
+            latitude, longitude
+        )));
+    }
+
+    Ok(value as f64)
+}
+
+fn missing(field: &str) -> MeteoError {
+    MeteoError::InvalidGrib(format!("champ {} absent", field))
+}
+
+fn grib_error(error: GribError) -> MeteoError {
+    MeteoError::InvalidGrib(error.to_string())
+}
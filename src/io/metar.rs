@@ -0,0 +1,158 @@
+//! Décodage des observations METAR pour initialiser la surface
+//!
+//! Seuls les groupes température/point de rosée et pression (QNH) sont
+//! exploités. Le QNH étant ramené au niveau de la mer, la position de surface
+//! est placée à 0 m.
+
+use crate::{BaroclinicCyclogenesis, MeteoError, PhysicalConstants, Position, ThermalAnomaly};
+
+/// Conversion des pouces de mercure en hPa
+const INHG_TO_HPA: f64 = 33.8639;
+
+/// Observation de surface décodée
+#[derive(Debug, Clone, PartialEq)]
+pub struct Metar {
+    station: String,
+    temperature: f64,       // Température (K)
+    dewpoint: Option<f64>,  // Point de rosée (K)
+    pressure: f64,          // QNH (hPa)
+}
+
+impl Metar {
+    /// Décode un message METAR ou SPECI
+    pub fn parse(report: &str) -> Result<Self, MeteoError> {
+        let mut groups = report
+            .split_whitespace()
+            .map(|group| group.trim_end_matches('='))
+            .take_while(|group| *group != "RMK")
+            .skip_while(|group| matches!(*group, "METAR" | "SPECI" | "COR"));
+
+        let station = groups
+            .next()
+            .filter(|id| id.len() == 4 && id.chars().all(|c| c.is_ascii_alphanumeric()))
+            .ok_or_else(|| invalid("indicatif de station absent"))?
+            .to_string();
+
+        let mut temperatures = None;
+        let mut pressure = None;
+        for group in groups {
+            if temperatures.is_none() {
+                temperatures = parse_temperatures(group);
+            }
+            if pressure.is_none() {
+                pressure = parse_pressure(group);
+            }
+        }
+
+        let (temperature, dewpoint) =
+            temperatures.ok_or_else(|| invalid("groupe température/point de rosée absent"))?;
+        let pressure = pressure.ok_or_else(|| invalid("groupe de pression (Q ou A) absent"))?;
+        log_event!(debug, station = %station, temperature, pressure, "METAR décodé");
+
+        Ok(Self {
+            station,
+            temperature,
+            dewpoint,
+            pressure,
+        })
+    }
+
+    /// Indicatif OACI de la station
+    pub fn station(&self) -> &str {
+        &self.station
+    }
+
+    /// Température observée (K)
+    pub fn temperature(&self) -> f64 {
+        self.temperature
+    }
+
+    /// Point de rosée observé (K), s'il est renseigné
+    pub fn dewpoint(&self) -> Option<f64> {
+        self.dewpoint
+    }
+
+    /// Pression ramenée au niveau de la mer (hPa)
+    pub fn pressure(&self) -> f64 {
+        self.pressure
+    }
+
+    /// Position de surface correspondant à l'observation
+    pub fn surface_position(&self, latitude: f64) -> Result<Position, MeteoError> {
+        Position::new(latitude, 0.0, self.pressure)
+    }
+
+    /// Anomalie de surface par rapport à la température de référence
+    pub fn surface_anomaly(
+        &self,
+        latitude: f64,
+        constants: PhysicalConstants,
+    ) -> Result<ThermalAnomaly, MeteoError> {
+        ThermalAnomaly::new(
+            self.temperature - constants.base_temp,
+            self.surface_position(latitude)?,
+            constants,
+        )
+    }
+
+    /// Construit une simulation idéalisée à partir de l'observation et d'une anomalie d'altitude
+    pub fn to_cyclogenesis(
+        &self,
+        latitude: f64,
+        altitude_temp: f64,
+    ) -> Result<BaroclinicCyclogenesis, MeteoError> {
+        let constants = PhysicalConstants::default();
+
+        let surface_anomaly = self.surface_anomaly(latitude, constants)?;
+        let altitude_anomaly = ThermalAnomaly::new(
+            altitude_temp,
+            Position::upper_level(latitude)?,
+            constants,
+        )?;
+
+        Ok(BaroclinicCyclogenesis::from_anomalies(surface_anomaly, altitude_anomaly))
+    }
+}
+
+fn invalid(message: &str) -> MeteoError {
+    MeteoError::InvalidMetar(message.to_string())
+}
+
+/// Groupe `TT/DD` en °C, `M` indiquant une valeur négative
+fn parse_temperatures(group: &str) -> Option<(f64, Option<f64>)> {
+    let (temperature, dewpoint) = group.split_once('/')?;
+    let temperature = parse_celsius(temperature)?;
+    let dewpoint = if dewpoint.is_empty() || dewpoint == "//" {
+        None
+    } else {
+        Some(parse_celsius(dewpoint)?)
+    };
+
+    Some((temperature, dewpoint))
+}
+
+fn parse_celsius(value: &str) -> Option<f64> {
+    let (sign, digits) = match value.strip_prefix('M') {
+        Some(digits) => (-1.0, digits),
+        None => (1.0, value),
+    };
+    if digits.len() != 2 || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    Some(sign * digits.parse::<f64>().ok()? + 273.15)
+}
+
+/// Groupe `Qpppp` (hPa) ou `Apppp` (centièmes de pouce de mercure)
+fn parse_pressure(group: &str) -> Option<f64> {
+    let digits = group.get(1..).filter(|digits| {
+        digits.len() == 4 && digits.chars().all(|c| c.is_ascii_digit())
+    })?;
+    let value: f64 = digits.parse().ok()?;
+
+    match group.as_bytes()[0] {
+        b'Q' => Some(value),
+        b'A' => Some(value / 100.0 * INHG_TO_HPA),
+        _ => None,
+    }
+}
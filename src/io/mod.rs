@@ -0,0 +1,18 @@
+//! Formats d'entrée et de sortie des simulations
+
+#[cfg(feature = "arrow")]
+pub mod arrow;
+pub mod binary;
+pub mod climatology;
+#[cfg(feature = "netcdf")]
+pub mod era5;
+#[cfg(feature = "grib")]
+pub mod grib;
+pub mod metar;
+#[cfg(feature = "netcdf")]
+pub mod netcdf;
+pub mod observations;
+#[cfg(feature = "parquet")]
+pub mod parquet;
+pub mod records;
+pub mod sounding;
@@ -0,0 +1,260 @@
+//! Export NetCDF-3 des champs reconstruits autour de la dépression
+//!
+//! Les fichiers suivent les conventions CF et se lisent avec xarray, ncview ou
+//! Panoply. Le vent est écrit sur la grille mobile `latitude(time, y)` et
+//! `longitude(time, x)` ; les coupes verticales sur les axes `level` et
+//! `distance`, communs à toutes les heures ; le diagramme de Hovmöller sur les
+//! axes `time` et `longitude` ; les probabilités de dépassement d'un ensemble
+//! sur l'axe `time`, une variable par événement.
+
+use std::path::Path;
+
+use netcdf3::{DataSet, FileWriter, Version};
+
+use crate::ensemble::ExceedanceStep;
+use crate::frame::Frame;
+use crate::hovmoller::Hovmoller;
+use crate::section::CrossSection;
+use crate::units::WindUnit;
+use crate::wind::WindField;
+use crate::MeteoError;
+
+/// Variable à écrire : nom, dimensions, unités, nom standard et valeurs
+struct Variable<'a> {
+    name: &'a str,
+    dims: &'a [&'a str],
+    units: &'a str,
+    standard_name: Option<&'a str>,
+    values: Vec<f64>,
+}
+
+/// Écrit une série de champs de vent sur une même taille de grille, en m/s
+pub fn write_wind_fields<P: AsRef<Path>>(path: P, fields: &[WindField]) -> Result<(), MeteoError> {
+    write_wind_fields_with(path, fields, WindUnit::MetersPerSecond)
+}
+
+/// Écrit une série de champs de vent, les composantes converties dans `unit`
+pub fn write_wind_fields_with<P: AsRef<Path>>(path: P, fields: &[WindField], unit: WindUnit) -> Result<(), MeteoError> {
+    let Some(first) = fields.first() else {
+        return Err(invalid("aucun champ de vent à écrire"));
+    };
+    let (rows, columns) = (first.latitudes().len(), first.longitudes().len());
+    if let Some(field) = fields.iter().find(|field| (field.latitudes().len(), field.longitudes().len()) != (rows, columns)) {
+        return Err(invalid(&format!("grille de l'heure {} différente de celle de l'heure {}", field.hour, first.hour)));
+    }
+    if let Some(field) = fields.iter().find(|field| field.frame() != first.frame()) {
+        return Err(invalid(&format!("repère de l'heure {} différent de celui de l'heure {}", field.hour, first.hour)));
+    }
+    // Les noms standard CF désignent le vent terrestre
+    let earth = first.frame() == Frame::Earth;
+
+    let collect = |values: fn(&WindField) -> Vec<f64>| fields.iter().flat_map(values).collect::<Vec<_>>();
+    let speed = |values: Vec<f64>| values.into_iter().map(|value| unit.express(value)).collect();
+    let variables = [
+        time(collect(|field| vec![f64::from(field.hour)])),
+        Variable {
+            name: "center_latitude",
+            dims: &["time"],
+            units: "degrees_north",
+            standard_name: None,
+            values: collect(|field| vec![field.center_latitude]),
+        },
+        Variable {
+            name: "center_longitude",
+            dims: &["time"],
+            units: "degrees_east",
+            standard_name: None,
+            values: collect(|field| vec![field.center_longitude]),
+        },
+        Variable {
+            name: "latitude",
+            dims: &["time", "y"],
+            units: "degrees_north",
+            standard_name: Some("latitude"),
+            values: collect(|field| field.latitudes().to_vec()),
+        },
+        Variable {
+            name: "longitude",
+            dims: &["time", "x"],
+            units: "degrees_east",
+            standard_name: Some("longitude"),
+            values: collect(|field| field.longitudes().to_vec()),
+        },
+        Variable {
+            name: "u",
+            dims: &["time", "y", "x"],
+            units: unit.udunits(),
+            standard_name: earth.then_some("eastward_wind"),
+            values: speed(collect(|field| field.u().to_vec())),
+        },
+        Variable {
+            name: "v",
+            dims: &["time", "y", "x"],
+            units: unit.udunits(),
+            standard_name: earth.then_some("northward_wind"),
+            values: speed(collect(|field| field.v().to_vec())),
+        },
+    ];
+    let dims = [("time", fields.len()), ("y", rows), ("x", columns)];
+    write(path.as_ref(), "Vent reconstruit autour de la dépression", &dims, &variables)
+}
+
+/// Écrit une série de coupes verticales sur les mêmes niveaux et distances
+pub fn write_cross_sections<P: AsRef<Path>>(path: P, sections: &[CrossSection]) -> Result<(), MeteoError> {
+    let Some(first) = sections.first() else {
+        return Err(invalid("aucune coupe à écrire"));
+    };
+    if let Some(section) =
+        sections.iter().find(|section| section.pressures() != first.pressures() || section.distances() != first.distances())
+    {
+        return Err(invalid(&format!("coupe de l'heure {} différente de celle de l'heure {}", section.hour, first.hour)));
+    }
+
+    let collect = |values: fn(&CrossSection) -> &[f64]| sections.iter().flat_map(values).copied().collect::<Vec<_>>();
+    let variables = [
+        time(sections.iter().map(|section| f64::from(section.hour)).collect()),
+        Variable {
+            name: "level",
+            dims: &["level"],
+            units: "hPa",
+            standard_name: Some("air_pressure"),
+            values: first.pressures().to_vec(),
+        },
+        Variable {
+            name: "distance",
+            dims: &["distance"],
+            units: "km",
+            standard_name: None,
+            values: first.distances().to_vec(),
+        },
+        Variable {
+            name: "temperature_anomaly",
+            dims: &["time", "level", "distance"],
+            units: "K",
+            standard_name: None,
+            values: collect(CrossSection::temperature_anomaly),
+        },
+        Variable {
+            name: "vertical_velocity",
+            dims: &["time", "level", "distance"],
+            units: "m s-1",
+            standard_name: Some("upward_air_velocity"),
+            values: collect(CrossSection::vertical_velocity),
+        },
+        Variable {
+            name: "relative_vorticity",
+            dims: &["time", "level", "distance"],
+            units: "s-1",
+            standard_name: Some("atmosphere_relative_vorticity"),
+            values: collect(CrossSection::relative_vorticity),
+        },
+    ];
+    let dims = [("time", sections.len()), ("level", first.pressures().len()), ("distance", first.distances().len())];
+    write(path.as_ref(), "Coupe verticale ouest-est de la dépression", &dims, &variables)
+}
+
+/// Écrit un diagramme de Hovmöller du tourbillon
+pub fn write_hovmoller<P: AsRef<Path>>(path: P, diagram: &Hovmoller) -> Result<(), MeteoError> {
+    if diagram.hours().is_empty() {
+        return Err(invalid("diagramme de Hovmöller vide"));
+    }
+
+    // Dans le repère lié à la dépression, l'axe est un écart de longitude au centre
+    let earth = diagram.frame() == Frame::Earth;
+    let variables = [
+        time(diagram.hours().iter().map(|hour| f64::from(*hour)).collect()),
+        Variable {
+            name: "longitude",
+            dims: &["longitude"],
+            units: if earth { "degrees_east" } else { "degrees" },
+            standard_name: earth.then_some("longitude"),
+            values: diagram.longitudes().to_vec(),
+        },
+        Variable {
+            name: "center_longitude",
+            dims: &["time"],
+            units: "degrees_east",
+            standard_name: None,
+            values: diagram.center_longitudes().to_vec(),
+        },
+        Variable {
+            name: "relative_vorticity",
+            dims: &["time", "longitude"],
+            units: "s-1",
+            standard_name: Some("atmosphere_relative_vorticity"),
+            values: diagram.vorticity().to_vec(),
+        },
+    ];
+    let dims = [("time", diagram.hours().len()), ("longitude", diagram.longitudes().len())];
+    write(path.as_ref(), "Diagramme de Hovmöller du tourbillon relatif", &dims, &variables)
+}
+
+/// Écrit les probabilités de dépassement d'un ensemble, `probability_deepening_above_20`
+/// pour `deepening>20` ; une probabilité indéfinie est écrite NaN
+pub fn write_exceedance<P: AsRef<Path>>(path: P, steps: &[ExceedanceStep]) -> Result<(), MeteoError> {
+    let Some(first) = steps.first() else {
+        return Err(invalid("aucune probabilité de dépassement"));
+    };
+    let names: Vec<_> = first
+        .probabilities
+        .iter()
+        .map(|probability| {
+            let event = probability.event;
+            let value: String = event.value.to_string().chars().map(|c| if c.is_ascii_alphanumeric() { c } else { '_' }).collect();
+            let comparison = if event.above { "above" } else { "below" };
+            format!("probability_{}_{}_{}", event.variable.name(), comparison, value)
+        })
+        .collect();
+
+    let mut variables = vec![time(steps.iter().map(|step| f64::from(step.hour)).collect())];
+    for (index, name) in names.iter().enumerate() {
+        variables.push(Variable {
+            name,
+            dims: &["time"],
+            units: "1",
+            standard_name: None,
+            values: steps.iter().map(|step| step.probabilities[index].probability.unwrap_or(f64::NAN)).collect(),
+        });
+    }
+    write(path.as_ref(), "Probabilités de dépassement de seuils dans un ensemble", &[("time", steps.len())], &variables)
+}
+
+/// Coordonnée temporelle, en heures depuis le départ
+fn time(values: Vec<f64>) -> Variable<'static> {
+    Variable { name: "time", dims: &["time"], units: "hours since start", standard_name: Some("time"), values }
+}
+
+fn write(path: &Path, title: &str, dims: &[(&str, usize)], variables: &[Variable]) -> Result<(), MeteoError> {
+    let mut data_set = DataSet::new();
+    for (name, size) in dims {
+        data_set.add_fixed_dim(name, *size).map_err(definition)?;
+    }
+    for variable in variables {
+        data_set.add_var_f64(variable.name, variable.dims).map_err(definition)?;
+        data_set.add_var_attr_string(variable.name, "units", variable.units).map_err(definition)?;
+        if let Some(standard_name) = variable.standard_name {
+            data_set.add_var_attr_string(variable.name, "standard_name", standard_name).map_err(definition)?;
+        }
+    }
+    data_set.add_global_attr_string("title", title).map_err(definition)?;
+    data_set.add_global_attr_string("Conventions", "CF-1.8").map_err(definition)?;
+
+    let mut writer = FileWriter::open(path).map_err(|e| invalid(&format!("{}: {:?}", path.display(), e)))?;
+    writer.set_def(&data_set, Version::Classic, 0).map_err(|e| invalid(&format!("{:?}", e)))?;
+    for variable in variables {
+        writer
+            .write_var_f64(variable.name, &variable.values)
+            .map_err(|e| invalid(&format!("{}: {:?}", variable.name, e)))?;
+    }
+    writer.close().map_err(|e| invalid(&format!("{:?}", e)))?;
+    log_event!(info, path = %path.display(), title, "fichier NetCDF écrit");
+    Ok(())
+}
+
+fn invalid(message: &str) -> MeteoError {
+    MeteoError::InvalidNetcdf(message.to_string())
+}
+
+fn definition(error: netcdf3::InvalidDataSet) -> MeteoError {
+    invalid(&error.to_string())
+}
@@ -0,0 +1,95 @@
+//! Lecture de séries observées au format CSV
+//!
+//! La première ligne nomme les colonnes, séparées par des virgules :
+//!
+//! - `hour` (heures de simulation) ou `time` (date RFC 3339, ramenée à l'heure
+//!   de simulation par la date de départ du scénario) ;
+//! - `central_pressure` ou `pressure` : pression centrale observée (hPa) ;
+//! - `relative_vorticity` ou `vorticity` : tourbillon relatif observé (s⁻¹).
+//!
+//! Une cellule vide est une valeur manquante. Les lignes vides et celles qui
+//! commencent par `#` sont ignorées, tout comme les colonnes inconnues.
+
+use chrono::{DateTime, Utc};
+
+use crate::MeteoError;
+
+/// État du système observé à une heure
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ObservedState {
+    pub hour: f64,                        // Heure de simulation, éventuellement fractionnaire
+    pub central_pressure: Option<f64>,    // Pression centrale (hPa)
+    pub relative_vorticity: Option<f64>,  // Tourbillon relatif (s⁻¹)
+}
+
+/// Colonne de temps du fichier
+enum TimeColumn {
+    Hour(usize),
+    Time(usize, DateTime<Utc>),
+}
+
+/// Lit une série observée ; `start_time` est nécessaire pour une colonne `time`
+pub fn parse_csv(text: &str, start_time: Option<DateTime<Utc>>) -> Result<Vec<ObservedState>, MeteoError> {
+    let mut lines = text
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'));
+
+    let (_, header) = lines.next().ok_or_else(|| invalid("fichier vide"))?;
+    let columns: Vec<_> = header.split(',').map(|name| name.trim().to_lowercase()).collect();
+    let find = |names: &[&str]| columns.iter().position(|column| names.contains(&column.as_str()));
+
+    let time = match (find(&["hour"]), find(&["time"])) {
+        (Some(column), _) => TimeColumn::Hour(column),
+        (None, Some(column)) => {
+            let start = start_time.ok_or_else(|| invalid("colonne time sans date de départ du scénario"))?;
+            TimeColumn::Time(column, start)
+        }
+        (None, None) => return Err(invalid("colonne hour ou time absente")),
+    };
+    let pressure = find(&["central_pressure", "pressure"]);
+    let vorticity = find(&["relative_vorticity", "vorticity"]);
+    if pressure.is_none() && vorticity.is_none() {
+        return Err(invalid("colonne central_pressure ou relative_vorticity absente"));
+    }
+
+    let mut states = Vec::new();
+    for (number, line) in lines {
+        let cells: Vec<_> = line.split(',').map(str::trim).collect();
+        let cell = |column: Option<usize>| column.and_then(|column| cells.get(column)).filter(|cell| !cell.is_empty());
+        let number_in = |column: Option<usize>| -> Result<Option<f64>, MeteoError> {
+            cell(column)
+                .map(|value| {
+                    value
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|value| value.is_finite())
+                        .ok_or_else(|| invalid(&format!("ligne {}: valeur invalide {}", number, value)))
+                })
+                .transpose()
+        };
+
+        let hour = match time {
+            TimeColumn::Hour(column) => number_in(Some(column))?,
+            TimeColumn::Time(column, start) => cell(Some(column))
+                .map(|value| {
+                    DateTime::parse_from_rfc3339(value)
+                        .map(|time| (time.with_timezone(&Utc) - start).num_seconds() as f64 / 3600.0)
+                        .map_err(|_| invalid(&format!("ligne {}: date invalide {}", number, value)))
+                })
+                .transpose()?,
+        }
+        .ok_or_else(|| invalid(&format!("ligne {}: heure manquante", number)))?;
+
+        states.push(ObservedState { hour, central_pressure: number_in(pressure)?, relative_vorticity: number_in(vorticity)? });
+    }
+    if states.is_empty() {
+        return Err(invalid("aucune observation"));
+    }
+    Ok(states)
+}
+
+fn invalid(message: &str) -> MeteoError {
+    MeteoError::InvalidObservations(message.to_string())
+}
@@ -0,0 +1,155 @@
+//! Export des résultats au format Apache Parquet
+//!
+//! Chaque ligne correspond à une heure d'un membre d'une simulation. Les colonnes
+//! sont en unités SI pour être exploitées directement dans DuckDB ou Polars.
+
+use std::io::Write;
+use std::sync::Arc;
+
+use parquet::basic::Compression;
+use parquet::data_type::{ByteArray, ByteArrayType, DoubleType, Int32Type, Int64Type};
+use parquet::errors::{ParquetError, Result};
+use parquet::file::properties::WriterProperties;
+use parquet::file::writer::SerializedFileWriter;
+use parquet::schema::parser::parse_message_type;
+
+use crate::DevelopmentResult;
+
+const SCHEMA: &str = "
+    message development_result {
+        REQUIRED BYTE_ARRAY run_id (UTF8);
+        REQUIRED INT32 member (INTEGER(32, false));
+        REQUIRED INT32 hour (INTEGER(32, false));
+        REQUIRED DOUBLE vertical_velocity;
+        REQUIRED DOUBLE relative_vorticity;
+        OPTIONAL INT64 valid_time (TIMESTAMP(MILLIS, true));
+    }
+";
+
+/// Nombre de lignes accumulées par défaut avant l'écriture d'un groupe de lignes
+pub const ROW_GROUP_SIZE: usize = 1 << 20;
+
+/// Colonnes en attente d'écriture
+#[derive(Default)]
+struct ColumnBuffers {
+    run_id: Vec<ByteArray>,
+    member: Vec<i32>,
+    hour: Vec<i32>,
+    vertical_velocity: Vec<f64>,
+    relative_vorticity: Vec<f64>,
+    valid_time: Vec<i64>,             // Valeurs présentes uniquement (ms depuis l'époque Unix)
+    valid_time_levels: Vec<i16>,      // Niveaux de définition : 1 si la date est connue
+}
+
+impl ColumnBuffers {
+    fn len(&self) -> usize {
+        self.hour.len()
+    }
+
+    fn clear(&mut self) {
+        self.run_id.clear();
+        self.member.clear();
+        self.hour.clear();
+        self.vertical_velocity.clear();
+        self.relative_vorticity.clear();
+        self.valid_time.clear();
+        self.valid_time_levels.clear();
+    }
+}
+
+/// Écrivain Parquet pour les résultats de simulations et d'ensembles
+pub struct ParquetWriter<W: Write + Send> {
+    writer: SerializedFileWriter<W>,
+    buffers: ColumnBuffers,
+    row_group_size: usize,  // Lignes par groupe
+}
+
+impl<W: Write + Send> ParquetWriter<W> {
+    /// Crée un écrivain vers la destination donnée
+    pub fn new(sink: W) -> Result<Self> {
+        Self::with_row_group_size(sink, ROW_GROUP_SIZE)
+    }
+
+    /// Crée un écrivain dont les groupes comptent au plus `rows` lignes
+    pub fn with_row_group_size(sink: W, rows: usize) -> Result<Self> {
+        if rows == 0 {
+            return Err(ParquetError::General("groupes de lignes vides".to_string()));
+        }
+        let schema = Arc::new(parse_message_type(SCHEMA)?);
+        let properties = WriterProperties::builder()
+            .set_compression(Compression::SNAPPY)
+            .build();
+
+        Ok(Self {
+            writer: SerializedFileWriter::new(sink, schema, Arc::new(properties))?,
+            buffers: ColumnBuffers::default(),
+            row_group_size: rows,
+        })
+    }
+
+    /// Ajoute les résultats d'un membre de simulation
+    pub fn write_run(
+        &mut self,
+        run_id: &str,
+        member: u32,
+        results: &[DevelopmentResult],
+    ) -> Result<()> {
+        for result in results {
+            self.buffers.run_id.push(ByteArray::from(run_id));
+            self.buffers.member.push(member as i32);
+            self.buffers.hour.push(result.hour as i32);
+            self.buffers.vertical_velocity.push(result.vertical_velocity);
+            self.buffers.relative_vorticity.push(result.relative_vorticity);
+            match result.valid_time {
+                Some(time) => {
+                    self.buffers.valid_time.push(time.timestamp_millis());
+                    self.buffers.valid_time_levels.push(1);
+                }
+                None => self.buffers.valid_time_levels.push(0),
+            }
+
+            if self.buffers.len() >= self.row_group_size {
+                self.flush()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Écrit les lignes en attente et finalise le fichier
+    pub fn close(mut self) -> Result<W> {
+        self.flush()?;
+        self.writer.into_inner()
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        if self.buffers.len() == 0 {
+            return Ok(());
+        }
+
+        log_event!(debug, rows = self.buffers.len(), "écriture d'un groupe de lignes Parquet");
+        let mut row_group = self.writer.next_row_group()?;
+        let mut index = 0;
+        while let Some(mut column) = row_group.next_column()? {
+            match index {
+                0 => column.typed::<ByteArrayType>().write_batch(&self.buffers.run_id, None, None)?,
+                1 => column.typed::<Int32Type>().write_batch(&self.buffers.member, None, None)?,
+                2 => column.typed::<Int32Type>().write_batch(&self.buffers.hour, None, None)?,
+                3 => column.typed::<DoubleType>().write_batch(&self.buffers.vertical_velocity, None, None)?,
+                4 => column.typed::<DoubleType>().write_batch(&self.buffers.relative_vorticity, None, None)?,
+                5 => column.typed::<Int64Type>().write_batch(
+                    &self.buffers.valid_time,
+                    Some(&self.buffers.valid_time_levels),
+                    None,
+                )?,
+                _ => return Err(ParquetError::General(format!("Colonne inattendue: {}", index))),
+            };
+            column.close()?;
+            index += 1;
+        }
+        row_group.close()?;
+
+        self.buffers.clear();
+        Ok(())
+    }
+}
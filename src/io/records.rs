@@ -0,0 +1,354 @@
+//! Écriture incrémentale des résultats en enregistrements de taille fixe
+//!
+//! Chaque résultat occupe [`RECORD_SIZE`] octets, en petit-boutiste :
+//!
+//! | Octets | Contenu                                                    |
+//! |--------|------------------------------------------------------------|
+//! | 0–3    | heure (`u32`)                                              |
+//! | 4–7    | présence : bit 0 date, bit 1 latitude, bit 2 longitude ; bit 3 toujours levé |
+//! | 8–39   | vitesse verticale, tourbillon, divergences basse et haute  |
+//! | 40–47  | date de validité (`i64`, ms depuis l'époque Unix)          |
+//! | 48–63  | latitude et longitude (°)                                  |
+//!
+//! Le fichier commence par l'en-tête de [`crate::io::binary`], dont l'empreinte
+//! du scénario simulé : une reprise par un autre scénario est refusée, et le
+//! fichier se lit aussi comme un fichier de résultats binaire.
+//!
+//! L'écrivain ajoute chaque enregistrement en fin de fichier dès qu'il est
+//! calculé et le force sur le disque (`sync_data`) : la mémoire reste constante
+//! quelle que soit la durée de l'intégration et une interruption perd au plus
+//! le pas en cours. Un enregistrement tronqué en fin de fichier est ignoré à la
+//! lecture et écrasé à la reprise.
+//!
+//! Avec la fonctionnalité `mmap`, [`RecordWriter::mapped`] écrit plutôt dans une
+//! fenêtre du fichier projetée en mémoire : le fichier est agrandi de
+//! [`MAPPED_RECORDS`] enregistrements nuls à la fois, chaque enregistrement est
+//! copié dans la projection puis forcé sur le disque (`flush_range`), et seule
+//! la fenêtre courante reste projetée. Le fichier est ramené à ses
+//! enregistrements écrits à la fermeture de l'écrivain ; après une interruption,
+//! les enregistrements nuls de fin de fichier, qu'aucun résultat ne produit
+//! grâce au bit 3, sont ignorés à la lecture et écrasés à la reprise.
+
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+use chrono::DateTime;
+
+#[cfg(feature = "mmap")]
+use memmap2::{MmapMut, MmapOptions};
+
+use crate::io::binary::{Header, HEADER_SIZE};
+use crate::{BaroclinicCyclogenesis, DevelopmentResult, MeteoError, Scenario};
+
+/// Taille d'un enregistrement (octets)
+pub const RECORD_SIZE: usize = 64;
+
+/// Enregistrements ajoutés au fichier à chaque agrandissement de la projection
+#[cfg(feature = "mmap")]
+pub const MAPPED_RECORDS: u64 = 1024;
+
+/// Alignement du début d'une projection (octets), multiple des tailles de page courantes
+#[cfg(feature = "mmap")]
+const MAPPING_ALIGNMENT: u64 = 1 << 16;
+
+const HAS_VALID_TIME: u32 = 1;
+const HAS_LATITUDE: u32 = 1 << 1;
+const HAS_LONGITUDE: u32 = 1 << 2;
+const WRITTEN: u32 = 1 << 3;
+
+/// Enregistrement d'un résultat
+pub fn encode(result: &DevelopmentResult) -> [u8; RECORD_SIZE] {
+    let flag = |present: bool, bit: u32| if present { bit } else { 0 };
+    let flags = WRITTEN
+        | flag(result.valid_time.is_some(), HAS_VALID_TIME)
+        | flag(result.latitude.is_some(), HAS_LATITUDE)
+        | flag(result.longitude.is_some(), HAS_LONGITUDE);
+
+    let mut record = [0; RECORD_SIZE];
+    record[0..4].copy_from_slice(&result.hour.to_le_bytes());
+    record[4..8].copy_from_slice(&flags.to_le_bytes());
+    let values = [
+        result.vertical_velocity,
+        result.relative_vorticity,
+        result.low_level_divergence,
+        result.upper_level_divergence,
+    ];
+    for (index, value) in values.iter().enumerate() {
+        record[8 + 8 * index..16 + 8 * index].copy_from_slice(&value.to_le_bytes());
+    }
+    let time = result.valid_time.map_or(0, |time| time.timestamp_millis());
+    record[40..48].copy_from_slice(&time.to_le_bytes());
+    record[48..56].copy_from_slice(&result.latitude.unwrap_or_default().to_le_bytes());
+    record[56..64].copy_from_slice(&result.longitude.unwrap_or_default().to_le_bytes());
+    record
+}
+
+/// Résultat d'un enregistrement
+pub fn decode(record: &[u8; RECORD_SIZE]) -> Result<DevelopmentResult, MeteoError> {
+    let word = |start: usize| u32::from_le_bytes(record[start..start + 4].try_into().expect("4 octets"));
+    let bytes = |start: usize| -> [u8; 8] { record[start..start + 8].try_into().expect("8 octets") };
+    let value = |start: usize| f64::from_le_bytes(bytes(start));
+    let flags = word(4);
+
+    let valid_time = if flags & HAS_VALID_TIME != 0 {
+        let millis = i64::from_le_bytes(bytes(40));
+        let time = DateTime::from_timestamp_millis(millis)
+            .ok_or_else(|| MeteoError::InvalidRun(format!("date d'enregistrement invalide: {} ms", millis)))?;
+        Some(time)
+    } else {
+        None
+    };
+    Ok(DevelopmentResult {
+        hour: word(0),
+        vertical_velocity: value(8),
+        relative_vorticity: value(16),
+        low_level_divergence: value(24),
+        upper_level_divergence: value(32),
+        valid_time,
+        latitude: (flags & HAS_LATITUDE != 0).then(|| value(48)),
+        longitude: (flags & HAS_LONGITUDE != 0).then(|| value(56)),
+    })
+}
+
+/// Écrivain incrémental d'un fichier d'enregistrements
+pub struct RecordWriter {
+    file: File,
+    #[cfg_attr(not(feature = "mmap"), allow(dead_code))]
+    start: u64,  // Taille de l'en-tête (octets)
+    count: u64,
+    #[cfg(feature = "mmap")]
+    window: Option<Window>,
+    #[cfg(feature = "mmap")]
+    mapped: bool,
+}
+
+/// Fenêtre projetée du fichier
+#[cfg(feature = "mmap")]
+struct Window {
+    map: MmapMut,
+    offset: u64,  // Position du début de la projection dans le fichier (octets)
+}
+
+#[cfg(feature = "mmap")]
+impl Window {
+    /// Position de la fin de la projection dans le fichier (octets)
+    fn end(&self) -> u64 {
+        self.offset + self.map.len() as u64
+    }
+}
+
+impl RecordWriter {
+    fn new(file: File, start: u64, count: u64) -> Self {
+        Self {
+            file,
+            start,
+            count,
+            #[cfg(feature = "mmap")]
+            window: None,
+            #[cfg(feature = "mmap")]
+            mapped: false,
+        }
+    }
+
+    /// Crée le fichier des résultats d'un scénario, en remplaçant un fichier existant
+    pub fn create<P: AsRef<Path>>(path: P, scenario: &Scenario) -> Result<Self, MeteoError> {
+        let path = path.as_ref();
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)
+            .map_err(|e| io_error(path, e))?;
+        file.write_all(&Header::new(scenario).encode())
+            .and_then(|_| file.sync_data())
+            .map_err(|e| io_error(path, e))?;
+        Ok(Self::new(file, HEADER_SIZE as u64, 0))
+    }
+
+    /// Rouvre un fichier pour y poursuivre l'écriture, après son dernier
+    /// enregistrement complet ; refuse un fichier écrit par un autre scénario
+    pub fn append<P: AsRef<Path>>(path: P, scenario: &Scenario) -> Result<Self, MeteoError> {
+        let path = path.as_ref();
+        let mut file = OpenOptions::new().read(true).write(true).open(path).map_err(|e| io_error(path, e))?;
+        let header = read_header(&mut file, path)?;
+        if !header.matches(scenario) {
+            return Err(MeteoError::InvalidRun(format!(
+                "{}: enregistrements d'un autre scénario, reprise refusée",
+                path.display()
+            )));
+        }
+        let start = u64::from(header.header_size);
+        let count = record_count(&mut file, path, start)?;
+        file.set_len(start + count * RECORD_SIZE as u64).map_err(|e| io_error(path, e))?;
+        file.seek(SeekFrom::End(0)).map_err(|e| io_error(path, e))?;
+        Ok(Self::new(file, start, count))
+    }
+
+    /// Écrit les enregistrements suivants dans une projection du fichier en mémoire
+    #[cfg(feature = "mmap")]
+    pub fn mapped(mut self) -> Self {
+        self.mapped = true;
+        self
+    }
+
+    /// Ajoute un résultat et le force sur le disque
+    pub fn write(&mut self, result: &DevelopmentResult) -> Result<(), MeteoError> {
+        let count = self.count;
+        let error = |e: std::io::Error| MeteoError::InvalidRun(format!("écriture de l'enregistrement {}: {}", count, e));
+        #[cfg(feature = "mmap")]
+        if self.mapped {
+            self.write_mapped(&encode(result)).map_err(error)?;
+            self.count += 1;
+            return Ok(());
+        }
+        self.file.write_all(&encode(result)).map_err(error)?;
+        self.file.sync_data().map_err(error)?;
+        self.count += 1;
+        Ok(())
+    }
+
+    /// Copie un enregistrement dans la projection, agrandie au besoin, et le
+    /// force sur le disque
+    #[cfg(feature = "mmap")]
+    fn write_mapped(&mut self, record: &[u8; RECORD_SIZE]) -> std::io::Result<()> {
+        let position = self.start + self.count * RECORD_SIZE as u64;
+        let window = match self.window.take() {
+            Some(window) if position + RECORD_SIZE as u64 <= window.end() => window,
+            _ => {
+                let end = position + MAPPED_RECORDS * RECORD_SIZE as u64;
+                self.file.set_len(end)?;
+                self.file.sync_all()?;
+                let offset = position - position % MAPPING_ALIGNMENT;
+                // SAFETY: la projection ne couvre que des octets du fichier, que
+                // l'écrivain seul modifie et ne tronque qu'après l'avoir libérée
+                let map = unsafe { MmapOptions::new().offset(offset).len((end - offset) as usize).map_mut(&self.file)? };
+                Window { map, offset }
+            }
+        };
+        let window = self.window.insert(window);
+        let at = (position - window.offset) as usize;
+        window.map[at..at + RECORD_SIZE].copy_from_slice(record);
+        window.map.flush_range(at, RECORD_SIZE)
+    }
+
+    /// Nombre d'enregistrements du fichier
+    pub fn len(&self) -> u64 {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+}
+
+/// Libère la projection et retire du fichier les enregistrements nuls préalloués
+#[cfg(feature = "mmap")]
+impl Drop for RecordWriter {
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    fn drop(&mut self) {
+        if self.window.take().is_some() {
+            let length = self.start + self.count * RECORD_SIZE as u64;
+            if let Err(error) = self.file.set_len(length).and_then(|_| self.file.sync_all()) {
+                log_event!(warn, %error, "troncature du fichier d'enregistrements");
+            }
+        }
+    }
+}
+
+/// En-tête d'un fichier d'enregistrements
+pub fn header<P: AsRef<Path>>(path: P) -> Result<Header, MeteoError> {
+    let path = path.as_ref();
+    read_header(&mut File::open(path).map_err(|e| io_error(path, e))?, path)
+}
+
+/// Lit les enregistrements complets d'un fichier
+pub fn read<P: AsRef<Path>>(path: P) -> Result<Vec<DevelopmentResult>, MeteoError> {
+    let path = path.as_ref();
+    let mut file = File::open(path).map_err(|e| io_error(path, e))?;
+    let start = u64::from(read_header(&mut file, path)?.header_size);
+    let count = record_count(&mut file, path, start)?;
+    let mut bytes = Vec::new();
+    file.seek(SeekFrom::Start(start))
+        .and_then(|_| (&mut file).take(count * RECORD_SIZE as u64).read_to_end(&mut bytes))
+        .map_err(|e| io_error(path, e))?;
+    bytes
+        .chunks_exact(RECORD_SIZE)
+        .map(|chunk| decode(chunk.try_into().expect("enregistrement complet")))
+        .collect()
+}
+
+/// Lit le dernier enregistrement complet d'un fichier, `None` s'il n'en a aucun
+pub fn read_last<P: AsRef<Path>>(path: P) -> Result<Option<DevelopmentResult>, MeteoError> {
+    let path = path.as_ref();
+    let mut file = File::open(path).map_err(|e| io_error(path, e))?;
+    let start = u64::from(read_header(&mut file, path)?.header_size);
+    let count = record_count(&mut file, path, start)?;
+    if count == 0 {
+        return Ok(None);
+    }
+    let mut record = [0; RECORD_SIZE];
+    file.seek(SeekFrom::Start(start + (count - 1) * RECORD_SIZE as u64))
+        .and_then(|_| file.read_exact(&mut record))
+        .map_err(|e| io_error(path, e))?;
+    decode(&record).map(Some)
+}
+
+/// Lit l'en-tête en tête du fichier ; les enregistrements doivent avoir la taille
+/// de ce module
+fn read_header(file: &mut File, path: &Path) -> Result<Header, MeteoError> {
+    let mut bytes = [0; HEADER_SIZE];
+    file.read_exact(&mut bytes)
+        .map_err(|_| MeteoError::InvalidRun(format!("{}: en-tête d'enregistrements incomplet", path.display())))?;
+    let header = Header::decode(&bytes)?;
+    if usize::from(header.record_size) != RECORD_SIZE {
+        return Err(MeteoError::InvalidRun(format!(
+            "{}: enregistrements de {} octets ({} attendus)",
+            path.display(),
+            header.record_size,
+            RECORD_SIZE
+        )));
+    }
+    Ok(header)
+}
+
+/// Nombre d'enregistrements complets après un en-tête de `start` octets, sans
+/// les enregistrements nuls préalloués en fin de fichier par une projection
+fn record_count(file: &mut File, path: &Path, start: u64) -> Result<u64, MeteoError> {
+    let length = file.metadata().map_err(|e| io_error(path, e))?.len();
+    let mut count = length.saturating_sub(start) / RECORD_SIZE as u64;
+    let mut record = [0; RECORD_SIZE];
+    while count > 0 {
+        file.seek(SeekFrom::Start(start + (count - 1) * RECORD_SIZE as u64))
+            .and_then(|_| file.read_exact(&mut record))
+            .map_err(|e| io_error(path, e))?;
+        if record.iter().any(|&byte| byte != 0) {
+            break;
+        }
+        count -= 1;
+    }
+    Ok(count)
+}
+
+impl BaroclinicCyclogenesis {
+    /// Simule `time_steps` heures en écrivant chaque résultat dès son calcul,
+    /// sans les garder en mémoire ; renvoie le dernier résultat
+    pub fn simulate_to(
+        &mut self,
+        time_steps: u32,
+        writer: &mut RecordWriter,
+    ) -> Result<Option<DevelopmentResult>, MeteoError> {
+        let mut last = None;
+        for hour in self.hours(time_steps)? {
+            let result = self.step(hour)?;
+            writer.write(&result)?;
+            last = Some(result);
+        }
+        Ok(last)
+    }
+}
+
+fn io_error(path: &Path, error: std::io::Error) -> MeteoError {
+    MeteoError::InvalidRun(format!("{}: {}", path.display(), error))
+}
@@ -0,0 +1,254 @@
+//! Lecture de radiosondages comme conditions initiales
+//!
+//! Deux formats texte sont reconnus : la liste de l'Université du Wyoming
+//! (`TEXT:LIST`) et les fichiers de données IGRA v2. Les anomalies thermiques
+//! sont mesurées par rapport à l'atmosphère standard.
+
+use crate::{BaroclinicCyclogenesis, MeteoError, PhysicalConstants, Position, ThermalAnomaly};
+
+/// Niveau de pression de l'anomalie d'altitude (hPa)
+const ALTITUDE_LEVEL: f64 = 500.0;
+
+/// Gradient thermique vertical de l'atmosphère standard (K/m)
+const STANDARD_LAPSE_RATE: f64 = 0.0065;
+
+/// Pression et température au niveau de la mer de l'atmosphère standard
+const STANDARD_SURFACE_PRESSURE: f64 = 1013.25;
+const STANDARD_SURFACE_TEMP: f64 = 288.15;
+
+/// Exposant R·Γ/g de la relation pression-altitude standard
+const STANDARD_EXPONENT: f64 = 0.190263;
+
+/// Altitude de la tropopause standard (m)
+const STANDARD_TROPOPAUSE: f64 = 11000.0;
+
+/// Largeur des colonnes du format Wyoming
+const WYOMING_COLUMN_WIDTH: usize = 7;
+
+/// Valeurs manquantes ou rejetées du format IGRA
+const IGRA_MISSING: [i32; 2] = [-9999, -8888];
+
+/// Niveau observé d'un radiosondage
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SoundingLevel {
+    pub pressure: f64,     // Pression (hPa)
+    pub height: f64,       // Altitude géopotentielle (m)
+    pub temperature: f64,  // Température (K)
+}
+
+/// Profil vertical observé, trié par pression décroissante
+#[derive(Debug, Clone)]
+pub struct Sounding {
+    station: Option<String>,
+    latitude: Option<f64>,
+    levels: Vec<SoundingLevel>,
+}
+
+impl Sounding {
+    /// Lit un sondage en détectant son format
+    pub fn parse(text: &str) -> Result<Self, MeteoError> {
+        if text.trim_start().starts_with('#') {
+            Self::parse_igra(text)
+        } else {
+            Self::parse_wyoming(text)
+        }
+    }
+
+    /// Lit un sondage au format texte de l'Université du Wyoming
+    pub fn parse_wyoming(text: &str) -> Result<Self, MeteoError> {
+        let mut lines = text.lines();
+
+        // En-tête des colonnes, puis ligne des unités et séparateur
+        lines
+            .by_ref()
+            .find(|line| line.contains("PRES") && line.contains("HGHT") && line.contains("TEMP"))
+            .ok_or_else(|| invalid("en-tête PRES/HGHT/TEMP introuvable"))?;
+        lines
+            .by_ref()
+            .find(|line| line.trim_start().starts_with("---"))
+            .ok_or_else(|| invalid("séparateur de l'en-tête introuvable"))?;
+
+        let mut levels = Vec::new();
+        let mut rest = Vec::new();
+        for line in lines.by_ref() {
+            let field = |index: usize| {
+                let start = index * WYOMING_COLUMN_WIDTH;
+                line.get(start..(start + WYOMING_COLUMN_WIDTH).min(line.len()))
+                    .map(str::trim)
+                    .filter(|value| !value.is_empty())
+            };
+
+            let Some(pressure) = field(0).and_then(|value| value.parse::<f64>().ok()) else {
+                rest.push(line);
+                break;
+            };
+            let height = field(1).and_then(|value| value.parse::<f64>().ok());
+            let temperature = field(2).and_then(|value| value.parse::<f64>().ok());
+
+            if let (Some(height), Some(temperature)) = (height, temperature) {
+                levels.push(SoundingLevel {
+                    pressure,
+                    height,
+                    temperature: temperature + 273.15,
+                });
+            }
+        }
+        rest.extend(lines);
+
+        let indice = |label: &str| {
+            rest.iter()
+                .find_map(|line| line.trim().strip_prefix(label))
+                .map(|value| value.trim_start_matches(':').trim().to_string())
+        };
+
+        Self::from_levels(
+            indice("Station identifier").or_else(|| indice("Station number")),
+            indice("Station latitude").and_then(|value| value.parse().ok()),
+            levels,
+        )
+    }
+
+    /// Lit le premier sondage d'un fichier de données IGRA v2
+    pub fn parse_igra(text: &str) -> Result<Self, MeteoError> {
+        let mut lines = text.lines().skip_while(|line| !line.starts_with('#'));
+        let header = lines
+            .next()
+            .ok_or_else(|| invalid("en-tête IGRA introuvable"))?;
+
+        // Les sources de données peuvent être vides : la latitude est l'avant-dernier champ
+        let fields: Vec<&str> = header.split_whitespace().collect();
+        let station = fields.first().map(|id| id.trim_start_matches('#').to_string());
+        let latitude = fields
+            .len()
+            .checked_sub(2)
+            .and_then(|index| fields[index].parse::<i32>().ok())
+            .filter(|lat| !IGRA_MISSING.contains(lat))
+            .map(|lat| lat as f64 / 10000.0);
+
+        let mut levels = Vec::new();
+        for line in lines.take_while(|line| !line.starts_with('#')) {
+            let pressure = igra_field(line, 10, 15);
+            let height = igra_field(line, 17, 21);
+            let temperature = igra_field(line, 23, 27);
+
+            if let (Some(pressure), Some(height), Some(temperature)) = (pressure, height, temperature) {
+                levels.push(SoundingLevel {
+                    pressure: pressure as f64 / 100.0,        // Pa -> hPa
+                    height: height as f64,
+                    temperature: temperature as f64 / 10.0 + 273.15,  // Dixièmes de °C -> K
+                });
+            }
+        }
+
+        Self::from_levels(station, latitude, levels)
+    }
+
+    pub(crate) fn from_levels(
+        station: Option<String>,
+        latitude: Option<f64>,
+        mut levels: Vec<SoundingLevel>,
+    ) -> Result<Self, MeteoError> {
+        if levels.is_empty() {
+            return Err(invalid("aucun niveau complet (pression, altitude, température)"));
+        }
+        levels.sort_by(|a, b| b.pressure.total_cmp(&a.pressure));
+        log_event!(debug, station = station.as_deref(), levels = levels.len(), "sondage lu");
+
+        Ok(Self {
+            station,
+            latitude,
+            levels,
+        })
+    }
+
+    /// Identifiant de la station, s'il est présent
+    pub fn station(&self) -> Option<&str> {
+        self.station.as_deref()
+    }
+
+    /// Latitude de la station (°), si elle est présente
+    pub fn latitude(&self) -> Option<f64> {
+        self.latitude
+    }
+
+    /// Structure thermique verticale observée
+    pub fn levels(&self) -> &[SoundingLevel] {
+        &self.levels
+    }
+
+    /// Niveau le plus bas du sondage
+    pub fn surface(&self) -> &SoundingLevel {
+        &self.levels[0]
+    }
+
+    /// Interpole le profil à une pression donnée (linéaire en ln p)
+    pub fn interpolate(&self, pressure: f64) -> Option<SoundingLevel> {
+        self.levels.windows(2).find_map(|pair| {
+            let (lower, upper) = (pair[0], pair[1]);
+            if !(upper.pressure..=lower.pressure).contains(&pressure) || lower.pressure == upper.pressure {
+                return None;
+            }
+
+            let weight = (lower.pressure / pressure).ln() / (lower.pressure / upper.pressure).ln();
+            Some(SoundingLevel {
+                pressure,
+                height: lower.height + weight * (upper.height - lower.height),
+                temperature: lower.temperature + weight * (upper.temperature - lower.temperature),
+            })
+        })
+    }
+
+    /// Écart de température d'un niveau par rapport à l'atmosphère standard (K)
+    pub fn temperature_anomaly(level: &SoundingLevel, constants: &PhysicalConstants) -> f64 {
+        let reference = constants.base_temp - STANDARD_LAPSE_RATE * level.height.min(STANDARD_TROPOPAUSE);
+        level.temperature - reference
+    }
+
+    /// Construit la simulation à partir du niveau de surface et du niveau 500 hPa
+    pub fn to_cyclogenesis(&self) -> Result<BaroclinicCyclogenesis, MeteoError> {
+        let latitude = self
+            .latitude
+            .ok_or_else(|| invalid("latitude de la station inconnue"))?;
+        let constants = PhysicalConstants::default();
+
+        let surface = *self.surface();
+        let altitude = self
+            .interpolate(ALTITUDE_LEVEL)
+            .ok_or_else(|| invalid("le sondage n'atteint pas 500 hPa"))?;
+
+        let surface_anomaly = ThermalAnomaly::new(
+            Self::temperature_anomaly(&surface, &constants),
+            Position::new(latitude, surface.height, surface.pressure)?,
+            constants,
+        )?;
+
+        let altitude_anomaly = ThermalAnomaly::new(
+            Self::temperature_anomaly(&altitude, &constants),
+            Position::new(latitude, altitude.height, altitude.pressure)?,
+            constants,
+        )?;
+
+        Ok(BaroclinicCyclogenesis::from_anomalies(surface_anomaly, altitude_anomaly))
+    }
+}
+
+/// Pression de l'atmosphère standard à une altitude donnée (hPa)
+pub fn standard_pressure(height: f64) -> f64 {
+    STANDARD_SURFACE_PRESSURE * (1.0 - STANDARD_LAPSE_RATE * height / STANDARD_SURFACE_TEMP).powf(STANDARD_EXPONENT.recip())
+}
+
+/// Altitude de l'atmosphère standard à une pression donnée (m)
+pub fn standard_height(pressure: f64) -> f64 {
+    STANDARD_SURFACE_TEMP / STANDARD_LAPSE_RATE * (1.0 - (pressure / STANDARD_SURFACE_PRESSURE).powf(STANDARD_EXPONENT))
+}
+
+fn invalid(message: &str) -> MeteoError {
+    MeteoError::InvalidSounding(message.to_string())
+}
+
+/// Lit un champ entier IGRA (colonnes 1-indexées, bornes incluses)
+fn igra_field(line: &str, first: usize, last: usize) -> Option<i32> {
+    line.get(first - 1..last.min(line.len()))
+        .and_then(|value| value.trim().parse().ok())
+        .filter(|value| !IGRA_MISSING.contains(value))
+}
@@ -0,0 +1,120 @@
+//! Représentation des anomalies en coordonnées isentropes
+//!
+//! Chaque anomalie est placée sur la surface de température potentielle
+//! θ = T·(1000/p)^κ de son niveau, la température étant celle de l'atmosphère
+//! standard augmentée de l'anomalie. Son tourbillon potentiel d'Ertel est
+//! PV = (ζ + f)·(∂θ/∂z)/ρ, la stabilité ∂θ/∂z = θ/T·(Γd − Γ) étant celle de
+//! l'état de base ; le tourbillon relatif est celui du système, de signe
+//! cyclonique pour une anomalie chaude et anticyclonique pour une anomalie froide.
+
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::diagnostics::{DRY_AIR_GAS_CONSTANT, DRY_LAPSE_RATE, STANDARD_LAPSE_RATE};
+use crate::{BaroclinicCyclogenesis, DevelopmentResult, NumberFormat, OutputLanguage};
+
+/// Rapport R/cp de l'air sec
+const POISSON_EXPONENT: f64 = DRY_AIR_GAS_CONSTANT / 1004.0;
+
+/// Unité de tourbillon potentiel, 1 PVU = 10⁻⁶ K m² kg⁻¹ s⁻¹
+const PVU: f64 = 1e-6;
+
+/// Anomalie sur sa surface isentrope
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct IsentropicLevel {
+    pub theta: f64,                // Température potentielle de la surface (K)
+    pub pressure: f64,             // Pression de l'anomalie (hPa)
+    pub potential_vorticity: f64,  // Tourbillon potentiel (PVU)
+    pub pv_anomaly: f64,           // Écart au tourbillon potentiel planétaire (PVU)
+    pub is_cyclonic: bool,
+}
+
+/// Présentation de la vue isentrope
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IsentropicOutput {
+    #[default]
+    Table,
+    Json,
+}
+
+impl FromStr for IsentropicOutput {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "table" => Ok(IsentropicOutput::Table),
+            "json" => Ok(IsentropicOutput::Json),
+            _ => Err(format!("Présentation inconnue: {} (table, json)", value)),
+        }
+    }
+}
+
+impl BaroclinicCyclogenesis {
+    /// Anomalies sur leurs surfaces isentropes à l'état du pas `result.hour`,
+    /// par température potentielle croissante
+    pub fn isentropic_view(&self, result: &DevelopmentResult) -> Vec<IsentropicLevel> {
+        let coriolis = self.coriolis_parameter(self.latitude());
+
+        let mut levels: Vec<_> = self
+            .anomalies
+            .iter()
+            .map(|anomaly| {
+                let constants = &anomaly.constants;
+                let temperature =
+                    constants.base_temp - STANDARD_LAPSE_RATE * anomaly.position.altitude + anomaly.temperature_delta;
+                let pressure = anomaly.position.pressure;
+                let theta = temperature * (1000.0 / pressure).powf(POISSON_EXPONENT);
+
+                let stability = theta / temperature * (DRY_LAPSE_RATE - STANDARD_LAPSE_RATE);
+                let density = pressure * 100.0 / (DRY_AIR_GAS_CONSTANT * temperature);
+                let sense = if anomaly.is_cyclonic { 1.0 } else { -1.0 };
+                let vorticity = sense * coriolis.signum() * result.relative_vorticity.abs();
+
+                IsentropicLevel {
+                    theta,
+                    pressure,
+                    potential_vorticity: (vorticity + coriolis) * stability / density / PVU,
+                    pv_anomaly: vorticity * stability / density / PVU,
+                    is_cyclonic: anomaly.is_cyclonic,
+                }
+            })
+            .collect();
+        levels.sort_by(|a, b| a.theta.total_cmp(&b.theta));
+        levels
+    }
+}
+
+/// Tableau de la vue isentrope
+pub fn to_table(levels: &[IsentropicLevel], language: OutputLanguage, format: &NumberFormat) -> String {
+    let (header, cyclonic, anticyclonic) = match language {
+        OutputLanguage::French => (
+            "θ (K)                | Pression (hPa)       | PV (PVU)             | Anomalie PV (PVU)    |",
+            "cyclonique",
+            "anticyclonique",
+        ),
+        OutputLanguage::English => (
+            "θ (K)                | Pressure (hPa)       | PV (PVU)             | PV anomaly (PVU)     |",
+            "cyclonic",
+            "anticyclonic",
+        ),
+    };
+
+    let mut table = format!("{}\n---------------------|----------------------|----------------------|----------------------|\n", header);
+    for level in levels {
+        table += &format!(
+            "{} | {} | {} | {} | {}\n",
+            format.format_width(level.theta, 20),
+            format.format_width(level.pressure, 20),
+            format.format_width(level.potential_vorticity, 20),
+            format.format_width(level.pv_anomaly, 20),
+            if level.is_cyclonic { cyclonic } else { anticyclonic }
+        );
+    }
+    table
+}
+
+/// Bloc JSON de la vue isentrope
+pub fn to_json(levels: &[IsentropicLevel]) -> String {
+    serde_json::to_string_pretty(levels).expect("niveaux isentropes sérialisables")
+}
@@ -0,0 +1,66 @@
+//! Langue des textes affichés : tableaux, rapports et messages d'erreur
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Langue de sortie du programme
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputLanguage {
+    #[default]
+    French,
+    English,
+}
+
+impl OutputLanguage {
+    /// Titre souligné affiché en tête de la sortie
+    pub fn banner(self) -> &'static str {
+        match self {
+            OutputLanguage::French => "SIMULATION DE CYCLOGÉNÈSE BAROCLINE\n====================================",
+            OutputLanguage::English => "BAROCLINIC CYCLOGENESIS SIMULATION\n==================================",
+        }
+    }
+
+    /// Titre d'une simulation à une latitude donnée
+    pub fn simulation_heading(self, latitude: f64) -> String {
+        match self {
+            OutputLanguage::French => format!("Simulation à {}°N :", latitude),
+            OutputLanguage::English => format!("Simulation at {}°N:", latitude),
+        }
+    }
+
+    /// En-tête du tableau des résultats horaires
+    pub fn table_header(self) -> &'static str {
+        match self {
+            OutputLanguage::French => "Heure | Vitesse verticale (cm/s) | Tourbillon relatif (10⁻⁵ s⁻¹)",
+            OutputLanguage::English => "Hour  | Vertical velocity (cm/s) | Relative vorticity (10⁻⁵ s⁻¹)",
+        }
+    }
+
+    /// Ligne de séparation sous l'en-tête du tableau
+    pub fn table_rule(self) -> &'static str {
+        "------|----------------------|----------------------"
+    }
+}
+
+impl FromStr for OutputLanguage {
+    type Err = String;
+
+    /// Accepte un code de langue (`fr`, `en-GB`...) ou le nom de la langue
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let code = value.split(['-', '_']).next().unwrap_or_default().to_lowercase();
+        match code.as_str() {
+            "fr" | "français" | "francais" | "french" => Ok(OutputLanguage::French),
+            "en" | "anglais" | "english" => Ok(OutputLanguage::English),
+            _ => Err(format!("Langue inconnue: {} (langues disponibles : fr, en)", value)),
+        }
+    }
+}
+
+impl fmt::Display for OutputLanguage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OutputLanguage::French => write!(f, "fr"),
+            OutputLanguage::English => write!(f, "en"),
+        }
+    }
+}
@@ -0,0 +1,1087 @@
+use std::f64::consts::{FRAC_PI_2, PI};
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+
+use chrono::{DateTime, Datelike, TimeDelta, Timelike, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Émet un événement `tracing` si la feature est activée, rien sinon
+macro_rules! log_event {
+    ($level:ident, $($arg:tt)+) => {
+        #[cfg(feature = "tracing")]
+        tracing::$level!($($arg)+);
+    };
+}
+
+#[cfg(feature = "grpc")]
+pub mod grpc;
+pub mod autodiff;
+pub mod batch;
+pub mod beaufort;
+pub mod boundary_layer;
+pub mod check;
+pub mod classify;
+pub mod climatology;
+pub mod cluster;
+pub mod columns;
+#[cfg(unix)]
+pub mod daemon;
+pub mod deepening;
+pub mod diagnostics;
+pub mod diff;
+pub mod dispersion;
+pub mod downstream;
+pub mod energetics;
+pub mod ensemble;
+pub mod exit;
+pub mod explain;
+pub mod float;
+pub mod format;
+pub mod frame;
+pub mod fujiwhara;
+pub mod geometry;
+pub mod gust;
+pub mod hindcast;
+pub mod hovmoller;
+pub mod impacts;
+pub mod integration;
+pub mod invariants;
+pub mod io;
+pub mod isentropic;
+pub mod lang;
+pub mod lifecycle;
+pub mod metrics;
+#[cfg(feature = "mqtt")]
+pub mod mqtt;
+pub mod nudging;
+pub mod phase;
+pub mod pipe;
+pub mod plausibility;
+#[cfg(feature = "plot")]
+pub mod plot;
+pub mod pressure;
+pub mod qvector;
+pub mod regime;
+pub mod report;
+pub mod restart;
+pub mod rossby;
+pub mod scenario;
+pub mod scenarios;
+pub mod season;
+pub mod section;
+pub mod server;
+pub mod series;
+pub mod stability;
+pub mod storms;
+pub mod sting_jet;
+pub mod streamer;
+pub mod structure;
+pub mod summary;
+pub mod surface;
+pub mod sweep;
+pub mod table;
+pub mod tropopause;
+pub mod trough;
+#[cfg(feature = "tui")]
+pub mod tui;
+pub mod uncertainty;
+pub mod units;
+pub mod validation;
+pub mod verify;
+pub mod vorticity;
+pub mod watch;
+pub mod waves;
+pub mod wind;
+
+pub use boundary_layer::BoundaryLayer;
+pub use climatology::Climatology;
+pub use columns::{ResultColumns, ResultRow};
+pub use diagnostics::{AnomalyDiagnostics, DiagnosticsLevel};
+pub use float::Float;
+pub use format::NumberFormat;
+pub use fujiwhara::InteractionModel;
+pub use geometry::Geometry;
+pub use integration::{IntegrationScheme, StepPolicy};
+pub use lang::OutputLanguage;
+pub use nudging::{Nudging, Observation};
+pub use rossby::RossbyWaveTrain;
+pub use scenario::Scenario;
+pub use season::{BackgroundState, Season};
+pub use series::ResultSeries;
+pub use streamer::PvStreamer;
+pub use structure::{AnomalyShape, HorizontalStructure};
+pub use surface::{DragLaw, SurfaceType};
+pub use table::TableFormatter;
+pub use trough::UpperTrough;
+pub use units::WindUnit;
+pub use validation::{ValidationLimits, ValidationPolicy};
+
+use validation::Bound;
+
+/// Constantes physiques regroupées dans une structure pour une meilleure organisation
+#[derive(Debug, Clone, Copy)]
+pub struct PhysicalConstants {
+    earth_omega: f64,      // Vitesse de rotation de la Terre (rad/s)
+    gravity: f64,          // Accélération gravitationnelle (m/s²)
+    base_temp: f64,       // Température de référence (K)
+}
+
+impl Default for PhysicalConstants {
+    fn default() -> Self {
+        Self {
+            earth_omega: 7.2921e-5,
+            gravity: 9.81,
+            base_temp: 288.15,
+        }
+    }
+}
+
+/// Types d'erreurs personnalisés
+#[derive(Debug)]
+pub enum MeteoError {
+    InvalidLatitude(f64),
+    InvalidPressure(f64),
+    InvalidTemperature(f64),
+    InvalidAltitude(f64),
+    InvalidLongitude(f64),
+    InvalidScale(f64),
+    InvalidSounding(String),
+    InvalidMetar(String),
+    InvalidGrib(String),
+    InvalidNetcdf(String),
+    InvalidScenario(String),
+    InvalidInteraction(String),
+    InvalidAspectRatio(f64),
+    InvalidTilt(f64),
+    InvalidGradient(f64),
+    InvalidTimeStep(f64),
+    InvalidNudging(String),
+    InvalidRun(String),
+    InvalidObservations(String),
+    InvalidGrid(String),
+    InvalidBinary(String),
+    InvalidClimatology(String),
+    NumericalInstability(StateSnapshot),
+}
+
+impl MeteoError {
+    /// Nom de la variante, stable d'une langue à l'autre
+    pub fn kind(&self) -> &'static str {
+        match self {
+            MeteoError::InvalidLatitude(_) => "InvalidLatitude",
+            MeteoError::InvalidPressure(_) => "InvalidPressure",
+            MeteoError::InvalidTemperature(_) => "InvalidTemperature",
+            MeteoError::InvalidAltitude(_) => "InvalidAltitude",
+            MeteoError::InvalidLongitude(_) => "InvalidLongitude",
+            MeteoError::InvalidScale(_) => "InvalidScale",
+            MeteoError::InvalidSounding(_) => "InvalidSounding",
+            MeteoError::InvalidMetar(_) => "InvalidMetar",
+            MeteoError::InvalidGrib(_) => "InvalidGrib",
+            MeteoError::InvalidNetcdf(_) => "InvalidNetcdf",
+            MeteoError::InvalidScenario(_) => "InvalidScenario",
+            MeteoError::InvalidInteraction(_) => "InvalidInteraction",
+            MeteoError::InvalidAspectRatio(_) => "InvalidAspectRatio",
+            MeteoError::InvalidTilt(_) => "InvalidTilt",
+            MeteoError::InvalidGradient(_) => "InvalidGradient",
+            MeteoError::InvalidTimeStep(_) => "InvalidTimeStep",
+            MeteoError::InvalidNudging(_) => "InvalidNudging",
+            MeteoError::InvalidRun(_) => "InvalidRun",
+            MeteoError::InvalidObservations(_) => "InvalidObservations",
+            MeteoError::InvalidGrid(_) => "InvalidGrid",
+            MeteoError::InvalidBinary(_) => "InvalidBinary",
+            MeteoError::InvalidClimatology(_) => "InvalidClimatology",
+            MeteoError::NumericalInstability(_) => "NumericalInstability",
+        }
+    }
+
+    /// Message d'erreur dans la langue demandée
+    ///
+    /// Le détail des erreurs de lecture de fichiers reste rédigé en français.
+    pub fn message(&self, language: OutputLanguage) -> String {
+        match (language, self) {
+            (OutputLanguage::French, MeteoError::InvalidLatitude(lat)) => format!("Latitude invalide: {}°", lat),
+            (OutputLanguage::French, MeteoError::InvalidPressure(p)) => format!("Pression invalide: {} hPa", p),
+            (OutputLanguage::French, MeteoError::InvalidTemperature(t)) => format!("Température invalide: {} K", t),
+            (OutputLanguage::French, MeteoError::InvalidAltitude(a)) => format!("Altitude invalide: {} m", a),
+            (OutputLanguage::French, MeteoError::InvalidLongitude(lon)) => format!("Longitude invalide: {}°", lon),
+            (OutputLanguage::French, MeteoError::InvalidScale(r)) => format!("Échelle horizontale invalide: {} m", r),
+            (OutputLanguage::French, MeteoError::InvalidSounding(msg)) => format!("Sondage invalide: {}", msg),
+            (OutputLanguage::French, MeteoError::InvalidMetar(msg)) => format!("METAR invalide: {}", msg),
+            (OutputLanguage::French, MeteoError::InvalidGrib(msg)) => format!("GRIB2 invalide: {}", msg),
+            (OutputLanguage::French, MeteoError::InvalidNetcdf(msg)) => format!("NetCDF invalide: {}", msg),
+            (OutputLanguage::French, MeteoError::InvalidScenario(msg)) => format!("Scénario invalide: {}", msg),
+            (OutputLanguage::French, MeteoError::InvalidInteraction(msg)) => format!("Interaction invalide: {}", msg),
+            (OutputLanguage::French, MeteoError::InvalidAspectRatio(ratio)) => format!("Rapport d'aspect invalide: {}", ratio),
+            (OutputLanguage::French, MeteoError::InvalidTilt(offset)) => format!("Décalage horizontal invalide: {} m", offset),
+            (OutputLanguage::French, MeteoError::InvalidGradient(gradient)) => format!("Gradient méridien invalide: {} K/1000 km", gradient),
+            (OutputLanguage::French, MeteoError::InvalidTimeStep(time_step)) => format!("Pas de temps invalide: {} s", time_step),
+            (OutputLanguage::French, MeteoError::InvalidNudging(msg)) => format!("Rappel invalide: {}", msg),
+            (OutputLanguage::French, MeteoError::InvalidRun(msg)) => format!("Résultats invalides: {}", msg),
+            (OutputLanguage::French, MeteoError::InvalidObservations(msg)) => format!("Observations invalides: {}", msg),
+            (OutputLanguage::French, MeteoError::InvalidGrid(msg)) => format!("Grille invalide: {}", msg),
+            (OutputLanguage::French, MeteoError::InvalidBinary(msg)) => format!("Fichier binaire invalide: {}", msg),
+            (OutputLanguage::French, MeteoError::InvalidClimatology(msg)) => format!("Climatologie invalide: {}", msg),
+            (OutputLanguage::French, MeteoError::NumericalInstability(state)) => format!(
+                "Instabilité numérique à l'heure {}: tourbillon {} s⁻¹, vitesse verticale {} m/s, intensités {}",
+                state.hour, state.relative_vorticity, state.vertical_velocity, state.intensity_list()
+            ),
+            (OutputLanguage::English, MeteoError::InvalidLatitude(lat)) => format!("Invalid latitude: {}°", lat),
+            (OutputLanguage::English, MeteoError::InvalidPressure(p)) => format!("Invalid pressure: {} hPa", p),
+            (OutputLanguage::English, MeteoError::InvalidTemperature(t)) => format!("Invalid temperature: {} K", t),
+            (OutputLanguage::English, MeteoError::InvalidAltitude(a)) => format!("Invalid altitude: {} m", a),
+            (OutputLanguage::English, MeteoError::InvalidLongitude(lon)) => format!("Invalid longitude: {}°", lon),
+            (OutputLanguage::English, MeteoError::InvalidScale(r)) => format!("Invalid horizontal scale: {} m", r),
+            (OutputLanguage::English, MeteoError::InvalidSounding(msg)) => format!("Invalid sounding: {}", msg),
+            (OutputLanguage::English, MeteoError::InvalidMetar(msg)) => format!("Invalid METAR: {}", msg),
+            (OutputLanguage::English, MeteoError::InvalidGrib(msg)) => format!("Invalid GRIB2: {}", msg),
+            (OutputLanguage::English, MeteoError::InvalidNetcdf(msg)) => format!("Invalid NetCDF: {}", msg),
+            (OutputLanguage::English, MeteoError::InvalidScenario(msg)) => format!("Invalid scenario: {}", msg),
+            (OutputLanguage::English, MeteoError::InvalidInteraction(msg)) => format!("Invalid interaction: {}", msg),
+            (OutputLanguage::English, MeteoError::InvalidAspectRatio(ratio)) => format!("Invalid aspect ratio: {}", ratio),
+            (OutputLanguage::English, MeteoError::InvalidTilt(offset)) => format!("Invalid horizontal offset: {} m", offset),
+            (OutputLanguage::English, MeteoError::InvalidGradient(gradient)) => format!("Invalid meridional gradient: {} K/1000 km", gradient),
+            (OutputLanguage::English, MeteoError::InvalidTimeStep(time_step)) => format!("Invalid time step: {} s", time_step),
+            (OutputLanguage::English, MeteoError::InvalidNudging(msg)) => format!("Invalid nudging: {}", msg),
+            (OutputLanguage::English, MeteoError::InvalidRun(msg)) => format!("Invalid results: {}", msg),
+            (OutputLanguage::English, MeteoError::InvalidObservations(msg)) => format!("Invalid observations: {}", msg),
+            (OutputLanguage::English, MeteoError::InvalidGrid(msg)) => format!("Invalid grid: {}", msg),
+            (OutputLanguage::English, MeteoError::InvalidBinary(msg)) => format!("Invalid binary file: {}", msg),
+            (OutputLanguage::English, MeteoError::InvalidClimatology(msg)) => format!("Invalid climatology: {}", msg),
+            (OutputLanguage::English, MeteoError::NumericalInstability(state)) => format!(
+                "Numerical instability at hour {}: vorticity {} s⁻¹, vertical velocity {} m/s, intensities {}",
+                state.hour, state.relative_vorticity, state.vertical_velocity, state.intensity_list()
+            ),
+        }
+    }
+}
+
+impl fmt::Display for MeteoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.message(OutputLanguage::default()))
+    }
+}
+
+impl Error for MeteoError {}
+
+/// État du modèle au pas où l'intégration a divergé
+#[derive(Debug, Clone, PartialEq)]
+pub struct StateSnapshot {
+    pub hour: u32,
+    pub vertical_velocity: f64,   // m/s
+    pub relative_vorticity: f64,  // s⁻¹
+    pub intensities: Vec<f64>,    // Intensité de chaque anomalie
+}
+
+impl StateSnapshot {
+    fn intensity_list(&self) -> String {
+        let intensities: Vec<_> = self.intensities.iter().map(f64::to_string).collect();
+        intensities.join(" / ")
+    }
+}
+
+/// Rapport maximal entre le tourbillon relatif et le tourbillon planétaire 2Ω
+/// au-delà duquel l'intégration est considérée comme divergente
+pub const MAX_VORTICITY_RATIO: f64 = 1.0e3;
+
+/// Épaisseur de la troposphère de l'état de fond de référence (m)
+pub(crate) const TROPOSPHERE_DEPTH: f64 = 1.0e4;
+
+/// Résultats du développement de la perturbation, sérialisés en unités SI
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DevelopmentResult<T: Float = f64> {
+    hour: u32,
+    vertical_velocity: T,
+    relative_vorticity: T,
+    #[serde(default)]
+    low_level_divergence: T,    // Négative en cas de convergence (s⁻¹)
+    #[serde(default)]
+    upper_level_divergence: T,  // Au sommet de la troposphère (s⁻¹)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    valid_time: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    latitude: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    longitude: Option<f64>,
+}
+
+impl<T: Float> DevelopmentResult<T> {
+    /// Heure du résultat depuis le départ de la simulation
+    pub fn hour(&self) -> u32 {
+        self.hour
+    }
+
+    /// Vitesse verticale (m/s), positive pour une ascendance
+    pub fn vertical_velocity(&self) -> T {
+        self.vertical_velocity
+    }
+
+    /// Tourbillon relatif (s⁻¹)
+    pub fn relative_vorticity(&self) -> T {
+        self.relative_vorticity
+    }
+
+    /// Date de validité du résultat, si la simulation a une date de départ
+    pub fn valid_time(&self) -> Option<DateTime<Utc>> {
+        self.valid_time
+    }
+
+    /// Divergence dans les basses couches (s⁻¹), négative en cas de convergence
+    pub fn low_level_divergence(&self) -> T {
+        self.low_level_divergence
+    }
+
+    /// Divergence en haute troposphère (s⁻¹)
+    pub fn upper_level_divergence(&self) -> T {
+        self.upper_level_divergence
+    }
+
+    /// Latitude du système (°), connue lorsque la géométrie le fait se déplacer
+    pub fn latitude(&self) -> Option<f64> {
+        self.latitude
+    }
+
+    /// Longitude du système (°), connue en géométrie sphérique
+    pub fn longitude(&self) -> Option<f64> {
+        self.longitude
+    }
+
+    /// Convertit les résultats en format lisible
+    #[deprecated(note = "utiliser `Display` ou `TableFormatter`")]
+    pub fn to_string_formatted(&self) -> String {
+        self.to_string_with(&NumberFormat::default())
+    }
+
+    /// Convertit les résultats en format lisible selon les règles données
+    pub fn to_string_with(&self, format: &NumberFormat) -> String {
+        TableFormatter::default().with_number_format(*format).row(self)
+    }
+}
+
+/// Ligne du tableau par défaut : heure, vitesse verticale (cm/s) et tourbillon relatif (10⁻⁵ s⁻¹)
+impl<T: Float> fmt::Display for DevelopmentResult<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&TableFormatter::default().row(self))
+    }
+}
+
+/// Position géographique et conditions atmosphériques
+#[derive(Debug, Clone)]
+pub struct Position {
+    latitude: f64,
+    altitude: f64,
+    pressure: f64,
+    surface: Option<SurfaceType>,  // Nature de la surface, pour l'anomalie de surface
+    drag_law: Option<DragLaw>,     // Loi de traînée, prioritaire sur celle de la surface
+}
+
+impl Position {
+    /// Crée une nouvelle position avec validation
+    pub fn new(latitude: f64, altitude: f64, pressure: f64) -> Result<Self, MeteoError> {
+        Self::with_policy(latitude, altitude, pressure, ValidationPolicy::Strict)
+    }
+
+    /// Crée une nouvelle position selon la politique de validation
+    pub fn with_policy(latitude: f64, altitude: f64, pressure: f64, policy: ValidationPolicy) -> Result<Self, MeteoError> {
+        Self::with_limits(latitude, altitude, pressure, policy, &ValidationLimits::default())
+    }
+
+    /// Crée une nouvelle position selon la politique et les plages de validation
+    pub fn with_limits(
+        latitude: f64,
+        altitude: f64,
+        pressure: f64,
+        policy: ValidationPolicy,
+        limits: &ValidationLimits,
+    ) -> Result<Self, MeteoError> {
+        Ok(Self {
+            latitude: limits.apply(policy, Bound::Latitude, latitude)?,
+            altitude: limits.apply(policy, Bound::Altitude, altitude)?,
+            pressure: limits.apply(policy, Bound::Pressure, pressure)?,
+            surface: None,
+            drag_law: None,
+        })
+    }
+
+    /// Position standard de l'anomalie de surface
+    pub fn surface(latitude: f64) -> Result<Self, MeteoError> {
+        Self::new(latitude, 0.0, 1013.0)
+    }
+
+    /// Position standard de l'anomalie d'altitude (500 hPa)
+    pub fn upper_level(latitude: f64) -> Result<Self, MeteoError> {
+        Self::new(latitude, 5000.0, 500.0)
+    }
+}
+
+/// Rayon horizontal de référence des anomalies (m)
+pub const HORIZONTAL_SCALE: f64 = 5.0e5;  // 500 km
+
+/// Rayon de déformation de Rossby des moyennes latitudes (m), NH/f pour
+/// N = 10⁻² s⁻¹, H = 10 km et f = 10⁻⁴ s⁻¹
+const DEFORMATION_RADIUS: f64 = 1.0e6;
+
+/// Rayons horizontaux acceptés (m)
+const SCALE_RANGE: std::ops::RangeInclusive<f64> = 1.0e4..=1.0e7;
+
+/// Pression (hPa) sous laquelle une anomalie appartient à la haute troposphère :
+/// elle reçoit alors le forçage du train d'ondes et du filament plutôt que le chauffage diurne
+const UPPER_LEVEL_PRESSURE: f64 = 700.0;
+
+/// Anomalie thermique
+#[derive(Debug)]
+pub struct ThermalAnomaly<T: Float = f64> {
+    temperature_delta: T,
+    position: Position,
+    is_cyclonic: bool,
+    intensity: T,
+    structure: HorizontalStructure,
+    constants: PhysicalConstants,
+}
+
+impl<T: Float> ThermalAnomaly<T> {
+    /// Crée une nouvelle anomalie thermique
+    pub fn new(
+        temperature_delta: T,
+        position: Position,
+        constants: PhysicalConstants,
+    ) -> Result<Self, MeteoError> {
+        Self::with_policy(temperature_delta, position, constants, ValidationPolicy::Strict)
+    }
+
+    /// Crée une nouvelle anomalie thermique selon la politique de validation
+    pub fn with_policy(
+        temperature_delta: T,
+        position: Position,
+        constants: PhysicalConstants,
+        policy: ValidationPolicy,
+    ) -> Result<Self, MeteoError> {
+        Self::with_limits(temperature_delta, position, constants, policy, &ValidationLimits::default())
+    }
+
+    /// Crée une nouvelle anomalie thermique selon la politique et les plages de validation
+    pub fn with_limits(
+        temperature_delta: T,
+        position: Position,
+        constants: PhysicalConstants,
+        policy: ValidationPolicy,
+        limits: &ValidationLimits,
+    ) -> Result<Self, MeteoError> {
+        let requested = temperature_delta.to_f64();
+        let value = limits.apply(policy, Bound::Temperature, requested)?;
+        // La valeur ramenée dans sa plage remplace la valeur demandée
+        let temperature_delta = if value == requested { temperature_delta } else { T::from_f64(value) };
+
+        Ok(Self {
+            temperature_delta,
+            position,
+            is_cyclonic: value > 0.0,
+            intensity: T::from_f64(1.0),
+            structure: HorizontalStructure::default(),
+            constants,
+        })
+    }
+
+    /// Fixe le rayon horizontal de l'anomalie (m) en conservant son profil
+    pub fn with_radius(mut self, radius: f64) -> Result<Self, MeteoError> {
+        self.structure = HorizontalStructure::new(radius, self.structure.shape())?;
+        Ok(self)
+    }
+
+    /// Fixe la géométrie horizontale de l'anomalie
+    pub fn with_structure(mut self, structure: HorizontalStructure) -> Self {
+        self.structure = structure;
+        self
+    }
+
+    /// Géométrie horizontale de l'anomalie
+    pub fn structure(&self) -> HorizontalStructure {
+        self.structure
+    }
+
+    /// Sélectivité d'échelle du taux de croissance, égale à 1 au rayon de référence
+    ///
+    /// Le nombre d'onde k = 1/rayon est comparé au rayon de déformation comme dans
+    /// le modèle d'Eady : les ondes plus courtes que 0,42 L_R ne croissent pas.
+    fn scale_selectivity(&self) -> f64 {
+        eady_growth_shape(DEFORMATION_RADIUS / self.structure.radius())
+            / eady_growth_shape(DEFORMATION_RADIUS / HORIZONTAL_SCALE)
+    }
+
+    /// Intensité de l'anomalie à une heure, croissant avec la sélectivité d'échelle
+    fn intensity_at(&self, hour: u32) -> f64 {
+        1.0 + (hour as f64 / 12.0) * self.scale_selectivity()
+    }
+
+    fn is_upper_level(&self) -> bool {
+        self.position.pressure <= UPPER_LEVEL_PRESSURE
+    }
+
+    fn compute_coriolis_force(&self) -> f64 {
+        self.constants.earth_omega * (self.position.latitude * PI / 180.0).sin()
+    }
+
+    fn compute_relative_vorticity(&self, thermal_wind: T) -> T {
+        let amplification = T::from_f64(self.structure.vorticity_factor());
+
+        let base_vorticity = thermal_wind / T::from_f64(self.structure.radius());
+        let altitude_factor = T::from_f64(if self.position.pressure < 500.0 { 2.0 } else { 1.0 });
+        
+        if self.is_cyclonic {
+            base_vorticity * self.intensity * altitude_factor * amplification
+        } else {
+            -base_vorticity * self.intensity * altitude_factor * amplification
+        }
+    }
+
+    fn develop_baroclinic_perturbation(
+        &mut self,
+        hour: u32,
+        forcing: f64,
+        tropopause_height: f64,
+    ) -> (DevelopmentResult<T>, AnomalyDiagnostics) {
+        // Mise à jour de l'intensité
+        self.intensity = T::from_f64(self.intensity_at(hour));
+        
+        let coriolis = T::from_f64(self.compute_coriolis_force());
+        
+        // Calcul du vent thermique
+        let base_wind = (self.temperature_delta + T::from_f64(forcing)) / T::from_f64(self.constants.base_temp) *
+                       T::from_f64(self.constants.gravity) * T::from_f64(1000.0);
+        let thermal_wind = if self.is_cyclonic {
+            base_wind * coriolis
+        } else {
+            -base_wind * coriolis
+        };
+
+        // Calcul de la vitesse verticale
+        let pressure_factor = T::from_f64((1000.0 / self.position.pressure).sqrt());
+        let altitude_factor = T::from_f64(tropopause::altitude_factor(self.position.altitude, tropopause_height));
+        let damping = T::from_f64(0.1);
+        
+        let vertical_velocity = if self.position.pressure > 500.0 {
+            thermal_wind * damping * pressure_factor * altitude_factor
+        } else {
+            -thermal_wind * damping * pressure_factor * altitude_factor
+        } * self.intensity;
+
+        let relative_vorticity = self.compute_relative_vorticity(thermal_wind);
+
+        let terms = AnomalyDiagnostics {
+            pressure: self.position.pressure,
+            coriolis: coriolis.to_f64(),
+            forcing,
+            thermal_wind: thermal_wind.to_f64(),
+            pressure_factor: pressure_factor.to_f64(),
+            altitude_factor: altitude_factor.to_f64(),
+            intensity: self.intensity.to_f64(),
+            vertical_velocity: vertical_velocity.to_f64(),
+            relative_vorticity: relative_vorticity.to_f64(),
+            friction_factor: 1.0,
+            interaction_factor: 1.0,
+        };
+        let development = DevelopmentResult {
+            vertical_velocity,
+            relative_vorticity,
+            low_level_divergence: T::from_f64(0.0),
+            upper_level_divergence: T::from_f64(0.0),
+            hour,
+            valid_time: None,
+            latitude: None,
+            longitude: None,
+        };
+        (development, terms)
+    }
+}
+
+/// Forme du taux de croissance d'Eady en fonction de μ = k·L_R, nulle au-delà
+/// de la coupure μ ≈ 2,4
+fn eady_growth_shape(mu: f64) -> f64 {
+    let half = mu / 2.0;
+    ((half - half.tanh()) * (1.0 / half.tanh() - half)).max(0.0).sqrt()
+}
+
+/// Gradient méridien de température de la zone barocline de référence (K/1000 km)
+pub const REFERENCE_MERIDIONAL_GRADIENT: f64 = 10.0;
+
+/// Gradients méridiens acceptés (K/1000 km)
+const GRADIENT_RANGE: std::ops::RangeInclusive<f64> = 0.0..=50.0;
+
+/// Altitude (m) et pression (hPa) de l'anomalie de surface
+pub(crate) const SURFACE_LEVEL: (f64, f64) = (0.0, 1013.0);
+
+/// Altitude (m) et pression (hPa) de l'anomalie d'altitude
+pub(crate) const UPPER_LEVEL: (f64, f64) = (5000.0, 500.0);
+
+/// Structure principale pour la simulation de cyclogénèse
+pub struct BaroclinicCyclogenesis<T: Float = f64> {
+    anomalies: Vec<ThermalAnomaly<T>>,
+    weights: Vec<Vec<f64>>,  // Poids de l'interaction de chaque anomalie avec les autres
+    meridional_gradient: f64,  // Gradient méridien de température de fond (K/1000 km)
+    tropopause_height: f64,    // Hauteur de la tropopause (m)
+    boundary_layer: Option<BoundaryLayer>,  // Couche limite évolutive, hauteur implicite constante sinon
+    tilt: Option<f64>,  // Décalage vers l'ouest de l'anomalie d'altitude (m)
+    geometry: Geometry,
+    initial_latitude: f64,
+    longitude: f64,      // Longitude de la trajectoire (°)
+    start_time: Option<DateTime<Utc>>,
+    diurnal_longitude: Option<f64>,
+    rossby_wave: Option<RossbyWaveTrain>,
+    pv_streamer: Option<PvStreamer>,
+    upper_trough: Option<UpperTrough>,
+    scheme: IntegrationScheme,
+    time_step: f64,  // Pas de temps de l'intégration (s)
+    step_policy: StepPolicy,
+    nudging: Option<Nudging>,
+    nudging_correction: T,  // Correction du tourbillon relatif par le rappel (s⁻¹)
+    exchanged_vorticity: T,  // Tourbillon échangé avec les tempêtes voisines (s⁻¹)
+    first_hour: u32,  // Première heure simulée, non nulle pour une reprise
+    diagnostics_level: DiagnosticsLevel,
+    anomaly_diagnostics: Vec<AnomalyDiagnostics>,  // Chaîne de calcul du dernier pas, au niveau complet
+}
+
+impl<T: Float> BaroclinicCyclogenesis<T> {
+    /// Crée une nouvelle instance de simulation
+    pub fn new(
+        surface_temp: T,
+        altitude_temp: T,
+        latitude: f64,
+    ) -> Result<Self, MeteoError> {
+        Self::with_policy(surface_temp, altitude_temp, latitude, ValidationPolicy::Strict)
+    }
+
+    /// Crée une simulation selon la politique de validation des paramètres
+    pub fn with_policy(
+        surface_temp: T,
+        altitude_temp: T,
+        latitude: f64,
+        policy: ValidationPolicy,
+    ) -> Result<Self, MeteoError> {
+        Self::with_limits(surface_temp, altitude_temp, latitude, policy, &ValidationLimits::default())
+    }
+
+    /// Crée une simulation selon la politique et les plages de validation
+    pub fn with_limits(
+        surface_temp: T,
+        altitude_temp: T,
+        latitude: f64,
+        policy: ValidationPolicy,
+        limits: &ValidationLimits,
+    ) -> Result<Self, MeteoError> {
+        Self::with_upper_level(surface_temp, altitude_temp, latitude, UPPER_LEVEL, policy, limits)
+    }
+
+    /// Crée une simulation dont l'anomalie d'altitude est placée à
+    /// `upper_level`, altitude (m) et pression (hPa), selon la politique et les
+    /// plages de validation
+    pub fn with_upper_level(
+        surface_temp: T,
+        altitude_temp: T,
+        latitude: f64,
+        upper_level: (f64, f64),
+        policy: ValidationPolicy,
+        limits: &ValidationLimits,
+    ) -> Result<Self, MeteoError> {
+        limits.validate()?;
+        let constants = PhysicalConstants::default();
+        let surface_position = Position::with_limits(latitude, SURFACE_LEVEL.0, SURFACE_LEVEL.1, policy, limits)?;
+        let altitude_position = Position::with_limits(latitude, upper_level.0, upper_level.1, policy, limits)?;
+        let surface_anomaly = ThermalAnomaly::with_limits(surface_temp, surface_position, constants, policy, limits)?;
+        let altitude_anomaly = ThermalAnomaly::with_limits(altitude_temp, altitude_position, constants, policy, limits)?;
+        Ok(Self::from_anomalies(surface_anomaly, altitude_anomaly))
+    }
+
+    /// Crée une simulation à partir d'anomalies déjà construites
+    pub fn from_anomalies(surface_anomaly: ThermalAnomaly<T>, altitude_anomaly: ThermalAnomaly<T>) -> Self {
+        Self::with_weights(vec![surface_anomaly, altitude_anomaly], vec![vec![0.0, 1.0], vec![1.0, 0.0]])
+    }
+
+    fn with_weights(anomalies: Vec<ThermalAnomaly<T>>, weights: Vec<Vec<f64>>) -> Self {
+        let initial_latitude = anomalies[0].position.latitude;
+        Self {
+            anomalies,
+            weights,
+            meridional_gradient: REFERENCE_MERIDIONAL_GRADIENT,
+            tropopause_height: TROPOSPHERE_DEPTH,
+            boundary_layer: None,
+            tilt: None,
+            geometry: Geometry::default(),
+            initial_latitude,
+            longitude: 0.0,
+            start_time: None,
+            diurnal_longitude: None,
+            rossby_wave: None,
+            pv_streamer: None,
+            upper_trough: None,
+            scheme: IntegrationScheme::default(),
+            time_step: integration::DEFAULT_TIME_STEP,
+            step_policy: StepPolicy::default(),
+            nudging: None,
+            nudging_correction: T::from_f64(0.0),
+            exchanged_vorticity: T::from_f64(0.0),
+            first_hour: 0,
+            diagnostics_level: DiagnosticsLevel::default(),
+            anomaly_diagnostics: Vec::new(),
+        }
+    }
+
+    /// Crée une simulation d'un nombre quelconque d'anomalies en interaction
+    ///
+    /// `weights[i][j]` pondère l'effet de l'anomalie `j` sur le couplage barocline
+    /// de l'anomalie `i` ; la diagonale est ignorée. Un poids de 1 entre une
+    /// anomalie de surface et une anomalie d'altitude reproduit `from_anomalies`.
+    pub fn from_interacting_anomalies(
+        anomalies: Vec<ThermalAnomaly<T>>,
+        weights: Vec<Vec<f64>>,
+    ) -> Result<Self, MeteoError> {
+        if anomalies.is_empty() {
+            return Err(MeteoError::InvalidInteraction("aucune anomalie".to_string()));
+        }
+        if weights.len() != anomalies.len() || weights.iter().any(|row| row.len() != anomalies.len()) {
+            return Err(MeteoError::InvalidInteraction(format!(
+                "la matrice des poids doit être de taille {0}×{0}",
+                anomalies.len()
+            )));
+        }
+        if let Some(weight) = weights.iter().flatten().find(|weight| !(weight.is_finite() && **weight >= 0.0)) {
+            return Err(MeteoError::InvalidInteraction(format!("poids invalide: {}", weight)));
+        }
+
+        Ok(Self::with_weights(anomalies, weights))
+    }
+
+    /// Anomalie la plus basse, base des diagnostics à deux couches
+    fn surface_anomaly(&self) -> &ThermalAnomaly<T> {
+        &self.anomalies[self.surface_index()]
+    }
+
+    /// Indice de l'anomalie la plus basse
+    fn surface_index(&self) -> usize {
+        self.anomalies
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.position.pressure.total_cmp(&b.position.pressure))
+            .map(|(index, _)| index)
+            .expect("au moins une anomalie")
+    }
+
+    /// Anomalie la plus haute, sommet des diagnostics à deux couches
+    fn altitude_anomaly(&self) -> &ThermalAnomaly<T> {
+        self.anomalies
+            .iter()
+            .min_by(|a, b| a.position.pressure.total_cmp(&b.position.pressure))
+            .expect("au moins une anomalie")
+    }
+
+    /// Fixe la date de départ (UTC) pour dater les résultats
+    pub fn with_start_time(mut self, start_time: DateTime<Utc>) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    /// Date de départ de la simulation, si elle a été fixée
+    pub fn start_time(&self) -> Option<DateTime<Utc>> {
+        self.start_time
+    }
+
+    /// Fixe le rayon horizontal (m) de toutes les anomalies
+    pub fn with_horizontal_scale(mut self, radius: f64) -> Result<Self, MeteoError> {
+        self.anomalies = self
+            .anomalies
+            .into_iter()
+            .map(|anomaly| anomaly.with_radius(radius))
+            .collect::<Result<_, _>>()?;
+        Ok(self)
+    }
+
+    /// Fixe le profil horizontal de toutes les anomalies, en conservant leur rayon
+    pub fn with_anomaly_shape(mut self, shape: AnomalyShape) -> Result<Self, MeteoError> {
+        shape.validate()?;
+        for anomaly in &mut self.anomalies {
+            anomaly.structure = HorizontalStructure::new(anomaly.structure.radius(), shape)?;
+        }
+        Ok(self)
+    }
+
+    /// Fixe le gradient méridien de température de fond (K/1000 km)
+    ///
+    /// Le vent thermique entre les niveaux et le couplage barocline lui sont
+    /// proportionnels ; la valeur de référence est de 10 K/1000 km.
+    pub fn with_meridional_gradient(mut self, gradient: f64) -> Result<Self, MeteoError> {
+        if !GRADIENT_RANGE.contains(&gradient) {
+            log_event!(warn, gradient, "gradient méridien rejeté");
+            return Err(MeteoError::InvalidGradient(gradient));
+        }
+        self.meridional_gradient = gradient;
+        Ok(self)
+    }
+
+    /// Gradient méridien de température de fond (K/1000 km)
+    pub fn meridional_gradient(&self) -> f64 {
+        self.meridional_gradient
+    }
+
+    /// Intensité de la zone barocline rapportée à la zone de référence
+    fn baroclinicity(&self) -> f64 {
+        self.meridional_gradient / REFERENCE_MERIDIONAL_GRADIENT
+    }
+
+    /// Active ou supprime la zone barocline de référence
+    #[deprecated(note = "utiliser `with_meridional_gradient`")]
+    pub fn with_baroclinic_zone(mut self, enabled: bool) -> Self {
+        self.meridional_gradient = if enabled { REFERENCE_MERIDIONAL_GRADIENT } else { 0.0 };
+        self
+    }
+
+    /// La simulation se déroule dans une zone barocline
+    #[deprecated(note = "utiliser `meridional_gradient`")]
+    pub fn baroclinic_zone(&self) -> bool {
+        self.meridional_gradient > 0.0
+    }
+
+    /// Décale l'anomalie d'altitude vers l'ouest de l'anomalie de surface (m)
+    ///
+    /// Une valeur négative incline le système vers l'est.
+    pub fn with_tilt(mut self, offset: f64) -> Result<Self, MeteoError> {
+        if !offset.is_finite() {
+            log_event!(warn, offset, "décalage horizontal rejeté");
+            return Err(MeteoError::InvalidTilt(offset));
+        }
+        self.tilt = Some(offset);
+        Ok(self)
+    }
+
+    /// Décalage vers l'ouest de l'anomalie d'altitude (m), un quart de longueur
+    /// d'onde λ = 2π × rayon par défaut
+    pub fn tilt(&self) -> f64 {
+        self.tilt.unwrap_or_else(|| FRAC_PI_2 * self.mean_radius())
+    }
+
+    /// Efficacité du couplage entre niveaux : sin(2π × décalage / λ), maximale au
+    /// quart de longueur d'onde, nulle pour des anomalies superposées et négative
+    /// pour une inclinaison vers l'est
+    pub fn tilt_efficiency(&self) -> f64 {
+        (self.tilt() / self.mean_radius()).sin()
+    }
+
+    fn mean_radius(&self) -> f64 {
+        (self.surface_anomaly().structure.radius() + self.altitude_anomaly().structure.radius()) / 2.0
+    }
+
+    /// Active le chauffage solaire diurne de l'anomalie de surface à la longitude donnée
+    ///
+    /// Le forçage n'a d'effet que si une date de départ est fixée.
+    pub fn with_diurnal_cycle(mut self, longitude: f64) -> Result<Self, MeteoError> {
+        if !(-180.0..=360.0).contains(&longitude) {
+            log_event!(warn, longitude, "longitude rejetée");
+            return Err(MeteoError::InvalidLongitude(longitude));
+        }
+        self.diurnal_longitude = Some(longitude);
+        self.longitude = (longitude + 180.0).rem_euclid(360.0) - 180.0;
+        Ok(self)
+    }
+
+    /// Choisit la géométrie du domaine, plan f par défaut
+    pub fn with_geometry(mut self, geometry: Geometry) -> Self {
+        self.geometry = geometry;
+        self
+    }
+
+    /// Fait défiler un train d'ondes de Rossby au-dessus de l'anomalie d'altitude
+    pub fn with_rossby_wave(mut self, wave: RossbyWaveTrain) -> Self {
+        self.rossby_wave = Some(wave);
+        self
+    }
+
+    /// Fait descendre un filament de tourbillon potentiel sur l'anomalie d'altitude
+    pub fn with_pv_streamer(mut self, streamer: PvStreamer) -> Self {
+        self.pv_streamer = Some(streamer);
+        self
+    }
+
+    /// Chauffage solaire de l'anomalie de surface à une heure donnée (K)
+    ///
+    /// Le terme suit l'ensoleillement au sommet de l'atmosphère, décalé de l'inertie
+    /// thermique du sol, diminué de sa moyenne journalière : il module l'anomalie
+    /// entre le jour et la nuit sans la réchauffer en moyenne.
+    pub fn solar_heating(&self, hour: u32) -> f64 {
+        const AMPLITUDE: f64 = 5.0;      // Chauffage pour un soleil au zénith (K)
+        const THERMAL_LAG: f64 = 3.0;    // Retard du maximum thermique sur midi solaire (h)
+        const OBLIQUITY: f64 = 23.44;    // Inclinaison de l'axe terrestre (°)
+
+        let (Some(start), Some(longitude)) = (self.start_time, self.diurnal_longitude) else {
+            return 0.0;
+        };
+        let time = start + TimeDelta::hours(i64::from(hour));
+
+        let latitude = self.surface_anomaly().position.latitude.to_radians();
+        let declination = (OBLIQUITY * (2.0 * PI * (284.0 + time.ordinal() as f64) / 365.0).sin()).to_radians();
+
+        let utc_hours = time.hour() as f64 + time.minute() as f64 / 60.0;
+        let solar_hours = utc_hours + longitude / 15.0 - THERMAL_LAG;
+        let hour_angle = (15.0 * (solar_hours - 12.0)).to_radians();
+
+        let (sin_product, cos_product) = (
+            latitude.sin() * declination.sin(),
+            latitude.cos() * declination.cos(),
+        );
+        let insolation = (sin_product + cos_product * hour_angle.cos()).max(0.0);
+
+        // Demi-durée du jour, bornée pour la nuit et le jour polaires
+        let sunset = (-latitude.tan() * declination.tan()).clamp(-1.0, 1.0).acos();
+        let daily_mean = (sunset * sin_product + cos_product * sunset.sin()) / PI;
+
+        AMPLITUDE * (insolation - daily_mean)
+    }
+
+    /// Heures des `time_steps` pas suivant la première heure de la simulation
+    ///
+    /// Renvoie `MeteoError::InvalidScenario` si la dernière heure dépasse `u32::MAX`.
+    pub fn hours(&self, time_steps: u32) -> Result<Range<u32>, MeteoError> {
+        let end = self.first_hour.checked_add(time_steps).ok_or_else(|| {
+            MeteoError::InvalidScenario(format!("{} heures après l'heure {} dépassent la dernière heure", time_steps, self.first_hour))
+        })?;
+        Ok(self.first_hour..end)
+    }
+
+    /// Calcule l'interaction entre les anomalies pour une heure donnée
+    ///
+    /// Renvoie `MeteoError::NumericalInstability` si un résultat n'est pas fini ou
+    /// si le tourbillon dépasse `MAX_VORTICITY_RATIO` fois le tourbillon planétaire.
+    pub fn step(&mut self, hour: u32) -> Result<DevelopmentResult<T>, MeteoError> {
+        let heating = self.solar_heating(hour);
+        let wave = self.rossby_wave.map_or(0.0, |wave| wave.temperature_perturbation(hour));
+
+        // Renforcement du couplage barocline, proportionnel au gradient méridien,
+        // pondéré pour chaque paire d'anomalies et modulé par l'inclinaison
+        let coupling = (1.5 * (1.0 + hour as f64 / 24.0) - 1.0) * self.baroclinicity();
+        let efficiency = self.coupling_efficiency(hour);
+        let upper_levels: Vec<bool> = self.anomalies.iter().map(ThermalAnomaly::is_upper_level).collect();
+
+        let coriolis = self.coriolis_parameter(self.latitude());
+        let elapsed = f64::from(hour) * 3600.0;
+        let (layer, pumping_scale, surface_index) = (self.boundary_layer, self.pumping_scale(), self.surface_index());
+        let (mut surface_wind, mut surface_flux, mut spin_down_rate) = (0.0, 0.0, 0.0);
+        let full = self.diagnostics_level == DiagnosticsLevel::Full;
+        let mut chain = Vec::with_capacity(if full { self.anomalies.len() } else { 0 });
+        let (mut vertical_velocity, mut relative_vorticity) = (T::from_f64(0.0), T::from_f64(0.0));
+        for (i, anomaly) in self.anomalies.iter_mut().enumerate() {
+            let forcing = match anomaly.position.surface {
+                _ if anomaly.is_upper_level() => {
+                    let streamer = self.pv_streamer.map_or(0.0, |streamer| {
+                        streamer.temperature_perturbation(hour, anomaly.position.altitude, self.tropopause_height)
+                    });
+                    let trough = self.upper_trough.map_or(0.0, |trough| trough.temperature_perturbation(hour));
+                    wave + streamer + trough
+                }
+                Some(surface) => surface.heat_flux(anomaly.constants.base_temp + anomaly.temperature_delta.to_f64(), heating),
+                None => heating,
+            };
+            // Un même flux réchauffe davantage une couche limite mince
+            let forcing = match layer {
+                Some(layer) if i == surface_index => forcing * layer.flux_scale(),
+                _ => forcing,
+            };
+            let (mut development, mut terms) = anomaly.develop_baroclinic_perturbation(hour, forcing, self.tropopause_height);
+            let wind = wind::peak_wind(anomaly.structure.shape(), anomaly.structure.radius(), development.relative_vorticity.to_f64());
+
+            // Usure du tourbillon par le frottement de surface depuis le départ,
+            // cumulée pas à pas sur une couche limite évolutive
+            if let Some(law) = anomaly.position.drag_law() {
+                let rate = law.spin_down_rate(wind, coriolis, self.tropopause_height) * pumping_scale;
+                terms.friction_factor = match layer {
+                    Some(layer) => layer.friction_factor(),
+                    None => (-rate * elapsed).exp(),
+                };
+                development.relative_vorticity = development.relative_vorticity * T::from_f64(terms.friction_factor);
+                if i == surface_index {
+                    spin_down_rate = rate;
+                }
+            }
+            if i == surface_index {
+                (surface_wind, surface_flux) = (wind, forcing);
+            }
+
+            let weight: f64 = self.weights[i]
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(j, weight)| if upper_levels[i] == upper_levels[j] { *weight } else { weight * efficiency })
+                .sum();
+            let interaction_factor = (1.0 + weight * coupling).max(0.0);
+            if full {
+                chain.push(AnomalyDiagnostics { interaction_factor, ..terms });
+            }
+            let interaction_factor = T::from_f64(interaction_factor);
+            vertical_velocity += development.vertical_velocity * interaction_factor;
+            relative_vorticity += development.relative_vorticity * interaction_factor;
+        }
+        self.anomaly_diagnostics = chain;
+        self.advance_boundary_layer(surface_wind, surface_flux, spin_down_rate);
+
+        // Continuité pour le premier mode barocline w(z) = w·sin(πz/H) :
+        // convergence sous l'ascendance, divergence au sommet de la troposphère
+        let divergence = T::from_f64(PI) * vertical_velocity / T::from_f64(self.tropopause_height);
+
+        let relative_vorticity = self.nudge(hour, relative_vorticity + self.beta_vorticity() + self.exchanged_vorticity);
+
+        let result = DevelopmentResult {
+            vertical_velocity,
+            relative_vorticity,
+            low_level_divergence: -divergence,
+            upper_level_divergence: divergence,
+            hour,
+            valid_time: self
+                .start_time
+                .map(|start| start + TimeDelta::hours(i64::from(hour))),
+            latitude: self.geometry.is_moving().then(|| self.latitude()),
+            longitude: (self.geometry == Geometry::Spherical).then_some(self.longitude),
+        };
+        log_event!(
+            trace,
+            hour,
+            heating,
+            wave,
+            vertical_velocity = result.vertical_velocity.to_f64(),
+            relative_vorticity = result.relative_vorticity.to_f64(),
+            "pas de temps"
+        );
+        self.check_stability(&result)?;
+        self.advance_track(&result);
+        Ok(result)
+    }
+
+    /// Garde-fou contre la divergence de l'intégration
+    fn check_stability(&self, result: &DevelopmentResult<T>) -> Result<(), MeteoError> {
+        let planetary_vorticity = 2.0 * self.surface_anomaly().constants.earth_omega;
+        let (vertical_velocity, relative_vorticity) = (result.vertical_velocity.to_f64(), result.relative_vorticity.to_f64());
+        let diverged = !vertical_velocity.is_finite()
+            || !relative_vorticity.is_finite()
+            || relative_vorticity.abs() > MAX_VORTICITY_RATIO * planetary_vorticity;
+        if !diverged {
+            return Ok(());
+        }
+
+        let state = StateSnapshot {
+            hour: result.hour,
+            vertical_velocity,
+            relative_vorticity,
+            intensities: self.anomalies.iter().map(|anomaly| anomaly.intensity.to_f64()).collect(),
+        };
+        log_event!(error, hour = state.hour, relative_vorticity = state.relative_vorticity, "intégration divergente");
+        Err(MeteoError::NumericalInstability(state))
+    }
+
+    /// Simule l'interaction entre les anomalies pendant `time_steps` heures
+    ///
+    /// Les heures sont comptées depuis la première heure de la simulation, nulle
+    /// sauf pour une reprise (`from_state`).
+    #[cfg_attr(feature = "tracing", tracing::instrument(
+        name = "simulation",
+        skip(self),
+        fields(latitude = self.surface_anomaly().position.latitude),
+    ))]
+    pub fn simulate_interaction(&mut self, time_steps: u32) -> Result<Vec<DevelopmentResult<T>>, MeteoError> {
+        self.hours(time_steps)?.map(|hour| self.step(hour)).collect()
+    }
+}
@@ -0,0 +1,188 @@
+//! Stades du cycle de vie selon les modèles conceptuels
+//!
+//! Chaque pas de temps reçoit un stade à partir des seuls pas déjà simulés : le
+//! tourbillon |ζ| atteint et son évolution d'une heure à l'autre. Le système
+//! naissant reste sous un seuil absolu de tourbillon ; au-delà, il forme une
+//! onde ouverte tant qu'il se creuse (|ζ| croît). Il devient mature lorsque le
+//! creusement cesse, puis s'occlut et décline à mesure que le tourbillon perd
+//! une part croissante du pic atteint jusque-là. Le déphasage entre les
+//! anomalies est relevé à chaque pas sans entrer dans le classement.
+//!
+//! Le modèle norvégien place l'occlusion en fin de vie, après une perte de 10 %
+//! du pic. Dans le modèle de Shapiro–Keyser, le front se fracture dès le début
+//! du creusement, à un seuil de tourbillon plus bas, et la séclusion chaude
+//! intervient plus tôt, dès 5 % de perte.
+
+use std::str::FromStr;
+
+use crate::diagnostics::StepDiagnostics;
+use crate::{DevelopmentResult, NumberFormat, OutputLanguage};
+
+/// Perte relative du pic au-delà de laquelle le système décline
+const DECAY_LOSS: f64 = 0.3;
+
+/// Modèle conceptuel du cycle de vie
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LifecycleModel {
+    #[default]
+    Norwegian,
+    ShapiroKeyser,
+}
+
+impl LifecycleModel {
+    /// Tourbillon (s⁻¹) sous lequel le système est naissant et perte relative du
+    /// pic séparant la maturité de l'occlusion
+    fn thresholds(self) -> (f64, f64) {
+        match self {
+            LifecycleModel::Norwegian => (5.0e-5, 0.1),
+            LifecycleModel::ShapiroKeyser => (3.0e-5, 0.05),
+        }
+    }
+}
+
+impl FromStr for LifecycleModel {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "norwegian" => Ok(LifecycleModel::Norwegian),
+            "shapiro-keyser" | "shapiro–keyser" => Ok(LifecycleModel::ShapiroKeyser),
+            _ => Err(format!("Modèle conceptuel inconnu: {} (norwegian, shapiro-keyser)", value)),
+        }
+    }
+}
+
+/// Stade du cycle de vie
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LifecycleStage {
+    Incipient,
+    OpenWave,
+    Mature,
+    Occluded,
+    Decaying,
+}
+
+impl LifecycleStage {
+    /// Nom du stade dans le vocabulaire du modèle
+    pub fn label(self, model: LifecycleModel, language: OutputLanguage) -> &'static str {
+        use LifecycleModel::{Norwegian, ShapiroKeyser};
+        use LifecycleStage::*;
+
+        match (language, model, self) {
+            (OutputLanguage::French, _, Incipient) => "naissant",
+            (OutputLanguage::French, Norwegian, OpenWave) => "onde ouverte",
+            (OutputLanguage::French, Norwegian, Mature) => "mature",
+            (OutputLanguage::French, Norwegian, Occluded) => "occlus",
+            (OutputLanguage::French, ShapiroKeyser, OpenWave) => "fracture frontale",
+            (OutputLanguage::French, ShapiroKeyser, Mature) => "front replié en T",
+            (OutputLanguage::French, ShapiroKeyser, Occluded) => "séclusion chaude",
+            (OutputLanguage::French, _, Decaying) => "en déclin",
+            (OutputLanguage::English, _, Incipient) => "incipient",
+            (OutputLanguage::English, Norwegian, OpenWave) => "open wave",
+            (OutputLanguage::English, Norwegian, Mature) => "mature",
+            (OutputLanguage::English, Norwegian, Occluded) => "occluded",
+            (OutputLanguage::English, ShapiroKeyser, OpenWave) => "frontal fracture",
+            (OutputLanguage::English, ShapiroKeyser, Mature) => "T-bone",
+            (OutputLanguage::English, ShapiroKeyser, Occluded) => "warm seclusion",
+            (OutputLanguage::English, _, Decaying) => "decaying",
+        }
+    }
+}
+
+/// Stade d'un pas de temps et grandeurs qui l'ont déterminé
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LifecycleStep {
+    pub hour: u32,
+    pub phase: f64,      // Déphasage entre les anomalies (°)
+    pub intensity: f64,  // Tourbillon rapporté au pic atteint jusque-là
+    pub stage: LifecycleStage,
+}
+
+/// Stade de chaque pas d'une simulation et de ses diagnostics, établi sans
+/// connaître les pas suivants
+pub fn tag(results: &[DevelopmentResult], diagnostics: &[StepDiagnostics], model: LifecycleModel) -> Vec<LifecycleStep> {
+    let (incipient, occlusion) = model.thresholds();
+    let mut peak: f64 = 0.0;
+    let mut previous: Option<f64> = None;
+
+    results
+        .iter()
+        .zip(diagnostics)
+        .map(|(result, step)| {
+            let vorticity = result.relative_vorticity.abs();
+            peak = peak.max(vorticity);
+            let intensity = if peak > 0.0 { vorticity / peak } else { 0.0 };
+            let deepening = previous.is_none_or(|previous| vorticity > previous);
+            previous = Some(vorticity);
+
+            let stage = if peak < incipient {
+                LifecycleStage::Incipient
+            } else if deepening {
+                LifecycleStage::OpenWave
+            } else if 1.0 - intensity <= occlusion {
+                LifecycleStage::Mature
+            } else if 1.0 - intensity <= DECAY_LOSS {
+                LifecycleStage::Occluded
+            } else {
+                LifecycleStage::Decaying
+            };
+            LifecycleStep { hour: result.hour, phase: step.phase.to_degrees().abs(), intensity, stage }
+        })
+        .collect()
+}
+
+/// Heure d'entrée dans chaque stade atteint, dans l'ordre de la simulation
+pub fn transitions(steps: &[LifecycleStep]) -> Vec<(u32, LifecycleStage)> {
+    let mut transitions: Vec<(u32, LifecycleStage)> = Vec::new();
+    for step in steps {
+        if transitions.last().is_none_or(|(_, stage)| *stage != step.stage) {
+            transitions.push((step.hour, step.stage));
+        }
+    }
+    transitions
+}
+
+/// Phrase résumant la succession des stades
+pub fn describe_transitions(steps: &[LifecycleStep], model: LifecycleModel, language: OutputLanguage) -> String {
+    let stages: Vec<_> = transitions(steps)
+        .iter()
+        .map(|(hour, stage)| match language {
+            OutputLanguage::French => format!("{} à l'heure {}", stage.label(model, language), hour),
+            OutputLanguage::English => format!("{} at hour {}", stage.label(model, language), hour),
+        })
+        .collect();
+    match language {
+        OutputLanguage::French => format!("Cycle de vie : {}", stages.join(", ")),
+        OutputLanguage::English => format!("Lifecycle: {}", stages.join(", ")),
+    }
+}
+
+/// Tableau du déphasage, de l'intensité relative et du stade à chaque heure, suivi de la succession des stades
+pub fn to_table(steps: &[LifecycleStep], model: LifecycleModel, language: OutputLanguage, format: &NumberFormat) -> String {
+    let header = match (language, model) {
+        (OutputLanguage::French, LifecycleModel::Norwegian) => {
+            "Heure | Déphasage (°)        | Intensité relative   | Stade (modèle norvégien)"
+        }
+        (OutputLanguage::French, LifecycleModel::ShapiroKeyser) => {
+            "Heure | Déphasage (°)        | Intensité relative   | Stade (Shapiro–Keyser)"
+        }
+        (OutputLanguage::English, LifecycleModel::Norwegian) => {
+            "Hour  | Phase (°)            | Relative intensity   | Stage (Norwegian model)"
+        }
+        (OutputLanguage::English, LifecycleModel::ShapiroKeyser) => {
+            "Hour  | Phase (°)            | Relative intensity   | Stage (Shapiro–Keyser)"
+        }
+    };
+
+    let mut table = format!("{}\n------|----------------------|----------------------|----------------------\n", header);
+    for step in steps {
+        table += &format!(
+            "{:4} | {} | {} | {}\n",
+            step.hour,
+            format.format_width(step.phase, 20),
+            format.format_width(step.intensity, 20),
+            step.stage.label(model, language)
+        );
+    }
+    table + &format!("\n{}\n", describe_transitions(steps, model, language))
+}
@@ -1,261 +1,1189 @@
-use std::f64::consts::PI;
-use std::error::Error;
-use std::fmt;
-
-/// Constantes physiques regroupées dans une structure pour une meilleure organisation
-#[derive(Debug, Clone, Copy)]
-pub struct PhysicalConstants {
-    earth_omega: f64,      // Vitesse de rotation de la Terre (rad/s)
-    gravity: f64,          // Accélération gravitationnelle (m/s²)
-    base_temp: f64,       // Température de référence (K)
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::Arc;
+
+use clap::error::ErrorKind;
+use clap::parser::ValueSource;
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand};
+
+use cyclogenese_rust::format::{ExponentStyle, Precision};
+use cyclogenese_rust::batch::{BatchFilter, BatchReport};
+use cyclogenese_rust::check::{DryRun, ScenarioCheck};
+use cyclogenese_rust::classify::{self, BuiltinClassifier};
+use cyclogenese_rust::cluster::Clustering;
+#[cfg(unix)]
+use cyclogenese_rust::daemon;
+use cyclogenese_rust::deepening;
+use cyclogenese_rust::diagnostics;
+use cyclogenese_rust::diff::{self, RunDiff};
+use cyclogenese_rust::dispersion::DispersionCurve;
+use cyclogenese_rust::downstream::{ChainConfig, DownstreamChain};
+use cyclogenese_rust::energetics;
+use cyclogenese_rust::exit::{ErrorFormat, ErrorReport};
+use cyclogenese_rust::ensemble::{self, Ensemble, EnsembleConfig, ExceedanceOutput, LaggedRun, Threshold};
+use cyclogenese_rust::frame::Frame;
+use cyclogenese_rust::gust::{self, GustModel};
+use cyclogenese_rust::hindcast::Hindcast;
+use cyclogenese_rust::hovmoller::HovmollerAxis;
+use cyclogenese_rust::impacts::{self, Coastline};
+#[cfg(feature = "arrow")]
+use cyclogenese_rust::{io::arrow, ResultColumns};
+use cyclogenese_rust::io::binary;
+use cyclogenese_rust::io::records::{self, RecordWriter};
+use cyclogenese_rust::isentropic::{self, IsentropicOutput};
+use cyclogenese_rust::lifecycle::{self, LifecycleModel};
+use cyclogenese_rust::metrics::Metrics;
+#[cfg(feature = "mqtt")]
+use cyclogenese_rust::mqtt::{MqttSink, MqttTopics};
+use cyclogenese_rust::pipe;
+use cyclogenese_rust::qvector;
+use cyclogenese_rust::report::{Report, ReportFormat};
+use cyclogenese_rust::scenarios;
+use cyclogenese_rust::section::{self, SectionLine};
+use cyclogenese_rust::server;
+use cyclogenese_rust::sting_jet::{self, StingJetCriteria};
+use cyclogenese_rust::storms::{self, MultiStormScenario};
+use cyclogenese_rust::summary::DevelopmentSummary;
+use cyclogenese_rust::sweep::SweepConfig;
+use cyclogenese_rust::verify::{EnsembleVerification, Verification};
+use cyclogenese_rust::vorticity;
+use cyclogenese_rust::waves::{self, WaveModel};
+use cyclogenese_rust::wind::{self, WindGrid};
+use cyclogenese_rust::scenario::TEMPLATE;
+use cyclogenese_rust::table::Units;
+use cyclogenese_rust::{
+    BaroclinicCyclogenesis, Climatology, DiagnosticsLevel, MeteoError, NumberFormat, OutputLanguage, Scenario,
+    TableFormatter, WindUnit,
+};
+
+/// Simulation de cyclogenèse barocline aux moyennes latitudes
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Langue des textes affichés (fr, en)
+    #[arg(long, global = true, default_value_t = OutputLanguage::French)]
+    lang: OutputLanguage,
+
+    /// Locale fixant le séparateur décimal des nombres (fr-FR, en-US...)
+    #[arg(long, global = true)]
+    format_locale: Option<String>,
+
+    /// Nombre de chiffres significatifs (deux décimales par défaut)
+    #[arg(long, global = true)]
+    precision: Option<usize>,
+
+    /// Ajoute le tableau des diagnostics (cisaillement, nombre de Richardson)
+    #[arg(long)]
+    diagnostics: bool,
+
+    /// Détail des diagnostics (standard, full : chaîne de calcul de chaque anomalie)
+    #[arg(long, default_value = "standard")]
+    diagnostics_level: DiagnosticsLevel,
+
+    /// Ajoute le bilan énergétique de Lorenz (APE, EKE, conversion)
+    #[arg(long)]
+    energy: bool,
+
+    /// Ajoute le bilan de tourbillon (étirement, basculement, advection, frottement)
+    #[arg(long)]
+    budget: bool,
+
+    /// Ajoute le vecteur Q et sa convergence, forçage de l'ascendance
+    #[arg(long)]
+    q_vector: bool,
+
+    /// Ajoute le creusement de la pression centrale sur 24 heures glissantes (hPa/24 h)
+    #[arg(long)]
+    deepening: bool,
+
+    /// Ajoute les rafales de pointe estimées à côté du vent moyen
+    #[arg(long)]
+    gusts: bool,
+
+    /// Ajoute la hauteur significative et la période des vagues levées par le vent
+    #[arg(long)]
+    waves: bool,
+
+    /// Fetch des vagues (km)
+    #[arg(long, default_value_t = 300.0)]
+    fetch: f64,
+
+    /// Ajoute l'indicateur de risque de sting jet (creusement, subsidence, front replié)
+    #[arg(long)]
+    sting_jet: bool,
+
+    /// Ajoute les stades du cycle de vie selon un modèle conceptuel (norwegian, shapiro-keyser)
+    #[arg(long)]
+    lifecycle: Option<LifecycleModel>,
+
+    /// Ajoute la vue isentrope des anomalies en fin de simulation (table, json)
+    #[arg(long)]
+    isentropic: Option<IsentropicOutput>,
+
+    /// Climatologie zonale (CSV, ou NetCDF-3 avec la fonctionnalité netcdf) fixant
+    /// l'état de fond à la latitude de chaque simulation ; un fichier NetCDF-4 est
+    /// refusé et doit être converti avec `nccopy -k classic`
+    #[arg(long, global = true, value_name = "FICHIER")]
+    climatology: Option<PathBuf>,
+
+    /// Ajoute la comparaison du pic de tourbillon selon la latitude
+    #[arg(long)]
+    latitude_summary: bool,
+
+    /// Vérifie les scénarios et affiche leurs paramètres dérivés et leur durée
+    /// estimée, sans les simuler
+    #[arg(long, global = true)]
+    dry_run: bool,
+
+    /// Explique terme à terme le calcul d'une heure, formules et valeurs comprises
+    #[arg(long, global = true, value_name = "HEURE")]
+    explain: Option<u32>,
+
+    /// Format des erreurs sur la sortie d'erreur (text, json)
+    #[arg(long, global = true, default_value = "text")]
+    error_format: ErrorFormat,
+
+    /// Notation des nombres (fixed, scientific, auto)
+    #[arg(long, global = true, default_value = "fixed")]
+    exponent: ExponentStyle,
+
+    /// Unités du tableau des résultats (display : cm/s et 10⁻⁵ s⁻¹, si : sans conversion)
+    #[arg(long, global = true, default_value = "display")]
+    units: Units,
+
+    /// Unité des vitesses de vent affichées et exportées (m/s, km/h, kt, mph)
+    #[arg(long, global = true, default_value = "m/s")]
+    wind_unit: WindUnit,
+
+    /// Repère du vent et du diagramme de Hovmöller (earth : terrestre, storm : lié à la dépression)
+    #[arg(long, global = true, default_value = "earth")]
+    frame: Frame,
+
+    /// Niveau des journaux écrits sur la sortie d'erreur (error, warn, info, debug, trace)
+    #[cfg(feature = "tracing")]
+    #[arg(long, global = true, default_value_t = tracing::Level::WARN)]
+    log_level: tracing::Level,
+
+    /// Journaux au format JSON, une ligne par événement
+    #[cfg(feature = "tracing")]
+    #[arg(long, global = true)]
+    log_json: bool,
 }
 
-impl Default for PhysicalConstants {
-    fn default() -> Self {
-        Self {
-            earth_omega: 7.2921e-5,
-            gravity: 9.81,
-            base_temp: 288.15,
-        }
-    }
+/// Modes du programme ; sans sous-commande, affiche le tableau de référence
+#[derive(Subcommand)]
+enum Command {
+    /// Tableau de bord interactif en terminal
+    #[cfg(feature = "tui")]
+    Tui,
+    /// Relance un scénario TOML à chaque modification du fichier
+    Watch {
+        /// Fichier de scénario
+        scenario: PathBuf,
+    },
+    /// Taux de croissance en fonction de la longueur d'onde des anomalies
+    Dispersion {
+        /// Fichier de scénario (cas de référence par défaut)
+        scenario: Option<PathBuf>,
+
+        /// Plus courte longueur d'onde (km)
+        #[arg(long, default_value_t = 1000.0)]
+        min_wavelength: f64,
+
+        /// Plus grande longueur d'onde (km)
+        #[arg(long, default_value_t = 10000.0)]
+        max_wavelength: f64,
+
+        /// Pas entre deux longueurs d'onde (km)
+        #[arg(long, default_value_t = 250.0)]
+        step: f64,
+    },
+    /// Écrit ou vérifie un fichier de scénario
+    Scenario {
+        #[command(subcommand)]
+        action: ScenarioAction,
+    },
+    /// Simule une tempête historique préréglée, ou liste les préréglages
+    Preset {
+        /// Nom du préréglage (great-storm-1987, lothar-1999, klaus-2009, qe2-1978)
+        name: Option<String>,
+    },
+    /// Compare heure par heure deux simulations enregistrées en JSON
+    Diff {
+        /// Résultats de la première simulation
+        run_a: PathBuf,
+
+        /// Résultats de la seconde simulation
+        run_b: PathBuf,
+
+        /// Écart relatif du tourbillon au-delà duquel les simulations divergent
+        #[arg(long, default_value_t = diff::DEFAULT_TOLERANCE)]
+        tolerance: f64,
+    },
+    /// Rapport autonome d'une simulation, en Markdown ou en HTML
+    Report {
+        /// Fichier de scénario (cas de référence par défaut)
+        scenario: Option<PathBuf>,
+
+        /// Format du rapport (markdown, html)
+        #[arg(long, default_value = "markdown")]
+        format: ReportFormat,
+    },
+    /// Vent reconstruit sur une grille autour du centre de la dépression
+    Wind {
+        /// Fichier de scénario (cas de référence par défaut)
+        scenario: Option<PathBuf>,
+
+        /// Demi-largeur de la grille (°)
+        #[arg(long, default_value_t = 8.0)]
+        half_width: f64,
+
+        /// Pas de la grille (°)
+        #[arg(long, default_value_t = 0.5)]
+        spacing: f64,
+
+        /// Fichier NetCDF-3 où écrire les champs de vent
+        #[cfg(feature = "netcdf")]
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Coupe verticale ouest–est passant par le centre de la dépression
+    Section {
+        /// Fichier de scénario (cas de référence par défaut)
+        scenario: Option<PathBuf>,
+
+        /// Demi-longueur de la coupe (km)
+        #[arg(long, default_value_t = 2000.0)]
+        half_length: f64,
+
+        /// Pas entre deux points de la coupe (km)
+        #[arg(long, default_value_t = 50.0)]
+        spacing: f64,
+
+        /// Fichier NetCDF-3 où écrire les coupes de chaque heure
+        #[cfg(feature = "netcdf")]
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Diagramme de Hovmöller du tourbillon le long de la trajectoire, en CSV
+    Hovmoller {
+        /// Fichier de scénario (cas de référence par défaut)
+        scenario: Option<PathBuf>,
+
+        /// Marge en longitude de part et d'autre de la trajectoire (°)
+        #[arg(long, default_value_t = 30.0)]
+        half_width: f64,
+
+        /// Pas en longitude (°)
+        #[arg(long, default_value_t = 1.0)]
+        spacing: f64,
+
+        /// Fichier NetCDF-3 où écrire le diagramme au lieu du CSV
+        #[cfg(feature = "netcdf")]
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Chaîne de systèmes amorcés en aval les uns des autres : Hovmöller du paquet d'ondes, en CSV
+    Downstream {
+        /// Fichier de scénario du premier système (cas de référence par défaut)
+        scenario: Option<PathBuf>,
+
+        /// Nombre maximal de systèmes
+        #[arg(long, default_value_t = 4)]
+        members: usize,
+
+        /// Écart en longitude entre deux systèmes successifs (°)
+        #[arg(long, default_value_t = 30.0)]
+        spacing: f64,
+
+        /// Fraction de l'énergie transmise au système suivant
+        #[arg(long, default_value_t = 0.7)]
+        transfer: f64,
+
+        /// Durée de la chaîne (h), à la place de celle du scénario
+        #[arg(long, default_value_t = 120)]
+        hours: u32,
+
+        /// Marge en longitude de part et d'autre de la chaîne (°)
+        #[arg(long, default_value_t = 30.0)]
+        half_width: f64,
+
+        /// Pas en longitude du diagramme (°)
+        #[arg(long, default_value_t = 1.0)]
+        axis_spacing: f64,
+
+        /// Affiche le tableau des systèmes au lieu du diagramme
+        #[arg(long)]
+        table: bool,
+    },
+    /// Simule ensemble les tempêtes d'un fichier de famille, sur une horloge commune
+    Storms {
+        /// Fichier de famille de tempêtes (`[[storms]]`)
+        family: PathBuf,
+
+        /// Résultats fusionnés en JSON au lieu du tableau
+        #[arg(long)]
+        json: bool,
+    },
+    /// Matrice heure × latitude du tourbillon relatif, en CSV pour une carte de chaleur
+    Heatmap {
+        /// Fichier de scénario (cas de référence par défaut), simulé à chaque latitude
+        scenario: Option<PathBuf>,
+
+        /// Latitudes balayées (°)
+        #[arg(long, value_delimiter = ',', default_values_t = [30.0, 45.0, 60.0])]
+        latitudes: Vec<f64>,
+
+        /// Fichier PNG où tracer la carte de chaleur au lieu du CSV
+        #[cfg(feature = "plot")]
+        #[arg(long)]
+        png: Option<PathBuf>,
+
+        /// Côté d'une case de la carte PNG (pixels)
+        #[cfg(feature = "plot")]
+        #[arg(long, default_value_t = 16)]
+        cell: u32,
+    },
+    /// Vérifie une simulation par une série observée de pression centrale ou de tourbillon (CSV)
+    Verify {
+        /// Fichier CSV des observations (colonnes hour ou time, central_pressure, relative_vorticity)
+        observations: PathBuf,
+
+        /// Fichier de scénario (cas de référence par défaut)
+        #[arg(long)]
+        scenario: Option<PathBuf>,
+    },
+    /// Ensemble de simulations aux anomalies thermiques perturbées, vérifiable par des observations
+    Ensemble {
+        /// Fichier de scénario (cas de référence par défaut)
+        scenario: Option<PathBuf>,
+
+        /// Nombre de membres
+        #[arg(long, default_value_t = 20)]
+        members: usize,
+
+        /// Écart type de l'anomalie de surface (K)
+        #[arg(long, default_value_t = 1.0)]
+        surface_spread: f64,
+
+        /// Écart type de l'anomalie d'altitude (K)
+        #[arg(long, default_value_t = 1.0)]
+        altitude_spread: f64,
+
+        /// Graine du générateur pseudo-aléatoire
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// Ensemble décalé : heures de départ le long du contrôle (0,6,12), au lieu des perturbations
+        #[arg(long, value_delimiter = ',')]
+        lags: Vec<u32>,
+
+        /// Poids relatifs des membres, un par membre ou par départ (1,2,3)
+        #[arg(long, value_delimiter = ',')]
+        weights: Vec<f64>,
+
+        /// Regroupe les membres en scénarios représentatifs (k-moyennes sur les trajectoires)
+        #[arg(long)]
+        clusters: Option<usize>,
+
+        /// Fichier CSV des observations, pour le CRPS, l'histogramme des rangs et le score de Brier
+        #[arg(long)]
+        observations: Option<PathBuf>,
+
+        /// Événement à seuil (deepening>20, pressure<980, wind>25, vorticity>10), répétable :
+        /// probabilité à chaque heure et score de Brier
+        #[arg(long = "event")]
+        events: Vec<Threshold>,
+
+        /// Présentation des probabilités des événements (table, json)
+        #[arg(long, default_value = "table")]
+        probabilities: ExceedanceOutput,
+
+        /// Fichier NetCDF-3 où écrire les probabilités des événements
+        #[cfg(feature = "netcdf")]
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Simule un scénario et écrit ses résultats au format binaire versionné
+    Export {
+        /// Fichier binaire à écrire
+        output: PathBuf,
+
+        /// Fichier de scénario (cas de référence par défaut)
+        #[arg(long)]
+        scenario: Option<PathBuf>,
+
+        /// Écrit un fichier Arrow IPC (`.arrow`) au lieu du format binaire
+        #[cfg(feature = "arrow")]
+        #[arg(long)]
+        arrow: bool,
+    },
+    /// Simule en parallèle les scénarios d'un répertoire, un fichier de résultats
+    /// par scénario et un index commun
+    Batch {
+        /// Répertoire des scénarios
+        directory: PathBuf,
+
+        /// Répertoire où écrire les résultats et l'index
+        #[arg(long)]
+        out: PathBuf,
+
+        /// Motif des noms de fichiers retenus, répétable (`*.toml` par défaut)
+        #[arg(long)]
+        include: Vec<String>,
+
+        /// Motif des noms de fichiers écartés, répétable
+        #[arg(long)]
+        exclude: Vec<String>,
+
+        /// Nombre de simulations simultanées (nombre de cœurs par défaut)
+        #[arg(long)]
+        jobs: Option<usize>,
+
+        /// Ne relance que les scénarios en échec ou en attente dans l'index existant
+        #[arg(long)]
+        resume: bool,
+
+        /// Adresse HTTP où exposer `/metrics` pour Prometheus pendant le lot
+        #[arg(long)]
+        metrics: Option<String>,
+    },
+    /// Lit des scénarios JSON sur l'entrée standard, un par ligne, et écrit leurs
+    /// résultats sur la sortie standard, une ligne JSON par scénario
+    Pipe,
+    /// Démon servant les simulations demandées sur une socket Unix
+    #[cfg(unix)]
+    Daemon {
+        /// Chemin de la socket à créer
+        socket: PathBuf,
+
+        /// Adresse HTTP où exposer `/metrics` pour Prometheus
+        #[arg(long)]
+        metrics: Option<String>,
+    },
+    /// Publie chaque résultat et chaque changement de classe sur un courtier MQTT
+    #[cfg(feature = "mqtt")]
+    Mqtt {
+        /// Adresse du courtier (hôte:port)
+        broker: String,
+
+        /// Fichier de scénario (cas de référence par défaut)
+        #[arg(long)]
+        scenario: Option<PathBuf>,
+
+        /// Sujet des résultats
+        #[arg(long, default_value = "cyclogenese/results")]
+        results_topic: String,
+
+        /// Sujet des alertes
+        #[arg(long, default_value = "cyclogenese/alerts")]
+        alerts_topic: String,
+
+        /// Classement déclenchant les alertes (beaufort, windstorm, pressure)
+        #[arg(long, default_value = "windstorm")]
+        classifier: BuiltinClassifier,
+    },
+    /// Serveur HTTP diffusant les simulations heure par heure par WebSocket sur `/simulate`,
+    /// avec les métriques Prometheus sur `/metrics`
+    Serve {
+        /// Adresse d'écoute
+        #[arg(default_value = "127.0.0.1:8080")]
+        address: String,
+    },
+    /// Simule en écrivant chaque pas dès son calcul dans un fichier d'enregistrements binaires
+    Record {
+        /// Fichier d'enregistrements à écrire
+        output: PathBuf,
+
+        /// Fichier de scénario (cas de référence par défaut)
+        #[arg(long)]
+        scenario: Option<PathBuf>,
+
+        /// Reprend après le dernier enregistrement complet d'un fichier existant, écrit par le même scénario
+        #[arg(long)]
+        resume: bool,
+
+        /// Écrit les enregistrements dans une projection du fichier en mémoire
+        #[cfg(feature = "mmap")]
+        #[arg(long)]
+        mmap: bool,
+    },
+    /// Estime la surcote sur une côte (baromètre inverse et surélévation due au vent)
+    Surge {
+        /// Fichier de scénario (cas de référence par défaut)
+        scenario: Option<PathBuf>,
+
+        /// Direction vers le large (°, 270 pour une côte exposée à l'ouest)
+        #[arg(long, default_value_t = 270.0)]
+        facing: f64,
+
+        /// Profondeur moyenne du plateau continental (m)
+        #[arg(long, default_value_t = 30.0)]
+        depth: f64,
+
+        /// Largeur du plateau continental (km)
+        #[arg(long, default_value_t = 100.0)]
+        shelf_width: f64,
+    },
+    /// Classe la sévérité du système à chaque heure (vent de surface, pression centrale)
+    Classify {
+        /// Fichier de scénario (cas de référence par défaut)
+        scenario: Option<PathBuf>,
+
+        /// Classement (beaufort, windstorm, pressure)
+        #[arg(long, default_value = "windstorm")]
+        classifier: BuiltinClassifier,
+    },
+    /// Intégration rétrograde depuis le tourbillon d'une dépression mature
+    Hindcast {
+        /// Fichier de scénario (cas de référence par défaut)
+        scenario: Option<PathBuf>,
+
+        /// Tourbillon relatif atteint à la dernière heure (10⁻⁵ s⁻¹)
+        #[arg(long, allow_negative_numbers = true)]
+        vorticity: f64,
+    },
 }
 
-/// Types d'erreurs personnalisés
-#[derive(Debug)]
-pub enum MeteoError {
-    InvalidLatitude(f64),
-    InvalidPressure(f64),
-    InvalidTemperature(f64),
-    InvalidAltitude(f64),
+/// Actions sur les fichiers de scénario
+#[derive(Subcommand)]
+enum ScenarioAction {
+    /// Écrit un fichier de scénario commenté reprenant le cas de référence
+    Init {
+        /// Fichier à créer
+        path: PathBuf,
+
+        /// Remplace le fichier s'il existe déjà
+        #[arg(long)]
+        force: bool,
+    },
+    /// Vérifie un scénario (plages, cohérence physique, critère d'instabilité) sans le simuler
+    Check {
+        /// Fichier de scénario
+        path: PathBuf,
+    },
 }
 
-impl fmt::Display for MeteoError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            MeteoError::InvalidLatitude(lat) => write!(f, "Latitude invalide: {}°", lat),
-            MeteoError::InvalidPressure(p) => write!(f, "Pression invalide: {} hPa", p),
-            MeteoError::InvalidTemperature(t) => write!(f, "Température invalide: {} K", t),
-            MeteoError::InvalidAltitude(a) => write!(f, "Altitude invalide: {} m", a),
+/// Options propres au tableau de référence, sans effet sur les sous-commandes
+const REFERENCE_OPTIONS: [&str; 13] = [
+    "diagnostics",
+    "diagnostics_level",
+    "energy",
+    "budget",
+    "q_vector",
+    "deepening",
+    "gusts",
+    "waves",
+    "fetch",
+    "sting_jet",
+    "lifecycle",
+    "isentropic",
+    "latitude_summary",
+];
+
+impl Cli {
+    /// Lit la ligne de commande, en refusant une option du tableau de référence
+    /// donnée avec une sous-commande
+    fn parse_checked() -> Self {
+        let mut command = Cli::command();
+        let matches = command.get_matches_mut();
+        let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|error| error.format(&mut command).exit());
+        if let Some((name, _)) = matches.subcommand() {
+            let given = REFERENCE_OPTIONS.iter().find(|id| matches.value_source(id) == Some(ValueSource::CommandLine));
+            if let Some(id) = given {
+                let message = format!(
+                    "l'option --{} ne s'applique qu'au tableau de référence et ne peut pas accompagner la sous-commande '{}'",
+                    id.replace('_', "-"),
+                    name
+                );
+                command.error(ErrorKind::ArgumentConflict, message).exit();
+            }
         }
+        cli
     }
-}
 
-impl Error for MeteoError {}
+    /// Installe l'abonné `tracing` selon les options demandées
+    #[cfg(feature = "tracing")]
+    fn init_logging(&self) {
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(self.log_level)
+            .with_writer(std::io::stderr);
+        if self.log_json {
+            subscriber.json().init();
+        } else {
+            subscriber.init();
+        }
+    }
 
-/// Résultats du développement de la perturbation
-#[derive(Debug, Clone)]
-pub struct DevelopmentResult {
-    vertical_velocity: f64,
-    relative_vorticity: f64,
-    hour: u32,
-}
+    /// Règles de mise en forme des nombres demandées
+    fn number_format(&self) -> Result<NumberFormat, String> {
+        let mut format = NumberFormat::default().with_exponent(self.exponent).with_wind_unit(self.wind_unit);
+        if let Some(locale) = &self.format_locale {
+            format = format.with_locale(locale)?;
+        }
+        if let Some(digits) = self.precision {
+            format = format.with_precision(Precision::Significant(digits));
+        }
+        Ok(format)
+    }
 
-impl DevelopmentResult {
-    /// Convertit les résultats en format lisible
-    pub fn to_string_formatted(&self) -> String {
-        format!("{:4} | {:20.2} | {:20.2}",
-            self.hour,
-            self.vertical_velocity * 100.0,  // Conversion en cm/s
-            self.relative_vorticity * 1e5    // Conversion en 10⁻⁵ s⁻¹
-        )
+    /// Mise en forme du tableau des résultats demandée
+    fn table_formatter(&self, format: &NumberFormat) -> TableFormatter {
+        TableFormatter::default()
+            .with_units(self.units)
+            .with_number_format(*format)
+            .with_language(self.lang)
     }
 }
 
-/// Position géographique et conditions atmosphériques
-#[derive(Debug, Clone)]
-pub struct Position {
-    latitude: f64,
-    altitude: f64,
-    pressure: f64,
-}
+fn main() -> ExitCode {
+    let cli = Cli::parse_checked();
 
-impl Position {
-    /// Crée une nouvelle position avec validation
-    pub fn new(latitude: f64, altitude: f64, pressure: f64) -> Result<Self, MeteoError> {
-        if !(-90.0..=90.0).contains(&latitude) {
-            return Err(MeteoError::InvalidLatitude(latitude));
-        }
-        if altitude < -400.0 || altitude > 20000.0 {
-            return Err(MeteoError::InvalidAltitude(altitude));
-        }
-        if pressure < 100.0 || pressure > 1100.0 {
-            return Err(MeteoError::InvalidPressure(pressure));
-        }
+    #[cfg(feature = "tracing")]
+    cli.init_logging();
+
+    #[cfg(feature = "tui")]
+    if let Some(Command::Tui) = cli.command {
+        return match cyclogenese_rust::tui::run(cli.lang) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(error) => {
+                eprintln!("{}", error);
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    let format = match cli.number_format() {
+        Ok(format) => format,
+        Err(message) => return fail(&ErrorReport::usage(&message), cli.error_format),
+    };
 
-        Ok(Self {
-            latitude,
-            altitude,
-            pressure,
-        })
+    match run(&cli, &format) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => fail(&ErrorReport::new(&error, cli.lang), cli.error_format),
     }
 }
 
-/// Anomalie thermique
-#[derive(Debug)]
-pub struct ThermalAnomaly {
-    temperature_delta: f64,
-    position: Position,
-    is_cyclonic: bool,
-    intensity: f64,
-    constants: PhysicalConstants,
+/// Affiche l'erreur sur la sortie d'erreur et renvoie le code de sortie de sa classe
+fn fail(report: &ErrorReport, format: ErrorFormat) -> ExitCode {
+    eprintln!("{}", report.render(format));
+    ExitCode::from(report.exit_code)
 }
 
-impl ThermalAnomaly {
-    /// Crée une nouvelle anomalie thermique
-    pub fn new(
-        temperature_delta: f64,
-        position: Position,
-        constants: PhysicalConstants,
-    ) -> Result<Self, MeteoError> {
-        if !(-50.0..=50.0).contains(&temperature_delta) {
-            return Err(MeteoError::InvalidTemperature(temperature_delta));
-        }
-
-        Ok(Self {
-            temperature_delta,
-            position,
-            is_cyclonic: temperature_delta > 0.0,
-            intensity: 1.0,
-            constants,
-        })
+fn run(cli: &Cli, format: &NumberFormat) -> Result<(), MeteoError> {
+    if cli.dry_run {
+        return dry_run(cli, format);
     }
-
-    fn compute_coriolis_force(&self) -> f64 {
-        self.constants.earth_omega * (self.position.latitude * PI / 180.0).sin()
+    if let Some(hour) = cli.explain {
+        return explain(cli, format, hour);
     }
+    match &cli.command {
+        None => reference_table(cli, format),
+        Some(Command::Watch { scenario }) => cyclogenese_rust::watch::run(scenario, cli.lang, format),
+        Some(Command::Dispersion { scenario, min_wavelength, max_wavelength, step }) => {
+            let scenario = load_scenario(scenario.as_deref(), cli, format)?;
+            if step.is_nan() || *step <= 0.0 {
+                return Err(MeteoError::InvalidScale(step * 1000.0));
+            }
+            let count = ((max_wavelength - min_wavelength) / step).floor().max(0.0) as usize + 1;
+            let wavelengths = (0..count).map(|index| (min_wavelength + index as f64 * step) * 1000.0);
 
-    fn compute_relative_vorticity(&self, thermal_wind: f64) -> f64 {
-        const RADIUS: f64 = 5.0e5;  // 500 km
-        const AMPLIFICATION: f64 = 1.0e3;
-        
-        let base_vorticity = thermal_wind / RADIUS;
-        let altitude_factor = if self.position.pressure < 500.0 { 2.0 } else { 1.0 };
-        
-        if self.is_cyclonic {
-            base_vorticity * self.intensity * altitude_factor * AMPLIFICATION
-        } else {
-            -base_vorticity * self.intensity * altitude_factor * AMPLIFICATION
+            let curve = DispersionCurve::compute(&scenario, wavelengths)?;
+            print!("{}", curve.to_table(cli.lang, format));
+            Ok(())
+        }
+        Some(Command::Scenario { action: ScenarioAction::Init { path, force } }) => {
+            if path.exists() && !force {
+                return Err(MeteoError::InvalidScenario(format!(
+                    "{} existe déjà (--force pour le remplacer)",
+                    path.display()
+                )));
+            }
+            std::fs::write(path, TEMPLATE)
+                .map_err(|e| MeteoError::InvalidScenario(format!("{}: {}", path.display(), e)))
+        }
+        Some(Command::Scenario { action: ScenarioAction::Check { path } }) => {
+            let check = ScenarioCheck::run(&Scenario::load(path)?)?;
+            print!("{}", check.describe(cli.lang, format));
+            Ok(())
+        }
+        Some(Command::Preset { name: None }) => {
+            print!("{}", scenarios::to_table(cli.lang, format));
+            Ok(())
+        }
+        Some(Command::Preset { name: Some(name) }) => {
+            let preset = scenarios::find(name)?;
+            println!("{}\n{}\n", preset.title, preset.summary);
+            print!("{}", cli.table_formatter(format).format(&preset.scenario().run()?));
+            Ok(())
+        }
+        Some(Command::Diff { run_a, run_b, tolerance }) => {
+            let comparison = RunDiff::compute(&diff::load(run_a)?, &diff::load(run_b)?);
+            print!("{}", comparison.to_table(*tolerance, cli.lang, format));
+            Ok(())
+        }
+        Some(Command::Report { scenario, format: output }) => {
+            let scenario = load_scenario(scenario.as_deref(), cli, format)?;
+            print!("{}", Report::compute(&scenario)?.render(*output, cli.lang, format));
+            Ok(())
+        }
+        Some(Command::Wind { scenario, half_width, spacing, .. }) => {
+            let scenario = load_scenario(scenario.as_deref(), cli, format)?;
+            let grid = WindGrid::new(*half_width, *spacing)?;
+            let mut cyclogenesis = scenario.to_cyclogenesis()?;
+            let results = cyclogenesis.simulate_interaction(scenario.time_steps)?;
+            let fields = cyclogenesis.wind_fields_in(&results, &grid, cli.frame);
+            print!("{}", wind::to_table(&fields, cli.lang, format));
+            #[cfg(feature = "netcdf")]
+            if let Some(Command::Wind { output: Some(path), .. }) = &cli.command {
+                cyclogenese_rust::io::netcdf::write_wind_fields_with(path, &fields, format.wind_unit())?;
+            }
+            Ok(())
+        }
+        Some(Command::Section { scenario, half_length, spacing, .. }) => {
+            let scenario = load_scenario(scenario.as_deref(), cli, format)?;
+            let line = SectionLine::new(*half_length, *spacing)?;
+            let mut cyclogenesis = scenario.to_cyclogenesis()?;
+            let results = cyclogenesis.simulate_interaction(scenario.time_steps)?;
+            let sections = cyclogenesis.cross_sections(&results, &line);
+            if let Some(last) = sections.last() {
+                println!("{}", cli.lang.simulation_heading(scenario.latitude));
+                print!("{}", section::to_table(last, cli.lang, format));
+            }
+            #[cfg(feature = "netcdf")]
+            if let Some(Command::Section { output: Some(path), .. }) = &cli.command {
+                cyclogenese_rust::io::netcdf::write_cross_sections(path, &sections)?;
+            }
+            Ok(())
+        }
+        Some(Command::Hovmoller { scenario, half_width, spacing, .. }) => {
+            let scenario = load_scenario(scenario.as_deref(), cli, format)?;
+            let axis = HovmollerAxis::new(*half_width, *spacing)?;
+            let mut cyclogenesis = scenario.to_cyclogenesis()?;
+            let results = cyclogenesis.simulate_interaction(scenario.time_steps)?;
+            let diagram = cyclogenesis.hovmoller_in(&results, &axis, cli.frame);
+            #[cfg(feature = "netcdf")]
+            if let Some(Command::Hovmoller { output: Some(path), .. }) = &cli.command {
+                return cyclogenese_rust::io::netcdf::write_hovmoller(path, &diagram);
+            }
+            print!("{}", diagram.to_csv());
+            Ok(())
+        }
+        Some(Command::Downstream { scenario, members, spacing, transfer, hours, half_width, axis_spacing, table }) => {
+            let scenario = Scenario { time_steps: *hours, ..load_scenario(scenario.as_deref(), cli, format)? };
+            let chain = DownstreamChain::simulate(&ChainConfig::new(scenario, *members, *spacing, *transfer)?)?;
+            if *table {
+                print!("{}", chain.to_table(cli.lang, format));
+            } else {
+                print!("{}", chain.hovmoller(&HovmollerAxis::new(*half_width, *axis_spacing)?).to_csv());
+            }
+            Ok(())
+        }
+        Some(Command::Storms { family, json }) => {
+            let results = MultiStormScenario::load(family)?.run()?;
+            if *json {
+                println!("{}", storms::to_json(&results));
+            } else {
+                print!("{}", storms::to_table(&results, cli.lang, format));
+            }
+            Ok(())
+        }
+        Some(Command::Heatmap { scenario, latitudes, .. }) => {
+            let config = SweepConfig::from(load_scenario(scenario.as_deref(), cli, format)?);
+            let matrix = BaroclinicCyclogenesis::sweep_latitudes(latitudes, &config)?.vorticity_matrix();
+            #[cfg(feature = "plot")]
+            if let Some(Command::Heatmap { png: Some(path), cell, .. }) = &cli.command {
+                return std::fs::write(path, matrix.to_png(*cell)?)
+                    .map_err(|e| MeteoError::InvalidRun(format!("{}: {}", path.display(), e)));
+            }
+            print!("{}", matrix.to_csv());
+            Ok(())
+        }
+        Some(Command::Verify { observations, scenario }) => {
+            let scenario = load_scenario(scenario.as_deref(), cli, format)?;
+            let text = std::fs::read_to_string(observations)
+                .map_err(|error| MeteoError::InvalidObservations(format!("{}: {}", observations.display(), error)))?;
+            let observations = cyclogenese_rust::io::observations::parse_csv(&text, scenario.start_time)?;
+            print!("{}", Verification::compute(&scenario, &observations)?.to_table(cli.lang, format));
+            Ok(())
+        }
+        Some(Command::Ensemble { scenario, members, surface_spread, altitude_spread, seed, lags, weights, clusters, observations, events, probabilities, .. }) => {
+            let scenario = load_scenario(scenario.as_deref(), cli, format)?;
+            let mut ensemble = if lags.is_empty() {
+                let config = EnsembleConfig::new(*members, *surface_spread, *altitude_spread)?.with_seed(*seed);
+                Ensemble::generate(&scenario, &config)?
+            } else {
+                let runs: Vec<_> = lags.iter().map(|start_hour| LaggedRun { start_hour: *start_hour, weight: 1.0 }).collect();
+                Ensemble::lagged(&scenario, &runs)?
+            };
+            if !weights.is_empty() {
+                ensemble = ensemble.with_weights(weights)?;
+            }
+            print!("{}", ensemble.to_table(cli.lang, format));
+            if let Some(count) = clusters {
+                print!("\n{}", Clustering::compute(&ensemble, *count)?.to_table(cli.lang, format));
+            }
+            if !events.is_empty() {
+                let steps = ensemble.exceedance(events);
+                #[cfg(feature = "netcdf")]
+                if let Some(Command::Ensemble { output: Some(path), .. }) = &cli.command {
+                    cyclogenese_rust::io::netcdf::write_exceedance(path, &steps)?;
+                }
+                match probabilities {
+                    ExceedanceOutput::Table => print!("\n{}", ensemble::exceedance_table(&steps, cli.lang, format)),
+                    ExceedanceOutput::Json => println!("\n{}", ensemble::exceedance_json(&steps)),
+                }
+            }
+            if let Some(observations) = observations {
+                let text = std::fs::read_to_string(observations)
+                    .map_err(|error| MeteoError::InvalidObservations(format!("{}: {}", observations.display(), error)))?;
+                let observations = cyclogenese_rust::io::observations::parse_csv(&text, scenario.start_time)?;
+                let verification = EnsembleVerification::compute(&ensemble, &observations, events)?;
+                print!("\n{}", verification.to_table(cli.lang, format));
+            }
+            Ok(())
+        }
+        Some(Command::Export { output, scenario, .. }) => {
+            let scenario = load_scenario(scenario.as_deref(), cli, format)?;
+            let results = scenario.run()?;
+            let file = std::fs::File::create(output)
+                .map_err(|error| MeteoError::InvalidBinary(format!("{}: {}", output.display(), error)))?;
+            #[cfg(feature = "arrow")]
+            if let Some(Command::Export { arrow: true, .. }) = &cli.command {
+                arrow::write(std::io::BufWriter::new(file), &ResultColumns::from(results))?;
+                return Ok(());
+            }
+            binary::write(std::io::BufWriter::new(file), &scenario, &results)?;
+            Ok(())
+        }
+        Some(Command::Batch { directory, out, include, exclude, jobs, resume, metrics: address }) => {
+            let jobs = jobs.unwrap_or_else(|| std::thread::available_parallelism().map_or(1, usize::from));
+            let filter = BatchFilter::new(include, exclude);
+            let metrics = Arc::new(Metrics::default());
+            if let Some(address) = address {
+                let listener = std::net::TcpListener::bind(address)
+                    .map_err(|error| MeteoError::InvalidRun(format!("{}: {}", address, error)))?;
+                let metrics = Arc::clone(&metrics);
+                std::thread::spawn(move || server::serve_metrics(listener, metrics));
+            }
+            let report = if *resume {
+                BatchReport::resume(directory, out, &filter, jobs, cli.lang, &metrics)?
+            } else {
+                BatchReport::run(directory, out, &filter, jobs, cli.lang, &metrics)?
+            };
+            print!("{}", report.to_table(cli.lang, format));
+            let failed = report.failures().count();
+            if failed > 0 {
+                return Err(MeteoError::InvalidRun(format!("{} scénario(s) en échec sur {}", failed, report.entries.len())));
+            }
+            Ok(())
+        }
+        Some(Command::Pipe) => {
+            let summary = pipe::run(std::io::stdin().lock(), std::io::stdout().lock(), cli.lang)?;
+            if summary.failed > 0 {
+                return Err(MeteoError::InvalidRun(format!(
+                    "{} scénario(s) en échec sur {}",
+                    summary.failed,
+                    summary.failed + summary.succeeded
+                )));
+            }
+            Ok(())
+        }
+        #[cfg(unix)]
+        Some(Command::Daemon { socket, metrics: address }) => {
+            let metrics = Arc::new(Metrics::default());
+            if let Some(address) = address {
+                let listener = std::net::TcpListener::bind(address)
+                    .map_err(|error| MeteoError::InvalidRun(format!("{}: {}", address, error)))?;
+                let metrics = Arc::clone(&metrics);
+                std::thread::spawn(move || server::serve_metrics(listener, metrics));
+            }
+            daemon::serve(daemon::bind(socket)?, cli.lang, metrics)
+        }
+        #[cfg(feature = "mqtt")]
+        Some(Command::Mqtt { broker, scenario, results_topic, alerts_topic, classifier }) => {
+            let scenario = load_scenario(scenario.as_deref(), cli, format)?;
+            let topics = MqttTopics { results: results_topic.clone(), alerts: alerts_topic.clone() };
+            let mut sink = MqttSink::connect(broker.as_str(), &format!("cyclogenese-{}", std::process::id()), topics)?;
+            let mut cyclogenesis = scenario.to_cyclogenesis()?;
+            let alerts = sink.stream(&mut cyclogenesis, scenario.time_steps, classifier.classifier().as_ref(), cli.lang)?;
+            sink.disconnect()?;
+            match cli.lang {
+                OutputLanguage::French => println!("{} résultats et {} alertes publiés sur {}", scenario.time_steps, alerts, broker),
+                OutputLanguage::English => println!("{} results and {} alerts published to {}", scenario.time_steps, alerts, broker),
+            }
+            Ok(())
+        }
+        Some(Command::Serve { address }) => {
+            let listener = std::net::TcpListener::bind(address)
+                .map_err(|error| MeteoError::InvalidRun(format!("{}: {}", address, error)))?;
+            server::serve(listener, cli.lang, Arc::new(Metrics::default()))
+        }
+        Some(Command::Record { output, scenario, resume, .. }) => {
+            let scenario = load_scenario(scenario.as_deref(), cli, format)?;
+            let last = if *resume && output.exists() { records::read_last(output)? } else { None };
+            let (mut writer, mut cyclogenesis) = match &last {
+                Some(last) => (
+                    RecordWriter::append(output, &scenario)?,
+                    BaroclinicCyclogenesis::from_state(&scenario, std::slice::from_ref(last))?,
+                ),
+                None => (RecordWriter::create(output, &scenario)?, scenario.to_cyclogenesis()?),
+            };
+            #[cfg(feature = "mmap")]
+            if let Some(Command::Record { mmap: true, .. }) = &cli.command {
+                writer = writer.mapped();
+            }
+            let remaining = scenario.time_steps.saturating_sub(cyclogenesis.first_hour());
+            cyclogenesis.simulate_to(remaining, &mut writer)?;
+            match cli.lang {
+                OutputLanguage::French => println!("{} pas enregistrés dans {}", writer.len(), output.display()),
+                OutputLanguage::English => println!("{} steps recorded in {}", writer.len(), output.display()),
+            }
+            Ok(())
+        }
+        Some(Command::Surge { scenario, facing, depth, shelf_width }) => {
+            let scenario = load_scenario(scenario.as_deref(), cli, format)?;
+            let coastline = Coastline::new(*facing, *depth, shelf_width * 1000.0)?;
+            let mut cyclogenesis = scenario.to_cyclogenesis()?;
+            let results = cyclogenesis.simulate_interaction(scenario.time_steps)?;
+            let surges = cyclogenesis.storm_surge(&results, &coastline);
+            print!("{}", impacts::to_table(&surges, cli.lang, format));
+            Ok(())
+        }
+        Some(Command::Classify { scenario, classifier }) => {
+            let scenario = load_scenario(scenario.as_deref(), cli, format)?;
+            let mut cyclogenesis = scenario.to_cyclogenesis()?;
+            let results = cyclogenesis.simulate_interaction(scenario.time_steps)?;
+            let states = cyclogenesis.storm_states(&results);
+            print!("{}", classify::to_table(classifier.classifier().as_ref(), &states, cli.lang, format));
+            Ok(())
+        }
+        Some(Command::Hindcast { scenario, vorticity }) => {
+            let scenario = load_scenario(scenario.as_deref(), cli, format)?;
+            let hindcast = Hindcast::compute(&scenario, vorticity * 1e-5)?;
+            print!("{}", hindcast.to_table(cli.lang, format));
+            Ok(())
         }
+        #[cfg(feature = "tui")]
+        Some(Command::Tui) => unreachable!("le tableau de bord est lancé par main"),
     }
+}
 
-    fn develop_baroclinic_perturbation(&mut self, hour: u32) -> DevelopmentResult {
-        // Mise à jour de l'intensité
-        self.intensity = 1.0 + (hour as f64 / 12.0);
-        
-        let coriolis = self.compute_coriolis_force();
-        
-        // Calcul du vent thermique
-        let base_wind = self.temperature_delta / self.constants.base_temp * 
-                       self.constants.gravity * 1000.0;
-        let thermal_wind = if self.is_cyclonic {
-            base_wind * coriolis
-        } else {
-            -base_wind * coriolis
-        };
-
-        // Calcul de la vitesse verticale
-        let pressure_factor = (1000.0 / self.position.pressure).sqrt();
-        let altitude_factor = (-self.position.altitude / 8000.0).exp();
-        
-        let vertical_velocity = if self.position.pressure > 500.0 {
-            thermal_wind * 0.1 * pressure_factor * altitude_factor
-        } else {
-            -thermal_wind * 0.1 * pressure_factor * altitude_factor
-        } * self.intensity;
-
-        let relative_vorticity = self.compute_relative_vorticity(thermal_wind);
+/// Simulation à blanc des scénarios que la commande simulerait
+fn dry_run(cli: &Cli, format: &NumberFormat) -> Result<(), MeteoError> {
+    let load = |scenario: &Option<PathBuf>| match scenario {
+        Some(path) => Scenario::load(path),
+        None => Ok(Scenario::default()),
+    };
+    // Scénarios nommés, simulés chacun `runs` fois
+    type Named = (String, Result<Scenario, MeteoError>);
+    let (scenarios, runs): (Vec<Named>, u32) = match &cli.command {
+        None => {
+            let reference = [30.0, 45.0, 60.0].map(|latitude| (format!("{}°", latitude), Ok(Scenario { latitude, ..Scenario::default() })));
+            (reference.into(), 1)
+        }
+        Some(Command::Batch { directory, include, exclude, .. }) => {
+            let paths = cyclogenese_rust::batch::discover(directory, &BatchFilter::new(include, exclude))?;
+            (paths.iter().map(|path| (path.display().to_string(), Scenario::load(path))).collect(), 1)
+        }
+        Some(Command::Ensemble { scenario, members, lags, .. }) => {
+            let runs = if lags.is_empty() { *members } else { lags.len() };
+            (vec![(String::new(), load(scenario))], runs as u32)
+        }
+        Some(Command::Watch { scenario }) => (vec![(String::new(), Scenario::load(scenario))], 1),
+        Some(command) => match scenario_argument(command) {
+            Some(scenario) => (vec![(String::new(), load(scenario))], 1),
+            None => {
+                return Err(MeteoError::InvalidScenario(
+                    "--dry-run ne s'applique qu'aux commandes qui simulent un scénario".to_string(),
+                ));
+            }
+        },
+    };
 
-        DevelopmentResult {
-            vertical_velocity,
-            relative_vorticity,
-            hour,
+    let mut failed = 0;
+    let mut total = std::time::Duration::ZERO;
+    for (index, (name, scenario)) in scenarios.iter().enumerate() {
+        if index > 0 {
+            println!();
+        }
+        if !name.is_empty() {
+            println!("{}", name);
+        }
+        match scenario.as_ref().map_err(|error| error.message(cli.lang)).and_then(|scenario| {
+            DryRun::run(scenario).map_err(|error| error.message(cli.lang))
+        }) {
+            Ok(dry_run) => {
+                total += dry_run.estimated_runtime * runs;
+                print!("{}", dry_run.describe(cli.lang, format));
+            }
+            Err(message) => {
+                failed += 1;
+                println!("{}", message);
+            }
+        }
+    }
+    if scenarios.len() > 1 || runs > 1 {
+        let total = format.format(total.as_secs_f64() * 1000.0);
+        match cli.lang {
+            OutputLanguage::French => println!("\n{} simulation(s), durée totale estimée {} ms", scenarios.len() as u32 * runs, total),
+            OutputLanguage::English => println!("\n{} simulation(s), total estimated runtime {} ms", scenarios.len() as u32 * runs, total),
         }
     }
+    if failed > 0 {
+        return Err(MeteoError::InvalidScenario(format!("{} scénario(s) invalide(s) sur {}", failed, scenarios.len())));
+    }
+    Ok(())
 }
 
-/// Structure principale pour la simulation de cyclogénèse
-pub struct BaroclinicCyclogenesis {
-    surface_anomaly: ThermalAnomaly,
-    altitude_anomaly: ThermalAnomaly,
-    baroclinic_zone: bool,
+/// Fichier de scénario facultatif d'une commande qui simule un seul scénario
+fn scenario_argument(command: &Command) -> Option<&Option<PathBuf>> {
+    match command {
+        Command::Dispersion { scenario, .. }
+        | Command::Report { scenario, .. }
+        | Command::Wind { scenario, .. }
+        | Command::Section { scenario, .. }
+        | Command::Hovmoller { scenario, .. }
+        | Command::Downstream { scenario, .. }
+        | Command::Heatmap { scenario, .. }
+        | Command::Verify { scenario, .. }
+        | Command::Export { scenario, .. }
+        | Command::Record { scenario, .. }
+        | Command::Surge { scenario, .. }
+        | Command::Classify { scenario, .. }
+        | Command::Hindcast { scenario, .. } => Some(scenario),
+        #[cfg(feature = "mqtt")]
+        Command::Mqtt { scenario, .. } => Some(scenario),
+        _ => None,
+    }
 }
 
-impl BaroclinicCyclogenesis {
-    /// Crée une nouvelle instance de simulation
-    pub fn new(
-        surface_temp: f64,
-        altitude_temp: f64,
-        latitude: f64,
-    ) -> Result<Self, MeteoError> {
-        let constants = PhysicalConstants::default();
-        
-        let surface_position = Position::new(latitude, 0.0, 1013.0)?;
-        let altitude_position = Position::new(latitude, 5000.0, 500.0)?;
-        
-        let surface_anomaly = ThermalAnomaly::new(
-            surface_temp,
-            surface_position,
-            constants,
-        )?;
-        
-        let altitude_anomaly = ThermalAnomaly::new(
-            altitude_temp,
-            altitude_position,
-            constants,
-        )?;
-
-        Ok(Self {
-            surface_anomaly,
-            altitude_anomaly,
-            baroclinic_zone: true,
-        })
-    }
+/// Explique le calcul d'une heure du scénario de la commande, du cas de
+/// référence à 45°N sans commande
+fn explain(cli: &Cli, format: &NumberFormat, hour: u32) -> Result<(), MeteoError> {
+    let scenario = match &cli.command {
+        None => None,
+        Some(command) => Some(scenario_argument(command).ok_or_else(|| {
+            MeteoError::InvalidScenario("--explain ne s'applique qu'aux commandes qui simulent un scénario".to_string())
+        })?),
+    };
+    let scenario = load_scenario(scenario.and_then(Option::as_deref), cli, format)?;
+    let explanation = scenario.to_cyclogenesis()?.explain(hour)?;
+    print!("{}", explanation.describe(cli.lang, format));
+    Ok(())
+}
 
-    /// Simule l'interaction entre les anomalies
-    pub fn simulate_interaction(&mut self, time_steps: u32) -> Vec<DevelopmentResult> {
-        let mut results = Vec::with_capacity(time_steps as usize);
-        
-        for hour in 0..time_steps {
-            let surface_result = self.surface_anomaly.develop_baroclinic_perturbation(hour);
-            let altitude_result = self.altitude_anomaly.develop_baroclinic_perturbation(hour);
-            
-            let interaction_factor = if self.baroclinic_zone {
-                1.5 * (1.0 + hour as f64 / 24.0)
-            } else {
-                1.0
-            };
-            
-            results.push(DevelopmentResult {
-                vertical_velocity: (surface_result.vertical_velocity + 
-                                  altitude_result.vertical_velocity) * interaction_factor,
-                relative_vorticity: (surface_result.relative_vorticity + 
-                                   altitude_result.relative_vorticity) * interaction_factor,
-                hour,
-            });
-        }
-        
-        results
+/// Lit le scénario d'une commande, le cas de référence à défaut, et signale sur
+/// la sortie d'erreur ce qu'il a de physiquement douteux
+fn load_scenario(path: Option<&Path>, cli: &Cli, format: &NumberFormat) -> Result<Scenario, MeteoError> {
+    let mut scenario = match path {
+        Some(path) => Scenario::load(path)?,
+        None => Scenario::default(),
+    };
+    if let Some(climatology) = &cli.climatology {
+        scenario.climatology = Some(Climatology::load(climatology)?);
     }
+    for clamp in scenario.clamps() {
+        eprintln!("{}", clamp.describe(cli.lang, format));
+    }
+    // Une valeur hors plage est signalée par la commande elle-même
+    for warning in scenario.plausibility_warnings().unwrap_or_default() {
+        eprintln!("{}", warning.describe(cli.lang, format));
+    }
+    Ok(scenario)
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let latitudes = vec![30.0, 45.0, 60.0];
-    
-    println!("SIMULATION DE CYCLOGÉNÈSE BAROCLINE");
-    println!("====================================\n");
-    
-    for latitude in latitudes {
-        println!("\nSimulation à {}°N :", latitude);
-        println!("Heure | Vitesse verticale (cm/s) | Tourbillon relatif (10⁻⁵ s⁻¹)");
-        println!("------|----------------------|----------------------");
-        
-        let mut cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, latitude)?;
-        let results = cyclogenesis.simulate_interaction(24);
-        
+/// Tableau de référence : +5 K en surface, -8 K en altitude, à 30, 45 et 60°N
+fn reference_table(cli: &Cli, format: &NumberFormat) -> Result<(), MeteoError> {
+    let language = cli.lang;
+    let latitudes = [30.0, 45.0, 60.0];
+    let table = cli.table_formatter(format);
+
+    println!("{}\n", language.banner());
+
+    let mut config = SweepConfig { diagnostics_level: cli.diagnostics_level, ..SweepConfig::default() };
+    if let Some(climatology) = &cli.climatology {
+        config.scenario.climatology = Some(Climatology::load(climatology)?);
+    }
+    let sweep = BaroclinicCyclogenesis::sweep_latitudes(&latitudes, &config)?;
+    for run in sweep.runs() {
+        let (cyclogenesis, results, diagnostics) = (&run.cyclogenesis, &run.series.to_results(), &run.diagnostics);
+
+        println!("\n{}", language.simulation_heading(run.latitude));
+        if let Some(regime) = &run.regime {
+            println!("{}", regime.describe(language, format));
+        }
+        println!("{}", table.header());
+        println!("{}", table.rule());
+
+        for warning in &run.warnings {
+            eprintln!("{}", warning.describe(language, format));
+        }
+        if let Some(check) = &run.charney_stern {
+            eprintln!("{}", check.describe(language, format));
+        }
+        if let Some(limit) = &run.stability {
+            eprintln!("{}", limit.describe(cyclogenesis.step_policy(), language, format));
+        }
+
         for result in results {
-            println!("{}", result.to_string_formatted());
+            println!("{}", table.row(result));
+        }
+        if let Some(summary) = DevelopmentSummary::compute(results, diagnostics) {
+            println!("\n{}", summary.describe(language, format));
+        }
+        if cli.diagnostics {
+            print!("\n{}", diagnostics::to_table(diagnostics, language, format));
+        }
+        if cli.diagnostics_level == DiagnosticsLevel::Full {
+            print!("\n{}", diagnostics::to_full_table(diagnostics, language, format));
+        }
+        if cli.energy {
+            let budgets: Vec<_> = diagnostics.iter().map(|step| step.energy).collect();
+            print!("\n{}", energetics::to_table(&budgets, language, format));
+        }
+        if cli.budget {
+            let budgets: Vec<_> = diagnostics.iter().map(|step| step.vorticity).collect();
+            print!("\n{}", vorticity::to_table(&budgets, language, format));
+        }
+        if cli.q_vector {
+            let vectors: Vec<_> = diagnostics.iter().map(|step| step.q_vector).collect();
+            print!("\n{}", qvector::to_table(results, &vectors, language, format));
+        }
+        if cli.deepening {
+            let rates = cyclogenesis.deepening_rates(results);
+            print!("\n{}", deepening::to_table(&rates, language, format));
+        }
+        if cli.gusts {
+            let gusts = cyclogenesis.gusts(results, diagnostics, &GustModel::default());
+            print!("\n{}", gust::to_table(&gusts, language, format));
+        }
+        if cli.waves {
+            let states = cyclogenesis.sea_states(results, &WaveModel::new(cli.fetch * 1000.0)?);
+            print!("\n{}", waves::to_table(&states, language, format));
+        }
+        if cli.sting_jet {
+            let risks = cyclogenesis.sting_jet_risk(results, &StingJetCriteria::default());
+            print!("\n{}", sting_jet::to_table(&risks, language, format));
+        }
+        if let Some(model) = cli.lifecycle {
+            let steps = lifecycle::tag(results, diagnostics, model);
+            print!("\n{}", lifecycle::to_table(&steps, model, language, format));
+        }
+        if let (Some(output), Some(last)) = (cli.isentropic, results.last()) {
+            let levels = cyclogenesis.isentropic_view(last);
+            match output {
+                IsentropicOutput::Table => print!("\n{}", isentropic::to_table(&levels, language, format)),
+                IsentropicOutput::Json => println!("\n{}", isentropic::to_json(&levels)),
+            }
         }
     }
+    if cli.latitude_summary {
+        print!("\n{}", sweep.summary_table(language, format));
+    }
 
     Ok(())
-}
\ No newline at end of file
+}
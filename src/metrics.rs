@@ -0,0 +1,133 @@
+//! Métriques Prometheus des modes service (`serve`, `daemon`, `batch`, gRPC)
+//!
+//! Les compteurs sont partagés entre les fils des connexions et exposés au
+//! format texte de Prometheus (version 0.0.4). Le débit en pas par seconde se
+//! déduit du compteur des pas : `rate(cyclogenese_steps_total[1m])`. Les
+//! durées sont aussi cumulées par scénario nommé, sous l'étiquette `scenario`
+//! (nom du fichier d'un lot, ou champ `name` du scénario) ; au-delà de
+//! [`MAX_SCENARIO_LABELS`] noms, les suivants ne comptent que dans les totaux.
+
+use std::fmt::Write;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Bornes supérieures des classes de l'histogramme des durées (s)
+pub const DURATION_BUCKETS: [f64; 12] = [0.0005, 0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5];
+
+/// Type MIME du format texte de Prometheus
+pub const CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+/// Nombre maximal de scénarios distingués par l'étiquette `scenario`
+pub const MAX_SCENARIO_LABELS: usize = 1000;
+
+/// Simulations d'un scénario nommé
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct ScenarioDurations {
+    pub count: u64,    // Simulations menées à terme
+    pub seconds: f64,  // Durée cumulée (s)
+}
+
+/// Compteurs d'un processus de service
+#[derive(Debug, Default)]
+pub struct Metrics {
+    simulations: AtomicU64,
+    errors: AtomicU64,
+    steps: AtomicU64,
+    buckets: [AtomicU64; DURATION_BUCKETS.len()],
+    duration_nanos: AtomicU64,
+    scenarios: Mutex<BTreeMap<String, ScenarioDurations>>,
+}
+
+impl Metrics {
+    /// Compte une simulation menée à terme
+    pub fn record_simulation(&self, steps: u32, duration: Duration) {
+        self.simulations.fetch_add(1, Ordering::Relaxed);
+        self.steps.fetch_add(u64::from(steps), Ordering::Relaxed);
+        self.duration_nanos.fetch_add(duration.as_nanos() as u64, Ordering::Relaxed);
+        let seconds = duration.as_secs_f64();
+        if let Some(index) = DURATION_BUCKETS.iter().position(|bound| seconds <= *bound) {
+            self.buckets[index].fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Compte une simulation menée à terme, et dans les durées de son scénario
+    /// s'il est nommé
+    pub fn record_scenario(&self, scenario: Option<&str>, steps: u32, duration: Duration) {
+        self.record_simulation(steps, duration);
+        let Some(name) = scenario else {
+            return;
+        };
+        let mut scenarios = self.scenarios.lock().expect("métriques verrouillées");
+        if scenarios.len() >= MAX_SCENARIO_LABELS && !scenarios.contains_key(name) {
+            return;
+        }
+        let durations = scenarios.entry(name.to_string()).or_default();
+        durations.count += 1;
+        durations.seconds += duration.as_secs_f64();
+    }
+
+    /// Compte une requête de simulation en échec
+    pub fn record_error(&self) {
+        self.errors.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Simulations menées à terme
+    pub fn simulations(&self) -> u64 {
+        self.simulations.load(Ordering::Relaxed)
+    }
+
+    /// Requêtes de simulation en échec
+    pub fn errors(&self) -> u64 {
+        self.errors.load(Ordering::Relaxed)
+    }
+
+    /// Pas de temps calculés
+    pub fn steps(&self) -> u64 {
+        self.steps.load(Ordering::Relaxed)
+    }
+
+    /// Simulations d'un scénario nommé
+    pub fn scenario(&self, name: &str) -> Option<ScenarioDurations> {
+        self.scenarios.lock().expect("métriques verrouillées").get(name).copied()
+    }
+
+    /// Exposition au format texte de Prometheus
+    pub fn render(&self) -> String {
+        let mut text = String::new();
+        let counter = |text: &mut String, name: &str, help: &str, value: u64| {
+            writeln!(text, "# HELP {} {}\n# TYPE {} counter\n{} {}", name, help, name, name, value).expect("écriture en mémoire");
+        };
+        counter(&mut text, "cyclogenese_simulations_total", "Simulations menées à terme.", self.simulations());
+        counter(&mut text, "cyclogenese_simulation_errors_total", "Requêtes de simulation en échec.", self.errors());
+        counter(&mut text, "cyclogenese_steps_total", "Pas de temps calculés.", self.steps());
+
+        let name = "cyclogenese_simulation_duration_seconds";
+        writeln!(text, "# HELP {} Durée d'une simulation.\n# TYPE {} histogram", name, name).expect("écriture en mémoire");
+        let mut cumulative = 0;
+        for (bound, count) in DURATION_BUCKETS.iter().zip(&self.buckets) {
+            cumulative += count.load(Ordering::Relaxed);
+            writeln!(text, "{}_bucket{{le=\"{}\"}} {}", name, bound, cumulative).expect("écriture en mémoire");
+        }
+        let seconds = self.duration_nanos.load(Ordering::Relaxed) as f64 * 1e-9;
+        writeln!(text, "{}_bucket{{le=\"+Inf\"}} {}", name, self.simulations()).expect("écriture en mémoire");
+        writeln!(text, "{}_sum {}", name, seconds).expect("écriture en mémoire");
+        writeln!(text, "{}_count {}", name, self.simulations()).expect("écriture en mémoire");
+
+        let name = "cyclogenese_scenario_duration_seconds";
+        writeln!(text, "# HELP {} Durée des simulations de chaque scénario nommé.\n# TYPE {} summary", name, name)
+            .expect("écriture en mémoire");
+        for (scenario, durations) in self.scenarios.lock().expect("métriques verrouillées").iter() {
+            let label = escape_label(scenario);
+            writeln!(text, "{}_sum{{scenario=\"{}\"}} {}", name, label, durations.seconds).expect("écriture en mémoire");
+            writeln!(text, "{}_count{{scenario=\"{}\"}} {}", name, label, durations.count).expect("écriture en mémoire");
+        }
+        text
+    }
+}
+
+/// Valeur d'étiquette échappée comme l'exige le format texte
+fn escape_label(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
@@ -0,0 +1,178 @@
+//! Publication MQTT des résultats et des alertes au fil de la simulation
+//!
+//! Client MQTT 3.1.1 minimal, sur la seule bibliothèque standard : connexion en
+//! session propre, publications en QoS 0 et déconnexion. Chaque résultat est
+//! publié en JSON sur le sujet des résultats dès son calcul ; chaque changement
+//! de classe de sévérité l'est sur le sujet des alertes, de quoi alimenter les
+//! tableaux de bord d'une salle de travaux pratiques.
+
+use std::io::{Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+
+use serde::Serialize;
+
+use crate::classify::Classifier;
+use crate::{BaroclinicCyclogenesis, DevelopmentResult, MeteoError, OutputLanguage};
+
+const CONNECT: u8 = 0x10;
+const CONNACK: u8 = 0x20;
+const PUBLISH: u8 = 0x30;
+const DISCONNECT: u8 = 0xe0;
+
+/// Niveau de protocole de MQTT 3.1.1
+const PROTOCOL_LEVEL: u8 = 4;
+
+/// Drapeau de session propre
+const CLEAN_SESSION: u8 = 0x02;
+
+/// Sujets de publication
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MqttTopics {
+    pub results: String,
+    pub alerts: String,
+}
+
+impl Default for MqttTopics {
+    fn default() -> Self {
+        Self { results: "cyclogenese/results".to_string(), alerts: "cyclogenese/alerts".to_string() }
+    }
+}
+
+/// Changement de classe de sévérité du système
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Alert {
+    pub hour: u32,
+    pub classifier: String,
+    pub class: Option<String>,  // `None` sous le premier seuil
+    pub rank: Option<u8>,
+    pub severe: bool,
+}
+
+/// Connexion à un courtier MQTT
+pub struct MqttSink {
+    stream: TcpStream,
+    topics: MqttTopics,
+}
+
+impl MqttSink {
+    /// Se connecte au courtier et attend son acquittement
+    pub fn connect<A: ToSocketAddrs>(broker: A, client_id: &str, topics: MqttTopics) -> Result<Self, MeteoError> {
+        let mut stream = TcpStream::connect(broker).map_err(socket_error)?;
+
+        let mut body = Vec::new();
+        put_string(&mut body, "MQTT")?;
+        body.push(PROTOCOL_LEVEL);
+        body.push(CLEAN_SESSION);
+        // Délai de maintien nul : la connexion ne dure que le temps d'une simulation
+        body.extend_from_slice(&0u16.to_be_bytes());
+        put_string(&mut body, client_id)?;
+        write_packet(&mut stream, CONNECT, &body)?;
+
+        let mut ack = [0; 4];
+        stream.read_exact(&mut ack).map_err(socket_error)?;
+        if ack[0] != CONNACK || ack[1] != 2 {
+            return Err(protocol("acquittement de connexion attendu"));
+        }
+        if ack[3] != 0 {
+            return Err(protocol(&format!("connexion refusée par le courtier (code {})", ack[3])));
+        }
+        Ok(Self { stream, topics })
+    }
+
+    /// Publie un message en QoS 0
+    pub fn publish(&mut self, topic: &str, payload: &[u8]) -> Result<(), MeteoError> {
+        let mut body = Vec::with_capacity(topic.len() + payload.len() + 2);
+        put_string(&mut body, topic)?;
+        body.extend_from_slice(payload);
+        write_packet(&mut self.stream, PUBLISH, &body)
+    }
+
+    /// Publie un résultat en JSON sur le sujet des résultats
+    pub fn publish_result(&mut self, result: &DevelopmentResult) -> Result<(), MeteoError> {
+        let payload = serde_json::to_vec(result).expect("résultat sérialisable");
+        let topic = self.topics.results.clone();
+        self.publish(&topic, &payload)
+    }
+
+    /// Publie une alerte en JSON sur le sujet des alertes
+    pub fn publish_alert(&mut self, alert: &Alert) -> Result<(), MeteoError> {
+        let payload = serde_json::to_vec(alert).expect("alerte sérialisable");
+        let topic = self.topics.alerts.clone();
+        self.publish(&topic, &payload)
+    }
+
+    /// Simule `time_steps` heures en publiant chaque résultat dès son calcul et
+    /// une alerte à chaque changement de classe ; renvoie le nombre d'alertes
+    pub fn stream(
+        &mut self,
+        cyclogenesis: &mut BaroclinicCyclogenesis,
+        time_steps: u32,
+        classifier: &dyn Classifier,
+        language: OutputLanguage,
+    ) -> Result<usize, MeteoError> {
+        let mut previous: Option<Option<u8>> = None;
+        let mut alerts = 0;
+        for hour in cyclogenesis.hours(time_steps)? {
+            let result = cyclogenesis.step(hour)?;
+            self.publish_result(&result)?;
+
+            let class = classifier.classify(&cyclogenesis.storm_state(&result), language);
+            let rank = class.as_ref().map(|class| class.rank);
+            // Pas d'alerte au départ tant que le système reste sous le premier seuil
+            if previous.map_or(rank.is_some(), |previous| previous != rank) {
+                self.publish_alert(&Alert {
+                    hour,
+                    classifier: classifier.name(language).to_string(),
+                    rank,
+                    severe: class.as_ref().is_some_and(|class| class.severe),
+                    class: class.map(|class| class.label),
+                })?;
+                alerts += 1;
+            }
+            previous = Some(rank);
+        }
+        Ok(alerts)
+    }
+
+    /// Se déconnecte proprement du courtier
+    pub fn disconnect(mut self) -> Result<(), MeteoError> {
+        write_packet(&mut self.stream, DISCONNECT, &[])
+    }
+}
+
+/// Écrit un paquet : en-tête fixe, longueur restante en base 128, corps
+fn write_packet(stream: &mut TcpStream, header: u8, body: &[u8]) -> Result<(), MeteoError> {
+    let mut packet = Vec::with_capacity(body.len() + 5);
+    packet.push(header);
+    let mut remaining = body.len();
+    if remaining > 268_435_455 {
+        return Err(protocol(&format!("paquet de {} octets trop long", remaining)));
+    }
+    loop {
+        let byte = (remaining % 128) as u8;
+        remaining /= 128;
+        if remaining == 0 {
+            packet.push(byte);
+            break;
+        }
+        packet.push(byte | 0x80);
+    }
+    packet.extend_from_slice(body);
+    stream.write_all(&packet).map_err(socket_error)
+}
+
+/// Chaîne UTF-8 précédée de sa longueur (`u16` gros-boutiste)
+fn put_string(buffer: &mut Vec<u8>, text: &str) -> Result<(), MeteoError> {
+    let length = u16::try_from(text.len()).map_err(|_| protocol(&format!("chaîne de {} octets trop longue", text.len())))?;
+    buffer.extend_from_slice(&length.to_be_bytes());
+    buffer.extend_from_slice(text.as_bytes());
+    Ok(())
+}
+
+fn protocol(message: &str) -> MeteoError {
+    MeteoError::InvalidRun(format!("MQTT: {}", message))
+}
+
+fn socket_error(error: std::io::Error) -> MeteoError {
+    protocol(&error.to_string())
+}
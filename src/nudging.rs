@@ -0,0 +1,148 @@
+//! Rappel newtonien vers une série d'observations
+//!
+//! Une correction du tourbillon relatif est relaxée vers l'écart entre
+//! l'observation et le modèle avec un temps de relaxation τ, au pas et selon le
+//! schéma de l'intégration : en explicite, un pas au-delà de 2τ fait osciller
+//! puis diverger la correction. Entre deux observations,
+//! la cible est interpolée linéairement ; hors de la série, la correction est
+//! conservée telle quelle.
+//!
+//! Une observation de pression centrale est convertie en tourbillon par
+//! l'équilibre géostrophique d'une dépression gaussienne de rayon R :
+//! ζ = −4·Δp/(ρ·f·R²).
+
+use serde::{Deserialize, Serialize};
+
+use crate::{BaroclinicCyclogenesis, Float, MeteoError};
+
+/// Observation horaire du système, en tourbillon ou en pression centrale
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Observation {
+    pub hour: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub relative_vorticity: Option<f64>,  // Tourbillon relatif observé (s⁻¹)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pressure_deficit: Option<f64>,    // Écart de pression centrale à l'environnement (hPa), négatif pour une dépression
+}
+
+impl Observation {
+    /// Observation du tourbillon relatif (s⁻¹)
+    pub fn vorticity(hour: u32, relative_vorticity: f64) -> Self {
+        Self { hour, relative_vorticity: Some(relative_vorticity), pressure_deficit: None }
+    }
+
+    /// Observation de l'écart de pression centrale (hPa)
+    pub fn pressure(hour: u32, pressure_deficit: f64) -> Self {
+        Self { hour, relative_vorticity: None, pressure_deficit: Some(pressure_deficit) }
+    }
+}
+
+/// Rappel de la simulation vers une série d'observations
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Nudging {
+    timescale: f64,                  // Temps de relaxation (s)
+    observations: Vec<Observation>,  // Observations, par heures croissantes
+}
+
+impl Nudging {
+    /// Crée un rappel de temps de relaxation `timescale` (s)
+    pub fn new(timescale: f64, observations: Vec<Observation>) -> Result<Self, MeteoError> {
+        let nudging = Self { timescale, observations };
+        nudging.validate()?;
+        Ok(nudging)
+    }
+
+    /// Temps de relaxation (s)
+    pub fn timescale(&self) -> f64 {
+        self.timescale
+    }
+
+    /// Observations, par heures croissantes
+    pub fn observations(&self) -> &[Observation] {
+        &self.observations
+    }
+
+    pub(crate) fn validate(&self) -> Result<(), MeteoError> {
+        if !(self.timescale.is_finite() && self.timescale > 0.0) {
+            return Err(MeteoError::InvalidNudging(format!("temps de relaxation {} s", self.timescale)));
+        }
+        if self.observations.is_empty() {
+            return Err(MeteoError::InvalidNudging("aucune observation".to_string()));
+        }
+        if let Some(pair) = self.observations.windows(2).find(|pair| pair[1].hour <= pair[0].hour) {
+            return Err(MeteoError::InvalidNudging(format!(
+                "heures non croissantes ({} puis {})",
+                pair[0].hour, pair[1].hour
+            )));
+        }
+        for observation in &self.observations {
+            let value = match (observation.relative_vorticity, observation.pressure_deficit) {
+                (Some(value), None) | (None, Some(value)) => value,
+                _ => {
+                    return Err(MeteoError::InvalidNudging(format!(
+                        "l'observation de l'heure {} doit donner soit le tourbillon, soit la pression",
+                        observation.hour
+                    )));
+                }
+            };
+            if !value.is_finite() {
+                return Err(MeteoError::InvalidNudging(format!("valeur {} à l'heure {}", value, observation.hour)));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: Float> BaroclinicCyclogenesis<T> {
+    /// Rappelle le tourbillon relatif vers une série d'observations
+    pub fn with_nudging(mut self, nudging: Nudging) -> Self {
+        self.nudging = Some(nudging);
+        self
+    }
+
+    /// Rappel vers les observations, s'il est actif
+    pub fn nudging(&self) -> Option<&Nudging> {
+        self.nudging.as_ref()
+    }
+
+    /// Tourbillon observé (s⁻¹) à une heure, interpolé entre les observations
+    fn observed_vorticity(&self, hour: u32) -> Option<f64> {
+        let observations = &self.nudging.as_ref()?.observations;
+        let next = observations.iter().position(|observation| observation.hour >= hour)?;
+        let after = observations[next];
+        if after.hour == hour {
+            return self.observation_vorticity(&after);
+        }
+        let before = observations[next.checked_sub(1)?];
+        let fraction = f64::from(hour - before.hour) / f64::from(after.hour - before.hour);
+        let (before, after) = (self.observation_vorticity(&before)?, self.observation_vorticity(&after)?);
+        Some(before + fraction * (after - before))
+    }
+
+    /// Tourbillon (s⁻¹) équivalent à une observation, inconnu à l'équateur pour une pression
+    fn observation_vorticity(&self, observation: &Observation) -> Option<f64> {
+        if let Some(vorticity) = observation.relative_vorticity {
+            return Some(vorticity);
+        }
+        let deficit = observation.pressure_deficit? * 100.0;
+        let density = self.surface_density();
+        let coriolis = self.coriolis_parameter(self.latitude());
+        let radius = self.mean_radius();
+        (coriolis != 0.0).then(|| -4.0 * deficit / (density * coriolis * radius * radius))
+    }
+
+    /// Met à jour la correction du rappel à une heure et renvoie le tourbillon corrigé
+    pub(crate) fn nudge(&mut self, hour: u32, relative_vorticity: T) -> T {
+        let Some(timescale) = self.nudging.as_ref().map(Nudging::timescale) else {
+            return relative_vorticity;
+        };
+        if let Some(target) = self.observed_vorticity(hour) {
+            let gap = target - relative_vorticity.to_f64();
+            let correction = self.scheme().relax(self.nudging_correction.to_f64(), gap, timescale, self.time_step());
+            self.nudging_correction = T::from_f64(correction);
+        }
+        relative_vorticity + self.nudging_correction
+    }
+}
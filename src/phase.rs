@@ -0,0 +1,65 @@
+//! Évolution du déphasage entre les anomalies et verrouillage de phase
+//!
+//! Les anomalies de surface et d'altitude se comportent comme deux ondes de
+//! Rossby de bord contra-propagatives. Leur déphasage ε (90° au quart de longueur
+//! d'onde) suit dε/dt = σ·(r + 1/r)·cos ε − k·ΔU_eff, où σ est le taux de
+//! croissance d'Eady, r le rapport des amplitudes, k = 1/R et ΔU_eff le
+//! cisaillement diminué de la contra-propagation des ondes. Le déphasage se
+//! verrouille lorsque les deux termes s'équilibrent.
+//!
+//! Le couplage du modèle garde l'inclinaison initiale : ce diagnostic indique la
+//! configuration vers laquelle le système évolue.
+
+use crate::diagnostics::background_stability;
+use crate::{BaroclinicCyclogenesis, Float, DEFORMATION_RADIUS};
+
+/// Tendance du déphasage sous laquelle les anomalies sont verrouillées (1°/h, en rad/s)
+pub const PHASE_LOCK_RATE: f64 = 1.0 / 180.0 * std::f64::consts::PI / 3600.0;
+
+/// Taux de croissance maximal d'Eady, en unités de f·ΔU/(N·H)
+pub(crate) const EADY_GROWTH_COEFFICIENT: f64 = 0.3098;
+
+impl<T: Float> BaroclinicCyclogenesis<T> {
+    /// Déphasage initial (rad), déduit de l'inclinaison
+    pub fn initial_phase(&self) -> f64 {
+        self.tilt() / self.mean_radius()
+    }
+
+    /// Tendance du déphasage (rad/s) pour un cisaillement entre les niveaux (m/s)
+    pub fn phase_tendency(&self, phase: f64, shear: f64) -> f64 {
+        let (coupling, advection) = self.phase_terms(shear);
+        coupling * phase.cos() - advection
+    }
+
+    /// Couplage mutuel σ·(r + 1/r) et advection k·ΔU_eff de l'équation du déphasage (rad/s)
+    pub(crate) fn phase_terms(&self, shear: f64) -> (f64, f64) {
+        let surface = self.surface_anomaly();
+        let altitude = self.altitude_anomaly();
+
+        let wavenumber = 1.0 / self.mean_radius();
+        let stretching = (wavenumber * DEFORMATION_RADIUS).powi(2);
+        let advection = wavenumber * shear * (stretching - 1.0) / (stretching + 1.0);
+
+        let depth = (altitude.position.altitude - surface.position.altitude).abs();
+        let coriolis = 2.0 * surface.compute_coriolis_force().abs();
+        let growth = if depth > 0.0 {
+            EADY_GROWTH_COEFFICIENT * coriolis * shear / (background_stability(&surface.constants).sqrt() * depth)
+        } else {
+            0.0
+        };
+
+        let (lower, upper) = (surface.temperature_delta.to_f64().abs(), altitude.temperature_delta.to_f64().abs());
+        let amplitudes = if lower > 0.0 && upper > 0.0 { lower / upper + upper / lower } else { 0.0 };
+        (growth * amplitudes, advection)
+    }
+
+    /// Fait évoluer le déphasage sur une heure avec le schéma et le pas choisis
+    pub(crate) fn advance_phase(&self, phase: f64, shear: f64) -> f64 {
+        let (coupling, advection) = self.phase_terms(shear);
+        let dt = self.time_step();
+        (0..self.substeps()).fold(phase, |phase, _| {
+            let tendency = coupling * phase.cos() - advection;
+            phase + self.scheme.increment(tendency, -coupling * phase.sin(), dt)
+        })
+    }
+}
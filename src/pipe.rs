@@ -0,0 +1,69 @@
+//! Mode tuyau : scénarios JSON lus ligne à ligne, résultats écrits en JSON lines
+//!
+//! Chaque ligne non vide de l'entrée est un scénario JSON complet. Chaque
+//! scénario produit exactement une ligne de sortie, écrite et vidée dès la fin
+//! de sa simulation, qui rappelle le numéro de la ligne lue :
+//!
+//! ```text
+//! {"line":1,"results":[{"hour":0,"vertical_velocity":...}, ...]}
+//! {"line":2,"error":"Latitude invalide: 95°"}
+//! ```
+//!
+//! Un scénario invalide n'interrompt pas le traitement des lignes suivantes.
+
+use std::io::{BufRead, Write};
+
+use serde::Serialize;
+
+use crate::{DevelopmentResult, MeteoError, OutputLanguage, Scenario};
+
+/// Bilan d'un passage sur l'entrée
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PipeSummary {
+    pub succeeded: usize,
+    pub failed: usize,
+}
+
+/// Ligne de sortie correspondant à un scénario
+#[derive(Serialize)]
+struct Response<'a> {
+    line: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    results: Option<&'a [DevelopmentResult]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// Simule chaque scénario lu sur `input` et écrit une ligne JSON par scénario
+/// sur `output` ; seules les erreurs de lecture ou d'écriture interrompent
+pub fn run<R: BufRead, W: Write>(input: R, mut output: W, language: OutputLanguage) -> Result<PipeSummary, MeteoError> {
+    let mut summary = PipeSummary::default();
+    for (index, line) in input.lines().enumerate() {
+        let line = line.map_err(|e| MeteoError::InvalidRun(format!("lecture de l'entrée: {}", e)))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let outcome = simulate(&line);
+        let response = match &outcome {
+            Ok(results) => Response { line: index + 1, results: Some(results), error: None },
+            Err(error) => Response { line: index + 1, results: None, error: Some(error.message(language)) },
+        };
+        match outcome {
+            Ok(_) => summary.succeeded += 1,
+            Err(_) => summary.failed += 1,
+        }
+
+        let text = serde_json::to_string(&response).expect("réponse sérialisable");
+        writeln!(output, "{}", text)
+            .and_then(|_| output.flush())
+            .map_err(|e| MeteoError::InvalidRun(format!("écriture de la sortie: {}", e)))?;
+    }
+    Ok(summary)
+}
+
+/// Simule le scénario JSON d'une ligne
+fn simulate(line: &str) -> Result<Vec<DevelopmentResult>, MeteoError> {
+    let scenario: Scenario = serde_json::from_str(line).map_err(|e| MeteoError::InvalidScenario(e.to_string()))?;
+    scenario.run()
+}
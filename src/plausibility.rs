@@ -0,0 +1,95 @@
+//! Avertissements de vraisemblance physique
+//!
+//! Une configuration peut respecter toutes les plages de validation et sortir
+//! pourtant du cadre du modèle : près de l'équateur, le paramètre de Coriolis
+//! s'annule et l'équilibre du vent thermique ne tient plus ; une anomalie froide
+//! en surface sous une anomalie chaude en altitude est l'inverse de la structure
+//! d'un développement barocline ; une anomalie d'altitude au-dessus de la
+//! tropopause est amortie par la stratosphère. Ces avertissements ne bloquent
+//! pas la simulation.
+
+use crate::{BaroclinicCyclogenesis, Float, MeteoError, NumberFormat, OutputLanguage, Scenario};
+
+/// Latitude (°, en valeur absolue) sous laquelle le cadre barocline est douteux
+pub const LOW_LATITUDE: f64 = 10.0;
+
+/// Configuration suspecte mais valide
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PlausibilityWarning {
+    /// Latitude tropicale (°) : f ≈ 0
+    LowLatitude(f64),
+    /// Anomalie froide en surface sous une anomalie chaude en altitude (K)
+    InvertedAnomalies { surface: f64, altitude: f64 },
+    /// Anomalie d'altitude au-dessus de la tropopause (m)
+    AboveTropopause { altitude: f64, tropopause: f64 },
+}
+
+impl PlausibilityWarning {
+    /// Avertissement d'une ligne
+    pub fn describe(&self, language: OutputLanguage, format: &NumberFormat) -> String {
+        match (language, self) {
+            (OutputLanguage::French, PlausibilityWarning::LowLatitude(latitude)) => format!(
+                "Attention : à {}° de latitude, f ≈ 0 et le cadre barocline ne s'applique plus (au-delà de {}° seulement)",
+                format.format(*latitude),
+                LOW_LATITUDE
+            ),
+            (OutputLanguage::French, PlausibilityWarning::InvertedAnomalies { surface, altitude }) => format!(
+                "Attention : anomalie froide en surface ({} K) sous une anomalie chaude en altitude ({} K), \
+                 l'inverse d'un développement barocline",
+                format.format(*surface),
+                format.format(*altitude)
+            ),
+            (OutputLanguage::French, PlausibilityWarning::AboveTropopause { altitude, tropopause }) => format!(
+                "Attention : anomalie d'altitude à {} m, au-dessus de la tropopause ({} m), \
+                 ses mouvements verticaux sont amortis par la stratosphère",
+                format.format(*altitude),
+                format.format(*tropopause)
+            ),
+            (OutputLanguage::English, PlausibilityWarning::LowLatitude(latitude)) => format!(
+                "Warning: at {}° latitude, f ≈ 0 and the baroclinic framework no longer applies (only beyond {}°)",
+                format.format(*latitude),
+                LOW_LATITUDE
+            ),
+            (OutputLanguage::English, PlausibilityWarning::InvertedAnomalies { surface, altitude }) => format!(
+                "Warning: cold surface anomaly ({} K) under a warm upper anomaly ({} K), \
+                 the reverse of a baroclinic development",
+                format.format(*surface),
+                format.format(*altitude)
+            ),
+            (OutputLanguage::English, PlausibilityWarning::AboveTropopause { altitude, tropopause }) => format!(
+                "Warning: upper anomaly at {} m, above the tropopause ({} m), \
+                 its vertical motion is damped by the stratosphere",
+                format.format(*altitude),
+                format.format(*tropopause)
+            ),
+        }
+    }
+}
+
+impl<T: Float> BaroclinicCyclogenesis<T> {
+    /// Avertissements de vraisemblance de la configuration initiale
+    pub fn plausibility_warnings(&self) -> Vec<PlausibilityWarning> {
+        let mut warnings = Vec::new();
+        let latitude = self.initial_latitude;
+        if latitude.abs() < LOW_LATITUDE {
+            warnings.push(PlausibilityWarning::LowLatitude(latitude));
+        }
+        let surface = self.surface_anomaly().temperature_delta.to_f64();
+        let altitude = self.altitude_anomaly().temperature_delta.to_f64();
+        if surface < 0.0 && altitude > 0.0 {
+            warnings.push(PlausibilityWarning::InvertedAnomalies { surface, altitude });
+        }
+        if self.is_above_tropopause() {
+            let altitude = self.altitude_anomaly().position.altitude;
+            warnings.push(PlausibilityWarning::AboveTropopause { altitude, tropopause: self.tropopause_height });
+        }
+        warnings
+    }
+}
+
+impl Scenario {
+    /// Avertissements de vraisemblance du scénario ; une valeur hors plage est renvoyée en erreur
+    pub fn plausibility_warnings(&self) -> Result<Vec<PlausibilityWarning>, MeteoError> {
+        Ok(self.to_cyclogenesis()?.plausibility_warnings())
+    }
+}
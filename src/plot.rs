@@ -0,0 +1,119 @@
+//! Graphiques SVG autonomes, sans dépendance externe
+//!
+//! Une courbe est tracée dans un cadre fixe de 640 × 320 pixels ; les axes
+//! portent les valeurs extrêmes des deux grandeurs. Le SVG produit s'insère tel
+//! quel dans une page HTML ou un document Markdown.
+//!
+//! Une carte de chaleur est encodée en PNG, une case par valeur : blanc pour un
+//! tourbillon nul, rouge pour le plus fort tourbillon positif, bleu pour le plus
+//! fort négatif, gris pour une valeur absente.
+
+use crate::report::escape;
+use crate::{MeteoError, NumberFormat};
+
+const WIDTH: f64 = 640.0;
+const HEIGHT: f64 = 320.0;
+const MARGIN: f64 = 56.0;
+
+/// Courbe y(x) d'une série de points, par x croissants
+pub fn line_chart(title: &str, x_label: &str, y_label: &str, points: &[(f64, f64)], format: &NumberFormat) -> String {
+    let (x_min, x_max) = bounds(points.iter().map(|point| point.0));
+    let (y_min, y_max) = bounds(points.iter().map(|point| point.1));
+    let scale_x = |x: f64| MARGIN + (x - x_min) / (x_max - x_min) * (WIDTH - 2.0 * MARGIN);
+    let scale_y = |y: f64| HEIGHT - MARGIN - (y - y_min) / (y_max - y_min) * (HEIGHT - 2.0 * MARGIN);
+    let path: Vec<_> = points
+        .iter()
+        .map(|&(x, y)| format!("{:.1},{:.1}", scale_x(x), scale_y(y)))
+        .collect();
+    let (left, right, top, bottom) = (MARGIN, WIDTH - MARGIN, MARGIN, HEIGHT - MARGIN);
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" font-family=\"sans-serif\" font-size=\"12\">\n",
+        w = WIDTH,
+        h = HEIGHT
+    );
+    svg += &format!("<title>{}</title>\n", escape(title));
+    svg += &format!("<text x=\"{}\" y=\"24\" text-anchor=\"middle\" font-size=\"14\">{}</text>\n", WIDTH / 2.0, escape(title));
+    svg += &format!(
+        "<polyline fill=\"none\" stroke=\"black\" points=\"{l},{t} {l},{b} {r},{b}\"/>\n",
+        l = left,
+        t = top,
+        b = bottom,
+        r = right
+    );
+    svg += &format!("<polyline fill=\"none\" stroke=\"steelblue\" stroke-width=\"2\" points=\"{}\"/>\n", path.join(" "));
+    svg += &format!("<text x=\"{}\" y=\"{}\" text-anchor=\"middle\">{}</text>\n", left, bottom + 16.0, format.format(x_min));
+    svg += &format!("<text x=\"{}\" y=\"{}\" text-anchor=\"middle\">{}</text>\n", right, bottom + 16.0, format.format(x_max));
+    svg += &format!("<text x=\"{}\" y=\"{}\" text-anchor=\"end\">{}</text>\n", left - 4.0, bottom, format.format(y_min));
+    svg += &format!("<text x=\"{}\" y=\"{}\" text-anchor=\"end\">{}</text>\n", left - 4.0, top + 4.0, format.format(y_max));
+    svg += &format!("<text x=\"{}\" y=\"{}\" text-anchor=\"middle\">{}</text>\n", WIDTH / 2.0, HEIGHT - 12.0, escape(x_label));
+    svg += &format!(
+        "<text x=\"16\" y=\"{y}\" text-anchor=\"middle\" transform=\"rotate(-90 16 {y})\">{}</text>\n",
+        escape(y_label),
+        y = HEIGHT / 2.0
+    );
+    svg + "</svg>\n"
+}
+
+/// Valeurs extrêmes, élargies d'une unité autour d'une série constante
+fn bounds(values: impl Iterator<Item = f64>) -> (f64, f64) {
+    let (min, max) = values.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| (min.min(value), max.max(value)));
+    match (min.is_finite() && max.is_finite(), min == max) {
+        (false, _) => (0.0, 1.0),
+        (true, true) => (min - 1.0, max + 1.0),
+        (true, false) => (min, max),
+    }
+}
+
+/// Carte de chaleur PNG d'une matrice rangée par ligne, de `columns` colonnes,
+/// une case de `cell` pixels de côté par valeur
+///
+/// L'échelle des couleurs est symétrique autour de zéro, bornée par la plus
+/// grande valeur absolue.
+pub fn heatmap_png(values: &[f64], columns: usize, cell: u32) -> Result<Vec<u8>, MeteoError> {
+    if columns == 0 || values.is_empty() || !values.len().is_multiple_of(columns) || cell == 0 {
+        return Err(MeteoError::InvalidRun(format!(
+            "carte de chaleur invalide: {} valeurs sur {} colonnes, cases de {} pixels",
+            values.len(),
+            columns,
+            cell
+        )));
+    }
+    let rows = values.len() / columns;
+    let (width, height) = (columns as u32 * cell, rows as u32 * cell);
+    let extent = values.iter().filter(|value| value.is_finite()).fold(0.0, |extent: f64, value| extent.max(value.abs()));
+
+    let mut pixels = Vec::with_capacity((width * height * 3) as usize);
+    for row in values.chunks(columns) {
+        let line: Vec<u8> = row
+            .iter()
+            .flat_map(|value| {
+                let color = color(*value, extent);
+                std::iter::repeat_n(color, cell as usize).flatten()
+            })
+            .collect();
+        for _ in 0..cell {
+            pixels.extend_from_slice(&line);
+        }
+    }
+
+    let mut png = Vec::new();
+    let mut encoder = png::Encoder::new(&mut png, width, height);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder
+        .write_header()
+        .and_then(|mut writer| writer.write_image_data(&pixels).and_then(|()| writer.finish()))
+        .map_err(|e| MeteoError::InvalidRun(format!("encodage PNG: {}", e)))?;
+    Ok(png)
+}
+
+/// Couleur d'une valeur sur l'échelle bleu–blanc–rouge de demi-étendue `extent`
+fn color(value: f64, extent: f64) -> [u8; 3] {
+    if !value.is_finite() {
+        return [160, 160, 160];
+    }
+    let t = if extent > 0.0 { (value / extent).clamp(-1.0, 1.0) } else { 0.0 };
+    let fade = (255.0 * (1.0 - t.abs())).round() as u8;
+    if t >= 0.0 { [255, fade, fade] } else { [fade, fade, 255] }
+}
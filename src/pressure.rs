@@ -0,0 +1,31 @@
+//! Pression centrale équivalente du système
+//!
+//! L'écart de pression au centre est déduit du tourbillon par l'équilibre
+//! géostrophique d'une dépression gaussienne de rayon R, comme pour le rappel
+//! vers des observations de pression : Δp = −ρ·|f|·|ζ|·R²/4. Il est négatif
+//! lorsque l'anomalie de surface est cyclonique, positif sinon, et nul à
+//! l'équateur. La pression de l'environnement est celle de l'anomalie de surface.
+
+use crate::diagnostics::DRY_AIR_GAS_CONSTANT;
+use crate::{BaroclinicCyclogenesis, DevelopmentResult, Float};
+
+impl<T: Float> BaroclinicCyclogenesis<T> {
+    /// Masse volumique de l'air au niveau de l'anomalie de surface (kg/m³)
+    pub(crate) fn surface_density(&self) -> f64 {
+        let surface = self.surface_anomaly();
+        surface.position.pressure * 100.0 / (DRY_AIR_GAS_CONSTANT * surface.constants.base_temp)
+    }
+
+    /// Écart de pression centrale à l'environnement (hPa) à l'heure d'un résultat
+    pub fn pressure_deficit(&self, result: &DevelopmentResult<T>) -> f64 {
+        let sense = if self.surface_anomaly().is_cyclonic { -1.0 } else { 1.0 };
+        let coriolis = self.coriolis_parameter(result.latitude.unwrap_or_else(|| self.latitude()));
+        let radius = self.mean_radius();
+        sense * self.surface_density() * coriolis.abs() * result.relative_vorticity.to_f64().abs() * radius * radius / 4.0 / 100.0
+    }
+
+    /// Pression centrale (hPa) à l'heure d'un résultat
+    pub fn central_pressure(&self, result: &DevelopmentResult<T>) -> f64 {
+        self.surface_anomaly().position.pressure + self.pressure_deficit(result)
+    }
+}
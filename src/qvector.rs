@@ -0,0 +1,80 @@
+//! Forçage quasi géostrophique de l'ascendance par le vecteur Q
+//!
+//! Le vecteur Q = −(R/p)·(∂V_g/∂x·∇T, ∂V_g/∂y·∇T) mesure la déformation du
+//! gradient de température de fond par le vent géostrophique du système, dont le
+//! cisaillement vaut ζ/2. L'équation oméga relie sa convergence −2∇·Q à
+//! l'ascendance : elle est estimée par 2|Q|/R, positive (ascendance forcée) pour
+//! un système cyclonique et réduite par l'inclinaison des anomalies.
+
+use crate::diagnostics::DRY_AIR_GAS_CONSTANT;
+use crate::{BaroclinicCyclogenesis, DevelopmentResult, NumberFormat, OutputLanguage};
+
+/// Unités d'affichage du vecteur Q (m² kg⁻¹ s⁻¹) et de sa convergence (m kg⁻¹ s⁻¹)
+const MAGNITUDE_UNIT: f64 = 1e-12;
+const CONVERGENCE_UNIT: f64 = 1e-18;
+
+/// Vecteur Q d'un pas de temps, au niveau moyen des anomalies
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QVector {
+    pub hour: u32,
+    pub magnitude: f64,    // Module du vecteur Q (m² kg⁻¹ s⁻¹)
+    pub convergence: f64,  // Convergence −2∇·Q (m kg⁻¹ s⁻¹), positive sous l'ascendance forcée
+}
+
+impl BaroclinicCyclogenesis {
+    /// Vecteur Q associé au résultat du pas `result.hour`
+    pub fn q_vector(&self, result: &DevelopmentResult) -> QVector {
+        let pressure = self
+            .anomalies
+            .iter()
+            .map(|anomaly| anomaly.position.pressure * 100.0)
+            .sum::<f64>()
+            / self.anomalies.len() as f64;
+        let gradient = self.meridional_gradient / 1.0e6;  // K/m
+        let deformation = result.relative_vorticity / 2.0;
+        let magnitude = DRY_AIR_GAS_CONSTANT / pressure * deformation.abs() * gradient;
+
+        let coriolis = self.coriolis_parameter(self.latitude());
+        let cyclonic = if result.relative_vorticity * coriolis > 0.0 { 1.0 } else { -1.0 };
+
+        QVector {
+            hour: result.hour,
+            magnitude,
+            convergence: cyclonic * 2.0 * magnitude / self.mean_radius() * self.coupling_efficiency(result.hour),
+        }
+    }
+}
+
+/// Tableau du vecteur Q, en regard de la vitesse verticale du modèle
+pub fn to_table(
+    results: &[DevelopmentResult],
+    vectors: &[QVector],
+    language: OutputLanguage,
+    format: &NumberFormat,
+) -> String {
+    let (title, header) = match language {
+        OutputLanguage::French => (
+            "Vecteur Q : |Q| en 10⁻¹² m² kg⁻¹ s⁻¹, −2∇·Q en 10⁻¹⁸ m kg⁻¹ s⁻¹",
+            "Heure | Vitesse (cm/s)       | |Q|                  | −2∇·Q",
+        ),
+        OutputLanguage::English => (
+            "Q-vector: |Q| in 10⁻¹² m² kg⁻¹ s⁻¹, −2∇·Q in 10⁻¹⁸ m kg⁻¹ s⁻¹",
+            "Hour  | Velocity (cm/s)      | |Q|                  | −2∇·Q",
+        ),
+    };
+
+    let mut table = format!(
+        "{}\n{}\n------|----------------------|----------------------|----------------------\n",
+        title, header
+    );
+    for (result, vector) in results.iter().zip(vectors) {
+        table += &format!(
+            "{:4} | {} | {} | {}\n",
+            vector.hour,
+            format.format_width(result.vertical_velocity * 100.0, 20),
+            format.format_width(vector.magnitude / MAGNITUDE_UNIT, 20),
+            format.format_width(vector.convergence / CONVERGENCE_UNIT, 20)
+        );
+    }
+    table
+}
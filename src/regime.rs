@@ -0,0 +1,80 @@
+//! Nombres sans dimension du régime dynamique
+//!
+//! Le nombre de Rossby Ro = ΔU/(f·R) compare l'advection à la force de Coriolis,
+//! le nombre de Burger Bu = (L_R/R)² la taille des anomalies au rayon de
+//! déformation L_R = N·H_T/f pour une troposphère de 10 km. L'échelle de temps
+//! d'Eady 1/σ, avec σ = 0,31·f·(ΔU/H)/N, est le temps de doublement typique
+//! d'une onde barocline dans la couche H séparant les anomalies.
+
+use crate::diagnostics::background_stability;
+use crate::phase::EADY_GROWTH_COEFFICIENT;
+use crate::{BaroclinicCyclogenesis, NumberFormat, OutputLanguage};
+
+/// Régime dynamique d'une configuration
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DynamicalRegime {
+    pub rossby: f64,          // Nombre de Rossby
+    pub burger: f64,          // Nombre de Burger
+    pub eady_timescale: f64,  // Échelle de temps d'Eady (s), infinie sans cisaillement
+}
+
+impl DynamicalRegime {
+    /// Régime quasi géostrophique, Ro < 1
+    pub fn is_quasi_geostrophic(&self) -> bool {
+        self.rossby < 1.0
+    }
+
+    /// Ligne d'en-tête résumant le régime
+    pub fn describe(&self, language: OutputLanguage, format: &NumberFormat) -> String {
+        let (rossby, burger) = (format.format(self.rossby), format.format(self.burger));
+        let timescale = if self.eady_timescale.is_finite() {
+            format!("{} h", format.format(self.eady_timescale / 3600.0))
+        } else {
+            "∞".to_string()
+        };
+        let quasi_geostrophic = self.is_quasi_geostrophic();
+        match language {
+            OutputLanguage::French => format!(
+                "Ro = {}, Bu = {}, temps d'Eady = {} (régime {})",
+                rossby,
+                burger,
+                timescale,
+                if quasi_geostrophic { "quasi géostrophique" } else { "agéostrophique" }
+            ),
+            OutputLanguage::English => format!(
+                "Ro = {}, Bu = {}, Eady timescale = {} ({} regime)",
+                rossby,
+                burger,
+                timescale,
+                if quasi_geostrophic { "quasi-geostrophic" } else { "ageostrophic" }
+            ),
+        }
+    }
+}
+
+impl BaroclinicCyclogenesis {
+    /// Nombres sans dimension de la configuration initiale, `None` à l'équateur
+    pub fn dynamical_regime(&self) -> Option<DynamicalRegime> {
+        let shear = self.thermal_wind_shear()?;
+        let surface = self.surface_anomaly();
+        let altitude = self.altitude_anomaly();
+
+        let coriolis = 2.0 * surface.compute_coriolis_force().abs();
+        let stability = background_stability(&surface.constants).sqrt();
+        let radius = self.mean_radius();
+        let depth = (altitude.position.altitude - surface.position.altitude).abs();
+
+        let deformation_radius = stability * self.tropopause_height / coriolis;
+        let growth_rate = if depth > 0.0 {
+            EADY_GROWTH_COEFFICIENT * coriolis * shear / (stability * depth)
+        } else {
+            0.0
+        };
+
+        Some(DynamicalRegime {
+            rossby: shear / (coriolis * radius),
+            burger: (deformation_radius / radius).powi(2),
+            eady_timescale: 1.0 / growth_rate,
+        })
+    }
+}
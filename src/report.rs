@@ -0,0 +1,344 @@
+//! Rapport autonome d'une simulation, en Markdown ou en HTML
+//!
+//! Le rapport reprend le scénario au format TOML, les constats de sa
+//! vérification, les indicateurs du pic et le tableau des résultats horaires.
+//! Avec la fonctionnalité `plot`, il intègre en SVG les courbes du tourbillon
+//! relatif et de la vitesse verticale ; le document n'a besoin d'aucun fichier
+//! annexe.
+
+use std::str::FromStr;
+
+use crate::beaufort::Beaufort;
+use crate::check::ScenarioCheck;
+use crate::deepening::{self, DeepeningRate};
+use crate::gust::{self, Gust, GustModel};
+use crate::impacts::{self, Coastline, SurgeEstimate};
+use crate::classify::{self, StormState, WindstormClassifier};
+use crate::sting_jet::{self, StingJetCriteria, StingJetRisk};
+use crate::summary::DevelopmentSummary;
+use crate::waves::{self, SeaState, WaveModel};
+use crate::{MeteoError, NumberFormat, OutputLanguage, ResultSeries, Scenario, TableFormatter};
+
+/// Format du rapport
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReportFormat {
+    #[default]
+    Markdown,
+    Html,
+}
+
+impl FromStr for ReportFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "markdown" | "md" => Ok(ReportFormat::Markdown),
+            "html" => Ok(ReportFormat::Html),
+            _ => Err(format!("Format de rapport inconnu: {} (markdown, html)", value)),
+        }
+    }
+}
+
+/// Titres des parties du rapport
+#[derive(Clone, Copy)]
+enum Section {
+    Scenario,
+    Check,
+    Metrics,
+    Results,
+    #[cfg(feature = "plot")]
+    Charts,
+}
+
+impl Section {
+    fn title(self, language: OutputLanguage) -> &'static str {
+        match (language, self) {
+            (OutputLanguage::French, Section::Scenario) => "Scénario",
+            (OutputLanguage::French, Section::Check) => "Vérification",
+            (OutputLanguage::French, Section::Metrics) => "Indicateurs",
+            (OutputLanguage::French, Section::Results) => "Résultats horaires",
+            (OutputLanguage::English, Section::Scenario) => "Scenario",
+            (OutputLanguage::English, Section::Check) => "Check",
+            (OutputLanguage::English, Section::Metrics) => "Key metrics",
+            (OutputLanguage::English, Section::Results) => "Hourly results",
+            #[cfg(feature = "plot")]
+            (OutputLanguage::French, Section::Charts) => "Graphiques",
+            #[cfg(feature = "plot")]
+            (OutputLanguage::English, Section::Charts) => "Charts",
+        }
+    }
+}
+
+/// Rapport d'une simulation
+#[derive(Debug, Clone)]
+pub struct Report {
+    scenario: Scenario,
+    check: ScenarioCheck,
+    series: ResultSeries,
+    states: Vec<StormState>,
+    deepening: Vec<DeepeningRate>,
+    gusts: Vec<Gust>,
+    sea_states: Vec<SeaState>,
+    surges: Vec<SurgeEstimate>,
+    sting_jet: Vec<StingJetRisk>,
+    summary: Option<DevelopmentSummary>,
+}
+
+impl Report {
+    /// Vérifie et simule un scénario
+    pub fn compute(scenario: &Scenario) -> Result<Self, MeteoError> {
+        let check = ScenarioCheck::run(scenario)?;
+        let mut cyclogenesis = scenario.to_cyclogenesis()?;
+        let (results, diagnostics) = cyclogenesis.simulate_with_diagnostics(scenario.time_steps)?;
+        let states = cyclogenesis.storm_states(&results);
+        let deepening = cyclogenesis.deepening_rates(&results);
+        let gusts = cyclogenesis.gusts(&results, &diagnostics, &GustModel::default());
+        let sea_states = cyclogenesis.sea_states(&results, &WaveModel::default());
+        let surges = cyclogenesis.storm_surge(&results, &Coastline::default());
+        let sting_jet = cyclogenesis.sting_jet_risk(&results, &StingJetCriteria::default());
+        let summary = DevelopmentSummary::compute(&results, &diagnostics);
+        Ok(Self {
+            scenario: scenario.clone(),
+            check,
+            series: ResultSeries::new(results),
+            states,
+            deepening,
+            gusts,
+            sea_states,
+            surges,
+            sting_jet,
+            summary,
+        })
+    }
+
+    /// Scénario simulé
+    pub fn scenario(&self) -> &Scenario {
+        &self.scenario
+    }
+
+    /// Vérification du scénario
+    pub fn check(&self) -> &ScenarioCheck {
+        &self.check
+    }
+
+    /// Résultats de la simulation
+    pub fn series(&self) -> &ResultSeries {
+        &self.series
+    }
+
+    /// Vent de surface et pression centrale à chaque heure
+    pub fn storm_states(&self) -> &[StormState] {
+        &self.states
+    }
+
+    /// Creusement sur 24 heures glissantes
+    pub fn deepening_rates(&self) -> &[DeepeningRate] {
+        &self.deepening
+    }
+
+    /// Rafale de pointe à chaque heure
+    pub fn gusts(&self) -> &[Gust] {
+        &self.gusts
+    }
+
+    /// Vagues levées par le vent à chaque heure, pour un fetch de 300 km
+    pub fn sea_states(&self) -> &[SeaState] {
+        &self.sea_states
+    }
+
+    /// Surcote à chaque heure, sur une côte exposée à l'ouest
+    pub fn surges(&self) -> &[SurgeEstimate] {
+        &self.surges
+    }
+
+    /// Risque de sting jet à chaque heure
+    pub fn sting_jet(&self) -> &[StingJetRisk] {
+        &self.sting_jet
+    }
+
+    /// Maturité, pic et temps de croissance, `None` sans pas de temps
+    pub fn summary(&self) -> Option<&DevelopmentSummary> {
+        self.summary.as_ref()
+    }
+
+    /// Rapport dans le format demandé
+    pub fn render(&self, output: ReportFormat, language: OutputLanguage, format: &NumberFormat) -> String {
+        match output {
+            ReportFormat::Markdown => self.to_markdown(language, format),
+            ReportFormat::Html => self.to_html(language, format),
+        }
+    }
+
+    /// Rapport en Markdown ; les tableaux suivent la syntaxe GitHub
+    pub fn to_markdown(&self, language: OutputLanguage, format: &NumberFormat) -> String {
+        let table = TableFormatter::default().with_language(language).with_number_format(*format);
+
+        let mut report = format!("# {} ({}°N)\n", title(language), self.scenario.latitude);
+        report += &format!("\n## {}\n\n```toml\n{}```\n", Section::Scenario.title(language), self.scenario_toml());
+        report += &format!("\n## {}\n\n", Section::Check.title(language));
+        for line in self.check.describe(language, format).lines() {
+            report += &format!("- {}\n", line);
+        }
+        report += &format!("\n## {}\n\n", Section::Metrics.title(language));
+        for line in self.metrics(language, format) {
+            report += &format!("- {}\n", line);
+        }
+        report += &format!("\n## {}\n\n{}", Section::Results.title(language), table.format(&self.series.to_results()));
+        #[cfg(feature = "plot")]
+        {
+            report += &format!("\n## {}\n\n{}", Section::Charts.title(language), self.charts(language, format));
+        }
+        report
+    }
+
+    /// Page HTML autonome
+    pub fn to_html(&self, language: OutputLanguage, format: &NumberFormat) -> String {
+        let table = TableFormatter::default().with_language(language).with_number_format(*format);
+
+        let mut report = format!(
+            "<!DOCTYPE html>\n<html lang=\"{}\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n{}</head>\n<body>\n",
+            language,
+            title(language),
+            STYLE
+        );
+        report += &format!("<h1>{} ({}°N)</h1>\n", title(language), self.scenario.latitude);
+        report += &format!(
+            "<h2>{}</h2>\n<pre><code>{}</code></pre>\n",
+            Section::Scenario.title(language),
+            escape(&self.scenario_toml())
+        );
+        report += &format!("<h2>{}</h2>\n", Section::Check.title(language));
+        report += &html_list(self.check.describe(language, format).lines());
+        report += &format!("<h2>{}</h2>\n", Section::Metrics.title(language));
+        report += &html_list(self.metrics(language, format).iter().map(String::as_str));
+
+        report += &format!("<h2>{}</h2>\n<table>\n<tr>", Section::Results.title(language));
+        for label in table.labels() {
+            report += &format!("<th>{}</th>", escape(label));
+        }
+        report += "</tr>\n";
+        for result in &self.series.to_results() {
+            report += "<tr>";
+            for cell in table.cells(result) {
+                report += &format!("<td>{}</td>", escape(&cell));
+            }
+            report += "</tr>\n";
+        }
+        report += "</table>\n";
+        #[cfg(feature = "plot")]
+        {
+            report += &format!("<h2>{}</h2>\n{}", Section::Charts.title(language), self.charts(language, format));
+        }
+        report + "</body>\n</html>\n"
+    }
+
+    /// Scénario au format TOML
+    fn scenario_toml(&self) -> String {
+        toml::to_string(&self.scenario).unwrap_or_default()
+    }
+
+    /// Indicateurs du pic, de la vitesse verticale, du vent et de la pression, une
+    /// ligne chacun
+    fn metrics(&self, language: OutputLanguage, format: &NumberFormat) -> Vec<String> {
+        let (Some(peak), Some(mean_w)) = (self.series.peak(), self.series.mean_w()) else {
+            return vec![match language {
+                OutputLanguage::French => "Aucun pas de temps simulé".to_string(),
+                OutputLanguage::English => "No time step simulated".to_string(),
+            }];
+        };
+        let vorticity = format.format(peak.relative_vorticity() * 1e5);
+        let mean_w = format.format(mean_w * 100.0);
+        let mut metrics = match language {
+            OutputLanguage::French => vec![
+                format!("Tourbillon maximal : {} 10⁻⁵ s⁻¹ à l'heure {}", vorticity, peak.hour()),
+                format!("Vitesse verticale moyenne : {} cm/s", mean_w),
+            ],
+            OutputLanguage::English => vec![
+                format!("Peak vorticity: {} 10⁻⁵ s⁻¹ at hour {}", vorticity, peak.hour()),
+                format!("Mean vertical velocity: {} cm/s", mean_w),
+            ],
+        };
+        if let Some(rate) = self.series.deepening_rate() {
+            let rate = format.format(rate * 1e5 * 3600.0);
+            metrics.push(match language {
+                OutputLanguage::French => format!("Taux de creusement jusqu'au pic : {} 10⁻⁵ s⁻¹/h", rate),
+                OutputLanguage::English => format!("Deepening rate up to the peak: {} 10⁻⁵ s⁻¹/h", rate),
+            });
+        }
+        if let Some(state) = self.states.iter().max_by(|a, b| a.surface_wind.total_cmp(&b.surface_wind)) {
+            let beaufort = Beaufort::from_speed(state.surface_wind).describe(language);
+            let wind = format!("{} {}", format.format_wind(state.surface_wind), format.wind_unit().symbol());
+            metrics.push(match language {
+                OutputLanguage::French => format!("Vent maximal en surface : {} ({}) à l'heure {}", wind, beaufort, state.hour),
+                OutputLanguage::English => format!("Peak surface wind: {} ({}) at hour {}", wind, beaufort, state.hour),
+            });
+        }
+        if !self.gusts.is_empty() {
+            metrics.push(gust::describe_peak(&self.gusts, language, format));
+        }
+        if !self.sea_states.is_empty() {
+            metrics.push(waves::describe_peak(&self.sea_states, language, format));
+        }
+        if !self.surges.is_empty() {
+            metrics.push(impacts::describe_peak(&self.surges, language, format));
+        }
+        if let Some(state) = self.states.iter().min_by(|a, b| a.central_pressure.total_cmp(&b.central_pressure)) {
+            let pressure = format.format(state.central_pressure);
+            metrics.push(match language {
+                OutputLanguage::French => format!("Pression centrale minimale : {} hPa à l'heure {}", pressure, state.hour),
+                OutputLanguage::English => format!("Lowest central pressure: {} hPa at hour {}", pressure, state.hour),
+            });
+        }
+        if !self.deepening.is_empty() {
+            metrics.push(deepening::describe_peak(&self.deepening, language, format));
+        }
+        metrics.push(classify::severity_statement(&WindstormClassifier::default(), &self.states, language));
+        metrics.push(sting_jet::describe_periods(&self.sting_jet, language));
+        if let Some(summary) = &self.summary {
+            metrics.push(summary.describe(language, format));
+        }
+        metrics
+    }
+
+    /// Courbes du tourbillon relatif et de la vitesse verticale
+    #[cfg(feature = "plot")]
+    fn charts(&self, language: OutputLanguage, format: &NumberFormat) -> String {
+        use crate::plot::line_chart;
+
+        let vorticity: Vec<_> =
+            self.series.rows().map(|result| (f64::from(result.hour()), result.relative_vorticity() * 1e5)).collect();
+        let vertical_velocity: Vec<_> =
+            self.series.rows().map(|result| (f64::from(result.hour()), result.vertical_velocity() * 100.0)).collect();
+        let (hour, vorticity_label, velocity_label) = match language {
+            OutputLanguage::French => ("Heure", "Tourbillon relatif (10⁻⁵ s⁻¹)", "Vitesse verticale (cm/s)"),
+            OutputLanguage::English => ("Hour", "Relative vorticity (10⁻⁵ s⁻¹)", "Vertical velocity (cm/s)"),
+        };
+        line_chart(vorticity_label, hour, vorticity_label, &vorticity, format)
+            + &line_chart(velocity_label, hour, velocity_label, &vertical_velocity, format)
+    }
+}
+
+/// Mise en page minimale de la page HTML
+const STYLE: &str = "<style>\n\
+body { font-family: sans-serif; max-width: 60em; margin: 2em auto; }\n\
+table { border-collapse: collapse; }\n\
+th, td { border: 1px solid #ccc; padding: 0.2em 0.6em; text-align: right; }\n\
+pre { background: #f4f4f4; padding: 0.6em; }\n\
+</style>\n";
+
+fn title(language: OutputLanguage) -> &'static str {
+    match language {
+        OutputLanguage::French => "Rapport de simulation",
+        OutputLanguage::English => "Simulation report",
+    }
+}
+
+fn html_list<'a>(lines: impl Iterator<Item = &'a str>) -> String {
+    let items: String = lines.map(|line| format!("<li>{}</li>\n", escape(line))).collect();
+    format!("<ul>\n{}</ul>\n", items)
+}
+
+/// Échappe un texte pour XML et HTML
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
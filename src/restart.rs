@@ -0,0 +1,48 @@
+//! Reprise d'une simulation à partir des résultats d'un calcul précédent
+//!
+//! Une simulation construite avec d'autres paramètres repart de l'heure qui suit
+//! le dernier résultat d'un calcul précédent, à la position atteinte par le
+//! système : les expériences de branchement comparent ainsi plusieurs suites
+//! d'un même tronc commun. Le déphasage des diagnostics et une éventuelle
+//! couche limite repartent de leur valeur initiale, et la correction d'un
+//! éventuel rappel vers des observations, comme le tourbillon échangé avec
+//! des tempêtes voisines, est nulle.
+
+use crate::{BaroclinicCyclogenesis, DevelopmentResult, MeteoError, Scenario};
+
+impl BaroclinicCyclogenesis {
+    /// Simulation du scénario `state` poursuivant les résultats `results_tail`
+    ///
+    /// Le scénario garde la latitude et la date de départ du calcul précédent ;
+    /// la simulation reprend à l'heure suivant le dernier résultat.
+    pub fn from_state(state: &Scenario, results_tail: &[DevelopmentResult]) -> Result<Self, MeteoError> {
+        let Some(last) = results_tail.last() else {
+            return Err(MeteoError::InvalidRun("aucun résultat à poursuivre".to_string()));
+        };
+        let mut cyclogenesis = state.to_cyclogenesis()?;
+
+        if let Some(latitude) = last.latitude {
+            for anomaly in &mut cyclogenesis.anomalies {
+                anomaly.position.latitude = latitude;
+            }
+        }
+        if let Some(longitude) = last.longitude {
+            cyclogenesis.longitude = longitude;
+            if cyclogenesis.diurnal_longitude.is_some() {
+                cyclogenesis.diurnal_longitude = Some(longitude.rem_euclid(360.0));
+            }
+        }
+        // Le dernier résultat précède le déplacement de sa propre heure
+        cyclogenesis.advance_track(last);
+        cyclogenesis.first_hour = last.hour.checked_add(1).ok_or_else(|| {
+            MeteoError::InvalidScenario(format!("aucune heure après l'heure {}", last.hour))
+        })?;
+        log_event!(info, hour = cyclogenesis.first_hour, "reprise de la simulation");
+        Ok(cyclogenesis)
+    }
+
+    /// Première heure simulée, non nulle pour une reprise
+    pub fn first_hour(&self) -> u32 {
+        self.first_hour
+    }
+}
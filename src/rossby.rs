@@ -0,0 +1,68 @@
+//! Train d'ondes de Rossby d'altitude
+//!
+//! Les talwegs et les dorsales du train défilent au-dessus de l'anomalie
+//! d'altitude à la vitesse de phase de l'onde et modulent son anomalie thermique :
+//! l'approche d'un talweg froid renforce le développement, celle d'une dorsale
+//! l'affaiblit.
+
+use std::f64::consts::PI;
+
+use serde::{Deserialize, Serialize};
+
+use crate::MeteoError;
+
+/// Train d'ondes défilant au niveau de l'anomalie d'altitude
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RossbyWaveTrain {
+    amplitude: f64,        // Anomalie thermique au passage d'un talweg (K)
+    wavelength: f64,       // Longueur d'onde (m)
+    phase_speed: f64,      // Vitesse de phase par rapport à l'anomalie (m/s)
+    #[serde(default)]
+    trough_distance: Option<f64>,  // Distance initiale du talweg en amont (m)
+}
+
+impl RossbyWaveTrain {
+    /// Crée un train d'ondes dont une dorsale surplombe l'anomalie au départ
+    pub fn new(amplitude: f64, wavelength: f64, phase_speed: f64) -> Result<Self, MeteoError> {
+        let wave = Self {
+            amplitude,
+            wavelength,
+            phase_speed,
+            trough_distance: None,
+        };
+        wave.validate()?;
+        Ok(wave)
+    }
+
+    /// Place le premier talweg à une distance donnée en amont de l'anomalie (m)
+    pub fn with_trough_distance(mut self, distance: f64) -> Self {
+        self.trough_distance = Some(distance);
+        self
+    }
+
+    /// Distance initiale du talweg en amont, une demi-longueur d'onde par défaut
+    pub fn trough_distance(&self) -> f64 {
+        self.trough_distance.unwrap_or(self.wavelength / 2.0)
+    }
+
+    /// Perturbation thermique apportée à l'anomalie d'altitude à une heure donnée (K)
+    pub fn temperature_perturbation(&self, hour: u32) -> f64 {
+        let travelled = self.phase_speed * f64::from(hour) * 3600.0;
+        let phase = 2.0 * PI * (travelled - self.trough_distance()) / self.wavelength;
+        -self.amplitude * phase.cos()
+    }
+
+    pub(crate) fn validate(&self) -> Result<(), MeteoError> {
+        if !(0.0..=50.0).contains(&self.amplitude) {
+            return Err(MeteoError::InvalidTemperature(self.amplitude));
+        }
+        if !(self.wavelength.is_finite() && self.wavelength > 0.0) {
+            return Err(MeteoError::InvalidScale(self.wavelength));
+        }
+        if !self.phase_speed.is_finite() {
+            return Err(MeteoError::InvalidScenario(format!("vitesse de phase invalide: {} m/s", self.phase_speed)));
+        }
+        Ok(())
+    }
+}
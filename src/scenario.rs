@@ -0,0 +1,408 @@
+//! Description d'une simulation dans un fichier TOML
+//!
+//! Tous les champs sont facultatifs et reprennent par défaut le cas de référence
+//! (+5 K en surface, -8 K à 500 hPa, 45°N, 24 heures) :
+//!
+//! ```toml
+//! surface_temp = 5.0
+//! altitude_temp = -8.0
+//! latitude = 45.0
+//! time_steps = 24
+//! start_time = 1999-12-26T00:00:00Z    # Date de départ (UTC)
+//! longitude = 2.35                     # Active le cycle diurne
+//! horizontal_scale = 5.0e5             # Rayon des anomalies (m)
+//! season = "winter"                    # État de fond saisonnier : winter, summer ou equinox
+//! meridional_gradient = 10.0           # Gradient de fond (K/1000 km), prioritaire sur celui de la saison
+//! tropopause_height = 11000.0          # Hauteur de la tropopause (m), prioritaire sur celle de la saison
+//! upper_level_altitude = 12000.0       # Altitude de l'anomalie d'altitude (m, 5000 par défaut)
+//! upper_level_pressure = 200.0         # Pression de l'anomalie d'altitude (hPa, 500 par défaut)
+//! surface = { ocean = { sst = 285.0 } } # Océan (K) ou { land = { roughness = 0.1, soil_moisture = 0.3 } }
+//! drag_law = { charnock = { alpha = 0.011 } }  # Traînée selon le vent, prioritaire sur celle de la surface
+//! boundary_layer_depth = 800.0         # Hauteur initiale d'une couche limite évolutive (m)
+//! tilt = 3.9e5                        # Décalage vers l'ouest de l'anomalie d'altitude (m)
+//! geometry = "spherical"               # f_plane (défaut), beta_plane ou spherical
+//! shape = "top_hat"                    # gaussian (défaut), top_hat ou { elliptical = { aspect_ratio = 2.0 } }
+//! scheme = "semi_implicit"             # explicit (défaut) ou semi_implicit
+//! time_step = 600.0                    # Pas de temps de l'intégration (s)
+//! step_policy = "reduce"               # warn (défaut) ou reduce au-delà de la limite de stabilité
+//! validation = "lenient"               # strict (défaut), lenient ou off face à une valeur hors plage
+//!
+//! [climatology]                          # État de fond d'une climatologie zonale, à la latitude du scénario
+//! latitudes = [30.0, 45.0, 60.0]         # °
+//! pressures = [1000.0, 500.0, 250.0]     # hPa
+//! temperature = [[298.0, 266.5, 229.0], [288.0, 255.0, 221.0], [276.0, 244.0, 222.0]]  # K
+//!
+//! [limits]                               # Plages de validation (martienne, stratosphérique...)
+//! altitude = { min = -400.0, max = 50000.0 }     # m
+//! pressure = { min = 1.0, max = 1100.0 }         # hPa
+//! temperature = { min = -80.0, max = 80.0 }      # K
+//!
+//! [nudging]                              # Rappel vers des observations
+//! timescale = 21600.0                    # Temps de relaxation (s)
+//! observations = [
+//!     { hour = 6, relative_vorticity = -4.0e-5 },  # s⁻¹
+//!     { hour = 18, pressure_deficit = -12.0 },     # hPa
+//! ]
+//!
+//! [rossby_wave]                          # Train d'ondes d'altitude
+//! amplitude = 3.0                        # K
+//! wavelength = 4.0e6                     # m
+//! phase_speed = 10.0                     # m/s
+//! trough_distance = 1.5e6                # m en amont, facultatif
+//!
+//! [upper_trough]                         # Talweg d'altitude en approche, à la place de `tilt`
+//! amplitude = 4.0                        # K
+//! half_wavelength = 1.5e6                # m
+//! approach_speed = 15.0                  # m/s
+//! distance = 1.2e6                       # m en amont, facultatif
+//!
+//! [pv_streamer]                          # Filament de tourbillon potentiel descendant
+//! amplitude = 4.0                        # K
+//! onset = 6                              # Heure du début de la descente
+//! duration = 12                          # h
+//! top = 12000.0                          # m, facultatif
+//! bottom = 5000.0                        # m, facultatif
+//! ```
+
+use std::fs;
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::{
+    AnomalyShape, BaroclinicCyclogenesis, Climatology, DevelopmentResult, DragLaw, Float, Geometry, IntegrationScheme,
+    MeteoError, Nudging, PvStreamer, RossbyWaveTrain, Season, StepPolicy, SurfaceType, UpperTrough, ValidationLimits,
+    ValidationPolicy, UPPER_LEVEL,
+};
+
+/// Fichier de scénario commenté, écrit par `cyclogenese scenario init`
+///
+/// Les champs actifs reprennent le cas de référence ; les champs facultatifs
+/// sont commentés avec leur valeur d'exemple.
+pub const TEMPLATE: &str = r#"# Scénario de cyclogenèse barocline
+#
+# Les champs absents reprennent le cas de référence. Vérifier le fichier sans
+# le simuler : cyclogenese scenario check <fichier>
+
+# Anomalie thermique de surface (K, -50 à 50)
+surface_temp = 5.0
+# Anomalie thermique à 500 hPa (K, -50 à 50)
+altitude_temp = -8.0
+# Latitude de départ (°, -90 à 90)
+latitude = 45.0
+# Nombre d'heures simulées
+time_steps = 24
+
+# Nom du scénario, étiquette de ses durées dans les métriques Prometheus
+# name = "lothar"
+# Date de départ (UTC), date les résultats
+# start_time = 1999-12-26T00:00:00Z
+# Longitude (°, -180 à 360), active le cycle diurne si la date est fixée
+# longitude = 2.35
+
+# Rayon des anomalies (m, 1e4 à 1e7)
+# horizontal_scale = 5.0e5
+# Profil horizontal : "gaussian", "top_hat" ou { elliptical = { aspect_ratio = 2.0 } }
+# shape = "gaussian"
+# État de fond saisonnier (température de référence, gradient méridien, tropopause) :
+# "winter", "summer" ou "equinox" (état de référence)
+# season = "equinox"
+# Gradient méridien de température de fond (K/1000 km, 0 à 50), prioritaire sur celui de la saison
+# meridional_gradient = 10.0
+# Hauteur de la tropopause (m, 5000 à 20000), prioritaire sur celle de la saison et de la climatologie
+# tropopause_height = 10000.0
+# Altitude (m) et pression (hPa) de l'anomalie d'altitude, 5000 m et 500 hPa par défaut ;
+# au-dessus de la tropopause, ses mouvements verticaux sont amortis
+# upper_level_altitude = 5000.0
+# upper_level_pressure = 500.0
+# Surface sous l'anomalie de surface, flux de chaleur et frottement :
+# { ocean = { sst = 285.0 } } (K) ou { land = { roughness = 0.1, soil_moisture = 0.3 } } (m, 0 à 1)
+# surface = { ocean = { sst = 285.0 } }
+# Loi de traînée du frottement, à la place de celle de la surface : { constant = { drag_coefficient = 1.2e-3 } },
+# { roughness = { length = 0.1 } } (m) ou { charnock = { alpha = 0.011 } } (rugosité de la mer selon le vent)
+# drag_law = { charnock = { alpha = 0.011 } }
+# Hauteur initiale d'une couche limite évolutive (m, 100 à 3000), 1000 m implicites et constants sinon
+# boundary_layer_depth = 1000.0
+# Décalage vers l'ouest de l'anomalie d'altitude (m), un quart de longueur d'onde par défaut
+# tilt = 7.85e5
+# Géométrie : "f_plane", "beta_plane" ou "spherical"
+# geometry = "f_plane"
+
+# Schéma d'intégration : "explicit" ou "semi_implicit"
+# scheme = "explicit"
+# Pas de temps de l'intégration (s, 1 à 3600)
+# time_step = 60.0
+# Au-delà de la limite de stabilité : "warn" ou "reduce"
+# step_policy = "warn"
+# Face à une valeur hors plage : "strict" (refus), "lenient" (ramenée à la borne) ou "off"
+# validation = "strict"
+
+# Train d'ondes de Rossby d'altitude
+# [rossby_wave]
+# amplitude = 3.0          # K
+# wavelength = 4.0e6       # m
+# phase_speed = 10.0       # m/s
+# trough_distance = 1.5e6  # m en amont
+
+# Talweg d'altitude en approche de l'anomalie de surface, dont le décalage remplace `tilt`
+# [upper_trough]
+# amplitude = 4.0          # K sur l'axe du talweg
+# half_wavelength = 1.5e6  # m de l'axe à la dorsale
+# approach_speed = 15.0    # m/s
+# distance = 1.5e6         # m en amont, une demi-longueur d'onde par défaut
+
+# Filament de tourbillon potentiel descendant d'un pli de tropopause
+# [pv_streamer]
+# amplitude = 4.0   # K de refroidissement en fin de descente
+# onset = 6         # heure du début de la descente
+# duration = 12     # h
+# top = 12000.0     # m
+# bottom = 5000.0   # m
+
+# Climatologie zonale fixant l'état de fond à la latitude du scénario (après la saison),
+# ou option --climatology <fichier CSV ou NetCDF>
+# [climatology]
+# latitudes = [30.0, 45.0, 60.0]                                                        # °
+# pressures = [1000.0, 500.0, 250.0]                                                    # hPa
+# temperature = [[298.0, 266.5, 229.0], [288.0, 255.0, 221.0], [276.0, 244.0, 222.0]]  # K
+
+# Plages de validation, pour une atmosphère martienne ou une étude de la stratosphère
+# [limits]
+# altitude = { min = -400.0, max = 20000.0 }  # m
+# pressure = { min = 100.0, max = 1100.0 }    # hPa
+# temperature = { min = -50.0, max = 50.0 }   # K
+
+# Rappel vers des observations
+# [nudging]
+# timescale = 21600.0      # s
+# observations = [
+#     { hour = 6, relative_vorticity = -4.0e-5 },  # s⁻¹
+#     { hour = 18, pressure_deficit = -12.0 },     # hPa
+# ]
+"#;
+
+/// Paramètres d'une simulation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Scenario {
+    pub surface_temp: f64,                   // Anomalie thermique de surface (K)
+    pub altitude_temp: f64,                  // Anomalie thermique d'altitude (K)
+    pub latitude: f64,                       // Latitude (°)
+    pub time_steps: u32,                     // Nombre d'heures simulées
+    #[serde(skip_serializing_if = "Option::is_none", deserialize_with = "deserialize_start_time")]
+    pub start_time: Option<DateTime<Utc>>,   // Date de départ (UTC)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub longitude: Option<f64>,              // Longitude du cycle diurne (°)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub horizontal_scale: Option<f64>,       // Rayon des anomalies (m)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub season: Option<Season>,              // État de fond saisonnier
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub climatology: Option<Climatology>,    // État de fond d'une climatologie zonale
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meridional_gradient: Option<f64>,    // Gradient méridien de température de fond (K/1000 km)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tropopause_height: Option<f64>,      // Hauteur de la tropopause (m)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upper_level_altitude: Option<f64>,   // Altitude de l'anomalie d'altitude (m)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upper_level_pressure: Option<f64>,   // Pression de l'anomalie d'altitude (hPa)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub surface: Option<SurfaceType>,        // Nature de la surface
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drag_law: Option<DragLaw>,           // Loi de traînée du frottement de surface
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub boundary_layer_depth: Option<f64>,   // Hauteur initiale de la couche limite évolutive (m)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tilt: Option<f64>,                   // Décalage vers l'ouest de l'anomalie d'altitude (m)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geometry: Option<Geometry>,          // Plan f, plan β ou sphère
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shape: Option<AnomalyShape>,         // Profil horizontal des anomalies
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rossby_wave: Option<RossbyWaveTrain>,  // Train d'ondes d'altitude
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upper_trough: Option<UpperTrough>,   // Talweg d'altitude en approche
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pv_streamer: Option<PvStreamer>,     // Filament de tourbillon potentiel descendant
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheme: Option<IntegrationScheme>,   // Schéma d'intégration temporelle
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_step: Option<f64>,              // Pas de temps de l'intégration (s)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub step_policy: Option<StepPolicy>,     // Conduite face à un pas instable
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validation: Option<ValidationPolicy>,  // Conduite face à une valeur hors plage
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limits: Option<ValidationLimits>,    // Plages de validation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nudging: Option<Nudging>,            // Rappel vers des observations
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,                // Nom du scénario, étiquette de ses métriques
+}
+
+impl Default for Scenario {
+    fn default() -> Self {
+        Self {
+            surface_temp: 5.0,
+            altitude_temp: -8.0,
+            latitude: 45.0,
+            time_steps: 24,
+            start_time: None,
+            longitude: None,
+            horizontal_scale: None,
+            season: None,
+            climatology: None,
+            surface: None,
+            drag_law: None,
+            boundary_layer_depth: None,
+            meridional_gradient: None,
+            tropopause_height: None,
+            upper_level_altitude: None,
+            upper_level_pressure: None,
+            tilt: None,
+            geometry: None,
+            shape: None,
+            rossby_wave: None,
+            upper_trough: None,
+            pv_streamer: None,
+            scheme: None,
+            time_step: None,
+            step_policy: None,
+            validation: None,
+            limits: None,
+            nudging: None,
+            name: None,
+        }
+    }
+}
+
+impl Scenario {
+    /// Lit un scénario depuis un fichier TOML
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, MeteoError> {
+        let path = path.as_ref();
+        log_event!(info, path = %path.display(), "lecture du scénario");
+        let text = fs::read_to_string(path)
+            .map_err(|e| MeteoError::InvalidScenario(format!("{}: {}", path.display(), e)))?;
+        Self::from_toml(&text)
+    }
+
+    /// Lit un scénario depuis un texte TOML
+    pub fn from_toml(text: &str) -> Result<Self, MeteoError> {
+        toml::from_str(text).map_err(|e| MeteoError::InvalidScenario(e.message().to_string()))
+    }
+
+    /// Scénario préréglé d'une tempête historique (`great-storm-1987`, `lothar-1999`...)
+    pub fn preset(name: &str) -> Result<Self, MeteoError> {
+        crate::scenarios::find(name).map(|preset| preset.scenario())
+    }
+
+    /// Altitude (m) et pression (hPa) de l'anomalie d'altitude
+    pub fn upper_level(&self) -> (f64, f64) {
+        (self.upper_level_altitude.unwrap_or(UPPER_LEVEL.0), self.upper_level_pressure.unwrap_or(UPPER_LEVEL.1))
+    }
+
+    /// Construit la simulation correspondante
+    pub fn to_cyclogenesis(&self) -> Result<BaroclinicCyclogenesis, MeteoError> {
+        self.to_cyclogenesis_as()
+    }
+
+    /// Construit la simulation correspondante dans la précision `T`
+    pub fn to_cyclogenesis_as<T: Float>(&self) -> Result<BaroclinicCyclogenesis<T>, MeteoError> {
+        let (policy, limits) = (self.validation.unwrap_or_default(), self.limits.unwrap_or_default());
+        let (surface_temp, altitude_temp) = (T::from_f64(self.surface_temp), T::from_f64(self.altitude_temp));
+        let mut cyclogenesis =
+            BaroclinicCyclogenesis::with_upper_level(surface_temp, altitude_temp, self.latitude, self.upper_level(), policy, &limits)?;
+        if let Some(start_time) = self.start_time {
+            cyclogenesis = cyclogenesis.with_start_time(start_time);
+        }
+        if let Some(radius) = self.horizontal_scale {
+            cyclogenesis = cyclogenesis.with_horizontal_scale(radius)?;
+        }
+        if let Some(shape) = self.shape {
+            cyclogenesis = cyclogenesis.with_anomaly_shape(shape)?;
+        }
+        if let Some(geometry) = self.geometry {
+            cyclogenesis = cyclogenesis.with_geometry(geometry);
+        }
+        if let Some(season) = self.season {
+            cyclogenesis = cyclogenesis.with_season(season)?;
+        }
+        if let Some(climatology) = &self.climatology {
+            cyclogenesis = cyclogenesis.with_climatology(climatology)?;
+        }
+        if let Some(gradient) = self.meridional_gradient {
+            cyclogenesis = cyclogenesis.with_meridional_gradient(gradient)?;
+        }
+        if let Some(height) = self.tropopause_height {
+            cyclogenesis = cyclogenesis.with_tropopause_height(height)?;
+        }
+        if let Some(surface) = self.surface {
+            cyclogenesis = cyclogenesis.with_surface_type(surface)?;
+        }
+        if let Some(law) = self.drag_law {
+            cyclogenesis = cyclogenesis.with_drag_law(law)?;
+        }
+        if let Some(depth) = self.boundary_layer_depth {
+            cyclogenesis = cyclogenesis.with_boundary_layer(depth)?;
+        }
+        if let Some(offset) = self.tilt {
+            cyclogenesis = cyclogenesis.with_tilt(offset)?;
+        }
+        if let Some(wave) = self.rossby_wave {
+            wave.validate()?;
+            cyclogenesis = cyclogenesis.with_rossby_wave(wave);
+        }
+        if let Some(trough) = self.upper_trough {
+            trough.validate()?;
+            cyclogenesis = cyclogenesis.with_upper_trough(trough);
+        }
+        if let Some(streamer) = self.pv_streamer {
+            streamer.validate()?;
+            cyclogenesis = cyclogenesis.with_pv_streamer(streamer);
+        }
+        if let Some(scheme) = self.scheme {
+            cyclogenesis = cyclogenesis.with_scheme(scheme);
+        }
+        if let Some(time_step) = self.time_step {
+            cyclogenesis = cyclogenesis.with_time_step(time_step)?;
+        }
+        if let Some(policy) = self.step_policy {
+            cyclogenesis = cyclogenesis.with_step_policy(policy);
+        }
+        if let Some(nudging) = &self.nudging {
+            nudging.validate()?;
+            cyclogenesis = cyclogenesis.with_nudging(nudging.clone());
+        }
+        if let Some(longitude) = self.longitude {
+            cyclogenesis = cyclogenesis.with_diurnal_cycle(longitude)?;
+        }
+        Ok(cyclogenesis)
+    }
+
+    /// Lance la simulation sur toute sa durée, après la politique de pas
+    pub fn run(&self) -> Result<Vec<DevelopmentResult>, MeteoError> {
+        let mut cyclogenesis = self.to_cyclogenesis()?;
+        cyclogenesis.apply_step_policy(self.time_steps);
+        cyclogenesis.simulate_interaction(self.time_steps)
+    }
+}
+
+/// Accepte une date TOML native comme une chaîne RFC 3339
+fn deserialize_start_time<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum DateInput {
+        Toml(toml::value::Datetime),
+        Text(String),
+    }
+
+    let text = match DateInput::deserialize(deserializer)? {
+        DateInput::Toml(datetime) => datetime.to_string(),
+        DateInput::Text(text) => text,
+    };
+    DateTime::parse_from_rfc3339(&text)
+        .map(|datetime| Some(datetime.with_timezone(&Utc)))
+        .map_err(|e| serde::de::Error::custom(format!("date de départ `{}` : {} (format attendu : 1999-12-26T00:00:00Z)", text, e)))
+}
@@ -0,0 +1,143 @@
+//! Scénarios préréglés de tempêtes extratropicales historiques
+//!
+//! Chaque préréglage place les anomalies à la date, à la latitude et à la
+//! longitude de la phase de creusement de la tempête, avec une zone barocline
+//! plus intense que la référence. Le modèle restant idéalisé, les amplitudes
+//! sont des ordres de grandeur choisis pour reproduire un creusement rapide, et
+//! non une reconstitution des analyses.
+
+use chrono::{DateTime, TimeZone, Utc};
+
+use crate::{Geometry, MeteoError, NumberFormat, OutputLanguage, Scenario};
+
+/// Tempête historique préréglée
+#[derive(Debug, Clone, Copy)]
+pub struct Preset {
+    pub name: &'static str,     // Identifiant accepté par l'API et la ligne de commande
+    pub title: &'static str,    // Nom usuel de la tempête
+    pub summary: &'static str,  // Contexte et source
+    surface_temp: f64,          // Anomalie thermique de surface (K)
+    altitude_temp: f64,         // Anomalie thermique d'altitude (K)
+    latitude: f64,              // Latitude de départ (°)
+    longitude: f64,             // Longitude de départ (°)
+    start: (i32, u32, u32, u32),  // Date de départ (année, mois, jour, heure UTC)
+    meridional_gradient: f64,   // Gradient méridien de fond (K/1000 km)
+    horizontal_scale: f64,      // Rayon des anomalies (m)
+    time_steps: u32,            // Durée simulée (h)
+}
+
+/// Préréglages disponibles
+pub const PRESETS: &[Preset] = &[
+    Preset {
+        name: "great-storm-1987",
+        title: "Great Storm (1987)",
+        summary: "Creusement explosif sur le golfe de Gascogne dans la nuit du 15 au 16 octobre 1987, \
+                  avant la traversée de la Manche (Burt et Mansfield, 1988)",
+        surface_temp: 8.0,
+        altitude_temp: -10.0,
+        latitude: 46.0,
+        longitude: -8.0,
+        start: (1987, 10, 15, 12),
+        meridional_gradient: 20.0,
+        horizontal_scale: 5.0e5,
+        time_steps: 18,
+    },
+    Preset {
+        name: "lothar-1999",
+        title: "Lothar (1999)",
+        summary: "Petite dépression portée par un courant-jet exceptionnel, traversant le nord de la France \
+                  le 26 décembre 1999 (Wernli et al., 2002)",
+        surface_temp: 6.0,
+        altitude_temp: -12.0,
+        latitude: 47.0,
+        longitude: -15.0,
+        start: (1999, 12, 25, 18),
+        meridional_gradient: 25.0,
+        horizontal_scale: 4.5e5,
+        time_steps: 18,
+    },
+    Preset {
+        name: "klaus-2009",
+        title: "Klaus (2009)",
+        summary: "Cyclogenèse rapide sur l'Atlantique subtropical, touchant le sud-ouest de la France \
+                  le 24 janvier 2009 (Liberato et al., 2011)",
+        surface_temp: 7.0,
+        altitude_temp: -10.0,
+        latitude: 44.0,
+        longitude: -15.0,
+        start: (2009, 1, 23, 6),
+        meridional_gradient: 20.0,
+        horizontal_scale: 5.0e5,
+        time_steps: 24,
+    },
+    Preset {
+        name: "qe2-1978",
+        title: "Queen Elizabeth II (1978)",
+        summary: "Bombe météorologique au large de la côte nord-est des États-Unis les 10 et 11 septembre 1978, \
+                  cas d'étude du creusement explosif (Gyakum, 1983)",
+        surface_temp: 10.0,
+        altitude_temp: -12.0,
+        latitude: 40.0,
+        longitude: -68.0,
+        start: (1978, 9, 10, 0),
+        meridional_gradient: 25.0,
+        horizontal_scale: 5.0e5,
+        time_steps: 24,
+    },
+];
+
+impl Preset {
+    /// Date de départ du préréglage (UTC)
+    pub fn start_time(&self) -> DateTime<Utc> {
+        let (year, month, day, hour) = self.start;
+        Utc.with_ymd_and_hms(year, month, day, hour, 0, 0).single().expect("date de préréglage valide")
+    }
+
+    /// Scénario du préréglage, en géométrie sphérique pour suivre la trajectoire
+    pub fn scenario(&self) -> Scenario {
+        Scenario {
+            surface_temp: self.surface_temp,
+            altitude_temp: self.altitude_temp,
+            latitude: self.latitude,
+            time_steps: self.time_steps,
+            start_time: Some(self.start_time()),
+            longitude: Some(self.longitude),
+            horizontal_scale: Some(self.horizontal_scale),
+            meridional_gradient: Some(self.meridional_gradient),
+            geometry: Some(Geometry::Spherical),
+            ..Scenario::default()
+        }
+    }
+}
+
+/// Préréglage d'un nom donné, sans tenir compte de la casse
+pub fn find(name: &str) -> Result<&'static Preset, MeteoError> {
+    PRESETS.iter().find(|preset| preset.name.eq_ignore_ascii_case(name.trim())).ok_or_else(|| {
+        let names: Vec<_> = PRESETS.iter().map(|preset| preset.name).collect();
+        MeteoError::InvalidScenario(format!("préréglage inconnu: {} (disponibles : {})", name, names.join(", ")))
+    })
+}
+
+/// Liste des préréglages avec leur date et leur position de départ
+pub fn to_table(language: OutputLanguage, format: &NumberFormat) -> String {
+    let header = match language {
+        OutputLanguage::French => "Préréglage           | Départ (UTC)         | Latitude (°)         | Longitude (°)        | Tempête",
+        OutputLanguage::English => "Preset               | Start (UTC)          | Latitude (°)         | Longitude (°)        | Storm",
+    };
+
+    let mut table = format!(
+        "{}\n---------------------|----------------------|----------------------|----------------------|---------\n",
+        header
+    );
+    for preset in PRESETS {
+        table += &format!(
+            "{:20} | {:>20} | {} | {} | {}\n",
+            preset.name,
+            preset.start_time().format("%Y-%m-%d %H:%M").to_string(),
+            format.format_width(preset.latitude, 20),
+            format.format_width(preset.longitude, 20),
+            preset.title
+        );
+    }
+    table
+}
@@ -0,0 +1,109 @@
+//! État de fond saisonnier
+//!
+//! Le développement barocline dépend fortement de la saison : en hiver, l'air
+//! plus froid, le gradient méridien de température plus fort et la tropopause
+//! plus basse favorisent des développements rapides ; en été, la zone barocline
+//! s'affaiblit et remonte vers le pôle. Chaque saison fixe la température de
+//! référence, le gradient méridien et la hauteur de la tropopause ; l'équinoxe
+//! reprend l'état de fond de référence.
+
+use std::ops::RangeInclusive;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{BaroclinicCyclogenesis, Float, MeteoError, REFERENCE_MERIDIONAL_GRADIENT, TROPOSPHERE_DEPTH};
+
+/// Températures de référence acceptées (K)
+const BASE_TEMP_RANGE: RangeInclusive<f64> = 150.0..=350.0;
+
+/// Hauteurs de tropopause acceptées (m)
+const TROPOPAUSE_RANGE: RangeInclusive<f64> = 5.0e3..=2.0e4;
+
+/// Saison de l'état de fond
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Season {
+    Winter,
+    Summer,
+    Equinox,
+}
+
+impl Season {
+    /// État de fond des latitudes moyennes pour la saison
+    pub fn background(self) -> BackgroundState {
+        match self {
+            Season::Winter => BackgroundState { base_temp: 278.15, meridional_gradient: 16.0, tropopause_height: 9.0e3 },
+            Season::Summer => BackgroundState { base_temp: 298.15, meridional_gradient: 5.0, tropopause_height: 1.2e4 },
+            Season::Equinox => BackgroundState::default(),
+        }
+    }
+}
+
+/// Atmosphère au repos dans laquelle se développent les anomalies
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BackgroundState {
+    pub base_temp: f64,            // Température de référence (K)
+    pub meridional_gradient: f64,  // Gradient méridien de température (K/1000 km)
+    pub tropopause_height: f64,    // Hauteur de la tropopause (m)
+}
+
+impl Default for BackgroundState {
+    /// État de fond de référence : 288,15 K, 10 K/1000 km, tropopause à 10 km
+    fn default() -> Self {
+        Self {
+            base_temp: 288.15,
+            meridional_gradient: REFERENCE_MERIDIONAL_GRADIENT,
+            tropopause_height: TROPOSPHERE_DEPTH,
+        }
+    }
+}
+
+impl BackgroundState {
+    /// Vérifie la température de référence, le gradient méridien et la hauteur de la tropopause
+    pub fn validate(&self) -> Result<(), MeteoError> {
+        if !BASE_TEMP_RANGE.contains(&self.base_temp) {
+            return Err(MeteoError::InvalidScenario(format!(
+                "température de référence {} K hors de [{}, {}]",
+                self.base_temp,
+                BASE_TEMP_RANGE.start(),
+                BASE_TEMP_RANGE.end()
+            )));
+        }
+        if !TROPOPAUSE_RANGE.contains(&self.tropopause_height) {
+            return Err(MeteoError::InvalidScenario(format!(
+                "hauteur de tropopause {} m hors de [{}, {}]",
+                self.tropopause_height,
+                TROPOPAUSE_RANGE.start(),
+                TROPOPAUSE_RANGE.end()
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl<T: Float> BaroclinicCyclogenesis<T> {
+    /// Place les anomalies dans un état de fond
+    pub fn with_background(mut self, background: BackgroundState) -> Result<Self, MeteoError> {
+        background.validate()?;
+        self = self.with_meridional_gradient(background.meridional_gradient)?;
+        for anomaly in &mut self.anomalies {
+            anomaly.constants.base_temp = background.base_temp;
+        }
+        self.tropopause_height = background.tropopause_height;
+        Ok(self)
+    }
+
+    /// Place les anomalies dans l'état de fond d'une saison
+    pub fn with_season(self, season: Season) -> Result<Self, MeteoError> {
+        self.with_background(season.background())
+    }
+
+    /// État de fond de la simulation
+    pub fn background(&self) -> BackgroundState {
+        BackgroundState {
+            base_temp: self.surface_anomaly().constants.base_temp,
+            meridional_gradient: self.meridional_gradient,
+            tropopause_height: self.tropopause_height,
+        }
+    }
+}
@@ -0,0 +1,169 @@
+//! Coupe verticale ouest–est passant par le centre de la dépression
+//!
+//! Les niveaux de la coupe sont ceux des anomalies, de la surface vers le
+//! sommet. À chaque niveau, l'anomalie thermique, la vitesse verticale et le
+//! tourbillon relatif suivent le profil horizontal de l'anomalie, centré sur le
+//! centre de surface ou décalé vers l'ouest de l'inclinaison pour les anomalies
+//! d'altitude. Une ellipse est coupée le long de son grand axe.
+//!
+//! L'anomalie thermique croît avec l'intensité de l'anomalie ; la vitesse
+//! verticale suit le premier mode barocline w·sin(πz/H), nulle au sol ; le
+//! tourbillon est celui du système, de signe donné par le sens de l'anomalie.
+
+use std::f64::consts::PI;
+
+use crate::{BaroclinicCyclogenesis, DevelopmentResult, MeteoError, NumberFormat, OutputLanguage};
+
+/// Plus grande demi-longueur de la coupe (km)
+const MAX_HALF_LENGTH: f64 = 5000.0;
+
+/// Points de la coupe, régulièrement espacés de part et d'autre du centre
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SectionLine {
+    half_length: f64,  // Demi-longueur (km)
+    spacing: f64,      // Pas entre deux points (km)
+}
+
+impl Default for SectionLine {
+    fn default() -> Self {
+        Self { half_length: 2000.0, spacing: 50.0 }
+    }
+}
+
+impl SectionLine {
+    /// Crée une coupe de demi-longueur `half_length` et de pas `spacing` (km)
+    pub fn new(half_length: f64, spacing: f64) -> Result<Self, MeteoError> {
+        if !(half_length > 0.0 && half_length <= MAX_HALF_LENGTH) {
+            return Err(MeteoError::InvalidGrid(format!(
+                "demi-longueur {} km hors de ]0, {}]",
+                half_length, MAX_HALF_LENGTH
+            )));
+        }
+        if !(spacing > 0.0 && spacing <= half_length) {
+            return Err(MeteoError::InvalidGrid(format!("pas {} km hors de ]0, {}]", spacing, half_length)));
+        }
+        Ok(Self { half_length, spacing })
+    }
+
+    /// Distances au centre des points, d'ouest en est (km)
+    pub fn distances(&self) -> Vec<f64> {
+        let side = (self.half_length / self.spacing).round() as i64;
+        (-side..=side).map(|index| index as f64 * self.spacing).collect()
+    }
+}
+
+/// Coupe verticale à une heure de la simulation
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrossSection {
+    pub hour: u32,
+    pressures: Vec<f64>,            // Niveaux, de la surface vers le sommet (hPa)
+    distances: Vec<f64>,            // Distances au centre, d'ouest en est (km)
+    temperature_anomaly: Vec<f64>,  // Anomalie thermique (K), niveau par niveau
+    vertical_velocity: Vec<f64>,    // Vitesse verticale (m/s), niveau par niveau
+    relative_vorticity: Vec<f64>,   // Tourbillon relatif (s⁻¹), niveau par niveau
+}
+
+impl CrossSection {
+    /// Niveaux de la coupe, de la surface vers le sommet (hPa)
+    pub fn pressures(&self) -> &[f64] {
+        &self.pressures
+    }
+
+    /// Distances au centre, d'ouest en est (km)
+    pub fn distances(&self) -> &[f64] {
+        &self.distances
+    }
+
+    /// Anomalie thermique (K), rangée par niveau puis par distance
+    pub fn temperature_anomaly(&self) -> &[f64] {
+        &self.temperature_anomaly
+    }
+
+    /// Vitesse verticale (m/s), rangée par niveau puis par distance
+    pub fn vertical_velocity(&self) -> &[f64] {
+        &self.vertical_velocity
+    }
+
+    /// Tourbillon relatif (s⁻¹), rangé par niveau puis par distance
+    pub fn relative_vorticity(&self) -> &[f64] {
+        &self.relative_vorticity
+    }
+
+    /// Valeurs d'un niveau (anomalie thermique, vitesse verticale, tourbillon)
+    pub fn level(&self, index: usize) -> Option<(&[f64], &[f64], &[f64])> {
+        let columns = self.distances.len();
+        let range = index * columns..(index + 1) * columns;
+        Some((
+            self.temperature_anomaly.get(range.clone())?,
+            self.vertical_velocity.get(range.clone())?,
+            self.relative_vorticity.get(range)?,
+        ))
+    }
+}
+
+impl BaroclinicCyclogenesis {
+    /// Coupe verticale à l'heure d'un résultat
+    pub fn cross_section(&self, result: &DevelopmentResult, line: &SectionLine) -> CrossSection {
+        let distances = line.distances();
+        let mut anomalies: Vec<_> = self.anomalies.iter().collect();
+        anomalies.sort_by(|a, b| b.position.pressure.total_cmp(&a.position.pressure));
+        let coriolis = self.coriolis_parameter(self.latitude());
+        let tilt = self.upper_offset(result.hour) / 1000.0;
+
+        let mut section = CrossSection {
+            hour: result.hour,
+            pressures: anomalies.iter().map(|anomaly| anomaly.position.pressure).collect(),
+            distances: distances.clone(),
+            temperature_anomaly: Vec::with_capacity(anomalies.len() * distances.len()),
+            vertical_velocity: Vec::with_capacity(anomalies.len() * distances.len()),
+            relative_vorticity: Vec::with_capacity(anomalies.len() * distances.len()),
+        };
+        for anomaly in anomalies {
+            let center = if anomaly.is_upper_level() { -tilt } else { 0.0 };
+            let radius = anomaly.structure.radius() / 1000.0;
+            let temperature = anomaly.temperature_delta * anomaly.intensity_at(result.hour);
+            // Premier mode barocline, nul au-dessus de la tropopause
+            let altitude = anomaly.position.altitude.min(self.tropopause_height);
+            let vertical_velocity = result.vertical_velocity * (PI * altitude / self.tropopause_height).sin();
+            let sense = if anomaly.is_cyclonic { 1.0 } else { -1.0 };
+            let vorticity = sense * coriolis.signum() * result.relative_vorticity.abs();
+
+            for distance in &distances {
+                let weight = anomaly.structure.shape().profile((distance - center) / radius);
+                section.temperature_anomaly.push(temperature * weight);
+                section.vertical_velocity.push(vertical_velocity * weight);
+                section.relative_vorticity.push(vorticity * weight);
+            }
+        }
+        section
+    }
+
+    /// Coupe verticale à chaque heure d'une simulation
+    pub fn cross_sections(&self, results: &[DevelopmentResult], line: &SectionLine) -> Vec<CrossSection> {
+        results.iter().map(|result| self.cross_section(result, line)).collect()
+    }
+}
+
+/// Tableau des niveaux d'une coupe : valeurs extrêmes de chaque niveau
+pub fn to_table(section: &CrossSection, language: OutputLanguage, format: &NumberFormat) -> String {
+    let header = match language {
+        OutputLanguage::French => "Niveau (hPa) | Anomalie extrême (K) | Vitesse vert. (cm/s) | Tourbillon (10⁻⁵ s⁻¹)",
+        OutputLanguage::English => "Level (hPa)  | Peak anomaly (K)     | Vertical vel. (cm/s) | Vorticity (10⁻⁵ s⁻¹)",
+    };
+    let extreme = |values: &[f64]| values.iter().copied().max_by(|a, b| a.abs().total_cmp(&b.abs())).unwrap_or(0.0);
+
+    let mut table = format!("{}\n-------------|----------------------|----------------------|----------------------\n", header);
+    for (index, pressure) in section.pressures.iter().enumerate() {
+        let Some((temperature, vertical_velocity, vorticity)) = section.level(index) else {
+            continue;
+        };
+        table += &format!(
+            "{} | {} | {} | {}\n",
+            format.format_width(*pressure, 12),
+            format.format_width(extreme(temperature), 20),
+            format.format_width(extreme(vertical_velocity) * 100.0, 20),
+            format.format_width(extreme(vorticity) * 1e5, 20)
+        );
+    }
+    table
+}
@@ -0,0 +1,111 @@
+//! Statistiques d'une série de résultats horaires
+//!
+//! La série garde ses résultats en colonnes ([`ResultColumns`]) et les lit par
+//! des vues sur les lignes, sans reconstruire de résultat. Le pic d'une
+//! simulation est le pas de plus fort tourbillon relatif en valeur absolue, quel
+//! que soit son signe. Le taux de creusement est l'accroissement moyen de ce
+//! tourbillon entre le premier pas et le pic.
+
+use crate::{DevelopmentResult, Float, ResultColumns, ResultRow};
+
+/// Résultats successifs d'une simulation
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResultSeries<T: Float = f64> {
+    columns: ResultColumns<T>,
+}
+
+impl<T: Float> ResultSeries<T> {
+    pub fn new(results: Vec<DevelopmentResult<T>>) -> Self {
+        Self { columns: results.into() }
+    }
+
+    /// Résultats rangés en colonnes
+    pub fn columns(&self) -> &ResultColumns<T> {
+        &self.columns
+    }
+
+    pub fn into_columns(self) -> ResultColumns<T> {
+        self.columns
+    }
+
+    /// Nombre de pas
+    pub fn len(&self) -> usize {
+        self.columns.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.columns.is_empty()
+    }
+
+    /// Vue sur le pas d'une position, `None` au-delà de la fin
+    pub fn row(&self, index: usize) -> Option<ResultRow<'_, T>> {
+        self.columns.row(index)
+    }
+
+    /// Vues sur les pas, dans l'ordre des heures
+    pub fn rows(&self) -> impl Iterator<Item = ResultRow<'_, T>> + '_ {
+        self.columns.rows()
+    }
+
+    /// Résultats reconstruits, dans l'ordre des heures
+    pub fn to_results(&self) -> Vec<DevelopmentResult<T>> {
+        self.columns.iter().collect()
+    }
+
+    pub fn into_results(self) -> Vec<DevelopmentResult<T>> {
+        self.columns.into()
+    }
+
+    /// Pas de plus fort tourbillon relatif en valeur absolue
+    pub fn peak(&self) -> Option<ResultRow<'_, T>> {
+        let vorticity = self.columns.relative_vorticity();
+        let index = (0..vorticity.len()).reduce(|peak, index| {
+            if vorticity[index].to_f64().abs() > vorticity[peak].to_f64().abs() {
+                index
+            } else {
+                peak
+            }
+        })?;
+        self.row(index)
+    }
+
+    /// Tourbillon relatif au pic (s⁻¹), avec son signe
+    pub fn max_vorticity(&self) -> Option<T> {
+        self.peak().map(|peak| peak.relative_vorticity())
+    }
+
+    /// Heure du pic
+    pub fn time_of_peak(&self) -> Option<u32> {
+        self.peak().map(|peak| peak.hour())
+    }
+
+    /// Vitesse verticale moyenne (m/s)
+    pub fn mean_w(&self) -> Option<T> {
+        let (first, rest) = self.columns.vertical_velocity().split_first()?;
+        let sum = rest.iter().fold(*first, |sum, &w| sum + w);
+        Some(sum / T::from_f64(self.len() as f64))
+    }
+
+    /// Accroissement moyen du tourbillon en valeur absolue entre le premier pas et
+    /// le pic (s⁻²), `None` si le pic est au premier pas
+    pub fn deepening_rate(&self) -> Option<T> {
+        let (first, peak) = (self.row(0)?, self.peak()?);
+        if peak.hour() <= first.hour() {
+            return None;
+        }
+        let elapsed = T::from_f64(f64::from(peak.hour() - first.hour()) * 3600.0);
+        Some((peak.relative_vorticity().abs() - first.relative_vorticity().abs()) / elapsed)
+    }
+}
+
+impl<T: Float> From<Vec<DevelopmentResult<T>>> for ResultSeries<T> {
+    fn from(results: Vec<DevelopmentResult<T>>) -> Self {
+        Self::new(results)
+    }
+}
+
+impl<T: Float> From<ResultColumns<T>> for ResultSeries<T> {
+    fn from(columns: ResultColumns<T>) -> Self {
+        Self { columns }
+    }
+}
@@ -0,0 +1,436 @@
+//! Serveur HTTP diffusant les simulations par WebSocket
+//!
+//! Un navigateur ouvre une WebSocket sur `/simulate` (RFC 6455) et y envoie un
+//! scénario JSON par message texte. Le serveur répond par un message par heure
+//! dès son calcul, puis par un message de fin, de quoi animer le creusement en
+//! direct :
+//!
+//! ```text
+//! {"result":{"hour":0,"vertical_velocity":...}}
+//! ...
+//! {"done":72}
+//! ```
+//!
+//! Un scénario invalide, ou de plus de [`MAX_TIME_STEPS`] heures, donne
+//! `{"error":"..."}` et la connexion reste ouverte. `/metrics` expose les
+//! compteurs du serveur au format de Prometheus. Le serveur n'utilise que la
+//! bibliothèque standard : chaque connexion est servie dans son propre fil, au
+//! plus [`MAX_CONNECTIONS`] à la fois, et les messages fragmentés ne sont pas
+//! acceptés. Une connexion muette pendant [`READ_TIMEOUT`], ou dont le client ne
+//! lit plus les réponses pendant [`WRITE_TIMEOUT`], est fermée et libère sa
+//! place ; un navigateur garde une WebSocket inactive ouverte par des pings.
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::metrics::{self, Metrics};
+use crate::{DevelopmentResult, MeteoError, OutputLanguage, Scenario};
+
+/// Identifiant ajouté à la clé du client pour calculer l'acceptation (RFC 6455)
+const WEBSOCKET_GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+
+/// Taille maximale de l'en-tête d'une requête HTTP (octets)
+const MAX_HEADER_SIZE: usize = 8 << 10;
+
+/// Taille maximale d'un message reçu d'un client (octets)
+pub const MAX_MESSAGE_SIZE: usize = 1 << 20;
+
+/// Nombre maximal d'heures simulées pour un scénario reçu d'un client
+pub const MAX_TIME_STEPS: u32 = 8760;
+
+/// Nombre maximal de connexions servies à la fois ; les suivantes reçoivent 503
+pub const MAX_CONNECTIONS: usize = 64;
+
+/// Délai d'attente d'une requête ou d'un message d'un client, au-delà duquel la connexion est fermée
+pub const READ_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Délai d'écriture d'une réponse à un client, au-delà duquel la connexion est fermée
+pub const WRITE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Délai de lecture de l'en-tête d'une connexion refusée
+const REFUSAL_TIMEOUT: Duration = Duration::from_millis(200);
+
+const TEXT: u8 = 0x1;
+const CLOSE: u8 = 0x8;
+const PING: u8 = 0x9;
+const PONG: u8 = 0xa;
+
+/// Message envoyé au navigateur
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Frame {
+    Result(DevelopmentResult),
+    Done(u32),
+    Error(String),
+}
+
+/// Bornes des connexions servies
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ServerLimits {
+    pub max_connections: usize,
+    pub read_timeout: Duration,
+    pub write_timeout: Duration,
+}
+
+impl Default for ServerLimits {
+    fn default() -> Self {
+        Self { max_connections: MAX_CONNECTIONS, read_timeout: READ_TIMEOUT, write_timeout: WRITE_TIMEOUT }
+    }
+}
+
+/// Sert les clients du serveur, sans fin
+pub fn serve(listener: TcpListener, language: OutputLanguage, metrics: Arc<Metrics>) -> ! {
+    serve_bounded(listener, language, metrics, ServerLimits::default())
+}
+
+/// Sert les clients du serveur dans les bornes de `limits`, sans fin
+pub fn serve_bounded(listener: TcpListener, language: OutputLanguage, metrics: Arc<Metrics>, limits: ServerLimits) -> ! {
+    accept(listener, limits, move |stream| {
+        let _ = handle(stream, language, &metrics);
+    })
+}
+
+/// Sert seulement `/metrics`, pour les modes sans serveur HTTP, sans fin
+pub fn serve_metrics(listener: TcpListener, metrics: Arc<Metrics>) -> ! {
+    accept(listener, ServerLimits::default(), move |stream| {
+        let _ = handle_metrics(stream, &metrics);
+    })
+}
+
+/// Confie chaque connexion acceptée à son propre fil, avec les délais de
+/// `limits`, tant que moins de `limits.max_connections` sont servies ; les
+/// connexions en surnombre sont refusées dans un fil à part, sans retenir la
+/// boucle d'acceptation
+fn accept<F>(listener: TcpListener, limits: ServerLimits, serve: F) -> !
+where
+    F: Fn(TcpStream) + Send + Sync + 'static,
+{
+    let serve = Arc::new(serve);
+    let active = Arc::new(AtomicUsize::new(0));
+    loop {
+        // Une connexion refusée par le système ne concerne que son client
+        let Ok((stream, _)) = listener.accept() else {
+            continue;
+        };
+        if active.fetch_add(1, Ordering::SeqCst) >= limits.max_connections {
+            active.fetch_sub(1, Ordering::SeqCst);
+            thread::spawn(move || {
+                let _ = refuse(stream, limits.write_timeout);
+            });
+            continue;
+        }
+        // Sans délai, une connexion muette garderait sa place indéfiniment
+        let timeouts = stream
+            .set_read_timeout(Some(limits.read_timeout))
+            .and_then(|_| stream.set_write_timeout(Some(limits.write_timeout)));
+        if timeouts.is_err() {
+            active.fetch_sub(1, Ordering::SeqCst);
+            continue;
+        }
+        let (serve, slot) = (Arc::clone(&serve), Slot(Arc::clone(&active)));
+        thread::spawn(move || {
+            let _slot = slot;
+            serve(stream);
+        });
+    }
+}
+
+/// Connexion comptée parmi celles servies, décomptée à la fin de son fil
+struct Slot(Arc<AtomicUsize>);
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Répond 503 à une connexion en surnombre
+///
+/// L'en-tête est lu, avec un délai court, pour que la fermeture ne coupe pas la
+/// réponse avant que le client ne la lise.
+fn refuse(mut stream: TcpStream, write_timeout: Duration) -> Result<(), MeteoError> {
+    stream
+        .set_read_timeout(Some(REFUSAL_TIMEOUT))
+        .and_then(|_| stream.set_write_timeout(Some(write_timeout)))
+        .map_err(socket_error)?;
+    let _ = read_request(&mut BufReader::new(&stream));
+    respond(&mut stream, "503 Service Unavailable", "text/plain", "Trop de connexions\n")
+}
+
+/// Requête HTTP réduite à ce dont le serveur a besoin
+struct Request {
+    method: String,
+    path: String,
+    websocket_key: Option<String>,
+}
+
+/// Lit l'en-tête d'une requête HTTP
+fn read_request<R: BufRead>(source: &mut R) -> Result<Request, MeteoError> {
+    let mut lines = Vec::new();
+    let mut size = 0;
+    loop {
+        let mut line = String::new();
+        let read = source.read_line(&mut line).map_err(socket_error)?;
+        size += read;
+        if read == 0 || size > MAX_HEADER_SIZE {
+            return Err(protocol("en-tête HTTP incomplet ou trop long"));
+        }
+        let line = line.trim_end().to_string();
+        if line.is_empty() {
+            break;
+        }
+        lines.push(line);
+    }
+
+    let mut request_line = lines.first().map(|line| line.split_whitespace()).ok_or_else(|| protocol("requête vide"))?;
+    let method = request_line.next().unwrap_or_default().to_string();
+    let path = request_line.next().unwrap_or_default().to_string();
+    let header = |name: &str| {
+        lines[1..].iter().find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            key.trim().eq_ignore_ascii_case(name).then(|| value.trim().to_string())
+        })
+    };
+    let upgrade = header("upgrade").is_some_and(|value| value.eq_ignore_ascii_case("websocket"));
+    let websocket_key = if upgrade { header("sec-websocket-key") } else { None };
+    Ok(Request { method, path, websocket_key })
+}
+
+/// Répond à une connexion jusqu'à sa fermeture
+fn handle(stream: TcpStream, language: OutputLanguage, metrics: &Metrics) -> Result<(), MeteoError> {
+    let mut reader = BufReader::new(stream.try_clone().map_err(socket_error)?);
+    let mut writer = stream;
+    let request = read_request(&mut reader)?;
+    match (request.method.as_str(), request.path.as_str(), request.websocket_key) {
+        ("GET", "/simulate", Some(key)) => {
+            let response = format!(
+                "HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\nConnection: Upgrade\r\nSec-WebSocket-Accept: {}\r\n\r\n",
+                accept_key(&key)
+            );
+            writer.write_all(response.as_bytes()).map_err(socket_error)?;
+            stream_simulations(&mut reader, &mut writer, language, metrics)
+        }
+        ("GET", "/simulate", None) => respond(&mut writer, "426 Upgrade Required", "text/plain", "WebSocket attendue\n"),
+        ("GET", "/metrics", _) => respond(&mut writer, "200 OK", metrics::CONTENT_TYPE, &metrics.render()),
+        _ => respond(&mut writer, "404 Not Found", "text/plain", "Introuvable\n"),
+    }
+}
+
+/// Répond à une requête de `/metrics`
+fn handle_metrics(stream: TcpStream, metrics: &Metrics) -> Result<(), MeteoError> {
+    let mut reader = BufReader::new(stream.try_clone().map_err(socket_error)?);
+    let mut writer = stream;
+    let request = read_request(&mut reader)?;
+    match (request.method.as_str(), request.path.as_str()) {
+        ("GET", "/metrics") => respond(&mut writer, "200 OK", metrics::CONTENT_TYPE, &metrics.render()),
+        _ => respond(&mut writer, "404 Not Found", "text/plain", "Introuvable\n"),
+    }
+}
+
+/// Écrit une réponse HTTP complète et ferme la connexion
+fn respond<W: Write>(writer: &mut W, status: &str, content_type: &str, body: &str) -> Result<(), MeteoError> {
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        content_type,
+        body.len(),
+        body
+    );
+    writer.write_all(response.as_bytes()).map_err(socket_error)
+}
+
+/// Simule chaque scénario reçu en diffusant ses résultats heure par heure
+fn stream_simulations<R: Read, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    language: OutputLanguage,
+    metrics: &Metrics,
+) -> Result<(), MeteoError> {
+    loop {
+        let (opcode, payload) = read_frame(reader)?;
+        match opcode {
+            TEXT => {
+                let start = Instant::now();
+                match stream_simulation(&payload, writer) {
+                    Ok(scenario) => metrics.record_scenario(scenario.name.as_deref(), scenario.time_steps, start.elapsed()),
+                    Err(error) => {
+                        metrics.record_error();
+                        send(writer, &Frame::Error(error.message(language)))?;
+                    }
+                }
+            }
+            PING => write_frame(writer, PONG, &payload)?,
+            PONG => {}
+            CLOSE => return write_frame(writer, CLOSE, &payload[..payload.len().min(2)]),
+            _ => {
+                // 1003 : type de message non pris en charge
+                return write_frame(writer, CLOSE, &1003u16.to_be_bytes());
+            }
+        }
+    }
+}
+
+/// Simule un scénario JSON en envoyant chaque résultat dès son calcul ;
+/// renvoie le scénario simulé
+fn stream_simulation<W: Write>(payload: &[u8], writer: &mut W) -> Result<Scenario, MeteoError> {
+    let scenario: Scenario = serde_json::from_slice(payload).map_err(|e| MeteoError::InvalidScenario(e.to_string()))?;
+    check_time_steps(scenario.time_steps)?;
+    let mut cyclogenesis = scenario.to_cyclogenesis()?;
+    for hour in cyclogenesis.hours(scenario.time_steps)? {
+        send(writer, &Frame::Result(cyclogenesis.step(hour)?))?;
+    }
+    send(writer, &Frame::Done(scenario.time_steps))?;
+    Ok(scenario)
+}
+
+/// Refuse un scénario de plus de [`MAX_TIME_STEPS`] heures
+pub fn check_time_steps(time_steps: u32) -> Result<(), MeteoError> {
+    if time_steps > MAX_TIME_STEPS {
+        return Err(MeteoError::InvalidScenario(format!("{} heures demandées, au plus {}", time_steps, MAX_TIME_STEPS)));
+    }
+    Ok(())
+}
+
+fn send<W: Write>(writer: &mut W, frame: &Frame) -> Result<(), MeteoError> {
+    write_frame(writer, TEXT, &serde_json::to_vec(frame).expect("message sérialisable"))
+}
+
+/// Lit une trame d'un client, masquée comme l'impose la RFC 6455
+fn read_frame<R: Read>(reader: &mut R) -> Result<(u8, Vec<u8>), MeteoError> {
+    let mut header = [0; 2];
+    reader.read_exact(&mut header).map_err(socket_error)?;
+    if header[0] & 0x80 == 0 {
+        return Err(protocol("messages fragmentés non pris en charge"));
+    }
+    if header[1] & 0x80 == 0 {
+        return Err(protocol("trame du client non masquée"));
+    }
+    let length = match header[1] & 0x7f {
+        126 => {
+            let mut bytes = [0; 2];
+            reader.read_exact(&mut bytes).map_err(socket_error)?;
+            u64::from(u16::from_be_bytes(bytes))
+        }
+        127 => {
+            let mut bytes = [0; 8];
+            reader.read_exact(&mut bytes).map_err(socket_error)?;
+            u64::from_be_bytes(bytes)
+        }
+        length => u64::from(length),
+    };
+    if length > MAX_MESSAGE_SIZE as u64 {
+        return Err(protocol(&format!("message de {} octets (au plus {})", length, MAX_MESSAGE_SIZE)));
+    }
+
+    let mut mask = [0; 4];
+    reader.read_exact(&mut mask).map_err(socket_error)?;
+    let mut payload = vec![0; length as usize];
+    reader.read_exact(&mut payload).map_err(socket_error)?;
+    for (index, byte) in payload.iter_mut().enumerate() {
+        *byte ^= mask[index % 4];
+    }
+    Ok((header[0] & 0x0f, payload))
+}
+
+/// Écrit une trame non fragmentée et non masquée
+fn write_frame<W: Write>(writer: &mut W, opcode: u8, payload: &[u8]) -> Result<(), MeteoError> {
+    let mut frame = Vec::with_capacity(payload.len() + 10);
+    frame.push(0x80 | opcode);
+    match payload.len() {
+        length @ 0..=125 => frame.push(length as u8),
+        length @ 126..=0xffff => {
+            frame.push(126);
+            frame.extend_from_slice(&(length as u16).to_be_bytes());
+        }
+        length => {
+            frame.push(127);
+            frame.extend_from_slice(&(length as u64).to_be_bytes());
+        }
+    }
+    frame.extend_from_slice(payload);
+    writer.write_all(&frame).and_then(|_| writer.flush()).map_err(socket_error)
+}
+
+/// Valeur de `Sec-WebSocket-Accept` pour la clé d'un client
+pub fn accept_key(key: &str) -> String {
+    base64(&sha1(format!("{}{}", key, WEBSOCKET_GUID).as_bytes()))
+}
+
+/// Empreinte SHA-1, utilisée seulement pour la poignée de main WebSocket
+fn sha1(message: &[u8]) -> [u8; 20] {
+    let mut state: [u32; 5] = [0x6745_2301, 0xefcd_ab89, 0x98ba_dcfe, 0x1032_5476, 0xc3d2_e1f0];
+    let mut padded = message.to_vec();
+    padded.push(0x80);
+    while padded.len() % 64 != 56 {
+        padded.push(0);
+    }
+    padded.extend_from_slice(&(message.len() as u64 * 8).to_be_bytes());
+
+    for block in padded.chunks_exact(64) {
+        let mut words = [0u32; 80];
+        for (index, word) in block.chunks_exact(4).enumerate() {
+            words[index] = u32::from_be_bytes(word.try_into().expect("4 octets"));
+        }
+        for index in 16..80 {
+            words[index] = (words[index - 3] ^ words[index - 8] ^ words[index - 14] ^ words[index - 16]).rotate_left(1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e] = state;
+        for (index, word) in words.iter().enumerate() {
+            let (f, k) = match index {
+                0..=19 => ((b & c) | (!b & d), 0x5a82_7999),
+                20..=39 => (b ^ c ^ d, 0x6ed9_eba1),
+                40..=59 => ((b & c) | (b & d) | (c & d), 0x8f1b_bcdc),
+                _ => (b ^ c ^ d, 0xca62_c1d6),
+            };
+            let temp = a.rotate_left(5).wrapping_add(f).wrapping_add(e).wrapping_add(k).wrapping_add(*word);
+            e = d;
+            d = c;
+            c = b.rotate_left(30);
+            b = a;
+            a = temp;
+        }
+        for (value, added) in state.iter_mut().zip([a, b, c, d, e]) {
+            *value = value.wrapping_add(added);
+        }
+    }
+
+    let mut digest = [0; 20];
+    for (chunk, value) in digest.chunks_exact_mut(4).zip(state) {
+        chunk.copy_from_slice(&value.to_be_bytes());
+    }
+    digest
+}
+
+/// Encodage base64 standard, avec remplissage
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut text = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let value = (u32::from(chunk[0]) << 16)
+            | (u32::from(chunk.get(1).copied().unwrap_or(0)) << 8)
+            | u32::from(chunk.get(2).copied().unwrap_or(0));
+        for position in 0..4 {
+            if position <= chunk.len() {
+                text.push(char::from(ALPHABET[(value >> (18 - 6 * position) & 0x3f) as usize]));
+            } else {
+                text.push('=');
+            }
+        }
+    }
+    text
+}
+
+fn protocol(message: &str) -> MeteoError {
+    MeteoError::InvalidRun(format!("serveur: {}", message))
+}
+
+fn socket_error(error: std::io::Error) -> MeteoError {
+    protocol(&error.to_string())
+}
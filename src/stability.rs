@@ -0,0 +1,100 @@
+//! Condition nécessaire d'instabilité barocline de Charney–Stern
+//!
+//! Dans un modèle à deux couches, une perturbation ne peut s'amplifier que si le
+//! gradient méridien de tourbillon potentiel change de signe entre les couches.
+//! Avec un cisaillement vertical ΔU, ces gradients valent β ± ΔU/L_R² : la couche
+//! inférieure change de signe dès que ΔU dépasse β·L_R². Le cisaillement est celui
+//! du vent thermique associé au contraste thermique des anomalies sur leur rayon.
+
+use crate::{BaroclinicCyclogenesis, Float, NumberFormat, OutputLanguage, DEFORMATION_RADIUS};
+
+/// Rayon moyen de la Terre (m)
+pub const EARTH_RADIUS: f64 = 6.371e6;
+
+/// Bilan du critère de Charney–Stern pour un état de base
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CharneySternCheck {
+    pub shear: f64,              // Cisaillement vertical du vent thermique (m/s)
+    pub critical_shear: f64,     // Cisaillement minimal β·L_R² (m/s)
+    pub upper_pv_gradient: f64,  // Gradient de tourbillon potentiel, couche supérieure (m⁻¹ s⁻¹)
+    pub lower_pv_gradient: f64,  // Gradient de tourbillon potentiel, couche inférieure (m⁻¹ s⁻¹)
+}
+
+impl CharneySternCheck {
+    /// Le gradient de tourbillon potentiel change de signe entre les couches
+    pub fn is_satisfied(&self) -> bool {
+        self.upper_pv_gradient * self.lower_pv_gradient < 0.0
+    }
+
+    /// Compte rendu d'une ligne du critère
+    pub fn describe(&self, language: OutputLanguage, format: &NumberFormat) -> String {
+        let speed = |value: f64| format!("{} {}", format.format_wind(value), format.wind_unit().symbol());
+        let (shear, critical) = (speed(self.shear), speed(self.critical_shear));
+        match (language, self.is_satisfied()) {
+            (OutputLanguage::French, true) => format!(
+                "Critère de Charney–Stern satisfait : cisaillement {} > β·L_R² = {}",
+                shear, critical
+            ),
+            (OutputLanguage::French, false) => format!(
+                "Attention : critère de Charney–Stern non satisfait (cisaillement {} ≤ β·L_R² = {}), \
+                 la perturbation ne peut pas s'amplifier physiquement",
+                shear, critical
+            ),
+            (OutputLanguage::English, true) => format!(
+                "Charney–Stern criterion satisfied: shear {} > β·L_R² = {}",
+                shear, critical
+            ),
+            (OutputLanguage::English, false) => format!(
+                "Warning: Charney–Stern criterion not satisfied (shear {} ≤ β·L_R² = {}), \
+                 the perturbation cannot physically amplify",
+                shear, critical
+            ),
+        }
+    }
+}
+
+impl<T: Float> BaroclinicCyclogenesis<T> {
+    /// Cisaillement du vent thermique entre les deux anomalies (m/s)
+    ///
+    /// ΔU = g·H·(∂T/∂y) / (|f|·T₀), avec ∂T/∂y estimé par le contraste thermique
+    /// moyen des anomalies sur leur rayon, rapporté au gradient méridien de fond de
+    /// la zone de référence. Renvoie `None` à l'équateur, où l'équilibre du vent
+    /// thermique n'existe pas.
+    pub fn thermal_wind_shear(&self) -> Option<T> {
+        let surface = self.surface_anomaly();
+        let altitude = self.altitude_anomaly();
+
+        let coriolis = 2.0 * surface.compute_coriolis_force();
+        if coriolis == 0.0 {
+            return None;
+        }
+
+        let depth = (altitude.position.altitude - surface.position.altitude).abs();
+        let contrast = (surface.temperature_delta.abs() + altitude.temperature_delta.abs()) / T::from_f64(2.0);
+        let radius = (surface.structure.radius() + altitude.structure.radius()) / 2.0;
+        let shear = T::from_f64(surface.constants.gravity * depth) * contrast
+            / T::from_f64(coriolis.abs() * surface.constants.base_temp * radius);
+        Some(shear * T::from_f64(self.baroclinicity()))
+    }
+}
+
+impl BaroclinicCyclogenesis {
+
+    /// Évalue le critère de Charney–Stern avant la simulation
+    ///
+    /// Renvoie `None` à l'équateur.
+    pub fn charney_stern(&self) -> Option<CharneySternCheck> {
+        let shear = self.thermal_wind_shear()?;
+        let constants = &self.surface_anomaly().constants;
+        let latitude = self.surface_anomaly().position.latitude.to_radians();
+        let beta = 2.0 * constants.earth_omega * latitude.cos() / EARTH_RADIUS;
+
+        let stretching = shear / (DEFORMATION_RADIUS * DEFORMATION_RADIUS);
+        Some(CharneySternCheck {
+            shear,
+            critical_shear: beta * DEFORMATION_RADIUS * DEFORMATION_RADIUS,
+            upper_pv_gradient: beta + stretching,
+            lower_pv_gradient: beta - stretching,
+        })
+    }
+}
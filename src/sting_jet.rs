@@ -0,0 +1,182 @@
+//! Indicateur heuristique du risque de sting jet
+//!
+//! Le sting jet, courant descendant issu de la tête nuageuse, apparaît dans les
+//! dépressions à creusement explosif de type Shapiro–Keyser. Trois critères sont
+//! évalués à chaque heure :
+//!
+//! - creusement rapide : tendance de la pression centrale, en bergerons
+//!   (1 hPa/h à 60°, rapporté à sin φ / sin 60°) ;
+//! - forte subsidence en moyenne troposphère : amplitude de la vitesse
+//!   verticale, portée par la branche descendante du dipôle incliné ;
+//! - front replié, approché par un nombre de Rossby |ζ/f| élevé : le tourbillon
+//!   domine la zone barocline lorsque le front se fracture et s'enroule.
+//!
+//! Le risque est élevé lorsque les trois critères sont remplis, modéré pour deux.
+
+use crate::{BaroclinicCyclogenesis, DevelopmentResult, MeteoError, NumberFormat, OutputLanguage};
+
+/// Seuils des trois critères
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StingJetCriteria {
+    deepening: f64,  // Creusement minimal (bergerons)
+    descent: f64,    // Vitesse verticale minimale (m/s)
+    rossby: f64,     // Nombre de Rossby minimal
+}
+
+impl Default for StingJetCriteria {
+    fn default() -> Self {
+        Self { deepening: 1.0, descent: 3.0e-3, rossby: 1.0 }
+    }
+}
+
+impl StingJetCriteria {
+    /// Seuils de creusement (bergerons), de subsidence (m/s) et de nombre de Rossby
+    pub fn new(deepening: f64, descent: f64, rossby: f64) -> Result<Self, MeteoError> {
+        if [deepening, descent, rossby].iter().any(|threshold| !(*threshold > 0.0 && threshold.is_finite())) {
+            return Err(MeteoError::InvalidScenario(format!(
+                "seuils de sting jet invalides: {} Bergeron, {} m/s, Rossby {}",
+                deepening, descent, rossby
+            )));
+        }
+        Ok(Self { deepening, descent, rossby })
+    }
+}
+
+/// Niveau de risque de sting jet
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum RiskLevel {
+    #[default]
+    Low,
+    Moderate,
+    Elevated,
+}
+
+impl RiskLevel {
+    /// Libellé du niveau
+    pub fn label(self, language: OutputLanguage) -> &'static str {
+        match (language, self) {
+            (OutputLanguage::French, RiskLevel::Low) => "faible",
+            (OutputLanguage::French, RiskLevel::Moderate) => "modéré",
+            (OutputLanguage::French, RiskLevel::Elevated) => "élevé",
+            (OutputLanguage::English, RiskLevel::Low) => "low",
+            (OutputLanguage::English, RiskLevel::Moderate) => "moderate",
+            (OutputLanguage::English, RiskLevel::Elevated) => "elevated",
+        }
+    }
+}
+
+/// Critères de sting jet à une heure de la simulation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StingJetRisk {
+    pub hour: u32,
+    pub deepening: f64,      // Creusement de la pression centrale (bergerons)
+    pub descent: f64,        // Amplitude de la vitesse verticale (m/s)
+    pub rossby_number: f64,  // Nombre de Rossby |ζ/f|
+    pub level: RiskLevel,
+}
+
+impl BaroclinicCyclogenesis {
+    /// Évalue le risque de sting jet à chaque heure d'une simulation
+    ///
+    /// Le creusement est la baisse de pression centrale depuis l'heure
+    /// précédente ; à la première heure, celle de l'heure suivante.
+    pub fn sting_jet_risk(&self, results: &[DevelopmentResult], criteria: &StingJetCriteria) -> Vec<StingJetRisk> {
+        let pressures: Vec<_> = results.iter().map(|result| self.central_pressure(result)).collect();
+        results
+            .iter()
+            .enumerate()
+            .map(|(index, result)| {
+                let (before, after) = match index {
+                    0 => (0, 1.min(results.len() - 1)),
+                    _ => (index - 1, index),
+                };
+                let hours = f64::from(results[after].hour) - f64::from(results[before].hour);
+                let tendency = if hours == 0.0 { 0.0 } else { (pressures[before] - pressures[after]) / hours };
+
+                // Critères propres aux latitudes moyennes : nuls à l'équateur
+                let latitude = result.latitude.unwrap_or_else(|| self.latitude());
+                let sine = latitude.to_radians().sin().abs();
+                let (deepening, rossby_number) = if sine == 0.0 {
+                    (0.0, 0.0)
+                } else {
+                    let coriolis = self.coriolis_parameter(latitude).abs();
+                    (tendency * 60f64.to_radians().sin() / sine, result.relative_vorticity.abs() / coriolis)
+                };
+                let descent = result.vertical_velocity.abs();
+
+                let met = [deepening >= criteria.deepening, descent >= criteria.descent, rossby_number >= criteria.rossby]
+                    .iter()
+                    .filter(|met| **met)
+                    .count();
+                let level = match met {
+                    3 => RiskLevel::Elevated,
+                    2 => RiskLevel::Moderate,
+                    _ => RiskLevel::Low,
+                };
+                StingJetRisk { hour: result.hour, deepening, descent, rossby_number, level }
+            })
+            .collect()
+    }
+}
+
+/// Périodes consécutives de risque élevé, (première heure, dernière heure)
+pub fn elevated_periods(risks: &[StingJetRisk]) -> Vec<(u32, u32)> {
+    let mut periods: Vec<(u32, u32)> = Vec::new();
+    let mut previous: Option<u32> = None;
+    for risk in risks.iter().filter(|risk| risk.level == RiskLevel::Elevated) {
+        match periods.last_mut() {
+            Some(period) if previous.is_some_and(|hour| risk.hour == hour + 1) => period.1 = risk.hour,
+            _ => periods.push((risk.hour, risk.hour)),
+        }
+        previous = Some(risk.hour);
+    }
+    periods
+}
+
+/// Phrase signalant les périodes de risque élevé
+pub fn describe_periods(risks: &[StingJetRisk], language: OutputLanguage) -> String {
+    let periods = elevated_periods(risks);
+    if periods.is_empty() {
+        return match language {
+            OutputLanguage::French => "Pas de risque élevé de sting jet".to_string(),
+            OutputLanguage::English => "No elevated sting-jet risk".to_string(),
+        };
+    }
+    let spans: Vec<_> = periods
+        .iter()
+        .map(|(start, end)| match (language, start == end) {
+            (OutputLanguage::French, true) => format!("à l'heure {}", start),
+            (OutputLanguage::French, false) => format!("de l'heure {} à l'heure {}", start, end),
+            (OutputLanguage::English, true) => format!("at hour {}", start),
+            (OutputLanguage::English, false) => format!("from hour {} to hour {}", start, end),
+        })
+        .collect();
+    match language {
+        OutputLanguage::French => format!("Risque élevé de sting jet {}", spans.join(", ")),
+        OutputLanguage::English => format!("Elevated sting-jet risk {}", spans.join(", ")),
+    }
+}
+
+/// Tableau des critères et du niveau de risque, suivi des périodes de risque élevé
+pub fn to_table(risks: &[StingJetRisk], language: OutputLanguage, format: &NumberFormat) -> String {
+    let header = match language {
+        OutputLanguage::French => "Heure | Creusement Bergeron  | Subsidence (cm/s)    | Nombre de Rossby     | Risque",
+        OutputLanguage::English => "Hour  | Deepening (Bergeron) | Descent (cm/s)       | Rossby number        | Risk",
+    };
+
+    let mut table = format!(
+        "{}\n------|----------------------|----------------------|----------------------|----------------------\n",
+        header
+    );
+    for risk in risks {
+        table += &format!(
+            "{:4} | {} | {} | {} | {}\n",
+            risk.hour,
+            format.format_width(risk.deepening, 20),
+            format.format_width(risk.descent * 100.0, 20),
+            format.format_width(risk.rossby_number, 20),
+            risk.level.label(language)
+        );
+    }
+    table + &format!("\n{}\n", describe_periods(risks, language))
+}
@@ -0,0 +1,311 @@
+//! Plusieurs tempêtes sur une horloge commune
+//!
+//! Une famille de cyclones compte plusieurs systèmes indépendants, chacun à sa
+//! position, qui évoluent aux mêmes heures. `MultiStorm` les fait avancer
+//! ensemble, heure par heure, et fusionne leurs résultats en les rangeant par
+//! heure puis dans l'ordre des tempêtes, chaque résultat portant l'identifiant
+//! de sa tempête. Les tempêtes partagent la première heure et la date de
+//! départ de l'horloge ; une tempête de durée limitée s'arrête au bout de ses
+//! heures tandis que les autres poursuivent. Indépendantes par défaut, deux
+//! tempêtes voisines peuvent interagir selon un `InteractionModel`.
+//!
+//! Un fichier de famille décrit chaque tempête par un scénario complet, précédé
+//! de son identifiant ; `time_steps` y fixe la durée de chaque tempête :
+//!
+//! ```toml
+//! interaction = { fujiwhara = { separation = 1.2e6 } }  # Interaction en deçà de 1200 km
+//!
+//! [[storms]]
+//! id = "A"
+//! latitude = 45.0
+//! time_steps = 36
+//!
+//! [[storms]]
+//! id = "B"
+//! latitude = 55.0
+//! surface_temp = 3.0
+//! ```
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::fujiwhara::{Vortex, VortexEffect};
+use crate::{
+    BaroclinicCyclogenesis, DevelopmentResult, InteractionModel, MeteoError, NumberFormat, OutputLanguage, ResultSeries,
+    Scenario, TableFormatter,
+};
+
+/// Tempête identifiée d'une simulation à plusieurs systèmes
+pub struct Storm {
+    id: String,
+    cyclogenesis: BaroclinicCyclogenesis,
+    time_steps: Option<u32>,  // Durée sur l'horloge commune (h), illimitée par défaut
+}
+
+impl Storm {
+    /// Tempête `id` simulée par `cyclogenesis`, jusqu'à la fin de l'horloge commune
+    pub fn new(id: impl Into<String>, cyclogenesis: BaroclinicCyclogenesis) -> Self {
+        Self { id: id.into(), cyclogenesis, time_steps: None }
+    }
+
+    /// Limite la tempête à ses `time_steps` premières heures
+    pub fn with_time_steps(mut self, time_steps: u32) -> Self {
+        self.time_steps = Some(time_steps);
+        self
+    }
+
+    /// Identifiant de la tempête
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Simulation de la tempête, dans son état courant
+    pub fn cyclogenesis(&self) -> &BaroclinicCyclogenesis {
+        &self.cyclogenesis
+    }
+
+    fn is_active(&self, hour: u32) -> bool {
+        self.time_steps.is_none_or(|time_steps| hour < self.cyclogenesis.first_hour + time_steps)
+    }
+}
+
+/// Résultat horaire d'une tempête
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StormResult {
+    pub storm: String,  // Identifiant de la tempête
+    #[serde(flatten)]
+    pub result: DevelopmentResult,
+}
+
+/// Tempêtes indépendantes avançant sur une horloge commune
+#[derive(Default)]
+pub struct MultiStorm {
+    storms: Vec<Storm>,
+    hour: u32,  // Prochaine heure de l'horloge
+    interaction: InteractionModel,
+}
+
+impl MultiStorm {
+    /// Simulation sans tempête
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ajoute une tempête, d'identifiant unique, calée sur l'horloge des précédentes
+    pub fn with_storm(mut self, storm: Storm) -> Result<Self, MeteoError> {
+        if storm.id.is_empty() {
+            return Err(MeteoError::InvalidScenario("identifiant de tempête vide".to_string()));
+        }
+        if self.storm(&storm.id).is_some() {
+            return Err(MeteoError::InvalidScenario(format!("tempête {} déjà présente", storm.id)));
+        }
+        match self.storms.first() {
+            Some(first) if first.cyclogenesis.first_hour != storm.cyclogenesis.first_hour => {
+                return Err(MeteoError::InvalidScenario(format!(
+                    "la tempête {} part de l'heure {}, l'horloge de l'heure {}",
+                    storm.id, storm.cyclogenesis.first_hour, first.cyclogenesis.first_hour
+                )));
+            }
+            Some(first) if first.cyclogenesis.start_time() != storm.cyclogenesis.start_time() => {
+                return Err(MeteoError::InvalidScenario(format!(
+                    "la tempête {} n'a pas la date de départ de l'horloge",
+                    storm.id
+                )));
+            }
+            Some(_) => {}
+            None => self.hour = storm.cyclogenesis.first_hour,
+        }
+        self.storms.push(storm);
+        Ok(self)
+    }
+
+    /// Fait interagir les tempêtes voisines, indépendantes par défaut
+    pub fn with_interaction(mut self, interaction: InteractionModel) -> Result<Self, MeteoError> {
+        interaction.validate()?;
+        self.interaction = interaction;
+        Ok(self)
+    }
+
+    /// Interaction entre les tempêtes
+    pub fn interaction(&self) -> InteractionModel {
+        self.interaction
+    }
+
+    /// Tempêtes, dans l'ordre de leur ajout
+    pub fn storms(&self) -> &[Storm] {
+        &self.storms
+    }
+
+    /// Simulation de la tempête `id`
+    pub fn storm(&self, id: &str) -> Option<&BaroclinicCyclogenesis> {
+        self.storms.iter().find(|storm| storm.id == id).map(|storm| &storm.cyclogenesis)
+    }
+
+    /// Prochaine heure de l'horloge commune
+    pub fn hour(&self) -> u32 {
+        self.hour
+    }
+
+    /// Avance toutes les tempêtes actives d'une heure
+    ///
+    /// L'interaction agit après le pas de chaque tempête : les déplacements
+    /// s'ajoutent à ceux de leur trajectoire et le tourbillon échangé compte à
+    /// partir du pas suivant.
+    pub fn step(&mut self) -> Result<Vec<StormResult>, MeteoError> {
+        let hour = self.hour;
+        let mut active = Vec::with_capacity(self.storms.len());
+        let mut results = Vec::with_capacity(self.storms.len());
+        for (index, storm) in self.storms.iter_mut().enumerate().filter(|(_, storm)| storm.is_active(hour)) {
+            results.push(StormResult { storm: storm.id.clone(), result: storm.cyclogenesis.step(hour)? });
+            active.push(index);
+        }
+        self.interact(&active, &results);
+        self.hour += 1;
+        Ok(results)
+    }
+
+    fn interact(&mut self, active: &[usize], results: &[StormResult]) {
+        if self.interaction == InteractionModel::Independent {
+            return;
+        }
+        let vortices: Vec<_> = active
+            .iter()
+            .zip(results)
+            .map(|(index, result)| {
+                let cyclogenesis = &self.storms[*index].cyclogenesis;
+                Vortex {
+                    latitude: cyclogenesis.latitude(),
+                    longitude: cyclogenesis.longitude(),
+                    vorticity: result.result.relative_vorticity,
+                    radius: cyclogenesis.mean_radius(),
+                    shape: cyclogenesis.surface_anomaly().structure.shape(),
+                }
+            })
+            .collect();
+        let mut effects = vec![VortexEffect::default(); vortices.len()];
+        for i in 0..vortices.len() {
+            for j in i + 1..vortices.len() {
+                if let Some((a, b)) = self.interaction.pair_effects(&vortices[i], &vortices[j]) {
+                    for (effect, pair) in [(i, a), (j, b)] {
+                        effects[effect].eastward += pair.eastward;
+                        effects[effect].northward += pair.northward;
+                        effects[effect].vorticity += pair.vorticity;
+                    }
+                }
+            }
+        }
+        for (index, effect) in active.iter().zip(effects) {
+            let cyclogenesis = &mut self.storms[*index].cyclogenesis;
+            cyclogenesis.displace(effect.eastward, effect.northward);
+            cyclogenesis.exchanged_vorticity += effect.vorticity;
+        }
+    }
+
+    /// Avance l'horloge de `time_steps` heures ; résultats fusionnés par heure
+    pub fn simulate(&mut self, time_steps: u32) -> Result<Vec<StormResult>, MeteoError> {
+        let mut results = Vec::new();
+        for _ in 0..time_steps {
+            results.extend(self.step()?);
+        }
+        Ok(results)
+    }
+}
+
+/// Résultats fusionnés répartis par tempête, dans l'ordre des identifiants
+pub fn by_storm(results: &[StormResult]) -> BTreeMap<&str, ResultSeries> {
+    let mut storms: BTreeMap<&str, Vec<DevelopmentResult>> = BTreeMap::new();
+    for result in results {
+        storms.entry(result.storm.as_str()).or_default().push(result.result.clone());
+    }
+    storms.into_iter().map(|(id, results)| (id, ResultSeries::new(results))).collect()
+}
+
+/// Tableau des résultats fusionnés, une ligne par tempête et par heure
+pub fn to_table(results: &[StormResult], language: OutputLanguage, format: &NumberFormat) -> String {
+    let formatter = TableFormatter::default().with_number_format(*format).with_language(language);
+    let label = match language {
+        OutputLanguage::French => "Tempête",
+        OutputLanguage::English => "Storm",
+    };
+    let width = results.iter().map(|result| result.storm.chars().count()).fold(label.chars().count(), usize::max);
+    let mut table = format!("{:<width$} | {}\n{}|{}\n", label, formatter.header(), "-".repeat(width + 1), formatter.rule());
+    for result in results {
+        table += &format!("{:<width$} | {}\n", result.storm, formatter.row(&result.result));
+    }
+    table
+}
+
+/// Résultats fusionnés en JSON, chaque résultat portant l'identifiant de sa tempête
+pub fn to_json(results: &[StormResult]) -> String {
+    serde_json::to_string_pretty(results).expect("résultats sérialisables")
+}
+
+/// Scénario d'une tempête d'un fichier de famille
+///
+/// Les champs du scénario sont lus à côté de l'identifiant ; un champ inconnu
+/// est refusé comme dans un scénario seul.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "toml::Table")]
+pub struct StormScenario {
+    pub id: String,
+    #[serde(flatten)]
+    pub scenario: Scenario,
+}
+
+impl TryFrom<toml::Table> for StormScenario {
+    type Error = String;
+
+    fn try_from(mut table: toml::Table) -> Result<Self, Self::Error> {
+        let id = match table.remove("id") {
+            Some(toml::Value::String(id)) => id,
+            Some(id) => return Err(format!("identifiant de tempête invalide: {}", id)),
+            None => return Err("tempête sans identifiant".to_string()),
+        };
+        let scenario = toml::Value::Table(table)
+            .try_into()
+            .map_err(|e: toml::de::Error| format!("tempête {}: {}", id, e.message()))?;
+        Ok(Self { id, scenario })
+    }
+}
+
+/// Famille de tempêtes décrite dans un fichier TOML
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MultiStormScenario {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interaction: Option<InteractionModel>,  // Interaction entre tempêtes voisines
+    pub storms: Vec<StormScenario>,
+}
+
+impl MultiStormScenario {
+    /// Lit une famille de tempêtes depuis un fichier TOML
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, MeteoError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path)
+            .map_err(|e| MeteoError::InvalidScenario(format!("{}: {}", path.display(), e)))?;
+        Self::from_toml(&text)
+    }
+
+    /// Lit une famille de tempêtes depuis un texte TOML
+    pub fn from_toml(text: &str) -> Result<Self, MeteoError> {
+        toml::from_str(text).map_err(|e| MeteoError::InvalidScenario(e.message().to_string()))
+    }
+
+    /// Simulation de la famille, chaque tempête limitée à ses `time_steps` heures
+    pub fn to_multi_storm(&self) -> Result<MultiStorm, MeteoError> {
+        let storms = MultiStorm::new().with_interaction(self.interaction.unwrap_or_default())?;
+        self.storms.iter().try_fold(storms, |storms, storm| {
+            let cyclogenesis = storm.scenario.to_cyclogenesis()?;
+            storms.with_storm(Storm::new(storm.id.clone(), cyclogenesis).with_time_steps(storm.scenario.time_steps))
+        })
+    }
+
+    /// Simule la famille jusqu'à la fin de la plus longue tempête
+    pub fn run(&self) -> Result<Vec<StormResult>, MeteoError> {
+        let time_steps = self.storms.iter().map(|storm| storm.scenario.time_steps).max().unwrap_or(0);
+        self.to_multi_storm()?.simulate(time_steps)
+    }
+}
@@ -0,0 +1,102 @@
+//! Intrusion stratosphérique : filament de tourbillon potentiel descendant
+//!
+//! Un pli de tropopause fait descendre un filament d'air stratosphérique, froid
+//! en altitude et riche en tourbillon potentiel, au-dessus de la zone
+//! barocline : c'est un déclencheur classique des creusements rapides. Le
+//! filament apparaît à l'heure `onset` et descend pendant `duration` heures de
+//! son altitude de départ (12 km par défaut) au niveau de l'anomalie
+//! d'altitude de référence (5 km par défaut), son anomalie thermique croissant
+//! jusqu'à `amplitude` selon la rampe (1 − cos(π·p))/2 de l'avancement p.
+//!
+//! Le filament refroidit l'anomalie d'altitude d'autant plus qu'il s'en
+//! approche : sa perturbation est pondérée par le rapport des facteurs
+//! d'altitude du filament et de l'anomalie, borné à 1, ce qui l'amortit tant
+//! qu'il reste au-dessus de la tropopause.
+
+use std::f64::consts::PI;
+
+use serde::{Deserialize, Serialize};
+
+use crate::tropopause::altitude_factor;
+use crate::MeteoError;
+
+/// Altitude de départ par défaut du filament, dans la basse stratosphère (m)
+pub const STREAMER_TOP: f64 = 12000.0;
+
+/// Altitude d'arrivée par défaut du filament, celle de l'anomalie d'altitude de référence (m)
+pub const STREAMER_BOTTOM: f64 = 5000.0;
+
+/// Filament de tourbillon potentiel descendant sur l'anomalie d'altitude
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PvStreamer {
+    amplitude: f64,  // Refroidissement apporté en fin de descente (K)
+    onset: u32,      // Heure du début de la descente
+    duration: u32,   // Durée de la descente (h)
+    #[serde(default)]
+    top: Option<f64>,     // Altitude de départ (m)
+    #[serde(default)]
+    bottom: Option<f64>,  // Altitude d'arrivée (m)
+}
+
+impl PvStreamer {
+    /// Crée un filament descendant de 12 à 5 km à partir de l'heure `onset`
+    pub fn new(amplitude: f64, onset: u32, duration: u32) -> Result<Self, MeteoError> {
+        let streamer = Self { amplitude, onset, duration, top: None, bottom: None };
+        streamer.validate()?;
+        Ok(streamer)
+    }
+
+    /// Fait descendre le filament de l'altitude `top` à l'altitude `bottom` (m)
+    pub fn with_descent(mut self, top: f64, bottom: f64) -> Result<Self, MeteoError> {
+        self.top = Some(top);
+        self.bottom = Some(bottom);
+        self.validate()?;
+        Ok(self)
+    }
+
+    /// Altitude de départ (m)
+    pub fn top(&self) -> f64 {
+        self.top.unwrap_or(STREAMER_TOP)
+    }
+
+    /// Altitude d'arrivée (m)
+    pub fn bottom(&self) -> f64 {
+        self.bottom.unwrap_or(STREAMER_BOTTOM)
+    }
+
+    /// Avancement de la descente à une heure, de 0 avant `onset` à 1 après `onset + duration`
+    pub fn progress(&self, hour: u32) -> f64 {
+        let elapsed = f64::from(hour.saturating_sub(self.onset)) / f64::from(self.duration);
+        (1.0 - (PI * elapsed.min(1.0)).cos()) / 2.0
+    }
+
+    /// Altitude du filament à une heure (m)
+    pub fn altitude(&self, hour: u32) -> f64 {
+        self.top() - (self.top() - self.bottom()) * self.progress(hour)
+    }
+
+    /// Perturbation thermique apportée à une anomalie d'altitude `altitude` (m)
+    /// sous une tropopause `tropopause` (m), à une heure donnée (K)
+    pub fn temperature_perturbation(&self, hour: u32, altitude: f64, tropopause: f64) -> f64 {
+        let reach = (altitude_factor(self.altitude(hour), tropopause) / altitude_factor(altitude, tropopause)).min(1.0);
+        -self.amplitude * self.progress(hour) * reach
+    }
+
+    pub(crate) fn validate(&self) -> Result<(), MeteoError> {
+        if !(0.0..=50.0).contains(&self.amplitude) {
+            return Err(MeteoError::InvalidTemperature(self.amplitude));
+        }
+        if self.duration == 0 {
+            return Err(MeteoError::InvalidScenario("durée de descente du filament nulle".to_string()));
+        }
+        let (top, bottom) = (self.top(), self.bottom());
+        if !((0.0..=20000.0).contains(&bottom) && bottom < top && top <= 20000.0) {
+            return Err(MeteoError::InvalidScenario(format!(
+                "descente du filament invalide: de {} m à {} m",
+                top, bottom
+            )));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+//! Structure horizontale des anomalies thermiques
+//!
+//! À vent maximal V égal, le tourbillon central d'une anomalie dépend de son
+//! profil : ζ₀ = 2V/R pour un disque uniforme, ζ₀ ≈ 3,13 V/R pour un profil
+//! gaussien et ζ₀ = V(a + b)/(ab) pour une ellipse de Kirchhoff de demi-axes a et b.
+//! Le profil gaussien sert de référence au calibrage du modèle.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{MeteoError, HORIZONTAL_SCALE, SCALE_RANGE};
+
+/// Calibrage du tourbillon du modèle pour le profil gaussien
+const REFERENCE_AMPLIFICATION: f64 = 1.0e3;
+
+/// ζ₀·R/V du tourbillon gaussien, dont le vent est maximal à r ≈ 1,12 R
+const GAUSSIAN_CONCENTRATION: f64 = 3.134;
+
+/// Profil horizontal d'une anomalie
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnomalyShape {
+    #[default]
+    Gaussian,
+    TopHat,
+    Elliptical { aspect_ratio: f64 },  // Rapport du grand axe (le rayon) au petit axe
+}
+
+impl AnomalyShape {
+    /// Tourbillon central rapporté à V/R
+    fn concentration(&self) -> f64 {
+        match self {
+            AnomalyShape::Gaussian => GAUSSIAN_CONCENTRATION,
+            AnomalyShape::TopHat => 2.0,
+            AnomalyShape::Elliptical { aspect_ratio } => 1.0 + aspect_ratio,
+        }
+    }
+
+    /// Profil horizontal normalisé à 1 au centre, en fonction de la distance
+    /// rapportée au rayon ; une ellipse est prise le long de son grand axe
+    pub(crate) fn profile(&self, x: f64) -> f64 {
+        match self {
+            AnomalyShape::Gaussian => (-x * x).exp(),
+            AnomalyShape::TopHat | AnomalyShape::Elliptical { .. } if x.abs() <= 1.0 => 1.0,
+            AnomalyShape::TopHat | AnomalyShape::Elliptical { .. } => 0.0,
+        }
+    }
+
+    pub(crate) fn validate(&self) -> Result<(), MeteoError> {
+        match *self {
+            AnomalyShape::Elliptical { aspect_ratio } if !(aspect_ratio.is_finite() && aspect_ratio >= 1.0) => {
+                log_event!(warn, aspect_ratio, "rapport d'aspect rejeté");
+                Err(MeteoError::InvalidAspectRatio(aspect_ratio))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Géométrie horizontale d'une anomalie
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HorizontalStructure {
+    radius: f64,          // Rayon, ou demi-grand axe d'une ellipse (m)
+    shape: AnomalyShape,
+}
+
+impl Default for HorizontalStructure {
+    fn default() -> Self {
+        Self {
+            radius: HORIZONTAL_SCALE,
+            shape: AnomalyShape::default(),
+        }
+    }
+}
+
+impl HorizontalStructure {
+    /// Crée une structure de rayon (m) et de profil donnés
+    pub fn new(radius: f64, shape: AnomalyShape) -> Result<Self, MeteoError> {
+        if !SCALE_RANGE.contains(&radius) {
+            log_event!(warn, radius, "échelle horizontale rejetée");
+            return Err(MeteoError::InvalidScale(radius));
+        }
+        shape.validate()?;
+        Ok(Self { radius, shape })
+    }
+
+    /// Rayon de l'anomalie (m)
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Profil de l'anomalie
+    pub fn shape(&self) -> AnomalyShape {
+        self.shape
+    }
+
+    /// Facteur appliqué au tourbillon de vent thermique, 10³ pour le profil gaussien
+    pub fn vorticity_factor(&self) -> f64 {
+        REFERENCE_AMPLIFICATION * (self.shape.concentration() / GAUSSIAN_CONCENTRATION)
+    }
+}
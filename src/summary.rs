@@ -0,0 +1,69 @@
+//! Résumé du développement : maturité, pic et temps de croissance
+//!
+//! Le système est mature au premier pas classé mature ou au-delà selon le modèle
+//! norvégien (voir [`crate::lifecycle`]), c'est-à-dire dès que le tourbillon
+//! cesse de croître au-delà du stade naissant. Le temps de croissance en e est
+//! l'inverse du taux de croissance exponentiel moyen du tourbillon entre le
+//! premier pas et le pic, ln(|ζ_pic|/|ζ₀|)/Δt ; il se compare à l'échelle de
+//! temps d'Eady du régime dynamique.
+
+use crate::diagnostics::StepDiagnostics;
+use crate::lifecycle::{self, LifecycleModel, LifecycleStage};
+use crate::{DevelopmentResult, NumberFormat, OutputLanguage};
+
+/// Maturité, pic d'intensité et temps de croissance d'une simulation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DevelopmentSummary {
+    pub maturity_hour: Option<u32>,   // Première heure au stade mature, `None` si jamais atteint
+    pub peak_hour: u32,
+    pub peak_vorticity: f64,          // Tourbillon relatif au pic (s⁻¹), avec son signe
+    pub e_folding_time: Option<f64>,  // Temps de croissance en e (s), `None` sans croissance
+}
+
+impl DevelopmentSummary {
+    /// Résumé d'une simulation et de ses diagnostics, `None` sans pas de temps
+    pub fn compute(results: &[DevelopmentResult], diagnostics: &[StepDiagnostics]) -> Option<Self> {
+        let first = results.first()?;
+        let peak = results.iter().reduce(|peak, result| {
+            if result.relative_vorticity.abs() > peak.relative_vorticity.abs() { result } else { peak }
+        })?;
+
+        let maturity_hour = lifecycle::tag(results, diagnostics, LifecycleModel::Norwegian)
+            .iter()
+            .find(|step| step.stage >= LifecycleStage::Mature)
+            .map(|step| step.hour);
+
+        let elapsed = (f64::from(peak.hour) - f64::from(first.hour)) * 3600.0;
+        let growth = (peak.relative_vorticity.abs() / first.relative_vorticity.abs()).ln();
+        let e_folding_time = (elapsed > 0.0 && growth > 0.0 && growth.is_finite()).then(|| elapsed / growth);
+
+        Some(Self { maturity_hour, peak_hour: peak.hour, peak_vorticity: peak.relative_vorticity, e_folding_time })
+    }
+
+    /// Phrase résumant la maturité, le pic et le temps de croissance
+    pub fn describe(&self, language: OutputLanguage, format: &NumberFormat) -> String {
+        let vorticity = format.format(self.peak_vorticity * 1e5);
+        let e_folding = match self.e_folding_time {
+            Some(time) => format!("{} h", format.format(time / 3600.0)),
+            None => "∞".to_string(),
+        };
+        match (language, self.maturity_hour) {
+            (OutputLanguage::French, Some(hour)) => format!(
+                "Maturité à l'heure {}, tourbillon maximal de {} 10⁻⁵ s⁻¹ à l'heure {}, temps de croissance en e = {}",
+                hour, vorticity, self.peak_hour, e_folding
+            ),
+            (OutputLanguage::French, None) => format!(
+                "Maturité non atteinte, tourbillon maximal de {} 10⁻⁵ s⁻¹ à l'heure {}, temps de croissance en e = {}",
+                vorticity, self.peak_hour, e_folding
+            ),
+            (OutputLanguage::English, Some(hour)) => format!(
+                "Mature at hour {}, peak vorticity {} 10⁻⁵ s⁻¹ at hour {}, e-folding time = {}",
+                hour, vorticity, self.peak_hour, e_folding
+            ),
+            (OutputLanguage::English, None) => format!(
+                "Maturity not reached, peak vorticity {} 10⁻⁵ s⁻¹ at hour {}, e-folding time = {}",
+                vorticity, self.peak_hour, e_folding
+            ),
+        }
+    }
+}
@@ -0,0 +1,216 @@
+//! Nature de la surface sous l'anomalie de surface
+//!
+//! La même dépression ne se développe pas de la même façon sur l'Atlantique et
+//! sur l'Europe continentale :
+//!
+//! - sur l'océan, le flux de chaleur sensible rappelle la température de l'air
+//!   vers celle de la mer, `(SST − T)·EXCHANGE`, et la forte capacité
+//!   thermique de l'eau amortit le cycle diurne ; la surface est lisse ;
+//! - sur terre, le chauffage diurne passe en chaleur sensible d'autant moins
+//!   que le sol est humide, le reste s'évaporant ; la rugosité freine le vent.
+//!
+//! Le frottement de surface use le tourbillon de l'anomalie de surface par le
+//! pompage d'Ekman, au taux √(K·|f|/2)/H où la viscosité turbulente K est
+//! proportionnelle au coefficient de traînée. Celui-ci suit une loi de traînée
+//! ([`DragLaw`]) : fixe (1,2·10⁻³ sur mer par défaut), neutre (κ/ln(10/z₀))²
+//! pour une longueur de rugosité z₀ (celle du sol sur terre), ou de Charnock,
+//! la rugosité de la mer z₀ = α·u*²/g + 0,11·ν/u* croissant avec le vent. Le
+//! vent est le maximum du vent tangentiel de l'anomalie de surface. Sans nature
+//! de surface ni loi de traînée, ni flux ni frottement ne s'appliquent.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{BaroclinicCyclogenesis, Float, MeteoError, Position};
+
+/// Constante de von Kármán
+const VON_KARMAN: f64 = 0.4;
+
+/// Hauteur de référence du vent de surface (m)
+const REFERENCE_HEIGHT: f64 = 10.0;
+
+/// Coefficient de traînée de la mer
+pub const OCEAN_DRAG: f64 = 1.2e-3;
+
+/// Constante de Charnock de la mer du large
+pub const CHARNOCK_ALPHA: f64 = 0.011;
+
+/// Viscosité cinématique de l'air (m²/s)
+const AIR_VISCOSITY: f64 = 1.5e-5;
+
+/// Vent sous lequel la loi de Charnock garde la traînée du vent minimal (m/s)
+const CHARNOCK_MIN_WIND: f64 = 1.0;
+
+/// Itérations du calcul de la rugosité de Charnock
+const CHARNOCK_ITERATIONS: usize = 20;
+
+/// Pesanteur (m/s²)
+const GRAVITY: f64 = 9.81;
+
+/// Viscosité turbulente de la couche limite pour la traînée de la mer (m²/s)
+const OCEAN_EDDY_VISCOSITY: f64 = 10.0;
+
+/// Part de l'écart à la température de la mer compensée par le flux de chaleur
+const EXCHANGE: f64 = 0.2;
+
+/// Part du chauffage diurne transmise à l'air au-dessus de la mer
+const OCEAN_DIURNAL_FRACTION: f64 = 0.1;
+
+/// Part du chauffage diurne évaporée par un sol saturé
+const SATURATED_EVAPORATION: f64 = 0.7;
+
+/// Nature de la surface
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum SurfaceType {
+    /// Océan de température de surface `sst` (K)
+    Ocean { sst: f64 },
+    /// Terre de longueur de rugosité `roughness` (m) et d'humidité du sol
+    /// `soil_moisture` (0 sec, 1 saturé)
+    Land { roughness: f64, soil_moisture: f64 },
+}
+
+impl SurfaceType {
+    /// Vérifie la température de la mer (260 à 310 K), la rugosité (positive,
+    /// sous 10 m) et l'humidité du sol (0 à 1)
+    pub fn validate(&self) -> Result<(), MeteoError> {
+        let valid = match *self {
+            SurfaceType::Ocean { sst } => (260.0..=310.0).contains(&sst),
+            SurfaceType::Land { roughness, soil_moisture } => {
+                roughness > 0.0 && roughness < REFERENCE_HEIGHT && (0.0..=1.0).contains(&soil_moisture)
+            }
+        };
+        if !valid {
+            return Err(MeteoError::InvalidScenario(format!("surface invalide: {:?}", self)));
+        }
+        Ok(())
+    }
+
+    /// Loi de traînée par défaut : fixe sur mer, rugosité du sol sur terre
+    pub fn drag_law(&self) -> DragLaw {
+        match *self {
+            SurfaceType::Ocean { .. } => DragLaw::Constant { drag_coefficient: OCEAN_DRAG },
+            SurfaceType::Land { roughness, .. } => DragLaw::Roughness { length: roughness },
+        }
+    }
+
+    /// Coefficient de traînée de la loi par défaut, indépendant du vent
+    pub fn drag_coefficient(&self) -> f64 {
+        self.drag_law().drag_coefficient(0.0)
+    }
+
+    /// Forçage thermique de l'anomalie de surface (K), pour l'air à `air_temp`
+    /// (K) et un chauffage diurne `heating` (K)
+    pub fn heat_flux(&self, air_temp: f64, heating: f64) -> f64 {
+        match *self {
+            SurfaceType::Ocean { sst } => EXCHANGE * (sst - air_temp) + OCEAN_DIURNAL_FRACTION * heating,
+            SurfaceType::Land { soil_moisture, .. } => (1.0 - SATURATED_EVAPORATION * soil_moisture) * heating,
+        }
+    }
+
+}
+
+/// Loi de traînée du frottement de surface
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum DragLaw {
+    /// Coefficient de traînée fixe
+    Constant { drag_coefficient: f64 },
+    /// Traînée neutre d'une longueur de rugosité `length` (m)
+    Roughness { length: f64 },
+    /// Rugosité de la mer selon le vent, de constante de Charnock `alpha`
+    Charnock { alpha: f64 },
+}
+
+impl DragLaw {
+    /// Loi de Charnock de la mer du large
+    pub fn charnock() -> Self {
+        DragLaw::Charnock { alpha: CHARNOCK_ALPHA }
+    }
+
+    /// Vérifie le coefficient de traînée (positif, sous 0,05), la rugosité
+    /// (positive, sous 10 m) et la constante de Charnock (positive, sous 0,1)
+    pub fn validate(&self) -> Result<(), MeteoError> {
+        let valid = match *self {
+            DragLaw::Constant { drag_coefficient } => drag_coefficient > 0.0 && drag_coefficient < 0.05,
+            DragLaw::Roughness { length } => length > 0.0 && length < REFERENCE_HEIGHT,
+            DragLaw::Charnock { alpha } => alpha > 0.0 && alpha <= 0.1,
+        };
+        if !valid {
+            return Err(MeteoError::InvalidScenario(format!("loi de traînée invalide: {:?}", self)));
+        }
+        Ok(())
+    }
+
+    /// Coefficient de traînée à 10 m pour un vent `wind` (m/s)
+    pub fn drag_coefficient(&self, wind: f64) -> f64 {
+        match *self {
+            DragLaw::Constant { drag_coefficient } => drag_coefficient,
+            DragLaw::Roughness { length } => neutral_drag(length),
+            DragLaw::Charnock { alpha } => neutral_drag(charnock_roughness(alpha, wind)),
+        }
+    }
+
+    /// Viscosité turbulente de la couche limite (m²/s) pour un vent `wind` (m/s)
+    pub fn eddy_viscosity(&self, wind: f64) -> f64 {
+        OCEAN_EDDY_VISCOSITY * self.drag_coefficient(wind) / OCEAN_DRAG
+    }
+
+    /// Taux d'usure du tourbillon par le frottement de surface (s⁻¹), pour un
+    /// vent `wind` (m/s), un paramètre de Coriolis `coriolis` (s⁻¹) et une
+    /// troposphère de `depth` (m)
+    pub fn spin_down_rate(&self, wind: f64, coriolis: f64, depth: f64) -> f64 {
+        (self.eddy_viscosity(wind) * coriolis.abs() / 2.0).sqrt() / depth
+    }
+}
+
+/// Traînée neutre à 10 m d'une longueur de rugosité (m)
+fn neutral_drag(roughness: f64) -> f64 {
+    (VON_KARMAN / (REFERENCE_HEIGHT / roughness).ln()).powi(2)
+}
+
+/// Rugosité de la mer (m) pour un vent à 10 m, par itération sur la vitesse de frottement
+fn charnock_roughness(alpha: f64, wind: f64) -> f64 {
+    let wind = wind.max(CHARNOCK_MIN_WIND);
+    let mut roughness = 2.0e-4;
+    for _ in 0..CHARNOCK_ITERATIONS {
+        let friction_velocity = VON_KARMAN * wind / (REFERENCE_HEIGHT / roughness).ln();
+        roughness = alpha * friction_velocity.powi(2) / GRAVITY + 0.11 * AIR_VISCOSITY / friction_velocity;
+    }
+    roughness
+}
+
+impl Position {
+    /// Loi de traînée sous la position : la loi donnée, sinon celle de la surface
+    pub(crate) fn drag_law(&self) -> Option<DragLaw> {
+        self.drag_law.or_else(|| self.surface.map(|surface| surface.drag_law()))
+    }
+}
+
+impl<T: Float> BaroclinicCyclogenesis<T> {
+    /// Place l'anomalie de surface au-dessus d'une nature de surface
+    pub fn with_surface_type(mut self, surface: SurfaceType) -> Result<Self, MeteoError> {
+        surface.validate()?;
+        let index = self.surface_index();
+        self.anomalies[index].position.surface = Some(surface);
+        Ok(self)
+    }
+
+    /// Nature de la surface sous l'anomalie de surface, `None` sans flux
+    pub fn surface_type(&self) -> Option<SurfaceType> {
+        self.surface_anomaly().position.surface
+    }
+
+    /// Fixe la loi de traînée du frottement de l'anomalie de surface, à la
+    /// place de celle de sa nature de surface
+    pub fn with_drag_law(mut self, law: DragLaw) -> Result<Self, MeteoError> {
+        law.validate()?;
+        let index = self.surface_index();
+        self.anomalies[index].position.drag_law = Some(law);
+        Ok(self)
+    }
+
+    /// Loi de traînée de l'anomalie de surface, `None` sans frottement
+    pub fn drag_law(&self) -> Option<DragLaw> {
+        self.surface_anomaly().position.drag_law()
+    }
+}
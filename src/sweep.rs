@@ -0,0 +1,244 @@
+//! Balayage en latitude d'un même scénario
+//!
+//! Le scénario de base est simulé à chaque latitude demandée, les autres
+//! paramètres restant inchangés. Les simulations sont rangées par latitude
+//! croissante ; la comparaison relève, pour chacune, le pic de tourbillon
+//! relatif, son heure et le taux de creusement qui y mène.
+//!
+//! La matrice heure × latitude du tourbillon relatif s'exporte en CSV pour
+//! tracer une carte de chaleur, ou directement en PNG avec la fonctionnalité
+//! `plot`.
+
+use std::fmt::Write;
+
+use crate::diagnostics::{DiagnosticsLevel, StepDiagnostics};
+use crate::integration::StabilityLimit;
+use crate::plausibility::PlausibilityWarning;
+use crate::regime::DynamicalRegime;
+use crate::stability::CharneySternCheck;
+use crate::{BaroclinicCyclogenesis, MeteoError, NumberFormat, OutputLanguage, ResultSeries, Scenario};
+
+/// Scénario de base et niveau de diagnostics d'un balayage
+#[derive(Debug, Clone, Default)]
+pub struct SweepConfig {
+    pub scenario: Scenario,  // Sa latitude est remplacée par celle de chaque simulation
+    pub diagnostics_level: DiagnosticsLevel,
+}
+
+impl From<Scenario> for SweepConfig {
+    fn from(scenario: Scenario) -> Self {
+        Self { scenario, diagnostics_level: DiagnosticsLevel::default() }
+    }
+}
+
+/// Simulation à une latitude
+///
+/// Le régime, les avertissements et les contrôles de stabilité sont relevés
+/// avant la simulation, sur la configuration initiale.
+pub struct LatitudeRun {
+    pub latitude: f64,
+    pub cyclogenesis: BaroclinicCyclogenesis,  // État en fin de simulation
+    pub regime: Option<DynamicalRegime>,
+    pub warnings: Vec<PlausibilityWarning>,
+    pub charney_stern: Option<CharneySternCheck>,  // Critère non satisfait seulement
+    pub stability: Option<StabilityLimit>,     // Pas de temps instable, `None` s'il est stable
+    pub series: ResultSeries,
+    pub diagnostics: Vec<StepDiagnostics>,
+}
+
+impl LatitudeRun {
+    fn simulate(latitude: f64, config: &SweepConfig) -> Result<Self, MeteoError> {
+        let scenario = Scenario { latitude, ..config.scenario.clone() };
+        let mut cyclogenesis = scenario.to_cyclogenesis()?.with_diagnostics_level(config.diagnostics_level);
+        let regime = cyclogenesis.dynamical_regime();
+        let warnings = cyclogenesis.plausibility_warnings();
+        let charney_stern = cyclogenesis.charney_stern().filter(|check| !check.is_satisfied());
+        let stability = cyclogenesis.apply_step_policy(scenario.time_steps);
+        let (results, diagnostics) = cyclogenesis.simulate_with_diagnostics(scenario.time_steps)?;
+        Ok(Self {
+            latitude,
+            cyclogenesis,
+            regime,
+            warnings,
+            charney_stern,
+            stability,
+            series: ResultSeries::new(results),
+            diagnostics,
+        })
+    }
+}
+
+/// Pic de tourbillon d'une simulation du balayage
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LatitudePeak {
+    pub latitude: f64,
+    pub hour: u32,
+    pub max_vorticity: f64,           // Tourbillon relatif au pic (s⁻¹), avec son signe
+    pub deepening_rate: Option<f64>,  // s⁻², `None` si le pic est au premier pas
+}
+
+/// Simulations d'un balayage, par latitude croissante
+pub struct LatitudeSweep {
+    runs: Vec<LatitudeRun>,
+}
+
+impl BaroclinicCyclogenesis {
+    /// Simule le scénario de `config` à chacune des latitudes (°), distinctes
+    pub fn sweep_latitudes(latitudes: &[f64], config: &SweepConfig) -> Result<LatitudeSweep, MeteoError> {
+        let mut sorted = latitudes.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        if sorted.windows(2).any(|pair| pair[0] == pair[1]) {
+            return Err(MeteoError::InvalidScenario(format!("latitudes répétées dans le balayage: {:?}", latitudes)));
+        }
+        let runs = sorted
+            .into_iter()
+            .map(|latitude| LatitudeRun::simulate(latitude, config))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(LatitudeSweep { runs })
+    }
+}
+
+impl LatitudeSweep {
+    /// Simulations, par latitude croissante
+    pub fn runs(&self) -> &[LatitudeRun] {
+        &self.runs
+    }
+
+    /// Latitudes simulées, croissantes
+    pub fn latitudes(&self) -> Vec<f64> {
+        self.runs.iter().map(|run| run.latitude).collect()
+    }
+
+    /// Simulation à une latitude, `None` si elle n'a pas été balayée
+    pub fn get(&self, latitude: f64) -> Option<&LatitudeRun> {
+        self.runs.iter().find(|run| run.latitude == latitude)
+    }
+
+    /// Résultats à une latitude
+    pub fn series(&self, latitude: f64) -> Option<&ResultSeries> {
+        self.get(latitude).map(|run| &run.series)
+    }
+
+    /// Latitude et résultats de chaque simulation, par latitude croissante
+    pub fn iter(&self) -> impl Iterator<Item = (f64, &ResultSeries)> {
+        self.runs.iter().map(|run| (run.latitude, &run.series))
+    }
+
+    /// Pic de chaque simulation, par latitude croissante ; une simulation sans pas est omise
+    pub fn summary(&self) -> Vec<LatitudePeak> {
+        self.runs
+            .iter()
+            .filter_map(|run| {
+                let peak = run.series.peak()?;
+                Some(LatitudePeak {
+                    latitude: run.latitude,
+                    hour: peak.hour(),
+                    max_vorticity: peak.relative_vorticity(),
+                    deepening_rate: run.series.deepening_rate(),
+                })
+            })
+            .collect()
+    }
+
+    /// Matrice du tourbillon relatif, une ligne par heure et une colonne par latitude
+    ///
+    /// Les heures sont celles d'au moins une simulation ; une heure absente
+    /// d'une simulation y laisse une valeur `NaN`.
+    pub fn vorticity_matrix(&self) -> VorticityMatrix {
+        let mut hours: Vec<_> = self.runs.iter().flat_map(|run| run.series.columns().hours().iter().copied()).collect();
+        hours.sort_unstable();
+        hours.dedup();
+        let mut vorticity = Vec::with_capacity(hours.len() * self.runs.len());
+        for hour in &hours {
+            for run in &self.runs {
+                let result = run.series.rows().find(|result| result.hour() == *hour);
+                vorticity.push(result.map_or(f64::NAN, |result| result.relative_vorticity()));
+            }
+        }
+        VorticityMatrix { hours, latitudes: self.latitudes(), vorticity }
+    }
+
+    /// Tableau comparatif du pic de tourbillon selon la latitude
+    pub fn summary_table(&self, language: OutputLanguage, format: &NumberFormat) -> String {
+        let header = match language {
+            OutputLanguage::French => {
+                "Latitude (°) | Heure du pic | ζ max (10⁻⁵ s⁻¹)     | Creusement (10⁻¹⁰ s⁻²)"
+            }
+            OutputLanguage::English => {
+                "Latitude (°) | Peak hour    | Max ζ (10⁻⁵ s⁻¹)     | Deepening (10⁻¹⁰ s⁻²)"
+            }
+        };
+        let mut table = format!("{}\n-------------|--------------|----------------------|----------------------\n", header);
+        for peak in self.summary() {
+            let deepening = peak.deepening_rate.map_or_else(|| format!("{:>20}", "-"), |rate| format.format_width(rate * 1e10, 20));
+            table += &format!(
+                "{} | {:12} | {} | {}\n",
+                format.format_width(peak.latitude, 12),
+                peak.hour,
+                format.format_width(peak.max_vorticity * 1e5, 20),
+                deepening
+            );
+        }
+        table
+    }
+}
+
+/// Tourbillon relatif dans le plan temps–latitude
+#[derive(Debug, Clone, PartialEq)]
+pub struct VorticityMatrix {
+    hours: Vec<u32>,
+    latitudes: Vec<f64>,  // Latitudes croissantes (°)
+    vorticity: Vec<f64>,  // Tourbillon relatif (s⁻¹), heure par heure
+}
+
+impl VorticityMatrix {
+    /// Heures de la matrice
+    pub fn hours(&self) -> &[u32] {
+        &self.hours
+    }
+
+    /// Latitudes des colonnes (°)
+    pub fn latitudes(&self) -> &[f64] {
+        &self.latitudes
+    }
+
+    /// Tourbillon relatif (s⁻¹), rangé par heure puis par latitude
+    pub fn vorticity(&self) -> &[f64] {
+        &self.vorticity
+    }
+
+    /// Tourbillon relatif (s⁻¹) de la ligne d'indice `row`
+    pub fn row(&self, row: usize) -> Option<&[f64]> {
+        let columns = self.latitudes.len();
+        self.vorticity.get(row * columns..(row + 1) * columns)
+    }
+
+    /// Matrice CSV : les latitudes en première ligne, puis une ligne par heure
+    /// commençant par l'heure ; tourbillon en s⁻¹, vide pour une valeur absente
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("hour");
+        for latitude in &self.latitudes {
+            let _ = write!(csv, ",{}", latitude);
+        }
+        csv += "\n";
+        for (index, hour) in self.hours.iter().enumerate() {
+            csv += &hour.to_string();
+            for value in self.row(index).unwrap_or_default() {
+                if value.is_nan() {
+                    csv += ",";
+                } else {
+                    let _ = write!(csv, ",{:e}", value);
+                }
+            }
+            csv += "\n";
+        }
+        csv
+    }
+
+    /// Carte de chaleur PNG, les heures de haut en bas et les latitudes
+    /// croissantes de gauche à droite, une case de `cell` pixels de côté par valeur
+    #[cfg(feature = "plot")]
+    pub fn to_png(&self, cell: u32) -> Result<Vec<u8>, MeteoError> {
+        crate::plot::heatmap_png(&self.vorticity, self.latitudes.len(), cell)
+    }
+}
@@ -0,0 +1,248 @@
+//! Tableaux des résultats horaires
+//!
+//! Le tableau par défaut reprend les colonnes historiques : heure, vitesse
+//! verticale en cm/s et tourbillon relatif en 10⁻⁵ s⁻¹. Les colonnes, les unités
+//! et l'alignement se configurent ; en unités SI, les valeurs sont affichées sans
+//! conversion et gagnent à être mises en forme en notation scientifique.
+
+use std::str::FromStr;
+
+use crate::{DevelopmentResult, Float, NumberFormat, OutputLanguage};
+
+/// Largeur par défaut des colonnes de valeurs
+const COLUMN_WIDTH: usize = 20;
+
+/// Largeur de la colonne des heures
+const HOUR_WIDTH: usize = 4;
+
+/// Colonne d'un tableau de résultats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    Hour,
+    VerticalVelocity,
+    RelativeVorticity,
+    LowLevelDivergence,
+    UpperLevelDivergence,
+    ValidTime,
+    Latitude,
+    Longitude,
+}
+
+/// Unités d'affichage des valeurs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Units {
+    /// Unités lisibles : cm/s, 10⁻⁵ s⁻¹ et 10⁻⁶ s⁻¹ pour la divergence
+    #[default]
+    Display,
+    /// Unités SI, sans conversion
+    Si,
+}
+
+impl FromStr for Units {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "display" => Ok(Units::Display),
+            "si" => Ok(Units::Si),
+            _ => Err(format!("Unités inconnues: {} (display, si)", value)),
+        }
+    }
+}
+
+/// Alignement des cellules
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Alignment {
+    Left,
+    #[default]
+    Right,
+}
+
+/// Mise en forme des résultats en tableau
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableFormatter {
+    columns: Vec<Column>,
+    units: Units,
+    alignment: Alignment,
+    width: usize,
+    format: NumberFormat,
+    language: OutputLanguage,
+}
+
+impl Default for TableFormatter {
+    fn default() -> Self {
+        Self {
+            columns: vec![Column::Hour, Column::VerticalVelocity, Column::RelativeVorticity],
+            units: Units::default(),
+            alignment: Alignment::default(),
+            width: COLUMN_WIDTH,
+            format: NumberFormat::default(),
+            language: OutputLanguage::default(),
+        }
+    }
+}
+
+impl TableFormatter {
+    /// Choisit les colonnes, dans l'ordre d'affichage
+    pub fn with_columns(mut self, columns: Vec<Column>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Choisit les unités des valeurs
+    pub fn with_units(mut self, units: Units) -> Self {
+        self.units = units;
+        self
+    }
+
+    /// Choisit l'alignement des cellules, à droite par défaut
+    pub fn with_alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Fixe la largeur des colonnes de valeurs (20 caractères par défaut)
+    pub fn with_width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Fixe les règles de mise en forme des nombres
+    pub fn with_number_format(mut self, format: NumberFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Fixe la langue des en-têtes
+    pub fn with_language(mut self, language: OutputLanguage) -> Self {
+        self.language = language;
+        self
+    }
+
+    /// Ligne d'en-tête
+    pub fn header(&self) -> String {
+        let last = self.columns.len().saturating_sub(1);
+        let labels: Vec<_> = self
+            .columns
+            .iter()
+            .zip(self.labels())
+            .enumerate()
+            .map(|(index, (column, label))| {
+                match (column, index == last) {
+                    (Column::Hour, _) => format!("{:<width$}", label, width = HOUR_WIDTH + 1),
+                    (_, true) => label.to_string(),
+                    (_, false) => format!("{:<width$}", label, width = self.width),
+                }
+            })
+            .collect();
+        labels.join(" | ")
+    }
+
+    /// Ligne de séparation sous l'en-tête
+    pub fn rule(&self) -> String {
+        let cells: Vec<_> = self
+            .columns
+            .iter()
+            .map(|column| match column {
+                Column::Hour => "-".repeat(HOUR_WIDTH + 2),
+                _ => "-".repeat(self.width + 2),
+            })
+            .collect();
+        cells.join("|")
+    }
+
+    /// Ligne d'un résultat
+    pub fn row<T: Float>(&self, result: &DevelopmentResult<T>) -> String {
+        let cells: Vec<_> = self.columns.iter().map(|column| self.cell(*column, result)).collect();
+        cells.join(" | ")
+    }
+
+    /// Tableau complet : en-tête, séparation et une ligne par résultat
+    pub fn format<T: Float>(&self, results: &[DevelopmentResult<T>]) -> String {
+        let mut table = format!("{}\n{}\n", self.header(), self.rule());
+        for result in results {
+            table += &self.row(result);
+            table += "\n";
+        }
+        table
+    }
+
+    fn label(&self, column: Column) -> &'static str {
+        let si = self.units == Units::Si;
+        match (self.language, column) {
+            (OutputLanguage::French, Column::Hour) => "Heure",
+            (OutputLanguage::French, Column::VerticalVelocity) if si => "Vitesse verticale (m/s)",
+            (OutputLanguage::French, Column::VerticalVelocity) => "Vitesse verticale (cm/s)",
+            (OutputLanguage::French, Column::RelativeVorticity) if si => "Tourbillon relatif (s⁻¹)",
+            (OutputLanguage::French, Column::RelativeVorticity) => "Tourbillon relatif (10⁻⁵ s⁻¹)",
+            (OutputLanguage::French, Column::LowLevelDivergence) if si => "Divergence basse (s⁻¹)",
+            (OutputLanguage::French, Column::LowLevelDivergence) => "Divergence basse (10⁻⁶ s⁻¹)",
+            (OutputLanguage::French, Column::UpperLevelDivergence) if si => "Divergence haute (s⁻¹)",
+            (OutputLanguage::French, Column::UpperLevelDivergence) => "Divergence haute (10⁻⁶ s⁻¹)",
+            (OutputLanguage::French, Column::ValidTime) => "Date (UTC)",
+            (OutputLanguage::English, Column::Hour) => "Hour",
+            (OutputLanguage::English, Column::VerticalVelocity) if si => "Vertical velocity (m/s)",
+            (OutputLanguage::English, Column::VerticalVelocity) => "Vertical velocity (cm/s)",
+            (OutputLanguage::English, Column::RelativeVorticity) if si => "Relative vorticity (s⁻¹)",
+            (OutputLanguage::English, Column::RelativeVorticity) => "Relative vorticity (10⁻⁵ s⁻¹)",
+            (OutputLanguage::English, Column::LowLevelDivergence) if si => "Low-level divergence (s⁻¹)",
+            (OutputLanguage::English, Column::LowLevelDivergence) => "Low-level divergence (10⁻⁶ s⁻¹)",
+            (OutputLanguage::English, Column::UpperLevelDivergence) if si => "Upper-level divergence (s⁻¹)",
+            (OutputLanguage::English, Column::UpperLevelDivergence) => "Upper-level divergence (10⁻⁶ s⁻¹)",
+            (OutputLanguage::English, Column::ValidTime) => "Valid time (UTC)",
+            (_, Column::Latitude) => "Latitude (°)",
+            (_, Column::Longitude) => "Longitude (°)",
+        }
+    }
+
+    /// Facteur de conversion d'une colonne vers ses unités d'affichage
+    fn scale(&self, column: Column) -> f64 {
+        match (self.units, column) {
+            (Units::Si, _) => 1.0,
+            (Units::Display, Column::VerticalVelocity) => 100.0,
+            (Units::Display, Column::RelativeVorticity) => 1e5,
+            (Units::Display, Column::LowLevelDivergence | Column::UpperLevelDivergence) => 1e6,
+            (Units::Display, _) => 1.0,
+        }
+    }
+
+    /// En-têtes des colonnes, sans remplissage
+    pub(crate) fn labels(&self) -> Vec<&'static str> {
+        self.columns.iter().map(|column| self.label(*column)).collect()
+    }
+
+    /// Cellules d'un résultat, sans remplissage
+    pub(crate) fn cells<T: Float>(&self, result: &DevelopmentResult<T>) -> Vec<String> {
+        self.columns.iter().map(|column| self.value(*column, result)).collect()
+    }
+
+    fn cell<T: Float>(&self, column: Column, result: &DevelopmentResult<T>) -> String {
+        let width = match column {
+            Column::Hour => HOUR_WIDTH,
+            _ => self.width,
+        };
+        let text = self.value(column, result);
+        match self.alignment {
+            Alignment::Left => format!("{:<width$}", text, width = width),
+            Alignment::Right => format!("{:>width$}", text, width = width),
+        }
+    }
+
+    fn value<T: Float>(&self, column: Column, result: &DevelopmentResult<T>) -> String {
+        let value = match column {
+            Column::Hour => Some(result.hour.to_string()),
+            Column::ValidTime => result.valid_time.map(|time| time.format("%Y-%m-%d %H:%M").to_string()),
+            Column::VerticalVelocity => Some(self.format.format(result.vertical_velocity.to_f64() * self.scale(column))),
+            Column::RelativeVorticity => Some(self.format.format(result.relative_vorticity.to_f64() * self.scale(column))),
+            Column::LowLevelDivergence => {
+                Some(self.format.format(result.low_level_divergence.to_f64() * self.scale(column)))
+            }
+            Column::UpperLevelDivergence => {
+                Some(self.format.format(result.upper_level_divergence.to_f64() * self.scale(column)))
+            }
+            Column::Latitude => result.latitude.map(|latitude| self.format.format(latitude)),
+            Column::Longitude => result.longitude.map(|longitude| self.format.format(longitude)),
+        };
+        value.unwrap_or_else(|| "—".to_string())
+    }
+}
@@ -0,0 +1,47 @@
+//! Tropopause et couvercle stratosphérique
+//!
+//! La tropopause de l'état de fond borne l'extension verticale des anomalies :
+//! la stratosphère, très stable, s'oppose aux mouvements verticaux. Sous la
+//! tropopause H, le facteur d'altitude de la vitesse verticale reste
+//! exp(−z/8000) ; au-dessus, il décroît en plus sur 1000 m,
+//! exp(−H/8000)·exp(−(z − H)/1000), et le premier mode barocline de la coupe
+//! verticale s'annule. Une anomalie d'altitude placée au-dessus de la
+//! tropopause est signalée par un avertissement de vraisemblance.
+
+use crate::season::BackgroundState;
+use crate::{BaroclinicCyclogenesis, Float, MeteoError};
+
+/// Hauteur d'échelle du facteur d'altitude (m)
+pub const ALTITUDE_SCALE: f64 = 8000.0;
+
+/// Hauteur d'amortissement des mouvements verticaux dans la stratosphère (m)
+pub const STRATOSPHERIC_DECAY: f64 = 1000.0;
+
+/// Facteur d'altitude de la vitesse verticale à l'altitude `altitude` (m)
+/// sous une tropopause `tropopause` (m)
+pub fn altitude_factor(altitude: f64, tropopause: f64) -> f64 {
+    if altitude <= tropopause {
+        (-altitude / ALTITUDE_SCALE).exp()
+    } else {
+        (-tropopause / ALTITUDE_SCALE).exp() * (-(altitude - tropopause) / STRATOSPHERIC_DECAY).exp()
+    }
+}
+
+impl<T: Float> BaroclinicCyclogenesis<T> {
+    /// Fixe la hauteur de la tropopause (m, 5000 à 20000) sans changer le reste de l'état de fond
+    pub fn with_tropopause_height(mut self, height: f64) -> Result<Self, MeteoError> {
+        BackgroundState { tropopause_height: height, ..self.background() }.validate()?;
+        self.tropopause_height = height;
+        Ok(self)
+    }
+
+    /// Hauteur de la tropopause (m)
+    pub fn tropopause_height(&self) -> f64 {
+        self.tropopause_height
+    }
+
+    /// Anomalie d'altitude au-dessus de la tropopause
+    pub fn is_above_tropopause(&self) -> bool {
+        self.altitude_anomaly().position.altitude > self.tropopause_height
+    }
+}
@@ -0,0 +1,118 @@
+//! Talweg d'altitude en approche de la zone barocline
+//!
+//! Plutôt qu'une anomalie ponctuelle à un décalage fixe, l'anomalie d'altitude
+//! peut être portée par un talweg d'amplitude, de demi-longueur d'onde L et de
+//! vitesse d'approche données. L'axe du talweg, à une distance d en amont de
+//! l'anomalie de surface (une demi-longueur d'onde au départ par défaut),
+//! s'en approche à la vitesse d'approche :
+//!
+//! - le talweg refroidit l'anomalie d'altitude de −amplitude·cos²(π·d/2L),
+//!   nul au-delà d'une demi-longueur d'onde ;
+//! - son décalage remplace celui de l'inclinaison dans l'efficacité du
+//!   couplage entre niveaux, sin(π·d/L) : nulle quand le talweg arrive, maximale
+//!   au quart de longueur d'onde en amont, négative une fois l'anomalie de
+//!   surface dépassée.
+
+use std::f64::consts::PI;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{BaroclinicCyclogenesis, Float, MeteoError};
+
+/// Talweg d'altitude s'approchant de l'anomalie de surface
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UpperTrough {
+    amplitude: f64,        // Refroidissement sur l'axe du talweg (K)
+    half_wavelength: f64,  // Distance de l'axe du talweg à la dorsale (m)
+    approach_speed: f64,   // Vitesse d'approche de l'anomalie de surface (m/s)
+    #[serde(default)]
+    distance: Option<f64>,  // Distance initiale de l'axe en amont (m)
+}
+
+impl UpperTrough {
+    /// Crée un talweg dont l'axe est au départ à une demi-longueur d'onde en amont
+    pub fn new(amplitude: f64, half_wavelength: f64, approach_speed: f64) -> Result<Self, MeteoError> {
+        let trough = Self { amplitude, half_wavelength, approach_speed, distance: None };
+        trough.validate()?;
+        Ok(trough)
+    }
+
+    /// Place l'axe du talweg à une distance donnée en amont de l'anomalie de surface (m)
+    pub fn with_distance(mut self, distance: f64) -> Result<Self, MeteoError> {
+        self.distance = Some(distance);
+        self.validate()?;
+        Ok(self)
+    }
+
+    /// Demi-longueur d'onde (m)
+    pub fn half_wavelength(&self) -> f64 {
+        self.half_wavelength
+    }
+
+    /// Distance de l'axe du talweg en amont de l'anomalie de surface à une heure (m)
+    pub fn distance(&self, hour: u32) -> f64 {
+        self.distance.unwrap_or(self.half_wavelength) - self.approach_speed * f64::from(hour) * 3600.0
+    }
+
+    /// Perturbation thermique apportée à l'anomalie d'altitude à une heure (K)
+    pub fn temperature_perturbation(&self, hour: u32) -> f64 {
+        let distance = self.distance(hour);
+        if distance.abs() > self.half_wavelength {
+            return 0.0;
+        }
+        -self.amplitude * (PI * distance / (2.0 * self.half_wavelength)).cos().powi(2)
+    }
+
+    /// Efficacité du couplage entre niveaux à une heure, nulle au-delà d'une
+    /// demi-longueur d'onde
+    pub fn coupling_efficiency(&self, hour: u32) -> f64 {
+        let distance = self.distance(hour);
+        if distance.abs() > self.half_wavelength {
+            return 0.0;
+        }
+        (PI * distance / self.half_wavelength).sin()
+    }
+
+    pub(crate) fn validate(&self) -> Result<(), MeteoError> {
+        if !(0.0..=50.0).contains(&self.amplitude) {
+            return Err(MeteoError::InvalidTemperature(self.amplitude));
+        }
+        if !(self.half_wavelength.is_finite() && self.half_wavelength > 0.0) {
+            return Err(MeteoError::InvalidScale(self.half_wavelength));
+        }
+        if !self.approach_speed.is_finite() {
+            return Err(MeteoError::InvalidScenario(format!("vitesse d'approche invalide: {} m/s", self.approach_speed)));
+        }
+        if let Some(distance) = self.distance.filter(|distance| !distance.is_finite()) {
+            return Err(MeteoError::InvalidTilt(distance));
+        }
+        Ok(())
+    }
+}
+
+impl<T: Float> BaroclinicCyclogenesis<T> {
+    /// Porte l'anomalie d'altitude par un talweg en approche, dont le décalage
+    /// remplace celui de l'inclinaison
+    pub fn with_upper_trough(mut self, trough: UpperTrough) -> Self {
+        self.upper_trough = Some(trough);
+        self
+    }
+
+    /// Talweg d'altitude, `None` pour une anomalie à décalage fixe
+    pub fn upper_trough(&self) -> Option<UpperTrough> {
+        self.upper_trough
+    }
+
+    /// Décalage vers l'ouest de l'anomalie d'altitude à une heure (m) : celui
+    /// du talweg, sinon l'inclinaison
+    pub fn upper_offset(&self, hour: u32) -> f64 {
+        self.upper_trough.map_or_else(|| self.tilt(), |trough| trough.distance(hour))
+    }
+
+    /// Efficacité du couplage entre niveaux à une heure : celle du talweg,
+    /// sinon celle de l'inclinaison
+    pub fn coupling_efficiency(&self, hour: u32) -> f64 {
+        self.upper_trough.map_or_else(|| self.tilt_efficiency(), |trough| trough.coupling_efficiency(hour))
+    }
+}
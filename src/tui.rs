@@ -0,0 +1,294 @@
+//! Tableau de bord interactif en terminal
+//!
+//! Les anomalies thermiques, la latitude et la durée se règlent au clavier ; la
+//! simulation est relancée à chaque modification pour visualiser immédiatement la
+//! sensibilité de la cyclogenèse à ses ingrédients.
+
+use std::io;
+
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::symbols::Marker;
+use ratatui::text::Line;
+use ratatui::widgets::{Axis, Block, Chart, Dataset, GraphType, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+
+use crate::stability::CharneySternCheck;
+use crate::{BaroclinicCyclogenesis, NumberFormat, OutputLanguage};
+
+/// Paramètre réglable au clavier
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Parameter {
+    SurfaceTemp,
+    AltitudeTemp,
+    Latitude,
+    Duration,
+}
+
+impl Parameter {
+    const ALL: [Parameter; 4] = [
+        Parameter::SurfaceTemp,
+        Parameter::AltitudeTemp,
+        Parameter::Latitude,
+        Parameter::Duration,
+    ];
+
+    /// Incrément appliqué par une pression de touche
+    fn increment(self) -> f64 {
+        match self {
+            Parameter::SurfaceTemp | Parameter::AltitudeTemp => 0.5,
+            Parameter::Latitude => 1.0,
+            Parameter::Duration => 6.0,
+        }
+    }
+
+    fn label(self, language: OutputLanguage) -> &'static str {
+        match (language, self) {
+            (OutputLanguage::French, Parameter::SurfaceTemp) => "ΔT surface (K)",
+            (OutputLanguage::French, Parameter::AltitudeTemp) => "ΔT altitude (K)",
+            (OutputLanguage::French, Parameter::Latitude) => "Latitude (°N)",
+            (OutputLanguage::French, Parameter::Duration) => "Durée (h)",
+            (OutputLanguage::English, Parameter::SurfaceTemp) => "Surface ΔT (K)",
+            (OutputLanguage::English, Parameter::AltitudeTemp) => "Upper-level ΔT (K)",
+            (OutputLanguage::English, Parameter::Latitude) => "Latitude (°N)",
+            (OutputLanguage::English, Parameter::Duration) => "Duration (h)",
+        }
+    }
+}
+
+/// Durées de simulation accessibles (h)
+const MIN_DURATION: u32 = 6;
+const MAX_DURATION: u32 = 120;
+
+/// État du tableau de bord, piloté par les touches et dessiné dans un cadre
+pub struct Dashboard {
+    language: OutputLanguage,
+    surface_temp: f64,
+    altitude_temp: f64,
+    latitude: f64,
+    duration: u32,
+    selected: usize,
+    vertical_velocity: Vec<(f64, f64)>,   // (heure, cm/s)
+    relative_vorticity: Vec<(f64, f64)>,  // (heure, 10⁻⁵ s⁻¹)
+    stability: Option<CharneySternCheck>,
+    error: Option<String>,
+    should_quit: bool,
+}
+
+impl Dashboard {
+    /// Tableau de bord du cas de référence, déjà simulé
+    pub fn new(language: OutputLanguage) -> Self {
+        let mut dashboard = Self {
+            language,
+            surface_temp: 5.0,
+            altitude_temp: -8.0,
+            latitude: 45.0,
+            duration: 24,
+            selected: 0,
+            vertical_velocity: Vec::new(),
+            relative_vorticity: Vec::new(),
+            stability: None,
+            error: None,
+            should_quit: false,
+        };
+        dashboard.simulate();
+        dashboard
+    }
+
+    /// L'utilisateur a-t-il demandé à quitter ?
+    pub fn should_quit(&self) -> bool {
+        self.should_quit
+    }
+
+    /// Tourbillon relatif de la dernière simulation, (heure, 10⁻⁵ s⁻¹)
+    pub fn relative_vorticity(&self) -> &[(f64, f64)] {
+        &self.relative_vorticity
+    }
+
+    /// Message de la dernière simulation refusée, effacé à la suivante
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    fn value(&self, parameter: Parameter) -> f64 {
+        match parameter {
+            Parameter::SurfaceTemp => self.surface_temp,
+            Parameter::AltitudeTemp => self.altitude_temp,
+            Parameter::Latitude => self.latitude,
+            Parameter::Duration => self.duration as f64,
+        }
+    }
+
+    /// Relance la simulation avec les paramètres courants
+    fn simulate(&mut self) -> bool {
+        let simulation = BaroclinicCyclogenesis::new(self.surface_temp, self.altitude_temp, self.latitude)
+            .and_then(|mut cyclogenesis| {
+                let stability = cyclogenesis.charney_stern();
+                Ok((stability, cyclogenesis.simulate_interaction(self.duration)?))
+            });
+        match simulation {
+            Ok((stability, results)) => {
+                self.stability = stability;
+                self.vertical_velocity = results
+                    .iter()
+                    .map(|result| (result.hour as f64, result.vertical_velocity * 100.0))
+                    .collect();
+                self.relative_vorticity = results
+                    .iter()
+                    .map(|result| (result.hour as f64, result.relative_vorticity * 1e5))
+                    .collect();
+                self.error = None;
+                true
+            }
+            Err(error) => {
+                self.error = Some(error.message(self.language));
+                false
+            }
+        }
+    }
+
+    /// Modifie le paramètre sélectionné, en refusant les valeurs invalides
+    fn adjust(&mut self, direction: f64) {
+        let parameter = Parameter::ALL[self.selected];
+        let previous = (self.surface_temp, self.altitude_temp, self.latitude, self.duration);
+        let delta = direction * parameter.increment();
+
+        match parameter {
+            Parameter::SurfaceTemp => self.surface_temp += delta,
+            Parameter::AltitudeTemp => self.altitude_temp += delta,
+            Parameter::Latitude => self.latitude += delta,
+            Parameter::Duration => {
+                self.duration = (self.duration as f64 + delta).clamp(MIN_DURATION as f64, MAX_DURATION as f64) as u32
+            }
+        }
+
+        if !self.simulate() {
+            (self.surface_temp, self.altitude_temp, self.latitude, self.duration) = previous;
+        }
+    }
+
+    /// Applique une touche : sélection, réglage, réinitialisation ou sortie
+    pub fn handle_key(&mut self, code: KeyCode) {
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => self.should_quit = true,
+            KeyCode::Up => self.selected = (self.selected + Parameter::ALL.len() - 1) % Parameter::ALL.len(),
+            KeyCode::Down | KeyCode::Tab => self.selected = (self.selected + 1) % Parameter::ALL.len(),
+            KeyCode::Left | KeyCode::Char('-') => self.adjust(-1.0),
+            KeyCode::Right | KeyCode::Char('+') => self.adjust(1.0),
+            KeyCode::Char('r') => {
+                let language = self.language;
+                *self = Self::new(language);
+            }
+            _ => {}
+        }
+    }
+
+    /// Dessine les réglages, les deux courbes et la ligne d'état
+    pub fn render(&self, frame: &mut Frame) {
+        let [controls, velocity, vorticity, status] = Layout::vertical([
+            Constraint::Length(Parameter::ALL.len() as u16 + 4),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+
+        self.render_controls(frame, controls);
+
+        let (velocity_title, vorticity_title) = match self.language {
+            OutputLanguage::French => ("Vitesse verticale (cm/s)", "Tourbillon relatif (10⁻⁵ s⁻¹)"),
+            OutputLanguage::English => ("Vertical velocity (cm/s)", "Relative vorticity (10⁻⁵ s⁻¹)"),
+        };
+        self.render_chart(frame, velocity, velocity_title, &self.vertical_velocity, Color::Cyan);
+        self.render_chart(frame, vorticity, vorticity_title, &self.relative_vorticity, Color::Magenta);
+
+        let help = match self.language {
+            OutputLanguage::French => "↑/↓ choisir · ←/→ modifier · r réinitialiser · q quitter",
+            OutputLanguage::English => "↑/↓ select · ←/→ adjust · r reset · q quit",
+        };
+        let status_line = match &self.error {
+            Some(error) => Line::styled(error.as_str(), Style::default().fg(Color::Red)),
+            None => Line::styled(help, Style::default().fg(Color::DarkGray)),
+        };
+        frame.render_widget(Paragraph::new(status_line), status);
+    }
+
+    fn render_controls(&self, frame: &mut Frame, area: Rect) {
+        let mut lines: Vec<Line> = Parameter::ALL
+            .iter()
+            .enumerate()
+            .map(|(index, &parameter)| {
+                let text = format!("{:<20} {:>7.1}", parameter.label(self.language), self.value(parameter));
+                if index == self.selected {
+                    Line::styled(format!("▶ {}", text), Style::default().add_modifier(Modifier::BOLD))
+                } else {
+                    Line::raw(format!("  {}", text))
+                }
+            })
+            .collect();
+
+        if let Some(check) = self.stability {
+            let color = if check.is_satisfied() { Color::Green } else { Color::Yellow };
+            lines.push(Line::raw(""));
+            lines.push(Line::styled(
+                check.describe(self.language, &NumberFormat::default()),
+                Style::default().fg(color),
+            ));
+        }
+
+        let title = match self.language {
+            OutputLanguage::French => " Cyclogenèse barocline ",
+            OutputLanguage::English => " Baroclinic cyclogenesis ",
+        };
+        frame.render_widget(Paragraph::new(lines).block(Block::bordered().title(title)), area);
+    }
+
+    fn render_chart(&self, frame: &mut Frame, area: Rect, title: &str, data: &[(f64, f64)], color: Color) {
+        let (min, max) = data
+            .iter()
+            .fold((0.0_f64, 0.0_f64), |(min, max), &(_, value)| (min.min(value), max.max(value)));
+        let margin = ((max - min) * 0.1).max(1e-3);
+        let bounds = [min - margin, max + margin];
+        let last_hour = self.duration.saturating_sub(1) as f64;
+
+        let dataset = Dataset::default()
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(color))
+            .data(data);
+
+        let chart = Chart::new(vec![dataset])
+            .block(Block::bordered().title(format!(" {} ", title)))
+            .x_axis(
+                Axis::default()
+                    .title("h")
+                    .bounds([0.0, last_hour])
+                    .labels(["0".to_string(), format!("{}", last_hour / 2.0), format!("{}", last_hour)]),
+            )
+            .y_axis(
+                Axis::default()
+                    .bounds(bounds)
+                    .labels([format!("{:.2}", bounds[0]), format!("{:.2}", bounds[1])]),
+            );
+        frame.render_widget(chart, area);
+    }
+}
+
+/// Lance le tableau de bord jusqu'à ce que l'utilisateur le quitte
+pub fn run(language: OutputLanguage) -> io::Result<()> {
+    ratatui::run(|terminal| event_loop(terminal, Dashboard::new(language)))
+}
+
+fn event_loop(terminal: &mut DefaultTerminal, mut dashboard: Dashboard) -> io::Result<()> {
+    while !dashboard.should_quit {
+        terminal.draw(|frame| dashboard.render(frame))?;
+
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press {
+                dashboard.handle_key(key.code);
+            }
+        }
+    }
+    Ok(())
+}
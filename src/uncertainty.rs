@@ -0,0 +1,154 @@
+//! Propagation analytique des incertitudes
+//!
+//! Une grandeur incertaine porte sa moyenne et son écart type. Les opérations
+//! propagent l'écart type au premier ordre en supposant leurs opérandes
+//! indépendants, ce qui est exact pour le modèle : chaque anomalie thermique
+//! n'intervient qu'une fois dans sa propre contribution, et les contributions des
+//! différentes anomalies s'additionnent. Une simulation sur `Uncertain` donne
+//! ainsi la bande ±σ des résultats sans lancer d'ensemble.
+//!
+//! ```
+//! use cyclogenese_rust::BaroclinicCyclogenesis;
+//! use cyclogenese_rust::uncertainty::Uncertain;
+//!
+//! let results = BaroclinicCyclogenesis::new(Uncertain::new(5.0, 1.0), Uncertain::new(-8.0, 0.5), 45.0)?
+//!     .simulate_interaction(24)?;
+//! # Ok::<(), cyclogenese_rust::MeteoError>(())
+//! ```
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{DevelopmentResult, Float, NumberFormat, OutputLanguage};
+
+/// Grandeur de moyenne et d'écart type connus
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Uncertain {
+    mean: f64,
+    sigma: f64,
+}
+
+impl Uncertain {
+    /// Grandeur de moyenne `mean` et d'écart type `sigma`
+    pub fn new(mean: f64, sigma: f64) -> Self {
+        Self { mean, sigma: sigma.abs() }
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    pub fn sigma(&self) -> f64 {
+        self.sigma
+    }
+
+    /// Borne inférieure de la bande ±σ
+    pub fn lower(&self) -> f64 {
+        self.mean - self.sigma
+    }
+
+    /// Borne supérieure de la bande ±σ
+    pub fn upper(&self) -> f64 {
+        self.mean + self.sigma
+    }
+}
+
+impl fmt::Display for Uncertain {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ± {}", self.mean, self.sigma)
+    }
+}
+
+impl Add for Uncertain {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.mean + other.mean, self.sigma.hypot(other.sigma))
+    }
+}
+
+impl AddAssign for Uncertain {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Uncertain {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.mean - other.mean, self.sigma.hypot(other.sigma))
+    }
+}
+
+impl Mul for Uncertain {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self::new(self.mean * other.mean, (other.mean * self.sigma).hypot(self.mean * other.sigma))
+    }
+}
+
+impl Div for Uncertain {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        let mean = self.mean / other.mean;
+        Self::new(mean, (self.sigma / other.mean).hypot(mean * other.sigma / other.mean))
+    }
+}
+
+impl Neg for Uncertain {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.mean, self.sigma)
+    }
+}
+
+impl Float for Uncertain {
+    fn from_f64(value: f64) -> Self {
+        Self::new(value, 0.0)
+    }
+
+    fn to_f64(self) -> f64 {
+        self.mean
+    }
+
+    fn abs(self) -> Self {
+        Self::new(self.mean.abs(), self.sigma)
+    }
+
+    fn sqrt(self) -> Self {
+        let root = self.mean.sqrt();
+        Self::new(root, self.sigma / (2.0 * root))
+    }
+}
+
+/// Tableau des résultats avec leur écart type
+pub fn to_table(results: &[DevelopmentResult<Uncertain>], language: OutputLanguage, format: &NumberFormat) -> String {
+    let hour = match language {
+        OutputLanguage::French => "Heure",
+        OutputLanguage::English => "Hour ",
+    };
+
+    let mut table = format!(
+        "{} | w (cm/s)             | ± σ                  | ζ (10⁻⁵ s⁻¹)         | ± σ\n\
+         ------|----------------------|----------------------|----------------------|----------------------\n",
+        hour
+    );
+    for result in results {
+        let (velocity, vorticity) = (result.vertical_velocity, result.relative_vorticity);
+        table += &format!(
+            "{:4} | {} | {} | {} | {}\n",
+            result.hour,
+            format.format_width(velocity.mean * 100.0, 20),
+            format.format_width(velocity.sigma * 100.0, 20),
+            format.format_width(vorticity.mean * 1e5, 20),
+            format.format_width(vorticity.sigma * 1e5, 20)
+        );
+    }
+    table
+}
@@ -0,0 +1,105 @@
+//! Unités de vitesse du vent
+//!
+//! Les calculs sont menés en m/s ; les tableaux, comptes rendus et exports
+//! convertissent les vitesses de vent dans l'unité demandée. Les vitesses
+//! verticales gardent leurs propres unités (cm/s ou m/s).
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Longueur d'un mille marin (m)
+const NAUTICAL_MILE: f64 = 1852.0;
+
+/// Longueur d'un mille terrestre (m)
+const STATUTE_MILE: f64 = 1609.344;
+
+/// Unité d'affichage des vitesses de vent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WindUnit {
+    /// Mètres par seconde
+    #[default]
+    MetersPerSecond,
+    /// Kilomètres par heure
+    KilometersPerHour,
+    /// Nœuds
+    Knots,
+    /// Milles terrestres par heure
+    MilesPerHour,
+}
+
+impl WindUnit {
+    /// Valeur d'une unité en m/s
+    pub fn in_meters_per_second(self) -> f64 {
+        match self {
+            WindUnit::MetersPerSecond => 1.0,
+            WindUnit::KilometersPerHour => 1000.0 / 3600.0,
+            WindUnit::Knots => NAUTICAL_MILE / 3600.0,
+            WindUnit::MilesPerHour => STATUTE_MILE / 3600.0,
+        }
+    }
+
+    /// Exprime dans cette unité une vitesse en m/s
+    pub fn express(self, value: f64) -> f64 {
+        match self {
+            WindUnit::MetersPerSecond => value,
+            _ => value / self.in_meters_per_second(),
+        }
+    }
+
+    /// Convertit une vitesse exprimée dans cette unité en m/s
+    pub fn to_si(self, value: f64) -> f64 {
+        match self {
+            WindUnit::MetersPerSecond => value,
+            _ => value * self.in_meters_per_second(),
+        }
+    }
+
+    /// Symbole affiché dans les tableaux
+    pub fn symbol(self) -> &'static str {
+        match self {
+            WindUnit::MetersPerSecond => "m/s",
+            WindUnit::KilometersPerHour => "km/h",
+            WindUnit::Knots => "kt",
+            WindUnit::MilesPerHour => "mph",
+        }
+    }
+
+    /// Unité au format UDUNITS, pour l'attribut `units` des exports CF
+    pub fn udunits(self) -> &'static str {
+        match self {
+            WindUnit::MetersPerSecond => "m s-1",
+            WindUnit::KilometersPerHour => "km h-1",
+            WindUnit::Knots => "knot",
+            WindUnit::MilesPerHour => "mile h-1",
+        }
+    }
+}
+
+impl fmt::Display for WindUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+impl FromStr for WindUnit {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "m/s" | "ms" | "mps" => Ok(WindUnit::MetersPerSecond),
+            "km/h" | "kmh" | "kph" => Ok(WindUnit::KilometersPerHour),
+            "kt" | "kts" | "knot" | "knots" => Ok(WindUnit::Knots),
+            "mph" => Ok(WindUnit::MilesPerHour),
+            _ => Err(format!("Unité de vent inconnue: {} (m/s, km/h, kt, mph)", value)),
+        }
+    }
+}
+
+/// Convertit une vitesse d'une unité à une autre
+pub fn convert(value: f64, from: WindUnit, to: WindUnit) -> f64 {
+    if from == to {
+        value
+    } else {
+        to.express(from.to_si(value))
+    }
+}
@@ -0,0 +1,221 @@
+//! Politique de validation des paramètres physiques
+//!
+//! Les plages acceptées pour la latitude, l'altitude, la pression et les
+//! anomalies thermiques protègent des configurations aberrantes. En usage
+//! exploratoire, la politique souple ramène une valeur hors plage à la borne la
+//! plus proche en le signalant ; la politique désactivée ne contrôle plus rien,
+//! aux risques de l'utilisateur. Les plages de l'altitude, de la pression et
+//! des anomalies thermiques se règlent par scénario ([`ValidationLimits`]) pour
+//! une atmosphère martienne ou une étude de la stratosphère.
+
+use std::ops::RangeInclusive;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{MeteoError, NumberFormat, OutputLanguage, Scenario, SURFACE_LEVEL};
+
+/// Conduite à tenir face à une valeur hors plage
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationPolicy {
+    /// Refuser la valeur
+    #[default]
+    Strict,
+    /// Ramener la valeur à la borne la plus proche et le signaler
+    Lenient,
+    /// Accepter la valeur sans contrôle
+    Off,
+}
+
+/// Paramètre contrôlé
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bound {
+    Latitude,
+    Altitude,
+    Pressure,
+    Temperature,
+}
+
+impl Bound {
+    /// Plage acceptée par défaut, dans l'unité du paramètre (°, m, hPa, K)
+    pub fn range(self) -> RangeInclusive<f64> {
+        ValidationLimits::default().range(self)
+    }
+
+    /// Erreur signalant une valeur hors plage
+    pub fn error(self, value: f64) -> MeteoError {
+        match self {
+            Bound::Latitude => MeteoError::InvalidLatitude(value),
+            Bound::Altitude => MeteoError::InvalidAltitude(value),
+            Bound::Pressure => MeteoError::InvalidPressure(value),
+            Bound::Temperature => MeteoError::InvalidTemperature(value),
+        }
+    }
+
+    fn unit(self) -> &'static str {
+        match self {
+            Bound::Latitude => "°",
+            Bound::Altitude => " m",
+            Bound::Pressure => " hPa",
+            Bound::Temperature => " K",
+        }
+    }
+
+    fn name(self, language: OutputLanguage) -> &'static str {
+        match (language, self) {
+            (OutputLanguage::French, Bound::Latitude) => "latitude",
+            (OutputLanguage::French, Bound::Altitude) => "altitude",
+            (OutputLanguage::French, Bound::Pressure) => "pression",
+            (OutputLanguage::French, Bound::Temperature) => "anomalie thermique",
+            (OutputLanguage::English, Bound::Latitude) => "latitude",
+            (OutputLanguage::English, Bound::Altitude) => "altitude",
+            (OutputLanguage::English, Bound::Pressure) => "pressure",
+            (OutputLanguage::English, Bound::Temperature) => "thermal anomaly",
+        }
+    }
+}
+
+/// Valeur ramenée dans sa plage par la politique souple
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Clamp {
+    pub bound: Bound,
+    pub value: f64,    // Valeur demandée
+    pub clamped: f64,  // Valeur retenue
+}
+
+impl Clamp {
+    /// Valeur ramenée dans la plage par défaut de `bound`, `None` si elle y est
+    /// déjà ou n'est pas un nombre
+    pub fn of(bound: Bound, value: f64) -> Option<Self> {
+        ValidationLimits::default().clamp(bound, value)
+    }
+
+    /// Avertissement indiquant la valeur retenue
+    pub fn describe(&self, language: OutputLanguage, format: &NumberFormat) -> String {
+        let unit = self.bound.unit();
+        let (value, clamped) = (format.format(self.value) + unit, format.format(self.clamped) + unit);
+        match language {
+            OutputLanguage::French => {
+                format!("Attention : {} de {} hors plage, ramenée à {}", self.bound.name(language), value, clamped)
+            }
+            OutputLanguage::English => {
+                format!("Warning: {} of {} out of range, clamped to {}", self.bound.name(language), value, clamped)
+            }
+        }
+    }
+}
+
+impl ValidationPolicy {
+    /// Valeur retenue pour un paramètre selon la politique, dans les plages par défaut
+    pub fn apply(self, bound: Bound, value: f64) -> Result<f64, MeteoError> {
+        ValidationLimits::default().apply(self, bound, value)
+    }
+}
+
+/// Bornes d'une plage
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Limit {
+    pub min: f64,
+    pub max: f64,
+}
+
+impl Limit {
+    pub const fn new(min: f64, max: f64) -> Self {
+        Self { min, max }
+    }
+}
+
+/// Plages acceptées des paramètres ; la latitude reste comprise entre -90° et 90°
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ValidationLimits {
+    pub altitude: Limit,     // m
+    pub pressure: Limit,     // hPa
+    pub temperature: Limit,  // Anomalie thermique (K)
+}
+
+impl Default for ValidationLimits {
+    fn default() -> Self {
+        Self {
+            altitude: Limit::new(-400.0, 20000.0),
+            pressure: Limit::new(100.0, 1100.0),
+            temperature: Limit::new(-50.0, 50.0),
+        }
+    }
+}
+
+impl ValidationLimits {
+    /// Plage acceptée d'un paramètre
+    pub fn range(&self, bound: Bound) -> RangeInclusive<f64> {
+        let limit = match bound {
+            Bound::Latitude => Limit::new(-90.0, 90.0),
+            Bound::Altitude => self.altitude,
+            Bound::Pressure => self.pressure,
+            Bound::Temperature => self.temperature,
+        };
+        limit.min..=limit.max
+    }
+
+    /// Vérifie que chaque plage est bornée par des nombres dans l'ordre
+    pub fn validate(&self) -> Result<(), MeteoError> {
+        let limits = [("altitude", self.altitude), ("pressure", self.pressure), ("temperature", self.temperature)];
+        match limits.iter().find(|(_, limit)| limit.min.is_nan() || limit.max.is_nan() || limit.min > limit.max) {
+            Some((name, limit)) => Err(MeteoError::InvalidScenario(format!(
+                "plage `limits.{}` invalide: {} à {}",
+                name, limit.min, limit.max
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Valeur ramenée dans la plage de `bound`, `None` si elle y est déjà ou n'est pas un nombre
+    pub fn clamp(&self, bound: Bound, value: f64) -> Option<Clamp> {
+        let range = self.range(bound);
+        if value.is_nan() || range.contains(&value) {
+            return None;
+        }
+        Some(Clamp { bound, value, clamped: value.clamp(*range.start(), *range.end()) })
+    }
+
+    /// Valeur retenue pour un paramètre selon la politique
+    ///
+    /// Une valeur qui n'est pas un nombre est refusée, sauf sans contrôle.
+    pub fn apply(&self, policy: ValidationPolicy, bound: Bound, value: f64) -> Result<f64, MeteoError> {
+        match policy {
+            ValidationPolicy::Off => Ok(value),
+            _ if self.range(bound).contains(&value) => Ok(value),
+            ValidationPolicy::Lenient if !value.is_nan() => {
+                let clamp = self.clamp(bound, value).expect("valeur hors plage");
+                log_event!(warn, value, clamped = clamp.clamped, "valeur ramenée dans sa plage");
+                Ok(clamp.clamped)
+            }
+            _ => {
+                log_event!(warn, value, "valeur rejetée");
+                Err(bound.error(value))
+            }
+        }
+    }
+}
+
+impl Scenario {
+    /// Valeurs que la politique souple ramène dans leur plage à la construction
+    /// de la simulation, dans l'ordre où elles sont contrôlées ; vide sous une
+    /// autre politique
+    pub fn clamps(&self) -> Vec<Clamp> {
+        if self.validation != Some(ValidationPolicy::Lenient) {
+            return Vec::new();
+        }
+        let (limits, upper_level) = (self.limits.unwrap_or_default(), self.upper_level());
+        let values = [
+            (Bound::Latitude, self.latitude),
+            (Bound::Altitude, SURFACE_LEVEL.0),
+            (Bound::Pressure, SURFACE_LEVEL.1),
+            (Bound::Altitude, upper_level.0),
+            (Bound::Pressure, upper_level.1),
+            (Bound::Temperature, self.surface_temp),
+            (Bound::Temperature, self.altitude_temp),
+        ];
+        values.into_iter().filter_map(|(bound, value)| limits.clamp(bound, value)).collect()
+    }
+}
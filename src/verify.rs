@@ -0,0 +1,488 @@
+//! Vérification d'une simulation par une série observée
+//!
+//! Chaque observation est appariée à la simulation interpolée linéairement à
+//! son heure ; les observations hors de la période simulée sont écartées. Pour
+//! la pression centrale (voir [`crate::pressure`]) et le tourbillon relatif,
+//! les scores portent sur l'écart simulation − observation : biais moyen,
+//! erreur quadratique moyenne (RMSE), erreur absolue moyenne (MAE) et
+//! coefficient de corrélation de Pearson.
+//!
+//! Un ensemble (voir [`crate::ensemble`]) se vérifie par des scores
+//! probabilistes : le CRPS empirique, moyenne de |xᵢ − y| − ½·moyenne de
+//! |xᵢ − xⱼ| sur les membres xᵢ et l'observation y ; l'histogramme des rangs de
+//! l'observation parmi les membres, plat pour un ensemble bien dispersé ; et le
+//! score de Brier des événements à seuil, écart quadratique moyen entre la
+//! fraction des membres qui prévoient l'événement et son occurrence observée.
+//! Le creusement observé se déduit de la pression observée, sur une fenêtre
+//! d'au plus 24 heures comme pour la simulation (voir [`crate::deepening`]).
+
+use crate::deepening::DEEPENING_WINDOW;
+use crate::ensemble::{Ensemble, EnsembleVariable, Threshold};
+use crate::io::observations::ObservedState;
+use crate::{Float, MeteoError, NumberFormat, OutputLanguage, Scenario};
+
+/// Scores d'une variable
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Scores {
+    pub count: usize,
+    pub bias: f64,                 // Écart moyen simulation − observation
+    pub rmse: f64,                 // Erreur quadratique moyenne
+    pub mae: f64,                  // Erreur absolue moyenne
+    pub correlation: Option<f64>,  // Corrélation de Pearson, indéfinie sans variance
+}
+
+impl Scores {
+    /// Scores de couples (simulé, observé), `None` sans couple
+    pub fn compute(pairs: &[(f64, f64)]) -> Option<Self> {
+        if pairs.is_empty() {
+            return None;
+        }
+        let count = pairs.len() as f64;
+        let errors = pairs.iter().map(|(simulated, observed)| simulated - observed);
+        let bias = errors.clone().sum::<f64>() / count;
+        let rmse = (errors.clone().map(|error| error * error).sum::<f64>() / count).sqrt();
+        let mae = errors.map(f64::abs).sum::<f64>() / count;
+
+        let (mean_simulated, mean_observed) = (
+            pairs.iter().map(|pair| pair.0).sum::<f64>() / count,
+            pairs.iter().map(|pair| pair.1).sum::<f64>() / count,
+        );
+        let (mut covariance, mut variance_simulated, mut variance_observed) = (0.0, 0.0, 0.0);
+        for (simulated, observed) in pairs {
+            let (ds, d_o) = (simulated - mean_simulated, observed - mean_observed);
+            covariance += ds * d_o;
+            variance_simulated += ds * ds;
+            variance_observed += d_o * d_o;
+        }
+        let correlation = (variance_simulated > 0.0 && variance_observed > 0.0)
+            .then(|| covariance / (variance_simulated * variance_observed).sqrt());
+
+        Some(Self { count: pairs.len(), bias, rmse, mae, correlation })
+    }
+}
+
+/// Observation appariée à la simulation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VerificationPair {
+    pub hour: f64,
+    pub simulated_pressure: f64,          // Pression centrale simulée (hPa)
+    pub observed_pressure: Option<f64>,   // Pression centrale observée (hPa)
+    pub simulated_vorticity: f64,         // Tourbillon relatif simulé (s⁻¹)
+    pub observed_vorticity: Option<f64>,  // Tourbillon relatif observé (s⁻¹)
+}
+
+/// Vérification d'un scénario par une série observée
+#[derive(Debug, Clone)]
+pub struct Verification {
+    pairs: Vec<VerificationPair>,
+    pressure: Option<Scores>,
+    vorticity: Option<Scores>,
+    discarded: usize,
+}
+
+impl Verification {
+    /// Simule le scénario et l'apparie aux observations
+    pub fn compute(scenario: &Scenario, observations: &[ObservedState]) -> Result<Self, MeteoError> {
+        let mut cyclogenesis = scenario.to_cyclogenesis()?;
+        let results = cyclogenesis.simulate_interaction(scenario.time_steps)?;
+        let simulated: Vec<_> = results
+            .iter()
+            .map(|result| (f64::from(result.hour), cyclogenesis.central_pressure(result), result.relative_vorticity))
+            .collect();
+
+        let pairs: Vec<_> = observations
+            .iter()
+            .filter_map(|observation| {
+                let after = simulated.iter().position(|(hour, _, _)| *hour >= observation.hour)?;
+                let (hour_b, pressure_b, vorticity_b) = simulated[after];
+                let (hour_a, pressure_a, vorticity_a) = simulated[after.saturating_sub(1)];
+                if observation.hour < hour_a {
+                    return None;
+                }
+                let weight = if hour_b > hour_a { (observation.hour - hour_a) / (hour_b - hour_a) } else { 1.0 };
+                Some(VerificationPair {
+                    hour: observation.hour,
+                    simulated_pressure: pressure_a + weight * (pressure_b - pressure_a),
+                    observed_pressure: observation.central_pressure,
+                    simulated_vorticity: vorticity_a + weight * (vorticity_b - vorticity_a),
+                    observed_vorticity: observation.relative_vorticity,
+                })
+            })
+            .collect();
+        if pairs.is_empty() {
+            return Err(MeteoError::InvalidObservations("aucune observation dans la période simulée".to_string()));
+        }
+
+        let pressure: Vec<_> =
+            pairs.iter().filter_map(|pair| Some((pair.simulated_pressure, pair.observed_pressure?))).collect();
+        let vorticity: Vec<_> =
+            pairs.iter().filter_map(|pair| Some((pair.simulated_vorticity, pair.observed_vorticity?))).collect();
+        Ok(Self {
+            pressure: Scores::compute(&pressure),
+            vorticity: Scores::compute(&vorticity),
+            discarded: observations.len() - pairs.len(),
+            pairs,
+        })
+    }
+
+    /// Observations appariées, dans l'ordre du fichier
+    pub fn pairs(&self) -> &[VerificationPair] {
+        &self.pairs
+    }
+
+    /// Nombre d'observations hors de la période simulée
+    pub fn discarded(&self) -> usize {
+        self.discarded
+    }
+
+    /// Scores de la pression centrale (hPa), `None` sans pression observée
+    pub fn pressure(&self) -> Option<&Scores> {
+        self.pressure.as_ref()
+    }
+
+    /// Scores du tourbillon relatif (s⁻¹), `None` sans tourbillon observé
+    pub fn vorticity(&self) -> Option<&Scores> {
+        self.vorticity.as_ref()
+    }
+
+    /// Rapport de vérification : tableau des couples puis scores de chaque variable
+    pub fn to_table(&self, language: OutputLanguage, format: &NumberFormat) -> String {
+        let labels = match language {
+            OutputLanguage::French => ["Heure", "Pression sim. (hPa)", "Pression obs. (hPa)", "ζ simulé (10⁻⁵ s⁻¹)", "ζ observé (10⁻⁵ s⁻¹)"],
+            OutputLanguage::English => ["Hour", "Sim. pressure (hPa)", "Obs. pressure (hPa)", "Sim. ζ (10⁻⁵ s⁻¹)", "Obs. ζ (10⁻⁵ s⁻¹)"],
+        };
+        let header = format!("{:<5} | {:<20} | {:<20} | {:<20} | {}", labels[0], labels[1], labels[2], labels[3], labels[4]);
+        let cell = |value: Option<f64>| match value {
+            Some(value) => format.format_width(value, 20),
+            None => format!("{:>20}", "-"),
+        };
+
+        let mut table = format!(
+            "{}\n------|----------------------|----------------------|----------------------|----------------------\n",
+            header
+        );
+        for pair in &self.pairs {
+            table += &format!(
+                "{:>5} | {} | {} | {} | {}\n",
+                format.format(pair.hour),
+                format.format_width(pair.simulated_pressure, 20),
+                cell(pair.observed_pressure),
+                format.format_width(pair.simulated_vorticity * 1e5, 20),
+                cell(pair.observed_vorticity.map(|vorticity| vorticity * 1e5))
+            );
+        }
+
+        table += "\n";
+        for (scores, scale, variable) in [
+            (self.pressure, 1.0, Variable::Pressure),
+            (self.vorticity, 1e5, Variable::Vorticity),
+        ] {
+            table += &describe_scores(scores.as_ref(), scale, variable, language, format);
+            table += "\n";
+        }
+        if self.discarded > 0 {
+            table += &match language {
+                OutputLanguage::French => format!("{} observation(s) hors de la période simulée\n", self.discarded),
+                OutputLanguage::English => format!("{} observation(s) outside the simulated period\n", self.discarded),
+            };
+        }
+        table
+    }
+}
+
+/// Variable vérifiée
+#[derive(Clone, Copy)]
+enum Variable {
+    Pressure,
+    Vorticity,
+}
+
+/// Ligne des scores d'une variable, exprimés dans l'unité du tableau
+fn describe_scores(
+    scores: Option<&Scores>,
+    scale: f64,
+    variable: Variable,
+    language: OutputLanguage,
+    format: &NumberFormat,
+) -> String {
+    let (name, unit) = match (language, variable) {
+        (OutputLanguage::French, Variable::Pressure) => ("Pression centrale", "hPa"),
+        (OutputLanguage::French, Variable::Vorticity) => ("Tourbillon relatif", "10⁻⁵ s⁻¹"),
+        (OutputLanguage::English, Variable::Pressure) => ("Central pressure", "hPa"),
+        (OutputLanguage::English, Variable::Vorticity) => ("Relative vorticity", "10⁻⁵ s⁻¹"),
+    };
+    let Some(scores) = scores else {
+        return match language {
+            OutputLanguage::French => format!("{} : aucune observation", name),
+            OutputLanguage::English => format!("{}: no observation", name),
+        };
+    };
+    let correlation = scores.correlation.map_or_else(|| "-".to_string(), |correlation| format.format(correlation));
+    let (bias, rmse, mae) = (format.format(scores.bias * scale), format.format(scores.rmse * scale), format.format(scores.mae * scale));
+    match language {
+        OutputLanguage::French => format!(
+            "{} ({} observations) : biais {} {}, RMSE {} {}, MAE {} {}, corrélation {}",
+            name, scores.count, bias, unit, rmse, unit, mae, unit, correlation
+        ),
+        OutputLanguage::English => format!(
+            "{} ({} observations): bias {} {}, RMSE {} {}, MAE {} {}, correlation {}",
+            name, scores.count, bias, unit, rmse, unit, mae, unit, correlation
+        ),
+    }
+}
+
+/// CRPS empirique d'un ensemble de valeurs pour une observation, dans l'unité des valeurs
+pub fn crps(members: &[f64], observed: f64) -> f64 {
+    let members: Vec<_> = members.iter().map(|member| (*member, 1.0)).collect();
+    weighted_crps(&members, observed)
+}
+
+/// CRPS d'un ensemble de valeurs pondérées (valeur, poids) pour une observation
+pub fn weighted_crps(members: &[(f64, f64)], observed: f64) -> f64 {
+    let total = members.iter().map(|(_, weight)| weight).sum::<f64>();
+    let error = members.iter().map(|(member, weight)| weight * (member - observed).abs()).sum::<f64>() / total;
+    let spread = members
+        .iter()
+        .map(|(a, wa)| members.iter().map(|(b, wb)| wa * wb * (a - b).abs()).sum::<f64>())
+        .sum::<f64>()
+        / (total * total);
+    error - 0.5 * spread
+}
+
+/// Rang d'une observation : nombre de membres strictement inférieurs
+pub fn rank(members: &[f64], observed: f64) -> usize {
+    members.iter().filter(|member| **member < observed).count()
+}
+
+/// Scores probabilistes d'une variable
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProbabilisticScores {
+    pub count: usize,
+    pub crps: f64,                  // CRPS moyen, dans l'unité de la variable
+    pub rank_histogram: Vec<usize>,  // Nombre d'observations de chaque rang, de 0 au nombre de membres
+}
+
+impl ProbabilisticScores {
+    /// Scores de cas (valeurs des membres, observé), `None` sans cas
+    pub fn compute(cases: &[(Vec<f64>, f64)], members: usize) -> Option<Self> {
+        let cases: Vec<_> =
+            cases.iter().map(|(values, observed)| (values.iter().map(|value| (*value, 1.0)).collect(), *observed)).collect();
+        Self::compute_weighted(&cases, members)
+    }
+
+    /// Scores de cas (valeurs et poids des membres, observé) ; le CRPS est
+    /// pondéré, l'histogramme compte les observations sans poids
+    pub fn compute_weighted(cases: &[(Vec<(f64, f64)>, f64)], members: usize) -> Option<Self> {
+        if cases.is_empty() {
+            return None;
+        }
+        let mut rank_histogram = vec![0; members + 1];
+        for (values, observed) in cases {
+            let values: Vec<_> = values.iter().map(|(value, _)| *value).collect();
+            rank_histogram[rank(&values, *observed).min(members)] += 1;
+        }
+        let crps =
+            cases.iter().map(|(values, observed)| weighted_crps(values, *observed)).sum::<f64>() / cases.len() as f64;
+        Some(Self { count: cases.len(), crps, rank_histogram })
+    }
+}
+
+/// Score de Brier d'un événement à seuil
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BrierScore {
+    pub count: usize,
+    pub score: f64,               // Écart quadratique moyen probabilité − occurrence
+    pub observed_frequency: f64,  // Fréquence observée de l'événement
+}
+
+impl BrierScore {
+    /// Score de cas (probabilité prévue, événement observé), `None` sans cas
+    pub fn compute(cases: &[(f64, bool)]) -> Option<Self> {
+        if cases.is_empty() {
+            return None;
+        }
+        let count = cases.len() as f64;
+        let outcome = |occurred: bool| if occurred { 1.0 } else { 0.0 };
+        let score = cases.iter().map(|(probability, occurred)| (probability - outcome(*occurred)).powi(2)).sum::<f64>() / count;
+        let observed_frequency = cases.iter().map(|(_, occurred)| outcome(*occurred)).sum::<f64>() / count;
+        Some(Self { count: cases.len(), score, observed_frequency })
+    }
+}
+
+/// Vérification d'un ensemble par une série observée
+#[derive(Debug, Clone)]
+pub struct EnsembleVerification {
+    members: usize,
+    pressure: Option<ProbabilisticScores>,
+    vorticity: Option<ProbabilisticScores>,
+    brier: Vec<(Threshold, Option<BrierScore>)>,
+    discarded: usize,
+}
+
+impl EnsembleVerification {
+    /// Apparie les membres de l'ensemble aux observations et calcule le score de
+    /// Brier de chaque événement ; le vent n'étant pas observé, ses événements
+    /// restent sans score. Seules les heures simulées par tous les membres sont
+    /// vérifiées, celles d'un ensemble décalé commençant au dernier départ.
+    pub fn compute<T: Float>(
+        ensemble: &Ensemble<T>,
+        observations: &[ObservedState],
+        thresholds: &[Threshold],
+    ) -> Result<Self, MeteoError> {
+        let members = ensemble.members().len();
+        // Valeurs des membres à une heure, si tous la simulent
+        let values = |variable: EnsembleVariable, hour: f64| {
+            Some(ensemble.weighted_values(variable, hour)).filter(|values| !values.is_empty() && values.len() == members)
+        };
+
+        let paired: Vec<_> =
+            observations.iter().filter(|observation| values(EnsembleVariable::Pressure, observation.hour).is_some()).collect();
+        if paired.is_empty() {
+            return Err(MeteoError::InvalidObservations("aucune observation dans la période simulée".to_string()));
+        }
+
+        let cases = |variable: EnsembleVariable, observed: &[(f64, f64)]| -> Vec<(Vec<(f64, f64)>, f64)> {
+            observed.iter().filter_map(|(hour, value)| Some((values(variable, *hour)?, *value))).collect()
+        };
+        let observed_pressure: Vec<_> =
+            paired.iter().filter_map(|observation| Some((observation.hour, observation.central_pressure?))).collect();
+        let observed_vorticity: Vec<_> =
+            paired.iter().filter_map(|observation| Some((observation.hour, observation.relative_vorticity?))).collect();
+        let observed_deepening = observed_deepening(&observed_pressure);
+
+        let brier = thresholds
+            .iter()
+            .map(|threshold| {
+                let observed: &[(f64, f64)] = match threshold.variable {
+                    EnsembleVariable::Pressure => &observed_pressure,
+                    EnsembleVariable::Vorticity => &observed_vorticity,
+                    EnsembleVariable::Deepening => &observed_deepening,
+                    EnsembleVariable::Wind => &[],
+                };
+                let cases: Vec<_> = cases(threshold.variable, observed)
+                    .into_iter()
+                    .filter_map(|(values, value)| Some((threshold.weighted_probability(&values)?, threshold.is_met(value))))
+                    .collect();
+                (*threshold, BrierScore::compute(&cases))
+            })
+            .collect();
+
+        Ok(Self {
+            members,
+            pressure: ProbabilisticScores::compute_weighted(&cases(EnsembleVariable::Pressure, &observed_pressure), members),
+            vorticity: ProbabilisticScores::compute_weighted(&cases(EnsembleVariable::Vorticity, &observed_vorticity), members),
+            brier,
+            discarded: observations.len() - paired.len(),
+        })
+    }
+
+    /// Nombre d'observations hors de la période simulée
+    pub fn discarded(&self) -> usize {
+        self.discarded
+    }
+
+    /// Scores de la pression centrale (hPa), `None` sans pression observée
+    pub fn pressure(&self) -> Option<&ProbabilisticScores> {
+        self.pressure.as_ref()
+    }
+
+    /// Scores du tourbillon relatif (s⁻¹), `None` sans tourbillon observé
+    pub fn vorticity(&self) -> Option<&ProbabilisticScores> {
+        self.vorticity.as_ref()
+    }
+
+    /// Score de Brier de chaque événement, `None` sans observation de sa grandeur
+    pub fn brier(&self) -> &[(Threshold, Option<BrierScore>)] {
+        &self.brier
+    }
+
+    /// Rapport de vérification : CRPS et histogramme des rangs de chaque variable,
+    /// puis score de Brier de chaque événement
+    pub fn to_table(&self, language: OutputLanguage, format: &NumberFormat) -> String {
+        let mut table = match language {
+            OutputLanguage::French => format!("Vérification de l'ensemble ({} membres)\n\n", self.members),
+            OutputLanguage::English => format!("Ensemble verification ({} members)\n\n", self.members),
+        };
+        for (scores, scale, variable) in [
+            (&self.pressure, 1.0, Variable::Pressure),
+            (&self.vorticity, 1e5, Variable::Vorticity),
+        ] {
+            table += &describe_probabilistic_scores(scores.as_ref(), scale, variable, language, format);
+            table += "\n";
+        }
+        for (threshold, score) in &self.brier {
+            table += &match (language, score) {
+                (OutputLanguage::French, Some(score)) => format!(
+                    "Brier {} ({} observations) : score {}, fréquence observée {}",
+                    threshold, score.count, format.format(score.score), format.format(score.observed_frequency)
+                ),
+                (OutputLanguage::English, Some(score)) => format!(
+                    "Brier {} ({} observations): score {}, observed frequency {}",
+                    threshold, score.count, format.format(score.score), format.format(score.observed_frequency)
+                ),
+                (OutputLanguage::French, None) => format!("Brier {} : aucune observation", threshold),
+                (OutputLanguage::English, None) => format!("Brier {}: no observation", threshold),
+            };
+            table += "\n";
+        }
+        if self.discarded > 0 {
+            table += &match language {
+                OutputLanguage::French => format!("{} observation(s) hors de la période simulée\n", self.discarded),
+                OutputLanguage::English => format!("{} observation(s) outside the simulated period\n", self.discarded),
+            };
+        }
+        table
+    }
+}
+
+/// Creusement observé (heure, hPa/24 h) d'une série (heure, pression centrale),
+/// sur une fenêtre d'au plus 24 heures ramenée à 24 h
+fn observed_deepening(pressures: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    let mut pressures = pressures.to_vec();
+    pressures.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let window = f64::from(DEEPENING_WINDOW);
+    pressures
+        .iter()
+        .enumerate()
+        .skip(1)
+        .filter_map(|(index, (hour, pressure))| {
+            let (start_hour, start_pressure) = pressures[..index].iter().find(|(start, _)| hour - start <= window)?;
+            let elapsed = hour - start_hour;
+            (elapsed > 0.0).then(|| (*hour, (start_pressure - pressure) * window / elapsed))
+        })
+        .collect()
+}
+
+/// Ligne du CRPS et de l'histogramme des rangs d'une variable, dans l'unité du tableau
+fn describe_probabilistic_scores(
+    scores: Option<&ProbabilisticScores>,
+    scale: f64,
+    variable: Variable,
+    language: OutputLanguage,
+    format: &NumberFormat,
+) -> String {
+    let (name, unit) = match (language, variable) {
+        (OutputLanguage::French, Variable::Pressure) => ("Pression centrale", "hPa"),
+        (OutputLanguage::French, Variable::Vorticity) => ("Tourbillon relatif", "10⁻⁵ s⁻¹"),
+        (OutputLanguage::English, Variable::Pressure) => ("Central pressure", "hPa"),
+        (OutputLanguage::English, Variable::Vorticity) => ("Relative vorticity", "10⁻⁵ s⁻¹"),
+    };
+    let Some(scores) = scores else {
+        return match language {
+            OutputLanguage::French => format!("{} : aucune observation", name),
+            OutputLanguage::English => format!("{}: no observation", name),
+        };
+    };
+    let histogram: Vec<_> = scores.rank_histogram.iter().map(usize::to_string).collect();
+    let (crps, histogram) = (format.format(scores.crps * scale), histogram.join(", "));
+    match language {
+        OutputLanguage::French => format!(
+            "{} ({} observations) : CRPS {} {}, histogramme des rangs [{}]",
+            name, scores.count, crps, unit, histogram
+        ),
+        OutputLanguage::English => format!(
+            "{} ({} observations): CRPS {} {}, rank histogram [{}]",
+            name, scores.count, crps, unit, histogram
+        ),
+    }
+}
@@ -0,0 +1,100 @@
+//! Bilan de tourbillon des perturbations
+//!
+//! La tendance du tourbillon relatif se décompose selon l'équation du tourbillon
+//! en quatre contributions :
+//! - l'étirement −(ζ + f)·∇·V par la convergence des basses couches ;
+//! - le basculement du tourbillon horizontal du cisaillement ΔU/H par le
+//!   gradient de vitesse verticale w/R, réduit par l'inclinaison des anomalies ;
+//! - l'advection −β·v du tourbillon planétaire par le vent tourbillonnaire
+//!   v = ζ·R, nulle sur le plan f ;
+//! - le frottement d'Ekman −ζ·√(K·|f|/2)/H de la couche limite, la viscosité
+//!   K suivant la loi de traînée de la surface lorsqu'elle est donnée et le
+//!   taux suivant la hauteur d'une couche limite évolutive.
+
+use crate::{BaroclinicCyclogenesis, DevelopmentResult, NumberFormat, OutputLanguage};
+
+/// Viscosité turbulente de la couche limite (m²/s)
+const EDDY_VISCOSITY: f64 = 10.0;
+
+/// Contributions à la tendance du tourbillon relatif d'un pas de temps (s⁻²)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VorticityBudget {
+    pub hour: u32,
+    pub stretching: f64,  // Étirement par la convergence
+    pub tilting: f64,     // Basculement du tourbillon horizontal
+    pub advection: f64,   // Advection du tourbillon planétaire
+    pub friction: f64,    // Frottement d'Ekman
+}
+
+impl VorticityBudget {
+    /// Tendance totale du tourbillon relatif (s⁻²)
+    pub fn tendency(&self) -> f64 {
+        self.stretching + self.tilting + self.advection + self.friction
+    }
+}
+
+impl BaroclinicCyclogenesis {
+    /// Bilan de tourbillon associé au résultat du pas `result.hour`
+    pub fn vorticity_budget(&self, result: &DevelopmentResult) -> VorticityBudget {
+        let surface = self.surface_anomaly();
+        let altitude = self.altitude_anomaly();
+        let coriolis = self.coriolis_parameter(self.latitude());
+        let vorticity = result.relative_vorticity;
+        let radius = self.mean_radius();
+
+        let intensity = (surface.intensity + altitude.intensity) / 2.0;
+        let shear = self.thermal_wind_shear().map_or(0.0, |shear| shear * intensity);
+        let depth = (altitude.position.altitude - surface.position.altitude).abs();
+        let tilting = if depth > 0.0 {
+            shear / depth * result.vertical_velocity / radius * self.coupling_efficiency(result.hour)
+        } else {
+            0.0
+        };
+
+        let wind = self.surface_wind(result);
+        let eddy_viscosity = surface.position.drag_law().map_or(EDDY_VISCOSITY, |law| law.eddy_viscosity(wind));
+
+        VorticityBudget {
+            hour: result.hour,
+            stretching: -(vorticity + coriolis) * result.low_level_divergence,
+            tilting,
+            advection: -self.beta_parameter() * vorticity * radius,
+            friction: -vorticity * (eddy_viscosity * coriolis.abs() / 2.0).sqrt() / self.tropopause_height * self.pumping_scale(),
+        }
+    }
+}
+
+/// Unité d'affichage des tendances (s⁻²)
+const TENDENCY_UNIT: f64 = 1e-11;
+
+/// Tableau du bilan de tourbillon, en 10⁻¹¹ s⁻²
+pub fn to_table(budgets: &[VorticityBudget], language: OutputLanguage, format: &NumberFormat) -> String {
+    let (title, header) = match language {
+        OutputLanguage::French => (
+            "Tendance du tourbillon relatif (10⁻¹¹ s⁻²)",
+            "Heure | Étirement            | Basculement          | Advection            | Frottement           | Total",
+        ),
+        OutputLanguage::English => (
+            "Relative vorticity tendency (10⁻¹¹ s⁻²)",
+            "Hour  | Stretching           | Tilting              | Advection            | Friction             | Total",
+        ),
+    };
+    let cell = |value: f64| format.format_width(value / TENDENCY_UNIT, 20);
+
+    let mut table = format!(
+        "{}\n{}\n------|----------------------|----------------------|----------------------|----------------------|----------------------\n",
+        title, header
+    );
+    for budget in budgets {
+        table += &format!(
+            "{:4} | {} | {} | {} | {} | {}\n",
+            budget.hour,
+            cell(budget.stretching),
+            cell(budget.tilting),
+            cell(budget.advection),
+            cell(budget.friction),
+            cell(budget.tendency())
+        );
+    }
+    table
+}
@@ -0,0 +1,154 @@
+//! Relance automatique d'un scénario à chaque modification de son fichier
+//!
+//! Le fichier est surveillé par sa date de modification, ce qui fonctionne aussi
+//! avec les éditeurs qui remplacent le fichier au lieu de le réécrire. Après chaque
+//! calcul, les pics d'intensité sont comparés à ceux du calcul précédent.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::{DevelopmentResult, MeteoError, NumberFormat, OutputLanguage, Scenario};
+
+/// Intervalle entre deux vérifications du fichier
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Valeurs extrêmes (en valeur absolue) d'une simulation
+#[derive(Debug, Clone, Copy)]
+struct PeakIntensity {
+    vertical_velocity: (f64, u32),   // (cm/s, heure)
+    relative_vorticity: (f64, u32),  // (10⁻⁵ s⁻¹, heure)
+}
+
+impl PeakIntensity {
+    fn from_results(results: &[DevelopmentResult]) -> Option<Self> {
+        let peak = |value: fn(&DevelopmentResult) -> f64| {
+            results
+                .iter()
+                .max_by(|a, b| value(a).abs().total_cmp(&value(b).abs()))
+                .map(|result| (value(result), result.hour))
+        };
+
+        Some(Self {
+            vertical_velocity: peak(|result| result.vertical_velocity * 100.0)?,
+            relative_vorticity: peak(|result| result.relative_vorticity * 1e5)?,
+        })
+    }
+}
+
+/// Surveille un scénario et le relance à chaque modification, sans fin
+pub fn run(path: &Path, language: OutputLanguage, format: &NumberFormat) -> ! {
+    let mut watcher = Watcher::new(path);
+    loop {
+        match watcher.poll(language, format) {
+            Some(Ok(text)) => print!("{}", text),
+            Some(Err(error)) => eprintln!("{}", error.message(language)),
+            None => {}
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Fichier de scénario surveillé et pics du dernier calcul réussi
+pub struct Watcher {
+    path: PathBuf,
+    last_modified: Option<Option<SystemTime>>,  // `None` avant la première vérification
+    previous: Option<PeakIntensity>,
+}
+
+impl Watcher {
+    pub fn new(path: &Path) -> Self {
+        Self { path: path.to_path_buf(), last_modified: None, previous: None }
+    }
+
+    /// Relance le scénario si son fichier a changé depuis l'appel précédent
+    ///
+    /// Rend le texte à afficher (résultats et pics comparés au calcul réussi
+    /// précédent), ou `None` si le fichier n'a pas changé.
+    pub fn poll(&mut self, language: OutputLanguage, format: &NumberFormat) -> Option<Result<String, MeteoError>> {
+        let modified = fs::metadata(&self.path).and_then(|metadata| metadata.modified()).ok();
+        if self.last_modified == Some(modified) {
+            return None;
+        }
+        self.last_modified = Some(modified);
+        log_event!(info, path = %self.path.display(), "scénario modifié");
+
+        Some(simulate(&self.path, language, format).map(|(mut text, peak)| {
+            text.push_str(&peaks(peak, self.previous, language, format));
+            self.previous = peak;
+            text
+        }))
+    }
+}
+
+/// Relit le scénario et rend le texte de ses résultats et ses pics
+fn simulate(
+    path: &Path,
+    language: OutputLanguage,
+    format: &NumberFormat,
+) -> Result<(String, Option<PeakIntensity>), MeteoError> {
+    let scenario = Scenario::load(path)?;
+    let mut cyclogenesis = scenario.to_cyclogenesis()?;
+    let stability = cyclogenesis.charney_stern();
+    let regime = cyclogenesis.dynamical_regime();
+    let results = cyclogenesis.simulate_interaction(scenario.time_steps)?;
+
+    let mut text = format!("\n{}\n", language.simulation_heading(scenario.latitude));
+    if let Some(regime) = regime {
+        text.push_str(&format!("{}\n", regime.describe(language, format)));
+    }
+    if let Some(check) = stability {
+        text.push_str(&format!("{}\n", check.describe(language, format)));
+    }
+    text.push_str(&format!("{}\n{}\n", language.table_header(), language.table_rule()));
+    for result in &results {
+        text.push_str(&format!("{}\n", result.to_string_with(format)));
+    }
+
+    Ok((text, PeakIntensity::from_results(&results)))
+}
+
+/// Lignes des pics, avec leur écart au calcul précédent
+fn peaks(
+    peak: Option<PeakIntensity>,
+    previous: Option<PeakIntensity>,
+    language: OutputLanguage,
+    format: &NumberFormat,
+) -> String {
+    let Some(peak) = peak else {
+        return String::new();
+    };
+
+    let (velocity_label, vorticity_label, at, versus) = match language {
+        OutputLanguage::French => (
+            "Pic de vitesse verticale (cm/s) :",
+            "Pic de tourbillon relatif (10⁻⁵ s⁻¹) :",
+            "à",
+            "par rapport au calcul précédent",
+        ),
+        OutputLanguage::English => (
+            "Peak vertical velocity (cm/s):",
+            "Peak relative vorticity (10⁻⁵ s⁻¹):",
+            "at",
+            "vs previous run",
+        ),
+    };
+
+    let line = |label: &str, (value, hour): (f64, u32), before: Option<(f64, u32)>| {
+        let change = before
+            .map(|(before, _)| {
+                let delta = value - before;
+                let sign = if delta >= 0.0 { "+" } else { "" };
+                format!(" ({}{} {})", sign, format.format(delta), versus)
+            })
+            .unwrap_or_default();
+        format!("{} {} {} {} h{}\n", label, format.format(value), at, hour, change)
+    };
+
+    format!(
+        "\n{}{}",
+        line(velocity_label, peak.vertical_velocity, previous.map(|p| p.vertical_velocity)),
+        line(vorticity_label, peak.relative_vorticity, previous.map(|p| p.relative_vorticity))
+    )
+}
@@ -0,0 +1,173 @@
+//! Hauteur significative des vagues levées par le vent de surface
+//!
+//! Les relations paramétriques de croissance de JONSWAP (Hasselmann et al.,
+//! 1973) donnent, en variables adimensionnées par le vent U et la gravité g :
+//!
+//! - à fetch limité, g·Hs/U² = 0,0016·(g·F/U²)^½ et g·Tp/U = 0,286·(g·F/U²)^⅓ ;
+//! - à durée limitée, le fetch équivalent déduit de g·t/U = 68,8·(g·F/U²)^⅔ ;
+//! - en mer pleinement développée (Pierson–Moskowitz), g·Hs/U² = 0,243 et
+//!   g·Tp/U = 8,13.
+//!
+//! La mer retenue est la plus faible des trois. Le vent est le vent maximal de
+//! la circulation de surface ; il souffle depuis le début de la simulation,
+//! chaque heure comptant pour une heure entière.
+
+use crate::{BaroclinicCyclogenesis, DevelopmentResult, MeteoError, NumberFormat, OutputLanguage};
+
+/// Accélération de la pesanteur (m/s²)
+const GRAVITY: f64 = 9.81;
+
+/// Fetch adimensionné g·F/U² de la mer pleinement développée
+const FULLY_DEVELOPED_FETCH: f64 = 2.3e4;
+
+/// Paramètres de la croissance des vagues
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WaveModel {
+    fetch: f64,  // Distance sur laquelle souffle le vent (m)
+}
+
+impl Default for WaveModel {
+    /// Fetch de 300 km, de l'ordre de la taille d'une dépression
+    fn default() -> Self {
+        Self { fetch: 3.0e5 }
+    }
+}
+
+impl WaveModel {
+    /// Modèle de croissance pour un fetch donné (m)
+    pub fn new(fetch: f64) -> Result<Self, MeteoError> {
+        if !(fetch > 0.0 && fetch.is_finite()) {
+            return Err(MeteoError::InvalidScenario(format!("fetch invalide: {} m", fetch)));
+        }
+        Ok(Self { fetch })
+    }
+
+    /// Fetch (m)
+    pub fn fetch(&self) -> f64 {
+        self.fetch
+    }
+
+    /// État de la mer pour un vent (m/s) soufflant depuis une durée donnée (s)
+    pub fn sea_state(&self, wind: f64, duration: f64) -> (f64, f64, WaveGrowth) {
+        if !(wind > 0.0 && duration > 0.0) {
+            return (0.0, 0.0, WaveGrowth::Duration);
+        }
+        let fetch = GRAVITY * self.fetch / (wind * wind);
+        let equivalent = (GRAVITY * duration / wind / 68.8).powf(1.5);
+        let (fetch, growth) = [
+            (fetch, WaveGrowth::Fetch),
+            (equivalent, WaveGrowth::Duration),
+            (FULLY_DEVELOPED_FETCH, WaveGrowth::FullyDeveloped),
+        ]
+        .into_iter()
+        .min_by(|a, b| a.0.total_cmp(&b.0))
+        .unwrap_or((fetch, WaveGrowth::Fetch));
+
+        let (height, period) = match growth {
+            WaveGrowth::FullyDeveloped => (0.243, 8.13),
+            _ => (0.0016 * fetch.sqrt(), 0.286 * fetch.cbrt()),
+        };
+        (height * wind * wind / GRAVITY, period * wind / GRAVITY, growth)
+    }
+}
+
+/// Facteur limitant la croissance des vagues
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaveGrowth {
+    Fetch,
+    Duration,
+    FullyDeveloped,
+}
+
+impl WaveGrowth {
+    /// Libellé du facteur limitant
+    pub fn label(self, language: OutputLanguage) -> &'static str {
+        match (language, self) {
+            (OutputLanguage::French, WaveGrowth::Fetch) => "limitée par le fetch",
+            (OutputLanguage::French, WaveGrowth::Duration) => "limitée par la durée",
+            (OutputLanguage::French, WaveGrowth::FullyDeveloped) => "mer pleinement développée",
+            (OutputLanguage::English, WaveGrowth::Fetch) => "fetch-limited",
+            (OutputLanguage::English, WaveGrowth::Duration) => "duration-limited",
+            (OutputLanguage::English, WaveGrowth::FullyDeveloped) => "fully developed sea",
+        }
+    }
+}
+
+/// Vagues levées par le vent à une heure de la simulation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SeaState {
+    pub hour: u32,
+    pub wind: f64,                // Vent maximal de surface (m/s)
+    pub significant_height: f64,  // Hauteur significative Hs (m)
+    pub peak_period: f64,         // Période du pic spectral Tp (s)
+    pub growth: WaveGrowth,
+}
+
+impl BaroclinicCyclogenesis {
+    /// Estime la hauteur significative des vagues à chaque heure d'une simulation
+    pub fn sea_states(&self, results: &[DevelopmentResult], model: &WaveModel) -> Vec<SeaState> {
+        let Some(first) = results.first() else {
+            return Vec::new();
+        };
+        results
+            .iter()
+            .map(|result| {
+                let wind = self.surface_wind(result);
+                let duration = (f64::from(result.hour) - f64::from(first.hour) + 1.0) * 3600.0;
+                let (significant_height, peak_period, growth) = model.sea_state(wind, duration);
+                SeaState { hour: result.hour, wind, significant_height, peak_period, growth }
+            })
+            .collect()
+    }
+}
+
+/// Phrase signalant la mer la plus forte
+pub fn describe_peak(states: &[SeaState], language: OutputLanguage, format: &NumberFormat) -> String {
+    let Some(peak) = states.iter().max_by(|a, b| a.significant_height.total_cmp(&b.significant_height)) else {
+        return match language {
+            OutputLanguage::French => "Aucun état de la mer estimé".to_string(),
+            OutputLanguage::English => "No sea state estimated".to_string(),
+        };
+    };
+    let (height, period) = (format.format(peak.significant_height), format.format(peak.peak_period));
+    match language {
+        OutputLanguage::French => {
+            format!("Hauteur significative maximale : {} m (période {} s) à l'heure {}", height, period, peak.hour)
+        }
+        OutputLanguage::English => {
+            format!("Peak significant wave height: {} m (period {} s) at hour {}", height, period, peak.hour)
+        }
+    }
+}
+
+/// Tableau du vent, de la hauteur significative, de la période et du facteur
+/// limitant, suivi de la mer la plus forte
+pub fn to_table(states: &[SeaState], language: OutputLanguage, format: &NumberFormat) -> String {
+    let unit = format.wind_unit().symbol();
+    let header = match language {
+        OutputLanguage::French => format!(
+            "Heure | {:<20} | Hauteur Hs (m)       | Période Tp (s)       | Croissance",
+            format!("Vent moyen ({})", unit)
+        ),
+        OutputLanguage::English => format!(
+            "Hour  | {:<20} | Height Hs (m)        | Period Tp (s)        | Growth",
+            format!("Mean wind ({})", unit)
+        ),
+    };
+
+    let mut table = format!(
+        "{}\n------|----------------------|----------------------|----------------------|----------------------\n",
+        header
+    );
+    for state in states {
+        table += &format!(
+            "{:4} | {} | {} | {} | {}\n",
+            state.hour,
+            format.format_wind_width(state.wind, 20),
+            format.format_width(state.significant_height, 20),
+            format.format_width(state.peak_period, 20),
+            state.growth.label(language)
+        );
+    }
+    table + &format!("\n{}\n", describe_peak(states, language, format))
+}
@@ -0,0 +1,276 @@
+//! Champ de vent reconstruit autour du centre de la dépression
+//!
+//! Le vent est supposé tangentiel et axisymétrique. Pour le profil gaussien
+//! ζ(r) = ζ₀·exp(−r²/R²), la circulation donne v(r) = ζ₀R²/(2r)·(1 − exp(−r²/R²)) ;
+//! le disque uniforme donne le tourbillon de Rankine, v = ζ₀r/2 à l'intérieur et
+//! ζ₀R²/(2r) à l'extérieur. Une ellipse de Kirchhoff est représentée par le
+//! tourbillon de Rankine de même vent maximal, de rayon 2R/(1 + a/b).
+//!
+//! Le tourbillon central ζ₀ est celui de chaque pas de la simulation, le rayon
+//! celui des anomalies. La grille régulière en latitude et longitude suit le
+//! centre ; les distances sont celles du plan tangent en ce centre. Le vent de
+//! la circulation est celui du repère lié à la dépression ; le repère terrestre
+//! y ajoute la vitesse de déplacement du système (voir `frame`).
+
+use crate::beaufort::Beaufort;
+use crate::frame::{self, Frame};
+use crate::stability::EARTH_RADIUS;
+use crate::{AnomalyShape, BaroclinicCyclogenesis, DevelopmentResult, Float, MeteoError, NumberFormat, OutputLanguage};
+
+/// Plus grande demi-largeur de la grille (°)
+const MAX_HALF_WIDTH: f64 = 30.0;
+
+/// Distance au centre du vent maximal du tourbillon gaussien, en rayons
+const GAUSSIAN_PEAK: f64 = 1.120_906_420;
+
+/// Grille régulière centrée sur la dépression
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindGrid {
+    half_width: f64,  // Demi-largeur en latitude et en longitude (°)
+    spacing: f64,     // Pas de la grille (°)
+}
+
+impl Default for WindGrid {
+    fn default() -> Self {
+        Self { half_width: 8.0, spacing: 0.5 }
+    }
+}
+
+impl WindGrid {
+    /// Crée une grille de demi-largeur `half_width` et de pas `spacing` (°)
+    pub fn new(half_width: f64, spacing: f64) -> Result<Self, MeteoError> {
+        if !(half_width > 0.0 && half_width <= MAX_HALF_WIDTH) {
+            return Err(MeteoError::InvalidGrid(format!("demi-largeur {}° hors de ]0, {}]", half_width, MAX_HALF_WIDTH)));
+        }
+        if !(spacing > 0.0 && spacing <= half_width) {
+            return Err(MeteoError::InvalidGrid(format!("pas {}° hors de ]0, {}]", spacing, half_width)));
+        }
+        Ok(Self { half_width, spacing })
+    }
+
+    /// Demi-largeur de la grille (°)
+    pub fn half_width(&self) -> f64 {
+        self.half_width
+    }
+
+    /// Pas de la grille (°)
+    pub fn spacing(&self) -> f64 {
+        self.spacing
+    }
+
+    /// Nombre de points sur chaque axe
+    pub fn size(&self) -> usize {
+        2 * self.points_per_side() + 1
+    }
+
+    fn points_per_side(&self) -> usize {
+        (self.half_width / self.spacing).round() as usize
+    }
+
+    /// Écarts au centre des points d'un axe, par valeurs croissantes (°)
+    fn offsets(&self) -> impl Iterator<Item = f64> + '_ {
+        let side = self.points_per_side() as f64;
+        (0..self.size()).map(move |index| (index as f64 - side) * self.spacing)
+    }
+}
+
+/// Vent horizontal sur la grille à une heure de la simulation
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindField {
+    pub hour: u32,
+    pub center_latitude: f64,   // Latitude du centre (°)
+    pub center_longitude: f64,  // Longitude du centre (°)
+    frame: Frame,               // Repère du vent
+    latitudes: Vec<f64>,        // Latitudes des lignes, croissantes (°)
+    longitudes: Vec<f64>,       // Longitudes des colonnes, dans [−180, 180[ (°)
+    u: Vec<f64>,                // Composante zonale (m/s), ligne par ligne
+    v: Vec<f64>,                // Composante méridienne (m/s), ligne par ligne
+}
+
+impl WindField {
+    /// Latitudes des lignes de la grille (°)
+    pub fn latitudes(&self) -> &[f64] {
+        &self.latitudes
+    }
+
+    /// Longitudes des colonnes de la grille (°)
+    pub fn longitudes(&self) -> &[f64] {
+        &self.longitudes
+    }
+
+    /// Repère du vent : terrestre ou lié à la dépression
+    pub fn frame(&self) -> Frame {
+        self.frame
+    }
+
+    /// Composante zonale (m/s), rangée par latitude puis par longitude
+    pub fn u(&self) -> &[f64] {
+        &self.u
+    }
+
+    /// Composante méridienne (m/s), rangée par latitude puis par longitude
+    pub fn v(&self) -> &[f64] {
+        &self.v
+    }
+
+    /// Vent (u, v) au point de ligne `row` et de colonne `column`
+    pub fn wind(&self, row: usize, column: usize) -> Option<(f64, f64)> {
+        if column >= self.longitudes.len() {
+            return None;
+        }
+        let index = row * self.longitudes.len() + column;
+        Some((*self.u.get(index)?, *self.v.get(index)?))
+    }
+
+    /// Plus forte vitesse du vent sur la grille (m/s)
+    pub fn max_speed(&self) -> f64 {
+        self.u.iter().zip(&self.v).map(|(u, v)| u.hypot(*v)).fold(0.0, f64::max)
+    }
+}
+
+impl<T: Float> BaroclinicCyclogenesis<T> {
+    /// Vent maximal de la circulation de surface à l'heure d'un résultat (m/s)
+    ///
+    /// C'est le maximum du vent tangentiel, atteint à une distance fixe du
+    /// centre ; il ne dépend pas de la résolution d'une grille.
+    pub fn surface_wind(&self, result: &DevelopmentResult<T>) -> f64 {
+        peak_wind(self.surface_anomaly().structure.shape(), self.mean_radius(), result.relative_vorticity.to_f64())
+    }
+
+    /// Force de Beaufort du vent de surface à l'heure d'un résultat
+    pub fn beaufort(&self, result: &DevelopmentResult<T>) -> Beaufort {
+        Beaufort::from_speed(self.surface_wind(result))
+    }
+}
+
+impl BaroclinicCyclogenesis {
+    /// Reconstruit le vent autour du centre à l'heure d'un résultat, dans le
+    /// repère lié à la dépression
+    ///
+    /// Sans position dans le résultat (plan f), le centre est la position
+    /// courante du système.
+    pub fn wind_field(&self, result: &DevelopmentResult, grid: &WindGrid) -> WindField {
+        let center_latitude = result.latitude.unwrap_or_else(|| self.latitude());
+        let center_longitude = result.longitude.unwrap_or_else(|| self.longitude());
+        let (shape, radius) = (self.surface_anomaly().structure.shape(), self.mean_radius());
+
+        let latitudes: Vec<_> = grid.offsets().map(|offset| center_latitude + offset).collect();
+        let longitudes: Vec<_> = grid
+            .offsets()
+            .map(|offset| (center_longitude + offset + 180.0).rem_euclid(360.0) - 180.0)
+            .collect();
+        let scale_x = EARTH_RADIUS * center_latitude.to_radians().cos();
+
+        let mut u = Vec::with_capacity(grid.size() * grid.size());
+        let mut v = Vec::with_capacity(grid.size() * grid.size());
+        for dy in grid.offsets().map(|offset| EARTH_RADIUS * offset.to_radians()) {
+            for dx in grid.offsets().map(|offset| scale_x * offset.to_radians()) {
+                let distance = dx.hypot(dy);
+                if distance == 0.0 {
+                    u.push(0.0);
+                    v.push(0.0);
+                    continue;
+                }
+                let speed = tangential_wind(shape, radius, result.relative_vorticity, distance);
+                u.push(-speed * dy / distance);
+                v.push(speed * dx / distance);
+            }
+        }
+
+        WindField {
+            hour: result.hour,
+            center_latitude,
+            center_longitude,
+            frame: Frame::StormRelative,
+            latitudes,
+            longitudes,
+            u,
+            v,
+        }
+    }
+
+    /// Reconstruit le vent relatif à la dépression à chaque heure d'une simulation
+    pub fn wind_fields(&self, results: &[DevelopmentResult], grid: &WindGrid) -> Vec<WindField> {
+        results.iter().map(|result| self.wind_field(result, grid)).collect()
+    }
+
+    /// Reconstruit le vent à chaque heure d'une simulation dans le repère demandé
+    ///
+    /// Dans le repère terrestre, la vitesse de déplacement du système, estimée sur
+    /// la trajectoire des résultats, s'ajoute au vent de la circulation.
+    pub fn wind_fields_in(&self, results: &[DevelopmentResult], grid: &WindGrid, frame: Frame) -> Vec<WindField> {
+        let mut fields = self.wind_fields(results, grid);
+        if frame == Frame::Earth {
+            for (field, (east, north)) in fields.iter_mut().zip(frame::storm_motion(results)) {
+                field.u.iter_mut().for_each(|u| *u += east);
+                field.v.iter_mut().for_each(|v| *v += north);
+                field.frame = Frame::Earth;
+            }
+        }
+        fields
+    }
+}
+
+/// Tableau de la position du centre, du vent maximal et de sa force de Beaufort
+/// à chaque heure
+pub fn to_table(fields: &[WindField], language: OutputLanguage, format: &NumberFormat) -> String {
+    let unit = format.wind_unit().symbol();
+    let header = match language {
+        OutputLanguage::French => format!(
+            "Heure | Latitude (°)         | Longitude (°)        | {:<20} | Beaufort",
+            format!("Vent maximal ({})", unit)
+        ),
+        OutputLanguage::English => format!(
+            "Hour  | Latitude (°)         | Longitude (°)        | {:<20} | Beaufort",
+            format!("Maximum wind ({})", unit)
+        ),
+    };
+
+    let mut table = format!(
+        "{}\n------|----------------------|----------------------|----------------------|----------------------\n",
+        header
+    );
+    for field in fields {
+        table += &format!(
+            "{:4} | {} | {} | {} | {}\n",
+            field.hour,
+            format.format_width(field.center_latitude, 20),
+            format.format_width(field.center_longitude, 20),
+            format.format_wind_width(field.max_speed(), 20),
+            Beaufort::from_speed(field.max_speed()).describe(language)
+        );
+    }
+    table
+}
+
+/// Maximum du vent tangentiel (m/s) d'une anomalie de rayon `radius` (m) et de
+/// tourbillon central `central_vorticity` (s⁻¹)
+pub(crate) fn peak_wind(shape: AnomalyShape, radius: f64, central_vorticity: f64) -> f64 {
+    let distance = match shape {
+        AnomalyShape::Gaussian => GAUSSIAN_PEAK * radius,
+        AnomalyShape::TopHat => radius,
+        AnomalyShape::Elliptical { aspect_ratio } => 2.0 * radius / (1.0 + aspect_ratio),
+    };
+    tangential_wind(shape, radius, central_vorticity.abs(), distance)
+}
+
+/// Vent tangentiel (m/s) à la distance `distance` (m), positif pour une rotation
+/// cyclonique dans l'hémisphère nord
+pub(crate) fn tangential_wind(shape: AnomalyShape, radius: f64, central_vorticity: f64, distance: f64) -> f64 {
+    match shape {
+        AnomalyShape::Gaussian => {
+            let x = distance / radius;
+            central_vorticity * radius * radius / (2.0 * distance) * (1.0 - (-x * x).exp())
+        }
+        AnomalyShape::TopHat => rankine(radius, central_vorticity, distance),
+        AnomalyShape::Elliptical { aspect_ratio } => rankine(2.0 * radius / (1.0 + aspect_ratio), central_vorticity, distance),
+    }
+}
+
+fn rankine(radius: f64, central_vorticity: f64, distance: f64) -> f64 {
+    if distance < radius {
+        central_vorticity * distance / 2.0
+    } else {
+        central_vorticity * radius * radius / (2.0 * distance)
+    }
+}
@@ -0,0 +1,39 @@
+//! Vent agéostrophique de la tendance non équilibrée du tourbillon
+
+use cyclogenese_rust::diagnostics::{self, StepDiagnostics};
+use cyclogenese_rust::units::WindUnit;
+use cyclogenese_rust::{BaroclinicCyclogenesis, NumberFormat, OutputLanguage};
+
+fn diagnose(latitude: f64) -> Vec<StepDiagnostics> {
+    let mut cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, latitude).unwrap();
+    cyclogenesis.simulate_with_diagnostics(24).unwrap().1
+}
+
+#[test]
+fn the_wind_follows_the_unbalanced_tendency() {
+    let steps = diagnose(45.0);
+    // |V_ag|/|∂ζ/∂t| = R/|f|, le même à chaque pas
+    let scale = |step: &StepDiagnostics| step.ageostrophic_wind.unwrap() / step.vorticity.tendency().abs();
+    let reference = scale(&steps[0]);
+    assert!(reference > 0.0);
+    for step in &steps {
+        assert!(step.ageostrophic_wind.unwrap() >= 0.0);
+        assert!((scale(step) / reference - 1.0).abs() < 1e-9);
+    }
+
+    // À tendance égale, le vent agéostrophique est plus fort là où f est faible
+    let south = diagnose(30.0);
+    assert!(scale(&south[0]) > reference && scale(&diagnose(60.0)[0]) < reference);
+    assert!(diagnose(0.0).iter().all(|step| step.ageostrophic_wind.is_none()));
+}
+
+#[test]
+fn the_diagnostics_table_shows_its_magnitude() {
+    let steps = diagnose(45.0);
+    let format = NumberFormat::default().with_wind_unit(WindUnit::Knots);
+    let table = diagnostics::to_table(&steps, OutputLanguage::English, &format);
+    assert!(table.lines().next().unwrap().contains("| Ageostrophic (kt)    |"));
+    let knots = format.format_width(WindUnit::Knots.express(steps[0].ageostrophic_wind.unwrap()), 20);
+    let row = table.lines().nth(2).unwrap();
+    assert_eq!(row.split(" | ").nth(4).map(|cell| cell.trim_end_matches(" |")), Some(knots.as_str()), "{}", row);
+}
@@ -0,0 +1,102 @@
+//! Export Arrow IPC, relu par les lecteurs d'`arrow-ipc`
+#![cfg(feature = "arrow")]
+
+use std::io::Cursor;
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::{Float64Type, TimestampMillisecondType, UInt32Type};
+use arrow_array::{Array, RecordBatch};
+use arrow_ipc::reader::{FileReader, StreamReader};
+use arrow_schema::{DataType, TimeUnit};
+use cyclogenese_rust::io::arrow::{self, ArrowWriter};
+use cyclogenese_rust::{ResultColumns, Scenario};
+
+fn read_file(bytes: Vec<u8>) -> Vec<RecordBatch> {
+    FileReader::try_new(Cursor::new(bytes), None).unwrap().collect::<Result<_, _>>().unwrap()
+}
+
+fn values(batch: &RecordBatch, name: &str) -> Vec<f64> {
+    batch.column_by_name(name).unwrap().as_primitive::<Float64Type>().values().to_vec()
+}
+
+#[test]
+fn files_hold_the_schema_and_the_columns() {
+    let scenario = Scenario::from_toml("geometry = \"spherical\"\nstart_time = 1999-12-25T12:00:00Z\nlongitude = -4.5\n").unwrap();
+    let columns = ResultColumns::from(scenario.run().unwrap());
+    let batches = read_file(arrow::write(Vec::new(), &columns).unwrap());
+    assert_eq!(batches.len(), 1);
+    let batch = &batches[0];
+
+    let schema = batch.schema();
+    let names = schema.fields().iter().map(|field| field.name().as_str()).collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        [
+            "hour",
+            "vertical_velocity",
+            "relative_vorticity",
+            "low_level_divergence",
+            "upper_level_divergence",
+            "valid_time",
+            "latitude",
+            "longitude",
+        ]
+    );
+    assert_eq!(schema.as_ref(), arrow::schema().as_ref());
+    assert_eq!(
+        schema.field_with_name("valid_time").unwrap().data_type(),
+        &DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".into()))
+    );
+
+    assert_eq!(batch.num_rows(), columns.len());
+    let hours = batch.column(0).as_primitive::<UInt32Type>();
+    assert_eq!(hours.values(), columns.hours());
+    assert_eq!(values(batch, "vertical_velocity"), columns.vertical_velocity());
+    assert_eq!(values(batch, "upper_level_divergence"), columns.upper_level_divergence());
+    let times = batch.column_by_name("valid_time").unwrap().as_primitive::<TimestampMillisecondType>();
+    assert!(times.values().iter().copied().eq(columns.valid_times().iter().map(|time| time.unwrap().timestamp_millis())));
+    assert_eq!(values(batch, "longitude"), columns.longitudes().iter().map(|value| value.unwrap()).collect::<Vec<_>>());
+    assert_eq!(batch, &arrow::record_batch(&columns).unwrap());
+}
+
+#[test]
+fn missing_values_are_null() {
+    let columns = ResultColumns::from(Scenario::default().run().unwrap());
+    assert!(columns.valid_times().iter().all(Option::is_none));
+    let batch = read_file(arrow::write(Vec::new(), &columns).unwrap()).remove(0);
+
+    assert_eq!(batch.column_by_name("hour").unwrap().null_count(), 0);
+    assert_eq!(batch.column_by_name("valid_time").unwrap().null_count(), columns.len());
+    assert_eq!(batch.column_by_name("latitude").unwrap().null_count(), columns.len());
+}
+
+#[test]
+fn each_write_appends_a_batch() {
+    let first = ResultColumns::from(Scenario::default().run().unwrap());
+    let second = ResultColumns::from(Scenario::from_toml("time_steps = 12\n").unwrap().run().unwrap());
+    let mut writer = ArrowWriter::new(Vec::new()).unwrap();
+    assert!(writer.is_empty());
+    writer.write(&first).unwrap();
+    writer.write(&second).unwrap();
+    assert_eq!(writer.len(), 2);
+
+    let batches = read_file(writer.finish().unwrap());
+    assert_eq!(batches.len(), 2);
+    assert_eq!(batches[0].num_rows(), first.len());
+    assert_eq!(batches[1].num_rows(), 12);
+    assert_eq!(values(&batches[1], "relative_vorticity"), second.relative_vorticity());
+}
+
+#[test]
+fn streams_hold_the_schema_a_batch_and_the_end_marker() {
+    let columns = ResultColumns::from(Scenario::from_toml("time_steps = 6\n").unwrap().run().unwrap());
+    let stream = arrow::to_stream(&columns).unwrap();
+    assert_eq!(stream[stream.len() - 8..], [0xff, 0xff, 0xff, 0xff, 0, 0, 0, 0]);
+
+    let reader = StreamReader::try_new(Cursor::new(stream), None).unwrap();
+    assert_eq!(reader.schema(), arrow::schema());
+    let batches = reader.collect::<Result<Vec<_>, _>>().unwrap();
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches[0].num_rows(), 6);
+    assert_eq!(values(&batches[0], "vertical_velocity"), columns.vertical_velocity());
+}
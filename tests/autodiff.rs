@@ -0,0 +1,34 @@
+//! Dérivées exactes du tourbillon maximal par différentiation automatique
+
+use cyclogenese_rust::autodiff::{self, Parameter};
+use cyclogenese_rust::BaroclinicCyclogenesis;
+
+fn vorticity(mut cyclogenesis: BaroclinicCyclogenesis, hour: u32) -> f64 {
+    let results = cyclogenesis.simulate_interaction(hour + 1).unwrap();
+    serde_json::to_value(&results).unwrap()[hour as usize]["relative_vorticity"].as_f64().unwrap()
+}
+
+#[test]
+fn derivative_matches_centered_finite_difference() {
+    let step = 1e-4;
+    for parameter in [Parameter::SurfaceTemp, Parameter::AltitudeTemp] {
+        let peak = autodiff::peak_vorticity_sensitivity(5.0, -8.0, 45.0, 24, parameter).unwrap().unwrap();
+        let (surface_step, altitude_step) = match parameter {
+            Parameter::SurfaceTemp => (step, 0.0),
+            Parameter::AltitudeTemp => (0.0, step),
+        };
+        let run = |sign: f64| {
+            let cyclogenesis =
+                BaroclinicCyclogenesis::new(5.0 + sign * surface_step, -8.0 + sign * altitude_step, 45.0).unwrap();
+            vorticity(cyclogenesis, peak.hour)
+        };
+        let expected = (run(1.0) - run(-1.0)) / (2.0 * step);
+        assert_eq!(peak.vorticity, vorticity(BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap(), peak.hour));
+        assert!((peak.derivative - expected).abs() <= 1e-6 * expected.abs(), "{:?} : {} / {}", parameter, peak.derivative, expected);
+    }
+}
+
+#[test]
+fn no_time_step_gives_no_peak() {
+    assert!(autodiff::peak_vorticity_sensitivity(5.0, -8.0, 45.0, 0, Parameter::SurfaceTemp).unwrap().is_none());
+}
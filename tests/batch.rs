@@ -0,0 +1,107 @@
+//! Traitement par lots d'un répertoire de scénarios
+
+use std::fs;
+use std::path::PathBuf;
+
+use cyclogenese_rust::batch::{self, BatchEntry, BatchFilter, BatchReport, JobStatus, INDEX_FILE};
+use cyclogenese_rust::metrics::Metrics;
+use cyclogenese_rust::{diff, OutputLanguage, Scenario};
+
+fn directory(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("cyclogenese-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&path);
+    fs::create_dir_all(&path).unwrap();
+    path
+}
+
+#[test]
+fn glob_patterns_match_names() {
+    assert!(batch::glob_match("*.toml", "tempete.toml"));
+    assert!(!batch::glob_match("*.toml", "tempete.json"));
+    assert!(batch::glob_match("lothar-*.toml", "lothar-1999.toml"));
+    assert!(batch::glob_match("a?c*", "abc"));
+    assert!(batch::glob_match("*a*b*", "xxaYYbzz"));
+    assert!(!batch::glob_match("a?c", "ac"));
+    assert!(batch::glob_match("*", ""));
+
+    let filter = BatchFilter::new(&[], &["*-draft.toml".to_string()]);
+    assert!(filter.matches("a.toml"));
+    assert!(!filter.matches("a-draft.toml"));
+    assert!(!filter.matches("a.json"));
+}
+
+#[test]
+fn directory_is_simulated_with_one_file_per_scenario() {
+    let scenarios = directory("batch-in");
+    let out = scenarios.join("results");
+    fs::write(scenarios.join("a.toml"), "time_steps = 24\n").unwrap();
+    fs::write(scenarios.join("b.toml"), "latitude = 60.0\nname = \"nordique\"\n").unwrap();
+    fs::write(scenarios.join("broken.toml"), "latitude = 95.0\n").unwrap();
+    fs::write(scenarios.join("skipped.toml"), "time_steps = 6\n").unwrap();
+    fs::write(scenarios.join("notes.txt"), "pas un scénario\n").unwrap();
+
+    let filter = BatchFilter::new(&[], &["skip*".to_string()]);
+    assert_eq!(batch::discover(&scenarios, &filter).unwrap().len(), 3);
+    let metrics = Metrics::default();
+    let report = BatchReport::run(&scenarios, &out, &filter, 2, OutputLanguage::French, &metrics).unwrap();
+    // Étiquette du nom du fichier, ou du champ `name` du scénario
+    assert_eq!((metrics.simulations(), metrics.errors(), metrics.steps()), (2, 1, 48));
+    assert_eq!(metrics.scenario("a").unwrap().count, 1);
+    assert_eq!(metrics.scenario("nordique").unwrap().count, 1);
+    assert!(metrics.scenario("b").is_none() && metrics.scenario("broken").is_none());
+
+    let names: Vec<_> = report.entries.iter().map(|entry| entry.scenario.as_str()).collect();
+    assert_eq!(names, ["a.toml", "b.toml", "broken.toml"]);
+    assert_eq!(report.failures().count(), 1);
+    assert!(report.entries[2].error.as_deref().unwrap().contains("Latitude"));
+    assert!(!out.join("broken.json").exists());
+
+    let a = diff::load(out.join("a.json")).unwrap();
+    assert_eq!(a.len(), 24);
+    assert_eq!(report.entries[0].time_steps, Some(24));
+    let expected = Scenario::from_toml("latitude = 60.0\nname = \"nordique\"\n").unwrap().run().unwrap();
+    assert_eq!(diff::load(out.join("b.json")).unwrap().len(), expected.len());
+
+    assert!(out.join(INDEX_FILE).exists());
+    let index = BatchReport::load_index(&out).unwrap();
+    for (read, written) in index.entries.iter().zip(&report.entries) {
+        assert_eq!((&read.scenario, &read.output, &read.error), (&written.scenario, &written.output, &written.error));
+        assert_eq!(read.peak_hour, written.peak_hour);
+    }
+    let table = report.to_table(OutputLanguage::French, &Default::default());
+    assert!(table.contains("2 scénario(s) simulé(s), 1 en échec"));
+    fs::remove_dir_all(&scenarios).unwrap();
+}
+
+#[test]
+fn resume_reruns_only_failed_and_pending_scenarios() {
+    let scenarios = directory("batch-resume");
+    let out = scenarios.join("results");
+    fs::write(scenarios.join("a.toml"), "time_steps = 24\n").unwrap();
+    fs::write(scenarios.join("b.toml"), "latitude = 95.0\n").unwrap();
+    let filter = BatchFilter::default();
+    let first = BatchReport::run(&scenarios, &out, &filter, 2, OutputLanguage::English, &Metrics::default()).unwrap();
+    let statuses: Vec<_> = first.entries.iter().map(|entry| entry.status).collect();
+    assert_eq!(statuses, [JobStatus::Succeeded, JobStatus::Failed]);
+    assert_eq!(first.entries[1].error_kind.as_deref(), Some("InvalidLatitude"));
+
+    // a.toml change sans être relancé ; b.toml est corrigé ; c.toml arrive
+    fs::write(scenarios.join("a.toml"), "time_steps = 12\n").unwrap();
+    fs::write(scenarios.join("b.toml"), "time_steps = 6\n").unwrap();
+    fs::write(scenarios.join("c.toml"), "time_steps = 3\n").unwrap();
+    let resumed = BatchReport::resume(&scenarios, &out, &filter, 2, OutputLanguage::English, &Metrics::default()).unwrap();
+    assert!(resumed.entries.iter().all(BatchEntry::succeeded));
+    let steps: Vec<_> = resumed.entries.iter().map(|entry| entry.time_steps).collect();
+    assert_eq!(steps, [Some(24), Some(6), Some(3)]);
+    assert_eq!(BatchReport::load_index(&out).unwrap().entries.len(), 3);
+
+    // Un lot interrompu laisse des scénarios en attente, relancés à la reprise
+    let mut index = BatchReport::load_index(&out).unwrap();
+    index.entries[0].status = JobStatus::Pending;
+    index.write_index(&out).unwrap();
+    assert_eq!(BatchReport::load_index(&out).unwrap().pending().count(), 1);
+    let resumed = BatchReport::resume(&scenarios, &out, &filter, 1, OutputLanguage::English, &Metrics::default()).unwrap();
+    assert_eq!(resumed.entries[0].time_steps, Some(12));
+    assert_eq!(resumed.pending().count(), 0);
+    fs::remove_dir_all(&scenarios).unwrap();
+}
@@ -0,0 +1,51 @@
+//! Échelle de Beaufort des vents de surface
+
+use cyclogenese_rust::beaufort::Beaufort;
+use cyclogenese_rust::report::Report;
+use cyclogenese_rust::wind::{self, WindGrid};
+use cyclogenese_rust::{AnomalyShape, BaroclinicCyclogenesis, NumberFormat, OutputLanguage, Scenario};
+
+#[test]
+fn speeds_fall_into_wmo_classes() {
+    assert_eq!(Beaufort::from_speed(0.0).force(), 0);
+    assert_eq!(Beaufort::from_speed(3.3).force(), 3);
+    assert_eq!(Beaufort::from_speed(-18.0).force(), 8);
+    assert_eq!(Beaufort::from_speed(26.0).force(), 10);
+    assert_eq!(Beaufort::from_speed(45.0), Beaufort::MAX);
+    assert_eq!(Beaufort::new(10).unwrap().lower_bound(), 24.4);
+    assert!(Beaufort::new(13).is_none());
+
+    let storm = Beaufort::from_speed(26.0);
+    assert_eq!(storm.describe(OutputLanguage::English), "Storm force 10");
+    assert_eq!(storm.describe(OutputLanguage::French), "Tempête, force 10");
+}
+
+#[test]
+fn surface_wind_bounds_the_gridded_wind() {
+    for shape in [None, Some(AnomalyShape::TopHat), Some(AnomalyShape::Elliptical { aspect_ratio: 2.0 })] {
+        let scenario = Scenario { shape, ..Scenario::default() };
+        let mut cyclogenesis = scenario.to_cyclogenesis().unwrap();
+        let results = cyclogenesis.simulate_interaction(12).unwrap();
+        let field = cyclogenesis.wind_field(&results[11], &WindGrid::new(10.0, 0.05).unwrap());
+
+        let surface = cyclogenesis.surface_wind(&results[11]);
+        assert!(field.max_speed() <= surface * (1.0 + 1e-9), "{:?}", shape);
+        assert!(field.max_speed() >= 0.98 * surface, "{:?}", shape);
+        assert_eq!(cyclogenesis.beaufort(&results[11]), Beaufort::from_speed(surface));
+    }
+}
+
+#[test]
+fn summaries_state_the_beaufort_force() {
+    let mut cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap();
+    let results = cyclogenesis.simulate_interaction(3).unwrap();
+    let fields = cyclogenesis.wind_fields(&results, &WindGrid::default());
+    let table = wind::to_table(&fields, OutputLanguage::English, &NumberFormat::default());
+    let expected = Beaufort::from_speed(fields[0].max_speed()).describe(OutputLanguage::English);
+    assert!(table.lines().nth(2).unwrap().ends_with(&format!("| {}", expected)));
+
+    let report = Report::compute(&Scenario::default()).unwrap();
+    let peak = report.storm_states().iter().map(|state| state.surface_wind).fold(0.0, f64::max);
+    let text = report.to_markdown(OutputLanguage::English, &NumberFormat::default());
+    assert!(text.contains(&format!("({})", Beaufort::from_speed(peak).describe(OutputLanguage::English))));
+}
@@ -0,0 +1,66 @@
+//! Format binaire versionné des résultats
+
+use cyclogenese_rust::io::binary::{self, BinaryReader, Header, HEADER_SIZE, MAGIC};
+use cyclogenese_rust::io::records::{self, RECORD_SIZE};
+use cyclogenese_rust::{diff, MeteoError, Scenario};
+
+#[test]
+fn results_round_trip_with_their_header() {
+    let scenario = Scenario::from_toml("geometry = \"spherical\"\nstart_time = 1999-12-25T12:00:00Z\nlongitude = -4.5\n").unwrap();
+    let results = scenario.run().unwrap();
+    let bytes = binary::write(Vec::new(), &scenario, &results).unwrap();
+    assert_eq!(bytes.len(), HEADER_SIZE + results.len() * RECORD_SIZE);
+    assert_eq!(bytes[..8], MAGIC);
+
+    let (header, read) = binary::read(bytes.as_slice()).unwrap();
+    assert_eq!(read, results);
+    assert_eq!(header, Header::new(&scenario));
+    assert!(header.matches(&scenario));
+    assert!(!header.matches(&Scenario::default()));
+    assert_eq!(binary::scenario_hash(&scenario), binary::scenario_hash(&scenario.clone()));
+
+    let path = std::env::temp_dir().join(format!("cyclogenese-binary-{}.bin", std::process::id()));
+    std::fs::write(&path, &bytes).unwrap();
+    let loaded = diff::load(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.unwrap(), results);
+}
+
+#[test]
+fn later_minor_versions_are_read_by_skipping_unknown_fields() {
+    let results = Scenario::default().run().unwrap();
+    // Version 1.3 : 8 octets de plus dans l'en-tête et dans chaque enregistrement
+    let mut bytes = MAGIC.to_vec();
+    bytes.extend([1, 3]);
+    bytes.extend(32u16.to_le_bytes());
+    bytes.extend(72u16.to_le_bytes());
+    bytes.extend([0; 10]);
+    bytes.extend([0xff; 8]);
+    for result in &results {
+        bytes.extend(records::encode(result));
+        bytes.extend([0xee; 8]);
+    }
+
+    let reader = BinaryReader::new(bytes.as_slice()).unwrap();
+    assert_eq!((reader.header().minor, reader.header().record_size), (3, 72));
+    assert_eq!(reader.collect::<Result<Vec<_>, _>>().unwrap(), results);
+}
+
+#[test]
+fn invalid_files_are_rejected() {
+    let scenario = Scenario::default();
+    let bytes = binary::write(Vec::new(), &scenario, &scenario.run().unwrap()).unwrap();
+
+    let mut other_major = bytes.clone();
+    other_major[8] = 2;
+    let mut short_records = bytes.clone();
+    short_records[12] = 32;
+    let mut signature = bytes.clone();
+    signature[0] = b'X';
+    for bytes in [other_major, short_records, signature, bytes[..10].to_vec(), bytes[..bytes.len() - 1].to_vec()] {
+        assert!(matches!(binary::read(bytes.as_slice()), Err(MeteoError::InvalidBinary(_))));
+    }
+
+    let (_, empty) = binary::read(binary::write(Vec::new(), &scenario, &[]).unwrap().as_slice()).unwrap();
+    assert!(empty.is_empty());
+}
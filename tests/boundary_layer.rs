@@ -0,0 +1,92 @@
+//! Couche limite de hauteur évolutive
+
+use cyclogenese_rust::boundary_layer::REFERENCE_DEPTH;
+use cyclogenese_rust::{
+    BaroclinicCyclogenesis, BoundaryLayer, DiagnosticsLevel, MeteoError, NumberFormat, OutputLanguage, Scenario, SurfaceType,
+};
+
+fn ocean() -> BaroclinicCyclogenesis {
+    BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap().with_surface_type(SurfaceType::Ocean { sst: 300.0 }).unwrap()
+}
+
+#[test]
+fn no_boundary_layer_keeps_the_reference_run() {
+    let reference = Scenario::default().run().unwrap();
+    assert_eq!(Scenario { boundary_layer_depth: None, ..Scenario::default() }.run().unwrap(), reference);
+    assert_eq!(BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap().boundary_layer(), None);
+}
+
+#[test]
+fn depth_evolves_towards_equilibrium() {
+    let mut cyclogenesis = ocean().with_boundary_layer(200.0).unwrap();
+    let (_, diagnostics) = cyclogenesis.simulate_with_diagnostics(24).unwrap();
+    let depths: Vec<f64> = diagnostics.iter().map(|step| step.boundary_layer_depth.unwrap()).collect();
+    assert!(depths[0] > 200.0);
+    assert!(depths.windows(2).all(|pair| pair[1] != pair[0]));
+    assert_eq!(cyclogenesis.boundary_layer().unwrap().depth(), depths[23]);
+}
+
+#[test]
+fn equilibrium_grows_with_wind_and_heating() {
+    let depth = |wind, flux| BoundaryLayer::equilibrium_depth(wind, 1.2e-3, 1.0e-4, flux);
+    assert!(depth(5.0, 0.0) < depth(15.0, 0.0));
+    assert!(depth(10.0, 0.0) < depth(10.0, 1.0));
+    assert_eq!(depth(10.0, -2.0), depth(10.0, 0.0));
+    assert_eq!(depth(0.0, 0.0), 100.0);
+    assert_eq!(depth(100.0, 5.0), 3000.0);
+}
+
+#[test]
+fn shallow_layer_strengthens_flux_and_weakens_pumping() {
+    let shallow = BoundaryLayer::new(500.0).unwrap();
+    assert_eq!(shallow.flux_scale(), 2.0);
+    assert_eq!(shallow.pumping_scale(), 0.5);
+    let reference = BoundaryLayer::new(REFERENCE_DEPTH).unwrap();
+    assert_eq!((reference.flux_scale(), reference.pumping_scale(), reference.friction_factor()), (1.0, 1.0, 1.0));
+
+    let chain = |cyclogenesis: BaroclinicCyclogenesis| {
+        let mut cyclogenesis = cyclogenesis.with_diagnostics_level(DiagnosticsLevel::Full);
+        cyclogenesis.explain(0).unwrap().anomalies[0].chain
+    };
+    let (implicit, thin) = (chain(ocean()), chain(ocean().with_boundary_layer(500.0).unwrap()));
+    assert!((thin.forcing - 2.0 * implicit.forcing).abs() < 1e-12);
+}
+
+#[test]
+fn deep_layer_damps_faster() {
+    let friction = |depth| {
+        let mut cyclogenesis = ocean().with_boundary_layer(depth).unwrap();
+        cyclogenesis.explain(12).unwrap().anomalies[0].chain.friction_factor
+    };
+    assert!(friction(2500.0) < friction(300.0));
+    assert!(friction(300.0) < 1.0);
+}
+
+#[test]
+fn explanation_reports_the_depth() {
+    let mut cyclogenesis = ocean().with_boundary_layer(800.0).unwrap();
+    let explanation = cyclogenesis.explain(0).unwrap();
+    assert_eq!(explanation.boundary_layer_depth, Some(800.0));
+    assert!(explanation.describe(OutputLanguage::English, &NumberFormat::default()).contains("Boundary layer h = 800 m"));
+    assert_eq!(ocean().explain(0).unwrap().boundary_layer_depth, None);
+}
+
+#[test]
+fn rejects_an_invalid_depth() {
+    for depth in [0.0, 50.0, 5000.0, f64::NAN] {
+        let result = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap().with_boundary_layer(depth);
+        assert!(matches!(result, Err(MeteoError::InvalidScenario(_))), "{}", depth);
+    }
+}
+
+#[test]
+fn depth_is_read_from_toml() {
+    let scenario = Scenario::from_toml("boundary_layer_depth = 800.0").unwrap();
+    assert_eq!(scenario.boundary_layer_depth, Some(800.0));
+    let cyclogenesis = scenario.to_cyclogenesis().unwrap();
+    assert_eq!(cyclogenesis.boundary_layer().map(|layer| layer.depth()), Some(800.0));
+
+    let ocean = Scenario { surface: Some(SurfaceType::Ocean { sst: 300.0 }), ..Scenario::default() };
+    let evolving = Scenario { boundary_layer_depth: Some(400.0), ..ocean.clone() };
+    assert!(evolving.run().unwrap() != ocean.run().unwrap());
+}
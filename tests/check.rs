@@ -0,0 +1,61 @@
+//! Modèle de scénario et vérification sans simulation
+
+use std::time::Duration;
+
+use cyclogenese_rust::check::{DryRun, Finding, ScenarioCheck};
+use cyclogenese_rust::scenario::TEMPLATE;
+use cyclogenese_rust::{MeteoError, OutputLanguage, Scenario};
+
+#[test]
+fn template_describes_the_reference_case() {
+    let scenario = Scenario::from_toml(TEMPLATE).unwrap();
+    assert_eq!(scenario, Scenario::default());
+    assert!(ScenarioCheck::run(&scenario).unwrap().is_clean());
+}
+
+#[test]
+fn inconsistent_configuration_is_reported() {
+    let scenario = Scenario::from_toml("latitude = 0.0\ntilt = -3.0e5\nmeridional_gradient = 0.0\ntime_steps = 0\n").unwrap();
+    let check = ScenarioCheck::run(&scenario).unwrap();
+    for finding in [Finding::NoTimeSteps, Finding::Equator, Finding::NoBaroclinicZone, Finding::EastwardTilt] {
+        assert!(check.findings().contains(&finding), "{:?}", finding);
+    }
+    assert!(!check.is_clean());
+}
+
+#[test]
+fn unstable_time_step_is_reported() {
+    let scenario = Scenario::from_toml(
+        "surface_temp = 15.0\naltitude_temp = -20.0\nlatitude = 80.0\nmeridional_gradient = 50.0\ntime_step = 3600.0\n",
+    )
+    .unwrap();
+    let check = ScenarioCheck::run(&scenario).unwrap();
+    assert!(check.findings().iter().any(|finding| matches!(finding, Finding::UnstableTimeStep(..))));
+}
+
+#[test]
+fn out_of_range_values_are_errors() {
+    let scenario = Scenario { surface_temp: 80.0, ..Scenario::default() };
+    assert!(matches!(ScenarioCheck::run(&scenario), Err(MeteoError::InvalidTemperature(_))));
+}
+
+#[test]
+fn dry_run_reports_derived_parameters_without_simulating() {
+    let scenario = Scenario::default();
+    let dry_run = DryRun::run(&scenario).unwrap();
+    assert_eq!(dry_run.check, ScenarioCheck::run(&scenario).unwrap());
+    assert!((dry_run.coriolis - 1.031e-4).abs() < 1e-7);
+    assert!(dry_run.thermal_wind_shear.unwrap() > 0.0);
+    assert_eq!(dry_run.levels.len(), 2);
+    assert!(dry_run.levels.iter().all(|level| level.is_hydrostatic()));
+    assert_eq!(dry_run.integration_steps, (3600.0 / dry_run.time_step) as u64 * 24);
+
+    let text = dry_run.describe(OutputLanguage::French, &Default::default());
+    assert!(text.contains("Paramètre de Coriolis f = 1.03 10⁻⁴ s⁻¹"));
+    assert!(text.contains("pas d'intégration, durée estimée"));
+    assert!(text.ends_with("Scénario valide\n"));
+
+    let empty = DryRun::run(&Scenario { time_steps: 0, ..Scenario::default() }).unwrap();
+    assert_eq!((empty.integration_steps, empty.estimated_runtime), (0, Duration::ZERO));
+    assert!(matches!(DryRun::run(&Scenario { latitude: 95.0, ..Scenario::default() }), Err(MeteoError::InvalidLatitude(_))));
+}
@@ -0,0 +1,81 @@
+//! Classement de la sévérité du système
+
+use cyclogenese_rust::classify::{
+    self, BeaufortClassifier, BuiltinClassifier, Class, Classifier, PressureClassifier, StormState, WindstormClassifier,
+};
+use cyclogenese_rust::{scenarios, BaroclinicCyclogenesis, MeteoError, NumberFormat, OutputLanguage};
+
+fn state(hour: u32, surface_wind: f64, central_pressure: f64) -> StormState {
+    StormState { hour, relative_vorticity: 0.0, surface_wind, central_pressure, pressure_deficit: central_pressure - 1013.0 }
+}
+
+#[test]
+fn pressure_follows_the_geostrophic_deficit() {
+    let mut cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap();
+    let results = cyclogenesis.simulate_interaction(24).unwrap();
+    let states = cyclogenesis.storm_states(&results);
+
+    // Dépression : la pression centrale baisse quand le tourbillon se renforce
+    assert!(states.iter().all(|state| state.pressure_deficit < 0.0));
+    assert_eq!(states[23].central_pressure, 1013.0 + states[23].pressure_deficit);
+    assert!(states[23].central_pressure < states[0].central_pressure);
+    let ratio = states[23].pressure_deficit / states[0].pressure_deficit;
+    assert!((ratio - results[23].relative_vorticity() / results[0].relative_vorticity()).abs() < 1e-9);
+}
+
+#[test]
+fn built_in_classifiers_rank_states() {
+    let calm = state(0, 5.0, 1010.0);
+    let storm = state(1, 26.0, 975.0);
+    let violent = state(2, 30.0, 955.0);
+
+    assert_eq!(
+        BeaufortClassifier.classify(&storm, OutputLanguage::English),
+        Some(Class { rank: 10, label: "Storm force 10".to_string(), severe: true })
+    );
+    let windstorm = WindstormClassifier::default();
+    assert!(windstorm.classify(&calm, OutputLanguage::English).is_none());
+    assert_eq!(windstorm.classify(&storm, OutputLanguage::French).unwrap().label, "Tempête");
+    assert!(windstorm.classify(&violent, OutputLanguage::English).unwrap().severe);
+    assert!(PressureClassifier.classify(&calm, OutputLanguage::English).is_none());
+    assert_eq!(PressureClassifier.classify(&storm, OutputLanguage::English).unwrap().rank, 2);
+    assert!(PressureClassifier.classify(&violent, OutputLanguage::English).unwrap().severe);
+
+    let states = [calm, storm, violent];
+    assert_eq!(windstorm.first_severe(&states).map(|state| state.hour), Some(2));
+    assert_eq!(BeaufortClassifier.first_severe(&states).map(|state| state.hour), Some(1));
+    assert_eq!(
+        classify::severity_statement(&windstorm, &states, OutputLanguage::English),
+        "The system becomes \"Severe windstorm\" at hour 2"
+    );
+
+    assert!(matches!(WindstormClassifier::new(20.0, 15.0), Err(MeteoError::InvalidScenario(_))));
+    assert_eq!("Pressure".parse::<BuiltinClassifier>(), Ok(BuiltinClassifier::Pressure));
+    assert!("saffir-simpson".parse::<BuiltinClassifier>().is_err());
+}
+
+#[test]
+fn custom_classifiers_plug_into_the_table() {
+    struct DeepeningClassifier;
+
+    impl Classifier for DeepeningClassifier {
+        fn name(&self, _: OutputLanguage) -> &'static str {
+            "Deficit"
+        }
+
+        fn classify(&self, state: &StormState, _: OutputLanguage) -> Option<Class> {
+            (state.pressure_deficit < -10.0).then(|| Class { rank: 1, label: "Deep".to_string(), severe: true })
+        }
+    }
+
+    let scenario = scenarios::find("lothar-1999").unwrap().scenario();
+    let mut cyclogenesis = scenario.to_cyclogenesis().unwrap();
+    let results = cyclogenesis.simulate_interaction(scenario.time_steps).unwrap();
+    let states = cyclogenesis.storm_states(&results);
+    let table = classify::to_table(&DeepeningClassifier, &states, OutputLanguage::English, &NumberFormat::default());
+
+    assert!(table.starts_with("Hour  | Wind (m/s)           | Pressure (hPa)       | Deficit\n"));
+    assert_eq!(table.lines().count(), states.len() + 4);
+    let first = states.iter().find(|state| state.pressure_deficit < -10.0).unwrap();
+    assert!(table.ends_with(&format!("The system becomes \"Deep\" at hour {}\n", first.hour)));
+}
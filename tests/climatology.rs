@@ -0,0 +1,73 @@
+//! État de fond tiré d'une climatologie zonale
+
+use cyclogenese_rust::exit::ErrorClass;
+use cyclogenese_rust::io::climatology::parse_csv;
+use cyclogenese_rust::sweep::SweepConfig;
+use cyclogenese_rust::{BaroclinicCyclogenesis, Climatology, MeteoError, Scenario};
+
+/// Moyenne zonale de janvier, hémisphère nord, niveaux et latitudes dans le désordre
+const JANUARY: &str = "\
+# Température (K)
+latitude,100,150,200,250,300,500,700,850,1000
+60,213.0,215.5,217.0,218.5,221.0,238.0,249.5,256.5,262.0
+30,203.0,212.0,220.0,229.0,238.0,262.0,276.0,284.0,290.0
+40,211.0,215.0,218.5,224.0,231.0,253.5,267.0,275.0,281.0
+50,214.0,216.0,217.5,220.0,225.0,245.0,257.5,265.0,271.5
+";
+
+#[test]
+fn csv_is_sorted_by_latitude_and_level() {
+    let climatology = parse_csv(JANUARY).unwrap();
+    assert_eq!(climatology.latitudes, vec![30.0, 40.0, 50.0, 60.0]);
+    assert_eq!(climatology.pressures[0], 1000.0);
+    assert_eq!(climatology.pressures[8], 100.0);
+    assert_eq!(climatology.temperature[1][0], 281.0);
+    assert_eq!(climatology.temperature_at(45.0, 1000.0), 276.25);
+}
+
+#[test]
+fn background_at_a_latitude() {
+    let background = parse_csv(JANUARY).unwrap().background(45.0).unwrap();
+    assert_eq!(background.base_temp, 276.25);
+    // Environ 9,5 K tous les 10° sous 500 hPa
+    assert!((7.5..9.5).contains(&background.meridional_gradient), "{}", background.meridional_gradient);
+    // Gradient vertical sous 2 K/km à partir de 200 hPa
+    assert!((10.0e3..13.0e3).contains(&background.tropopause_height), "{}", background.tropopause_height);
+}
+
+#[test]
+fn latitudes_get_their_own_environment() {
+    let scenario = Scenario { climatology: Some(parse_csv(JANUARY).unwrap()), ..Scenario::default() };
+    let sweep = BaroclinicCyclogenesis::sweep_latitudes(&[35.0, 55.0], &SweepConfig::from(scenario)).unwrap();
+    let (south, north) = (sweep.runs()[0].cyclogenesis.background(), sweep.runs()[1].cyclogenesis.background());
+    assert!(south.base_temp > north.base_temp);
+    assert!(south.tropopause_height > north.tropopause_height);
+}
+
+#[test]
+fn climatology_is_read_inline_from_toml() {
+    let scenario = Scenario::from_toml(
+        "[climatology]\n\
+         latitudes = [30.0, 60.0]\n\
+         pressures = [1000.0, 500.0]\n\
+         temperature = [[290.0, 262.0], [262.0, 238.0]]\n",
+    )
+    .unwrap();
+    let cyclogenesis = scenario.to_cyclogenesis().unwrap();
+    assert_eq!(cyclogenesis.background().base_temp, 276.0);
+    assert_ne!(scenario.run().unwrap(), Scenario::default().run().unwrap());
+}
+
+#[test]
+fn rejects_malformed_tables() {
+    for text in ["", "pressure,1000,500\n45,288,255\n", "latitude,1000,500\n45,288\n", "latitude,1000,500\n45,288,abc\n"] {
+        let error = parse_csv(text).unwrap_err();
+        assert!(matches!(error, MeteoError::InvalidClimatology(_)), "{:?}", text);
+        assert_eq!(error.class(), ErrorClass::InvalidInput);
+    }
+
+    let repeated = Climatology { latitudes: vec![45.0, 45.0], pressures: vec![1000.0, 500.0], temperature: vec![vec![288.0, 255.0]; 2] };
+    assert!(matches!(repeated.validate(), Err(MeteoError::InvalidClimatology(_))));
+    let scenario = Scenario { climatology: Some(repeated), ..Scenario::default() };
+    assert!(matches!(scenario.run(), Err(MeteoError::InvalidClimatology(_))));
+}
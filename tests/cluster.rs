@@ -0,0 +1,62 @@
+//! Regroupement des membres d'un ensemble
+
+use cyclogenese_rust::cluster::Clustering;
+use cyclogenese_rust::ensemble::{Ensemble, EnsembleConfig, EnsembleMember};
+use cyclogenese_rust::{MeteoError, NumberFormat, OutputLanguage, Scenario};
+
+/// Ensemble de deux familles de membres, d'anomalies de surface voisines de 3 K et de 8 K
+fn two_families() -> Ensemble {
+    let members = [3.0, 8.0, 3.2, 8.3, 3.1, 7.9, 8.1]
+        .into_iter()
+        .map(|surface_temp| EnsembleMember::simulate(Scenario { surface_temp, ..Scenario::default() }).unwrap())
+        .collect();
+    Ensemble::from_members(members).unwrap()
+}
+
+#[test]
+fn members_are_grouped_by_development() {
+    let ensemble = two_families();
+    let clustering = Clustering::compute(&ensemble, 2).unwrap();
+    let clusters = clustering.clusters();
+    assert_eq!(clusters.len(), 2);
+    // Classés par effectif décroissant
+    assert_eq!(clusters[0].members, [1, 3, 5, 6]);
+    assert_eq!(clusters[1].members, [0, 2, 4]);
+    assert!(clusters[0].members.contains(&clusters[0].representative));
+    assert_eq!(clusters[1].representative, 4);
+
+    // Le centroïde est la moyenne des trajectoires du groupe
+    let last = ensemble.hours().len() - 1;
+    let mean = clusters[1].members.iter().map(|member| ensemble.members()[*member].states[last].central_pressure).sum::<f64>() / 3.0;
+    assert!((clusters[1].centroid[last].central_pressure - mean).abs() < 1e-9);
+    assert!((clusters[0].lowest_pressure().unwrap().1 - clusters[1].lowest_pressure().unwrap().1).abs() > 5.0);
+}
+
+#[test]
+fn clustering_is_reproducible_and_validated() {
+    let ensemble = Ensemble::generate(&Scenario::default(), &EnsembleConfig::new(10, 2.0, 2.0).unwrap()).unwrap();
+    let a = Clustering::compute(&ensemble, 3).unwrap();
+    let b = Clustering::compute(&ensemble, 3).unwrap();
+    assert_eq!(a.clusters(), b.clusters());
+    assert_eq!(a.clusters().iter().map(|cluster| cluster.members.len()).sum::<usize>(), 10);
+
+    // Autant de groupes que de membres : un membre par groupe
+    let singletons = Clustering::compute(&ensemble, 10).unwrap();
+    assert!(singletons.clusters().iter().all(|cluster| cluster.members == [cluster.representative]));
+
+    // Membres identiques : les groupes vides sont écartés
+    let identical = Ensemble::generate(&Scenario::default(), &EnsembleConfig::new(4, 0.0, 0.0).unwrap()).unwrap();
+    assert_eq!(Clustering::compute(&identical, 3).unwrap().clusters().len(), 1);
+
+    for count in [0, 11] {
+        assert!(matches!(Clustering::compute(&ensemble, count), Err(MeteoError::InvalidScenario(_))));
+    }
+}
+
+#[test]
+fn clusters_are_tabulated() {
+    let table = Clustering::compute(&two_families(), 2).unwrap().to_table(OutputLanguage::English, &NumberFormat::default());
+    assert!(table.starts_with("Cluster 1: 4 member(s), representative #"));
+    assert!(table.contains("\nCluster 2: 3 member(s), representative #4, lowest pressure "));
+    assert!(table.contains("\nHour  | Cluster 1 (hPa)      | Cluster 2 (hPa)\n------|----------------------|----------------------\n"));
+}
@@ -0,0 +1,58 @@
+//! Stockage en colonnes des résultats
+
+use cyclogenese_rust::{DevelopmentResult, ResultColumns, ResultSeries, Scenario};
+
+#[test]
+fn columns_round_trip_the_row_results() {
+    let scenario = Scenario::from_toml("geometry = \"spherical\"\nstart_time = 1999-12-25T12:00:00Z\nlongitude = -4.5\n").unwrap();
+    let results = scenario.run().unwrap();
+    let columns = ResultColumns::from(results.clone());
+
+    assert_eq!(columns.len(), results.len());
+    assert_eq!(columns.hours(), results.iter().map(|result| result.hour()).collect::<Vec<_>>());
+    assert_eq!(columns.relative_vorticity(), results.iter().map(|result| result.relative_vorticity()).collect::<Vec<_>>());
+    assert_eq!(columns.vertical_velocity()[5], results[5].vertical_velocity());
+    assert_eq!(columns.low_level_divergence()[5], results[5].low_level_divergence());
+    assert_eq!(columns.upper_level_divergence()[5], results[5].upper_level_divergence());
+    assert_eq!(columns.valid_times()[5], results[5].valid_time());
+    assert_eq!((columns.latitudes()[5], columns.longitudes()[5]), (results[5].latitude(), results[5].longitude()));
+
+    assert_eq!(columns.get(5).as_ref(), results.get(5));
+    assert_eq!(columns.get(results.len()), None);
+    assert_eq!(Vec::<DevelopmentResult>::from(columns.clone()), results);
+    assert_eq!(columns.iter().collect::<ResultColumns>(), columns);
+}
+
+#[test]
+fn simulation_fills_the_columns_directly() {
+    let scenario = Scenario::default();
+    let mut cyclogenesis = scenario.to_cyclogenesis().unwrap();
+    let columns = cyclogenesis.simulate_columns(scenario.time_steps).unwrap();
+    assert_eq!(columns, ResultColumns::from(scenario.run().unwrap()));
+
+    let series = ResultSeries::from(columns.clone());
+    assert_eq!(series.columns(), &columns);
+    assert_eq!(series.time_of_peak(), Some(23));
+
+    let empty = ResultColumns::<f64>::default();
+    assert!(empty.is_empty() && empty.iter().next().is_none());
+}
+
+#[test]
+fn series_rows_read_the_columns_in_place() {
+    let results = Scenario::default().run().unwrap();
+    let series = ResultSeries::from(results.clone());
+
+    let row = series.row(5).unwrap();
+    assert_eq!((row.index(), row.hour()), (5, 5));
+    assert_eq!(row.relative_vorticity(), series.columns().relative_vorticity()[5]);
+    assert!(row == results[5]);
+    assert_eq!(row.to_result(), results[5]);
+    assert!(series.row(results.len()).is_none());
+
+    assert_eq!(series.rows().count(), results.len());
+    assert!(series.rows().zip(&results).all(|(row, result)| row == *result));
+    assert_eq!(series.peak().unwrap().index(), results.len() - 1);
+    assert_eq!(series.to_results(), results);
+    assert_eq!(series.into_results(), results);
+}
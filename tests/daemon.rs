@@ -0,0 +1,84 @@
+//! Démon de simulation sur socket Unix
+#![cfg(unix)]
+
+use std::io::Cursor;
+use std::os::unix::net::UnixStream;
+use std::sync::Arc;
+use std::thread;
+
+use cyclogenese_rust::daemon::{self, Response, MAX_FRAME_SIZE};
+use cyclogenese_rust::metrics::Metrics;
+use cyclogenese_rust::{OutputLanguage, Scenario};
+
+fn socket(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("cyclogenese-{}-{}.sock", name, std::process::id()))
+}
+
+#[test]
+fn frames_round_trip_and_end_cleanly() {
+    let mut buffer = Vec::new();
+    daemon::write_frame(&mut buffer, b"{}").unwrap();
+    daemon::write_frame(&mut buffer, b"").unwrap();
+    assert_eq!(&buffer[..6], &[2, 0, 0, 0, b'{', b'}']);
+
+    let mut source = Cursor::new(buffer);
+    assert_eq!(daemon::read_frame(&mut source).unwrap().unwrap(), b"{}");
+    assert_eq!(daemon::read_frame(&mut source).unwrap().unwrap(), b"");
+    assert!(daemon::read_frame(&mut source).unwrap().is_none());
+
+    assert!(daemon::read_frame(&mut Cursor::new(vec![5, 0, 0, 0, b'a'])).is_err());
+    assert!(daemon::read_frame(&mut Cursor::new(vec![1, 0])).is_err());
+    let oversized = ((MAX_FRAME_SIZE + 1) as u32).to_le_bytes().to_vec();
+    assert!(daemon::read_frame(&mut Cursor::new(oversized)).is_err());
+}
+
+#[test]
+fn invalid_requests_get_an_error_response() {
+    assert!(matches!(daemon::respond(b"not json", OutputLanguage::French), Response::Error(_)));
+    match daemon::respond(b"{\"latitude\": 95.0}", OutputLanguage::English) {
+        Response::Error(message) => assert!(message.contains("Invalid latitude")),
+        response => panic!("réponse inattendue: {:?}", response),
+    }
+}
+
+#[test]
+fn oversized_responses_become_an_error_frame() {
+    let results = Scenario::default().run().unwrap();
+    let response = Response::Results(results.clone());
+    assert_eq!(daemon::encode(&response, OutputLanguage::French), serde_json::to_vec(&response).unwrap());
+
+    // Assez de copies du premier résultat pour dépasser une trame
+    let copies = MAX_FRAME_SIZE / serde_json::to_vec(&results[0]).unwrap().len() + 1;
+    let payload = daemon::encode(&Response::Results(vec![results[0].clone(); copies]), OutputLanguage::French);
+    assert!(payload.len() <= MAX_FRAME_SIZE);
+    match serde_json::from_slice(&payload).unwrap() {
+        Response::Error(message) => assert!(message.contains("au plus 16777216"), "{}", message),
+        response => panic!("réponse inattendue: {:?}", response),
+    }
+}
+
+#[test]
+fn daemon_serves_several_requests_per_connection() {
+    let path = socket("daemon");
+    let listener = daemon::bind(&path).unwrap();
+    let metrics = Arc::new(Metrics::default());
+    let served = Arc::clone(&metrics);
+    thread::spawn(move || daemon::serve(listener, OutputLanguage::French, served));
+    assert!(daemon::bind(&path).is_err(), "la socket est déjà servie");
+
+    let mut stream = UnixStream::connect(&path).unwrap();
+    let short = Scenario { time_steps: 6, ..Scenario::default() };
+    for scenario in [Scenario::default(), short] {
+        assert_eq!(daemon::request(&mut stream, &scenario).unwrap().len(), scenario.time_steps as usize);
+    }
+    let invalid = Scenario { latitude: 95.0, ..Scenario::default() };
+    assert!(daemon::request(&mut stream, &invalid).is_err());
+    let hours: Vec<u32> = daemon::request(&mut stream, &Scenario::default()).unwrap().iter().map(|r| r.hour()).collect();
+    assert_eq!(hours, (0..Scenario::default().time_steps).collect::<Vec<_>>());
+
+    let mut other = UnixStream::connect(&path).unwrap();
+    assert!(daemon::request(&mut other, &Scenario::default()).is_ok());
+    assert_eq!((metrics.simulations(), metrics.errors()), (4, 1));
+    assert_eq!(metrics.steps(), 3 * u64::from(Scenario::default().time_steps) + 6);
+    std::fs::remove_file(&path).unwrap();
+}
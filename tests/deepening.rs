@@ -0,0 +1,50 @@
+//! Creusement de la pression centrale en hPa par 24 h
+
+use cyclogenese_rust::deepening::{self, DEEPENING_WINDOW};
+use cyclogenese_rust::sting_jet::StingJetCriteria;
+use cyclogenese_rust::{scenarios, BaroclinicCyclogenesis, NumberFormat, OutputLanguage};
+
+#[test]
+fn rates_slide_over_24_hours() {
+    let mut cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap();
+    let results = cyclogenesis.simulate_interaction(36).unwrap();
+    let rates = cyclogenesis.deepening_rates(&results);
+    let pressure = |index: usize| cyclogenesis.central_pressure(&results[index]);
+
+    assert_eq!(rates.len(), results.len() - 1);
+    assert_eq!(rates[0].hour, 1);
+    // Fenêtre réduite au temps écoulé, baisse ramenée à 24 h
+    assert_eq!(rates[11].window, 12);
+    assert!((rates[11].rate - 2.0 * (pressure(0) - pressure(12))).abs() < 1e-9);
+    assert!(rates[23..].iter().all(|rate| rate.window == DEEPENING_WINDOW));
+    assert!((rates[34].rate - (pressure(11) - pressure(35))).abs() < 1e-9);
+    assert!(rates.iter().all(|rate| rate.rate > 0.0 && !rate.is_explosive()));
+}
+
+#[test]
+fn bergeron_matches_the_hourly_sting_jet_criterion() {
+    let scenario = scenarios::find("lothar-1999").unwrap().scenario();
+    let mut cyclogenesis = scenario.to_cyclogenesis().unwrap();
+    let results = cyclogenesis.simulate_interaction(scenario.time_steps).unwrap();
+    let rates = cyclogenesis.deepening_rates(&results);
+    let risks = cyclogenesis.sting_jet_risk(&results, &StingJetCriteria::default());
+
+    assert_eq!(rates[0].window, 1);
+    assert!((rates[0].bergeron - risks[1].deepening).abs() < 1e-9);
+    assert!(rates.iter().any(|rate| rate.is_explosive()));
+}
+
+#[test]
+fn table_ends_with_the_peak_deepening() {
+    let mut cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap();
+    let results = cyclogenesis.simulate_interaction(24).unwrap();
+    let rates = cyclogenesis.deepening_rates(&results);
+    let format = NumberFormat::default();
+    let table = deepening::to_table(&rates, OutputLanguage::English, &format);
+
+    assert!(table.starts_with("Hour  | Pressure (hPa)       | Deepening (hPa/24h)  | Deepening (Bergeron) | Window\n"));
+    assert!(table.contains("| 1 h (scaled to 24 h)\n"));
+    assert!(table.ends_with(&format!("\n{}\n", deepening::describe_peak(&rates, OutputLanguage::English, &format))));
+    assert!(deepening::describe_peak(&rates, OutputLanguage::English, &format).ends_with("Bergeron) at hour 23"));
+    assert_eq!(deepening::describe_peak(&[], OutputLanguage::French, &format), "Creusement sur 24 h indisponible");
+}
@@ -0,0 +1,41 @@
+//! Chaîne de calcul des anomalies au niveau de diagnostics complet
+
+use cyclogenese_rust::diagnostics;
+use cyclogenese_rust::{BaroclinicCyclogenesis, DiagnosticsLevel, OutputLanguage};
+
+#[test]
+fn standard_level_keeps_no_calculation_chain() {
+    let mut cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap();
+    assert_eq!(cyclogenesis.diagnostics_level(), DiagnosticsLevel::Standard);
+    let (_, steps) = cyclogenesis.simulate_with_diagnostics(6).unwrap();
+    assert!(steps.iter().all(|step| step.anomalies.is_empty()));
+    assert!(cyclogenesis.anomaly_diagnostics().is_empty());
+}
+
+#[test]
+fn full_level_exposes_each_anomaly_at_each_step() {
+    let mut full = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap().with_diagnostics_level(DiagnosticsLevel::Full);
+    let (results, steps) = full.simulate_with_diagnostics(6).unwrap();
+    let (reference, _) = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap().simulate_with_diagnostics(6).unwrap();
+    assert_eq!(results, reference);
+
+    for (hour, step) in steps.iter().enumerate() {
+        let [surface, altitude] = step.anomalies.as_slice() else {
+            panic!("deux anomalies attendues : {:?}", step.anomalies);
+        };
+        assert_eq!((surface.pressure, altitude.pressure), (1013.0, 500.0));
+        assert!((surface.coriolis - 7.2921e-5 * 45f64.to_radians().sin()).abs() < 1e-12);
+        assert!((altitude.pressure_factor - 2f64.sqrt()).abs() < 1e-12);
+        assert!((altitude.altitude_factor - (-5000.0f64 / 8000.0).exp()).abs() < 1e-12);
+        assert!((surface.intensity - (1.0 + hour as f64 / 12.0)).abs() < 1e-12);
+        assert!(surface.interaction_factor > 1.0);
+        // Anomalie chaude en surface, froide en altitude : vents thermiques de même signe
+        assert!(surface.thermal_wind > 0.0 && altitude.thermal_wind > 0.0);
+    }
+    assert_eq!(full.anomaly_diagnostics(), steps.last().unwrap().anomalies.as_slice());
+
+    let table = diagnostics::to_full_table(&steps, OutputLanguage::French, &Default::default());
+    assert_eq!(table.lines().count(), 2 + 2 * 6);
+    assert!(table.starts_with("Heure | Pression (hPa)"));
+    assert_eq!("full".parse::<DiagnosticsLevel>(), Ok(DiagnosticsLevel::Full));
+}
@@ -0,0 +1,46 @@
+//! Comparaison de simulations enregistrées
+
+use std::fs;
+
+use cyclogenese_rust::diff::{self, RunDiff};
+use cyclogenese_rust::{DevelopmentResult, MeteoError, Scenario};
+
+fn run(scenario: Scenario) -> Vec<DevelopmentResult> {
+    scenario.run().unwrap()
+}
+
+#[test]
+fn identical_runs_do_not_diverge() {
+    let results = run(Scenario::default());
+    let comparison = RunDiff::compute(&results, &results);
+    assert_eq!(comparison.steps().len(), 24);
+    assert_eq!(comparison.divergence_hour(0.0), None);
+    assert_eq!(comparison.max_vorticity_difference().unwrap().relative_vorticity, 0.0);
+}
+
+#[test]
+fn runs_are_aligned_by_hour() {
+    let first = run(Scenario::default());
+    let second = run(Scenario { time_steps: 36, meridional_gradient: Some(12.0), ..Scenario::default() });
+    let comparison = RunDiff::compute(&first, &second);
+
+    assert_eq!(comparison.steps().len(), 24);
+    assert_eq!(comparison.unmatched_hours(), (&[][..], &(24..36).collect::<Vec<_>>()[..]));
+    let largest = comparison.max_vorticity_difference().unwrap();
+    assert!(comparison.steps().iter().all(|step| step.relative_vorticity.abs() <= largest.relative_vorticity.abs()));
+    assert_eq!(comparison.divergence_hour(diff::DEFAULT_TOLERANCE), Some(0));
+}
+
+#[test]
+fn saved_runs_are_read_back() {
+    let path = std::env::temp_dir().join(format!("cyclogenese-diff-{}.json", std::process::id()));
+    let results = run(Scenario::default());
+    fs::write(&path, serde_json::to_string(&results).unwrap()).unwrap();
+    let loaded = diff::load(&path);
+    fs::write(&path, "{").unwrap();
+    let invalid = diff::load(&path);
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(RunDiff::compute(&results, &loaded.unwrap()).divergence_hour(1e-12), None);
+    assert!(matches!(invalid, Err(MeteoError::InvalidRun(_))));
+}
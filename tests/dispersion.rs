@@ -0,0 +1,52 @@
+//! Relation de dispersion du taux de croissance
+
+use std::f64::consts::PI;
+
+use cyclogenese_rust::dispersion::DispersionCurve;
+use cyclogenese_rust::{BaroclinicCyclogenesis, MeteoError, NumberFormat, OutputLanguage, Scenario};
+
+fn sweep() -> Vec<f64> {
+    (2..=24).map(|step| f64::from(step) * 5.0e5).collect()
+}
+
+#[test]
+fn the_most_unstable_scale_lies_inside_the_sweep() {
+    let curve = DispersionCurve::compute(&Scenario::default(), sweep()).unwrap();
+    let wavelengths = curve.points().iter().map(|point| point.wavelength).collect::<Vec<_>>();
+    assert_eq!(wavelengths, sweep());
+
+    let most_unstable = curve.most_unstable().unwrap();
+    assert!(most_unstable.growth_rate > 0.0);
+    let (first, last) = (curve.points().first().unwrap(), curve.points().last().unwrap());
+    assert!(most_unstable.growth_rate > first.growth_rate && most_unstable.growth_rate > last.growth_rate);
+    assert!((1.5e6..1.0e7).contains(&most_unstable.wavelength), "{}", most_unstable.wavelength);
+}
+
+#[test]
+fn short_waves_grow_slower_than_the_reference_scale() {
+    let reference = 2.0 * PI * 5.0e5;
+    let curve = DispersionCurve::compute(&Scenario::default(), [2.0 * PI * 2.0e5, reference]).unwrap();
+    let [short, reference] = curve.points() else { panic!("deux points attendus") };
+    assert!(short.growth_rate < reference.growth_rate, "{} < {}", short.growth_rate, reference.growth_rate);
+}
+
+#[test]
+fn the_reference_radius_is_the_default_scale() {
+    let scaled = Scenario { horizontal_scale: Some(5.0e5), ..Scenario::default() };
+    assert_eq!(scaled.run().unwrap(), Scenario::default().run().unwrap());
+    for radius in [0.0, 1.0e3, 2.0e7] {
+        let result = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap().with_horizontal_scale(radius);
+        assert!(matches!(result, Err(MeteoError::InvalidScale(_))), "{}", radius);
+    }
+}
+
+#[test]
+fn tables_list_each_wavelength_and_the_most_unstable_one() {
+    let curve = DispersionCurve::compute(&Scenario::default(), [2.0e6, 4.0e6]).unwrap();
+    let table = curve.to_table(OutputLanguage::English, &NumberFormat::default());
+    assert!(table.starts_with("Wavelength (km)"));
+    assert!(table.contains("2000.00 |") && table.contains("4000.00 |"));
+    let summary = table.lines().last().unwrap();
+    let best = curve.most_unstable().unwrap().wavelength / 1000.0;
+    assert!(summary.starts_with("Most unstable wavelength:") && summary.contains(&format!("{:.2} km", best)));
+}
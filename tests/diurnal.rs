@@ -0,0 +1,66 @@
+//! Chauffage solaire diurne de l'anomalie de surface
+
+use chrono::{DateTime, TimeZone, Utc};
+use cyclogenese_rust::{BaroclinicCyclogenesis, MeteoError};
+
+fn start(month: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(1999, month, 26, 0, 0, 0).unwrap()
+}
+
+fn diurnal(month: u32, longitude: f64) -> BaroclinicCyclogenesis {
+    BaroclinicCyclogenesis::new(5.0, -8.0, 48.0)
+        .unwrap()
+        .with_start_time(start(month))
+        .with_diurnal_cycle(longitude)
+        .unwrap()
+}
+
+#[test]
+fn heating_needs_a_start_time_and_a_longitude() {
+    let undated = BaroclinicCyclogenesis::new(5.0, -8.0, 48.0).unwrap().with_diurnal_cycle(0.0).unwrap();
+    let unplaced = BaroclinicCyclogenesis::new(5.0, -8.0, 48.0).unwrap().with_start_time(start(12));
+    assert!((0..24).all(|hour| undated.solar_heating(hour) == 0.0 && unplaced.solar_heating(hour) == 0.0));
+}
+
+#[test]
+fn afternoons_are_warmed_and_nights_cooled_without_net_heating() {
+    let cyclogenesis = diurnal(6, 0.0);
+    let heating = (0..24).map(|hour| cyclogenesis.solar_heating(hour)).collect::<Vec<_>>();
+    // Maximum en milieu d'après-midi (midi solaire et inertie du sol), minimum la nuit
+    let warmest = (0..24).max_by(|&a, &b| heating[a].total_cmp(&heating[b])).unwrap();
+    assert_eq!(warmest, 15);
+    assert!(heating[3] < 0.0 && heating[15] > 0.0);
+    let mean = heating.iter().sum::<f64>() / 24.0;
+    assert!(mean.abs() < 0.05, "moyenne journalière {} K", mean);
+}
+
+#[test]
+fn the_cycle_follows_local_solar_time() {
+    // À 90°E, le soleil passe au méridien six heures plus tôt qu'à Greenwich
+    let (greenwich, east) = (diurnal(12, 0.0), diurnal(12, 90.0));
+    for hour in 0..12 {
+        assert!((east.solar_heating(hour) - greenwich.solar_heating(hour + 6)).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn summer_days_swing_wider_than_winter_days() {
+    let swing = |cyclogenesis: BaroclinicCyclogenesis| {
+        let heating = (0..24).map(|hour| cyclogenesis.solar_heating(hour)).collect::<Vec<_>>();
+        heating.iter().cloned().fold(f64::MIN, f64::max) - heating.iter().cloned().fold(f64::MAX, f64::min)
+    };
+    let (summer, winter) = (swing(diurnal(6, 0.0)), swing(diurnal(12, 0.0)));
+    assert!(summer > winter && winter > 0.0, "{} > {}", summer, winter);
+}
+
+#[test]
+fn heating_modulates_the_development() {
+    let dated = |cyclogenesis: BaroclinicCyclogenesis| cyclogenesis.with_start_time(start(6));
+    let reference = dated(BaroclinicCyclogenesis::new(5.0, -8.0, 48.0).unwrap()).simulate_interaction(24).unwrap();
+    let heated = diurnal(6, 0.0).simulate_interaction(24).unwrap();
+    assert_ne!(reference[15].relative_vorticity(), heated[15].relative_vorticity());
+    assert!(matches!(
+        BaroclinicCyclogenesis::new(5.0, -8.0, 48.0).unwrap().with_diurnal_cycle(400.0),
+        Err(MeteoError::InvalidLongitude(_))
+    ));
+}
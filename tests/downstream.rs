@@ -0,0 +1,76 @@
+//! Développement en aval d'une chaîne de systèmes baroclines
+
+use cyclogenese_rust::downstream::{ChainConfig, DownstreamChain};
+use cyclogenese_rust::hovmoller::HovmollerAxis;
+use cyclogenese_rust::{MeteoError, NumberFormat, OutputLanguage, Scenario, UpperTrough};
+
+fn chain(hours: u32) -> DownstreamChain {
+    let config = ChainConfig::new(Scenario { time_steps: hours, ..Scenario::default() }, 4, 30.0, 0.64).unwrap();
+    DownstreamChain::simulate(&config).unwrap()
+}
+
+#[test]
+fn each_system_is_seeded_by_the_decay_of_the_previous_one() {
+    let chain = chain(120);
+    let members = chain.members();
+    assert_eq!(members.len(), 4);
+    assert_eq!((members[0].onset, members[0].longitude, members[0].altitude_temp), (0, 0.0, -8.0));
+    for pair in members.windows(2) {
+        assert_eq!(Some(pair[1].onset), pair[0].decay_onset());
+        assert_eq!(pair[1].longitude, pair[0].longitude + 30.0);
+        assert!((pair[1].altitude_temp - 0.8 * pair[0].altitude_temp).abs() < 1e-12);
+        let peak = |member: &cyclogenese_rust::downstream::ChainMember| member.series.peak().unwrap().relative_vorticity();
+        assert!(peak(&pair[1]).abs() < peak(&pair[0]).abs());
+    }
+}
+
+#[test]
+fn chain_stops_at_the_end_of_the_run() {
+    assert_eq!(chain(20).members().len(), 1);
+    assert!(chain(20).group_velocity().is_none());
+    let short = chain(48);
+    assert_eq!(short.members().len(), 3);
+    assert!(short.members().iter().all(|member| member.onset < 48));
+
+    // Sans déclin avant la fin, aucun système n'est amorcé en aval
+    let scenario = Scenario { time_steps: 120, ..Scenario::default() };
+    let mut config = ChainConfig::new(scenario, 4, 30.0, 0.64).unwrap();
+    config.trough = UpperTrough::new(4.0, 1.5e6, 0.0).unwrap().with_distance(7.5e5).unwrap();
+    assert_eq!(DownstreamChain::simulate(&config).unwrap().members().len(), 1);
+}
+
+#[test]
+fn wave_packet_outruns_its_systems() {
+    let chain = chain(120);
+    let speed = chain.group_velocity().unwrap();
+    assert!(speed > 15.0, "{}", speed);
+    let text = chain.to_table(OutputLanguage::English, &NumberFormat::default());
+    assert!(text.starts_with("System  | Longitude (°) | Onset (h)"), "{}", text);
+    assert!(text.contains("Wave packet group velocity"), "{}", text);
+}
+
+#[test]
+fn hovmoller_follows_the_packet_eastward() {
+    let chain = chain(120);
+    let diagram = chain.hovmoller(&HovmollerAxis::new(10.0, 1.0).unwrap());
+    assert_eq!(diagram.hours().len(), 120);
+    assert_eq!(diagram.longitudes().first(), Some(&-10.0));
+    assert_eq!(diagram.longitudes().last(), Some(&100.0));
+    let first = chain.members()[0].series.row(0).unwrap().relative_vorticity();
+    assert_eq!(diagram.row(0).unwrap()[10], first);
+
+    // Au pic de chaque système, le centre du paquet est passé sur lui
+    let centers = diagram.center_longitudes();
+    for member in chain.members() {
+        let peak = member.onset + member.series.peak().unwrap().hour();
+        assert_eq!(centers[peak as usize], member.longitude, "{:?}", centers);
+    }
+}
+
+#[test]
+fn rejects_an_invalid_chain() {
+    for (members, spacing, transfer) in [(0, 30.0, 0.5), (4, 0.0, 0.5), (4, 30.0, 0.0), (4, 30.0, 1.5)] {
+        let config = ChainConfig::new(Scenario::default(), members, spacing, transfer);
+        assert!(matches!(config, Err(MeteoError::InvalidScenario(_))), "{} {} {}", members, spacing, transfer);
+    }
+}
@@ -0,0 +1,56 @@
+//! Cycle énergétique de Lorenz des perturbations
+
+use std::process::Command;
+
+use cyclogenese_rust::energetics::{self, EnergyBudget};
+use cyclogenese_rust::{BaroclinicCyclogenesis, DevelopmentResult, NumberFormat, OutputLanguage};
+
+fn simulate(surface: f64, altitude: f64) -> (Vec<DevelopmentResult>, Vec<EnergyBudget>) {
+    let mut cyclogenesis = BaroclinicCyclogenesis::new(surface, altitude, 45.0).unwrap();
+    let (results, steps) = cyclogenesis.simulate_with_diagnostics(24).unwrap();
+    (results, steps.iter().map(|step| step.energy).collect())
+}
+
+#[test]
+fn kinetic_energy_follows_the_vortex_wind() {
+    let (results, budgets) = simulate(5.0, -8.0);
+    assert_eq!(budgets.iter().map(|budget| budget.hour).collect::<Vec<_>>(), (0..24).collect::<Vec<_>>());
+    // EKE = ½·(ζ·R)², à rayon constant
+    let ratio = budgets[23].eddy_kinetic / budgets[0].eddy_kinetic;
+    let vorticity = results[23].relative_vorticity() / results[0].relative_vorticity();
+    assert!((ratio / vorticity.powi(2) - 1.0).abs() < 1e-9);
+    assert!(budgets[23].eddy_kinetic > budgets[0].eddy_kinetic);
+
+    // Anomalies imposées : l'APE ne change pas sans forçage
+    assert!(budgets.iter().all(|budget| budget.eddy_potential > 0.0 && budget.eddy_potential == budgets[0].eddy_potential));
+}
+
+#[test]
+fn conversion_follows_the_ascent_of_the_net_anomaly() {
+    let (results, budgets) = simulate(5.0, -8.0);
+    // (g/T₀)·w·T' : même facteur à chaque pas, de signe opposé à w pour une colonne froide
+    let factor = budgets[0].conversion / results[0].vertical_velocity();
+    assert!(factor < 0.0);
+    for (budget, result) in budgets.iter().zip(&results) {
+        assert!((budget.conversion / result.vertical_velocity() / factor - 1.0).abs() < 1e-9);
+    }
+
+    // Anomalies qui se compensent : ni APE ni conversion
+    let (_, balanced) = simulate(5.0, -5.0);
+    assert!(balanced.iter().all(|budget| budget.eddy_potential == 0.0 && budget.conversion == 0.0));
+}
+
+#[test]
+fn budget_tables_list_each_step() {
+    let (_, budgets) = simulate(5.0, -8.0);
+    let format = NumberFormat::default();
+    let table = energetics::to_table(&budgets, OutputLanguage::English, &format);
+    let lines = table.lines().collect::<Vec<_>>();
+    assert_eq!(lines[0], "Hour  | APE (J/kg)           | EKE (J/kg)           | Conversion (W/kg)");
+    assert_eq!(lines.len(), 26);
+    assert!(lines[2].starts_with("   0 | ") && lines[2].contains(&format.format(budgets[0].eddy_kinetic)));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_cyclogenese")).arg("--energy").output().unwrap();
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap().matches("Heure | APE (J/kg)").count(), 3);
+}
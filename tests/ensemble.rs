@@ -0,0 +1,237 @@
+//! Ensemble perturbé et scores probabilistes
+
+use cyclogenese_rust::ensemble::{
+    self, Ensemble, EnsembleConfig, EnsembleMember, EnsembleVariable, ExceedanceOutput, LaggedRun, Threshold,
+};
+use cyclogenese_rust::io::observations;
+use cyclogenese_rust::verify::{self, BrierScore, EnsembleVerification, ProbabilisticScores};
+use cyclogenese_rust::{MeteoError, NumberFormat, OutputLanguage, Scenario};
+
+#[test]
+fn ensemble_is_reproducible_and_perturbs_the_anomalies() {
+    let scenario = Scenario::default();
+    let config = EnsembleConfig::new(5, 1.0, 0.5).unwrap().with_seed(7);
+    let a = Ensemble::generate(&scenario, &config).unwrap();
+    let b = Ensemble::generate(&scenario, &config).unwrap();
+    assert_eq!(a.members().len(), 5);
+    for (a, b) in a.members().iter().zip(b.members()) {
+        assert_eq!(a.scenario.surface_temp, b.scenario.surface_temp);
+        assert_eq!(a.results, b.results);
+    }
+    assert!(a.members().windows(2).all(|pair| pair[0].scenario.surface_temp != pair[1].scenario.surface_temp));
+
+    let other = Ensemble::generate(&scenario, &config.with_seed(8)).unwrap();
+    assert_ne!(other.members()[0].scenario.surface_temp, a.members()[0].scenario.surface_temp);
+
+    // Sans dispersion, chaque membre reprend le contrôle
+    let control = Ensemble::generate(&scenario, &EnsembleConfig::new(3, 0.0, 0.0).unwrap()).unwrap();
+    assert!(control.members().iter().all(|member| member.scenario == scenario));
+
+    assert!(matches!(EnsembleConfig::new(1, 1.0, 1.0), Err(MeteoError::InvalidScenario(_))));
+    assert!(EnsembleConfig::new(10, -1.0, 1.0).is_err());
+    assert!(Ensemble::from_members(vec![EnsembleMember::simulate(scenario).unwrap()]).is_err());
+
+    let table = a.to_table(OutputLanguage::English, &NumberFormat::default());
+    assert!(table.starts_with("Hour  | Mean ζ"));
+    assert!(table.ends_with("\nEnsemble of 5 members\n"));
+}
+
+#[test]
+fn member_values_are_interpolated_between_hours() {
+    let member = EnsembleMember::simulate(Scenario::default()).unwrap();
+    let (a, b) = (member.states[2].central_pressure, member.states[3].central_pressure);
+    let midway = member.value(EnsembleVariable::Pressure, 2.5).unwrap();
+    assert!((midway - (a + b) / 2.0).abs() < 1e-9);
+    assert_eq!(member.value(EnsembleVariable::Wind, 3.0), Some(member.states[3].surface_wind));
+    assert_eq!(member.value(EnsembleVariable::Vorticity, 500.0), None);
+    // Le creusement commence à la deuxième heure
+    assert_eq!(member.value(EnsembleVariable::Deepening, f64::from(member.states[0].hour)), None);
+}
+
+#[test]
+fn thresholds_parse_and_compare_in_their_units() {
+    let threshold: Threshold = "deepening>20".parse().unwrap();
+    assert_eq!((threshold.variable, threshold.above, threshold.value), (EnsembleVariable::Deepening, true, 20.0));
+    assert_eq!(threshold.to_string(), "deepening>20");
+    assert!(threshold.is_met(24.0) && !threshold.is_met(20.0));
+
+    let vorticity: Threshold = "Vorticity > 10".parse().unwrap();
+    assert!(vorticity.is_met(-1.2e-4) && !vorticity.is_met(-0.8e-4));
+    let pressure: Threshold = "pressure<980".parse().unwrap();
+    assert_eq!(pressure.probability(&[975.0, 985.0, 990.0, 970.0]), Some(0.5));
+    assert_eq!(pressure.probability(&[]), None);
+
+    for text in ["gusts>30", "wind", "wind>abc", "pressure<"] {
+        assert!(text.parse::<Threshold>().unwrap_err().starts_with("Seuil inconnu"), "{}", text);
+    }
+}
+
+#[test]
+fn crps_rank_and_brier_follow_their_definitions() {
+    // Ensemble ponctuel : le CRPS se réduit à l'erreur absolue
+    assert_eq!(verify::crps(&[3.0, 3.0], 1.0), 2.0);
+    // mean|xᵢ − y| = 1, mean|xᵢ − xⱼ| = 1
+    assert_eq!(verify::crps(&[0.0, 2.0], 1.0), 0.5);
+    assert_eq!(verify::rank(&[1.0, 2.0, 3.0], 2.5), 2);
+
+    let scores = ProbabilisticScores::compute(&[(vec![1.0, 2.0], 0.0), (vec![1.0, 2.0], 3.0), (vec![1.0, 2.0], 1.5)], 2).unwrap();
+    assert_eq!(scores.rank_histogram, [1, 1, 1]);
+    assert_eq!(scores.count, 3);
+    assert!(ProbabilisticScores::compute(&[], 2).is_none());
+
+    let brier = BrierScore::compute(&[(1.0, true), (0.5, false), (0.0, false), (0.0, true)]).unwrap();
+    assert_eq!((brier.count, brier.score, brier.observed_frequency), (4, 1.25 / 4.0, 0.5));
+    assert!(BrierScore::compute(&[]).is_none());
+}
+
+#[test]
+fn ensemble_is_scored_against_observations() {
+    let scenario = Scenario::default();
+    let ensemble = Ensemble::generate(&scenario, &EnsembleConfig::new(4, 0.0, 0.0).unwrap()).unwrap();
+    let member = &ensemble.members()[0];
+    let (early, late) = (&member.states[2], &member.states[20]);
+
+    // Observations identiques au contrôle : rang 0 (aucun membre strictement inférieur), CRPS nul
+    let text = format!(
+        "hour,central_pressure,relative_vorticity\n2,{},{}\n20,{},\n90,990,\n",
+        early.central_pressure, early.relative_vorticity, late.central_pressure
+    );
+    let observations = observations::parse_csv(&text, None).unwrap();
+    let events: Vec<Threshold> = ["deepening>0", "pressure<900", "wind>20"].iter().map(|event| event.parse().unwrap()).collect();
+    let verification = EnsembleVerification::compute(&ensemble, &observations, &events).unwrap();
+
+    assert_eq!(verification.discarded(), 1);
+    let pressure = verification.pressure().unwrap();
+    assert_eq!((pressure.count, pressure.rank_histogram.clone()), (2, vec![2, 0, 0, 0, 0]));
+    assert!(pressure.crps.abs() < 1e-9);
+    assert_eq!(verification.vorticity().unwrap().count, 1);
+
+    // La pression observée baisse entre les heures 2 et 20, comme celle de tous les membres
+    let brier = verification.brier();
+    assert_eq!(brier[0].1.map(|score| (score.count, score.score, score.observed_frequency)), Some((1, 0.0, 1.0)));
+    assert_eq!(brier[1].1.map(|score| score.score), Some(0.0));
+    assert_eq!(brier[2].1, None);
+
+    let table = verification.to_table(OutputLanguage::French, &NumberFormat::default());
+    assert!(table.starts_with("Vérification de l'ensemble (4 membres)\n"));
+    assert!(table.contains("histogramme des rangs [2, 0, 0, 0, 0]"));
+    assert!(table.contains("Brier wind>20 : aucune observation"));
+
+    let outside = observations::parse_csv("hour,pressure\n500,990\n", None).unwrap();
+    assert!(matches!(
+        EnsembleVerification::compute(&ensemble, &outside, &events),
+        Err(MeteoError::InvalidObservations(_))
+    ));
+}
+
+#[test]
+fn exceedance_probabilities_are_member_fractions() {
+    let scenario = Scenario::default();
+    let ensemble = Ensemble::generate(&scenario, &EnsembleConfig::new(8, 1.5, 1.5).unwrap().with_seed(3)).unwrap();
+    let events: Vec<Threshold> = ["deepening>5", "wind>15"].iter().map(|event| event.parse().unwrap()).collect();
+    let steps = ensemble.exceedance(&events);
+
+    assert_eq!(steps.len(), ensemble.hours().len());
+    // Creusement indéfini à la première heure
+    assert_eq!(steps[0].probabilities[0].probability, None);
+    for step in &steps {
+        let winds = ensemble.values(EnsembleVariable::Wind, f64::from(step.hour));
+        let expected = winds.iter().filter(|wind| **wind > 15.0).count() as f64 / 8.0;
+        assert_eq!(step.probabilities[1].probability, Some(expected));
+        assert_eq!(step.probabilities[1].event, events[1]);
+    }
+
+    let format = NumberFormat::default();
+    let table = ensemble::exceedance_table(&steps, OutputLanguage::English, &format);
+    assert!(table.starts_with("Hour  | deepening>5          | wind>15\n------|"));
+    assert!(table.contains("Peak probability of wind>15: "));
+    assert!(ensemble::describe_peaks(&steps[..1], OutputLanguage::French, &format)
+        .starts_with("Probabilité de deepening>5 indisponible\n"));
+
+    let json: serde_json::Value = serde_json::from_str(&ensemble::exceedance_json(&steps)).unwrap();
+    assert_eq!(json[0]["probabilities"][0]["event"], "deepening>5");
+    assert!(json[0]["probabilities"][0]["probability"].is_null());
+    assert_eq!(json.as_array().unwrap().len(), steps.len());
+
+    assert_eq!("json".parse::<ExceedanceOutput>(), Ok(ExceedanceOutput::Json));
+    assert!("csv".parse::<ExceedanceOutput>().is_err());
+}
+
+#[cfg(feature = "netcdf")]
+#[test]
+fn exceedance_probabilities_round_trip_through_netcdf() {
+    use netcdf3::FileReader;
+
+    let ensemble = Ensemble::generate(&Scenario::default(), &EnsembleConfig::default()).unwrap();
+    let events: Vec<Threshold> = ["pressure<1005.5", "wind>15"].iter().map(|event| event.parse().unwrap()).collect();
+    let steps = ensemble.exceedance(&events);
+    let path = std::env::temp_dir().join(format!("cyclogenese-exceedance-{}.nc", std::process::id()));
+    cyclogenese_rust::io::netcdf::write_exceedance(&path, &steps).unwrap();
+
+    let mut reader = FileReader::open(&path).unwrap();
+    let pressure = reader.read_var_f64("probability_pressure_below_1005_5").unwrap();
+    let wind = reader.read_var_f64("probability_wind_above_15").unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(pressure, steps.iter().map(|step| step.probabilities[0].probability.unwrap()).collect::<Vec<_>>());
+    assert_eq!(wind.len(), steps.len());
+}
+
+#[test]
+fn lagged_members_start_along_the_control_run() {
+    let scenario = Scenario::from_toml("geometry = \"spherical\"\nstart_time = 1999-12-25T12:00:00Z\nlongitude = -4.5\n").unwrap();
+    let control = scenario.run().unwrap();
+    let runs = [LaggedRun { start_hour: 0, weight: 1.0 }, LaggedRun { start_hour: 6, weight: 3.0 }];
+    let ensemble = Ensemble::lagged(&scenario, &runs).unwrap();
+    let (first, lagged) = (&ensemble.members()[0], &ensemble.members()[1]);
+
+    assert_eq!(first.results, control);
+    assert_eq!(lagged.results.len(), control.len() - 6);
+    // Départ aux heures et à la date du contrôle, depuis la position atteinte
+    assert_eq!(lagged.results[0].hour(), 6);
+    assert_eq!(lagged.results[0].valid_time(), control[6].valid_time());
+    assert_eq!(lagged.results[0].longitude(), control[6].longitude());
+    // Les anomalies repartent de leur état initial : le membre décalé est moins développé
+    assert!(lagged.results[0].relative_vorticity().abs() < control[6].relative_vorticity().abs());
+    assert_eq!(lagged.deepening[0].hour, 7);
+    assert_eq!(ensemble.hours(), (0..scenario.time_steps).collect::<Vec<_>>());
+
+    // Avant le second départ, seul le contrôle est présent
+    assert_eq!(ensemble.weighted_values(EnsembleVariable::Pressure, 3.0).len(), 1);
+    assert_eq!(ensemble.weighted_values(EnsembleVariable::Pressure, 10.0)[1].1, 3.0);
+
+    for runs in [
+        vec![LaggedRun { start_hour: 0, weight: 1.0 }, LaggedRun { start_hour: 0, weight: 1.0 }],
+        vec![LaggedRun { start_hour: 0, weight: 1.0 }, LaggedRun { start_hour: 500, weight: 1.0 }],
+        vec![LaggedRun { start_hour: 0, weight: 0.0 }, LaggedRun { start_hour: 6, weight: 0.0 }],
+    ] {
+        assert!(matches!(Ensemble::lagged(&scenario, &runs), Err(MeteoError::InvalidScenario(_))));
+    }
+}
+
+#[test]
+fn weights_enter_means_probabilities_and_scores() {
+    let members = [4.0, 6.0]
+        .into_iter()
+        .map(|surface_temp| EnsembleMember::simulate(Scenario { surface_temp, ..Scenario::default() }).unwrap())
+        .collect();
+    let ensemble = Ensemble::from_members(members).unwrap();
+    assert_eq!(ensemble.weights(), [1.0, 1.0]);
+    let weighted = ensemble.clone().with_weights(&[3.0, 1.0]).unwrap();
+
+    let last = f64::from(*ensemble.hours().last().unwrap());
+    let pressures = ensemble.values(EnsembleVariable::Pressure, last);
+    let between = (pressures[0] + pressures[1]) / 2.0;
+    let threshold = Threshold { variable: EnsembleVariable::Pressure, above: pressures[0] > pressures[1], value: between };
+    let probability = |ensemble: &Ensemble| ensemble.exceedance(&[threshold]).last().unwrap().probabilities[0].probability;
+    assert_eq!(probability(&ensemble), Some(0.5));
+    assert_eq!(probability(&weighted), Some(0.75));
+    assert_eq!(threshold.weighted_probability(&[(between, 1.0)]), Some(0.0));
+
+    // CRPS pondéré : un poids nul écarte le membre
+    assert_eq!(verify::weighted_crps(&[(0.0, 1.0), (2.0, 1.0)], 1.0), verify::crps(&[0.0, 2.0], 1.0));
+    assert_eq!(verify::weighted_crps(&[(3.0, 1.0), (10.0, 0.0)], 1.0), 2.0);
+
+    for weights in [&[1.0][..], &[1.0, -1.0], &[0.0, 0.0], &[1.0, f64::NAN]] {
+        assert!(ensemble.clone().with_weights(weights).is_err(), "{:?}", weights);
+    }
+}
@@ -0,0 +1,116 @@
+//! Lecture des extraits ERA5
+#![cfg(feature = "netcdf")]
+
+use std::path::PathBuf;
+
+use cyclogenese_rust::io::era5::{Era5Dataset, TrackPoint};
+use cyclogenese_rust::MeteoError;
+use netcdf3::{DataSet, FileWriter, Version};
+
+const LEVELS: [f64; 2] = [850.0, 300.0];
+const LATITUDES: [f64; 3] = [40.0, 45.0, 50.0];
+const LONGITUDES: [f64; 4] = [-10.0, -5.0, 0.0, 5.0];
+
+fn temporary(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("cyclogenese-era5-{}-{}.nc", name, std::process::id()))
+}
+
+/// Température de l'extrait, différente selon chaque axe (K)
+fn temperature(time: usize, level: usize, latitude: usize, longitude: usize) -> f64 {
+    280.0 - 40.0 * level as f64 - LATITUDES[latitude] / 10.0 + time as f64 + 0.5 * longitude as f64
+}
+
+/// Écrit un extrait ERA5 de deux instants
+fn write(path: &PathBuf) {
+    let longitudes = LONGITUDES;
+    let mut data_set = DataSet::new();
+    for (name, size) in [("time", 2), ("level", 2), ("latitude", 3), ("longitude", longitudes.len())] {
+        data_set.add_fixed_dim(name, size).unwrap();
+    }
+    for name in ["time", "level", "latitude", "longitude"] {
+        data_set.add_var_f64(name, &[name]).unwrap();
+    }
+    data_set.add_var_f64("t", &["time", "level", "latitude", "longitude"]).unwrap();
+    data_set.add_var_attr_string("time", "units", "hours since 1900-01-01").unwrap();
+
+    let mut values = Vec::new();
+    for time in 0..2 {
+        for level in 0..2 {
+            for latitude in 0..3 {
+                values.extend((0..longitudes.len()).map(|longitude| temperature(time, level, latitude, longitude)));
+            }
+        }
+    }
+    let mut writer = FileWriter::open(path).unwrap();
+    writer.set_def(&data_set, Version::Classic, 0).unwrap();
+    writer.write_var_f64("time", &[876_576.0, 876_582.0]).unwrap();
+    writer.write_var_f64("level", &LEVELS).unwrap();
+    writer.write_var_f64("latitude", &LATITUDES).unwrap();
+    writer.write_var_f64("longitude", &longitudes).unwrap();
+    writer.write_var_f64("t", &values).unwrap();
+    writer.close().unwrap();
+}
+
+#[test]
+fn temperature_and_track_anomalies_are_read() {
+    let path = temporary("track");
+    write(&path);
+    let dataset = Era5Dataset::open(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(dataset.times(), [876_576.0, 876_582.0]);
+    assert_eq!(dataset.time_units(), Some("hours since 1900-01-01"));
+    assert_eq!(dataset.levels(), LEVELS);
+    // Point de grille le plus proche, longitudes prises modulo 360°
+    assert_eq!(dataset.temperature(1, 300.0, 44.0, 354.0).unwrap(), temperature(1, 1, 1, 1));
+
+    let track = [TrackPoint { time_index: 0, latitude: 50.0, longitude: 5.0 }];
+    let scenarios = dataset.track_scenarios(&track, 850.0, 300.0).unwrap();
+    // Moyenne du domaine : 280 - 4.5 + 0.75 K à 850 hPa au premier instant
+    assert!((scenarios[0].surface_temp - (temperature(0, 0, 2, 3) - 276.25)).abs() < 1e-12);
+    assert!(scenarios[0].to_cyclogenesis().is_ok());
+
+    assert!(matches!(dataset.temperature(2, 850.0, 45.0, 0.0), Err(MeteoError::InvalidNetcdf(_))));
+    assert!(matches!(dataset.temperature(0, 500.0, 45.0, 0.0), Err(MeteoError::InvalidNetcdf(_))));
+}
+
+#[test]
+fn empty_dimensions_are_an_error() {
+    // Une dimension fixe n'est jamais vide : les longitudes sont ici la dimension
+    // illimitée, sans enregistrement, et viennent en tête de `t`
+    let path = temporary("empty");
+    let mut data_set = DataSet::new();
+    data_set.set_unlimited_dim("longitude", 0).unwrap();
+    for (name, size) in [("time", 2), ("level", 2), ("latitude", 3)] {
+        data_set.add_fixed_dim(name, size).unwrap();
+    }
+    for name in ["time", "level", "latitude", "longitude"] {
+        data_set.add_var_f64(name, &[name]).unwrap();
+    }
+    data_set.add_var_f64("t", &["longitude", "time", "level", "latitude"]).unwrap();
+    let mut writer = FileWriter::open(&path).unwrap();
+    writer.set_def(&data_set, Version::Classic, 0).unwrap();
+    writer.write_var_f64("time", &[876_576.0, 876_582.0]).unwrap();
+    writer.write_var_f64("level", &LEVELS).unwrap();
+    writer.write_var_f64("latitude", &LATITUDES).unwrap();
+    writer.close().unwrap();
+
+    let dataset = Era5Dataset::open(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    match dataset.temperature(0, 850.0, 45.0, 0.0) {
+        Err(MeteoError::InvalidNetcdf(message)) => assert!(message.contains("longitude"), "{}", message),
+        outcome => panic!("résultat inattendu: {:?}", outcome),
+    }
+}
+
+#[test]
+fn netcdf4_files_are_refused() {
+    let path = temporary("hdf5");
+    std::fs::write(&path, b"\x89HDF\r\n\x1a\n\0\0\0\0").unwrap();
+    let outcome = Era5Dataset::open(&path);
+    std::fs::remove_file(&path).unwrap();
+    match outcome {
+        Err(MeteoError::InvalidNetcdf(message)) => assert!(message.contains("NetCDF-4") && message.contains("nccopy")),
+        outcome => panic!("résultat inattendu: {:?}", outcome.map(|_| ())),
+    }
+}
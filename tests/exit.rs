@@ -0,0 +1,54 @@
+//! Codes de sortie par classe d'erreur et compte rendu JSON
+
+use cyclogenese_rust::exit::{ErrorClass, ErrorFormat, ErrorReport};
+use cyclogenese_rust::{BaroclinicCyclogenesis, MeteoError, OutputLanguage};
+
+#[test]
+fn error_classes_have_distinct_exit_codes() {
+    let errors = [
+        (MeteoError::InvalidLatitude(95.0), ErrorClass::InvalidScenario),
+        (MeteoError::InvalidScenario("champ inconnu".to_string()), ErrorClass::InvalidScenario),
+        (MeteoError::InvalidGrib("section tronquée".to_string()), ErrorClass::InvalidInput),
+        (MeteoError::InvalidRun("disque plein".to_string()), ErrorClass::RunFailure),
+    ];
+    for (error, class) in &errors {
+        assert_eq!(error.class(), *class, "{:?}", error);
+    }
+
+    let mut codes: Vec<_> = [
+        ErrorClass::Usage,
+        ErrorClass::InvalidScenario,
+        ErrorClass::InvalidInput,
+        ErrorClass::NumericalInstability,
+        ErrorClass::RunFailure,
+    ]
+    .iter()
+    .map(|class| class.exit_code())
+    .collect();
+    codes.dedup();
+    assert_eq!(codes.len(), 5);
+    assert!(!codes.contains(&0) && !codes.contains(&1));
+}
+
+#[test]
+fn numerical_blow_up_is_its_own_class() {
+    let error = BaroclinicCyclogenesis::new(30.0, 20.0, 60.0).unwrap().simulate_interaction(10_000).unwrap_err();
+    assert_eq!(error.class(), ErrorClass::NumericalInstability);
+    assert_eq!(ErrorReport::new(&error, OutputLanguage::French).kind, "NumericalInstability");
+}
+
+#[test]
+fn json_report_carries_kind_class_and_code() {
+    let report = ErrorReport::new(&MeteoError::InvalidLatitude(95.0), OutputLanguage::English);
+    assert_eq!(report.render(ErrorFormat::Text), "Invalid latitude: 95°");
+
+    let json: serde_json::Value = serde_json::from_str(&report.render(ErrorFormat::Json)).unwrap();
+    assert_eq!(json["kind"], "InvalidLatitude");
+    assert_eq!(json["class"], "invalid_scenario");
+    assert_eq!(json["exit_code"], 3);
+    assert_eq!(json["message"], "Invalid latitude: 95°");
+
+    assert_eq!(ErrorReport::usage("locale inconnue").exit_code, 2);
+    assert_eq!("JSON".parse::<ErrorFormat>(), Ok(ErrorFormat::Json));
+    assert!("xml".parse::<ErrorFormat>().is_err());
+}
@@ -0,0 +1,44 @@
+//! Explication terme à terme d'un pas de temps
+
+use cyclogenese_rust::{BaroclinicCyclogenesis, MeteoError, OutputLanguage, Scenario};
+
+#[test]
+fn explanation_matches_the_simulated_step() {
+    let mut reference = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap();
+    let expected = reference.simulate_interaction(13).unwrap().pop().unwrap();
+
+    let mut cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap();
+    let explanation = cyclogenesis.explain(12).unwrap();
+    assert_eq!(explanation.result, expected);
+    assert_eq!(explanation.anomalies.len(), 2);
+
+    // La somme des contributions pondérées redonne le résultat du pas
+    let vertical_velocity: f64 =
+        explanation.anomalies.iter().map(|anomaly| anomaly.chain.vertical_velocity * anomaly.chain.interaction_factor).sum();
+    assert!((vertical_velocity - expected.vertical_velocity()).abs() < 1e-15);
+
+    let surface = &explanation.anomalies[0];
+    let thermal_wind = surface.temperature_delta / explanation.base_temp * explanation.gravity * 1000.0 * surface.chain.coriolis;
+    assert!((surface.chain.thermal_wind - thermal_wind).abs() < 1e-15);
+}
+
+#[test]
+fn description_gives_formulas_and_values() {
+    let explanation = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap().explain(0).unwrap();
+    let text = explanation.describe(OutputLanguage::French, &Default::default());
+    assert!(text.starts_with("Explication du pas de l'heure 0\n"));
+    assert!(text.contains("Vent thermique V_T = (ΔT + F)/T₀ · g · 1000 · Ω·sin φ = (5.00 + 0.00)/288 · 9.81 · 1000 · 5.16e-5 = 0.00878"));
+    assert!(text.contains("Terme de Coriolis Ω·sin φ = 7.29e-5 · sin(45.0°) = 5.16e-5 s⁻¹"));
+    assert!(text.contains("Divergence des basses couches = −π · w/H"));
+
+    let english = explanation.describe(OutputLanguage::English, &Default::default());
+    assert!(english.contains("Thermal wind V_T = "));
+}
+
+#[test]
+fn hour_before_the_start_is_rejected() {
+    let trunk = Scenario::default().run().unwrap();
+    let mut restarted = BaroclinicCyclogenesis::from_state(&Scenario::default(), &trunk[..6]).unwrap();
+    assert!(matches!(restarted.explain(3), Err(MeteoError::InvalidScenario(_))));
+    assert_eq!(restarted.explain(6).unwrap().result.hour(), 6);
+}
@@ -0,0 +1,55 @@
+//! Mise en forme des nombres
+
+use cyclogenese_rust::format::{ExponentStyle, Precision};
+use cyclogenese_rust::{BaroclinicCyclogenesis, NumberFormat};
+
+#[test]
+fn locales_choose_the_decimal_separator() {
+    let default = NumberFormat::default();
+    assert_eq!(default.format(1234.5678), "1234.57");
+
+    let french = default.with_locale("fr-FR").unwrap();
+    assert_eq!(french.decimal_separator(), ',');
+    assert_eq!(french.format(1234.5678), "1234,57");
+    assert_eq!(default.with_locale("de").unwrap().format(-0.5), "-0,50");
+    assert_eq!(french.with_locale("en_US").unwrap().format(0.5), "0.50");
+
+    for locale in ["", "f", "1234", "français"] {
+        assert!(default.with_locale(locale).is_err(), "{}", locale);
+    }
+}
+
+#[test]
+fn precision_counts_decimals_or_significant_digits() {
+    let decimals = NumberFormat::default().with_precision(Precision::Decimals(4));
+    assert_eq!(decimals.format(2.0), "2.0000");
+
+    let significant = NumberFormat::default().with_precision(Precision::Significant(3));
+    assert_eq!(significant.format(98.765), "98.8");
+    assert_eq!(significant.format(0.00123456), "0.00123");
+    assert_eq!(significant.format(0.0), "0.00");
+}
+
+#[test]
+fn exponents_are_written_on_demand() {
+    let scientific = NumberFormat::default().with_exponent(ExponentStyle::Scientific);
+    assert_eq!(scientific.format(0.000123), "1.23e-4");
+
+    let auto = NumberFormat::default().with_exponent(ExponentStyle::Auto).with_precision(Precision::Significant(2));
+    assert_eq!(auto.format(0.0001), "1.0e-4");
+    assert_eq!(auto.format(2.5e7), "2.5e7");
+    assert_eq!(auto.format(12.7), "13");
+    assert_eq!(auto.format(0.0), "0.0");
+
+    assert_eq!("Scientific".parse::<ExponentStyle>(), Ok(ExponentStyle::Scientific));
+    assert!("engineering".parse::<ExponentStyle>().is_err());
+}
+
+#[test]
+fn table_rows_use_the_format() {
+    let result = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap().step(6).unwrap();
+    let french = NumberFormat::default().with_locale("fr").unwrap();
+    assert_eq!(result.to_string(), result.to_string_with(&NumberFormat::default()));
+    assert_eq!(result.to_string_with(&french), result.to_string().replace('.', ","));
+    assert_eq!(NumberFormat::default().format_width(1.5, 8), "    1.50");
+}
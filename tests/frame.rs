@@ -0,0 +1,101 @@
+//! Repère terrestre et repère lié à la dépression
+
+use cyclogenese_rust::frame::{self, Frame};
+use cyclogenese_rust::hovmoller::HovmollerAxis;
+use cyclogenese_rust::wind::WindGrid;
+use cyclogenese_rust::{BaroclinicCyclogenesis, Scenario};
+
+fn moving_storm() -> Scenario {
+    Scenario::from_toml("geometry = \"spherical\"\nstart_time = 1999-12-25T12:00:00Z\nlongitude = -4.5\nlatitude = 48.5\n")
+        .unwrap()
+}
+
+#[test]
+fn motion_is_estimated_along_the_track() {
+    let mut cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap();
+    let results = cyclogenesis.simulate_interaction(6).unwrap();
+    assert!(frame::storm_motion(&results).iter().all(|motion| *motion == (0.0, 0.0)));
+
+    let scenario = moving_storm();
+    let mut cyclogenesis = scenario.to_cyclogenesis().unwrap();
+    let results = cyclogenesis.simulate_interaction(scenario.time_steps).unwrap();
+    let motion = frame::storm_motion(&results);
+    assert_eq!(motion.len(), results.len());
+    // Entraîné vers l'est par le vent directeur, à quelques mètres par seconde
+    assert!(motion.iter().all(|(east, north)| *east > 0.0 && east.hypot(*north) < 30.0), "{:?}", motion);
+    assert!(frame::storm_motion(&results[..1]).iter().all(|motion| *motion == (0.0, 0.0)));
+}
+
+#[test]
+fn earth_wind_adds_the_storm_motion() {
+    let scenario = moving_storm();
+    let mut cyclogenesis = scenario.to_cyclogenesis().unwrap();
+    let results = cyclogenesis.simulate_interaction(scenario.time_steps).unwrap();
+    let grid = WindGrid::new(4.0, 1.0).unwrap();
+    let earth = cyclogenesis.wind_fields_in(&results, &grid, Frame::Earth);
+    let relative = cyclogenesis.wind_fields_in(&results, &grid, Frame::StormRelative);
+    assert_eq!(relative, cyclogenesis.wind_fields(&results, &grid));
+
+    let motion = frame::storm_motion(&results);
+    for ((earth, relative), (east, north)) in earth.iter().zip(&relative).zip(motion) {
+        assert_eq!((earth.frame(), relative.frame()), (Frame::Earth, Frame::StormRelative));
+        // Au centre, seul reste le déplacement du système
+        assert_eq!(earth.wind(4, 4), Some((east, north)));
+        assert_eq!(relative.wind(4, 4), Some((0.0, 0.0)));
+        assert!(earth.u().iter().zip(relative.u()).all(|(a, b)| (a - b - east).abs() < 1e-12));
+    }
+}
+
+#[test]
+fn storm_relative_hovmoller_stays_centred() {
+    let scenario = moving_storm();
+    let mut cyclogenesis = scenario.to_cyclogenesis().unwrap();
+    let results = cyclogenesis.simulate_interaction(scenario.time_steps).unwrap();
+    let axis = HovmollerAxis::new(5.0, 1.0).unwrap();
+    let earth = cyclogenesis.hovmoller_in(&results, &axis, Frame::Earth);
+    let relative = cyclogenesis.hovmoller_in(&results, &axis, Frame::StormRelative);
+
+    assert_eq!(earth, cyclogenesis.hovmoller(&results, &axis));
+    assert_eq!(relative.frame(), Frame::StormRelative);
+    assert_eq!(relative.longitudes(), &[-5.0, -4.0, -3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+    assert_eq!(relative.center_longitudes(), earth.center_longitudes());
+    for (index, result) in results.iter().enumerate() {
+        let row = relative.row(index).unwrap();
+        assert_eq!(row[5], result.relative_vorticity());
+        assert_eq!(row[4], row[6]);
+    }
+}
+
+#[test]
+fn frames_parse_from_their_names() {
+    assert_eq!("earth".parse::<Frame>(), Ok(Frame::Earth));
+    assert_eq!("Storm".parse::<Frame>(), Ok(Frame::StormRelative));
+    assert_eq!("storm-relative".parse::<Frame>(), Ok(Frame::StormRelative));
+    assert!("lagrangian".parse::<Frame>().is_err());
+}
+
+#[cfg(feature = "netcdf")]
+#[test]
+fn storm_relative_wind_drops_the_cf_standard_names() {
+    use netcdf3::FileReader;
+
+    let scenario = moving_storm();
+    let mut cyclogenesis = scenario.to_cyclogenesis().unwrap();
+    let results = cyclogenesis.simulate_interaction(3).unwrap();
+    let grid = WindGrid::new(2.0, 1.0).unwrap();
+    let path = std::env::temp_dir().join(format!("cyclogenese-frame-{}.nc", std::process::id()));
+
+    let mut names = Vec::new();
+    for frame in [Frame::Earth, Frame::StormRelative] {
+        let fields = cyclogenesis.wind_fields_in(&results, &grid, frame);
+        cyclogenese_rust::io::netcdf::write_wind_fields(&path, &fields).unwrap();
+        let reader = FileReader::open(&path).unwrap();
+        names.push(reader.data_set().get_var_attr_as_string("u", "standard_name"));
+    }
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(names, [Some("eastward_wind".to_string()), None]);
+
+    let mut fields = cyclogenesis.wind_fields_in(&results, &grid, Frame::Earth);
+    fields.extend(cyclogenesis.wind_fields(&results, &grid));
+    assert!(cyclogenese_rust::io::netcdf::write_wind_fields(&path, &fields).is_err());
+}
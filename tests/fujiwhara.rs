@@ -0,0 +1,108 @@
+//! Interaction de deux dépressions voisines (effet Fujiwhara)
+
+use cyclogenese_rust::storms::{self, MultiStorm, MultiStormScenario, Storm, StormResult};
+use cyclogenese_rust::{InteractionModel, MeteoError, Scenario};
+
+fn storm(id: &str, geometry: &str, longitude: f64, surface_temp: f64) -> Storm {
+    let toml = format!("geometry = \"{}\"\nlongitude = {}\nsurface_temp = {}", geometry, longitude, surface_temp);
+    Storm::new(id, Scenario::from_toml(&toml).unwrap().to_cyclogenesis().unwrap())
+}
+
+/// Tempête A à 0°E et tempête B, plus intense, à 10°E sur le 45e parallèle
+fn pair(geometry: &str, interaction: InteractionModel) -> Vec<StormResult> {
+    MultiStorm::new()
+        .with_interaction(interaction)
+        .unwrap()
+        .with_storm(storm("A", geometry, 0.0, 5.0))
+        .unwrap()
+        .with_storm(storm("B", geometry, 10.0, 3.0))
+        .unwrap()
+        .simulate(12)
+        .unwrap()
+}
+
+/// Vecteur de B vers A sur le plan tangent (km) à chaque heure
+fn separation(results: &[StormResult]) -> Vec<(f64, f64)> {
+    results
+        .chunks(2)
+        .map(|pair| {
+            let (a, b) = (&pair[0].result, &pair[1].result);
+            let latitude = (0.5 * (a.latitude().unwrap() + b.latitude().unwrap())).to_radians();
+            let x = 6371.0 * latitude.cos() * (a.longitude().unwrap() - b.longitude().unwrap()).to_radians();
+            (x, 6371.0 * (a.latitude().unwrap() - b.latitude().unwrap()).to_radians())
+        })
+        .collect()
+}
+
+#[test]
+fn independent_storms_keep_their_own_tracks() {
+    let results = pair("spherical", InteractionModel::Independent);
+    let toml = "geometry = \"spherical\"\nlongitude = 10.0\nsurface_temp = 3.0\ntime_steps = 12";
+    let alone = Scenario::from_toml(toml).unwrap();
+    assert_eq!(storms::by_storm(&results)["B"].to_results(), alone.run().unwrap());
+}
+
+#[test]
+fn nearby_lows_rotate_about_each_other_and_draw_together() {
+    let independent = separation(&pair("spherical", InteractionModel::Independent));
+    let interacting = separation(&pair("spherical", InteractionModel::fujiwhara(1.2e6)));
+    assert_eq!(interacting[0], independent[0]);
+    // Angle du vecteur de B vers A, compté depuis l'ouest
+    let angle = |(x, y): (f64, f64)| y.atan2(-x);
+    let distance = |(x, y): (f64, f64)| x.hypot(y);
+    assert!((angle(independent[11]) - angle(independent[0])).abs() < 0.05);
+    assert!((angle(interacting[11]) - angle(interacting[0])).abs() > 0.2, "{:?}", interacting);
+    assert!(distance(interacting[11]) < distance(independent[11]), "{:?}", interacting);
+}
+
+#[test]
+fn stronger_low_absorbs_the_weaker_one() {
+    let independent = storms::by_storm(&pair("spherical", InteractionModel::Independent))
+        .into_iter()
+        .map(|(id, series)| (id.to_string(), series.rows().last().unwrap().relative_vorticity()))
+        .collect::<Vec<_>>();
+    let interacting = pair("spherical", InteractionModel::fujiwhara(1.2e6));
+    let merged = storms::by_storm(&interacting);
+    let last = |id: &str| merged[id].rows().last().unwrap().relative_vorticity();
+    assert!(last("A").abs() < independent[0].1.abs());
+    assert!(last("B").abs() > independent[1].1.abs());
+}
+
+#[test]
+fn distant_lows_do_not_interact() {
+    assert_eq!(pair("spherical", InteractionModel::fujiwhara(5.0e5)), pair("spherical", InteractionModel::Independent));
+}
+
+#[test]
+fn lows_on_the_f_plane_stay_in_place() {
+    let results = pair("f_plane", InteractionModel::fujiwhara(1.2e6));
+    assert!(results.iter().all(|result| result.result.latitude().is_none()));
+    let independent = pair("f_plane", InteractionModel::Independent);
+    assert_eq!(results[..2], independent[..2]);
+    assert!(results[23].result.relative_vorticity().abs() > independent[23].result.relative_vorticity().abs());
+}
+
+#[test]
+fn rejects_an_invalid_interaction() {
+    for model in [
+        InteractionModel::fujiwhara(0.0),
+        InteractionModel::fujiwhara(1.0e7),
+        InteractionModel::Fujiwhara { separation: 1.2e6, attraction: 2.0 },
+    ] {
+        assert!(matches!(MultiStorm::new().with_interaction(model), Err(MeteoError::InvalidScenario(_))), "{:?}", model);
+    }
+    assert_eq!(InteractionModel::default().separation(), None);
+}
+
+#[test]
+fn interaction_is_read_from_toml() {
+    let toml = "interaction = { fujiwhara = { separation = 1.2e6 } }\n\n\
+                [[storms]]\nid = \"A\"\ngeometry = \"spherical\"\nlongitude = 0.0\ntime_steps = 12\n\n\
+                [[storms]]\nid = \"B\"\ngeometry = \"spherical\"\nlongitude = 10.0\nsurface_temp = 3.0\ntime_steps = 12\n";
+    let family = MultiStormScenario::from_toml(toml).unwrap();
+    assert_eq!(family.interaction, Some(InteractionModel::fujiwhara(1.2e6)));
+    assert_eq!(family.to_multi_storm().unwrap().interaction().separation(), Some(1.2e6));
+    assert_eq!(family.run().unwrap(), pair("spherical", InteractionModel::fujiwhara(1.2e6)));
+    let invalid = "interaction = { fujiwhara = { separation = -1.0 } }\n\n[[storms]]\nid = \"A\"\n";
+    assert!(matches!(MultiStormScenario::from_toml(invalid).unwrap().run(), Err(MeteoError::InvalidScenario(_))));
+}
@@ -0,0 +1,120 @@
+//! Tests de non-régression sur des scénarios de référence
+//!
+//! Chaque scénario `tests/golden/<nom>.toml` est simulé et ses résultats sont
+//! comparés au fichier `tests/golden/<nom>.json` enregistré dans le dépôt.
+//!
+//! - `GOLDEN_RTOL` et `GOLDEN_ATOL` ajustent les tolérances relative et absolue ;
+//! - `UPDATE_GOLDEN=1` réécrit les fichiers de référence après un changement voulu
+//!   de la physique.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use cyclogenese_rust::Scenario;
+use serde_json::Value;
+
+/// Tolérances par défaut : les écarts d'arrondi entre plateformes passent
+const DEFAULT_RTOL: f64 = 1e-9;
+const DEFAULT_ATOL: f64 = 1e-15;
+
+/// Nombre d'écarts détaillés par scénario
+const MAX_REPORTED: usize = 5;
+
+fn golden_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("golden")
+}
+
+fn tolerance(name: &str, default: f64) -> f64 {
+    env::var(name)
+        .ok()
+        .map(|value| value.parse().unwrap_or_else(|_| panic!("{} invalide: {}", name, value)))
+        .unwrap_or(default)
+}
+
+/// Compare deux valeurs JSON, les nombres à la tolérance près
+fn compare(path: &str, actual: &Value, expected: &Value, rtol: f64, atol: f64, errors: &mut Vec<String>) {
+    match (actual, expected) {
+        (Value::Number(a), Value::Number(e)) => {
+            let (a, e) = (a.as_f64().unwrap(), e.as_f64().unwrap());
+            if (a - e).abs() > atol + rtol * e.abs() {
+                errors.push(format!("{}: {} au lieu de {} (écart {:.3e})", path, a, e, a - e));
+            }
+        }
+        (Value::Array(a), Value::Array(e)) => {
+            if a.len() != e.len() {
+                errors.push(format!("{}: {} éléments au lieu de {}", path, a.len(), e.len()));
+            }
+            for (index, (a, e)) in a.iter().zip(e).enumerate() {
+                compare(&format!("{}[{}]", path, index), a, e, rtol, atol, errors);
+            }
+        }
+        (Value::Object(a), Value::Object(e)) => {
+            for key in a.keys().chain(e.keys().filter(|key| !a.contains_key(*key))) {
+                match (a.get(key), e.get(key)) {
+                    (Some(a), Some(e)) => compare(&format!("{}.{}", path, key), a, e, rtol, atol, errors),
+                    (Some(_), None) => errors.push(format!("{}.{}: champ inattendu", path, key)),
+                    (None, _) => errors.push(format!("{}.{}: champ manquant", path, key)),
+                }
+            }
+        }
+        _ if actual != expected => errors.push(format!("{}: {} au lieu de {}", path, actual, expected)),
+        _ => {}
+    }
+}
+
+#[test]
+fn golden_scenarios() {
+    let rtol = tolerance("GOLDEN_RTOL", DEFAULT_RTOL);
+    let atol = tolerance("GOLDEN_ATOL", DEFAULT_ATOL);
+    let update = env::var_os("UPDATE_GOLDEN").is_some();
+
+    let mut scenarios: Vec<PathBuf> = fs::read_dir(golden_dir())
+        .expect("répertoire tests/golden")
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+        .collect();
+    scenarios.sort();
+    assert!(!scenarios.is_empty(), "aucun scénario de référence");
+
+    let mut failures = Vec::new();
+    for scenario_path in &scenarios {
+        let name = scenario_path.file_stem().unwrap().to_string_lossy().into_owned();
+        let results = Scenario::load(scenario_path)
+            .and_then(|scenario| scenario.run())
+            .unwrap_or_else(|e| panic!("{}: {}", name, e));
+        let actual = serde_json::to_value(&results).unwrap();
+        let golden_path = scenario_path.with_extension("json");
+
+        if update {
+            fs::write(&golden_path, serde_json::to_string_pretty(&actual).unwrap() + "\n").unwrap();
+            continue;
+        }
+
+        let expected: Value = match fs::read_to_string(&golden_path) {
+            Ok(text) => serde_json::from_str(&text).unwrap_or_else(|e| panic!("{}: {}", golden_path.display(), e)),
+            Err(_) => {
+                failures.push(format!("{}: fichier de référence absent (UPDATE_GOLDEN=1 pour le créer)", name));
+                continue;
+            }
+        };
+
+        let mut errors = Vec::new();
+        compare(&name, &actual, &expected, rtol, atol, &mut errors);
+        if !errors.is_empty() {
+            let count = errors.len();
+            failures.extend(errors.into_iter().take(MAX_REPORTED));
+            if count > MAX_REPORTED {
+                failures.push(format!("{}: ... et {} autres écarts", name, count - MAX_REPORTED));
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "résultats différents des fichiers de référence (rtol = {}, atol = {}) :\n{}",
+        rtol,
+        atol,
+        failures.join("\n")
+    );
+}
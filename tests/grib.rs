@@ -0,0 +1,59 @@
+//! Lecture de champs GRIB2
+//!
+//! `tests/fixtures/gfs_point.grib2` contient sept messages sur une grille
+//! latitude-longitude de 3 × 3 points (50°N à 40°N, 0° à 10°E, pas de 5°), en
+//! compression simple : température, hauteur et pression de surface, température
+//! et hauteur à 500 hPa, plus une température à 850 hPa et une seconde
+//! température de surface qui doivent être écartées. Au point de grille d'indice
+//! k, chaque champ vaut `base + k × pas`. `gfs_no_upper_height.grib2` est le même
+//! fichier sans le dernier message (hauteur à 500 hPa).
+#![cfg(feature = "grib")]
+
+use std::fs::File;
+use std::io::Cursor;
+use std::path::PathBuf;
+
+use cyclogenese_rust::io::grib::read_profile;
+use cyclogenese_rust::MeteoError;
+
+fn fixture(name: &str) -> File {
+    File::open(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join(name)).unwrap()
+}
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!((actual - expected).abs() < 1e-3, "{} au lieu de {}", actual, expected);
+}
+
+#[test]
+fn surface_and_500_hpa_fields_are_read_at_the_nearest_point() {
+    let profile = read_profile(fixture("gfs_point.grib2"), 45.5, 4.0).unwrap();
+    assert_eq!(profile.latitude(), Some(45.5));
+    let [surface, upper] = profile.levels() else { panic!("deux niveaux attendus") };
+
+    // Point central (45°N, 5°E), indice 4
+    assert_close(surface.temperature, 282.0);
+    assert_close(surface.height, 140.0);
+    assert_close(surface.pressure, 994.0);
+    assert_eq!(upper.pressure, 500.0);
+    assert_close(upper.temperature, 250.8);
+    assert_close(upper.height, 5540.0);
+    assert!(profile.to_cyclogenesis().is_ok());
+}
+
+#[test]
+fn longitudes_are_taken_modulo_360() {
+    // 369°E est à 9°E : point (50°N, 10°E), indice 2
+    let profile = read_profile(fixture("gfs_point.grib2"), 49.0, 369.0).unwrap();
+    assert_close(profile.surface().temperature, 281.0);
+    assert_close(profile.surface().height, 120.0);
+}
+
+#[test]
+fn missing_fields_and_other_files_are_refused() {
+    match read_profile(fixture("gfs_no_upper_height.grib2"), 45.0, 5.0) {
+        Err(MeteoError::InvalidGrib(message)) => assert!(message.contains("HGT 500 hPa"), "{}", message),
+        other => panic!("erreur GRIB2 attendue, obtenu {:?}", other),
+    }
+    assert!(matches!(read_profile(Cursor::new(b"CDF\x01".to_vec()), 45.0, 5.0), Err(MeteoError::InvalidGrib(_))));
+    assert!(matches!(read_profile(Cursor::new(Vec::new()), 45.0, 5.0), Err(MeteoError::InvalidGrib(_))));
+}
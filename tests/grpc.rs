@@ -0,0 +1,85 @@
+//! Service gRPC de simulation
+#![cfg(feature = "grpc")]
+
+use cyclogenese_rust::grpc::proto::simulation_server::Simulation;
+use cyclogenese_rust::grpc::{proto, SimulationService};
+use cyclogenese_rust::{server, DevelopmentResult, Scenario};
+use prost::Message;
+use tokio_stream::StreamExt;
+use tonic::{Code, Request};
+
+fn scenario(time_steps: u32) -> proto::Scenario {
+    proto::Scenario {
+        surface_temp: -5.0,
+        altitude_temp: -10.0,
+        latitude: 45.0,
+        time_steps,
+        start_time: None,
+        name: Some("grpc".to_string()),
+        definition: None,
+    }
+}
+
+/// Scénario complet : géométrie sphérique, talweg d'altitude et date de départ
+const DEFINITION: &str = r#"
+time_steps = 12
+start_time = "1999-12-26T00:00:00Z"
+longitude = -5.0
+geometry = "spherical"
+tilt = 3.0e5
+
+[upper_trough]
+amplitude = 4.0
+half_wavelength = 1.5e6
+approach_speed = 15.0
+"#;
+
+fn runtime() -> tokio::runtime::Runtime {
+    tokio::runtime::Builder::new_multi_thread().enable_all().build().unwrap()
+}
+
+#[test]
+fn results_are_streamed_and_overlong_runs_rejected() {
+    runtime().block_on(async {
+        let service = SimulationService::default();
+        let stream = service.simulate(Request::new(scenario(6))).await.unwrap().into_inner();
+        let hours: Vec<_> = stream.map(|message| message.unwrap().hour).collect().await;
+        assert_eq!(hours, (0..6).collect::<Vec<_>>());
+        // La simulation est comptée à la fin du flux, sous le nom du scénario
+        assert_eq!((service.metrics().simulations(), service.metrics().steps()), (1, 6));
+        assert_eq!(service.metrics().scenario("grpc").unwrap().count, 1);
+
+        let status = service.simulate(Request::new(scenario(server::MAX_TIME_STEPS + 1))).await.unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+        assert!(status.message().contains("au plus"));
+        let status = service.simulate(Request::new(scenario(u32::MAX))).await.unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+        assert_eq!(service.metrics().errors(), 2);
+    });
+}
+
+#[test]
+fn full_scenarios_are_simulated_with_all_their_result_fields() {
+    let expected = Scenario::from_toml(DEFINITION).unwrap().run().unwrap();
+    assert!(expected.iter().all(|result| result.latitude().is_some() && result.longitude().is_some()));
+
+    runtime().block_on(async {
+        let service = SimulationService::default();
+        let request = proto::Scenario { definition: Some(DEFINITION.to_string()), ..scenario(0) };
+        let stream = service.simulate(Request::new(request)).await.unwrap().into_inner();
+        let messages: Vec<_> = stream.map(|message| message.unwrap()).collect().await;
+
+        // Transmis sur le réseau puis relus, les résultats sont ceux de la bibliothèque
+        let received: Vec<DevelopmentResult> = messages
+            .iter()
+            .map(|message| proto::DevelopmentResult::decode(message.encode_to_vec().as_slice()).unwrap().into())
+            .collect();
+        assert_eq!(received, expected);
+        assert_eq!(messages[3].low_level_divergence, expected[3].low_level_divergence());
+        assert_eq!(service.metrics().scenario("grpc").unwrap().count, 1);
+
+        let invalid = proto::Scenario { definition: Some("geometry = \"torus\"".to_string()), ..scenario(6) };
+        let status = service.simulate(Request::new(invalid)).await.unwrap_err();
+        assert_eq!(status.code(), Code::InvalidArgument);
+    });
+}
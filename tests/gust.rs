@@ -0,0 +1,57 @@
+//! Rafales de surface
+
+use cyclogenese_rust::gust::{self, GustModel};
+use cyclogenese_rust::{BaroclinicCyclogenesis, MeteoError, NumberFormat, OutputLanguage, Scenario};
+
+#[test]
+fn gust_factor_follows_stability() {
+    let model = GustModel::default();
+    let neutral = model.gust_factor(Some(0.0));
+    assert!((neutral - (1.0 + 3.0 / (10.0f64 / 0.03).ln())).abs() < 1e-12);
+    assert_eq!(model.gust_factor(None), neutral);
+    assert!(model.gust_factor(Some(1.0)) < neutral);
+    assert!(model.gust_factor(Some(-0.5)) > neutral);
+
+    // Terrain plus rugueux, turbulence plus forte
+    let rough = GustModel::new(3.0, 0.5, 1000.0).unwrap();
+    assert!(rough.gust_factor(Some(0.0)) > neutral);
+    assert!(matches!(GustModel::new(3.0, 10.0, 1000.0), Err(MeteoError::InvalidScenario(_))));
+    assert!(GustModel::new(0.0, 0.03, 1000.0).is_err());
+}
+
+#[test]
+fn gusts_exceed_the_mean_wind() {
+    let mut cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap();
+    let (results, diagnostics) = cyclogenesis.simulate_with_diagnostics(24).unwrap();
+    let gusts = cyclogenesis.gusts(&results, &diagnostics, &GustModel::default());
+
+    assert_eq!(gusts.len(), results.len());
+    for ((gust, result), step) in gusts.iter().zip(&results).zip(&diagnostics) {
+        assert_eq!(gust.mean_wind, cyclogenesis.surface_wind(result));
+        assert_eq!(gust.convective, 0.0);
+        assert_eq!(gust.peak_gust, gust.mean_wind * GustModel::default().gust_factor(step.richardson));
+    }
+    // La couche se déstabilise à mesure que le cisaillement croît
+    assert!(gusts.windows(2).all(|pair| pair[1].gust_factor > pair[0].gust_factor));
+}
+
+#[test]
+fn daytime_convection_adds_to_the_gusts() {
+    let scenario = Scenario::from_toml(
+        "surface_temp = 6.0\naltitude_temp = -10.0\nlatitude = 48.5\nstart_time = 1999-12-25T12:00:00Z\nlongitude = -4.5\n",
+    )
+    .unwrap();
+    let mut cyclogenesis = scenario.to_cyclogenesis().unwrap();
+    let (results, diagnostics) = cyclogenesis.simulate_with_diagnostics(24).unwrap();
+    let gusts = cyclogenesis.gusts(&results, &diagnostics, &GustModel::default());
+
+    assert!(gusts.iter().any(|gust| gust.convective > 0.0));
+    assert!(gusts.iter().any(|gust| gust.convective == 0.0));
+    assert!(gusts.iter().all(|gust| gust.peak_gust >= gust.mean_wind * gust.gust_factor));
+
+    let format = NumberFormat::default();
+    let table = gust::to_table(&gusts, OutputLanguage::English, &format);
+    assert!(table.starts_with("Hour  | Mean wind (m/s)      | Gust factor          | Convection (m/s)     | Gust (m/s)\n"));
+    assert!(table.ends_with(&format!("\n{}\n", gust::describe_peak(&gusts, OutputLanguage::English, &format))));
+    assert_eq!(gust::describe_peak(&[], OutputLanguage::English, &format), "No gust estimated");
+}
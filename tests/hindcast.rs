@@ -0,0 +1,31 @@
+//! Intégration rétrograde vers les anomalies précurseurs
+
+use cyclogenese_rust::hindcast::Hindcast;
+use cyclogenese_rust::{MeteoError, Scenario};
+
+fn vorticities(scenario: &Scenario) -> Vec<f64> {
+    let results = serde_json::to_value(scenario.run().unwrap()).unwrap();
+    results.as_array().unwrap().iter().map(|result| result["relative_vorticity"].as_f64().unwrap()).collect()
+}
+
+#[test]
+fn forward_run_of_the_precursor_reaches_the_mature_state() {
+    let scenario = Scenario::default();
+    let mature = -3.0e-5;
+    let hindcast = Hindcast::compute(&scenario, mature).unwrap();
+
+    let forward = vorticities(hindcast.precursor());
+    assert!((forward.last().unwrap() - mature).abs() <= 1e-12 * mature.abs());
+    for step in hindcast.steps() {
+        let expected = forward[step.hour as usize];
+        assert!((step.relative_vorticity - expected).abs() <= 1e-12 * expected.abs(), "heure {}", step.hour);
+    }
+    assert_eq!(hindcast.steps().first().unwrap().hour, scenario.time_steps - 1);
+    assert_eq!(hindcast.steps().last().unwrap().hour, 0);
+}
+
+#[test]
+fn mature_vorticity_of_the_wrong_sign_is_rejected() {
+    let result = Hindcast::compute(&Scenario::default(), 2.0e-4);
+    assert!(matches!(result, Err(MeteoError::InvalidScenario(_))));
+}
@@ -0,0 +1,85 @@
+//! Diagramme de Hovmöller du tourbillon le long de la trajectoire
+
+use cyclogenese_rust::hovmoller::HovmollerAxis;
+use cyclogenese_rust::{BaroclinicCyclogenesis, MeteoError, Scenario};
+
+fn crossing_the_antimeridian() -> Scenario {
+    Scenario::from_toml("geometry = \"spherical\"\nstart_time = 1999-12-25T12:00:00Z\nlongitude = 179.5\nlatitude = 48.5\n")
+        .unwrap()
+}
+
+#[test]
+fn each_row_peaks_at_the_storm_center() {
+    let mut cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap();
+    let results = cyclogenesis.simulate_interaction(24).unwrap();
+    let diagram = cyclogenesis.hovmoller(&results, &HovmollerAxis::new(10.0, 1.0).unwrap());
+
+    assert_eq!(diagram.hours().len(), 24);
+    assert_eq!(diagram.longitudes().len(), 21);
+    for (index, result) in results.iter().enumerate() {
+        let row = diagram.row(index).unwrap();
+        assert_eq!(row[10], result.relative_vorticity());
+        assert!(row.iter().all(|zeta| zeta.abs() <= result.relative_vorticity().abs()));
+    }
+    assert!(diagram.row(24).is_none());
+}
+
+#[test]
+fn moving_storm_is_tracked_across_the_antimeridian() {
+    let scenario = crossing_the_antimeridian();
+    let mut cyclogenesis = scenario.to_cyclogenesis().unwrap();
+    let results = cyclogenesis.simulate_interaction(scenario.time_steps).unwrap();
+    let diagram = cyclogenesis.hovmoller(&results, &HovmollerAxis::default());
+
+    let centers = diagram.center_longitudes();
+    assert!(centers.windows(2).all(|pair| pair[1] > pair[0]), "{:?}", centers);
+    assert!(centers.last().unwrap() > &180.0);
+    assert!(diagram.longitudes().windows(2).all(|pair| pair[1] > pair[0]));
+    for (result, center) in results.iter().zip(centers) {
+        // Longitude dépliée : égale à celle du résultat à un tour près
+        let offset = (center - result.longitude().unwrap()).rem_euclid(360.0);
+        assert!(offset < 1e-9 || 360.0 - offset < 1e-9);
+    }
+}
+
+#[test]
+fn csv_has_one_row_per_hour() {
+    let mut cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap();
+    let results = cyclogenesis.simulate_interaction(6).unwrap();
+    let diagram = cyclogenesis.hovmoller(&results, &HovmollerAxis::new(2.0, 1.0).unwrap());
+    let csv = diagram.to_csv();
+    let lines: Vec<_> = csv.lines().collect();
+
+    assert_eq!(lines[0], "hour,-2,-1,0,1,2");
+    assert_eq!(lines.len(), 7);
+    let cells: Vec<_> = lines[6].split(',').collect();
+    assert_eq!(cells[0], "5");
+    assert_eq!(cells[3].parse::<f64>().unwrap(), results[5].relative_vorticity());
+}
+
+#[test]
+fn invalid_axes_are_rejected() {
+    for (half_width, spacing) in [(0.0, 1.0), (200.0, 1.0), (10.0, 0.0), (1.0, 2.0)] {
+        assert!(matches!(HovmollerAxis::new(half_width, spacing), Err(MeteoError::InvalidGrid(_))));
+    }
+}
+
+#[cfg(feature = "netcdf")]
+#[test]
+fn hovmoller_round_trips_through_netcdf() {
+    use netcdf3::FileReader;
+
+    let scenario = crossing_the_antimeridian();
+    let mut cyclogenesis = scenario.to_cyclogenesis().unwrap();
+    let results = cyclogenesis.simulate_interaction(scenario.time_steps).unwrap();
+    let diagram = cyclogenesis.hovmoller(&results, &HovmollerAxis::default());
+    let path = std::env::temp_dir().join(format!("cyclogenese-hovmoller-{}.nc", std::process::id()));
+    cyclogenese_rust::io::netcdf::write_hovmoller(&path, &diagram).unwrap();
+
+    let mut reader = FileReader::open(&path).unwrap();
+    let longitudes = reader.read_var_f64("longitude").unwrap();
+    let vorticity = reader.read_var_f64("relative_vorticity").unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(longitudes, diagram.longitudes());
+    assert_eq!(vorticity, diagram.vorticity());
+}
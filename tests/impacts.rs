@@ -0,0 +1,62 @@
+//! Première estimation de la surcote
+
+use cyclogenese_rust::impacts::{self, Coastline};
+use cyclogenese_rust::report::Report;
+use cyclogenese_rust::{scenarios, BaroclinicCyclogenesis, MeteoError, NumberFormat, OutputLanguage};
+
+#[test]
+fn inverse_barometer_raises_the_sea_about_a_centimetre_per_hectopascal() {
+    assert!((impacts::inverse_barometer(-10.0) - 0.0994).abs() < 1e-3);
+    assert_eq!(impacts::inverse_barometer(0.0), 0.0);
+    assert!(impacts::inverse_barometer(5.0) < 0.0);
+}
+
+#[test]
+fn wind_setup_follows_the_onshore_component() {
+    let coastline = Coastline::default();
+    let onshore = coastline.wind_setup(30.0, 270.0);
+    assert!(onshore > 0.5 && onshore < 2.0, "{}", onshore);
+    assert!((coastline.wind_setup(30.0, 90.0) + onshore).abs() < 1e-12);
+    assert!(coastline.wind_setup(30.0, 0.0).abs() < 1e-12);
+
+    // Plateau plus profond, surélévation plus faible
+    let deep = Coastline::new(-90.0, 60.0, 1.0e5).unwrap();
+    assert_eq!(deep.facing(), 270.0);
+    assert!((deep.wind_setup(30.0, 270.0) - onshore / 2.0).abs() < 1e-12);
+    assert!(matches!(Coastline::new(270.0, 0.0, 1.0e5), Err(MeteoError::InvalidScenario(_))));
+}
+
+#[test]
+fn surge_combines_pressure_and_wind() {
+    let scenario = scenarios::find("lothar-1999").unwrap().scenario();
+    let mut cyclogenesis = scenario.to_cyclogenesis().unwrap();
+    let results = cyclogenesis.simulate_interaction(scenario.time_steps).unwrap();
+    let exposed = cyclogenesis.storm_surge(&results, &Coastline::default());
+    let sheltered = cyclogenesis.storm_surge(&results, &Coastline::new(90.0, 30.0, 1.0e5).unwrap());
+
+    for ((surge, sheltered), result) in exposed.iter().zip(&sheltered).zip(&results) {
+        assert_eq!(surge.inverse_barometer, impacts::inverse_barometer(cyclogenesis.pressure_deficit(result)));
+        assert!(surge.inverse_barometer > 0.0 && surge.wind_setup > 0.0);
+        assert_eq!(surge.total, surge.inverse_barometer + surge.wind_setup);
+        // Vent de terre sur la côte exposée à l'est
+        assert!(sheltered.wind_setup < 0.0);
+    }
+
+    let format = NumberFormat::default();
+    let table = impacts::to_table(&exposed, OutputLanguage::English, &format);
+    assert!(table.starts_with("Hour  | Barometer (m)        | Wind setup (m)       | Surge (m)\n"));
+    let peak = impacts::describe_peak(&exposed, OutputLanguage::English, &format);
+    assert!(table.ends_with(&format!("\n{}\n", peak)));
+
+    let report = Report::compute(&scenario).unwrap();
+    assert_eq!(report.surges(), exposed.as_slice());
+    assert!(report.to_markdown(OutputLanguage::English, &format).contains(&format!("- {}\n", peak)));
+}
+
+#[test]
+fn anticyclones_blow_from_the_east() {
+    let mut cyclogenesis = BaroclinicCyclogenesis::new(-5.0, 8.0, 45.0).unwrap();
+    let results = cyclogenesis.simulate_interaction(6).unwrap();
+    let surges = cyclogenesis.storm_surge(&results, &Coastline::new(90.0, 30.0, 1.0e5).unwrap());
+    assert!(surges.iter().all(|surge| surge.inverse_barometer < 0.0 && surge.wind_setup > 0.0));
+}
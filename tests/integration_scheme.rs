@@ -0,0 +1,163 @@
+//! Stabilité de l'intégration aux grands pas de temps
+
+use cyclogenese_rust::integration::SteppedTerm;
+use cyclogenese_rust::{
+    BaroclinicCyclogenesis, Geometry, IntegrationScheme, MeteoError, Nudging, Observation, OutputLanguage, Scenario, StepPolicy,
+};
+
+/// Déphasage final (°) d'une zone barocline intense à 80°N, sur 48 heures
+fn final_phase(scheme: IntegrationScheme, time_step: f64) -> f64 {
+    let (_, diagnostics) = BaroclinicCyclogenesis::new(15.0, -20.0, 80.0)
+        .unwrap()
+        .with_meridional_gradient(50.0)
+        .unwrap()
+        .with_scheme(scheme)
+        .with_time_step(time_step)
+        .unwrap()
+        .simulate_with_diagnostics(48)
+        .unwrap();
+    diagnostics.last().unwrap().phase.to_degrees()
+}
+
+#[test]
+fn semi_implicit_scheme_stays_stable_at_hourly_steps() {
+    let reference = final_phase(IntegrationScheme::Explicit, 10.0);
+    let explicit = final_phase(IntegrationScheme::Explicit, 3600.0);
+    let semi_implicit = final_phase(IntegrationScheme::SemiImplicit, 3600.0);
+
+    assert!((explicit - reference).abs() > 10.0, "explicite {} / référence {}", explicit, reference);
+    assert!((semi_implicit - reference).abs() < 0.5, "semi-implicite {} / référence {}", semi_implicit, reference);
+}
+
+#[test]
+fn time_step_outside_the_hour_is_rejected() {
+    for time_step in [0.0, -60.0, 7200.0, f64::NAN] {
+        let result = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap().with_time_step(time_step);
+        assert!(matches!(result, Err(MeteoError::InvalidTimeStep(_))), "pas {}", time_step);
+    }
+}
+
+fn intense_zone(scheme: IntegrationScheme, time_step: f64) -> BaroclinicCyclogenesis {
+    BaroclinicCyclogenesis::new(15.0, -20.0, 80.0)
+        .unwrap()
+        .with_meridional_gradient(50.0)
+        .unwrap()
+        .with_scheme(scheme)
+        .with_time_step(time_step)
+        .unwrap()
+}
+
+#[test]
+fn stability_limit_flags_long_explicit_steps() {
+    let explicit = intense_zone(IntegrationScheme::Explicit, 3600.0).stability_limit(48).unwrap();
+    assert!(!explicit.is_stable(), "{:?}", explicit);
+    assert!(explicit.damping > 2.0);
+
+    let semi_implicit = intense_zone(IntegrationScheme::SemiImplicit, 3600.0).stability_limit(48).unwrap();
+    assert!(semi_implicit.is_stable(), "{:?}", semi_implicit);
+    assert!(intense_zone(IntegrationScheme::Explicit, 60.0).stability_limit(48).unwrap().is_stable());
+}
+
+#[test]
+fn reduce_policy_shortens_the_step_below_the_limit() {
+    let mut cyclogenesis = intense_zone(IntegrationScheme::Explicit, 3600.0).with_step_policy(StepPolicy::Reduce);
+    let limit = cyclogenesis.apply_step_policy(48).unwrap();
+
+    assert!(cyclogenesis.time_step() < limit.max_time_step);
+    assert_eq!(3600.0 % cyclogenesis.time_step(), 0.0);
+    assert!(cyclogenesis.stability_limit(48).unwrap().is_stable());
+    assert_eq!(cyclogenesis.apply_step_policy(48), None);
+
+    let (_, diagnostics) = cyclogenesis.simulate_with_diagnostics(48).unwrap();
+    let reference = final_phase(IntegrationScheme::Explicit, 10.0);
+    assert!((diagnostics.last().unwrap().phase.to_degrees() - reference).abs() < 1.0);
+}
+
+#[test]
+fn warn_policy_keeps_the_requested_step() {
+    let mut cyclogenesis = intense_zone(IntegrationScheme::Explicit, 3600.0);
+    assert!(cyclogenesis.apply_step_policy(48).is_some());
+    assert_eq!(cyclogenesis.time_step(), 3600.0);
+}
+
+/// Rappel serré (τ = 600 s) vers un tourbillon observé constant, sur 48 heures
+fn tight_nudging(scheme: IntegrationScheme, time_step: f64) -> Scenario {
+    let observations = (0..48).map(|hour| Observation::vorticity(hour, 1.0e-5)).collect();
+    Scenario {
+        nudging: Some(Nudging::new(600.0, observations).unwrap()),
+        scheme: Some(scheme),
+        time_step: Some(time_step),
+        time_steps: 48,
+        ..Scenario::default()
+    }
+}
+
+#[test]
+fn semi_implicit_nudging_stays_stable_at_hourly_steps() {
+    let explicit = tight_nudging(IntegrationScheme::Explicit, 3600.0).run();
+    assert!(matches!(explicit, Err(MeteoError::NumericalInstability(_))), "{:?}", explicit);
+
+    let reference = tight_nudging(IntegrationScheme::Explicit, 10.0).run().unwrap();
+    let semi_implicit = tight_nudging(IntegrationScheme::SemiImplicit, 3600.0).run().unwrap();
+    for (semi_implicit, reference) in semi_implicit.iter().zip(&reference) {
+        let (vorticity, expected) = (semi_implicit.relative_vorticity(), reference.relative_vorticity());
+        assert!((vorticity - expected).abs() < 1.5e-5, "heure {} : {} / {}", semi_implicit.hour(), vorticity, expected);
+    }
+    assert!((reference.last().unwrap().relative_vorticity() - 1.0e-5).abs() < 1.0e-7);
+}
+
+#[test]
+fn stability_limit_covers_the_stepped_terms() {
+    let limit = tight_nudging(IntegrationScheme::Explicit, 3600.0).to_cyclogenesis().unwrap().stability_limit(48).unwrap();
+    assert_eq!((limit.term, limit.max_time_step, limit.damping), (SteppedTerm::Nudging, 1200.0, 6.0));
+    assert!(!limit.is_stable());
+    let semi_implicit = tight_nudging(IntegrationScheme::SemiImplicit, 3600.0).to_cyclogenesis().unwrap();
+    assert!(semi_implicit.stability_limits(48).iter().all(|limit| limit.is_stable()));
+
+    let moving = Scenario { geometry: Some(Geometry::Spherical), boundary_layer_depth: Some(800.0), ..Scenario::default() };
+    let limits = moving.to_cyclogenesis().unwrap().stability_limits(24);
+    let terms = limits.iter().map(|limit| limit.term).collect::<Vec<_>>();
+    assert_eq!(terms, [SteppedTerm::Phase, SteppedTerm::BoundaryLayer, SteppedTerm::Track]);
+    assert_eq!(limits[1].max_time_step, 2.0 * 6.0 * 3600.0);
+    assert!(limits[2].courant > 0.0 && limits[2].courant < 1.0, "{:?}", limits[2]);
+
+    let text = limit.describe(StepPolicy::Warn, OutputLanguage::French, &Default::default());
+    assert!(text.contains("limite de stabilité de 1200.00 s du rappel"), "{}", text);
+}
+
+#[test]
+fn reduce_policy_stabilises_a_tight_nudging() {
+    let scenario = Scenario { step_policy: Some(StepPolicy::Reduce), ..tight_nudging(IntegrationScheme::Explicit, 3600.0) };
+    let results = scenario.run().unwrap();
+    for result in &results[1..] {
+        assert!((result.relative_vorticity() - 1.0e-5).abs() < 1.0e-6, "heure {} : {}", result.hour(), result.relative_vorticity());
+    }
+}
+
+#[test]
+fn time_step_drives_the_track() {
+    let track = |time_step| {
+        let scenario = Scenario { geometry: Some(Geometry::Spherical), time_step: Some(time_step), ..Scenario::default() };
+        let last = scenario.run().unwrap().pop().unwrap();
+        (last.latitude().unwrap(), last.longitude().unwrap())
+    };
+    let (fine, hourly) = (track(10.0), track(3600.0));
+    assert_ne!(fine, hourly);
+    assert!((fine.0 - hourly.0).abs() < 0.1 && (fine.1 - hourly.1).abs() < 0.1, "{:?} / {:?}", fine, hourly);
+}
+
+#[test]
+fn closed_form_runs_do_not_depend_on_the_scheme() {
+    let (reference, _) = BaroclinicCyclogenesis::new(5.0, -8.0, 70.0).unwrap().simulate_with_diagnostics(48).unwrap();
+    for (scheme, time_step) in [(IntegrationScheme::Explicit, 3600.0), (IntegrationScheme::SemiImplicit, 600.0)] {
+        let (results, _) = BaroclinicCyclogenesis::new(5.0, -8.0, 70.0)
+            .unwrap()
+            .with_scheme(scheme)
+            .with_time_step(time_step)
+            .unwrap()
+            .simulate_with_diagnostics(48)
+            .unwrap();
+        assert_eq!(results, reference);
+    }
+}
+
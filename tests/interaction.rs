@@ -0,0 +1,65 @@
+//! Interaction d'un nombre quelconque d'anomalies
+
+use cyclogenese_rust::{BaroclinicCyclogenesis, DevelopmentResult, MeteoError, PhysicalConstants, Position, ThermalAnomaly};
+
+fn anomaly(temperature: f64, position: Position) -> ThermalAnomaly {
+    ThermalAnomaly::new(temperature, position, PhysicalConstants::default()).unwrap()
+}
+
+fn vorticity(results: &[DevelopmentResult]) -> Vec<f64> {
+    serde_json::to_value(results)
+        .unwrap()
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|result| result["relative_vorticity"].as_f64().unwrap())
+        .collect()
+}
+
+/// Deux dépressions de surface sous un même talweg, couplées entre elles par `weight`
+fn two_lows_under_one_trough(weight: f64) -> Vec<DevelopmentResult> {
+    let anomalies = vec![
+        anomaly(5.0, Position::surface(45.0).unwrap()),
+        anomaly(5.0, Position::surface(45.0).unwrap()),
+        anomaly(-8.0, Position::upper_level(45.0).unwrap()),
+    ];
+    let weights = vec![vec![0.0, weight, 1.0], vec![weight, 0.0, 1.0], vec![1.0, 1.0, 0.0]];
+    BaroclinicCyclogenesis::from_interacting_anomalies(anomalies, weights)
+        .unwrap()
+        .simulate_interaction(24)
+        .unwrap()
+}
+
+#[test]
+fn unit_weights_reproduce_the_two_anomaly_setup() {
+    let pair = vec![
+        anomaly(5.0, Position::surface(45.0).unwrap()),
+        anomaly(-8.0, Position::upper_level(45.0).unwrap()),
+    ];
+    let generalized = BaroclinicCyclogenesis::from_interacting_anomalies(pair, vec![vec![0.0, 1.0], vec![1.0, 0.0]])
+        .unwrap()
+        .simulate_interaction(24)
+        .unwrap();
+    let reference = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap().simulate_interaction(24).unwrap();
+
+    for (a, b) in vorticity(&generalized).into_iter().zip(vorticity(&reference)) {
+        assert!((a - b).abs() <= 1e-12 * b.abs());
+    }
+}
+
+#[test]
+fn coupling_between_surface_lows_strengthens_the_development() {
+    let coupled = vorticity(&two_lows_under_one_trough(0.5));
+    let independent = vorticity(&two_lows_under_one_trough(0.0));
+    assert!(coupled[23] > independent[23]);
+}
+
+#[test]
+fn weight_matrix_must_match_the_anomalies() {
+    let pair = vec![
+        anomaly(5.0, Position::surface(45.0).unwrap()),
+        anomaly(-8.0, Position::upper_level(45.0).unwrap()),
+    ];
+    let error = BaroclinicCyclogenesis::from_interacting_anomalies(pair, vec![vec![0.0, 1.0]]).err();
+    assert!(matches!(error, Some(MeteoError::InvalidInteraction(_))));
+}
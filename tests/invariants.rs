@@ -0,0 +1,63 @@
+//! Tests par propriétés des invariants physiques de la simulation
+
+use cyclogenese_rust::invariants::{is_antisymmetric, is_quiescent, vorticity_sign_matches_anomaly};
+use cyclogenese_rust::BaroclinicCyclogenesis;
+use proptest::prelude::*;
+
+/// Tolérance relative de l'antisymétrie équatoriale
+const SYMMETRY_TOLERANCE: f64 = 1e-12;
+
+fn anomaly() -> impl Strategy<Value = f64> {
+    -50.0..=50.0
+}
+
+fn latitude() -> impl Strategy<Value = f64> {
+    -90.0..=90.0
+}
+
+fn duration() -> impl Strategy<Value = u32> {
+    1..=72_u32
+}
+
+proptest! {
+    #[test]
+    fn vorticity_sign_matches_net_anomaly(
+        surface_temp in anomaly(),
+        altitude_temp in anomaly(),
+        latitude in latitude(),
+        time_steps in duration(),
+    ) {
+        let results = BaroclinicCyclogenesis::new(surface_temp, altitude_temp, latitude)
+            .unwrap()
+            .simulate_interaction(time_steps)
+            .unwrap();
+        prop_assert!(vorticity_sign_matches_anomaly(&results, surface_temp, altitude_temp, latitude));
+    }
+
+    #[test]
+    fn zero_anomaly_produces_no_development(latitude in latitude(), time_steps in duration()) {
+        let results = BaroclinicCyclogenesis::new(0.0, 0.0, latitude)
+            .unwrap()
+            .simulate_interaction(time_steps)
+            .unwrap();
+        prop_assert!(is_quiescent(&results));
+    }
+
+    #[test]
+    fn results_are_antisymmetric_about_the_equator(
+        surface_temp in anomaly(),
+        altitude_temp in anomaly(),
+        latitude in 0.0..=90.0,
+        time_steps in duration(),
+    ) {
+        let north = BaroclinicCyclogenesis::new(surface_temp, altitude_temp, latitude)
+            .unwrap()
+            .simulate_interaction(time_steps)
+            .unwrap();
+        let south = BaroclinicCyclogenesis::new(surface_temp, altitude_temp, -latitude)
+            .unwrap()
+            .simulate_interaction(time_steps)
+            .unwrap();
+        prop_assert!(is_antisymmetric(&north, &south, SYMMETRY_TOLERANCE));
+    }
+}
@@ -0,0 +1,57 @@
+//! Représentation des anomalies en coordonnées isentropes
+
+use std::process::Command;
+
+use cyclogenese_rust::isentropic::{self, IsentropicLevel, IsentropicOutput};
+use cyclogenese_rust::{BaroclinicCyclogenesis, NumberFormat, OutputLanguage};
+
+fn levels(latitude: f64) -> Vec<IsentropicLevel> {
+    let mut cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, latitude).unwrap();
+    let results = cyclogenesis.simulate_interaction(24).unwrap();
+    cyclogenesis.isentropic_view(results.last().unwrap())
+}
+
+#[test]
+fn anomalies_sit_on_increasing_isentropes() {
+    let levels = levels(45.0);
+    assert_eq!(levels.len(), 2);
+    let (surface, upper) = (levels[0], levels[1]);
+    assert!(upper.theta > surface.theta && upper.pressure < surface.pressure);
+    // Surface chaude : θ au-dessus de la température standard
+    assert!(surface.theta > 288.15 && surface.is_cyclonic);
+}
+
+#[test]
+fn potential_vorticity_adds_the_planetary_part() {
+    for (latitude, sign) in [(45.0, 1.0), (-45.0, -1.0)] {
+        for level in levels(latitude) {
+            let planetary = level.potential_vorticity - level.pv_anomaly;
+            assert!(planetary * sign > 0.0, "{:?}", level);
+            assert_eq!(level.pv_anomaly * sign > 0.0, level.is_cyclonic, "{:?}", level);
+        }
+    }
+    // Air moins dense en altitude : le tourbillon potentiel planétaire y est plus fort
+    let levels = levels(45.0);
+    let planetary = |level: &IsentropicLevel| level.potential_vorticity - level.pv_anomaly;
+    assert!(planetary(&levels[1]) > planetary(&levels[0]));
+}
+
+#[test]
+fn the_view_is_printed_as_a_table_or_json() {
+    let levels = levels(45.0);
+    let format = NumberFormat::default();
+    let table = isentropic::to_table(&levels, OutputLanguage::English, &format);
+    let lines = table.lines().collect::<Vec<_>>();
+    assert!(lines[0].starts_with("θ (K)                | Pressure (hPa)"));
+    assert_eq!(lines.len(), 4);
+    assert!(lines[2].starts_with(&format.format_width(levels[0].theta, 20)) && lines[2].ends_with("| cyclonic"));
+
+    let json = isentropic::to_json(&levels);
+    assert_eq!(serde_json::from_str::<Vec<IsentropicLevel>>(&json).unwrap(), levels);
+    assert_eq!("JSON".parse::<IsentropicOutput>(), Ok(IsentropicOutput::Json));
+    assert!("csv".parse::<IsentropicOutput>().is_err());
+
+    let output = Command::new(env!("CARGO_BIN_EXE_cyclogenese")).args(["--isentropic", "json"]).output().unwrap();
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap().matches("\"potential_vorticity\"").count(), 6);
+}
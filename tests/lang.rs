@@ -0,0 +1,61 @@
+//! Langue des textes affichés
+
+use cyclogenese_rust::report::{Report, ReportFormat};
+use cyclogenese_rust::{scenarios, MeteoError, NumberFormat, OutputLanguage, Scenario};
+
+#[test]
+fn languages_are_read_from_codes_and_names() {
+    for (value, language) in [
+        ("fr", OutputLanguage::French),
+        ("fr_CA", OutputLanguage::French),
+        ("Français", OutputLanguage::French),
+        ("en", OutputLanguage::English),
+        ("en-GB", OutputLanguage::English),
+        ("EN", OutputLanguage::English),
+        ("english", OutputLanguage::English),
+    ] {
+        assert_eq!(value.parse::<OutputLanguage>(), Ok(language), "{}", value);
+    }
+    for language in [OutputLanguage::French, OutputLanguage::English] {
+        assert_eq!(language.to_string().parse::<OutputLanguage>(), Ok(language));
+    }
+
+    let error = "de".parse::<OutputLanguage>().unwrap_err();
+    assert!(error.contains("de") && error.contains("fr, en"), "{}", error);
+    assert_eq!(OutputLanguage::default(), OutputLanguage::French);
+}
+
+#[test]
+fn headings_follow_the_language() {
+    assert!(OutputLanguage::French.banner().starts_with("SIMULATION DE CYCLOGÉNÈSE"));
+    assert!(OutputLanguage::English.banner().starts_with("BAROCLINIC CYCLOGENESIS"));
+    assert_eq!(OutputLanguage::English.simulation_heading(45.0), "Simulation at 45°N:");
+    assert!(OutputLanguage::English.table_header().starts_with("Hour"));
+    // Les colonnes gardent leur largeur d'une langue à l'autre
+    assert_eq!(
+        OutputLanguage::French.table_header().find('|'),
+        OutputLanguage::English.table_header().find('|')
+    );
+}
+
+#[test]
+fn errors_are_translated_and_display_in_french() {
+    let error = MeteoError::InvalidLatitude(95.0);
+    assert_eq!(error.message(OutputLanguage::French), "Latitude invalide: 95°");
+    assert_eq!(error.message(OutputLanguage::English), "Invalid latitude: 95°");
+    assert_eq!(error.to_string(), error.message(OutputLanguage::French));
+    assert_eq!(error.kind(), "InvalidLatitude");
+}
+
+#[test]
+fn tables_and_reports_are_translated() {
+    let format = NumberFormat::default();
+    assert!(scenarios::to_table(OutputLanguage::English, &format).starts_with("Preset"));
+    assert!(scenarios::to_table(OutputLanguage::French, &format).starts_with("Préréglage"));
+
+    let report = Report::compute(&Scenario::default()).unwrap();
+    let english = report.render(ReportFormat::Markdown, OutputLanguage::English, &format);
+    assert!(english.contains("Hourly results") && !english.contains("Résultats horaires"));
+    let french = report.render(ReportFormat::Markdown, OutputLanguage::French, &format);
+    assert!(french.contains("Résultats horaires"));
+}
@@ -0,0 +1,79 @@
+//! Stades du cycle de vie selon les modèles conceptuels
+
+use cyclogenese_rust::diagnostics::StepDiagnostics;
+use cyclogenese_rust::lifecycle::{self, LifecycleModel, LifecycleStage};
+use cyclogenese_rust::{scenarios, BaroclinicCyclogenesis, DevelopmentResult, NumberFormat, OutputLanguage};
+
+#[test]
+fn stages_follow_the_deepening() {
+    let mut cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap();
+    let (results, diagnostics) = cyclogenesis.simulate_with_diagnostics(24).unwrap();
+    let steps = lifecycle::tag(&results, &diagnostics, LifecycleModel::Norwegian);
+
+    assert_eq!(steps.len(), results.len());
+    assert_eq!(steps[0].stage, LifecycleStage::Incipient);
+    assert_eq!(steps[0].phase, 90.0);
+    assert_eq!(steps[23].intensity, 1.0);
+    // Le tourbillon croît pendant toute la simulation : ni maturité ni occlusion
+    let opening = results.iter().position(|result| result.relative_vorticity().abs() >= 5.0e-5).unwrap();
+    let transitions = lifecycle::transitions(&steps);
+    assert_eq!(transitions, [(0, LifecycleStage::Incipient), (results[opening].hour(), LifecycleStage::OpenWave)]);
+}
+
+/// Lothar (1999), dont le tourbillon culmine avant la dernière heure
+fn peaking_run() -> (Vec<DevelopmentResult>, Vec<StepDiagnostics>) {
+    let scenario = scenarios::find("lothar-1999").unwrap().scenario();
+    scenario.to_cyclogenesis().unwrap().simulate_with_diagnostics(scenario.time_steps).unwrap()
+}
+
+#[test]
+fn stages_depend_only_on_the_past() {
+    let (results, diagnostics) = peaking_run();
+    let steps = lifecycle::tag(&results, &diagnostics, LifecycleModel::Norwegian);
+    for hour in 1..results.len() {
+        let partial = lifecycle::tag(&results[..hour], &diagnostics[..hour], LifecycleModel::Norwegian);
+        assert_eq!(partial[..], steps[..hour], "heure {}", hour);
+    }
+    // Maturité à la première heure où le tourbillon cesse de croître
+    let peak = results.iter().enumerate().max_by(|a, b| a.1.relative_vorticity().abs().total_cmp(&b.1.relative_vorticity().abs()));
+    let peak = peak.unwrap().0;
+    assert!(peak + 1 < results.len());
+    assert!(steps[..=peak].iter().all(|step| step.stage < LifecycleStage::Mature));
+    assert_eq!(steps[peak + 1].stage, LifecycleStage::Mature);
+}
+
+#[test]
+fn shapiro_keyser_secludes_earlier() {
+    let (results, diagnostics) = peaking_run();
+    let last = |model| lifecycle::tag(&results, &diagnostics, model).last().unwrap().stage;
+    assert_eq!(last(LifecycleModel::Norwegian), LifecycleStage::Mature);
+    assert_eq!(last(LifecycleModel::ShapiroKeyser), LifecycleStage::Occluded);
+}
+
+#[test]
+fn decay_follows_the_peak() {
+    let (results, diagnostics) = peaking_run();
+    // Affaiblissement de moitié imposé à la dernière heure
+    let last = results.len() - 1;
+    let mut values = serde_json::to_value(&results).unwrap();
+    values[last]["relative_vorticity"] = (results[last - 1].relative_vorticity() / 2.0).into();
+    let results: Vec<DevelopmentResult> = serde_json::from_value(values).unwrap();
+    let steps = lifecycle::tag(&results, &diagnostics, LifecycleModel::Norwegian);
+    assert_eq!(steps[last - 1].intensity, 1.0);
+    assert_eq!(steps[last].stage, LifecycleStage::Decaying);
+}
+
+#[test]
+fn tables_use_the_model_vocabulary() {
+    let (results, diagnostics) = peaking_run();
+    let model = LifecycleModel::ShapiroKeyser;
+    let steps = lifecycle::tag(&results, &diagnostics, model);
+    let table = lifecycle::to_table(&steps, model, OutputLanguage::English, &NumberFormat::default());
+
+    assert!(table.starts_with("Hour  | Phase (°)            | Relative intensity   | Stage (Shapiro–Keyser)\n"));
+    assert!(table.contains("| warm seclusion\n"));
+    assert!(table.ends_with("\nLifecycle: frontal fracture at hour 0, warm seclusion at hour 17\n"));
+    assert_eq!(LifecycleStage::OpenWave.label(LifecycleModel::Norwegian, OutputLanguage::French), "onde ouverte");
+    assert_eq!("Shapiro-Keyser".parse::<LifecycleModel>(), Ok(LifecycleModel::ShapiroKeyser));
+    assert!("bergen".parse::<LifecycleModel>().is_err());
+}
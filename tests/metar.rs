@@ -0,0 +1,70 @@
+//! Décodage des observations METAR
+
+use std::path::Path;
+
+use cyclogenese_rust::io::metar::Metar;
+use cyclogenese_rust::{MeteoError, PhysicalConstants, Position, ThermalAnomaly};
+
+fn reports() -> Vec<Metar> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join("metar.txt");
+    std::fs::read_to_string(path).unwrap().lines().map(|line| Metar::parse(line).unwrap()).collect()
+}
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!((actual - expected).abs() < 1e-9, "{} au lieu de {}", actual, expected);
+}
+
+fn assert_invalid(report: &str, message: &str) {
+    match Metar::parse(report) {
+        Err(MeteoError::InvalidMetar(text)) => assert!(text.contains(message), "{}", text),
+        other => panic!("erreur METAR attendue, obtenu {:?}", other),
+    }
+}
+
+#[test]
+fn temperatures_and_qnh_are_decoded() {
+    let reports = reports();
+    let stations = reports.iter().map(Metar::station).collect::<Vec<_>>();
+    assert_eq!(stations, ["LFPG", "KJFK", "EGLL", "LFRN"]);
+
+    assert_close(reports[0].temperature(), 8.0 + 273.15);
+    assert_close(reports[0].dewpoint().unwrap(), 6.0 + 273.15);
+    assert_eq!(reports[0].pressure(), 987.0);
+
+    // Températures négatives et altimètre en centièmes de pouce de mercure
+    assert_close(reports[1].temperature(), -5.0 + 273.15);
+    assert_close(reports[1].dewpoint().unwrap(), -12.0 + 273.15);
+    assert_close(reports[1].pressure(), 30.12 * 33.8639);
+}
+
+#[test]
+fn missing_dewpoints_and_remarks_are_ignored() {
+    let reports = reports();
+    // SPECI corrigé, point de rosée omis
+    assert_eq!(reports[2].dewpoint(), None);
+    assert_eq!(reports[2].pressure(), 975.0);
+    // Point de rosée `//`, groupe Q des remarques écarté
+    assert_eq!(reports[3].dewpoint(), None);
+    assert_eq!(reports[3].pressure(), 972.0);
+}
+
+#[test]
+fn observations_set_the_surface_anomaly() {
+    let report = &reports()[0];
+    let constants = PhysicalConstants::default();
+    // Anomalie au niveau de la mer, au QNH, par rapport à 288,15 K
+    let position = Position::new(49.0, 0.0, 987.0).unwrap();
+    assert_eq!(format!("{:?}", report.surface_position(49.0).unwrap()), format!("{:?}", position));
+    let expected = ThermalAnomaly::new(8.0 + 273.15 - 288.15, position, constants).unwrap();
+    assert_eq!(format!("{:?}", report.surface_anomaly(49.0, constants).unwrap()), format!("{:?}", expected));
+    assert!(report.to_cyclogenesis(49.0, -8.0).is_ok());
+    assert!(report.to_cyclogenesis(95.0, -8.0).is_err());
+}
+
+#[test]
+fn incomplete_reports_are_refused() {
+    assert_invalid("METAR 251200Z 08/06 Q0987", "indicatif de station");
+    assert_invalid("METAR LFPG 251200Z 24015KT Q0987", "température");
+    assert_invalid("METAR LFPG 251200Z 24015KT 08/06 NOSIG", "pression");
+    assert_invalid("METAR LFPG 251200Z 08/06 RMK Q0987", "pression");
+}
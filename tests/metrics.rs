@@ -0,0 +1,83 @@
+//! Métriques Prometheus des modes service
+
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use cyclogenese_rust::metrics::{Metrics, ScenarioDurations, DURATION_BUCKETS, MAX_SCENARIO_LABELS};
+use cyclogenese_rust::server;
+
+#[test]
+fn exposition_follows_the_prometheus_text_format() {
+    let metrics = Metrics::default();
+    metrics.record_simulation(72, Duration::from_millis(2));
+    metrics.record_simulation(48, Duration::from_millis(40));
+    metrics.record_simulation(24, Duration::from_secs(10));
+    metrics.record_error();
+
+    let text = metrics.render();
+    assert!(text.contains("# TYPE cyclogenese_simulations_total counter\ncyclogenese_simulations_total 3\n"));
+    assert!(text.contains("cyclogenese_simulation_errors_total 1\n"));
+    assert!(text.contains("cyclogenese_steps_total 144\n"));
+    assert!(text.contains("# TYPE cyclogenese_simulation_duration_seconds histogram\n"));
+    assert!(text.contains("cyclogenese_simulation_duration_seconds_bucket{le=\"0.001\"} 0\n"));
+    assert!(text.contains("cyclogenese_simulation_duration_seconds_bucket{le=\"0.0025\"} 1\n"));
+    assert!(text.contains("cyclogenese_simulation_duration_seconds_bucket{le=\"0.05\"} 2\n"));
+    assert!(text.contains("cyclogenese_simulation_duration_seconds_bucket{le=\"2.5\"} 2\n"));
+    assert!(text.contains("cyclogenese_simulation_duration_seconds_bucket{le=\"+Inf\"} 3\n"));
+    assert!(text.contains("cyclogenese_simulation_duration_seconds_sum 10.042\n"));
+    assert!(text.contains("cyclogenese_simulation_duration_seconds_count 3\n"));
+    let buckets = text.lines().filter(|line| line.contains("_bucket{")).count();
+    assert_eq!(buckets, DURATION_BUCKETS.len() + 1);
+}
+
+#[test]
+fn durations_are_labelled_by_scenario() {
+    let metrics = Metrics::default();
+    metrics.record_scenario(Some("lothar"), 72, Duration::from_millis(250));
+    metrics.record_scenario(Some("lothar"), 72, Duration::from_millis(500));
+    metrics.record_scenario(Some("dit \"martin\""), 24, Duration::from_secs(1));
+    metrics.record_scenario(None, 24, Duration::from_secs(2));
+
+    assert_eq!((metrics.simulations(), metrics.steps()), (4, 192));
+    assert_eq!(metrics.scenario("lothar"), Some(ScenarioDurations { count: 2, seconds: 0.75 }));
+    let text = metrics.render();
+    assert!(text.contains("# TYPE cyclogenese_scenario_duration_seconds summary\n"));
+    assert!(text.contains("cyclogenese_scenario_duration_seconds_sum{scenario=\"lothar\"} 0.75\n"));
+    assert!(text.contains("cyclogenese_scenario_duration_seconds_count{scenario=\"lothar\"} 2\n"));
+    assert!(text.contains("cyclogenese_scenario_duration_seconds_count{scenario=\"dit \\\"martin\\\"\"} 1\n"));
+    assert_eq!(text.matches("cyclogenese_scenario_duration_seconds_count{").count(), 2);
+
+    // Au-delà de la limite, les nouveaux noms ne comptent que dans les totaux
+    for index in 0..MAX_SCENARIO_LABELS {
+        metrics.record_scenario(Some(&index.to_string()), 1, Duration::ZERO);
+    }
+    assert!(metrics.scenario(&(MAX_SCENARIO_LABELS - 1).to_string()).is_none());
+    metrics.record_scenario(Some("lothar"), 72, Duration::ZERO);
+    assert_eq!(metrics.scenario("lothar").unwrap().count, 3);
+}
+
+#[test]
+fn metrics_listener_serves_only_metrics() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    let metrics = Arc::new(Metrics::default());
+    metrics.record_error();
+    let served = Arc::clone(&metrics);
+    thread::spawn(move || server::serve_metrics(listener, served));
+
+    let get = |path: &str| {
+        let mut stream = TcpStream::connect(address).unwrap();
+        write!(stream, "GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    };
+    let response = get("/metrics");
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(response.contains("Content-Type: text/plain; version=0.0.4"));
+    assert!(response.ends_with(&metrics.render()));
+    assert!(get("/simulate").starts_with("HTTP/1.1 404 Not Found\r\n"));
+}
@@ -0,0 +1,95 @@
+//! Publication MQTT vers un courtier factice
+#![cfg(feature = "mqtt")]
+
+use std::io::Read;
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+
+use cyclogenese_rust::classify::WindstormClassifier;
+use cyclogenese_rust::mqtt::{MqttSink, MqttTopics};
+use cyclogenese_rust::{OutputLanguage, Scenario};
+use serde_json::Value;
+
+/// Lit un paquet : type et corps
+fn read_packet(stream: &mut TcpStream) -> (u8, Vec<u8>) {
+    let mut header = [0; 1];
+    stream.read_exact(&mut header).unwrap();
+    let (mut length, mut shift) = (0usize, 0);
+    loop {
+        let mut byte = [0; 1];
+        stream.read_exact(&mut byte).unwrap();
+        length |= usize::from(byte[0] & 0x7f) << shift;
+        shift += 7;
+        if byte[0] & 0x80 == 0 {
+            break;
+        }
+    }
+    let mut body = vec![0; length];
+    stream.read_exact(&mut body).unwrap();
+    (header[0], body)
+}
+
+/// Courtier acceptant une connexion et rendant les publications reçues
+fn broker(return_code: u8) -> (String, thread::JoinHandle<Vec<(String, Value)>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    let handle = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let (header, body) = read_packet(&mut stream);
+        assert_eq!(header, 0x10);
+        assert_eq!(&body[..7], b"\0\x04MQTT\x04");
+        std::io::Write::write_all(&mut stream, &[0x20, 2, 0, return_code]).unwrap();
+        if return_code != 0 {
+            return Vec::new();
+        }
+
+        let mut messages = Vec::new();
+        loop {
+            match read_packet(&mut stream) {
+                (0x30, body) => {
+                    let length = usize::from(u16::from_be_bytes([body[0], body[1]]));
+                    let topic = String::from_utf8(body[2..2 + length].to_vec()).unwrap();
+                    messages.push((topic, serde_json::from_slice(&body[2 + length..]).unwrap()));
+                }
+                (0xe0, body) => {
+                    assert!(body.is_empty());
+                    return messages;
+                }
+                (header, _) => panic!("paquet inattendu: {:#x}", header),
+            }
+        }
+    });
+    (address, handle)
+}
+
+#[test]
+fn results_and_alerts_are_published_live() {
+    let (address, handle) = broker(0);
+    let topics = MqttTopics { results: "tp/results".to_string(), alerts: "tp/alerts".to_string() };
+    let mut sink = MqttSink::connect(address.as_str(), "test", topics).unwrap();
+    let scenario = Scenario { time_steps: 72, ..Scenario::default() };
+    let mut cyclogenesis = scenario.to_cyclogenesis().unwrap();
+    let classifier = WindstormClassifier::new(5.0, 15.0).unwrap();
+    let alerts = sink.stream(&mut cyclogenesis, scenario.time_steps, &classifier, OutputLanguage::French).unwrap();
+    sink.disconnect().unwrap();
+
+    let messages = handle.join().unwrap();
+    let results: Vec<_> = messages.iter().filter(|(topic, _)| topic == "tp/results").collect();
+    let published: Vec<_> = messages.iter().filter(|(topic, _)| topic == "tp/alerts").collect();
+    assert_eq!(results.len(), 72);
+    assert_eq!(results[0].1["hour"], 0);
+    assert_eq!(results[71].1["hour"], 71);
+    assert_eq!(published.len(), alerts);
+    assert!(alerts > 0);
+    assert!(published.iter().any(|(_, alert)| alert["severe"] == true));
+    let ranks: Vec<u64> = published.iter().map(|(_, alert)| alert["rank"].as_u64().unwrap()).collect();
+    assert!(ranks.windows(2).all(|pair| pair[0] != pair[1]));
+}
+
+#[test]
+fn refused_connections_are_reported() {
+    let (address, handle) = broker(5);
+    let error = MqttSink::connect(address.as_str(), "test", MqttTopics::default()).err().unwrap();
+    assert!(error.to_string().contains("code 5"));
+    assert!(handle.join().unwrap().is_empty());
+}
@@ -0,0 +1,62 @@
+//! Rappel de la simulation vers une série d'observations
+
+use cyclogenese_rust::{MeteoError, Nudging, Observation, Scenario};
+
+fn vorticities(scenario: &Scenario) -> Vec<f64> {
+    let results = serde_json::to_value(scenario.run().unwrap()).unwrap();
+    results.as_array().unwrap().iter().map(|result| result["relative_vorticity"].as_f64().unwrap()).collect()
+}
+
+/// Pas d'intégration d'une seconde, qui rattrape l'écart en un pas pour τ = 1 s
+fn nudged(timescale: f64, observations: Vec<Observation>) -> Vec<f64> {
+    let nudging = Some(Nudging::new(timescale, observations).unwrap());
+    vorticities(&Scenario { nudging, time_step: Some(1.0), ..Scenario::default() })
+}
+
+#[test]
+fn short_timescale_follows_the_observations() {
+    let observations = (0..24).map(|hour| Observation::vorticity(hour, -2.0e-5 - 1.0e-6 * f64::from(hour))).collect();
+    for (hour, vorticity) in nudged(1.0, observations).iter().enumerate() {
+        let expected = -2.0e-5 - 1.0e-6 * hour as f64;
+        assert!((vorticity - expected).abs() <= 1e-12, "heure {} : {} / {}", hour, vorticity, expected);
+    }
+}
+
+#[test]
+fn relaxation_pulls_the_run_toward_the_observations() {
+    let free = vorticities(&Scenario::default());
+    let observations = vec![Observation::vorticity(0, 0.0), Observation::vorticity(23, 0.0)];
+    let (slow, fast) = (nudged(86400.0, observations.clone()), nudged(3600.0, observations));
+
+    for hour in 0..24 {
+        assert!(fast[hour].abs() < slow[hour].abs(), "heure {}", hour);
+        assert!(slow[hour].abs() < free[hour].abs(), "heure {}", hour);
+    }
+}
+
+#[test]
+fn central_pressure_is_read_from_a_scenario() {
+    let scenario = Scenario::from_toml(
+        "time_step = 1.0\n\
+         [nudging]\n\
+         timescale = 1.0\n\
+         observations = [{ hour = 0, pressure_deficit = -10.0 }, { hour = 12, pressure_deficit = -20.0 }]\n",
+    )
+    .unwrap();
+    let vorticity = vorticities(&scenario);
+
+    // Une dépression de l'hémisphère nord est cyclonique ; interpolation linéaire entre les observations
+    assert!(vorticity[0] > 0.0);
+    assert!((vorticity[12] - 2.0 * vorticity[0]).abs() <= 1e-9 * vorticity[0]);
+    assert!((vorticity[6] - 1.5 * vorticity[0]).abs() <= 1e-9 * vorticity[0]);
+}
+
+#[test]
+fn inconsistent_observations_are_rejected() {
+    let unordered = Nudging::new(3600.0, vec![Observation::vorticity(6, 0.0), Observation::vorticity(6, 1.0e-5)]);
+    assert!(matches!(unordered, Err(MeteoError::InvalidNudging(_))));
+
+    let both = Observation { pressure_deficit: Some(-10.0), ..Observation::vorticity(0, 1.0e-5) };
+    assert!(matches!(Nudging::new(3600.0, vec![both]), Err(MeteoError::InvalidNudging(_))));
+    assert!(matches!(Nudging::new(0.0, vec![Observation::vorticity(0, 0.0)]), Err(MeteoError::InvalidNudging(_))));
+}
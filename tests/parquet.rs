@@ -0,0 +1,53 @@
+//! Export Parquet, relu avec le lecteur de la bibliothèque parquet
+#![cfg(feature = "parquet")]
+
+use std::fs::File;
+
+use cyclogenese_rust::io::parquet::ParquetWriter;
+use cyclogenese_rust::Scenario;
+use parquet::file::reader::{FileReader, SerializedFileReader};
+use parquet::record::{Field, RowAccessor};
+
+#[test]
+fn runs_are_read_back_across_row_groups() {
+    let undated = Scenario::default().run().unwrap();
+    let dated = Scenario::from_toml("start_time = 1999-12-25T12:00:00Z\n").unwrap().run().unwrap();
+    let path = std::env::temp_dir().join(format!("cyclogenese-parquet-{}.parquet", std::process::id()));
+
+    let mut writer = ParquetWriter::with_row_group_size(File::create(&path).unwrap(), 10).unwrap();
+    writer.write_run("reference", 0, &undated).unwrap();
+    writer.write_run("lothar", 1, &dated).unwrap();
+    writer.close().unwrap();
+
+    let reader = SerializedFileReader::new(File::open(&path).unwrap()).unwrap();
+    let total = undated.len() + dated.len();
+    let groups = (0..reader.num_row_groups())
+        .map(|index| reader.metadata().row_group(index).num_rows())
+        .collect::<Vec<_>>();
+    assert_eq!(groups.len(), total.div_ceil(10));
+    assert!(groups[..groups.len() - 1].iter().all(|&rows| rows == 10));
+    assert_eq!(groups.iter().sum::<i64>(), total as i64);
+
+    let rows = reader.get_row_iter(None).unwrap().collect::<Result<Vec<_>, _>>().unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(rows.len(), total);
+    let expected = undated.iter().map(|result| ("reference", 0, result))
+        .chain(dated.iter().map(|result| ("lothar", 1, result)));
+    for (row, (run_id, member, result)) in rows.iter().zip(expected) {
+        assert_eq!(row.get_string(0).unwrap(), run_id);
+        assert_eq!(row.get_uint(1).unwrap(), member);
+        assert_eq!(row.get_uint(2).unwrap(), result.hour());
+        assert_eq!(row.get_double(3).unwrap(), result.vertical_velocity());
+        assert_eq!(row.get_double(4).unwrap(), result.relative_vorticity());
+        let valid_time = row.get_column_iter().nth(5).unwrap().1;
+        match result.valid_time() {
+            Some(time) => assert_eq!(valid_time, &Field::TimestampMillis(time.timestamp_millis())),
+            None => assert_eq!(valid_time, &Field::Null),
+        }
+    }
+}
+
+#[test]
+fn empty_row_groups_are_refused() {
+    assert!(ParquetWriter::with_row_group_size(Vec::new(), 0).is_err());
+}
@@ -0,0 +1,56 @@
+//! Évolution du déphasage entre les anomalies et verrouillage de phase
+
+use std::f64::consts::FRAC_PI_2;
+
+use cyclogenese_rust::diagnostics::{self, StepDiagnostics};
+use cyclogenese_rust::phase::PHASE_LOCK_RATE;
+use cyclogenese_rust::{BaroclinicCyclogenesis, NumberFormat, OutputLanguage};
+
+fn phases(tilt: Option<f64>) -> Vec<StepDiagnostics> {
+    let mut cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap();
+    if let Some(tilt) = tilt {
+        cyclogenesis = cyclogenesis.with_tilt(tilt).unwrap();
+    }
+    cyclogenesis.simulate_with_diagnostics(48).unwrap().1
+}
+
+#[test]
+fn the_quarter_wavelength_tilt_relaxes_towards_a_locked_phase() {
+    let cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap();
+    assert!((cyclogenesis.initial_phase() - FRAC_PI_2).abs() < 1e-12);
+
+    let steps = phases(None);
+    assert!((steps[0].phase - FRAC_PI_2).abs() < 1e-12);
+    for pair in steps.windows(2) {
+        assert!(pair[1].phase < pair[0].phase);
+        assert!(pair[1].phase_tendency.unwrap().abs() < pair[0].phase_tendency.unwrap().abs());
+    }
+    let last = steps.last().unwrap();
+    assert!(last.phase > 0.0 && last.phase_tendency.unwrap().abs() < PHASE_LOCK_RATE / 10.0);
+}
+
+#[test]
+fn locking_is_reported_from_the_first_quasi_stationary_hour() {
+    let steps = phases(None);
+    let locked = steps.iter().position(StepDiagnostics::is_phase_locked).unwrap();
+    assert!(locked > 0 && steps[locked..].iter().all(StepDiagnostics::is_phase_locked));
+    assert!(steps[locked].phase_tendency.unwrap().abs() < PHASE_LOCK_RATE);
+    assert!(steps[locked - 1].phase_tendency.unwrap().abs() >= PHASE_LOCK_RATE);
+
+    let format = NumberFormat::default();
+    let table = diagnostics::to_table(&steps, OutputLanguage::English, &format);
+    let summary = format!("Phase locking at hour {} (phase {}°)", locked, format.format(steps[locked].phase.to_degrees()));
+    assert_eq!(table.lines().last(), Some(summary.as_str()));
+    assert!(table.lines().nth(2 + locked).unwrap().ends_with("phase locked"));
+    assert!(!table.lines().nth(1 + locked).unwrap().contains("phase locked"));
+}
+
+#[test]
+fn an_upright_system_tilts_towards_the_same_phase() {
+    let upright = phases(Some(0.0));
+    assert_eq!(upright[0].phase, 0.0);
+    assert!(upright.windows(2).all(|pair| pair[1].phase > pair[0].phase));
+    let tilted = phases(None);
+    let (upright, tilted) = (upright.last().unwrap().phase, tilted.last().unwrap().phase);
+    assert!((upright - tilted).abs() < 1.0_f64.to_radians(), "{} {}", upright.to_degrees(), tilted.to_degrees());
+}
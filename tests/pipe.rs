@@ -0,0 +1,55 @@
+//! Mode tuyau : scénarios JSON en entrée, résultats JSON lines en sortie
+
+use cyclogenese_rust::pipe::{self, PipeSummary};
+use cyclogenese_rust::{DevelopmentResult, OutputLanguage, Scenario};
+use serde_json::Value;
+
+fn run(input: &str) -> (PipeSummary, Vec<Value>) {
+    let mut output = Vec::new();
+    let summary = pipe::run(input.as_bytes(), &mut output, OutputLanguage::French).unwrap();
+    let lines = String::from_utf8(output)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    (summary, lines)
+}
+
+#[test]
+fn each_scenario_line_yields_one_result_line() {
+    let spherical = Scenario::from_toml("geometry = \"spherical\"\nstart_time = 1999-12-25T12:00:00Z\ntime_steps = 12\n").unwrap();
+    let scenarios = [Scenario::default(), spherical];
+    let input: String = scenarios.iter().map(|s| serde_json::to_string(s).unwrap() + "\n").collect();
+
+    let (summary, lines) = run(&input);
+    assert_eq!(summary, PipeSummary { succeeded: 2, failed: 0 });
+    assert_eq!(lines.len(), 2);
+    for (index, (line, scenario)) in lines.iter().zip(&scenarios).enumerate() {
+        assert_eq!(line["line"], index + 1);
+        let results: Vec<DevelopmentResult> = serde_json::from_value(line["results"].clone()).unwrap();
+        let expected = scenario.run().unwrap();
+        assert_eq!(results.len(), expected.len());
+        for (result, expected) in results.iter().zip(&expected) {
+            assert_eq!(result.hour(), expected.hour());
+            assert_eq!(result.valid_time(), expected.valid_time());
+            assert!((result.relative_vorticity() - expected.relative_vorticity()).abs() <= 1e-15 * expected.relative_vorticity().abs());
+            assert!((result.vertical_velocity() - expected.vertical_velocity()).abs() <= 1e-15 * expected.vertical_velocity().abs());
+        }
+    }
+}
+
+#[test]
+fn invalid_lines_are_reported_without_stopping() {
+    let input = "{\"latitude\": 95.0}\n\nnot json\n{\"time_steps\": 6}\n";
+    let (summary, lines) = run(input);
+    assert_eq!(summary, PipeSummary { succeeded: 1, failed: 2 });
+    assert_eq!(lines.len(), 3);
+
+    assert_eq!(lines[0]["line"], 1);
+    assert!(lines[0]["error"].as_str().unwrap().contains("Latitude"));
+    assert!(lines[0].get("results").is_none());
+    assert_eq!(lines[1]["line"], 3);
+    assert!(lines[1]["error"].is_string());
+    assert_eq!(lines[2]["line"], 4);
+    assert_eq!(lines[2]["results"].as_array().unwrap().len(), 6);
+}
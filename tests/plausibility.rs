@@ -0,0 +1,41 @@
+//! Avertissements de vraisemblance physique
+
+use cyclogenese_rust::check::{Finding, ScenarioCheck};
+use cyclogenese_rust::plausibility::PlausibilityWarning;
+use cyclogenese_rust::{BaroclinicCyclogenesis, OutputLanguage, Scenario};
+
+#[test]
+fn reference_case_is_plausible() {
+    assert!(BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap().plausibility_warnings().is_empty());
+    assert!(Scenario::default().plausibility_warnings().unwrap().is_empty());
+}
+
+#[test]
+fn tropical_latitude_and_inverted_anomalies_are_flagged() {
+    let cyclogenesis = BaroclinicCyclogenesis::new(-3.0, 4.0, -5.0).unwrap();
+    assert_eq!(
+        cyclogenesis.plausibility_warnings(),
+        vec![
+            PlausibilityWarning::LowLatitude(-5.0),
+            PlausibilityWarning::InvertedAnomalies { surface: -3.0, altitude: 4.0 },
+        ]
+    );
+    // Les avertissements ne bloquent pas la simulation
+    let scenario = Scenario { surface_temp: -3.0, altitude_temp: 4.0, latitude: -5.0, ..Scenario::default() };
+    assert!(scenario.run().is_ok());
+
+    let text = PlausibilityWarning::LowLatitude(-5.0).describe(OutputLanguage::English, &Default::default());
+    assert!(text.starts_with("Warning: at -5.00° latitude, f ≈ 0"));
+}
+
+#[test]
+fn scenario_check_reports_plausibility_warnings() {
+    let check = ScenarioCheck::run(&Scenario { latitude: 8.0, ..Scenario::default() }).unwrap();
+    assert!(check.findings().contains(&Finding::Implausible(PlausibilityWarning::LowLatitude(8.0))));
+    assert!(!check.is_clean());
+
+    // À l'équateur, seul le constat dédié est rapporté
+    let equator = ScenarioCheck::run(&Scenario { latitude: 0.0, ..Scenario::default() }).unwrap();
+    assert!(equator.findings().contains(&Finding::Equator));
+    assert!(!equator.findings().iter().any(|finding| matches!(finding, Finding::Implausible(_))));
+}
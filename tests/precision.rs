@@ -0,0 +1,53 @@
+//! Simulation en simple précision
+
+use cyclogenese_rust::ensemble::{Ensemble, EnsembleConfig, EnsembleVariable};
+use cyclogenese_rust::{BaroclinicCyclogenesis, DevelopmentResult, Float, Scenario};
+
+fn series<T: Float + serde::Serialize>(results: &[DevelopmentResult<T>], field: &str) -> Vec<f64> {
+    serde_json::to_value(results)
+        .unwrap()
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|result| result[field].as_f64().unwrap())
+        .collect()
+}
+
+#[test]
+fn single_precision_matches_double_precision() {
+    for latitude in [30.0, 45.0, -60.0] {
+        let double = BaroclinicCyclogenesis::<f64>::new(5.0, -8.0, latitude).unwrap().simulate_interaction(48).unwrap();
+        let single = BaroclinicCyclogenesis::<f32>::new(5.0, -8.0, latitude).unwrap().simulate_interaction(48).unwrap();
+
+        for field in ["vertical_velocity", "relative_vorticity", "upper_level_divergence"] {
+            for (d, s) in series(&double, field).into_iter().zip(series(&single, field)) {
+                assert!((d - s).abs() <= 1e-5 * d.abs(), "{} à {}° : {} / {}", field, latitude, d, s);
+            }
+        }
+    }
+}
+
+#[test]
+fn single_precision_ensemble_matches_double_precision() {
+    let scenario = Scenario { time_steps: 36, ..Scenario::default() };
+    let config = EnsembleConfig::new(8, 1.0, 1.0).unwrap().with_seed(7);
+    let double = Ensemble::generate(&scenario, &config).unwrap();
+    let single = Ensemble::<f32>::generate_as(&scenario, &config).unwrap();
+
+    assert_eq!(single.hours(), double.hours());
+    for hour in [0.0, 12.0, 35.0] {
+        let (d, s) = (double.values(EnsembleVariable::Pressure, hour), single.values(EnsembleVariable::Pressure, hour));
+        for (d, s) in d.into_iter().zip(s) {
+            assert!((d - s).abs() < 1e-3, "{} h : {} / {}", hour, d, s);
+        }
+    }
+}
+
+#[test]
+fn single_precision_scenario_keeps_the_phase_diagnostic() {
+    let scenario = Scenario { latitude: 80.0, time_step: Some(3600.0), ..Default::default() };
+    let double = scenario.to_cyclogenesis_as::<f64>().unwrap().stability_limit(48).unwrap();
+    let single = scenario.to_cyclogenesis_as::<f32>().unwrap().stability_limit(48).unwrap();
+    assert!((double.max_time_step - single.max_time_step).abs() <= 1e-4 * double.max_time_step);
+    assert_eq!(single.time_step, 3600.0);
+}
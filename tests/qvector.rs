@@ -0,0 +1,56 @@
+//! Vecteur Q et forçage de l'ascendance
+
+use std::process::Command;
+
+use cyclogenese_rust::qvector::{self, QVector};
+use cyclogenese_rust::{BaroclinicCyclogenesis, DevelopmentResult, NumberFormat, OutputLanguage};
+
+fn vectors(cyclogenesis: BaroclinicCyclogenesis) -> (Vec<DevelopmentResult>, Vec<QVector>) {
+    let mut cyclogenesis = cyclogenesis;
+    let (results, steps) = cyclogenesis.simulate_with_diagnostics(24).unwrap();
+    (results, steps.iter().map(|step| step.q_vector).collect())
+}
+
+#[test]
+fn the_magnitude_follows_the_geostrophic_deformation() {
+    let reference = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap();
+    let (results, vectors) = vectors(reference);
+    // |Q| ∝ |ζ|/2 à gradient de fond et pression fixés
+    let scale = vectors[0].magnitude / results[0].relative_vorticity().abs();
+    for (vector, result) in vectors.iter().zip(&results) {
+        assert_eq!(vector.hour, result.hour());
+        assert!(vector.magnitude > 0.0);
+        assert!((vector.magnitude / result.relative_vorticity().abs() / scale - 1.0).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn convergence_forces_ascent_in_cyclonic_systems() {
+    for latitude in [45.0, -45.0] {
+        let (results, vectors) = vectors(BaroclinicCyclogenesis::new(5.0, -8.0, latitude).unwrap());
+        for (vector, result) in vectors.iter().zip(&results) {
+            let cyclonic = result.relative_vorticity() * latitude > 0.0;
+            assert_eq!(vector.convergence > 0.0, cyclonic, "{} {}", latitude, vector.hour);
+        }
+    }
+    // Anomalies à la verticale l'une de l'autre : pas de forçage
+    let upright = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap().with_tilt(0.0).unwrap();
+    assert!(vectors(upright).1.iter().all(|vector| vector.magnitude > 0.0 && vector.convergence.abs() < 1e-30));
+}
+
+#[test]
+fn the_q_vector_option_prints_the_table() {
+    let (results, vectors) = vectors(BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap());
+    let format = NumberFormat::default();
+    let table = qvector::to_table(&results, &vectors, OutputLanguage::English, &format);
+    let lines = table.lines().collect::<Vec<_>>();
+    assert!(lines[0].starts_with("Q-vector: |Q| in 10⁻¹² m² kg⁻¹ s⁻¹"));
+    assert_eq!(lines.len(), 3 + 24);
+    let velocity = format.format_width(results[0].vertical_velocity() * 100.0, 20);
+    assert!(lines[3].starts_with(&format!("   0 | {} | ", velocity)), "{}", lines[3]);
+    assert!(lines[3].ends_with(&format.format_width(vectors[0].convergence / 1e-18, 20)));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_cyclogenese")).arg("--q-vector").output().unwrap();
+    assert!(output.status.success());
+    assert_eq!(String::from_utf8(output.stdout).unwrap().matches("Vecteur Q : |Q|").count(), 3);
+}
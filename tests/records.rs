@@ -0,0 +1,111 @@
+//! Enregistrements binaires écrits au fil de la simulation
+
+use cyclogenese_rust::io::binary::{self, HEADER_SIZE};
+use cyclogenese_rust::io::records::{self, RecordWriter, RECORD_SIZE};
+use cyclogenese_rust::{BaroclinicCyclogenesis, MeteoError, Scenario};
+
+fn temporary(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("cyclogenese-{}-{}.bin", name, std::process::id()))
+}
+
+#[test]
+fn records_round_trip_every_field() {
+    let scenario = Scenario::from_toml("geometry = \"spherical\"\nstart_time = 1999-12-25T12:00:00Z\nlongitude = -4.5\n").unwrap();
+    for scenario in [Scenario::default(), scenario] {
+        for result in scenario.run().unwrap() {
+            assert_eq!(records::decode(&records::encode(&result)).unwrap(), result);
+        }
+    }
+}
+
+#[test]
+fn simulation_is_written_step_by_step_and_resumed() {
+    let scenario = Scenario { time_steps: 30, ..Scenario::default() };
+    let path = temporary("records");
+    let mut writer = RecordWriter::create(&path, &scenario).unwrap();
+    let last = scenario.to_cyclogenesis().unwrap().simulate_to(scenario.time_steps, &mut writer).unwrap();
+    assert_eq!(writer.len(), 30);
+    let expected = scenario.run().unwrap();
+    assert_eq!(records::read(&path).unwrap(), expected);
+    assert_eq!(last.as_ref(), expected.last());
+    assert_eq!(std::fs::metadata(&path).unwrap().len(), (HEADER_SIZE + 30 * RECORD_SIZE) as u64);
+    assert!(records::header(&path).unwrap().matches(&scenario));
+    // Le fichier se lit aussi comme un fichier de résultats binaire
+    assert_eq!(binary::read(std::fs::File::open(&path).unwrap()).unwrap().1.len(), 30);
+
+    // Interruption au milieu du onzième enregistrement
+    let file = std::fs::OpenOptions::new().write(true).open(&path).unwrap();
+    file.set_len((HEADER_SIZE + 10 * RECORD_SIZE + 17) as u64).unwrap();
+    assert_eq!(records::read(&path).unwrap(), expected[..10]);
+    let last = records::read_last(&path).unwrap().unwrap();
+    assert_eq!(last.hour(), 9);
+
+    let other = Scenario { latitude: 50.0, ..scenario.clone() };
+    assert!(matches!(RecordWriter::append(&path, &other), Err(MeteoError::InvalidRun(_))));
+    let mut writer = RecordWriter::append(&path, &scenario).unwrap();
+    assert_eq!(writer.len(), 10);
+    let mut cyclogenesis = BaroclinicCyclogenesis::from_state(&scenario, &[last]).unwrap();
+    cyclogenesis.simulate_to(20, &mut writer).unwrap();
+    let resumed = records::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(resumed, expected);
+}
+
+#[test]
+fn empty_and_missing_files() {
+    let path = temporary("records-empty");
+    let writer = RecordWriter::create(&path, &Scenario::default()).unwrap();
+    assert!(writer.is_empty());
+    assert_eq!(records::read_last(&path).unwrap(), None);
+    assert!(records::read(&path).unwrap().is_empty());
+    std::fs::remove_file(&path).unwrap();
+    assert!(matches!(records::read(&path), Err(MeteoError::InvalidRun(_))));
+}
+
+#[cfg(feature = "mmap")]
+#[test]
+fn mapped_records_match_the_appended_ones() {
+    use cyclogenese_rust::io::records::MAPPED_RECORDS;
+
+    // Plusieurs projections successives, dont une à cheval sur l'alignement de 64 Kio
+    let scenario = Scenario::default();
+    let run = scenario.run().unwrap();
+    let expected = run.iter().cycle().take(2 * MAPPED_RECORDS as usize + 100).cloned().collect::<Vec<_>>();
+    let path = temporary("records-mapped");
+    let mut writer = RecordWriter::create(&path, &scenario).unwrap().mapped();
+    for result in &expected {
+        writer.write(result).unwrap();
+    }
+    assert_eq!(writer.len(), expected.len() as u64);
+    // Les enregistrements préalloués restent jusqu'à la fermeture de l'écrivain
+    assert!(std::fs::metadata(&path).unwrap().len() > (HEADER_SIZE + expected.len() * RECORD_SIZE) as u64);
+    assert_eq!(records::read(&path).unwrap(), expected);
+    drop(writer);
+    assert_eq!(std::fs::metadata(&path).unwrap().len(), (HEADER_SIZE + expected.len() * RECORD_SIZE) as u64);
+    assert_eq!(records::read(&path).unwrap(), expected);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[cfg(feature = "mmap")]
+#[test]
+fn interrupted_mapped_records_are_resumed() {
+    let scenario = Scenario { time_steps: 30, ..Scenario::default() };
+    let expected = scenario.run().unwrap();
+    let path = temporary("records-mapped-resume");
+    let mut writer = RecordWriter::create(&path, &scenario).unwrap().mapped();
+    scenario.to_cyclogenesis().unwrap().simulate_to(10, &mut writer).unwrap();
+    // Interruption : l'écrivain ne ramène pas le fichier à ses enregistrements écrits
+    std::mem::forget(writer);
+    assert_eq!(records::read(&path).unwrap(), expected[..10]);
+    assert_eq!(binary::read(std::fs::File::open(&path).unwrap()).unwrap().1, expected[..10]);
+    let last = records::read_last(&path).unwrap().unwrap();
+    assert_eq!(last.hour(), 9);
+
+    let mut writer = RecordWriter::append(&path, &scenario).unwrap().mapped();
+    assert_eq!(writer.len(), 10);
+    BaroclinicCyclogenesis::from_state(&scenario, &[last]).unwrap().simulate_to(20, &mut writer).unwrap();
+    drop(writer);
+    let resumed = records::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(resumed, expected);
+}
@@ -0,0 +1,57 @@
+//! Nombres sans dimension du régime dynamique
+
+use std::process::Command;
+
+use cyclogenese_rust::regime::DynamicalRegime;
+use cyclogenese_rust::{BaroclinicCyclogenesis, NumberFormat, OutputLanguage};
+
+fn regime(surface: f64, altitude: f64, latitude: f64) -> Option<DynamicalRegime> {
+    BaroclinicCyclogenesis::new(surface, altitude, latitude).unwrap().dynamical_regime()
+}
+
+#[test]
+fn the_reference_case_is_quasi_geostrophic() {
+    let regime = regime(5.0, -8.0, 45.0).unwrap();
+    assert!(regime.is_quasi_geostrophic());
+    assert!(regime.rossby > 0.0 && regime.burger > 0.0);
+    // Temps de doublement de l'ordre de la journée
+    assert!((3600.0..5.0 * 86400.0).contains(&regime.eady_timescale), "{}", regime.eady_timescale);
+}
+
+#[test]
+fn numbers_follow_the_shear_and_the_latitude() {
+    let (weak, strong) = (regime(1.0, -1.0, 45.0).unwrap(), regime(5.0, -8.0, 45.0).unwrap());
+    // Ro ∝ ΔU et σ ∝ ΔU ; Bu ne dépend pas du contraste thermique
+    let ratio = strong.rossby / weak.rossby;
+    assert!(ratio > 1.0);
+    assert!((weak.eady_timescale / strong.eady_timescale - ratio).abs() < 1e-9);
+    assert!((strong.burger - weak.burger).abs() < 1e-12);
+
+    // Vers le pôle, f croît : L_R et le nombre de Burger diminuent
+    let (south, north) = (regime(5.0, -8.0, 30.0).unwrap(), regime(5.0, -8.0, 60.0).unwrap());
+    assert!(south.burger > strong.burger && strong.burger > north.burger);
+    assert_eq!(regime(5.0, -8.0, 0.0), None);
+}
+
+#[test]
+fn the_run_header_describes_the_regime() {
+    let format = NumberFormat::default();
+    let regime = regime(5.0, -8.0, 45.0).unwrap();
+    let english = regime.describe(OutputLanguage::English, &format);
+    let expected = format!(
+        "Ro = {}, Bu = {}, Eady timescale = {} h (quasi-geostrophic regime)",
+        format.format(regime.rossby),
+        format.format(regime.burger),
+        format.format(regime.eady_timescale / 3600.0)
+    );
+    assert_eq!(english, expected);
+
+    let still = DynamicalRegime { rossby: 2.0, burger: 1.0, eady_timescale: f64::INFINITY };
+    assert!(!still.is_quasi_geostrophic());
+    assert_eq!(still.describe(OutputLanguage::French, &format), "Ro = 2.00, Bu = 1.00, temps d'Eady = ∞ (régime agéostrophique)");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_cyclogenese")).output().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.lines().filter(|line| line.starts_with("Ro = ")).count(), 3);
+    assert!(stdout.contains(&regime.describe(OutputLanguage::French, &format)));
+}
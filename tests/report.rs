@@ -0,0 +1,42 @@
+//! Rapports Markdown et HTML d'une simulation
+
+use cyclogenese_rust::report::{Report, ReportFormat};
+use cyclogenese_rust::{NumberFormat, OutputLanguage, Scenario, TableFormatter};
+
+#[test]
+fn markdown_report_embeds_the_scenario_and_results() {
+    let scenario = Scenario::default();
+    let format = NumberFormat::default();
+    let report = Report::compute(&scenario).unwrap();
+    let text = report.render(ReportFormat::Markdown, OutputLanguage::French, &format);
+
+    // Le scénario du rapport se relit tel quel
+    let start = text.find("```toml\n").unwrap() + "```toml\n".len();
+    let end = start + text[start..].find("```").unwrap();
+    assert_eq!(Scenario::from_toml(&text[start..end]).unwrap(), scenario);
+
+    assert!(text.contains("- Scénario valide\n"));
+    assert!(text.contains("à l'heure 23"));
+    assert!(text.contains(&TableFormatter::default().format(&report.series().to_results())));
+}
+
+#[test]
+fn html_report_is_a_standalone_page() {
+    let report = Report::compute(&Scenario::default()).unwrap();
+    let page = report.to_html(OutputLanguage::English, &NumberFormat::default());
+
+    assert!(page.starts_with("<!DOCTYPE html>\n<html lang=\"en\">"));
+    assert!(page.ends_with("</body>\n</html>\n"));
+    assert_eq!(page.matches("<tr>").count(), report.series().len() + 1);
+    assert!(page.contains("&gt; β·L_R²"));
+    assert_eq!(page.contains("<svg"), cfg!(feature = "plot"));
+}
+
+#[test]
+fn report_without_time_steps() {
+    let scenario = Scenario { time_steps: 0, ..Scenario::default() };
+    let text = Report::compute(&scenario)
+        .unwrap()
+        .to_markdown(OutputLanguage::English, &NumberFormat::default());
+    assert!(text.contains("- No time step simulated\n"));
+}
@@ -0,0 +1,55 @@
+//! Reprise d'une simulation à partir d'un calcul précédent
+
+use cyclogenese_rust::{BaroclinicCyclogenesis, Geometry, MeteoError, Scenario};
+
+fn scenarios() -> Vec<Scenario> {
+    vec![
+        Scenario::default(),
+        Scenario { geometry: Some(Geometry::BetaPlane), ..Scenario::default() },
+        Scenario::from_toml(
+            "geometry = \"spherical\"\nstart_time = 1999-12-25T12:00:00Z\nlongitude = -4.5\nlatitude = 48.5\n",
+        )
+        .unwrap(),
+    ]
+}
+
+#[test]
+fn restart_continues_the_uninterrupted_run() {
+    for scenario in scenarios() {
+        let full = Scenario { time_steps: 48, ..scenario.clone() }.run().unwrap();
+        let mut restarted = BaroclinicCyclogenesis::from_state(&scenario, &full[..24]).unwrap();
+        assert_eq!(restarted.first_hour(), 24);
+        assert_eq!(restarted.simulate_interaction(24).unwrap(), full[24..], "{:?}", scenario.geometry);
+    }
+}
+
+#[test]
+fn branch_with_changed_parameters_diverges_after_the_restart() {
+    let scenario = Scenario { time_steps: 48, ..Scenario::default() };
+    let trunk = scenario.run().unwrap();
+    let branch = Scenario { meridional_gradient: Some(15.0), ..scenario };
+    let results = BaroclinicCyclogenesis::from_state(&branch, &trunk[..24]).unwrap().simulate_interaction(24).unwrap();
+
+    let full = branch.run().unwrap();
+    assert_eq!(results, full[24..]);
+    assert_ne!(results, trunk[24..]);
+}
+
+#[test]
+fn empty_tail_is_rejected() {
+    assert!(matches!(BaroclinicCyclogenesis::from_state(&Scenario::default(), &[]), Err(MeteoError::InvalidRun(_))));
+}
+
+#[test]
+fn hours_past_the_last_representable_hour_are_rejected() {
+    let at_hour = |hour: u32| {
+        let mut value = serde_json::to_value(&Scenario::default().run().unwrap()[0]).unwrap();
+        value["hour"] = hour.into();
+        serde_json::from_value(value).unwrap()
+    };
+    let mut late = BaroclinicCyclogenesis::from_state(&Scenario::default(), &[at_hour(u32::MAX - 2)]).unwrap();
+    assert_eq!(late.hours(1).unwrap(), u32::MAX - 1..u32::MAX);
+    assert!(matches!(late.simulate_interaction(2), Err(MeteoError::InvalidScenario(_))));
+    assert!(matches!(late.simulate_columns(2), Err(MeteoError::InvalidScenario(_))));
+    assert!(matches!(BaroclinicCyclogenesis::from_state(&Scenario::default(), &[at_hour(u32::MAX)]), Err(MeteoError::InvalidScenario(_))));
+}
@@ -0,0 +1,56 @@
+//! Nombre de Richardson et cisaillement entre les niveaux des anomalies
+
+use std::process::Command;
+
+use cyclogenese_rust::diagnostics::{self, StepDiagnostics, CRITICAL_RICHARDSON};
+use cyclogenese_rust::{BaroclinicCyclogenesis, NumberFormat, OutputLanguage};
+
+fn diagnose(surface: f64, altitude: f64, latitude: f64) -> Vec<StepDiagnostics> {
+    let mut cyclogenesis = BaroclinicCyclogenesis::new(surface, altitude, latitude).unwrap();
+    cyclogenesis.simulate_with_diagnostics(24).unwrap().1
+}
+
+#[test]
+fn richardson_number_falls_as_the_shear_grows() {
+    let steps = diagnose(5.0, -8.0, 45.0);
+    let cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap();
+    assert_eq!(steps[0].shear, cyclogenesis.thermal_wind_shear());
+
+    for pair in steps.windows(2) {
+        assert!(pair[1].shear.unwrap() > pair[0].shear.unwrap());
+        assert!(pair[1].richardson.unwrap() < pair[0].richardson.unwrap());
+    }
+    // Ri = N²·H²/ΔU², la stabilité de la couche ne change pas
+    let stability = |step: &StepDiagnostics| step.richardson.unwrap() * step.shear.unwrap().powi(2);
+    assert!((stability(&steps[23]) / stability(&steps[0]) - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn steps_below_the_critical_value_are_flagged() {
+    let steps = diagnose(5.0, -8.0, 45.0);
+    let first = steps.iter().position(StepDiagnostics::is_shear_unstable).unwrap();
+    assert!(first > 0 && steps[first - 1].richardson.unwrap() >= CRITICAL_RICHARDSON);
+    assert!(steps[first..].iter().all(StepDiagnostics::is_shear_unstable));
+
+    // Faible contraste : pas d'instabilité de cisaillement ; équateur : pas de vent thermique
+    assert!(!diagnose(1.0, -1.0, 45.0).iter().any(StepDiagnostics::is_shear_unstable));
+    let equator = diagnose(5.0, -8.0, 0.0);
+    assert!(equator.iter().all(|step| step.shear.is_none() && step.richardson.is_none() && !step.is_shear_unstable()));
+
+    let table = diagnostics::to_table(&steps, OutputLanguage::English, &NumberFormat::default());
+    let rows = table.lines().skip(2).take(24).collect::<Vec<_>>();
+    assert!(rows[..first].iter().all(|row| !row.contains("Ri < 0.25")));
+    assert!(rows[first..].iter().all(|row| row.contains("Ri < 0.25")), "{}", table);
+    let french = diagnostics::to_table(&equator, OutputLanguage::French, &NumberFormat::default());
+    assert!(french.starts_with("Heure | Cisaillement (m/s)") && !french.contains("Ri < 0,25"));
+    assert!(french.lines().nth(2).unwrap().contains("—"));
+}
+
+#[test]
+fn the_diagnostics_option_adds_the_table() {
+    let output = Command::new(env!("CARGO_BIN_EXE_cyclogenese")).arg("--diagnostics").output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.matches("| Richardson ").count(), 3);
+    assert!(stdout.contains("Heure | Cisaillement (m/s)") && stdout.contains("Ri < 0,25"));
+}
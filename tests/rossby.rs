@@ -0,0 +1,57 @@
+//! Train d'ondes de Rossby d'altitude
+
+use cyclogenese_rust::{BaroclinicCyclogenesis, MeteoError, ResultSeries, RossbyWaveTrain, Scenario};
+
+fn wave() -> RossbyWaveTrain {
+    // Talweg à 360 km en amont, à 10 m/s : au-dessus de l'anomalie à 10 h
+    RossbyWaveTrain::new(3.0, 4.0e6, 10.0).unwrap().with_trough_distance(3.6e5)
+}
+
+#[test]
+fn troughs_and_ridges_pass_at_the_phase_speed() {
+    let ridge = RossbyWaveTrain::new(3.0, 4.0e6, 10.0).unwrap();
+    assert_eq!(ridge.trough_distance(), 2.0e6);
+    assert!((ridge.temperature_perturbation(0) - 3.0).abs() < 1e-12);
+
+    let wave = wave();
+    assert!((wave.temperature_perturbation(10) + 3.0).abs() < 1e-12);
+    assert!(wave.temperature_perturbation(5) > wave.temperature_perturbation(10));
+    // Une longueur d'onde plus loin, le talweg suivant
+    let period = (4.0e6 / 10.0 / 3600.0) as u32;
+    assert!((wave.temperature_perturbation(10 + period) - wave.temperature_perturbation(10)).abs() < 0.1);
+}
+
+#[test]
+fn invalid_waves_are_refused() {
+    assert!(matches!(RossbyWaveTrain::new(-1.0, 4.0e6, 10.0), Err(MeteoError::InvalidTemperature(_))));
+    assert!(matches!(RossbyWaveTrain::new(3.0, 0.0, 10.0), Err(MeteoError::InvalidScale(_))));
+    assert!(matches!(RossbyWaveTrain::new(3.0, 4.0e6, f64::NAN), Err(MeteoError::InvalidScenario(_))));
+}
+
+#[test]
+fn an_approaching_trough_strengthens_the_development() {
+    let run = |wave: Option<RossbyWaveTrain>| {
+        let scenario = Scenario { rossby_wave: wave, time_steps: 24, ..Scenario::default() };
+        ResultSeries::from(scenario.run().unwrap())
+    };
+    let calm = run(None);
+    assert_eq!(run(Some(RossbyWaveTrain::new(0.0, 4.0e6, 10.0).unwrap())), calm);
+    let waved = run(Some(wave()));
+    assert_ne!(waved, calm);
+    let peak = |series: &ResultSeries<f64>| series.peak().unwrap().relative_vorticity().abs();
+    assert!(peak(&waved) > peak(&calm), "{} > {}", peak(&waved), peak(&calm));
+    // Une dorsale au-dessus de l'anomalie freine le développement
+    let ridge = run(Some(RossbyWaveTrain::new(3.0, 4.0e6, 10.0).unwrap()));
+    assert!(peak(&ridge) < peak(&calm), "{} < {}", peak(&ridge), peak(&calm));
+
+    let mut direct = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap().with_rossby_wave(wave());
+    let scenario = Scenario { rossby_wave: Some(wave()), time_steps: 24, ..Scenario::default() };
+    assert_eq!(direct.simulate_interaction(24).unwrap(), scenario.run().unwrap());
+}
+
+#[test]
+fn scenarios_read_the_wave_train() {
+    let scenario: Scenario =
+        toml::from_str("[rossby_wave]\namplitude = 3.0\nwavelength = 4.0e6\nphase_speed = 10.0\ntrough_distance = 3.6e5\n").unwrap();
+    assert_eq!(scenario.rossby_wave, Some(wave()));
+}
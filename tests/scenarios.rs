@@ -0,0 +1,20 @@
+//! Préréglages de tempêtes historiques
+
+use cyclogenese_rust::scenarios::{self, PRESETS};
+use cyclogenese_rust::{MeteoError, Scenario};
+
+#[test]
+fn every_preset_runs_over_its_duration() {
+    for preset in PRESETS {
+        let scenario = preset.scenario();
+        let results = scenario.run().unwrap_or_else(|error| panic!("{} : {}", preset.name, error));
+        assert_eq!(results.len(), scenario.time_steps as usize, "{}", preset.name);
+        assert_eq!(results[0].valid_time(), Some(preset.start_time()), "{}", preset.name);
+    }
+}
+
+#[test]
+fn presets_are_found_by_name() {
+    assert_eq!(Scenario::preset("Lothar-1999").unwrap(), scenarios::find("lothar-1999").unwrap().scenario());
+    assert!(matches!(Scenario::preset("lothar"), Err(MeteoError::InvalidScenario(_))));
+}
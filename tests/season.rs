@@ -0,0 +1,54 @@
+//! États de fond saisonniers
+
+use cyclogenese_rust::{BackgroundState, BaroclinicCyclogenesis, MeteoError, ResultSeries, Scenario, Season};
+
+fn peak(season: Option<Season>) -> f64 {
+    let results = Scenario { season, ..Scenario::default() }.run().unwrap();
+    ResultSeries::from(results).max_vorticity().unwrap().abs()
+}
+
+#[test]
+fn equinox_is_the_reference_state() {
+    assert_eq!(Season::Equinox.background(), BackgroundState::default());
+    let reference = Scenario::default().run().unwrap();
+    assert_eq!(Scenario { season: Some(Season::Equinox), ..Scenario::default() }.run().unwrap(), reference);
+}
+
+#[test]
+fn winter_deepens_faster_than_summer() {
+    let (winter, equinox, summer) = (peak(Some(Season::Winter)), peak(Some(Season::Equinox)), peak(Some(Season::Summer)));
+    assert!(winter > equinox && equinox > summer, "{} > {} > {}", winter, equinox, summer);
+}
+
+#[test]
+fn season_sets_the_background_state() {
+    let cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap().with_season(Season::Winter).unwrap();
+    assert_eq!(cyclogenesis.background(), Season::Winter.background());
+    assert_eq!(cyclogenesis.meridional_gradient(), 16.0);
+}
+
+#[test]
+fn explicit_gradient_overrides_the_season() {
+    let scenario = Scenario { season: Some(Season::Summer), meridional_gradient: Some(12.0), ..Scenario::default() };
+    let background = scenario.to_cyclogenesis().unwrap().background();
+    assert_eq!(background.meridional_gradient, 12.0);
+    assert_eq!(background.tropopause_height, Season::Summer.background().tropopause_height);
+}
+
+#[test]
+fn season_is_read_from_toml() {
+    let scenario = Scenario::from_toml("season = \"summer\"").unwrap();
+    assert_eq!(scenario.season, Some(Season::Summer));
+    assert!(matches!(Scenario::from_toml("season = \"monsoon\""), Err(MeteoError::InvalidScenario(_))));
+}
+
+#[test]
+fn rejects_an_invalid_background() {
+    let cyclogenesis = || BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap();
+    let cold = BackgroundState { base_temp: 20.0, ..BackgroundState::default() };
+    assert!(matches!(cyclogenesis().with_background(cold), Err(MeteoError::InvalidScenario(_))));
+    let low = BackgroundState { tropopause_height: 1000.0, ..BackgroundState::default() };
+    assert!(matches!(cyclogenesis().with_background(low), Err(MeteoError::InvalidScenario(_))));
+    let steep = BackgroundState { meridional_gradient: 80.0, ..BackgroundState::default() };
+    assert!(matches!(cyclogenesis().with_background(steep), Err(MeteoError::InvalidGradient(_))));
+}
@@ -0,0 +1,71 @@
+//! Coupes verticales passant par le centre de la dépression
+
+use cyclogenese_rust::section::SectionLine;
+use cyclogenese_rust::{BaroclinicCyclogenesis, MeteoError};
+
+#[test]
+fn section_levels_run_from_the_surface_upward() {
+    let mut cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap();
+    let results = cyclogenesis.simulate_interaction(24).unwrap();
+    let line = SectionLine::new(1000.0, 100.0).unwrap();
+    let sections = cyclogenesis.cross_sections(&results, &line);
+
+    assert_eq!(sections.len(), results.len());
+    let section = &sections[12];
+    assert_eq!(section.hour, 12);
+    assert_eq!(section.pressures(), &[1013.0, 500.0]);
+    assert_eq!(section.distances().len(), 21);
+    assert_eq!(section.temperature_anomaly().len(), 2 * 21);
+
+    // Au sol, la vitesse verticale est nulle ; à 500 hPa, maximale au centre de l'anomalie
+    let (surface_temperature, surface_w, _) = section.level(0).unwrap();
+    let (_, upper_w, upper_vorticity) = section.level(1).unwrap();
+    assert!(surface_w.iter().all(|w| *w == 0.0));
+    assert_eq!(surface_temperature[10], 5.0 * (1.0 + 12.0 / 12.0));
+    assert!(upper_w.iter().all(|w| w.abs() <= results[12].vertical_velocity().abs()));
+    assert!(upper_vorticity.iter().all(|zeta| zeta.abs() <= results[12].relative_vorticity().abs()));
+    assert!(section.level(2).is_none());
+}
+
+#[test]
+fn upper_anomaly_is_shifted_westward_by_the_tilt() {
+    let mut cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap();
+    let results = cyclogenesis.simulate_interaction(1).unwrap();
+    let section = cyclogenesis.cross_section(&results[0], &SectionLine::new(2000.0, 10.0).unwrap());
+
+    let peak = |values: &[f64]| {
+        let index = (0..values.len()).max_by(|a, b| values[*a].abs().total_cmp(&values[*b].abs())).unwrap();
+        section.distances()[index]
+    };
+    let (surface, _, _) = section.level(0).unwrap();
+    let (upper, _, _) = section.level(1).unwrap();
+    assert_eq!(peak(surface), 0.0);
+    assert!((peak(upper) + cyclogenesis.tilt() / 1000.0).abs() <= 5.0);
+}
+
+#[test]
+fn invalid_lines_are_rejected() {
+    for (half_length, spacing) in [(0.0, 10.0), (6000.0, 10.0), (1000.0, 0.0), (100.0, 200.0)] {
+        assert!(matches!(SectionLine::new(half_length, spacing), Err(MeteoError::InvalidGrid(_))));
+    }
+}
+
+#[cfg(feature = "netcdf")]
+#[test]
+fn cross_sections_round_trip_through_netcdf() {
+    use netcdf3::FileReader;
+
+    let mut cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap();
+    let results = cyclogenesis.simulate_interaction(3).unwrap();
+    let sections = cyclogenesis.cross_sections(&results, &SectionLine::new(500.0, 100.0).unwrap());
+    let path = std::env::temp_dir().join(format!("cyclogenese-section-{}.nc", std::process::id()));
+    cyclogenese_rust::io::netcdf::write_cross_sections(&path, &sections).unwrap();
+
+    let mut reader = FileReader::open(&path).unwrap();
+    let levels = reader.read_var_f64("level").unwrap();
+    let vorticity = reader.read_var_f64("relative_vorticity").unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(levels, sections[0].pressures());
+    let expected: Vec<_> = sections.iter().flat_map(|section| section.relative_vorticity().to_vec()).collect();
+    assert_eq!(vorticity, expected);
+}
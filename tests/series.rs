@@ -0,0 +1,40 @@
+//! Accesseurs des résultats et statistiques d'une série
+
+use cyclogenese_rust::{ResultSeries, Scenario};
+
+#[test]
+fn getters_match_the_serialized_results() {
+    let results = Scenario::default().run().unwrap();
+    let values = serde_json::to_value(&results).unwrap();
+    for (result, value) in results.iter().zip(values.as_array().unwrap()) {
+        assert_eq!(u64::from(result.hour()), value["hour"].as_u64().unwrap());
+        assert_eq!(result.vertical_velocity(), value["vertical_velocity"].as_f64().unwrap());
+        assert_eq!(result.relative_vorticity(), value["relative_vorticity"].as_f64().unwrap());
+    }
+}
+
+#[test]
+fn statistics_of_the_reference_case() {
+    let results = Scenario::default().run().unwrap();
+    let series = ResultSeries::from(results.clone());
+
+    // Le tourbillon croît en valeur absolue jusqu'à la dernière heure
+    let last = results.last().unwrap();
+    assert_eq!(series.time_of_peak(), Some(23));
+    assert_eq!(series.max_vorticity(), Some(last.relative_vorticity()));
+
+    let mean = results.iter().map(|result| result.vertical_velocity()).sum::<f64>() / 24.0;
+    assert!((series.mean_w().unwrap() - mean).abs() <= 1e-15 * mean.abs());
+
+    let growth = (last.relative_vorticity().abs() - results[0].relative_vorticity().abs()) / (23.0 * 3600.0);
+    assert!((series.deepening_rate().unwrap() - growth).abs() <= 1e-12 * growth);
+}
+
+#[test]
+fn empty_series_has_no_statistics() {
+    let series = ResultSeries::<f64>::new(Vec::new());
+    assert_eq!(series.max_vorticity(), None);
+    assert_eq!(series.time_of_peak(), None);
+    assert_eq!(series.mean_w(), None);
+    assert_eq!(series.deepening_rate(), None);
+}
@@ -0,0 +1,192 @@
+//! Diffusion des simulations par WebSocket
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use cyclogenese_rust::metrics::Metrics;
+use cyclogenese_rust::server::{self, ServerLimits};
+use cyclogenese_rust::{OutputLanguage, Scenario};
+use serde_json::Value;
+
+fn start() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    thread::spawn(move || server::serve(listener, OutputLanguage::French, Arc::new(Metrics::default())));
+    address
+}
+
+/// En-tête de la réponse HTTP à une requête
+fn http(address: &str, request: &str) -> (BufReader<TcpStream>, Vec<String>) {
+    let mut stream = TcpStream::connect(address).unwrap();
+    stream.write_all(request.as_bytes()).unwrap();
+    let mut reader = BufReader::new(stream);
+    let mut lines = Vec::new();
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        if line.trim_end().is_empty() {
+            return (reader, lines);
+        }
+        lines.push(line.trim_end().to_string());
+    }
+}
+
+/// Trame masquée, comme en envoie un navigateur
+fn send(stream: &mut TcpStream, opcode: u8, payload: &[u8]) {
+    let mask = [0x12, 0x34, 0x56, 0x78];
+    let mut frame = vec![0x80 | opcode];
+    if payload.len() < 126 {
+        frame.push(0x80 | payload.len() as u8);
+    } else {
+        frame.push(0x80 | 126);
+        frame.extend_from_slice(&(payload.len() as u16).to_be_bytes());
+    }
+    frame.extend_from_slice(&mask);
+    frame.extend(payload.iter().enumerate().map(|(index, byte)| byte ^ mask[index % 4]));
+    stream.write_all(&frame).unwrap();
+}
+
+fn receive<R: Read>(reader: &mut R) -> (u8, Vec<u8>) {
+    let mut header = [0; 2];
+    reader.read_exact(&mut header).unwrap();
+    assert_eq!(header[1] & 0x80, 0, "trame du serveur masquée");
+    let length = match header[1] {
+        126 => {
+            let mut bytes = [0; 2];
+            reader.read_exact(&mut bytes).unwrap();
+            usize::from(u16::from_be_bytes(bytes))
+        }
+        length => usize::from(length),
+    };
+    let mut payload = vec![0; length];
+    reader.read_exact(&mut payload).unwrap();
+    (header[0] & 0x0f, payload)
+}
+
+fn message<R: Read>(reader: &mut R) -> Value {
+    let (opcode, payload) = receive(reader);
+    assert_eq!(opcode, 1);
+    serde_json::from_slice(&payload).unwrap()
+}
+
+#[test]
+fn handshake_matches_rfc_6455_example() {
+    assert_eq!(server::accept_key("dGhlIHNhbXBsZSBub25jZQ=="), "s3pPLMBiTxaQ9kYGzzhZRbK+xOo=");
+}
+
+#[test]
+fn results_are_streamed_hour_by_hour() {
+    let address = start();
+    let (mut reader, lines) = http(
+        &address,
+        "GET /simulate HTTP/1.1\r\nHost: localhost\r\nUpgrade: websocket\r\nConnection: Upgrade\r\n\
+         Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\nSec-WebSocket-Version: 13\r\n\r\n",
+    );
+    assert_eq!(lines[0], "HTTP/1.1 101 Switching Protocols");
+    assert!(lines.contains(&"Sec-WebSocket-Accept: s3pPLMBiTxaQ9kYGzzhZRbK+xOo=".to_string()));
+    let mut stream = reader.get_ref().try_clone().unwrap();
+
+    let scenario = Scenario { time_steps: 12, ..Scenario::default() };
+    send(&mut stream, 1, serde_json::to_string(&scenario).unwrap().as_bytes());
+    for hour in 0..12 {
+        assert_eq!(message(&mut reader)["result"]["hour"], hour);
+    }
+    assert_eq!(message(&mut reader)["done"], 12);
+
+    send(&mut stream, 1, b"{\"latitude\": 95.0}");
+    assert!(message(&mut reader)["error"].as_str().unwrap().contains("Latitude"));
+    let endless = Scenario { time_steps: server::MAX_TIME_STEPS + 1, ..Scenario::default() };
+    send(&mut stream, 1, serde_json::to_string(&endless).unwrap().as_bytes());
+    assert!(message(&mut reader)["error"].as_str().unwrap().contains("au plus 8760"));
+
+    let (mut metrics, lines) = http(&address, "GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n");
+    assert_eq!(lines[0], "HTTP/1.1 200 OK");
+    let mut body = String::new();
+    metrics.read_to_string(&mut body).unwrap();
+    assert!(body.contains("cyclogenese_simulations_total 1\n"));
+    assert!(body.contains("cyclogenese_simulation_errors_total 2\n"));
+    assert!(body.contains("cyclogenese_steps_total 12\n"));
+
+    send(&mut stream, 9, b"ping");
+    assert_eq!(receive(&mut reader), (0xa, b"ping".to_vec()));
+    send(&mut stream, 8, &1000u16.to_be_bytes());
+    assert_eq!(receive(&mut reader), (0x8, 1000u16.to_be_bytes().to_vec()));
+}
+
+#[test]
+fn plain_requests_are_refused() {
+    let address = start();
+    let (_, lines) = http(&address, "GET /simulate HTTP/1.1\r\nHost: localhost\r\n\r\n");
+    assert_eq!(lines[0], "HTTP/1.1 426 Upgrade Required");
+    let (_, lines) = http(&address, "GET /ailleurs HTTP/1.1\r\nHost: localhost\r\n\r\n");
+    assert_eq!(lines[0], "HTTP/1.1 404 Not Found");
+}
+
+#[test]
+fn connections_beyond_the_bound_are_refused() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    let limits = ServerLimits { max_connections: 1, ..ServerLimits::default() };
+    thread::spawn(move || server::serve_bounded(listener, OutputLanguage::French, Arc::new(Metrics::default()), limits));
+
+    let upgrade = "GET /simulate HTTP/1.1\r\nHost: localhost\r\nUpgrade: websocket\r\nConnection: Upgrade\r\n\
+                   Sec-WebSocket-Key: dGhlIHNhbXBsZSBub25jZQ==\r\nSec-WebSocket-Version: 13\r\n\r\n";
+    let (mut open, lines) = http(&address, upgrade);
+    assert_eq!(lines[0], "HTTP/1.1 101 Switching Protocols");
+    let (_, lines) = http(&address, "GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n");
+    assert_eq!(lines[0], "HTTP/1.1 503 Service Unavailable");
+
+    // La place se libère à la fermeture de la première connexion
+    let mut stream = open.get_ref().try_clone().unwrap();
+    send(&mut stream, 8, &1000u16.to_be_bytes());
+    assert_eq!(receive(&mut open).0, 0x8);
+    drop((open, stream));
+    let lines = (0..50)
+        .map(|_| {
+            thread::sleep(Duration::from_millis(20));
+            http(&address, "GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n").1
+        })
+        .find(|lines| lines[0] == "HTTP/1.1 200 OK");
+    assert!(lines.is_some());
+}
+
+#[test]
+fn silent_connections_time_out_and_free_their_slot() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    let limits = ServerLimits { max_connections: 1, read_timeout: Duration::from_millis(200), ..ServerLimits::default() };
+    thread::spawn(move || server::serve_bounded(listener, OutputLanguage::French, Arc::new(Metrics::default()), limits));
+
+    let mut silent = TcpStream::connect(&address).unwrap();
+    let start = Instant::now();
+    assert_eq!(silent.read(&mut [0; 1]).unwrap_or(0), 0, "connexion muette fermée par le serveur");
+    assert!(start.elapsed() < Duration::from_secs(5));
+    let lines = (0..50)
+        .map(|_| {
+            thread::sleep(Duration::from_millis(20));
+            http(&address, "GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n").1
+        })
+        .find(|lines| lines[0] == "HTTP/1.1 200 OK");
+    assert!(lines.is_some());
+}
+
+#[test]
+fn refusals_do_not_hold_the_accept_loop() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+    let limits = ServerLimits { max_connections: 1, ..ServerLimits::default() };
+    thread::spawn(move || server::serve_bounded(listener, OutputLanguage::French, Arc::new(Metrics::default()), limits));
+
+    let _occupied = TcpStream::connect(&address).unwrap();
+    thread::sleep(Duration::from_millis(50));
+    // Chaque refus muet attend l'en-tête jusqu'à son délai ; servis l'un après
+    // l'autre, ils retarderaient la réponse suivante de plusieurs secondes
+    let _silent = (0..20).map(|_| TcpStream::connect(&address).unwrap()).collect::<Vec<_>>();
+    let start = Instant::now();
+    let (_, lines) = http(&address, "GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n");
+    assert_eq!(lines[0], "HTTP/1.1 503 Service Unavailable");
+    assert!(start.elapsed() < Duration::from_secs(2), "{:?}", start.elapsed());
+}
@@ -0,0 +1,97 @@
+//! Lecture des radiosondages Wyoming et IGRA v2
+
+use std::path::Path;
+
+use cyclogenese_rust::io::sounding::Sounding;
+use cyclogenese_rust::MeteoError;
+
+fn fixture(name: &str) -> String {
+    std::fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures").join(name)).unwrap()
+}
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!((actual - expected).abs() < 1e-9, "{} au lieu de {}", actual, expected);
+}
+
+fn assert_invalid(result: Result<Sounding, MeteoError>, message: &str) {
+    match result {
+        Err(MeteoError::InvalidSounding(text)) => assert!(text.contains(message), "{}", text),
+        other => panic!("erreur de sondage attendue, obtenu {:?}", other),
+    }
+}
+
+#[test]
+fn wyoming_lists_are_read_with_their_station() {
+    let sounding = Sounding::parse(&fixture("wyoming_72520.txt")).unwrap();
+    assert_eq!(sounding.station(), Some("PIT"));
+    assert_eq!(sounding.latitude(), Some(40.53));
+
+    // Le niveau 1000 hPa, sans température, est écarté
+    let pressures = sounding.levels().iter().map(|level| level.pressure).collect::<Vec<_>>();
+    assert_eq!(pressures, [978.0, 925.0, 850.0, 700.0, 500.0, 400.0, 300.0]);
+    assert_eq!(sounding.surface().height, 360.0);
+    assert_close(sounding.surface().temperature, -1.9 + 273.15);
+
+    let upper = sounding.interpolate(500.0).unwrap();
+    assert_eq!(upper.height, 5570.0);
+    assert_close(upper.temperature, -25.3 + 273.15);
+    assert!(sounding.to_cyclogenesis().is_ok());
+}
+
+#[test]
+fn igra_files_give_their_first_sounding() {
+    let sounding = Sounding::parse(&fixture("igra_USM00072520.txt")).unwrap();
+    assert_eq!(sounding.station(), Some("USM00072520"));
+    assert_close(sounding.latitude().unwrap(), 40.5331);
+
+    // Pa -> hPa, dixièmes de °C -> K ; le niveau 600 hPa sans température est écarté
+    let pressures = sounding.levels().iter().map(|level| level.pressure).collect::<Vec<_>>();
+    assert_eq!(pressures, [978.0, 925.0, 850.0, 700.0, 500.0, 300.0]);
+    assert_close(sounding.surface().temperature, -1.9 + 273.15);
+    assert_close(sounding.levels()[5].temperature, -49.7 + 273.15);
+    assert_eq!(sounding.levels()[5].height, 9160.0);
+    assert!(sounding.to_cyclogenesis().is_ok());
+}
+
+#[test]
+fn both_formats_give_the_same_profile() {
+    let wyoming = Sounding::parse(&fixture("wyoming_72520.txt")).unwrap();
+    let igra = Sounding::parse(&fixture("igra_USM00072520.txt")).unwrap();
+    let upper = |sounding: &Sounding| sounding.interpolate(500.0).unwrap();
+    assert_eq!(upper(&wyoming).height, upper(&igra).height);
+    assert_close(upper(&wyoming).temperature, upper(&igra).temperature);
+}
+
+#[test]
+fn incomplete_soundings_are_refused() {
+    assert_invalid(Sounding::parse("72520 PIT\n"), "en-tête PRES/HGHT/TEMP");
+    let wyoming = fixture("wyoming_72520.txt");
+    let header_only = wyoming.lines().take(8).collect::<Vec<_>>().join("\n");
+    assert_invalid(Sounding::parse(&header_only), "aucun niveau complet");
+
+    let igra = fixture("igra_USM00072520.txt");
+    // Températures manquantes (colonnes 23 à 27) à tous les niveaux
+    let missing = igra
+        .lines()
+        .map(|line| match line.starts_with('#') {
+            true => line.to_string(),
+            false => format!("{}-9999{}", &line[..22], &line[27..]),
+        })
+        .collect::<Vec<_>>();
+    assert_invalid(Sounding::parse(&missing.join("\n")), "aucun niveau complet");
+}
+
+#[test]
+fn simulations_need_a_latitude_and_the_500_hpa_level() {
+    let wyoming = fixture("wyoming_72520.txt");
+    let unplaced = Sounding::parse(&wyoming.replace("Station latitude", "Station altitude")).unwrap();
+    assert_eq!(unplaced.latitude(), None);
+    assert!(matches!(unplaced.to_cyclogenesis(), Err(MeteoError::InvalidSounding(text)) if text.contains("latitude")));
+
+    let low = wyoming
+        .lines()
+        .filter(|line| !["  500.0", "  400.0", "  300.0"].iter().any(|level| line.starts_with(level)))
+        .collect::<Vec<_>>();
+    let sounding = Sounding::parse(&low.join("\n")).unwrap();
+    assert!(matches!(sounding.to_cyclogenesis(), Err(MeteoError::InvalidSounding(text)) if text.contains("500 hPa")));
+}
@@ -0,0 +1,53 @@
+//! Critère d'instabilité barocline de Charney–Stern
+
+use cyclogenese_rust::stability::{CharneySternCheck, EARTH_RADIUS};
+use cyclogenese_rust::{BaroclinicCyclogenesis, NumberFormat, OutputLanguage};
+
+/// Rayon de déformation de Rossby du modèle (m)
+const DEFORMATION_RADIUS: f64 = 1.0e6;
+
+fn check(surface: f64, altitude: f64, latitude: f64) -> Option<CharneySternCheck> {
+    BaroclinicCyclogenesis::new(surface, altitude, latitude).unwrap().charney_stern()
+}
+
+#[test]
+fn the_reference_case_can_amplify() {
+    let check = check(5.0, -8.0, 45.0).unwrap();
+    assert!(check.is_satisfied());
+    assert!(check.shear > check.critical_shear);
+    assert!(check.upper_pv_gradient > 0.0 && check.lower_pv_gradient < 0.0);
+
+    // β·L_R² à 45°N, et des gradients symétriques autour de β
+    let beta = 2.0 * 7.2921e-5 * 45.0_f64.to_radians().cos() / EARTH_RADIUS;
+    assert!((check.critical_shear - beta * DEFORMATION_RADIUS * DEFORMATION_RADIUS).abs() < 0.01);
+    let mean = (check.upper_pv_gradient + check.lower_pv_gradient) / 2.0;
+    assert!((mean * DEFORMATION_RADIUS * DEFORMATION_RADIUS - check.critical_shear).abs() < 1e-6);
+}
+
+#[test]
+fn weak_contrasts_cannot_amplify() {
+    let check = check(0.5, -0.5, 45.0).unwrap();
+    assert!(!check.is_satisfied());
+    assert!(check.shear < check.critical_shear);
+    assert!(check.upper_pv_gradient > 0.0 && check.lower_pv_gradient > 0.0);
+
+    // Le seuil baisse vers le pôle avec β
+    let critical = |latitude| self::check(5.0, -8.0, latitude).unwrap().critical_shear;
+    assert!(critical(30.0) > critical(45.0) && critical(45.0) > critical(60.0));
+    assert_eq!(self::check(5.0, -8.0, 0.0), None);
+}
+
+#[test]
+fn the_report_warns_when_the_setup_cannot_amplify() {
+    let format = NumberFormat::default();
+    let satisfied = check(5.0, -8.0, 45.0).unwrap();
+    let english = satisfied.describe(OutputLanguage::English, &format);
+    assert!(english.starts_with("Charney–Stern criterion satisfied: shear "), "{}", english);
+    assert!(english.contains(&format!("{} m/s", format.format_wind(satisfied.critical_shear))));
+
+    let unsatisfied = check(0.5, -0.5, 45.0).unwrap();
+    let french = unsatisfied.describe(OutputLanguage::French, &format);
+    assert!(french.starts_with("Attention : critère de Charney–Stern non satisfait"), "{}", french);
+    assert!(french.ends_with("la perturbation ne peut pas s'amplifier physiquement"));
+    assert!(unsatisfied.describe(OutputLanguage::English, &format).starts_with("Warning:"));
+}
@@ -0,0 +1,63 @@
+//! Indicateur de risque de sting jet
+
+use cyclogenese_rust::report::Report;
+use cyclogenese_rust::sting_jet::{self, RiskLevel, StingJetCriteria, StingJetRisk};
+use cyclogenese_rust::{scenarios, BaroclinicCyclogenesis, MeteoError, NumberFormat, OutputLanguage};
+
+fn risk(hour: u32, level: RiskLevel) -> StingJetRisk {
+    StingJetRisk { hour, deepening: 0.0, descent: 0.0, rossby_number: 0.0, level }
+}
+
+#[test]
+fn explosive_storm_raises_an_elevated_risk() {
+    let scenario = scenarios::find("lothar-1999").unwrap().scenario();
+    let mut cyclogenesis = scenario.to_cyclogenesis().unwrap();
+    let results = cyclogenesis.simulate_interaction(scenario.time_steps).unwrap();
+    let risks = cyclogenesis.sting_jet_risk(&results, &StingJetCriteria::default());
+
+    assert_eq!(risks.len(), results.len());
+    let elevated = risks.iter().find(|risk| risk.level == RiskLevel::Elevated).unwrap();
+    assert!(elevated.deepening >= 1.0 && elevated.descent >= 3.0e-3 && elevated.rossby_number >= 1.0);
+    assert!(!sting_jet::elevated_periods(&risks).is_empty());
+
+    // Le cas de référence se creuse trop lentement
+    let mut reference = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap();
+    let results = reference.simulate_interaction(24).unwrap();
+    let risks = reference.sting_jet_risk(&results, &StingJetCriteria::default());
+    assert!(risks.iter().all(|risk| risk.level == RiskLevel::Low));
+    let table = sting_jet::to_table(&risks, OutputLanguage::English, &NumberFormat::default());
+    assert!(table.ends_with("\nNo elevated sting-jet risk\n"));
+}
+
+#[test]
+fn elevated_hours_merge_into_periods() {
+    let risks = [
+        risk(3, RiskLevel::Elevated),
+        risk(4, RiskLevel::Elevated),
+        risk(5, RiskLevel::Moderate),
+        risk(6, RiskLevel::Elevated),
+        risk(7, RiskLevel::Low),
+    ];
+    assert_eq!(sting_jet::elevated_periods(&risks), [(3, 4), (6, 6)]);
+    assert_eq!(
+        sting_jet::describe_periods(&risks, OutputLanguage::French),
+        "Risque élevé de sting jet de l'heure 3 à l'heure 4, à l'heure 6"
+    );
+}
+
+#[test]
+fn thresholds_must_be_positive() {
+    assert!(StingJetCriteria::new(1.0, 2.0e-3, 1.5).is_ok());
+    for (deepening, descent, rossby) in [(0.0, 1.0e-3, 1.0), (1.0, -1.0, 1.0), (1.0, 1.0e-3, f64::NAN)] {
+        assert!(matches!(StingJetCriteria::new(deepening, descent, rossby), Err(MeteoError::InvalidScenario(_))));
+    }
+}
+
+#[test]
+fn report_flags_sting_jet_periods() {
+    let scenario = scenarios::find("lothar-1999").unwrap().scenario();
+    let report = Report::compute(&scenario).unwrap();
+    let text = report.to_markdown(OutputLanguage::English, &NumberFormat::default());
+    assert!(text.contains(&format!("- {}\n", sting_jet::describe_periods(report.sting_jet(), OutputLanguage::English))));
+    assert!(text.contains("- Elevated sting-jet risk "));
+}
@@ -0,0 +1,85 @@
+//! Plusieurs tempêtes sur une horloge commune
+
+use cyclogenese_rust::storms::{self, MultiStorm, MultiStormScenario, Storm};
+use cyclogenese_rust::{BaroclinicCyclogenesis, MeteoError, NumberFormat, OutputLanguage, ResultSeries, Scenario};
+
+fn family() -> MultiStorm {
+    MultiStorm::new()
+        .with_storm(Storm::new("A", BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap()))
+        .unwrap()
+        .with_storm(Storm::new("B", BaroclinicCyclogenesis::new(3.0, -6.0, 55.0).unwrap()))
+        .unwrap()
+}
+
+#[test]
+fn storms_evolve_independently_on_a_shared_clock() {
+    let mut family = family();
+    let results = family.simulate(12).unwrap();
+    assert_eq!(results.len(), 24);
+    assert_eq!(family.hour(), 12);
+    assert!(results.chunks(2).enumerate().all(|(hour, pair)| {
+        pair[0].storm == "A" && pair[1].storm == "B" && pair.iter().all(|result| result.result.hour() == hour as u32)
+    }));
+
+    let alone = BaroclinicCyclogenesis::new(3.0, -6.0, 55.0).unwrap().simulate_interaction(12).unwrap();
+    let merged = storms::by_storm(&results);
+    assert_eq!(merged.keys().copied().collect::<Vec<_>>(), ["A", "B"]);
+    assert_eq!(merged["B"], ResultSeries::new(alone));
+    assert_eq!(family.storm("B").unwrap().latitude(), 55.0);
+    assert!(family.storm("C").is_none());
+}
+
+#[test]
+fn storm_of_limited_duration_stops_early() {
+    let mut family = family()
+        .with_storm(Storm::new("C", BaroclinicCyclogenesis::new(4.0, -7.0, 50.0).unwrap()).with_time_steps(3))
+        .unwrap();
+    let results = family.simulate(6).unwrap();
+    assert_eq!(results.iter().filter(|result| result.storm == "C").count(), 3);
+    assert_eq!(results.len(), 15);
+}
+
+#[test]
+fn rejects_a_storm_off_the_clock() {
+    let storm = |id: &str| Storm::new(id, BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap());
+    assert!(matches!(family().with_storm(storm("A")), Err(MeteoError::InvalidScenario(_))));
+    assert!(matches!(family().with_storm(storm("")), Err(MeteoError::InvalidScenario(_))));
+
+    let results = Scenario::default().run().unwrap();
+    let resumed = BaroclinicCyclogenesis::from_state(&Scenario::default(), &results).unwrap();
+    assert!(matches!(family().with_storm(Storm::new("C", resumed)), Err(MeteoError::InvalidScenario(_))));
+    let dated = Scenario::from_toml("start_time = 1999-12-26T00:00:00Z").unwrap().to_cyclogenesis().unwrap();
+    assert!(matches!(family().with_storm(Storm::new("C", dated)), Err(MeteoError::InvalidScenario(_))));
+}
+
+#[test]
+fn merged_output_is_keyed_by_storm() {
+    let results = family().simulate(2).unwrap();
+    let table = storms::to_table(&results, OutputLanguage::English, &NumberFormat::default());
+    let lines: Vec<_> = table.lines().collect();
+    assert!(lines[0].starts_with("Storm | Hour"), "{}", table);
+    assert!(lines[2].starts_with("A     |    0 |") && lines[3].starts_with("B     |    0 |"), "{}", table);
+    assert_eq!(lines.len(), 6);
+
+    let json: serde_json::Value = serde_json::from_str(&storms::to_json(&results)).unwrap();
+    assert_eq!(json[1]["storm"], "B");
+    assert_eq!(json[1]["hour"], 0);
+    assert!(json[1]["relative_vorticity"].is_f64());
+}
+
+#[test]
+fn family_is_read_from_toml() {
+    let toml = "[[storms]]\nid = \"A\"\ntime_steps = 6\nstart_time = 1999-12-26T00:00:00Z\n\n\
+                [[storms]]\nid = \"B\"\nlatitude = 55.0\nstart_time = 1999-12-26T00:00:00Z\n";
+    let family = MultiStormScenario::from_toml(toml).unwrap();
+    assert_eq!(family.storms[1].scenario.latitude, 55.0);
+    let results = family.run().unwrap();
+    let merged = storms::by_storm(&results);
+    assert_eq!((merged["A"].len(), merged["B"].len()), (6, 24));
+    assert_eq!(merged["A"].to_results(), Scenario { time_steps: 6, ..family.storms[0].scenario.clone() }.run().unwrap());
+
+    let unknown = "[[storms]]\nid = \"A\"\nlatitud = 45.0\n";
+    assert!(matches!(MultiStormScenario::from_toml(unknown), Err(MeteoError::InvalidScenario(_))));
+    let repeated = "[[storms]]\nid = \"A\"\n\n[[storms]]\nid = \"A\"\n";
+    assert!(matches!(MultiStormScenario::from_toml(repeated).unwrap().run(), Err(MeteoError::InvalidScenario(_))));
+}
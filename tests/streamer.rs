@@ -0,0 +1,66 @@
+//! Filament de tourbillon potentiel descendant
+
+use cyclogenese_rust::streamer::{STREAMER_BOTTOM, STREAMER_TOP};
+use cyclogenese_rust::{BaroclinicCyclogenesis, MeteoError, PvStreamer, ResultSeries, Scenario};
+
+fn streamer() -> PvStreamer {
+    PvStreamer::new(4.0, 6, 12).unwrap()
+}
+
+#[test]
+fn streamer_descends_over_its_duration() {
+    let streamer = streamer();
+    assert_eq!((streamer.progress(0), streamer.progress(6)), (0.0, 0.0));
+    assert!((streamer.progress(12) - 0.5).abs() < 1e-12);
+    assert_eq!((streamer.progress(18), streamer.progress(30)), (1.0, 1.0));
+    assert_eq!(streamer.altitude(0), STREAMER_TOP);
+    assert_eq!(streamer.altitude(18), STREAMER_BOTTOM);
+    assert!((7..18).all(|hour| streamer.altitude(hour + 1) < streamer.altitude(hour)));
+}
+
+#[test]
+fn cooling_grows_as_the_streamer_reaches_the_anomaly() {
+    let streamer = streamer();
+    assert_eq!(streamer.temperature_perturbation(3, 5000.0, 10000.0), 0.0);
+    let early = streamer.temperature_perturbation(9, 5000.0, 10000.0);
+    let late = streamer.temperature_perturbation(18, 5000.0, 10000.0);
+    assert!(early < 0.0 && late < early, "{} < {}", late, early);
+    assert!((late + 4.0).abs() < 1e-12);
+    // Une tropopause haute laisse le filament agir plus tôt
+    assert!(streamer.altitude(8) > 10000.0);
+    assert!(streamer.temperature_perturbation(8, 5000.0, 15000.0) < streamer.temperature_perturbation(8, 5000.0, 10000.0));
+}
+
+#[test]
+fn streamer_triggers_rapid_deepening() {
+    let reference = ResultSeries::from(Scenario::default().run().unwrap());
+    let scenario = Scenario { pv_streamer: Some(streamer()), ..Scenario::default() };
+    let triggered = ResultSeries::from(scenario.run().unwrap());
+    assert_eq!(triggered.to_results()[..6], reference.to_results()[..6]);
+    assert!(triggered.max_vorticity().unwrap().abs() > reference.max_vorticity().unwrap().abs());
+    assert!(triggered.deepening_rate().unwrap().abs() > reference.deepening_rate().unwrap().abs());
+}
+
+#[test]
+fn rejects_an_invalid_streamer() {
+    assert!(matches!(PvStreamer::new(80.0, 6, 12), Err(MeteoError::InvalidTemperature(_))));
+    assert!(matches!(PvStreamer::new(4.0, 6, 0), Err(MeteoError::InvalidScenario(_))));
+    assert!(matches!(streamer().with_descent(5000.0, 8000.0), Err(MeteoError::InvalidScenario(_))));
+    assert!(matches!(streamer().with_descent(30000.0, 5000.0), Err(MeteoError::InvalidScenario(_))));
+    let toml = "[pv_streamer]\namplitude = 4.0\nonset = 6\nduration = 0";
+    assert!(matches!(Scenario::from_toml(toml).unwrap().to_cyclogenesis(), Err(MeteoError::InvalidScenario(_))));
+}
+
+#[test]
+fn streamer_is_read_from_toml() {
+    let toml = "[pv_streamer]\namplitude = 4.0\nonset = 6\nduration = 12\ntop = 11000.0\nbottom = 6000.0";
+    let scenario = Scenario::from_toml(toml).unwrap();
+    let expected = streamer().with_descent(11000.0, 6000.0).unwrap();
+    assert_eq!(scenario.pv_streamer, Some(expected));
+    let defaults = Scenario::from_toml("[pv_streamer]\namplitude = 4.0\nonset = 6\nduration = 12").unwrap();
+    assert_eq!(defaults.pv_streamer, Some(streamer()));
+
+    let mut cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap().with_pv_streamer(expected);
+    let chain = cyclogenesis.explain(18).unwrap().anomalies[1].chain;
+    assert!(chain.forcing < 0.0);
+}
@@ -0,0 +1,77 @@
+//! Résumé du développement : maturité, pic et temps de croissance
+
+use cyclogenese_rust::report::Report;
+use cyclogenese_rust::summary::DevelopmentSummary;
+use cyclogenese_rust::{scenarios, BaroclinicCyclogenesis, NumberFormat, OutputLanguage};
+
+#[test]
+fn summary_reports_maturity_peak_and_growth() {
+    let mut cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap();
+    let (results, diagnostics) = cyclogenesis.simulate_with_diagnostics(24).unwrap();
+    let summary = DevelopmentSummary::compute(&results, &diagnostics).unwrap();
+
+    // Le tourbillon croît jusqu'à la dernière heure : pas de maturité
+    assert_eq!(summary.maturity_hour, None);
+    assert_eq!(summary.peak_hour, 23);
+    assert_eq!(summary.peak_vorticity, results[23].relative_vorticity());
+    // Croissance exponentielle moyenne : |ζ| est multiplié par e en un temps de croissance
+    let e_folding = summary.e_folding_time.unwrap();
+    let growth = (results[23].relative_vorticity() / results[0].relative_vorticity()).ln();
+    assert!((e_folding * growth - 23.0 * 3600.0).abs() < 1e-6);
+    assert!(DevelopmentSummary::compute(&[], &[]).is_none());
+    let single = DevelopmentSummary::compute(&results[..1], &diagnostics[..1]).unwrap();
+    assert_eq!((single.maturity_hour, single.e_folding_time), (None, None));
+}
+
+#[test]
+fn maturity_is_never_reported_while_vorticity_increases() {
+    let lothar = scenarios::find("lothar-1999").unwrap().scenario();
+    let mut runs = vec![lothar.to_cyclogenesis().unwrap().simulate_with_diagnostics(lothar.time_steps).unwrap()];
+    for latitude in [30.0, 45.0, 60.0] {
+        runs.push(BaroclinicCyclogenesis::new(5.0, -8.0, latitude).unwrap().simulate_with_diagnostics(48).unwrap());
+    }
+
+    for (results, diagnostics) in &runs {
+        let summary = DevelopmentSummary::compute(results, diagnostics).unwrap();
+        let Some(hour) = summary.maturity_hour else {
+            // Sans maturité, le tourbillon n'a jamais cessé de croître au-delà du stade naissant
+            assert_eq!(summary.peak_hour, results.last().unwrap().hour());
+            continue;
+        };
+        let index = results.iter().position(|result| result.hour() == hour).unwrap();
+        assert!(index > 0);
+        let (before, at) = (results[index - 1].relative_vorticity().abs(), results[index].relative_vorticity().abs());
+        assert!(at <= before, "maturité à l'heure {} alors que |ζ| croît : {} > {}", hour, at, before);
+        assert!(summary.peak_hour < hour);
+    }
+    // Lothar culmine avant la fin : la maturité suit le pic
+    let (results, diagnostics) = &runs[0];
+    let summary = DevelopmentSummary::compute(results, diagnostics).unwrap();
+    assert_eq!(summary.maturity_hour, Some(summary.peak_hour + 1));
+}
+
+#[test]
+fn summary_is_described_in_both_languages() {
+    let mut cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap();
+    let (results, diagnostics) = cyclogenesis.simulate_with_diagnostics(24).unwrap();
+    let summary = DevelopmentSummary::compute(&results, &diagnostics).unwrap();
+    let format = NumberFormat::default();
+
+    assert_eq!(
+        summary.describe(OutputLanguage::English, &format),
+        "Maturity not reached, peak vorticity -9.02 10⁻⁵ s⁻¹ at hour 23, e-folding time = 13.20 h"
+    );
+    let early = DevelopmentSummary::compute(&results[..3], &diagnostics[..3]).unwrap();
+    assert!(early.describe(OutputLanguage::French, &format).starts_with("Maturité non atteinte, tourbillon maximal de"));
+}
+
+#[test]
+fn report_carries_the_summary() {
+    let scenario = scenarios::find("lothar-1999").unwrap().scenario();
+    let report = Report::compute(&scenario).unwrap();
+    let summary = report.summary().unwrap();
+    assert_eq!(Some(summary.peak_hour), report.series().time_of_peak());
+
+    let markdown = report.to_markdown(OutputLanguage::English, &NumberFormat::default());
+    assert!(markdown.contains(&format!("- {}\n", summary.describe(OutputLanguage::English, &NumberFormat::default()))));
+}
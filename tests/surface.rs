@@ -0,0 +1,141 @@
+//! Nature de la surface sous l'anomalie de surface
+
+use cyclogenese_rust::diagnostics::AnomalyDiagnostics;
+use cyclogenese_rust::surface::OCEAN_DRAG;
+use cyclogenese_rust::{BaroclinicCyclogenesis, DragLaw, MeteoError, Scenario, SurfaceType};
+
+fn surface_chain(surface: SurfaceType, hour: u32) -> AnomalyDiagnostics {
+    let mut cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap().with_surface_type(surface).unwrap();
+    cyclogenesis.explain(hour).unwrap().anomalies[0].chain
+}
+
+#[test]
+fn no_surface_keeps_the_reference_run() {
+    let reference = Scenario::default().run().unwrap();
+    assert_eq!(Scenario { surface: None, ..Scenario::default() }.run().unwrap(), reference);
+    assert_eq!(BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap().surface_type(), None);
+}
+
+#[test]
+fn ocean_and_land_develop_differently() {
+    let run = |surface| Scenario { surface: Some(surface), ..Scenario::default() }.run().unwrap();
+    let ocean = run(SurfaceType::Ocean { sst: 300.0 });
+    let land = run(SurfaceType::Land { roughness: 0.5, soil_moisture: 0.2 });
+    assert_ne!(ocean, land);
+    assert_ne!(ocean, Scenario::default().run().unwrap());
+}
+
+#[test]
+fn rough_land_damps_more_than_the_sea() {
+    let ocean = surface_chain(SurfaceType::Ocean { sst: 288.0 }, 12);
+    let forest = surface_chain(SurfaceType::Land { roughness: 1.0, soil_moisture: 0.5 }, 12);
+    assert!(forest.friction_factor < ocean.friction_factor && ocean.friction_factor < 1.0);
+    assert!(surface_chain(SurfaceType::Ocean { sst: 288.0 }, 24).friction_factor < ocean.friction_factor);
+}
+
+#[test]
+fn warm_sea_feeds_the_surface_anomaly() {
+    assert!(surface_chain(SurfaceType::Ocean { sst: 300.0 }, 6).forcing > 0.0);
+    assert!(surface_chain(SurfaceType::Ocean { sst: 270.0 }, 6).forcing < 0.0);
+}
+
+#[test]
+fn land_drag_grows_with_roughness() {
+    let drag = |roughness| SurfaceType::Land { roughness, soil_moisture: 0.5 }.drag_coefficient();
+    assert!(drag(0.01) < drag(0.1) && drag(0.1) < drag(1.0));
+    assert_eq!(SurfaceType::Ocean { sst: 285.0 }.drag_coefficient(), OCEAN_DRAG);
+    assert!(drag(0.0002) < OCEAN_DRAG * 1.5);
+}
+
+#[test]
+fn heat_flux_follows_the_sea_temperature() {
+    let ocean = SurfaceType::Ocean { sst: 290.0 };
+    assert!(ocean.heat_flux(285.0, 0.0) > 0.0);
+    assert!(ocean.heat_flux(295.0, 0.0) < 0.0);
+    let dry = SurfaceType::Land { roughness: 0.1, soil_moisture: 0.0 };
+    let wet = SurfaceType::Land { roughness: 0.1, soil_moisture: 1.0 };
+    assert_eq!(dry.heat_flux(288.0, 2.0), 2.0);
+    assert!(wet.heat_flux(288.0, 2.0) < dry.heat_flux(288.0, 2.0));
+}
+
+#[test]
+fn rejects_an_invalid_surface() {
+    let cyclogenesis = || BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap();
+    for surface in [
+        SurfaceType::Ocean { sst: 200.0 },
+        SurfaceType::Land { roughness: 0.0, soil_moisture: 0.5 },
+        SurfaceType::Land { roughness: 20.0, soil_moisture: 0.5 },
+        SurfaceType::Land { roughness: 0.1, soil_moisture: 1.5 },
+    ] {
+        assert!(matches!(cyclogenesis().with_surface_type(surface), Err(MeteoError::InvalidScenario(_))), "{:?}", surface);
+    }
+}
+
+#[test]
+fn surface_is_read_from_toml() {
+    let scenario = Scenario::from_toml("surface = { ocean = { sst = 285.0 } }").unwrap();
+    assert_eq!(scenario.surface, Some(SurfaceType::Ocean { sst: 285.0 }));
+    let scenario = Scenario::from_toml("[surface.land]\nroughness = 0.1\nsoil_moisture = 0.3").unwrap();
+    assert_eq!(scenario.surface, Some(SurfaceType::Land { roughness: 0.1, soil_moisture: 0.3 }));
+    assert_eq!(scenario.to_cyclogenesis().unwrap().surface_type(), scenario.surface);
+    assert!(matches!(Scenario::from_toml("surface = \"ice\""), Err(MeteoError::InvalidScenario(_))));
+}
+
+#[test]
+fn charnock_drag_grows_with_wind() {
+    let law = DragLaw::charnock();
+    let (calm, gale, storm) = (law.drag_coefficient(5.0), law.drag_coefficient(15.0), law.drag_coefficient(30.0));
+    assert!(calm < gale && gale < storm, "{} < {} < {}", calm, gale, storm);
+    assert!((1.0e-3..1.5e-3).contains(&law.drag_coefficient(10.0)));
+    let roughness = DragLaw::Roughness { length: 0.1 };
+    assert_eq!(roughness.drag_coefficient(5.0), roughness.drag_coefficient(30.0));
+}
+
+#[test]
+fn drag_law_sets_the_decay() {
+    let chain = |law| {
+        let cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap().with_drag_law(law).unwrap();
+        let mut cyclogenesis = cyclogenesis.with_surface_type(SurfaceType::Ocean { sst: 288.0 }).unwrap();
+        cyclogenesis.explain(12).unwrap().anomalies[0].chain.friction_factor
+    };
+    let smooth = chain(DragLaw::Constant { drag_coefficient: 1.0e-3 });
+    let rough = chain(DragLaw::Roughness { length: 0.5 });
+    assert!(rough < smooth && smooth < 1.0, "{} < {}", rough, smooth);
+    let ocean = surface_chain(SurfaceType::Ocean { sst: 288.0 }, 12);
+    assert_eq!(chain(DragLaw::Constant { drag_coefficient: OCEAN_DRAG }), ocean.friction_factor);
+}
+
+#[test]
+fn drag_law_alone_applies_friction() {
+    let cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap();
+    assert_eq!(cyclogenesis.drag_law(), None);
+    let cyclogenesis = cyclogenesis.with_drag_law(DragLaw::charnock()).unwrap();
+    assert_eq!(cyclogenesis.drag_law(), Some(DragLaw::charnock()));
+    assert_eq!(cyclogenesis.surface_type(), None);
+    let land = SurfaceType::Land { roughness: 0.2, soil_moisture: 0.5 };
+    let land = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap().with_surface_type(land).unwrap();
+    assert_eq!(land.drag_law(), Some(DragLaw::Roughness { length: 0.2 }));
+
+    let reference = Scenario::default().run().unwrap();
+    assert_ne!(Scenario { drag_law: Some(DragLaw::charnock()), ..Scenario::default() }.run().unwrap(), reference);
+}
+
+#[test]
+fn rejects_an_invalid_drag_law() {
+    for law in [
+        DragLaw::Constant { drag_coefficient: 0.0 },
+        DragLaw::Constant { drag_coefficient: 0.2 },
+        DragLaw::Roughness { length: -1.0 },
+        DragLaw::Charnock { alpha: 0.5 },
+    ] {
+        let result = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap().with_drag_law(law);
+        assert!(matches!(result, Err(MeteoError::InvalidScenario(_))), "{:?}", law);
+    }
+}
+
+#[test]
+fn drag_law_is_read_from_toml() {
+    let scenario = Scenario::from_toml("drag_law = { charnock = { alpha = 0.018 } }").unwrap();
+    assert_eq!(scenario.drag_law, Some(DragLaw::Charnock { alpha: 0.018 }));
+    assert_eq!(scenario.to_cyclogenesis().unwrap().drag_law(), scenario.drag_law);
+}
@@ -0,0 +1,94 @@
+//! Balayage en latitude d'un scénario
+
+use cyclogenese_rust::diagnostics::DiagnosticsLevel;
+use cyclogenese_rust::sweep::SweepConfig;
+use cyclogenese_rust::{BaroclinicCyclogenesis, MeteoError, NumberFormat, OutputLanguage, Scenario};
+
+#[test]
+fn runs_are_ordered_by_latitude() {
+    let sweep = BaroclinicCyclogenesis::sweep_latitudes(&[60.0, 30.0, 45.0], &SweepConfig::default()).unwrap();
+    assert_eq!(sweep.latitudes(), vec![30.0, 45.0, 60.0]);
+    assert!(sweep.series(50.0).is_none());
+
+    // Chaque simulation est celle du scénario à sa latitude
+    for (latitude, series) in sweep.iter() {
+        let expected = Scenario { latitude, ..Scenario::default() }.run().unwrap();
+        assert_eq!(series.to_results(), expected);
+    }
+}
+
+#[test]
+fn summary_compares_peaks() {
+    let sweep = BaroclinicCyclogenesis::sweep_latitudes(&[30.0, 45.0, 60.0], &SweepConfig::default()).unwrap();
+    let summary = sweep.summary();
+    assert_eq!(summary.len(), 3);
+    for peak in &summary {
+        let series = sweep.series(peak.latitude).unwrap();
+        assert_eq!(Some(peak.hour), series.time_of_peak());
+        assert_eq!(Some(peak.max_vorticity), series.max_vorticity());
+        assert_eq!(peak.deepening_rate, series.deepening_rate());
+    }
+
+    // Le tourbillon au pic croît vers le pôle avec le paramètre de Coriolis
+    assert!(summary.windows(2).all(|pair| pair[0].max_vorticity.abs() < pair[1].max_vorticity.abs()));
+
+    let table = sweep.summary_table(OutputLanguage::English, &NumberFormat::default());
+    assert_eq!(table.lines().count(), 5);
+    assert!(table.starts_with("Latitude (°) | Peak hour"));
+}
+
+#[test]
+fn config_carries_the_scenario_and_diagnostics_level() {
+    let scenario = Scenario { time_steps: 6, surface_temp: 3.0, ..Scenario::default() };
+    let config = SweepConfig { diagnostics_level: DiagnosticsLevel::Full, ..SweepConfig::from(scenario) };
+    let sweep = BaroclinicCyclogenesis::sweep_latitudes(&[40.0, 50.0], &config).unwrap();
+    for run in sweep.runs() {
+        assert_eq!(run.series.len(), 6);
+        assert!(run.diagnostics.iter().all(|step| step.anomalies.len() == 2));
+    }
+}
+
+#[test]
+fn rejects_repeated_or_invalid_latitudes() {
+    let repeated = BaroclinicCyclogenesis::sweep_latitudes(&[45.0, 30.0, 45.0], &SweepConfig::default());
+    assert!(matches!(repeated, Err(MeteoError::InvalidScenario(_))));
+
+    let invalid = BaroclinicCyclogenesis::sweep_latitudes(&[45.0, 95.0], &SweepConfig::default());
+    assert!(matches!(invalid, Err(MeteoError::InvalidLatitude(value)) if value == 95.0));
+}
+
+#[test]
+fn vorticity_matrix_has_a_column_per_latitude() {
+    let sweep = BaroclinicCyclogenesis::sweep_latitudes(&[30.0, 45.0, 60.0], &SweepConfig::default()).unwrap();
+    let matrix = sweep.vorticity_matrix();
+    assert_eq!(matrix.latitudes(), &[30.0, 45.0, 60.0]);
+    assert_eq!(matrix.hours(), (0..24).collect::<Vec<_>>().as_slice());
+    assert_eq!(matrix.vorticity().len(), 24 * 3);
+
+    let row = matrix.row(5).unwrap();
+    for (value, (_, series)) in row.iter().zip(sweep.iter()) {
+        assert_eq!(*value, series.row(5).unwrap().relative_vorticity());
+    }
+
+    let csv = matrix.to_csv();
+    let lines: Vec<_> = csv.lines().collect();
+    assert_eq!(lines.len(), 25);
+    assert_eq!(lines[0], "hour,30,45,60");
+    let values: Vec<f64> = lines[6].split(',').skip(1).map(|value| value.parse().unwrap()).collect();
+    assert_eq!(values, row);
+}
+
+#[cfg(feature = "plot")]
+#[test]
+fn vorticity_matrix_renders_to_png() {
+    let scenario = Scenario { time_steps: 4, ..Scenario::default() };
+    let sweep = BaroclinicCyclogenesis::sweep_latitudes(&[40.0, 50.0], &SweepConfig::from(scenario)).unwrap();
+    let png = sweep.vorticity_matrix().to_png(8).unwrap();
+
+    // Signature puis en-tête IHDR : 2 × 8 pixels de large, 4 × 8 de haut
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    assert_eq!(&png[12..16], b"IHDR");
+    assert_eq!(u32::from_be_bytes(png[16..20].try_into().unwrap()), 16);
+    assert_eq!(u32::from_be_bytes(png[20..24].try_into().unwrap()), 32);
+    assert!(sweep.vorticity_matrix().to_png(0).is_err());
+}
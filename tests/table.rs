@@ -0,0 +1,65 @@
+//! Mise en forme des résultats en tableau
+
+use cyclogenese_rust::format::ExponentStyle;
+use cyclogenese_rust::table::{Alignment, Column, Units};
+use cyclogenese_rust::{NumberFormat, OutputLanguage, Scenario, TableFormatter};
+
+#[test]
+fn default_table_matches_the_historical_layout() {
+    let results = Scenario::default().run().unwrap();
+    let format = NumberFormat::default();
+    for language in [OutputLanguage::French, OutputLanguage::English] {
+        let table = TableFormatter::default().with_language(language);
+        assert_eq!(table.header(), language.table_header());
+        assert_eq!(table.rule(), language.table_rule());
+    }
+
+    let table = TableFormatter::default();
+    for result in &results {
+        assert_eq!(table.row(result), result.to_string_with(&format));
+        assert_eq!(result.to_string(), result.to_string_with(&format));
+    }
+    let text = table.format(&results);
+    assert_eq!(text.lines().count(), results.len() + 2);
+}
+
+#[test]
+fn si_units_skip_the_conversions() {
+    let results = Scenario::default().run().unwrap();
+    let format = NumberFormat::default().with_exponent(ExponentStyle::Scientific);
+    let table = TableFormatter::default()
+        .with_units(Units::Si)
+        .with_number_format(format)
+        .with_columns(vec![Column::VerticalVelocity, Column::RelativeVorticity]);
+
+    assert_eq!(table.header(), "Vitesse verticale (m/s) | Tourbillon relatif (s⁻¹)");
+    let last = results.last().unwrap();
+    let expected = format!(
+        "{} | {}",
+        format.format_width(last.vertical_velocity(), 20),
+        format.format_width(last.relative_vorticity(), 20)
+    );
+    assert_eq!(table.row(last), expected);
+}
+
+#[test]
+fn columns_and_alignment_are_configurable() {
+    let scenario = Scenario::from_toml(
+        "geometry = \"spherical\"\nstart_time = 1999-12-25T12:00:00Z\nlongitude = -4.5\nlatitude = 48.5\n",
+    )
+    .unwrap();
+    let results = scenario.run().unwrap();
+    let table = TableFormatter::default()
+        .with_columns(vec![Column::Hour, Column::ValidTime, Column::Latitude])
+        .with_alignment(Alignment::Left)
+        .with_width(18)
+        .with_language(OutputLanguage::English);
+
+    assert_eq!(table.header(), "Hour  | Valid time (UTC)   | Latitude (°)");
+    assert_eq!(table.rule(), "------|--------------------|--------------------");
+    assert_eq!(table.row(&results[0]), "0    | 1999-12-25 12:00   | 48.50             ");
+
+    // Sans date de départ, la cellule reste vide
+    let results = Scenario::default().run().unwrap();
+    assert!(table.row(&results[0]).contains("—"));
+}
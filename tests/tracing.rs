@@ -0,0 +1,90 @@
+//! Journaux structurés de la simulation et des options `--log-level`/`--log-json`
+#![cfg(feature = "tracing")]
+
+use std::io::{self, Write};
+use std::process::Command;
+use std::sync::{Arc, Mutex};
+
+use cyclogenese_rust::BaroclinicCyclogenesis;
+use serde_json::Value;
+use tracing::Level;
+
+/// Destination partagée des journaux JSON
+#[derive(Clone, Default)]
+struct Capture(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Capture {
+    fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(bytes)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Événements émis pendant `run` au niveau donné, un objet JSON par ligne
+fn events(level: Level, run: impl FnOnce()) -> Vec<Value> {
+    let capture = Capture::default();
+    let writer = capture.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .json()
+        .with_max_level(level)
+        .with_writer(move || writer.clone())
+        .finish();
+    tracing::subscriber::with_default(subscriber, run);
+
+    let bytes = capture.0.lock().unwrap().clone();
+    String::from_utf8(bytes).unwrap().lines().map(|line| serde_json::from_str(line).unwrap()).collect()
+}
+
+fn message(event: &Value) -> &str {
+    event["fields"]["message"].as_str().unwrap()
+}
+
+#[test]
+fn time_steps_are_traced_inside_the_simulation_span() {
+    let events = events(Level::TRACE, || {
+        BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap().simulate_interaction(24).unwrap();
+    });
+    let steps = events.iter().filter(|event| message(event) == "pas de temps").collect::<Vec<_>>();
+    assert_eq!(steps.len(), 24);
+    for (hour, step) in steps.iter().enumerate() {
+        assert_eq!(step["level"], "TRACE");
+        assert_eq!(step["fields"]["hour"], hour as u64);
+        assert!(step["fields"]["relative_vorticity"].is_f64());
+        assert_eq!(step["span"]["name"], "simulation");
+        assert_eq!(step["span"]["latitude"], 45.0);
+    }
+}
+
+#[test]
+fn rejected_parameters_are_warned_about() {
+    let events = events(Level::WARN, || {
+        assert!(BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap().with_diurnal_cycle(400.0).is_err());
+        BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap().simulate_interaction(6).unwrap();
+    });
+    // Les pas de temps, au niveau trace, sont filtrés
+    assert_eq!(events.len(), 1, "{:?}", events);
+    assert_eq!(events[0]["level"], "WARN");
+    assert_eq!(message(&events[0]), "longitude rejetée");
+    assert_eq!(events[0]["fields"]["longitude"], 400.0);
+}
+
+#[test]
+fn the_command_line_chooses_the_level_and_the_format() {
+    let run = |arguments: &[&str]| {
+        let output = Command::new(env!("CARGO_BIN_EXE_cyclogenese")).args(arguments).output().unwrap();
+        assert!(output.status.success());
+        String::from_utf8(output.stderr).unwrap()
+    };
+
+    assert_eq!(run(&[]), "");
+    let json = run(&["--log-level", "trace", "--log-json"]);
+    let events = json.lines().map(|line| serde_json::from_str::<Value>(line).unwrap()).collect::<Vec<_>>();
+    assert!(events.iter().any(|event| message(event) == "pas de temps"));
+
+    let text = run(&["--log-level", "trace"]);
+    assert!(text.contains("TRACE") && text.contains("pas de temps"));
+    assert!(serde_json::from_str::<Value>(text.lines().next().unwrap()).is_err());
+}
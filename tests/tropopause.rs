@@ -0,0 +1,108 @@
+//! Tropopause et couvercle stratosphérique
+
+use std::process::Command;
+
+use cyclogenese_rust::plausibility::PlausibilityWarning;
+use cyclogenese_rust::tropopause::altitude_factor;
+use cyclogenese_rust::{
+    BaroclinicCyclogenesis, MeteoError, NumberFormat, OutputLanguage, PhysicalConstants, Position, Scenario, ThermalAnomaly,
+};
+
+fn lower_stratosphere(altitude: f64) -> BaroclinicCyclogenesis {
+    let constants = PhysicalConstants::default();
+    let surface = ThermalAnomaly::new(5.0, Position::surface(45.0).unwrap(), constants).unwrap();
+    let upper = ThermalAnomaly::new(-8.0, Position::new(45.0, altitude, 200.0).unwrap(), constants).unwrap();
+    BaroclinicCyclogenesis::from_anomalies(surface, upper)
+}
+
+#[test]
+fn altitude_factor_is_capped_above_the_tropopause() {
+    assert_eq!(altitude_factor(5000.0, 10000.0), (-5000.0_f64 / 8000.0).exp());
+    assert_eq!(altitude_factor(10000.0, 10000.0), (-10000.0_f64 / 8000.0).exp());
+    let free = (-12000.0_f64 / 8000.0).exp();
+    let capped = altitude_factor(12000.0, 10000.0);
+    assert!(capped < free / 5.0, "{} < {}", capped, free);
+    assert!(altitude_factor(12000.0, 13000.0) > capped);
+}
+
+#[test]
+fn default_tropopause_keeps_the_reference_run() {
+    let reference = Scenario::default().run().unwrap();
+    assert_eq!(Scenario { tropopause_height: Some(10000.0), ..Scenario::default() }.run().unwrap(), reference);
+    assert_eq!(BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap().tropopause_height(), 10000.0);
+}
+
+#[test]
+fn upper_anomaly_above_the_tropopause_is_flagged() {
+    let cyclogenesis = lower_stratosphere(12000.0);
+    assert!(cyclogenesis.is_above_tropopause());
+    let warning = PlausibilityWarning::AboveTropopause { altitude: 12000.0, tropopause: 10000.0 };
+    assert_eq!(cyclogenesis.plausibility_warnings(), vec![warning]);
+    let text = warning.describe(OutputLanguage::English, &NumberFormat::default());
+    assert!(text.starts_with("Warning: upper anomaly at 12000.00 m, above the tropopause"), "{}", text);
+
+    let raised = lower_stratosphere(12000.0).with_tropopause_height(13000.0).unwrap();
+    assert!(raised.plausibility_warnings().is_empty());
+}
+
+#[test]
+fn stratosphere_damps_the_upper_ascent() {
+    let velocity = |cyclogenesis: BaroclinicCyclogenesis| {
+        let mut cyclogenesis = cyclogenesis;
+        cyclogenesis.explain(0).unwrap().anomalies[1].chain.vertical_velocity.abs()
+    };
+    let capped = velocity(lower_stratosphere(12000.0));
+    let raised = velocity(lower_stratosphere(12000.0).with_tropopause_height(15000.0).unwrap());
+    assert!(capped < raised, "{} < {}", capped, raised);
+
+    let mut cyclogenesis = lower_stratosphere(12000.0);
+    let text = cyclogenesis.explain(0).unwrap().describe(OutputLanguage::French, &NumberFormat::default());
+    assert!(text.contains("exp(−H/8000) · exp(−(z − H)/1000)"), "{}", text);
+}
+
+#[test]
+fn rejects_an_invalid_tropopause() {
+    for height in [1000.0, 30000.0] {
+        let result = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap().with_tropopause_height(height);
+        assert!(matches!(result, Err(MeteoError::InvalidScenario(_))), "{}", height);
+    }
+}
+
+#[test]
+fn tropopause_is_read_from_toml() {
+    let scenario = Scenario::from_toml("season = \"winter\"\ntropopause_height = 11000.0").unwrap();
+    assert_eq!(scenario.tropopause_height, Some(11000.0));
+    let cyclogenesis = scenario.to_cyclogenesis().unwrap();
+    assert_eq!(cyclogenesis.tropopause_height(), 11000.0);
+    assert_eq!(cyclogenesis.background().base_temp, 278.15);
+}
+
+#[test]
+fn scenarios_place_the_upper_anomaly() {
+    assert_eq!(Scenario::default().upper_level(), (5000.0, 500.0));
+    let scenario = Scenario::from_toml("upper_level_altitude = 12000.0\nupper_level_pressure = 200.0\n").unwrap();
+    assert_eq!(scenario.upper_level(), (12000.0, 200.0));
+    let warning = PlausibilityWarning::AboveTropopause { altitude: 12000.0, tropopause: 10000.0 };
+    assert_eq!(scenario.plausibility_warnings().unwrap(), vec![warning]);
+    assert_eq!(scenario.to_cyclogenesis().unwrap().plausibility_warnings(), lower_stratosphere(12000.0).plausibility_warnings());
+
+    let raised = Scenario { tropopause_height: Some(13000.0), ..scenario.clone() };
+    assert!(raised.plausibility_warnings().unwrap().is_empty());
+    let out_of_range = Scenario { upper_level_pressure: Some(50.0), ..scenario };
+    assert!(matches!(out_of_range.to_cyclogenesis(), Err(MeteoError::InvalidPressure(_))));
+}
+
+#[test]
+fn commands_warn_about_a_stratospheric_upper_anomaly() {
+    let path = std::env::temp_dir().join(format!("cyclogenese-tropopause-{}.toml", std::process::id()));
+    std::fs::write(&path, "upper_level_altitude = 12000.0\nupper_level_pressure = 200.0\n").unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_cyclogenese")).arg("report").arg(&path).output().unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(
+        stderr.contains("Attention : anomalie d'altitude à 12000.00 m, au-dessus de la tropopause (10000.00 m)"),
+        "{}",
+        stderr
+    );
+}
@@ -0,0 +1,85 @@
+//! Talweg d'altitude en approche de la zone barocline
+
+use cyclogenese_rust::check::{Finding, ScenarioCheck};
+use cyclogenese_rust::{BaroclinicCyclogenesis, MeteoError, ResultSeries, Scenario, UpperTrough};
+
+fn trough() -> UpperTrough {
+    // Axe à 1500 km en amont, à 15 m/s : au quart de longueur d'onde vers 14 h
+    UpperTrough::new(4.0, 1.5e6, 15.0).unwrap()
+}
+
+#[test]
+fn trough_approaches_the_surface_anomaly() {
+    let trough = trough();
+    assert_eq!(trough.distance(0), 1.5e6);
+    assert_eq!(trough.distance(10), 1.5e6 - 15.0 * 36000.0);
+    assert!(trough.temperature_perturbation(0).abs() < 1e-12);
+    assert!(trough.temperature_perturbation(24) < trough.temperature_perturbation(12));
+    assert_eq!(trough.with_distance(0.0).unwrap().temperature_perturbation(0), -4.0);
+    assert_eq!(trough.with_distance(2.0e6).unwrap().temperature_perturbation(0), 0.0);
+}
+
+#[test]
+fn coupling_peaks_a_quarter_wavelength_upstream() {
+    let efficiency = |distance| trough().with_distance(distance).unwrap().coupling_efficiency(0);
+    assert!(efficiency(1.5e6).abs() < 1e-12);
+    assert!((efficiency(7.5e5) - 1.0).abs() < 1e-12);
+    assert!(efficiency(3.0e5) < efficiency(7.5e5) && efficiency(3.0e5) > 0.0);
+    assert!(efficiency(-5.0e5) < 0.0);
+    assert_eq!(efficiency(3.0e6), 0.0);
+}
+
+#[test]
+fn trough_offset_replaces_the_tilt() {
+    let cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap();
+    assert_eq!(cyclogenesis.upper_offset(6), cyclogenesis.tilt());
+    assert_eq!(cyclogenesis.coupling_efficiency(6), cyclogenesis.tilt_efficiency());
+    let cyclogenesis = cyclogenesis.with_upper_trough(trough());
+    assert_eq!(cyclogenesis.upper_trough(), Some(trough()));
+    assert_eq!(cyclogenesis.upper_offset(6), trough().distance(6));
+    assert_eq!(cyclogenesis.coupling_efficiency(6), trough().coupling_efficiency(6));
+}
+
+#[test]
+fn approaching_trough_drives_the_development() {
+    let run = |trough: UpperTrough| {
+        let scenario = Scenario { upper_trough: Some(trough), time_steps: 36, ..Scenario::default() };
+        ResultSeries::from(scenario.run().unwrap())
+    };
+    let approaching = run(trough());
+    let distant = run(trough().with_distance(3.0e6).unwrap());
+    let early = approaching.row(0).unwrap().relative_vorticity().abs();
+    let peak = approaching.peak().unwrap();
+    assert!(peak.relative_vorticity().abs() > 5.0 * early);
+    assert!(peak.hour() < distant.peak().unwrap().hour());
+    // Une fois l'anomalie de surface dépassée, le talweg cesse de la renforcer
+    let last = approaching.rows().last().unwrap().relative_vorticity().abs();
+    assert!(last < peak.relative_vorticity().abs() / 2.0);
+}
+
+#[test]
+fn trough_downstream_is_flagged_by_the_check() {
+    let downstream = trough().with_distance(-5.0e5).unwrap();
+    let check = ScenarioCheck::run(&Scenario { upper_trough: Some(downstream), ..Scenario::default() }).unwrap();
+    assert!(check.findings().contains(&Finding::EastwardTilt));
+    let check = ScenarioCheck::run(&Scenario { upper_trough: Some(trough()), ..Scenario::default() }).unwrap();
+    assert!(!check.findings().contains(&Finding::EastwardTilt));
+}
+
+#[test]
+fn rejects_an_invalid_trough() {
+    assert!(matches!(UpperTrough::new(90.0, 1.5e6, 15.0), Err(MeteoError::InvalidTemperature(_))));
+    assert!(matches!(UpperTrough::new(4.0, 0.0, 15.0), Err(MeteoError::InvalidScale(_))));
+    assert!(matches!(UpperTrough::new(4.0, 1.5e6, f64::NAN), Err(MeteoError::InvalidScenario(_))));
+    assert!(matches!(trough().with_distance(f64::INFINITY), Err(MeteoError::InvalidTilt(_))));
+}
+
+#[test]
+fn trough_is_read_from_toml() {
+    let toml = "[upper_trough]\namplitude = 4.0\nhalf_wavelength = 1.5e6\napproach_speed = 15.0\ndistance = 1.2e6";
+    let scenario = Scenario::from_toml(toml).unwrap();
+    assert_eq!(scenario.upper_trough, Some(trough().with_distance(1.2e6).unwrap()));
+    assert_eq!(scenario.to_cyclogenesis().unwrap().upper_offset(0), 1.2e6);
+    let invalid = "[upper_trough]\namplitude = 4.0\nhalf_wavelength = -1.0\napproach_speed = 15.0";
+    assert!(matches!(Scenario::from_toml(invalid).unwrap().to_cyclogenesis(), Err(MeteoError::InvalidScale(_))));
+}
@@ -0,0 +1,94 @@
+//! Tableau de bord interactif, dessiné dans un terminal de test
+#![cfg(feature = "tui")]
+
+use cyclogenese_rust::tui::Dashboard;
+use cyclogenese_rust::OutputLanguage;
+use ratatui::backend::TestBackend;
+use ratatui::crossterm::event::KeyCode;
+use ratatui::Terminal;
+
+/// Lignes de l'écran après un dessin du tableau de bord
+fn screen(dashboard: &Dashboard) -> Vec<String> {
+    let mut terminal = Terminal::new(TestBackend::new(100, 40)).unwrap();
+    terminal.draw(|frame| dashboard.render(frame)).unwrap();
+    let buffer = terminal.backend().buffer();
+    buffer
+        .content()
+        .chunks(usize::from(buffer.area.width))
+        .map(|row| row.iter().map(|cell| cell.symbol()).collect())
+        .collect()
+}
+
+/// Valeur affichée d'un réglage
+fn setting(dashboard: &Dashboard, label: &str) -> f64 {
+    let screen = screen(dashboard);
+    let line = screen.iter().find(|line| line.contains(label)).expect("réglage affiché");
+    let value = line.split(label).nth(1).unwrap().split_whitespace().next().unwrap();
+    value.parse().unwrap()
+}
+
+fn press(dashboard: &mut Dashboard, code: KeyCode, times: usize) {
+    for _ in 0..times {
+        dashboard.handle_key(code);
+    }
+}
+
+#[test]
+fn the_reference_case_is_drawn_on_start() {
+    let dashboard = Dashboard::new(OutputLanguage::French);
+    let screen = screen(&dashboard).join("\n");
+    assert!(screen.contains("Cyclogenèse barocline"));
+    assert!(screen.contains("▶ ΔT surface (K)"));
+    assert!(screen.contains("Vitesse verticale (cm/s)") && screen.contains("Tourbillon relatif"));
+    assert!(screen.contains("q quitter"));
+    assert_eq!(setting(&dashboard, "ΔT surface (K)"), 5.0);
+    assert_eq!(dashboard.relative_vorticity().len(), 24);
+}
+
+#[test]
+fn adjusting_a_setting_reruns_the_simulation() {
+    let mut dashboard = Dashboard::new(OutputLanguage::English);
+    let before = dashboard.relative_vorticity().to_vec();
+    dashboard.handle_key(KeyCode::Right);
+    assert_eq!(setting(&dashboard, "Surface ΔT (K)"), 5.5);
+    assert_ne!(dashboard.relative_vorticity(), before);
+
+    // Latitude, deux lignes plus bas
+    press(&mut dashboard, KeyCode::Down, 2);
+    press(&mut dashboard, KeyCode::Char('-'), 3);
+    assert_eq!(setting(&dashboard, "Latitude (°N)"), 42.0);
+    assert!(screen(&dashboard).iter().any(|line| line.contains("▶ Latitude")));
+
+    // La durée reste dans ses bornes
+    dashboard.handle_key(KeyCode::Tab);
+    press(&mut dashboard, KeyCode::Right, 30);
+    assert_eq!(setting(&dashboard, "Duration (h)"), 120.0);
+    assert_eq!(dashboard.relative_vorticity().len(), 120);
+}
+
+#[test]
+fn invalid_settings_are_undone_and_reported() {
+    let mut dashboard = Dashboard::new(OutputLanguage::English);
+    press(&mut dashboard, KeyCode::Up, 2);
+    press(&mut dashboard, KeyCode::Right, 60);
+    // 91°N est refusé, le réglage reste au pôle
+    assert_eq!(setting(&dashboard, "Latitude (°N)"), 90.0);
+    let error = dashboard.error().expect("latitude refusée").to_string();
+    assert!(error.starts_with("Invalid latitude"), "{}", error);
+    assert!(screen(&dashboard).last().unwrap().contains(&error));
+
+    // Une simulation acceptée efface l'erreur
+    dashboard.handle_key(KeyCode::Left);
+    assert_eq!(dashboard.error(), None);
+}
+
+#[test]
+fn reset_and_quit_keys() {
+    let mut dashboard = Dashboard::new(OutputLanguage::French);
+    press(&mut dashboard, KeyCode::Right, 4);
+    dashboard.handle_key(KeyCode::Char('r'));
+    assert_eq!(setting(&dashboard, "ΔT surface (K)"), 5.0);
+    assert!(!dashboard.should_quit());
+    dashboard.handle_key(KeyCode::Char('q'));
+    assert!(dashboard.should_quit());
+}
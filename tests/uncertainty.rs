@@ -0,0 +1,41 @@
+//! Propagation des incertitudes sur les anomalies thermiques
+
+use cyclogenese_rust::uncertainty::Uncertain;
+use cyclogenese_rust::{BaroclinicCyclogenesis, DevelopmentResult, Float};
+
+fn field<T: Float + serde::Serialize>(results: &[DevelopmentResult<T>], name: &str) -> Vec<serde_json::Value> {
+    serde_json::to_value(results).unwrap().as_array().unwrap().iter().map(|result| result[name].clone()).collect()
+}
+
+fn run(surface_temp: f64, altitude_temp: f64) -> Vec<f64> {
+    let results = BaroclinicCyclogenesis::new(surface_temp, altitude_temp, 45.0).unwrap().simulate_interaction(24).unwrap();
+    field(&results, "relative_vorticity").iter().map(|value| value.as_f64().unwrap()).collect()
+}
+
+#[test]
+fn sigma_matches_the_response_to_each_input() {
+    let (surface_sigma, altitude_sigma) = (1.0, 0.5);
+    let results = BaroclinicCyclogenesis::new(Uncertain::new(5.0, surface_sigma), Uncertain::new(-8.0, altitude_sigma), 45.0)
+        .unwrap()
+        .simulate_interaction(24)
+        .unwrap();
+
+    let reference = run(5.0, -8.0);
+    let surface = run(5.0 + surface_sigma, -8.0);
+    let altitude = run(5.0, -8.0 + altitude_sigma);
+    for (hour, vorticity) in field(&results, "relative_vorticity").iter().enumerate() {
+        let (mean, sigma) = (vorticity["mean"].as_f64().unwrap(), vorticity["sigma"].as_f64().unwrap());
+        let expected = (surface[hour] - reference[hour]).hypot(altitude[hour] - reference[hour]);
+        assert!((mean - reference[hour]).abs() <= 1e-12 * reference[hour].abs(), "heure {}", hour);
+        assert!((sigma - expected).abs() <= 1e-9 * expected, "heure {} : {} / {}", hour, sigma, expected);
+    }
+}
+
+#[test]
+fn certain_inputs_give_certain_results() {
+    let results = BaroclinicCyclogenesis::new(Uncertain::new(5.0, 0.0), Uncertain::new(-8.0, 0.0), 45.0)
+        .unwrap()
+        .simulate_interaction(24)
+        .unwrap();
+    assert!(field(&results, "vertical_velocity").iter().all(|value| value["sigma"] == 0.0));
+}
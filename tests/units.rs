@@ -0,0 +1,62 @@
+//! Unités de vitesse du vent
+
+use cyclogenese_rust::units::{self, WindUnit};
+use cyclogenese_rust::wind::{self, WindGrid};
+use cyclogenese_rust::{diagnostics, BaroclinicCyclogenesis, NumberFormat, OutputLanguage};
+
+#[test]
+fn conversions_match_the_definitions() {
+    assert_eq!(WindUnit::KilometersPerHour.express(10.0), 36.0);
+    assert!((WindUnit::Knots.express(1852.0 / 3600.0) - 1.0).abs() < 1e-12);
+    assert!((WindUnit::MilesPerHour.to_si(1.0) - 0.44704).abs() < 1e-12);
+    assert!((units::convert(100.0, WindUnit::KilometersPerHour, WindUnit::Knots) - 53.995680).abs() < 1e-6);
+
+    for unit in [WindUnit::MetersPerSecond, WindUnit::KilometersPerHour, WindUnit::Knots, WindUnit::MilesPerHour] {
+        assert!((unit.to_si(unit.express(25.0)) - 25.0).abs() < 1e-12);
+        assert_eq!(unit.symbol().parse::<WindUnit>(), Ok(unit));
+    }
+    assert!("beaufort".parse::<WindUnit>().is_err());
+}
+
+#[test]
+fn tables_are_converted_to_the_selected_unit() {
+    let mut cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap();
+    let (_, steps) = cyclogenesis.simulate_with_diagnostics(3).unwrap();
+    let results = cyclogenesis.simulate_interaction(3).unwrap();
+    let fields = cyclogenesis.wind_fields(&results, &WindGrid::default());
+    let format = NumberFormat::default().with_wind_unit(WindUnit::Knots);
+
+    let table = wind::to_table(&fields, OutputLanguage::English, &format);
+    assert!(table.lines().next().unwrap().contains("| Maximum wind (kt)    |"));
+    let expected = format.format_width(WindUnit::Knots.express(fields[2].max_speed()), 20);
+    assert!(table.lines().nth(4).unwrap().contains(&format!("| {} |", expected)));
+
+    let table = diagnostics::to_table(&steps, OutputLanguage::English, &format);
+    let header = table.lines().next().unwrap();
+    assert!(header.starts_with("Hour  | Shear (kt)           | Richardson"));
+    assert_eq!(header.len(), diagnostics::to_table(&steps, OutputLanguage::English, &NumberFormat::default()).lines().next().unwrap().len());
+
+    let check = cyclogenesis.charney_stern().unwrap();
+    let text = check.describe(OutputLanguage::English, &format);
+    assert!(text.contains(&format!("shear {} kt", format.format(WindUnit::Knots.express(check.shear)))));
+}
+
+#[cfg(feature = "netcdf")]
+#[test]
+fn wind_export_carries_the_selected_unit() {
+    use netcdf3::FileReader;
+
+    let mut cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap();
+    let results = cyclogenesis.simulate_interaction(2).unwrap();
+    let fields = cyclogenesis.wind_fields(&results, &WindGrid::new(2.0, 1.0).unwrap());
+    let path = std::env::temp_dir().join(format!("cyclogenese-units-{}.nc", std::process::id()));
+    cyclogenese_rust::io::netcdf::write_wind_fields_with(&path, &fields, WindUnit::KilometersPerHour).unwrap();
+
+    let mut reader = FileReader::open(&path).unwrap();
+    let units = reader.data_set().get_var_attr_as_string("u", "units");
+    let u = reader.read_var_f64("u").unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(units.as_deref(), Some("km h-1"));
+    let expected: Vec<_> = fields.iter().flat_map(|field| field.u().iter().map(|u| u * 3.6)).collect();
+    assert!(u.iter().zip(&expected).all(|(a, b)| (a - b).abs() <= 1e-9 * b.abs().max(1.0)));
+}
@@ -0,0 +1,50 @@
+//! Dates de validité des résultats
+
+use chrono::{TimeDelta, TimeZone, Utc};
+use cyclogenese_rust::{BaroclinicCyclogenesis, Scenario};
+
+#[test]
+fn results_are_dated_from_the_start_time() {
+    let start = Utc.with_ymd_and_hms(1999, 12, 26, 0, 0, 0).unwrap();
+    let mut cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, 48.0).unwrap().with_start_time(start);
+    assert_eq!(cyclogenesis.start_time(), Some(start));
+    assert_eq!(cyclogenesis.step(6).unwrap().valid_time(), Some(Utc.with_ymd_and_hms(1999, 12, 26, 6, 0, 0).unwrap()));
+
+    let results = cyclogenesis.simulate_interaction(30).unwrap();
+    for result in &results {
+        assert_eq!(result.valid_time(), Some(start + TimeDelta::hours(i64::from(result.hour()))));
+    }
+    // Le passage à minuit change de jour
+    assert_eq!(results[25].valid_time(), Some(Utc.with_ymd_and_hms(1999, 12, 27, 1, 0, 0).unwrap()));
+}
+
+#[test]
+fn undated_simulations_keep_hour_counters() {
+    let mut cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, 48.0).unwrap();
+    assert_eq!(cyclogenesis.start_time(), None);
+    let results = cyclogenesis.simulate_interaction(24).unwrap();
+    assert!(results.iter().all(|result| result.valid_time().is_none()));
+    assert_eq!(results.iter().map(|result| result.hour()).collect::<Vec<_>>(), (0..24).collect::<Vec<_>>());
+}
+
+#[test]
+fn dating_does_not_change_the_physics() {
+    let start = Utc.with_ymd_and_hms(2010, 2, 27, 18, 0, 0).unwrap();
+    let undated = BaroclinicCyclogenesis::new(5.0, -8.0, 48.0).unwrap().simulate_interaction(24).unwrap();
+    let dated = BaroclinicCyclogenesis::new(5.0, -8.0, 48.0).unwrap().with_start_time(start).simulate_interaction(24).unwrap();
+    for (undated, dated) in undated.iter().zip(&dated) {
+        assert_eq!(undated.relative_vorticity(), dated.relative_vorticity());
+        assert_eq!(undated.vertical_velocity(), dated.vertical_velocity());
+    }
+}
+
+#[test]
+fn scenarios_date_their_results() {
+    let scenario = Scenario::from_toml("start_time = 1999-12-26T00:00:00Z\ntime_steps = 12\n").unwrap();
+    let results = scenario.run().unwrap();
+    assert_eq!(results.last().unwrap().valid_time(), Some(Utc.with_ymd_and_hms(1999, 12, 26, 11, 0, 0).unwrap()));
+
+    // La date est sérialisée en RFC 3339
+    let json = serde_json::to_string(&results[3]).unwrap();
+    assert!(json.contains("1999-12-26T03:00:00Z"), "{}", json);
+}
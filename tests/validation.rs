@@ -0,0 +1,105 @@
+//! Politique de validation : refus, valeurs ramenées à leur borne, aucun contrôle
+
+use std::process::Command;
+
+use cyclogenese_rust::check::{Finding, ScenarioCheck};
+use cyclogenese_rust::validation::{Bound, Clamp, Limit};
+use cyclogenese_rust::{
+    BaroclinicCyclogenesis, MeteoError, OutputLanguage, Position, Scenario, ValidationLimits, ValidationPolicy,
+};
+
+#[test]
+fn strict_policy_rejects_out_of_range_values() {
+    assert!(matches!(Position::new(95.0, 0.0, 1013.0), Err(MeteoError::InvalidLatitude(_))));
+    assert!(matches!(
+        Position::with_policy(45.0, 0.0, 50.0, ValidationPolicy::Strict),
+        Err(MeteoError::InvalidPressure(_))
+    ));
+}
+
+#[test]
+fn lenient_policy_clamps_to_the_nearest_bound() {
+    assert_eq!(ValidationPolicy::Lenient.apply(Bound::Latitude, 95.0).unwrap(), 90.0);
+    assert_eq!(ValidationPolicy::Lenient.apply(Bound::Altitude, -1000.0).unwrap(), -400.0);
+    assert_eq!(ValidationPolicy::Lenient.apply(Bound::Temperature, 12.0).unwrap(), 12.0);
+    assert!(matches!(ValidationPolicy::Lenient.apply(Bound::Pressure, f64::NAN), Err(MeteoError::InvalidPressure(_))));
+
+    let mut clamped = BaroclinicCyclogenesis::with_policy(80.0, -8.0, 45.0, ValidationPolicy::Lenient).unwrap();
+    let mut reference = BaroclinicCyclogenesis::new(50.0, -8.0, 45.0).unwrap();
+    assert_eq!(clamped.simulate_interaction(6).unwrap(), reference.simulate_interaction(6).unwrap());
+}
+
+#[test]
+fn disabled_policy_accepts_any_value() {
+    assert_eq!(ValidationPolicy::Off.apply(Bound::Temperature, 80.0).unwrap(), 80.0);
+    assert!(Position::with_policy(95.0, 30000.0, 50.0, ValidationPolicy::Off).is_ok());
+}
+
+#[test]
+fn scenario_check_reports_clamped_values() {
+    let scenario = Scenario::from_toml("validation = \"lenient\"\nlatitude = 95.0\nsurface_temp = 5.0\n").unwrap();
+    let check = ScenarioCheck::run(&scenario).unwrap();
+    let clamp = Clamp::of(Bound::Latitude, 95.0).unwrap();
+    assert_eq!(clamp.clamped, 90.0);
+    assert!(check.findings().contains(&Finding::Clamped(clamp)));
+    assert!(!check.is_clean());
+    assert_eq!(
+        clamp.describe(OutputLanguage::French, &Default::default()),
+        "Attention : latitude de 95.00° hors plage, ramenée à 90.00°"
+    );
+
+    let strict = Scenario { validation: None, ..scenario };
+    assert!(matches!(ScenarioCheck::run(&strict), Err(MeteoError::InvalidLatitude(_))));
+}
+
+#[test]
+fn scenario_limits_replace_the_default_ranges() {
+    let scenario = Scenario::from_toml("surface_temp = 70.0\n[limits]\ntemperature = { min = -80.0, max = 80.0 }\n").unwrap();
+    assert_eq!(scenario.limits.unwrap().altitude, ValidationLimits::default().altitude);
+    assert!(ScenarioCheck::run(&scenario).unwrap().is_clean());
+    assert!(matches!(Scenario { limits: None, ..scenario.clone() }.run(), Err(MeteoError::InvalidTemperature(_))));
+
+    let lenient = Scenario { surface_temp: 95.0, validation: Some(ValidationPolicy::Lenient), ..scenario };
+    let check = ScenarioCheck::run(&lenient).unwrap();
+    assert!(check.findings().contains(&Finding::Clamped(Clamp { bound: Bound::Temperature, value: 95.0, clamped: 80.0 })));
+
+    let stratosphere = ValidationLimits { pressure: Limit::new(1.0, 1100.0), ..ValidationLimits::default() };
+    assert!(Position::with_limits(45.0, 15000.0, 50.0, ValidationPolicy::Strict, &stratosphere).is_ok());
+    assert_eq!(stratosphere.range(Bound::Latitude), Bound::Latitude.range());
+}
+
+#[test]
+fn inverted_limits_are_rejected() {
+    let scenario = Scenario::from_toml("[limits]\npressure = { min = 1100.0, max = 100.0 }\n").unwrap();
+    assert!(matches!(scenario.to_cyclogenesis(), Err(MeteoError::InvalidScenario(_))));
+}
+
+#[test]
+fn lenient_scenarios_return_their_clamps() {
+    let scenario = Scenario::from_toml(
+        "validation = \"lenient\"\nlatitude = 95.0\nsurface_temp = 60.0\n[limits]\naltitude = { min = 0.0, max = 3000.0 }\n",
+    )
+    .unwrap();
+    assert_eq!(
+        scenario.clamps(),
+        vec![
+            Clamp { bound: Bound::Latitude, value: 95.0, clamped: 90.0 },
+            Clamp { bound: Bound::Altitude, value: 5000.0, clamped: 3000.0 },
+            Clamp { bound: Bound::Temperature, value: 60.0, clamped: 50.0 },
+        ]
+    );
+    let check = ScenarioCheck::run(&scenario).unwrap();
+    assert!(scenario.clamps().into_iter().all(|clamp| check.findings().contains(&Finding::Clamped(clamp))));
+    assert!(Scenario { validation: Some(ValidationPolicy::Off), ..scenario.clone() }.clamps().is_empty());
+    assert!(Scenario::default().clamps().is_empty());
+
+    // La commande signale chaque valeur ramenée avant de simuler
+    let path = std::env::temp_dir().join(format!("cyclogenese-clamps-{}.toml", std::process::id()));
+    std::fs::write(&path, "validation = \"lenient\"\nlatitude = 95.0\nsurface_temp = 60.0\n").unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_cyclogenese")).arg("report").arg(&path).output().unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("Attention : latitude de 95.00° hors plage, ramenée à 90.00°"), "{}", stderr);
+    assert!(stderr.contains("Attention : anomalie thermique de 60.00 K hors plage, ramenée à 50.00 K"), "{}", stderr);
+}
@@ -0,0 +1,67 @@
+//! Vérification d'une simulation par une série observée
+
+use cyclogenese_rust::io::observations;
+use cyclogenese_rust::verify::{Scores, Verification};
+use cyclogenese_rust::{MeteoError, NumberFormat, OutputLanguage, Scenario};
+
+#[test]
+fn csv_observations_are_read_by_hour_or_date() {
+    let states = observations::parse_csv("# Lothar\nhour,pressure,note\n0,1010.5,début\n\n3.5,,\n", None).unwrap();
+    assert_eq!(states.len(), 2);
+    assert_eq!((states[0].hour, states[0].central_pressure, states[0].relative_vorticity), (0.0, Some(1010.5), None));
+    assert_eq!((states[1].hour, states[1].central_pressure), (3.5, None));
+
+    let start = "1999-12-25T12:00:00Z".parse().unwrap();
+    let text = "time,relative_vorticity\n1999-12-25T18:00:00Z,-4e-5\n1999-12-26T00:30:00+00:00,-8e-5\n";
+    let states = observations::parse_csv(text, Some(start)).unwrap();
+    assert_eq!(states.iter().map(|state| state.hour).collect::<Vec<_>>(), [6.0, 12.5]);
+    assert_eq!(states[1].relative_vorticity, Some(-8e-5));
+
+    for text in ["time,pressure\n1999-12-25T18:00:00Z,1000\n", "pressure\n1000\n", "hour,wind\n0,20\n", "hour,pressure\n0,abc\n", ""] {
+        assert!(matches!(observations::parse_csv(text, None), Err(MeteoError::InvalidObservations(_))), "{:?}", text);
+    }
+}
+
+#[test]
+fn scores_measure_bias_error_and_correlation() {
+    let scores = Scores::compute(&[(1.0, 0.0), (3.0, 2.0), (2.0, 4.0)]).unwrap();
+    assert_eq!(scores.count, 3);
+    assert_eq!(scores.bias, 0.0);
+    assert!((scores.mae - 4.0 / 3.0).abs() < 1e-12);
+    assert!((scores.rmse - 2f64.sqrt()).abs() < 1e-12);
+    assert!((scores.correlation.unwrap() - 0.5).abs() < 1e-12);
+
+    assert_eq!(Scores::compute(&[(1.0, 1.0), (2.0, 1.0)]).unwrap().correlation, None);
+    assert!(Scores::compute(&[]).is_none());
+}
+
+#[test]
+fn simulation_is_interpolated_at_the_observed_hours() {
+    let scenario = Scenario::default();
+    let mut cyclogenesis = scenario.to_cyclogenesis().unwrap();
+    let results = cyclogenesis.simulate_interaction(scenario.time_steps).unwrap();
+
+    // Observations tirées de la simulation elle-même, plus une hors de la période
+    let text = format!(
+        "hour,central_pressure,relative_vorticity\n2,{},{}\n2.5,,\n30,990,\n",
+        cyclogenesis.central_pressure(&results[2]),
+        results[2].relative_vorticity()
+    );
+    let observations = observations::parse_csv(&text, None).unwrap();
+    let verification = Verification::compute(&scenario, &observations).unwrap();
+
+    assert_eq!(verification.pairs().len(), 2);
+    assert_eq!(verification.discarded(), 1);
+    let midway = (results[2].relative_vorticity() + results[3].relative_vorticity()) / 2.0;
+    assert!((verification.pairs()[1].simulated_vorticity - midway).abs() < 1e-18);
+    let pressure = verification.pressure().unwrap();
+    assert_eq!((pressure.count, pressure.rmse, pressure.correlation), (1, 0.0, None));
+    assert_eq!(verification.vorticity().unwrap().bias, 0.0);
+
+    let table = verification.to_table(OutputLanguage::English, &NumberFormat::default());
+    assert!(table.contains("\nCentral pressure (1 observations): bias 0.00 hPa, RMSE 0.00 hPa, MAE 0.00 hPa, correlation -\n"));
+    assert!(table.ends_with("1 observation(s) outside the simulated period\n"));
+
+    let late = observations::parse_csv("hour,pressure\n100,990\n", None).unwrap();
+    assert!(matches!(Verification::compute(&scenario, &late), Err(MeteoError::InvalidObservations(_))));
+}
@@ -0,0 +1,76 @@
+//! Bilan de tourbillon des perturbations
+
+use std::process::Command;
+
+use cyclogenese_rust::vorticity::{self, VorticityBudget};
+use cyclogenese_rust::{BaroclinicCyclogenesis, DevelopmentResult, Geometry, NumberFormat, OutputLanguage};
+
+fn budgets(cyclogenesis: BaroclinicCyclogenesis) -> (Vec<DevelopmentResult>, Vec<VorticityBudget>) {
+    let mut cyclogenesis = cyclogenesis;
+    let (results, steps) = cyclogenesis.simulate_with_diagnostics(24).unwrap();
+    (results, steps.iter().map(|step| step.vorticity).collect())
+}
+
+fn reference() -> BaroclinicCyclogenesis {
+    BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap()
+}
+
+#[test]
+fn the_tendency_is_the_sum_of_the_terms() {
+    let (results, budgets) = budgets(reference());
+    for (budget, result) in budgets.iter().zip(&results) {
+        assert_eq!(budget.hour, result.hour());
+        assert_eq!(budget.tendency(), budget.stretching + budget.tilting + budget.advection + budget.friction);
+        // Le frottement s'oppose toujours au tourbillon
+        assert!(budget.friction * result.relative_vorticity() < 0.0);
+        assert_ne!(budget.stretching, 0.0);
+    }
+}
+
+#[test]
+fn each_term_depends_on_its_own_process() {
+    // Plan f : pas d'advection du tourbillon planétaire
+    let (_, f_plane) = budgets(reference());
+    assert!(f_plane.iter().all(|budget| budget.advection == 0.0 && budget.tilting != 0.0));
+    let (results, beta_plane) = budgets(reference().with_geometry(Geometry::BetaPlane));
+    for (budget, result) in beta_plane.iter().zip(&results) {
+        assert!(budget.advection * result.relative_vorticity() < 0.0);
+    }
+
+    // Anomalies à la verticale l'une de l'autre : pas de basculement
+    let (_, upright) = budgets(reference().with_tilt(0.0).unwrap());
+    assert!(upright.iter().all(|budget| budget.tilting.abs() < 1e-18));
+}
+
+#[test]
+fn the_budget_option_prints_each_term() {
+    let (_, budgets) = budgets(reference());
+    let format = NumberFormat::default();
+    let table = vorticity::to_table(&budgets, OutputLanguage::English, &format);
+    let lines = table.lines().collect::<Vec<_>>();
+    assert_eq!(lines[0], "Relative vorticity tendency (10⁻¹¹ s⁻²)");
+    assert!(lines[1].starts_with("Hour  | Stretching           | Tilting"));
+    assert_eq!(lines.len(), 3 + 24);
+    assert!(lines[3].ends_with(&format.format_width(budgets[0].tendency() / 1e-11, 20)));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_cyclogenese")).arg("--budget").output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.matches("Tendance du tourbillon relatif (10⁻¹¹ s⁻²)").count(), 3);
+    assert!(stdout.contains("Heure | Étirement"));
+}
+
+#[test]
+fn the_budget_option_is_refused_with_a_subcommand() {
+    let run = |arguments: &[&str]| Command::new(env!("CARGO_BIN_EXE_cyclogenese")).args(arguments).output().unwrap();
+    for arguments in [&["--budget", "report"][..], &["report", "--budget"], &["--diagnostics", "downstream", "--table"]] {
+        let output = run(arguments);
+        assert_eq!(output.status.code(), Some(2), "{:?}", arguments);
+        assert!(output.stdout.is_empty());
+    }
+    let refused = String::from_utf8(run(&["--energy", "classify"]).stderr).unwrap();
+    assert!(refused.contains("--energy ne s'applique qu'au tableau de référence"), "{}", refused);
+
+    let help = String::from_utf8(run(&["downstream", "--help"]).stdout).unwrap();
+    assert!(!help.contains("--diagnostics") && !help.contains("--budget") && help.contains("--lang"));
+}
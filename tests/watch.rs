@@ -0,0 +1,63 @@
+//! Relance d'un scénario à chaque modification de son fichier
+
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use cyclogenese_rust::watch::Watcher;
+use cyclogenese_rust::{MeteoError, NumberFormat, OutputLanguage};
+
+fn scenario_file(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("cyclogenese-watch-{}-{}.toml", name, std::process::id()))
+}
+
+/// Réécrit le fichier avec une date de modification distincte de la précédente
+fn rewrite(path: &Path, text: &str, seconds: u64) {
+    fs::write(path, text).unwrap();
+    let file = File::options().write(true).open(path).unwrap();
+    file.set_modified(SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000 + seconds)).unwrap();
+}
+
+#[test]
+fn runs_are_repeated_only_when_the_file_changes() {
+    let path = scenario_file("changes");
+    rewrite(&path, "time_steps = 12\n", 0);
+    let (language, format) = (OutputLanguage::English, NumberFormat::default());
+    let mut watcher = Watcher::new(&path);
+
+    let first = watcher.poll(language, &format).unwrap().unwrap();
+    assert!(first.contains("Simulation at 45°N:"));
+    assert!(first.contains("Peak relative vorticity (10⁻⁵ s⁻¹):"));
+    assert!(!first.contains("vs previous run"));
+    assert_eq!(first.lines().filter(|line| line.contains(" | ")).count(), 13);
+    assert!(watcher.poll(language, &format).is_none());
+
+    // Anomalie de surface plus chaude : pics plus forts que le calcul précédent
+    rewrite(&path, "time_steps = 12\nsurface_temp = 7.0\n", 1);
+    let second = watcher.poll(language, &format).unwrap().unwrap();
+    fs::remove_file(&path).unwrap();
+    let vorticity = second.lines().find(|line| line.starts_with("Peak relative vorticity")).unwrap();
+    assert!(vorticity.contains("(+") && vorticity.ends_with("vs previous run)"), "{}", vorticity);
+}
+
+#[test]
+fn failed_runs_are_reported_and_skipped_in_comparisons() {
+    let path = scenario_file("failures");
+    rewrite(&path, "time_steps = 12\n", 0);
+    let (language, format) = (OutputLanguage::French, NumberFormat::default());
+    let mut watcher = Watcher::new(&path);
+    let first = watcher.poll(language, &format).unwrap().unwrap();
+
+    rewrite(&path, "latitude = 95.0\n", 1);
+    assert!(matches!(watcher.poll(language, &format), Some(Err(MeteoError::InvalidLatitude(_)))));
+
+    // Le même scénario que le premier calcul : écart nul
+    rewrite(&path, "time_steps = 12\n", 2);
+    let again = watcher.poll(language, &format).unwrap().unwrap();
+    assert!(again.contains("(+0.00 par rapport au calcul précédent)"), "{}", again);
+    assert_eq!(again.lines().next(), first.lines().next());
+
+    fs::remove_file(&path).unwrap();
+    assert!(matches!(watcher.poll(language, &format), Some(Err(_))));
+    assert!(watcher.poll(language, &format).is_none());
+}
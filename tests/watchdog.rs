@@ -0,0 +1,36 @@
+//! Garde-fou contre la divergence de l'intégration
+
+use cyclogenese_rust::{BaroclinicCyclogenesis, DevelopmentResult, Geometry, MeteoError, MAX_VORTICITY_RATIO};
+
+#[test]
+fn reference_run_stays_below_the_threshold() {
+    let results = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap().simulate_interaction(120);
+    assert_eq!(results.unwrap().len(), 120);
+}
+
+#[test]
+fn runaway_growth_reports_the_offending_step() {
+    let error = BaroclinicCyclogenesis::new(30.0, 20.0, 60.0)
+        .unwrap()
+        .simulate_interaction(10_000)
+        .unwrap_err();
+
+    let MeteoError::NumericalInstability(state) = error else {
+        panic!("erreur inattendue : {error}");
+    };
+    assert!(state.relative_vorticity.abs() > MAX_VORTICITY_RATIO * 2.0 * 7.2921e-5);
+    assert!(state.hour > 0 && state.hour < 10_000);
+    assert!(state.intensities.iter().all(|&intensity| intensity > 1.0));
+}
+
+#[test]
+fn algebraic_growth_of_ordinary_runs_is_not_divergent() {
+    // Une semaine du cas de référence, trois jours de dérive β d'anomalies de 8 et 2 K
+    let week: Vec<DevelopmentResult> = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap().simulate_interaction(168).unwrap();
+    assert_eq!(week.len(), 168);
+    let beta = BaroclinicCyclogenesis::new(8.0, 2.0, 45.0).unwrap().with_geometry(Geometry::BetaPlane).simulate_interaction(72);
+    assert_eq!(beta.unwrap().len(), 72);
+    // Le tourbillon y dépasse le tourbillon planétaire sans approcher le seuil
+    let peak = week.iter().map(|result| result.relative_vorticity().abs()).fold(0.0, f64::max);
+    assert!(peak > 2.0 * 7.2921e-5 && peak < MAX_VORTICITY_RATIO / 10.0 * 2.0 * 7.2921e-5, "{}", peak);
+}
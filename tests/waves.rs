@@ -0,0 +1,52 @@
+//! Hauteur significative des vagues levées par le vent
+
+use cyclogenese_rust::waves::{self, WaveGrowth, WaveModel};
+use cyclogenese_rust::{scenarios, MeteoError, NumberFormat, OutputLanguage};
+
+#[test]
+fn growth_is_limited_by_fetch_duration_or_saturation() {
+    let model = WaveModel::new(1.0e5).unwrap();
+    let day = 86_400.0;
+
+    // 20 m/s sur 100 km pendant une journée : fetch limitant
+    let (height, period, growth) = model.sea_state(20.0, day);
+    assert_eq!(growth, WaveGrowth::Fetch);
+    let fetch = 9.81 * 1.0e5 / 400.0;
+    assert!((height - 0.0016 * f64::sqrt(fetch) * 400.0 / 9.81).abs() < 1e-9);
+    assert!((period - 0.286 * f64::cbrt(fetch) * 20.0 / 9.81).abs() < 1e-9);
+
+    // Une heure seulement : la durée limite la croissance
+    let (short, _, growth) = model.sea_state(20.0, 3600.0);
+    assert_eq!(growth, WaveGrowth::Duration);
+    assert!(short < height);
+
+    // Vent faible sur un grand fetch : mer pleinement développée de Pierson–Moskowitz
+    let (height, period, growth) = WaveModel::default().sea_state(5.0, day);
+    assert_eq!(growth, WaveGrowth::FullyDeveloped);
+    assert!((height - 0.243 * 25.0 / 9.81).abs() < 1e-9);
+    assert!((period - 8.13 * 5.0 / 9.81).abs() < 1e-9);
+
+    assert_eq!(model.sea_state(0.0, day), (0.0, 0.0, WaveGrowth::Duration));
+    assert!(matches!(WaveModel::new(-1.0), Err(MeteoError::InvalidScenario(_))));
+}
+
+#[test]
+fn storm_waves_build_with_the_wind() {
+    let scenario = scenarios::find("lothar-1999").unwrap().scenario();
+    let mut cyclogenesis = scenario.to_cyclogenesis().unwrap();
+    let results = cyclogenesis.simulate_interaction(scenario.time_steps).unwrap();
+    let states = cyclogenesis.sea_states(&results, &WaveModel::default());
+
+    assert_eq!(states.len(), results.len());
+    assert!(states.iter().zip(&results).all(|(state, result)| state.wind == cyclogenesis.surface_wind(result)));
+    let peak = states.iter().max_by(|a, b| a.significant_height.total_cmp(&b.significant_height)).unwrap();
+    assert!(peak.significant_height > 1.0, "{:?}", peak);
+    // Un fetch plus court réduit la mer
+    let short = cyclogenesis.sea_states(&results, &WaveModel::new(5.0e4).unwrap());
+    assert!(short.iter().zip(&states).all(|(short, state)| short.significant_height <= state.significant_height));
+
+    let format = NumberFormat::default();
+    let table = waves::to_table(&states, OutputLanguage::English, &format);
+    assert!(table.starts_with("Hour  | Mean wind (m/s)      | Height Hs (m)        | Period Tp (s)        | Growth\n"));
+    assert!(table.ends_with(&format!("\n{}\n", waves::describe_peak(&states, OutputLanguage::English, &format))));
+}
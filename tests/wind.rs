@@ -0,0 +1,87 @@
+//! Champ de vent reconstruit autour de la dépression
+
+use cyclogenese_rust::wind::WindGrid;
+use cyclogenese_rust::{AnomalyShape, BaroclinicCyclogenesis, MeteoError, Scenario};
+
+#[test]
+fn gaussian_vortex_has_the_calibrated_maximum_wind() {
+    let mut cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap();
+    let results = cyclogenesis.simulate_interaction(24).unwrap();
+    let last = results.last().unwrap();
+    let field = cyclogenesis.wind_field(last, &WindGrid::new(10.0, 0.1).unwrap());
+
+    // ζ₀ ≈ 3,134 V/R pour le profil gaussien de rayon 500 km
+    let expected = last.relative_vorticity().abs() * 5.0e5 / 3.134;
+    assert!((field.max_speed() - expected).abs() < 0.01 * expected, "{} au lieu de {}", field.max_speed(), expected);
+}
+
+#[test]
+fn rotation_follows_the_sign_of_the_vorticity() {
+    let mut cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap();
+    let results = cyclogenesis.simulate_interaction(6).unwrap();
+    let grid = WindGrid::new(4.0, 1.0).unwrap();
+    let field = cyclogenesis.wind_field(&results[5], &grid);
+    let (center, north, east) = (4, 6, 6);
+
+    assert_eq!(field.latitudes().len(), grid.size());
+    assert_eq!(field.wind(center, center), Some((0.0, 0.0)));
+    let sign = results[5].relative_vorticity().signum();
+    // Cyclonique (ζ > 0) : vent d'est au nord du centre, vent du sud à l'est
+    let (u, _) = field.wind(north, center).unwrap();
+    let (_, v) = field.wind(center, east).unwrap();
+    assert_eq!(u.signum(), -sign);
+    assert_eq!(v.signum(), sign);
+}
+
+#[test]
+fn top_hat_vortex_is_rankine() {
+    let scenario = Scenario { shape: Some(AnomalyShape::TopHat), ..Scenario::default() };
+    let mut cyclogenesis = scenario.to_cyclogenesis().unwrap();
+    let results = cyclogenesis.simulate_interaction(1).unwrap();
+    let field = cyclogenesis.wind_field(&results[0], &WindGrid::new(10.0, 0.05).unwrap());
+
+    // Vent maximal V = ζ₀R/2 sur le bord du disque
+    let expected = results[0].relative_vorticity().abs() * 5.0e5 / 2.0;
+    assert!((field.max_speed() - expected).abs() < 0.01 * expected);
+}
+
+#[test]
+fn moving_center_follows_the_track() {
+    let scenario = Scenario::from_toml(
+        "geometry = \"spherical\"\nstart_time = 1999-12-25T12:00:00Z\nlongitude = -4.5\nlatitude = 48.5\n",
+    )
+    .unwrap();
+    let mut cyclogenesis = scenario.to_cyclogenesis().unwrap();
+    let results = cyclogenesis.simulate_interaction(scenario.time_steps).unwrap();
+    let fields = cyclogenesis.wind_fields(&results, &WindGrid::default());
+
+    for (field, result) in fields.iter().zip(&results) {
+        assert_eq!(Some(field.center_latitude), result.latitude());
+        assert_eq!(Some(field.center_longitude), result.longitude());
+    }
+}
+
+#[test]
+fn invalid_grids_are_rejected() {
+    for (half_width, spacing) in [(0.0, 0.5), (45.0, 1.0), (5.0, 0.0), (5.0, 6.0), (f64::NAN, 1.0)] {
+        assert!(matches!(WindGrid::new(half_width, spacing), Err(MeteoError::InvalidGrid(_))));
+    }
+}
+
+#[cfg(feature = "netcdf")]
+#[test]
+fn wind_fields_round_trip_through_netcdf() {
+    use netcdf3::FileReader;
+
+    let mut cyclogenesis = BaroclinicCyclogenesis::new(5.0, -8.0, 45.0).unwrap();
+    let results = cyclogenesis.simulate_interaction(3).unwrap();
+    let fields = cyclogenesis.wind_fields(&results, &WindGrid::new(2.0, 1.0).unwrap());
+    let path = std::env::temp_dir().join(format!("cyclogenese-wind-{}.nc", std::process::id()));
+    cyclogenese_rust::io::netcdf::write_wind_fields(&path, &fields).unwrap();
+
+    let mut reader = FileReader::open(&path).unwrap();
+    let u = reader.read_var_f64("u").unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let expected: Vec<_> = fields.iter().flat_map(|field| field.u().to_vec()).collect();
+    assert_eq!(u, expected);
+}